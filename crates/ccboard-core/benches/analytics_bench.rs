@@ -10,6 +10,7 @@ use ccboard_core::analytics::{
     compute_trends, detect_patterns, forecast_usage, generate_insights, AnalyticsData, Period,
 };
 use ccboard_core::models::session::SessionMetadata;
+use ccboard_types::Currency;
 use chrono::Utc;
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 use std::sync::Arc;
@@ -24,7 +25,6 @@ fn generate_test_sessions(count: usize, days: usize) -> Vec<Arc<SessionMetadata>
 
             Arc::new(SessionMetadata {
                 id: format!("session-{}", i).into(),
-                source_tool: None,
                 file_path: std::path::PathBuf::from(format!("/test/session-{}.jsonl", i)),
                 project_path: "/test".into(),
                 first_timestamp: Some(ts),
@@ -41,13 +41,23 @@ fn generate_test_sessions(count: usize, days: usize) -> Vec<Arc<SessionMetadata>
                 first_user_message: None,
                 has_subagents: false,
                 parent_session_id: None,
+                resumed_from: None,
+                compaction_count: 0,
                 tool_usage: std::collections::HashMap::new(),
                 tool_token_usage: std::collections::HashMap::new(),
+                tool_followup_tokens: std::collections::HashMap::new(),
                 duration_seconds: Some(1800),
                 branch: None,
                 source_tool: Default::default(),
                 lines_added: 0,
                 lines_removed: 0,
+                tool_result_bytes: 0,
+                peak_context_tokens: 0,
+                peak_context_pct: 0.0,
+                malformed_lines: 0,
+                estimated_extra_tokens: 0,
+                cwd: None,
+                author: None,
             })
         })
         .collect()
@@ -119,7 +129,12 @@ fn insights_benchmark(c: &mut Criterion) {
 
     c.bench_function("generate_insights", |b| {
         b.iter(|| {
-            black_box(generate_insights(&trends, &patterns, &forecast));
+            black_box(generate_insights(
+                &trends,
+                &patterns,
+                &forecast,
+                &Currency::default(),
+            ));
         });
     });
 }
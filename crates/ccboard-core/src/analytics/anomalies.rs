@@ -5,12 +5,14 @@
 
 use crate::models::config::AnomalyThresholds;
 use crate::models::session::{SessionId, SessionMetadata};
+use ccboard_types::Currency;
 use chrono::{Local, NaiveDate};
+use serde::Serialize;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
 /// Severity level for anomalies based on standard deviations
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum AnomalySeverity {
     /// Critical: >3 standard deviations from mean
     Critical,
@@ -37,12 +39,14 @@ impl AnomalySeverity {
 }
 
 /// Metric type for anomaly detection
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum AnomalyMetric {
     /// Total token usage
     Tokens,
     /// Session cost estimate
     Cost,
+    /// Share of prompt tokens served from cache
+    CacheRatio,
 }
 
 impl AnomalyMetric {
@@ -51,12 +55,13 @@ impl AnomalyMetric {
         match self {
             Self::Tokens => "Tokens",
             Self::Cost => "Cost",
+            Self::CacheRatio => "Cache Ratio",
         }
     }
 }
 
 /// Detected anomaly with context
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Anomaly {
     /// Session ID
     pub session_id: SessionId,
@@ -76,10 +81,11 @@ pub struct Anomaly {
 
 impl Anomaly {
     /// Format value based on metric type
-    pub fn format_value(&self) -> String {
+    pub fn format_value(&self, currency: &Currency) -> String {
         match self.metric {
             AnomalyMetric::Tokens => format!("{:.0}", self.value),
-            AnomalyMetric::Cost => format!("${:.2}", self.value),
+            AnomalyMetric::Cost => currency.format_cost(self.value),
+            AnomalyMetric::CacheRatio => format!("{:.0}%", self.value * 100.0),
         }
     }
 
@@ -138,12 +144,44 @@ impl Statistics {
     }
 }
 
-/// Detect anomalies in session token usage
+/// Severity for a Z-score magnitude, or `None` if within the warning threshold
+fn classify(abs_z: f64, thresholds: &AnomalyThresholds) -> Option<AnomalySeverity> {
+    if abs_z > thresholds.critical_z_score {
+        Some(AnomalySeverity::Critical)
+    } else if abs_z > thresholds.warning_z_score {
+        Some(AnomalySeverity::Warning)
+    } else {
+        None
+    }
+}
+
+/// Display date for a session (first timestamp, minute precision)
+fn session_date(session: &SessionMetadata) -> String {
+    session
+        .first_timestamp
+        .as_ref()
+        .map(|ts| ts.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Fraction of prompt tokens served from cache for a session
+///
+/// Returns `None` when the session has no prompt tokens at all.
+fn cache_ratio(session: &SessionMetadata) -> Option<f64> {
+    ccboard_types::cache_hit_ratio(
+        session.input_tokens,
+        session.cache_read_tokens,
+        session.cache_creation_tokens,
+    )
+}
+
+/// Detect anomalies in session token usage and cache hit ratio
 ///
 /// # Algorithm
 /// - Calculate mean (μ) and standard deviation (σ) for all sessions
 /// - Z-score = (x - μ) / σ
 /// - Flag if |z| > 2 (warning) or |z| > 3 (critical)
+/// - Cache ratio uses a per-project baseline and only flags drops (z < -2)
 ///
 /// # Requirements
 /// - Minimum 10 sessions for meaningful statistics
@@ -175,22 +213,11 @@ pub fn detect_anomalies_with_thresholds(
             for (idx, session) in sessions.iter().enumerate() {
                 let value = token_values[idx];
                 let z_score = stats.z_score(value);
-                let abs_z = z_score.abs();
-
-                if abs_z > thresholds.warning_z_score {
-                    let severity = if abs_z > thresholds.critical_z_score {
-                        AnomalySeverity::Critical
-                    } else {
-                        AnomalySeverity::Warning
-                    };
 
+                if let Some(severity) = classify(z_score.abs(), thresholds) {
                     anomalies.push(Anomaly {
                         session_id: session.id.clone(),
-                        date: session
-                            .first_timestamp
-                            .as_ref()
-                            .map(|ts| ts.format("%Y-%m-%d %H:%M").to_string())
-                            .unwrap_or_else(|| "unknown".to_string()),
+                        date: session_date(session),
                         metric: AnomalyMetric::Tokens,
                         value,
                         z_score,
@@ -202,6 +229,50 @@ pub fn detect_anomalies_with_thresholds(
         }
     }
 
+    // Cache ratio anomalies: compared against each project's own baseline, since
+    // caching behavior differs a lot between projects. Only drops are flagged —
+    // an unusually high hit ratio is good news.
+    let mut by_project: BTreeMap<&str, Vec<(&Arc<SessionMetadata>, f64)>> = BTreeMap::new();
+    for session in sessions {
+        if let Some(ratio) = cache_ratio(session) {
+            by_project
+                .entry(session.project_path.as_str())
+                .or_default()
+                .push((session, ratio));
+        }
+    }
+
+    for samples in by_project.values() {
+        if samples.len() < thresholds.min_sessions {
+            continue;
+        }
+        let ratios: Vec<f64> = samples.iter().map(|(_, ratio)| *ratio).collect();
+        let Some(stats) = Statistics::compute(&ratios) else {
+            continue;
+        };
+        if stats.std_dev <= 0.0 {
+            continue;
+        }
+
+        for (session, value) in samples {
+            let z_score = stats.z_score(*value);
+            if z_score >= 0.0 {
+                continue;
+            }
+            if let Some(severity) = classify(z_score.abs(), thresholds) {
+                anomalies.push(Anomaly {
+                    session_id: session.id.clone(),
+                    date: session_date(session),
+                    metric: AnomalyMetric::CacheRatio,
+                    value: *value,
+                    z_score,
+                    deviation_pct: stats.deviation_pct(*value),
+                    severity,
+                });
+            }
+        }
+    }
+
     // TODO: Cost anomalies
     // Cost calculation requires StatsCache for pricing data, which is not available here.
     // For now, focus on token-based anomaly detection only.
@@ -226,7 +297,7 @@ pub fn detect_anomalies_with_thresholds(
 }
 
 /// A daily cost spike: one day's estimated cost is an outlier vs the recent baseline.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DailyCostAnomaly {
     /// Date of the spike
     pub date: NaiveDate,
@@ -247,8 +318,8 @@ impl DailyCostAnomaly {
     }
 
     /// Formatted cost
-    pub fn format_cost(&self) -> String {
-        format!("${:.3}", self.cost_estimate)
+    pub fn format_cost(&self, currency: &Currency) -> String {
+        currency.format(self.cost_estimate, 3)
     }
 }
 
@@ -357,13 +428,23 @@ mod tests {
             first_user_message: Some("test message".to_string()),
             has_subagents: false,
             parent_session_id: None,
+            resumed_from: None,
+            compaction_count: 0,
             duration_seconds: Some(60),
             branch: Some("main".to_string()),
             tool_usage: std::collections::HashMap::new(),
             tool_token_usage: std::collections::HashMap::new(),
+            tool_followup_tokens: std::collections::HashMap::new(),
             source_tool: Default::default(),
             lines_added: 0,
             lines_removed: 0,
+            tool_result_bytes: 0,
+            peak_context_tokens: 0,
+            peak_context_pct: 0.0,
+            malformed_lines: 0,
+            estimated_extra_tokens: 0,
+            cwd: None,
+            author: None,
         })
     }
 
@@ -465,6 +546,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_detect_cache_ratio_drop() {
+        let with_cache = |id: &str, project: &str, cache_read: u64| {
+            let mut session = (*create_test_session(id, 1000)).clone();
+            session.project_path = project.into();
+            session.input_tokens = 100;
+            session.cache_read_tokens = cache_read;
+            Arc::new(session)
+        };
+
+        let mut sessions = vec![];
+        // Project "a": ~90% hit ratio, then one session where caching broke
+        for i in 0..10 {
+            sessions.push(with_cache(&format!("a{}", i), "a", 880 + i * 5));
+        }
+        sessions.push(with_cache("a_broken", "a", 0));
+        // Project "b": never caches, which is its normal baseline
+        for i in 0..10 {
+            sessions.push(with_cache(&format!("b{}", i), "b", 0));
+        }
+
+        let anomalies: Vec<_> = detect_anomalies(&sessions)
+            .into_iter()
+            .filter(|a| a.metric == AnomalyMetric::CacheRatio)
+            .collect();
+
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].session_id.as_str(), "a_broken");
+        assert!(anomalies[0].z_score < 0.0);
+        assert_eq!(anomalies[0].format_value(&Currency::default()), "0%");
+        assert_eq!(anomalies[0].metric.name(), "Cache Ratio");
+    }
+
     #[test]
     fn test_deviation_percentage() {
         let mut sessions = vec![];
@@ -516,13 +630,23 @@ mod tests {
             first_user_message: Some("test".to_string()),
             has_subagents: false,
             parent_session_id: None,
+            resumed_from: None,
+            compaction_count: 0,
             duration_seconds: Some(30),
             branch: Some("main".to_string()),
             tool_usage: std::collections::HashMap::new(),
             tool_token_usage: std::collections::HashMap::new(),
+            tool_followup_tokens: std::collections::HashMap::new(),
             source_tool: Default::default(),
             lines_added: 0,
             lines_removed: 0,
+            tool_result_bytes: 0,
+            peak_context_tokens: 0,
+            peak_context_pct: 0.0,
+            malformed_lines: 0,
+            estimated_extra_tokens: 0,
+            cwd: None,
+            author: None,
         })
     }
 
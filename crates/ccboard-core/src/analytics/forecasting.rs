@@ -3,10 +3,13 @@
 //! Predicts future token usage and costs based on historical trends,
 //! with R² confidence metric to assess prediction reliability.
 
+use ccboard_types::Currency;
+use serde::{Deserialize, Serialize};
+
 use super::trends::TrendsData;
 
 /// Forecast data with predictions
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForecastData {
     /// Predicted tokens for next 30 days
     pub next_30_days_tokens: u64,
@@ -14,6 +17,10 @@ pub struct ForecastData {
     pub next_30_days_cost: f64,
     /// Monthly cost estimate (extrapolated)
     pub monthly_cost_estimate: f64,
+    /// Lower bound of the monthly estimate (-1 standard error, floored at 0)
+    pub monthly_cost_low: Option<f64>,
+    /// Upper bound of the monthly estimate (+1 standard error)
+    pub monthly_cost_high: Option<f64>,
     /// Confidence (R² coefficient, 0.0-1.0)
     pub confidence: f64,
     /// Trend direction
@@ -23,7 +30,7 @@ pub struct ForecastData {
 }
 
 /// Trend direction with percentage change
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TrendDirection {
     /// Increasing trend (percentage)
     Up(f64),
@@ -40,11 +47,31 @@ impl ForecastData {
             next_30_days_tokens: 0,
             next_30_days_cost: 0.0,
             monthly_cost_estimate: 0.0,
+            monthly_cost_low: None,
+            monthly_cost_high: None,
             confidence: 0.0,
             trend_direction: TrendDirection::Stable,
             unavailable_reason: Some(reason.to_string()),
         }
     }
+
+    /// Half-width of the monthly cost band, if bounds are available
+    pub fn monthly_cost_margin(&self) -> Option<f64> {
+        self.monthly_cost_high
+            .map(|high| high - self.monthly_cost_estimate)
+    }
+
+    /// Monthly estimate as "$X (±$Y)", or "$X" without bounds
+    pub fn monthly_cost_display(&self, currency: &Currency) -> String {
+        match self.monthly_cost_margin() {
+            Some(margin) => format!(
+                "{} (±{})",
+                currency.format_cost(self.monthly_cost_estimate),
+                currency.format_cost(margin)
+            ),
+            None => currency.format_cost(self.monthly_cost_estimate),
+        }
+    }
 }
 
 /// Forecast usage with linear regression
@@ -57,8 +84,9 @@ impl ForecastData {
 /// Target: <20ms
 ///
 /// # Returns
-/// - `ForecastData::unavailable()` if <7 days of data
-/// - `ForecastData` with R² confidence otherwise
+/// - `ForecastData::unavailable()` if <7 days of data (bounds need at least 3
+///   points, so they are only ever `None` together with `unavailable_reason`)
+/// - `ForecastData` with R² confidence and a ±1 standard error cost band otherwise
 pub fn forecast_usage(trends: &TrendsData) -> ForecastData {
     if trends.dates.len() < 7 {
         return ForecastData::unavailable("Insufficient data (<7 days)");
@@ -73,7 +101,7 @@ pub fn forecast_usage(trends: &TrendsData) -> ForecastData {
         .collect();
 
     // Linear regression: y = slope * x + intercept
-    let (slope, intercept, r_squared) = linear_regression(&points);
+    let (slope, intercept, r_squared, std_error) = linear_regression(&points);
 
     // R² = coefficient of determination (0.0-1.0)
     // 1.0 = perfect fit, 0.0 = no correlation
@@ -98,6 +126,19 @@ pub fn forecast_usage(trends: &TrendsData) -> ForecastData {
     let days_in_period = trends.dates.len() as f64;
     let monthly_cost_estimate = (total_cost / days_in_period) * 30.0;
 
+    // Band: daily residual standard error (in cost) accumulated over 30 days,
+    // treating daily deviations as independent
+    let (monthly_cost_low, monthly_cost_high) = match std_error {
+        Some(se) => {
+            let margin = se * cost_per_token * 30f64.sqrt();
+            (
+                Some((monthly_cost_estimate - margin).max(0.0)),
+                Some(monthly_cost_estimate + margin),
+            )
+        }
+        None => (None, None),
+    };
+
     // Trend direction (slope-based)
     let trend_direction = if slope.abs() < 0.01 * intercept.abs() {
         TrendDirection::Stable
@@ -113,6 +154,8 @@ pub fn forecast_usage(trends: &TrendsData) -> ForecastData {
         next_30_days_tokens,
         next_30_days_cost,
         monthly_cost_estimate,
+        monthly_cost_low,
+        monthly_cost_high,
         confidence,
         trend_direction,
         unavailable_reason: None,
@@ -121,11 +164,12 @@ pub fn forecast_usage(trends: &TrendsData) -> ForecastData {
 
 /// Simple linear regression with R² calculation
 ///
-/// Computes the best-fit line y = slope * x + intercept and R² coefficient.
+/// Computes the best-fit line y = slope * x + intercept, R² coefficient and
+/// the residual standard error `sqrt(SS_res / (n - 2))`.
 ///
 /// # Returns
-/// (slope, intercept, r_squared)
-fn linear_regression(points: &[(f64, f64)]) -> (f64, f64, f64) {
+/// (slope, intercept, r_squared, std_error) — `std_error` is `None` below 3 points
+fn linear_regression(points: &[(f64, f64)]) -> (f64, f64, f64, Option<f64>) {
     let n = points.len() as f64;
     let sum_x: f64 = points.iter().map(|p| p.0).sum();
     let sum_y: f64 = points.iter().map(|p| p.1).sum();
@@ -153,5 +197,7 @@ fn linear_regression(points: &[(f64, f64)]) -> (f64, f64, f64) {
         0.0
     };
 
-    (slope, intercept, r_squared)
+    let std_error = (points.len() >= 3).then(|| (ss_res / (n - 2.0)).sqrt());
+
+    (slope, intercept, r_squared, std_error)
 }
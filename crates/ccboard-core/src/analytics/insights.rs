@@ -5,6 +5,12 @@
 use super::forecasting::{ForecastData, TrendDirection};
 use super::patterns::UsagePatterns;
 use super::trends::TrendsData;
+use crate::models::billing_block::CurrentSpend;
+use crate::models::SessionMetadata;
+use crate::pricing::normalize_model_id;
+use ccboard_types::Currency;
+use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Alert types for budget and anomaly detection
 #[derive(Debug, Clone)]
@@ -19,6 +25,51 @@ pub enum Alert {
         budget: f64,
         overage: f64,
     },
+    /// Today's projected spend exceeds the daily budget
+    DailyBudgetExceeded {
+        spent: f64,
+        projected: f64,
+        budget: f64,
+    },
+    /// Current billing block's projected spend exceeds the per-block budget
+    BlockBudgetExceeded {
+        spent: f64,
+        projected: f64,
+        budget: f64,
+    },
+    /// A project's month-to-date spend is past its `perProject` alert threshold
+    ProjectBudgetWarning {
+        project: String,
+        spent: f64,
+        budget: f64,
+        pct: f64,
+    },
+}
+
+/// Month-to-date spend of a project with an explicit `budget.perProject` entry
+#[derive(Debug, Clone)]
+pub struct ProjectBudgetStatus {
+    /// Project path
+    pub project: String,
+    /// Spend this calendar month in USD
+    pub spent: f64,
+    /// Monthly budget in USD
+    pub budget: f64,
+    /// Alert threshold percentage (0-100)
+    pub alert_threshold_pct: f64,
+}
+
+/// Optional daily / per-block budgets with the spend to check them against
+///
+/// The default (no budgets) keeps `generate_budget_alerts` monthly-only.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShortTermBudget {
+    /// Daily budget in USD
+    pub daily: Option<f64>,
+    /// Per billing block budget in USD
+    pub per_block: Option<f64>,
+    /// Spend of the current day and block
+    pub spend: CurrentSpend,
 }
 
 /// Generate actionable insights
@@ -30,13 +81,17 @@ pub enum Alert {
 /// - Cost trend >+20% with confidence >0.5 → budget alert
 /// - Weekend usage <10% → weekday optimization
 /// - Low confidence (<0.5) → unreliable forecast warning
+/// - Cache hit ratio down >10 points (last 7d vs prior 7d) → caching regression
+/// - Longest consecutive-day streak ≥3 days → streak / active days summary
+/// - Context window pressure / compactions → sessions losing earlier context
 ///
 /// # Performance
 /// Target: <10ms
 pub fn generate_insights(
-    _trends: &TrendsData,
+    trends: &TrendsData,
     patterns: &UsagePatterns,
     forecast: &ForecastData,
+    currency: &Currency,
 ) -> Vec<String> {
     let mut insights = Vec::new();
 
@@ -60,13 +115,18 @@ pub fn generate_insights(
     }
 
     // 2. Expensive model warning (Opus >20% usage)
-    if let Some(&opus_pct) = patterns.model_distribution.get("opus") {
-        if opus_pct > 0.2 {
-            insights.push(format!(
-                "Opus usage: {:.0}% tokens. Costs 3x more than Sonnet. Review necessity.",
-                opus_pct * 100.0
-            ));
-        }
+    // Distribution keys are canonical IDs (claude-opus-4-5, claude-opus-4-6, ...)
+    let opus_pct: f64 = patterns
+        .model_distribution
+        .iter()
+        .filter(|(model, _)| model.starts_with("claude-opus"))
+        .map(|(_, pct)| pct)
+        .sum();
+    if opus_pct > 0.2 {
+        insights.push(format!(
+            "Opus usage: {:.0}% tokens. Costs 3x more than Sonnet. Review necessity.",
+            opus_pct * 100.0
+        ));
     }
 
     // 3. Cost imbalance (tokens vs cost distribution mismatch)
@@ -89,9 +149,9 @@ pub fn generate_insights(
     if let TrendDirection::Up(pct) = forecast.trend_direction {
         if pct > 20.0 && forecast.confidence > 0.5 {
             insights.push(format!(
-                "Cost trend: +{:.0}% over period. Monthly estimate: ${:.2} (confidence: {:.0}%).",
+                "Cost trend: +{:.0}% over period. Monthly estimate: {} (confidence: {:.0}%).",
                 pct,
-                forecast.monthly_cost_estimate,
+                currency.format_cost(forecast.monthly_cost_estimate),
                 forecast.confidence * 100.0
             ));
         }
@@ -120,14 +180,121 @@ pub fn generate_insights(
         ));
     }
 
+    // 7. Cache hit ratio regression (trailing 7 days vs the 7 days before)
+    if let Some((previous, trailing)) = trends.cache_hit_ratio_change(7) {
+        if previous - trailing > 0.1 {
+            insights.push(format!(
+                "Cache hit ratio dropped: {:.0}% → {:.0}% (last 7d). Losing caching benefits increases input cost.",
+                previous * 100.0,
+                trailing * 100.0
+            ));
+        }
+    }
+
+    // 8. Activity streak (engagement)
+    let streaks = trends.activity_streaks();
+    if streaks.longest >= 3 {
+        if streaks.current == streaks.longest {
+            insights.push(format!(
+                "{}-day streak—your longest this period ({} active days).",
+                streaks.longest, streaks.active_days
+            ));
+        } else {
+            insights.push(format!(
+                "Longest streak: {} days ({} active days). Current streak: {} day(s).",
+                streaks.longest, streaks.active_days, streaks.current
+            ));
+        }
+    }
+
+    // 9. Context window pressure (sessions peaking past the warning threshold)
+    if patterns.context_saturated_sessions > 0 {
+        insights.push(format!(
+            "{} session(s) used >{:.0}% of the context window (peak {:.0}%). Expect compaction and quality loss; split long tasks or /clear earlier.",
+            patterns.context_saturated_sessions,
+            crate::models::session::CONTEXT_WARNING_PCT,
+            patterns.max_context_pct
+        ));
+    }
+
+    // 10. Compaction (context dropped mid-session)
+    let compaction = &patterns.compaction;
+    if compaction.compacted_sessions > 0 {
+        insights.push(format!(
+            "{} session(s) hit context compaction ({} total): avg {} and {:.0} min vs {} and {:.0} min for the rest. Earlier context may have been forgotten.",
+            compaction.compacted_sessions,
+            compaction.total_compactions,
+            currency.format_cost(compaction.avg_cost_compacted),
+            compaction.avg_minutes_compacted,
+            currency.format_cost(compaction.avg_cost_other),
+            compaction.avg_minutes_other
+        ));
+    }
+
     insights
 }
 
+/// Insights for high-volume models that barely use the prompt cache
+///
+/// Cache hit ratio as defined by [`ccboard_types::cache_hit_ratio`], over
+/// `sessions` (the period's). Tokens of multi-model sessions are split like
+/// their cost (`SessionMetadata::model_shares`). A model is flagged when it
+/// read more than 1M input tokens in total and less than 30% of them came
+/// from the cache; the 3 largest are reported, alongside the overall ratio
+/// across all models.
+pub fn cache_efficiency_insights(sessions: &[Arc<SessionMetadata>]) -> Vec<String> {
+    // (input, cache reads, cache writes) per canonical model ID
+    let mut by_model: HashMap<String, (u64, u64, u64)> = HashMap::new();
+    for session in sessions {
+        for (model, share) in session.model_shares() {
+            let scaled = |tokens: u64| (tokens as f64 * share).round() as u64;
+            let entry = by_model.entry(normalize_model_id(model)).or_default();
+            entry.0 += scaled(session.input_tokens);
+            entry.1 += scaled(session.cache_read_tokens);
+            entry.2 += scaled(session.cache_creation_tokens);
+        }
+    }
+
+    let (input, reads, writes) = by_model
+        .values()
+        .fold((0, 0, 0), |(i, r, w), (mi, mr, mw)| {
+            (i + mi, r + mr, w + mw)
+        });
+    let Some(overall) = ccboard_types::cache_hit_ratio(input, reads, writes) else {
+        return Vec::new();
+    };
+
+    let mut flagged: Vec<(&String, u64, f64)> = by_model
+        .iter()
+        .filter_map(|(model, &(input, reads, writes))| {
+            let prompt = input + reads + writes;
+            let ratio = ccboard_types::cache_hit_ratio(input, reads, writes)?;
+            (prompt > 1_000_000 && ratio < 0.3).then_some((model, prompt, ratio))
+        })
+        .collect();
+    flagged.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    flagged
+        .into_iter()
+        .take(3)
+        .map(|(model, _, ratio)| {
+            format!(
+                "Model {}: only {:.0}% cache reads (overall {:.0}%)—consider stable prompts.",
+                model,
+                ratio * 100.0,
+                overall * 100.0
+            )
+        })
+        .collect()
+}
+
 /// Generate budget and anomaly alerts
 ///
 /// Detects:
 /// - Budget warnings (current cost > threshold%)
 /// - Projected overages (forecast > budget)
+/// - Daily / per-block projected spend > budget (when configured)
+/// - Per-project month-to-date spend > project threshold (when configured)
 /// - Usage spikes (daily tokens > 2x average)
 ///
 /// # Arguments
@@ -135,11 +302,15 @@ pub fn generate_insights(
 /// - `forecast`: Forecast data for budget projections
 /// - `monthly_budget`: Optional monthly budget in USD
 /// - `alert_threshold_pct`: Alert threshold (default 80%)
+/// - `short_term`: Optional daily / per-block budgets and current spend
+/// - `projects`: Month-to-date spend of projects with their own budget
 pub fn generate_budget_alerts(
     trends: &TrendsData,
     forecast: &ForecastData,
     monthly_budget: Option<f64>,
     alert_threshold_pct: f64,
+    short_term: &ShortTermBudget,
+    projects: &[ProjectBudgetStatus],
 ) -> Vec<Alert> {
     let mut alerts = Vec::new();
 
@@ -169,7 +340,43 @@ pub fn generate_budget_alerts(
         }
     }
 
-    // 3. Usage spikes (tokens > 2x average)
+    // 3. Daily and per-block budgets (projected to the end of the window)
+    if let Some(budget) = short_term.daily {
+        if short_term.spend.today_projected > budget {
+            alerts.push(Alert::DailyBudgetExceeded {
+                spent: short_term.spend.today,
+                projected: short_term.spend.today_projected,
+                budget,
+            });
+        }
+    }
+    if let Some(budget) = short_term.per_block {
+        if short_term.spend.block_projected > budget {
+            alerts.push(Alert::BlockBudgetExceeded {
+                spent: short_term.spend.block,
+                projected: short_term.spend.block_projected,
+                budget,
+            });
+        }
+    }
+
+    // 4. Per-project budgets
+    for status in projects {
+        if status.budget <= 0.0 {
+            continue;
+        }
+        let pct = status.spent / status.budget * 100.0;
+        if pct >= status.alert_threshold_pct {
+            alerts.push(Alert::ProjectBudgetWarning {
+                project: status.project.clone(),
+                spent: status.spent,
+                budget: status.budget,
+                pct,
+            });
+        }
+    }
+
+    // 5. Usage spikes (tokens > 2x average)
     if !trends.daily_tokens.is_empty() {
         let avg_tokens: u64 =
             trends.daily_tokens.iter().sum::<u64>() / trends.daily_tokens.len() as u64;
@@ -189,3 +396,89 @@ pub fn generate_budget_alerts(
 
     alerts
 }
+
+/// Severity of a budget alert, ordered so a higher level is an escalation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AlertLevel {
+    /// Past the warning threshold, or projected to overrun
+    Warning,
+    /// Past the critical threshold
+    Critical,
+    /// Budget spent
+    Exceeded,
+}
+
+impl Alert {
+    /// Budget this alert is about, `None` for usage spikes
+    pub fn budget_key(&self) -> Option<String> {
+        match self {
+            Alert::BudgetWarning { .. } => Some("monthly".to_string()),
+            Alert::ProjectedOverage { .. } => Some("projected".to_string()),
+            Alert::DailyBudgetExceeded { .. } => Some("daily".to_string()),
+            Alert::BlockBudgetExceeded { .. } => Some("block".to_string()),
+            Alert::ProjectBudgetWarning { project, .. } => Some(format!("project:{}", project)),
+            Alert::UsageSpike { .. } => None,
+        }
+    }
+
+    /// Level of this alert, `critical_pct` being `budget.criticalThreshold`
+    pub fn level(&self, critical_pct: f64) -> AlertLevel {
+        let by_pct = |pct: f64| {
+            if pct >= 100.0 {
+                AlertLevel::Exceeded
+            } else if pct >= critical_pct {
+                AlertLevel::Critical
+            } else {
+                AlertLevel::Warning
+            }
+        };
+        match self {
+            Alert::BudgetWarning { pct, .. } | Alert::ProjectBudgetWarning { pct, .. } => {
+                by_pct(*pct)
+            }
+            Alert::DailyBudgetExceeded { spent, budget, .. }
+            | Alert::BlockBudgetExceeded { spent, budget, .. } => {
+                by_pct(spent / budget * 100.0).max(AlertLevel::Warning)
+            }
+            Alert::ProjectedOverage { .. } | Alert::UsageSpike { .. } => AlertLevel::Warning,
+        }
+    }
+}
+
+/// Remembers the level last reported for each budget, so that repeated
+/// checks report a threshold crossing once
+#[derive(Debug, Default)]
+pub struct BudgetWatch {
+    levels: HashMap<String, AlertLevel>,
+}
+
+impl BudgetWatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Budget alerts that reached a higher level than in the previous call
+    ///
+    /// Budgets without an alert anymore are forgotten, so crossing the
+    /// threshold again (e.g. next month) is reported again.
+    pub fn escalations(&mut self, alerts: &[Alert], critical_pct: f64) -> Vec<Alert> {
+        let mut levels = HashMap::new();
+        let mut escalated = Vec::new();
+        for alert in alerts {
+            let Some(key) = alert.budget_key() else {
+                continue;
+            };
+            let level = alert.level(critical_pct);
+            if self
+                .levels
+                .get(&key)
+                .is_none_or(|previous| level > *previous)
+            {
+                escalated.push(alert.clone());
+            }
+            levels.insert(key, level);
+        }
+        self.levels = levels;
+        escalated
+    }
+}
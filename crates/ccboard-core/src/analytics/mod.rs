@@ -3,20 +3,26 @@
 //! Provides time series trends, forecasting, usage pattern detection,
 //! and actionable insights to optimize costs and productivity.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, TimeZone, Utc};
+use serde::Serialize;
 use std::sync::Arc;
 
 use crate::models::config::AnomalyThresholds;
 use crate::models::session::SessionMetadata;
+use ccboard_types::Currency;
 
 pub mod anomalies;
 pub mod discover;
 pub mod discover_llm;
 pub mod forecasting;
 pub mod insights;
+pub mod model_swap;
 pub mod optimization;
 pub mod patterns;
+pub mod period_comparison;
 pub mod plugin_usage;
+pub mod project_models;
+pub mod standup;
 pub mod tool_chains;
 pub mod trends;
 
@@ -33,22 +39,46 @@ pub use discover::{
 };
 pub use discover_llm::{call_claude_cli as discover_call_llm, LlmSuggestion};
 pub use forecasting::{forecast_usage, ForecastData, TrendDirection};
-pub use insights::{generate_budget_alerts, generate_insights, Alert};
+pub use insights::{
+    cache_efficiency_insights, generate_budget_alerts, generate_insights, Alert, AlertLevel,
+    BudgetWatch, ProjectBudgetStatus, ShortTermBudget,
+};
+pub use model_swap::{primary_models, simulate_model_swap, CostSavings};
 pub use optimization::{
     generate_cost_suggestions, generate_model_recommendations, CostSuggestion, OptimizationCategory,
 };
-pub use patterns::{detect_patterns, UsagePatterns};
+pub use patterns::{
+    cost_heatmap, detect_patterns, detect_patterns_for_period, AuthorUsage, BranchUsage,
+    CompactionStats, ToolCost, UsagePatterns, NO_BRANCH_LABEL, UNKNOWN_AUTHOR_LABEL,
+};
+pub use period_comparison::{
+    compare_periods, DateRange, MetricDelta, PeriodComparison, PeriodMetrics,
+};
 pub use plugin_usage::{aggregate_plugin_usage, PluginAnalytics, PluginType, PluginUsage};
+pub use project_models::{
+    primary_model_by_tokens, project_model_insights, project_model_preferences,
+    ProjectModelPreference,
+};
+pub use standup::{standup_summary, ProjectActivity, StandupSummary, WindowTotals};
 pub use tool_chains::{analyze_tool_chains, ToolChain, ToolChainAnalysis};
-pub use trends::{compute_trends, SessionDurationStats, TrendsData};
+pub use trends::{
+    compute_streaks, compute_trends, compute_trends_for_period, ActivityStreaks,
+    SessionDurationStats, TokenPercentiles, TrendsData,
+};
 
 /// Period selection for analytics computation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Period {
     /// Last N days from now
     Days(usize),
     /// All loaded sessions (honest: not "all time", limited by DataStore)
     Available,
+    /// Explicit range `[start, end)` (e.g. a billing month)
+    Custom {
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
 }
 
 impl Period {
@@ -72,11 +102,92 @@ impl Period {
         Self::Available
     }
 
+    /// Local calendar days of `range` (from its first midnight to the one after its last day)
+    pub fn from_date_range(range: &DateRange) -> Self {
+        let local_midnight = |date: chrono::NaiveDate| {
+            let midnight = date.and_time(chrono::NaiveTime::MIN);
+            Local
+                .from_local_datetime(&midnight)
+                .earliest()
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or_else(|| midnight.and_utc())
+        };
+        let end = range.end.succ_opt().unwrap_or(range.end);
+        Self::Custom {
+            start: local_midnight(range.start),
+            end: local_midnight(end),
+        }
+    }
+
+    /// Parse a period spec: `<N>d` (`7d`, `30d`, `90d`, up to the span of
+    /// `all`), `all`, or `custom` with inclusive `YYYY-MM-DD` start and end dates
+    pub fn parse(spec: &str, start: Option<&str>, end: Option<&str>) -> Result<Self, String> {
+        match spec.trim().to_lowercase().as_str() {
+            "all" => Ok(Self::Available),
+            "custom" => match (start, end) {
+                (Some(start), Some(end)) => DateRange::parse(&format!("{}..{}", start, end))
+                    .map(|r| Self::from_date_range(&r)),
+                _ => Err("Custom period requires start and end dates (YYYY-MM-DD)".to_string()),
+            },
+            other => other
+                .strip_suffix('d')
+                .and_then(|n| n.parse::<usize>().ok())
+                .filter(|n| (1..=Self::Available.days()).contains(n))
+                .map(Self::Days)
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid period '{}' (expected 7d, 30d, 90d, all or custom)",
+                        spec
+                    )
+                }),
+        }
+    }
+
     /// Convert to days (for filtering)
     pub fn days(&self) -> usize {
         match self {
             Period::Days(n) => *n,
             Period::Available => 36500, // 100 years (effectively all)
+            Period::Custom { start, end } => {
+                // Rounded so DST shifts (23h/25h days) don't add or drop a day
+                let secs = (*end - *start).num_seconds().max(0);
+                if secs == 0 {
+                    0
+                } else {
+                    ((secs as f64 / 86_400.0).round() as usize).max(1)
+                }
+            }
+        }
+    }
+
+    /// `[start, end)` instants covered by the period (`Days` ends now)
+    pub fn bounds(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        match self {
+            Period::Custom { start, end } => (*start, *end),
+            _ => {
+                let now = Utc::now();
+                (now - chrono::Duration::days(self.days() as i64), now)
+            }
+        }
+    }
+
+    /// Equal-length window right before this one, `None` for `Available`
+    pub fn preceding(&self) -> Option<Period> {
+        if *self == Period::Available {
+            return None;
+        }
+        let (start, end) = self.bounds();
+        Some(Period::Custom {
+            start: start - (end - start),
+            end: start,
+        })
+    }
+
+    /// Whether a session starting at `ts` falls within the period
+    pub fn contains(&self, ts: &DateTime<Utc>) -> bool {
+        match self {
+            Period::Custom { start, end } => ts >= start && ts < end,
+            _ => *ts >= Utc::now() - chrono::Duration::days(self.days() as i64),
         }
     }
 
@@ -85,12 +196,21 @@ impl Period {
         match self {
             Period::Days(n) => format!("Last {} days", n),
             Period::Available => format!("All loaded ({} sessions)", total_loaded),
+            Period::Custom { start, end } => {
+                // `end` is exclusive: show the last instant it covers
+                let last = (*end - chrono::Duration::seconds(1)).max(*start);
+                format!(
+                    "{} → {}",
+                    start.with_timezone(&Local).format("%Y-%m-%d"),
+                    last.with_timezone(&Local).format("%Y-%m-%d")
+                )
+            }
         }
     }
 }
 
 /// Per-tool token and cost attribution for a period
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ToolTokenStat {
     pub tool_name: String,
     pub call_count: usize,
@@ -104,7 +224,7 @@ pub struct ToolTokenStat {
 }
 
 /// Complete analytics data for a period
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AnalyticsData {
     /// Time series trends
     pub trends: TrendsData,
@@ -138,52 +258,95 @@ impl AnalyticsData {
     /// Compute analytics from sessions (sync function)
     ///
     /// This is a sync function for simplicity. If computation exceeds 16ms
-    /// (render deadline), caller should offload to `tokio::task::spawn_blocking`.
+    /// (render deadline), caller should offload to `tokio::task::spawn_blocking`;
+    /// async callers get that from `DataStore::compute_analytics()` and
+    /// `DataStore::analytics_for()`.
     ///
     /// # Performance
     /// Target: <100ms for 1000 sessions over 30 days
     pub fn compute(sessions: &[Arc<SessionMetadata>], period: Period) -> Self {
-        Self::compute_inner(sessions, period, &AnomalyThresholds::default())
+        Self::compute_inner(
+            sessions,
+            period,
+            &AnomalyThresholds::default(),
+            &Currency::default(),
+        )
     }
 
-    /// Compute analytics using custom anomaly thresholds from settings.json.
+    /// Compute analytics using custom anomaly thresholds from settings.json,
+    /// with costs in insight texts shown in `currency`.
     pub fn compute_with_thresholds(
         sessions: &[Arc<SessionMetadata>],
         period: Period,
         thresholds: &AnomalyThresholds,
+        currency: &Currency,
     ) -> Self {
-        Self::compute_inner(sessions, period, thresholds)
+        Self::compute_inner(sessions, period, thresholds, currency)
     }
 
-    fn compute_inner(
+    /// Compute analytics reusing trends, patterns and forecast restored from
+    /// the metadata cache; the cheaper per-period parts are recomputed.
+    pub fn compute_with_cached(
         sessions: &[Arc<SessionMetadata>],
         period: Period,
         thresholds: &AnomalyThresholds,
+        currency: &Currency,
+        trends: TrendsData,
+        patterns: UsagePatterns,
+        forecast: ForecastData,
     ) -> Self {
-        use chrono::Local;
+        Self::assemble(
+            sessions, period, thresholds, currency, trends, patterns, forecast,
+        )
+    }
 
-        let trends = compute_trends(sessions, period.days());
+    fn compute_inner(
+        sessions: &[Arc<SessionMetadata>],
+        period: Period,
+        thresholds: &AnomalyThresholds,
+        currency: &Currency,
+    ) -> Self {
+        let trends = compute_trends_for_period(sessions, &period);
         let forecast = forecast_usage(&trends);
-        let patterns = detect_patterns(sessions, period.days());
-        let insights = generate_insights(&trends, &patterns, &forecast);
+        let patterns = detect_patterns_for_period(sessions, &period);
+        Self::assemble(
+            sessions, period, thresholds, currency, trends, patterns, forecast,
+        )
+    }
+
+    fn assemble(
+        sessions: &[Arc<SessionMetadata>],
+        period: Period,
+        thresholds: &AnomalyThresholds,
+        currency: &Currency,
+        trends: TrendsData,
+        patterns: UsagePatterns,
+        forecast: ForecastData,
+    ) -> Self {
+        let mut insights = generate_insights(&trends, &patterns, &forecast, currency);
 
-        let cutoff = Local::now() - chrono::Duration::days(period.days() as i64);
         let period_sessions: Vec<Arc<SessionMetadata>> = sessions
             .iter()
-            .filter(|s| {
-                s.first_timestamp
-                    .map(|ts| ts.with_timezone(&Local) >= cutoff)
-                    .unwrap_or(false)
-            })
+            .filter(|s| s.first_timestamp.is_some_and(|ts| period.contains(&ts)))
             .cloned()
             .collect();
 
         let sessions_in_period = period_sessions.len();
+        insights.extend(project_model_insights(&project_model_preferences(
+            &period_sessions,
+        )));
+        insights.extend(cache_efficiency_insights(&period_sessions));
         let anomalies_detected =
             anomalies::detect_anomalies_with_thresholds(&period_sessions, thresholds);
+        // `period_sessions` are already bounded: a custom range only needs a
+        // look-back window reaching its start
+        let spike_window = match period {
+            Period::Custom { .. } => Period::Available.days(),
+            _ => period.days(),
+        };
         let daily_spikes_detected = anomalies::detect_daily_cost_spikes_with_thresholds(
             &period_sessions,
-            period.days(),
+            spike_window,
             thresholds,
         );
 
@@ -249,6 +412,7 @@ impl AnalyticsData {
             &plugin_usage::PluginAnalytics::empty(),
             &aggregated_tool_tokens,
             total_cost_estimate,
+            currency,
         );
 
         // Append model downgrade recommendations
@@ -287,9 +451,9 @@ impl AnalyticsData {
         tracing::warn!("Stats cache missing, computing analytics from sessions only");
 
         Self {
-            trends: compute_trends(sessions, period.days()),
+            trends: compute_trends_for_period(sessions, &period),
             forecast: ForecastData::unavailable("Stats cache required for cost forecasting"),
-            patterns: detect_patterns(sessions, period.days()),
+            patterns: detect_patterns_for_period(sessions, &period),
             insights: vec!["Limited insights: stats cache unavailable".to_string()],
             tool_chains: Some(analyze_tool_chains(sessions)),
             cost_suggestions: Vec::new(),
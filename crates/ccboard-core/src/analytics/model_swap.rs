@@ -0,0 +1,164 @@
+//! Model swap cost simulator
+//!
+//! Answers "what if these sessions had run on another model?" by re-pricing
+//! the same token counts with a different model's rates.
+//!
+//! This is a naive simulation: token counts are kept identical, while in
+//! practice a different model would produce different outputs, tool calls
+//! and cache behavior.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use crate::models::session::SessionMetadata;
+use crate::pricing::{calculate_cost, normalize_model_id};
+
+/// Result of re-pricing sessions with an alternate model
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CostSavings {
+    /// Canonical ID of the model that was replaced
+    pub from_model: String,
+    /// Canonical ID of the model used for the simulation
+    pub to_model: String,
+    /// Number of sessions whose primary model matched `from_model`
+    pub sessions_matched: usize,
+    /// Total tokens of the matched sessions
+    pub tokens_matched: u64,
+    /// Cost of the matched sessions at `from_model` rates
+    pub original_cost: f64,
+    /// Cost of the same token counts at `to_model` rates
+    pub simulated_cost: f64,
+    /// `original_cost - simulated_cost` (negative when the swap is more expensive)
+    pub savings: f64,
+    /// Savings as a percentage of `original_cost` (0 when nothing matched)
+    pub savings_pct: f64,
+}
+
+/// Re-price sessions that ran on `from` as if they had used `to`
+///
+/// A session matches when its primary model (first in `models_used`, the same
+/// convention as the project leaderboard) normalizes to the same canonical ID
+/// as `from`. Aliases such as `"opus"` or dated IDs are accepted for both models.
+pub fn simulate_model_swap(sessions: &[Arc<SessionMetadata>], from: &str, to: &str) -> CostSavings {
+    let from_model = normalize_model_id(from);
+    let to_model = normalize_model_id(to);
+
+    let mut result = CostSavings {
+        from_model: from_model.clone(),
+        to_model: to_model.clone(),
+        ..Default::default()
+    };
+
+    for session in sessions {
+        let Some(primary) = session.models_used.first() else {
+            continue;
+        };
+        if normalize_model_id(primary) != from_model {
+            continue;
+        }
+
+        result.sessions_matched += 1;
+        result.tokens_matched += session.total_tokens;
+        result.original_cost += calculate_cost(
+            primary,
+            session.input_tokens,
+            session.output_tokens,
+            session.cache_creation_tokens,
+            session.cache_read_tokens,
+        );
+        result.simulated_cost += calculate_cost(
+            &to_model,
+            session.input_tokens,
+            session.output_tokens,
+            session.cache_creation_tokens,
+            session.cache_read_tokens,
+        );
+    }
+
+    result.savings = result.original_cost - result.simulated_cost;
+    if result.original_cost > 0.0 {
+        result.savings_pct = result.savings / result.original_cost * 100.0;
+    }
+    result
+}
+
+/// Canonical primary models found in `sessions`, sorted (candidates for `from`)
+pub fn primary_models(sessions: &[Arc<SessionMetadata>]) -> Vec<String> {
+    sessions
+        .iter()
+        .filter_map(|s| s.models_used.first())
+        .map(|m| normalize_model_id(m))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::session::{ProjectId, SessionId};
+    use std::path::PathBuf;
+
+    fn session(id: &str, model: &str, input: u64, output: u64) -> Arc<SessionMetadata> {
+        let mut meta = SessionMetadata::from_path(
+            PathBuf::from(format!("/tmp/{}.jsonl", id)),
+            ProjectId::from("/tmp/project"),
+        );
+        meta.id = SessionId::from(id);
+        meta.models_used = vec![model.to_string()];
+        meta.input_tokens = input;
+        meta.output_tokens = output;
+        meta.total_tokens = input + output;
+        Arc::new(meta)
+    }
+
+    #[test]
+    fn test_simulate_opus_to_sonnet() {
+        let sessions = vec![
+            session("a", "claude-opus-4-5-20251101", 1_000_000, 100_000),
+            session("b", "claude-opus-4-5", 1_000_000, 100_000),
+            session("c", "claude-sonnet-4-5", 5_000_000, 0),
+        ];
+
+        let result = simulate_model_swap(&sessions, "opus", "sonnet");
+
+        assert_eq!(result.from_model, "claude-opus-4-5");
+        assert_eq!(result.to_model, "claude-sonnet-4-5");
+        assert_eq!(result.sessions_matched, 2);
+        assert_eq!(result.tokens_matched, 2_200_000);
+
+        // Opus 4.5: $5 in / $25 out; Sonnet 4.5: $3 in / $15 out (per 1M)
+        assert!((result.original_cost - 2.0 * (5.0 + 2.5)).abs() < 1e-9);
+        assert!((result.simulated_cost - 2.0 * (3.0 + 1.5)).abs() < 1e-9);
+        assert!((result.savings - 6.0).abs() < 1e-9);
+        assert!((result.savings_pct - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_simulate_no_match_and_upgrade() {
+        let sessions = vec![session("a", "claude-haiku-4-5", 1_000_000, 0)];
+
+        let none = simulate_model_swap(&sessions, "opus", "sonnet");
+        assert_eq!(none.sessions_matched, 0);
+        assert_eq!(none.savings_pct, 0.0);
+
+        // Upgrading costs more: negative savings
+        let upgrade = simulate_model_swap(&sessions, "haiku", "opus");
+        assert_eq!(upgrade.sessions_matched, 1);
+        assert!(upgrade.savings < 0.0);
+    }
+
+    #[test]
+    fn test_primary_models_canonical_and_sorted() {
+        let sessions = vec![
+            session("a", "claude-sonnet-4-5-20250929", 1, 1),
+            session("b", "claude-opus-4-5", 1, 1),
+            session("c", "claude-sonnet-4-5", 1, 1),
+        ];
+        assert_eq!(
+            primary_models(&sessions),
+            vec!["claude-opus-4-5", "claude-sonnet-4-5"]
+        );
+    }
+}
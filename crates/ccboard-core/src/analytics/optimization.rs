@@ -3,6 +3,7 @@
 //! Analyzes plugin analytics and tool token usage to generate actionable
 //! suggestions for reducing Claude Code costs.
 
+use ccboard_types::Currency;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -72,6 +73,7 @@ pub fn generate_cost_suggestions(
     plugin_analytics: &PluginAnalytics,
     tool_token_usage: &HashMap<String, u64>,
     total_monthly_cost: f64,
+    currency: &Currency,
 ) -> Vec<CostSuggestion> {
     let mut suggestions: Vec<CostSuggestion> = Vec::new();
 
@@ -109,11 +111,11 @@ pub fn generate_cost_suggestions(
                         title: format!("High-cost tool: {}", tool),
                         description: format!(
                             "'{}' consumes {:.1}% of your total token budget ({} tokens). \
-                             This accounts for an estimated ${:.2}/month.",
+                             This accounts for an estimated {}/month.",
                             tool,
                             pct * 100.0,
                             tokens,
-                            estimated_cost
+                            currency.format_cost(estimated_cost)
                         ),
                         potential_savings: savings,
                         action: format!(
@@ -215,7 +217,8 @@ mod tests {
     fn test_no_suggestions_empty_data() {
         let analytics = empty_analytics();
         let tool_tokens: HashMap<String, u64> = HashMap::new();
-        let suggestions = generate_cost_suggestions(&analytics, &tool_tokens, 0.0);
+        let suggestions =
+            generate_cost_suggestions(&analytics, &tool_tokens, 0.0, &Currency::default());
         assert!(suggestions.is_empty());
     }
 
@@ -225,7 +228,8 @@ mod tests {
         analytics.dead_plugins = vec!["unused-skill".to_string(), "old-command".to_string()];
 
         let tool_tokens: HashMap<String, u64> = HashMap::new();
-        let suggestions = generate_cost_suggestions(&analytics, &tool_tokens, 10.0);
+        let suggestions =
+            generate_cost_suggestions(&analytics, &tool_tokens, 10.0, &Currency::default());
 
         let unused: Vec<_> = suggestions
             .iter()
@@ -242,7 +246,8 @@ mod tests {
         tool_tokens.insert("Bash".to_string(), 500u64);
         tool_tokens.insert("Read".to_string(), 500u64);
 
-        let suggestions = generate_cost_suggestions(&analytics, &tool_tokens, 20.0);
+        let suggestions =
+            generate_cost_suggestions(&analytics, &tool_tokens, 20.0, &Currency::default());
 
         let high_cost: Vec<_> = suggestions
             .iter()
@@ -263,7 +268,8 @@ mod tests {
             tool_tokens.insert(format!("Tool{}", i), 200u64);
         }
 
-        let suggestions = generate_cost_suggestions(&analytics, &tool_tokens, 10.0);
+        let suggestions =
+            generate_cost_suggestions(&analytics, &tool_tokens, 10.0, &Currency::default());
         let high_cost: Vec<_> = suggestions
             .iter()
             .filter(|s| s.category == OptimizationCategory::HighCostTool)
@@ -279,7 +285,8 @@ mod tests {
         tool_tokens.insert("Bash".to_string(), 800u64);
         tool_tokens.insert("Read".to_string(), 200u64);
 
-        let suggestions = generate_cost_suggestions(&analytics, &tool_tokens, 100.0);
+        let suggestions =
+            generate_cost_suggestions(&analytics, &tool_tokens, 100.0, &Currency::default());
 
         if suggestions.len() >= 2 {
             assert!(
@@ -314,13 +321,23 @@ mod tests {
             first_user_message: None,
             has_subagents: false,
             parent_session_id: None,
+            resumed_from: None,
+            compaction_count: 0,
             duration_seconds: Some(60),
             branch: None,
             tool_usage,
             tool_token_usage: HashMap::new(),
+            tool_followup_tokens: HashMap::new(),
             source_tool: Default::default(),
             lines_added: 0,
             lines_removed: 0,
+            tool_result_bytes: 0,
+            peak_context_tokens: 0,
+            peak_context_pct: 0.0,
+            malformed_lines: 0,
+            estimated_extra_tokens: 0,
+            cwd: None,
+            author: None,
         })
     }
 
@@ -1,17 +1,20 @@
 //! Usage pattern detection
 //!
 //! Identifies behavioral patterns: peak hours, productive days,
-//! model distribution, and session duration analytics.
+//! model distribution, per-branch usage, and session duration analytics.
 
 use chrono::{Datelike, NaiveDate, Timelike, Weekday};
+use serde::{Deserialize, Serialize};
 use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 use std::time::Duration;
 
+use super::Period;
 use crate::models::session::SessionMetadata;
+use crate::pricing::normalize_model_id;
 
 /// Usage patterns
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UsagePatterns {
     /// Most productive hour (0-23)
     pub most_productive_hour: u8,
@@ -40,6 +43,87 @@ pub struct UsagePatterns {
     pub current_streak_days: u32,
     /// Longest consecutive-day streak across all loaded sessions
     pub longest_streak_days: u32,
+    /// Usage per git branch, most expensive first
+    #[serde(default)]
+    pub branch_distribution: Vec<BranchUsage>,
+    /// Sessions whose peak context reached `CONTEXT_WARNING_PCT` of the window
+    #[serde(default)]
+    pub context_saturated_sessions: usize,
+    /// Highest peak context utilization among those sessions (0-100)
+    #[serde(default)]
+    pub max_context_pct: f64,
+    /// Usage per git author, most expensive first; empty unless `trackAuthor`
+    /// attributed at least one session in the period
+    #[serde(default)]
+    pub per_author: Vec<AuthorUsage>,
+    /// Tokens and cost driven by each tool, most expensive first
+    #[serde(default)]
+    pub tool_cost: Vec<ToolCost>,
+    /// Compacted sessions compared with the others
+    #[serde(default)]
+    pub compaction: CompactionStats,
+}
+
+/// Cost and length of sessions whose context was compacted, against the rest
+///
+/// Compaction happens when a conversation outgrows the context window, so it
+/// tracks long, expensive sessions (and the ones most likely to lose earlier
+/// context).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompactionStats {
+    /// Sessions compacted at least once
+    pub compacted_sessions: usize,
+    /// Compactions across those sessions
+    pub total_compactions: u32,
+    /// Average estimated cost of a compacted session, in USD
+    pub avg_cost_compacted: f64,
+    /// Average estimated cost of the other sessions, in USD
+    pub avg_cost_other: f64,
+    /// Average duration of a compacted session, in minutes
+    pub avg_minutes_compacted: f64,
+    /// Average duration of the other sessions, in minutes
+    pub avg_minutes_other: f64,
+}
+
+/// Label for sessions without a recorded git branch
+pub const NO_BRANCH_LABEL: &str = "(no branch)";
+
+/// Sessions, tokens and cost attributed to one git branch
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BranchUsage {
+    /// Branch name, or `NO_BRANCH_LABEL`
+    pub branch: String,
+    pub sessions: usize,
+    pub tokens: u64,
+    /// Estimated cost in USD
+    pub cost: f64,
+}
+
+/// Label for sessions without a known git author
+pub const UNKNOWN_AUTHOR_LABEL: &str = "(unknown)";
+
+/// Sessions, tokens and cost attributed to one git author
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthorUsage {
+    /// `git config user.email`, or `UNKNOWN_AUTHOR_LABEL`
+    pub author: String,
+    pub sessions: usize,
+    pub tokens: u64,
+    /// Estimated cost in USD
+    pub cost: f64,
+}
+
+/// Tokens and cost attributed to one tool
+///
+/// Approximate: an assistant turn that answers a tool result is charged to
+/// that tool (split evenly across several results), and priced at its
+/// session's average cost per token.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolCost {
+    pub tool: String,
+    pub tokens: u64,
+    /// Estimated cost in USD
+    pub cost: f64,
 }
 
 impl UsagePatterns {
@@ -59,6 +143,12 @@ impl UsagePatterns {
             tool_usage: HashMap::new(),
             current_streak_days: 0,
             longest_streak_days: 0,
+            branch_distribution: Vec::new(),
+            context_saturated_sessions: 0,
+            max_context_pct: 0.0,
+            per_author: Vec::new(),
+            tool_cost: Vec::new(),
+            compaction: CompactionStats::default(),
         }
     }
 }
@@ -68,6 +158,24 @@ fn estimate_cost(session: &SessionMetadata) -> f64 {
     (session.total_tokens as f64 / 1000.0) * 0.01
 }
 
+/// Estimated cost by start time: `[weekday][hour]` in USD, local time
+///
+/// Each session's whole cost goes to the hour it started in, like the
+/// activity heatmap counts it there. weekday: 0-6 (Mon-Sun), hour: 0-23.
+pub fn cost_heatmap(sessions: &[Arc<SessionMetadata>]) -> [[f64; 24]; 7] {
+    use chrono::Local;
+
+    let mut heatmap = [[0.0; 24]; 7];
+    for session in sessions {
+        if let Some(ts) = session.first_timestamp {
+            let local_ts = ts.with_timezone(&Local);
+            let weekday = local_ts.weekday().num_days_from_monday() as usize;
+            heatmap[weekday][local_ts.hour() as usize] += session.estimated_cost();
+        }
+    }
+    heatmap
+}
+
 /// Compute current and longest consecutive-day streaks across all sessions.
 ///
 /// "Current streak" counts backward from today (or yesterday if no session today).
@@ -153,6 +261,15 @@ fn compute_streaks(sessions: &[Arc<SessionMetadata>]) -> (u32, u32) {
 }
 
 pub fn detect_patterns(sessions: &[Arc<SessionMetadata>], days: usize) -> UsagePatterns {
+    detect_patterns_for_period(sessions, &Period::Days(days))
+}
+
+/// Same as `detect_patterns`, keeping sessions that start within `period`
+/// (both bounds are honored for `Period::Custom`)
+pub fn detect_patterns_for_period(
+    sessions: &[Arc<SessionMetadata>],
+    period: &Period,
+) -> UsagePatterns {
     use chrono::Local;
 
     if sessions.is_empty() {
@@ -167,19 +284,21 @@ pub fn detect_patterns(sessions: &[Arc<SessionMetadata>], days: usize) -> UsageP
     let mut duration_count = 0usize;
     let mut model_tokens: HashMap<String, f64> = HashMap::new();
     let mut model_costs: HashMap<String, f64> = HashMap::new();
-
-    // Filter by period (same logic as compute_trends)
-    let now = Local::now();
-    let cutoff = now - chrono::Duration::days(days as i64);
+    let mut branches: HashMap<&str, BranchUsage> = HashMap::new();
+    let mut authors: HashMap<&str, AuthorUsage> = HashMap::new();
+    let mut tool_costs: HashMap<&str, ToolCost> = HashMap::new();
+    let mut context_saturated_sessions = 0;
+    let mut max_context_pct: f64 = 0.0;
+    // (sessions, cost, minutes) of compacted / other sessions
+    let mut compacted = (0usize, 0.0f64, 0.0f64);
+    let mut uncompacted = (0usize, 0.0f64, 0.0f64);
+    let mut total_compactions = 0u32;
 
     for session in sessions {
-        // Apply period filter check
-        let passes_filter = if let Some(ts) = session.first_timestamp {
-            let local_ts = ts.with_timezone(&Local);
-            local_ts >= cutoff
-        } else {
-            false
-        };
+        // Filter by period (same logic as compute_trends)
+        let passes_filter = session
+            .first_timestamp
+            .is_some_and(|ts| period.contains(&ts));
 
         if !passes_filter {
             continue;
@@ -201,6 +320,67 @@ pub fn detect_patterns(sessions: &[Arc<SessionMetadata>], days: usize) -> UsageP
             *tool_usage.entry(tool_name.clone()).or_default() += count;
         }
 
+        // Tool cost: follow-up turns priced at the session's cost per token
+        if session.total_tokens > 0 && !session.tool_followup_tokens.is_empty() {
+            let cost_per_token = session.estimated_cost() / session.total_tokens as f64;
+            for (tool, &tokens) in &session.tool_followup_tokens {
+                let usage = tool_costs.entry(tool).or_insert_with(|| ToolCost {
+                    tool: tool.clone(),
+                    tokens: 0,
+                    cost: 0.0,
+                });
+                usage.tokens += tokens;
+                usage.cost += tokens as f64 * cost_per_token;
+            }
+        }
+
+        // Branch breakdown
+        let branch = session
+            .branch
+            .as_deref()
+            .filter(|b| !b.is_empty())
+            .unwrap_or(NO_BRANCH_LABEL);
+        let usage = branches.entry(branch).or_insert_with(|| BranchUsage {
+            branch: branch.to_string(),
+            sessions: 0,
+            tokens: 0,
+            cost: 0.0,
+        });
+        usage.sessions += 1;
+        usage.tokens += session.total_tokens;
+        usage.cost += session.estimated_cost();
+
+        // Author breakdown
+        let author = session.author.as_deref().unwrap_or(UNKNOWN_AUTHOR_LABEL);
+        let usage = authors.entry(author).or_insert_with(|| AuthorUsage {
+            author: author.to_string(),
+            sessions: 0,
+            tokens: 0,
+            cost: 0.0,
+        });
+        usage.sessions += 1;
+        usage.tokens += session.total_tokens;
+        usage.cost += session.estimated_cost();
+
+        // Context window pressure
+        if session.context_near_limit() {
+            context_saturated_sessions += 1;
+            max_context_pct = max_context_pct.max(session.peak_context_pct);
+        }
+
+        // Compacted vs other sessions
+        let group = if session.compaction_count > 0 {
+            total_compactions += session.compaction_count;
+            &mut compacted
+        } else {
+            &mut uncompacted
+        };
+        group.0 += 1;
+        group.1 += session.estimated_cost();
+        if let (Some(start), Some(end)) = (session.first_timestamp, session.last_timestamp) {
+            group.2 += (end - start).num_seconds().max(0) as f64 / 60.0;
+        }
+
         // Session duration
         if let (Some(start), Some(end)) = (session.first_timestamp, session.last_timestamp) {
             if let Ok(duration) = (end - start).to_std() {
@@ -222,8 +402,9 @@ pub fn detect_patterns(sessions: &[Arc<SessionMetadata>], days: usize) -> UsageP
             let cost_per_model = cost / models_count;
 
             for model in &session.models_used {
+                let model = normalize_model_id(model);
                 *model_tokens.entry(model.clone()).or_default() += tokens_per_model;
-                *model_costs.entry(model.clone()).or_default() += cost_per_model;
+                *model_costs.entry(model).or_default() += cost_per_model;
             }
         }
     }
@@ -290,8 +471,53 @@ pub fn detect_patterns(sessions: &[Arc<SessionMetadata>], days: usize) -> UsageP
         HashMap::new()
     };
 
+    let mut branch_distribution: Vec<BranchUsage> = branches.into_values().collect();
+    branch_distribution.sort_by(|a, b| {
+        b.cost
+            .total_cmp(&a.cost)
+            .then_with(|| b.tokens.cmp(&a.tokens))
+            .then_with(|| a.branch.cmp(&b.branch))
+    });
+
+    // Without any attributed session the breakdown is a single "(unknown)" row
+    let mut per_author: Vec<AuthorUsage> = if authors.keys().any(|a| *a != UNKNOWN_AUTHOR_LABEL) {
+        authors.into_values().collect()
+    } else {
+        Vec::new()
+    };
+    per_author.sort_by(|a, b| {
+        b.cost
+            .total_cmp(&a.cost)
+            .then_with(|| b.tokens.cmp(&a.tokens))
+            .then_with(|| a.author.cmp(&b.author))
+    });
+
+    let mut tool_cost: Vec<ToolCost> = tool_costs.into_values().collect();
+    tool_cost.sort_by(|a, b| {
+        b.cost
+            .total_cmp(&a.cost)
+            .then_with(|| b.tokens.cmp(&a.tokens))
+            .then_with(|| a.tool.cmp(&b.tool))
+    });
+
     let (current_streak_days, longest_streak_days) = compute_streaks(sessions);
 
+    let average = |total: f64, count: usize| {
+        if count > 0 {
+            total / count as f64
+        } else {
+            0.0
+        }
+    };
+    let compaction = CompactionStats {
+        compacted_sessions: compacted.0,
+        total_compactions,
+        avg_cost_compacted: average(compacted.1, compacted.0),
+        avg_cost_other: average(uncompacted.1, uncompacted.0),
+        avg_minutes_compacted: average(compacted.2, compacted.0),
+        avg_minutes_other: average(uncompacted.2, uncompacted.0),
+    };
+
     UsagePatterns {
         most_productive_hour,
         most_productive_day,
@@ -306,6 +532,12 @@ pub fn detect_patterns(sessions: &[Arc<SessionMetadata>], days: usize) -> UsageP
         tool_usage,
         current_streak_days,
         longest_streak_days,
+        branch_distribution,
+        context_saturated_sessions,
+        max_context_pct,
+        per_author,
+        tool_cost,
+        compaction,
     }
 }
 
@@ -333,16 +565,117 @@ mod tests {
             first_user_message: None,
             has_subagents: false,
             parent_session_id: None,
+            resumed_from: None,
+            compaction_count: 0,
             duration_seconds: Some(10),
             branch: None,
             tool_usage: std::collections::HashMap::new(),
             tool_token_usage: std::collections::HashMap::new(),
+            tool_followup_tokens: std::collections::HashMap::new(),
             source_tool: Default::default(),
             lines_added: 0,
             lines_removed: 0,
+            tool_result_bytes: 0,
+            peak_context_tokens: 0,
+            peak_context_pct: 0.0,
+            malformed_lines: 0,
+            estimated_extra_tokens: 0,
+            cwd: None,
+            author: None,
         })
     }
 
+    #[test]
+    fn test_branch_distribution() {
+        let on_branch = |days_ago: i64, branch: Option<&str>, tokens: u64| {
+            let mut session = (*session_on_days_ago(days_ago)).clone();
+            session.branch = branch.map(str::to_string);
+            session.models_used = vec!["claude-sonnet-4-5".to_string()];
+            session.input_tokens = tokens;
+            session.output_tokens = 0;
+            session.total_tokens = tokens;
+            Arc::new(session)
+        };
+        let sessions = vec![
+            on_branch(0, Some("feat/export"), 1_000_000),
+            on_branch(1, Some("feat/export"), 1_000_000),
+            on_branch(1, Some("main"), 500_000),
+            on_branch(2, None, 100),
+            on_branch(2, Some(""), 100),
+            // Outside the 7-day window
+            on_branch(30, Some("old"), 9_000_000),
+        ];
+
+        let branches = detect_patterns(&sessions, 7).branch_distribution;
+        let names: Vec<&str> = branches.iter().map(|b| b.branch.as_str()).collect();
+        assert_eq!(names, ["feat/export", "main", NO_BRANCH_LABEL]);
+
+        assert_eq!(branches[0].sessions, 2);
+        assert_eq!(branches[0].tokens, 2_000_000);
+        assert!((branches[0].cost - 6.0).abs() < 1e-9);
+        assert_eq!(branches[2].sessions, 2);
+    }
+
+    #[test]
+    fn test_per_author() {
+        let by = |days_ago: i64, author: Option<&str>, tokens: u64| {
+            let mut session = (*session_on_days_ago(days_ago)).clone();
+            session.author = author.map(str::to_string);
+            session.models_used = vec!["claude-sonnet-4-5".to_string()];
+            session.input_tokens = tokens;
+            session.total_tokens = tokens;
+            Arc::new(session)
+        };
+
+        // Nobody attributed (trackAuthor off): no breakdown at all
+        let untracked = vec![by(0, None, 1_000), by(1, None, 2_000)];
+        assert!(detect_patterns(&untracked, 7).per_author.is_empty());
+
+        let sessions = vec![
+            by(0, Some("ana@example.com"), 1_000_000),
+            by(1, Some("bo@example.com"), 2_000_000),
+            by(1, Some("ana@example.com"), 2_000_000),
+            by(2, None, 100),
+        ];
+        let authors = detect_patterns(&sessions, 7).per_author;
+        let names: Vec<&str> = authors.iter().map(|a| a.author.as_str()).collect();
+        assert_eq!(
+            names,
+            ["ana@example.com", "bo@example.com", UNKNOWN_AUTHOR_LABEL]
+        );
+        assert_eq!(authors[0].sessions, 2);
+        assert_eq!(authors[0].tokens, 3_000_000);
+    }
+
+    #[test]
+    fn test_tool_cost() {
+        let with_tools = |tools: &[(&str, u64)]| {
+            let mut session = (*session_on_days_ago(0)).clone();
+            session.models_used = vec!["claude-sonnet-4-5".to_string()];
+            session.input_tokens = 1_000_000;
+            session.output_tokens = 0;
+            session.total_tokens = 1_000_000;
+            session.tool_followup_tokens = tools
+                .iter()
+                .map(|(tool, tokens)| (tool.to_string(), *tokens))
+                .collect();
+            Arc::new(session)
+        };
+        let sessions = vec![
+            with_tools(&[("Read", 500_000), ("Bash", 100_000)]),
+            with_tools(&[("Bash", 800_000)]),
+            with_tools(&[]),
+        ];
+
+        let tool_cost = detect_patterns(&sessions, 7).tool_cost;
+        let names: Vec<&str> = tool_cost.iter().map(|t| t.tool.as_str()).collect();
+        assert_eq!(names, ["Bash", "Read"]);
+        assert_eq!(tool_cost[0].tokens, 900_000);
+        // $3/M input tokens on Sonnet
+        assert!((tool_cost[0].cost - 2.7).abs() < 1e-9);
+        assert!((tool_cost[1].cost - 1.5).abs() < 1e-9);
+    }
+
     #[test]
     fn test_streak_empty_sessions() {
         let (current, longest) = compute_streaks(&[]);
@@ -0,0 +1,284 @@
+//! Period-over-period comparison
+//!
+//! Compares two date ranges (e.g. "2026-01" vs "2026-02") on sessions,
+//! messages, tokens and cost, with per-day series for sparklines. Period `a`
+//! is the baseline: deltas are expressed as `b` relative to `a`.
+
+use chrono::{Datelike, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::models::session::SessionMetadata;
+
+/// Inclusive date range (local dates)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DateRange {
+    /// Label as given by the user ("2026-01", "2026-01-01..2026-01-15")
+    pub label: String,
+    /// First day (inclusive)
+    pub start: NaiveDate,
+    /// Last day (inclusive)
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    /// Parse a month (`YYYY-MM`) or an inclusive range (`YYYY-MM-DD..YYYY-MM-DD`)
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec = spec.trim();
+        let (start, end) = if let Some((from, to)) = spec.split_once("..") {
+            let start = NaiveDate::parse_from_str(from.trim(), "%Y-%m-%d")
+                .map_err(|_| format!("Invalid start date '{}' (expected YYYY-MM-DD)", from))?;
+            let end = NaiveDate::parse_from_str(to.trim(), "%Y-%m-%d")
+                .map_err(|_| format!("Invalid end date '{}' (expected YYYY-MM-DD)", to))?;
+            (start, end)
+        } else {
+            let start =
+                NaiveDate::parse_from_str(&format!("{}-01", spec), "%Y-%m-%d").map_err(|_| {
+                    format!(
+                        "Invalid period '{}' (expected YYYY-MM or YYYY-MM-DD..YYYY-MM-DD)",
+                        spec
+                    )
+                })?;
+            let next_month = if start.month() == 12 {
+                NaiveDate::from_ymd_opt(start.year() + 1, 1, 1)
+            } else {
+                NaiveDate::from_ymd_opt(start.year(), start.month() + 1, 1)
+            };
+            let end = next_month
+                .and_then(|d| d.pred_opt())
+                .ok_or_else(|| format!("Invalid period '{}'", spec))?;
+            (start, end)
+        };
+
+        if end < start {
+            return Err(format!("Period '{}' ends before it starts", spec));
+        }
+        Ok(Self {
+            label: spec.to_string(),
+            start,
+            end,
+        })
+    }
+
+    /// Number of days in the period
+    pub fn num_days(&self) -> usize {
+        (self.end - self.start).num_days() as usize + 1
+    }
+
+    /// Whether `date` falls within the period
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.start && date <= self.end
+    }
+}
+
+/// Aggregated metrics of one period
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PeriodMetrics {
+    pub sessions: usize,
+    pub messages: u64,
+    pub tokens: u64,
+    /// Estimated cost in USD (primary model pricing)
+    pub cost: f64,
+    /// Days with at least one session
+    pub active_days: usize,
+    /// Per-day series, one entry per day of the period
+    pub daily_sessions: Vec<u64>,
+    pub daily_tokens: Vec<u64>,
+    pub daily_cost: Vec<f64>,
+}
+
+/// Change of one metric between the two periods
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricDelta {
+    pub metric: String,
+    pub a: f64,
+    pub b: f64,
+    /// `b - a`
+    pub delta: f64,
+    /// `(b - a) / a` in percent, `None` when `a` is zero (no baseline data)
+    pub delta_pct: Option<f64>,
+}
+
+/// Side-by-side metrics of two periods
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PeriodComparison {
+    pub a: DateRange,
+    pub b: DateRange,
+    pub metrics_a: PeriodMetrics,
+    pub metrics_b: PeriodMetrics,
+}
+
+impl PeriodComparison {
+    /// Per-metric deltas (sessions, messages, tokens, cost, active days)
+    pub fn deltas(&self) -> Vec<MetricDelta> {
+        let (a, b) = (&self.metrics_a, &self.metrics_b);
+        [
+            ("Sessions", a.sessions as f64, b.sessions as f64),
+            ("Messages", a.messages as f64, b.messages as f64),
+            ("Tokens", a.tokens as f64, b.tokens as f64),
+            ("Cost", a.cost, b.cost),
+            ("Active days", a.active_days as f64, b.active_days as f64),
+        ]
+        .into_iter()
+        .map(|(metric, a, b)| MetricDelta {
+            metric: metric.to_string(),
+            a,
+            b,
+            delta: b - a,
+            delta_pct: (a > 0.0).then(|| (b - a) / a * 100.0),
+        })
+        .collect()
+    }
+}
+
+/// Compare sessions started in period `a` against those started in period `b`
+///
+/// Sessions are bucketed by the local date of their first timestamp; sessions
+/// without a timestamp are ignored.
+pub fn compare_periods(
+    sessions: &[Arc<SessionMetadata>],
+    a: &DateRange,
+    b: &DateRange,
+) -> PeriodComparison {
+    PeriodComparison {
+        a: a.clone(),
+        b: b.clone(),
+        metrics_a: period_metrics(sessions, a),
+        metrics_b: period_metrics(sessions, b),
+    }
+}
+
+fn period_metrics(sessions: &[Arc<SessionMetadata>], period: &DateRange) -> PeriodMetrics {
+    let days = period.num_days();
+    let mut metrics = PeriodMetrics {
+        daily_sessions: vec![0; days],
+        daily_tokens: vec![0; days],
+        daily_cost: vec![0.0; days],
+        ..Default::default()
+    };
+
+    for session in sessions {
+        let Some(ts) = session.first_timestamp else {
+            continue;
+        };
+        let date = ts.with_timezone(&Local).date_naive();
+        if !period.contains(date) {
+            continue;
+        }
+        let day = (date - period.start).num_days() as usize;
+        let cost = session.estimated_cost();
+
+        metrics.sessions += 1;
+        metrics.messages += session.message_count;
+        metrics.tokens += session.total_tokens;
+        metrics.cost += cost;
+        metrics.daily_sessions[day] += 1;
+        metrics.daily_tokens[day] += session.total_tokens;
+        metrics.daily_cost[day] += cost;
+    }
+
+    metrics.active_days = metrics.daily_sessions.iter().filter(|&&n| n > 0).count();
+    metrics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::session::{ProjectId, SessionId};
+    use chrono::{TimeZone, Utc};
+    use std::path::PathBuf;
+
+    fn session(id: &str, date: NaiveDate, tokens: u64) -> Arc<SessionMetadata> {
+        let mut meta = SessionMetadata::from_path(
+            PathBuf::from(format!("/tmp/{}.jsonl", id)),
+            ProjectId::from("/tmp/project"),
+        );
+        meta.id = SessionId::from(id);
+        let local = Local
+            .from_local_datetime(&date.and_hms_opt(12, 0, 0).unwrap())
+            .unwrap();
+        meta.first_timestamp = Some(local.with_timezone(&Utc));
+        meta.models_used = vec!["claude-sonnet-4-5".to_string()];
+        meta.input_tokens = tokens;
+        meta.total_tokens = tokens;
+        meta.message_count = 10;
+        Arc::new(meta)
+    }
+
+    fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_parse_period() {
+        let feb = DateRange::parse("2024-02").unwrap();
+        assert_eq!(feb.start, day(2024, 2, 1));
+        assert_eq!(feb.end, day(2024, 2, 29));
+        assert_eq!(feb.num_days(), 29);
+
+        let dec = DateRange::parse("2025-12").unwrap();
+        assert_eq!(dec.end, day(2025, 12, 31));
+
+        let range = DateRange::parse("2026-01-10..2026-01-12").unwrap();
+        assert_eq!(range.num_days(), 3);
+
+        assert!(DateRange::parse("2026-13").is_err());
+        assert!(DateRange::parse("2026-01-12..2026-01-10").is_err());
+    }
+
+    #[test]
+    fn test_compare_periods() {
+        let sessions = vec![
+            session("a", day(2026, 1, 5), 1_000_000),
+            session("b", day(2026, 2, 3), 1_000_000),
+            session("c", day(2026, 2, 3), 1_000_000),
+            session("d", day(2026, 3, 1), 1_000_000),
+        ];
+        let jan = DateRange::parse("2026-01").unwrap();
+        let feb = DateRange::parse("2026-02").unwrap();
+        let cmp = compare_periods(&sessions, &jan, &feb);
+
+        assert_eq!(cmp.metrics_a.sessions, 1);
+        assert_eq!(cmp.metrics_b.sessions, 2);
+        assert_eq!(cmp.metrics_b.active_days, 1);
+        assert_eq!(cmp.metrics_b.daily_tokens.len(), 28);
+        assert_eq!(cmp.metrics_b.daily_tokens[2], 2_000_000);
+        // Sonnet 4.5: $3 per 1M input tokens
+        assert!((cmp.metrics_b.cost - 6.0).abs() < 1e-9);
+
+        let deltas = cmp.deltas();
+        let sessions_delta = &deltas[0];
+        assert_eq!(sessions_delta.delta, 1.0);
+        assert_eq!(sessions_delta.delta_pct, Some(100.0));
+    }
+
+    #[test]
+    fn test_compare_splits_multi_model_cost() {
+        let mut mixed = (*session("m", day(2026, 2, 3), 1_000_000)).clone();
+        mixed.models_used.push("claude-opus-4-5".to_string());
+        mixed.model_segments = vec![
+            ("claude-sonnet-4-5".to_string(), 1),
+            ("claude-opus-4-5".to_string(), 1),
+        ];
+        let expected = mixed.estimated_cost();
+        let feb = DateRange::parse("2026-02").unwrap();
+        let cmp = compare_periods(&[Arc::new(mixed)], &feb, &feb);
+
+        assert!((cmp.metrics_a.cost - expected).abs() < 1e-9);
+        assert!((cmp.metrics_a.daily_cost[2] - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compare_without_baseline_has_no_nan() {
+        let sessions = vec![session("b", day(2026, 2, 3), 1_000)];
+        let cmp = compare_periods(
+            &sessions,
+            &DateRange::parse("2025-02").unwrap(),
+            &DateRange::parse("2026-02").unwrap(),
+        );
+        for delta in cmp.deltas() {
+            assert!(delta.delta.is_finite());
+            assert_eq!(delta.delta_pct, None);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Per-project model preference detection
+//!
+//! Finds the model each project predominantly uses, weighted by tokens rather
+//! than session count, so one long Opus session outweighs a handful of short
+//! Haiku ones.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::models::session::SessionMetadata;
+use crate::pricing::normalize_model_id;
+
+/// Dominant model of a project
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectModelPreference {
+    /// Project path (as stored in `SessionMetadata::project_path`)
+    pub project_path: String,
+    /// Canonical ID of the token-dominant model
+    pub primary_model: String,
+    /// Share of the project's tokens attributed to `primary_model` (0.0-1.0)
+    pub share: f64,
+    /// Total tokens of the project's sessions with a known model
+    pub total_tokens: u64,
+}
+
+/// Token-dominant model among `sessions`, with its share of tokens
+///
+/// A session's tokens are split evenly across its `models_used`. Sessions
+/// without a model are ignored. Ties go to the alphabetically first model.
+pub fn primary_model_by_tokens<'a>(
+    sessions: impl IntoIterator<Item = &'a SessionMetadata>,
+) -> Option<(String, f64)> {
+    let (tokens_by_model, total) = tokens_by_model(sessions);
+    if total == 0.0 {
+        return None;
+    }
+    tokens_by_model
+        .into_iter()
+        .max_by(|(a_model, a), (b_model, b)| a.total_cmp(b).then_with(|| b_model.cmp(a_model)))
+        .map(|(model, tokens)| (model, tokens / total))
+}
+
+/// Model preference of every project, most tokens first
+pub fn project_model_preferences(sessions: &[Arc<SessionMetadata>]) -> Vec<ProjectModelPreference> {
+    let mut by_project: HashMap<&str, Vec<&SessionMetadata>> = HashMap::new();
+    for session in sessions {
+        by_project
+            .entry(session.project_path.as_str())
+            .or_default()
+            .push(session);
+    }
+
+    let mut prefs: Vec<ProjectModelPreference> = by_project
+        .into_iter()
+        .filter_map(|(project, sessions)| {
+            let total_tokens = sessions
+                .iter()
+                .filter(|s| !s.models_used.is_empty())
+                .map(|s| s.total_tokens)
+                .sum();
+            let (primary_model, share) = primary_model_by_tokens(sessions)?;
+            Some(ProjectModelPreference {
+                project_path: project.to_string(),
+                primary_model,
+                share,
+                total_tokens,
+            })
+        })
+        .collect();
+
+    prefs.sort_by(|a, b| {
+        b.total_tokens
+            .cmp(&a.total_tokens)
+            .then_with(|| a.project_path.cmp(&b.project_path))
+    });
+    prefs
+}
+
+/// Insights for projects that lean heavily on Opus
+///
+/// Only the 3 largest projects (by tokens) with ≥80% Opus share are reported.
+pub fn project_model_insights(prefs: &[ProjectModelPreference]) -> Vec<String> {
+    prefs
+        .iter()
+        .filter(|p| p.primary_model.starts_with("claude-opus") && p.share >= 0.8)
+        .take(3)
+        .map(|p| {
+            let name = std::path::Path::new(&p.project_path)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or(&p.project_path);
+            format!(
+                "Project {} is {:.0}% Opus—consider Sonnet for routine work.",
+                name,
+                p.share * 100.0
+            )
+        })
+        .collect()
+}
+
+fn tokens_by_model<'a>(
+    sessions: impl IntoIterator<Item = &'a SessionMetadata>,
+) -> (HashMap<String, f64>, f64) {
+    let mut by_model: HashMap<String, f64> = HashMap::new();
+    let mut total = 0.0;
+    for session in sessions {
+        if session.models_used.is_empty() {
+            continue;
+        }
+        let per_model = session.total_tokens as f64 / session.models_used.len() as f64;
+        for model in &session.models_used {
+            *by_model.entry(normalize_model_id(model)).or_default() += per_model;
+        }
+        total += session.total_tokens as f64;
+    }
+    (by_model, total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::session::{ProjectId, SessionId};
+    use std::path::PathBuf;
+
+    fn session(id: &str, project: &str, model: &str, tokens: u64) -> Arc<SessionMetadata> {
+        let mut meta = SessionMetadata::from_path(
+            PathBuf::from(format!("/tmp/{}.jsonl", id)),
+            ProjectId::from(project),
+        );
+        meta.id = SessionId::from(id);
+        meta.models_used = vec![model.to_string()];
+        meta.total_tokens = tokens;
+        Arc::new(meta)
+    }
+
+    #[test]
+    fn test_primary_model_is_token_weighted() {
+        // Haiku is used in more sessions, but Opus carries most tokens
+        let sessions = vec![
+            session("a", "/code/api", "claude-haiku-4-5", 1_000),
+            session("b", "/code/api", "claude-haiku-4-5", 1_000),
+            session("c", "/code/api", "claude-haiku-4-5", 1_000),
+            session("d", "/code/api", "claude-opus-4-5", 27_000),
+        ];
+
+        let prefs = project_model_preferences(&sessions);
+        assert_eq!(prefs.len(), 1);
+        assert_eq!(prefs[0].primary_model, "claude-opus-4-5");
+        assert!((prefs[0].share - 0.9).abs() < 1e-9);
+        assert_eq!(prefs[0].total_tokens, 30_000);
+
+        assert_eq!(
+            project_model_insights(&prefs),
+            vec!["Project api is 90% Opus—consider Sonnet for routine work."]
+        );
+    }
+
+    #[test]
+    fn test_preferences_per_project_sorted_by_tokens() {
+        let sessions = vec![
+            session("a", "/code/small", "claude-sonnet-4-5", 500),
+            session("b", "/code/big", "claude-sonnet-4-5", 9_000),
+            session("c", "/code/big", "claude-opus-4-5", 1_000),
+        ];
+
+        let prefs = project_model_preferences(&sessions);
+        assert_eq!(prefs[0].project_path, "/code/big");
+        assert_eq!(prefs[0].primary_model, "claude-sonnet-4-5");
+        assert_eq!(prefs[1].project_path, "/code/small");
+        // Sonnet-dominant projects produce no Opus insight
+        assert!(project_model_insights(&prefs).is_empty());
+    }
+}
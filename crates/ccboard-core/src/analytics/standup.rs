@@ -0,0 +1,319 @@
+//! Standup summary: a short Markdown recap of a period
+//!
+//! Built on `compute_trends_for_period` / `detect_patterns_for_period` for the
+//! requested window and the equal-length window right before it, so the recap
+//! can say how activity moved ("↑ 12% vs prior week").
+
+use ccboard_types::Currency;
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::{compute_trends_for_period, detect_patterns_for_period, Period};
+use crate::models::session::SessionMetadata;
+
+/// Changes smaller than this (in percent) read as flat
+const FLAT_THRESHOLD_PCT: f64 = 1.0;
+
+/// Activity of one project within the period
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProjectActivity {
+    pub project: String,
+    pub sessions: usize,
+    pub tokens: u64,
+}
+
+/// Headline numbers of one window
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct WindowTotals {
+    pub sessions: usize,
+    pub tokens: u64,
+    /// Estimated cost in USD
+    pub cost: f64,
+}
+
+/// Recap of a period against the equal-length window before it
+#[derive(Debug, Clone, Serialize)]
+pub struct StandupSummary {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Length of the window in days
+    pub days: usize,
+    pub current: WindowTotals,
+    pub previous: WindowTotals,
+    /// Up to 5 projects with the most sessions
+    pub top_projects: Vec<ProjectActivity>,
+    /// Local date with the most sessions, and that count
+    pub busiest_day: Option<(NaiveDate, usize)>,
+    /// Up to 5 tools by call count
+    pub top_tools: Vec<(String, usize)>,
+}
+
+impl StandupSummary {
+    /// Cost change vs the previous window in percent, `None` without a baseline
+    pub fn cost_change_pct(&self) -> Option<f64> {
+        (self.previous.cost > 0.0)
+            .then(|| (self.current.cost - self.previous.cost) / self.previous.cost * 100.0)
+    }
+
+    /// "prior week" for 7-day windows, "prior N days" otherwise
+    fn previous_label(&self) -> String {
+        if self.days == 7 {
+            "prior week".to_string()
+        } else {
+            format!("prior {} days", self.days)
+        }
+    }
+
+    /// One-line trend, e.g. "↑ 12% vs prior week ($5.60 vs $5.00)"
+    pub fn trend_line(&self, currency: &Currency) -> String {
+        let label = self.previous_label();
+        match self.cost_change_pct() {
+            None => format!("No activity in the {} to compare against", label),
+            Some(pct) => {
+                let arrow = if pct.abs() < FLAT_THRESHOLD_PCT {
+                    "→"
+                } else if pct > 0.0 {
+                    "↑"
+                } else {
+                    "↓"
+                };
+                format!(
+                    "{} {:.0}% vs {} ({} vs {})",
+                    arrow,
+                    pct.abs(),
+                    label,
+                    currency.format_cost(self.current.cost),
+                    currency.format_cost(self.previous.cost)
+                )
+            }
+        }
+    }
+
+    /// Markdown recap for status updates
+    pub fn to_markdown(&self, currency: &Currency) -> String {
+        let title = if self.days == 7 {
+            "Weekly Summary"
+        } else {
+            "Summary"
+        };
+        let last_day = (self.end - chrono::Duration::seconds(1)).max(self.start);
+        let mut md = format!(
+            "# {}: {} → {}\n\n{}\n\n",
+            title,
+            self.start.with_timezone(&Local).format("%Y-%m-%d"),
+            last_day.with_timezone(&Local).format("%Y-%m-%d"),
+            self.trend_line(currency)
+        );
+
+        md.push_str(&format!("- **Sessions:** {}\n", self.current.sessions));
+        md.push_str(&format!(
+            "- **Est. cost:** {}\n",
+            currency.format_cost(self.current.cost)
+        ));
+        md.push_str(&format!(
+            "- **Tokens:** {}\n",
+            format_tokens(self.current.tokens)
+        ));
+        if let Some((day, sessions)) = self.busiest_day {
+            md.push_str(&format!(
+                "- **Busiest day:** {} ({})\n",
+                day.format("%a %Y-%m-%d"),
+                plural(sessions, "session")
+            ));
+        }
+
+        if !self.top_projects.is_empty() {
+            md.push_str("\n## Top Projects\n\n");
+            for (i, p) in self.top_projects.iter().enumerate() {
+                md.push_str(&format!(
+                    "{}. `{}`: {}, {} tokens\n",
+                    i + 1,
+                    p.project,
+                    plural(p.sessions, "session"),
+                    format_tokens(p.tokens)
+                ));
+            }
+        }
+
+        if !self.top_tools.is_empty() {
+            md.push_str("\n## Most-Used Tools\n\n");
+            for (i, (tool, calls)) in self.top_tools.iter().enumerate() {
+                md.push_str(&format!(
+                    "{}. {}: {}\n",
+                    i + 1,
+                    tool,
+                    plural(*calls, "call")
+                ));
+            }
+        }
+
+        md
+    }
+}
+
+/// Recap of sessions started within `period` (`Available` compares nothing)
+pub fn standup_summary(sessions: &[Arc<SessionMetadata>], period: Period) -> StandupSummary {
+    let (start, end) = period.bounds();
+    let trends = compute_trends_for_period(sessions, &period);
+    let patterns = detect_patterns_for_period(sessions, &period);
+
+    let busiest_day = trends
+        .dates
+        .iter()
+        .zip(&trends.daily_sessions)
+        // Earliest day wins ties
+        .rev()
+        .max_by_key(|(_, &count)| count)
+        .and_then(|(date, &count)| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .ok()
+                .map(|d| (d, count))
+        });
+
+    let mut top_tools: Vec<(String, usize)> = patterns.tool_usage.into_iter().collect();
+    top_tools.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    top_tools.truncate(5);
+
+    let mut projects: HashMap<&str, ProjectActivity> = HashMap::new();
+    for session in in_period(sessions, &period) {
+        let entry = projects
+            .entry(session.project_path.as_str())
+            .or_insert_with(|| ProjectActivity {
+                project: session.project_path.to_string(),
+                sessions: 0,
+                tokens: 0,
+            });
+        entry.sessions += 1;
+        entry.tokens += session.total_tokens;
+    }
+    let mut top_projects: Vec<ProjectActivity> = projects.into_values().collect();
+    top_projects.sort_by(|a, b| {
+        b.sessions
+            .cmp(&a.sessions)
+            .then_with(|| b.tokens.cmp(&a.tokens))
+            .then_with(|| a.project.cmp(&b.project))
+    });
+    top_projects.truncate(5);
+
+    let current = WindowTotals {
+        sessions: trends.daily_sessions.iter().sum(),
+        tokens: trends.daily_tokens.iter().sum(),
+        cost: in_period(sessions, &period)
+            .map(|s| s.estimated_cost())
+            .sum(),
+    };
+    let previous = period
+        .preceding()
+        .map(|prev| {
+            let mut totals = WindowTotals::default();
+            for session in in_period(sessions, &prev) {
+                totals.sessions += 1;
+                totals.tokens += session.total_tokens;
+                totals.cost += session.estimated_cost();
+            }
+            totals
+        })
+        .unwrap_or_default();
+
+    StandupSummary {
+        start,
+        end,
+        days: period.days(),
+        current,
+        previous,
+        top_projects,
+        busiest_day,
+        top_tools,
+    }
+}
+
+fn in_period<'a>(
+    sessions: &'a [Arc<SessionMetadata>],
+    period: &'a Period,
+) -> impl Iterator<Item = &'a Arc<SessionMetadata>> {
+    sessions
+        .iter()
+        .filter(|s| s.first_timestamp.is_some_and(|ts| period.contains(&ts)))
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    format!("{} {}{}", count, noun, if count == 1 { "" } else { "s" })
+}
+
+fn format_tokens(tokens: u64) -> String {
+    if tokens >= 1_000_000 {
+        format!("{:.1}M", tokens as f64 / 1_000_000.0)
+    } else if tokens >= 1_000 {
+        format!("{:.1}K", tokens as f64 / 1_000.0)
+    } else {
+        tokens.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn session(days_ago: i64, project: &str, tokens: u64, tool: &str) -> Arc<SessionMetadata> {
+        let mut s = SessionMetadata::from_path(
+            PathBuf::from(format!("/tmp/{}-{}.jsonl", project, days_ago)),
+            project.into(),
+        );
+        s.first_timestamp = Some(Utc::now() - chrono::Duration::days(days_ago));
+        s.models_used = vec!["claude-sonnet-4-5".to_string()];
+        s.input_tokens = tokens;
+        s.total_tokens = tokens;
+        s.tool_usage.insert(tool.to_string(), 3);
+        Arc::new(s)
+    }
+
+    #[test]
+    fn test_standup_summary_against_prior_week() {
+        let sessions = vec![
+            session(1, "/work/api", 1_000_000, "Read"),
+            session(1, "/work/api", 1_000_000, "Edit"),
+            session(2, "/work/web", 200_000, "Read"),
+            // Prior week: 1M tokens
+            session(9, "/work/api", 1_000_000, "Bash"),
+            // Outside both windows
+            session(30, "/work/old", 5_000_000, "Bash"),
+        ];
+
+        let summary = standup_summary(&sessions, Period::Days(7));
+        assert_eq!(summary.current.sessions, 3);
+        assert_eq!(summary.current.tokens, 2_200_000);
+        assert_eq!(summary.previous.sessions, 1);
+        assert!((summary.cost_change_pct().unwrap() - 120.0).abs() < 1e-6);
+        assert!(summary
+            .trend_line(&Currency::default())
+            .starts_with("↑ 120% vs prior week"));
+
+        assert_eq!(summary.top_projects[0].project, "/work/api");
+        assert_eq!(summary.top_projects[0].sessions, 2);
+        assert_eq!(summary.top_projects.len(), 2);
+        assert_eq!(summary.busiest_day.map(|(_, n)| n), Some(2));
+        assert_eq!(summary.top_tools[0], ("Read".to_string(), 6));
+
+        let md = summary.to_markdown(&Currency::default());
+        assert!(md.starts_with("# Weekly Summary: "));
+        assert!(md.contains("- **Sessions:** 3\n"));
+        assert!(md.contains("1. `/work/api`: 2 sessions, 2.0M tokens"));
+        assert!(md.contains("## Most-Used Tools"));
+    }
+
+    #[test]
+    fn test_trend_line_without_baseline() {
+        let sessions = vec![session(1, "/work/api", 1_000, "Read")];
+        let summary = standup_summary(&sessions, Period::Days(3));
+        assert_eq!(
+            summary.trend_line(&Currency::default()),
+            "No activity in the prior 3 days to compare against"
+        );
+        assert!(summary
+            .to_markdown(&Currency::default())
+            .starts_with("# Summary: "));
+    }
+}
@@ -32,13 +32,23 @@ fn generate_test_sessions(count: usize, days: usize) -> Vec<Arc<SessionMetadata>
                 first_user_message: None,
                 has_subagents: false,
                 parent_session_id: None,
+                resumed_from: None,
+                compaction_count: 0,
                 duration_seconds: Some(1800),
                 branch: None,
                 tool_usage: std::collections::HashMap::new(),
                 tool_token_usage: std::collections::HashMap::new(),
+                tool_followup_tokens: std::collections::HashMap::new(),
                 source_tool: Default::default(),
                 lines_added: 0,
                 lines_removed: 0,
+                tool_result_bytes: 0,
+                peak_context_tokens: 0,
+                peak_context_pct: 0.0,
+                malformed_lines: 0,
+                estimated_extra_tokens: 0,
+                cwd: None,
+                author: None,
             })
         })
         .collect()
@@ -56,6 +66,51 @@ fn test_trends_empty_sessions() {
     assert_eq!(trends.daily_tokens.len(), 0);
 }
 
+#[test]
+fn test_trends_custom_period_respects_both_bounds() {
+    // One session per day, from today back to 9 days ago
+    let sessions = generate_test_sessions(10, 10);
+    let now = Utc::now();
+    let period = Period::Custom {
+        start: now - chrono::Duration::hours(5 * 24 + 12),
+        end: now - chrono::Duration::hours(2 * 24 + 12),
+    };
+    assert_eq!(period.days(), 3);
+
+    let trends = compute_trends_for_period(&sessions, &period);
+    assert_eq!(trends.daily_sessions.iter().sum::<usize>(), 3);
+
+    let patterns = detect_patterns_for_period(&sessions, &period);
+    assert_eq!(patterns.hourly_distribution.iter().sum::<usize>(), 3);
+
+    let analytics = AnalyticsData::compute(&sessions, period);
+    assert_eq!(analytics.sessions_in_period, 3);
+}
+
+#[test]
+fn test_empty_custom_period_yields_empty_trends() {
+    let sessions = generate_test_sessions(10, 10);
+    let now = Utc::now();
+    let period = Period::Custom {
+        start: now - chrono::Duration::days(3),
+        end: now - chrono::Duration::days(3),
+    };
+    assert_eq!(period.days(), 0);
+
+    assert!(compute_trends_for_period(&sessions, &period).is_empty());
+    let analytics = AnalyticsData::compute(&sessions, period);
+    assert_eq!(analytics.sessions_in_period, 0);
+    assert!(!period.display(0).is_empty());
+}
+
+#[test]
+fn test_period_from_month() {
+    let march = DateRange::parse("2026-03").unwrap();
+    let period = Period::from_date_range(&march);
+    assert_eq!(period.days(), 31);
+    assert_eq!(period.display(0), "2026-03-01 → 2026-03-31");
+}
+
 #[test]
 fn test_trends_single_day() {
     let sessions = generate_test_sessions(10, 1);
@@ -76,6 +131,35 @@ fn test_trends_multi_day_aggregation() {
     assert_eq!(total_sessions, 30, "Should have 30 total sessions");
 }
 
+#[test]
+fn test_trends_weekly_and_monthly_rollup() {
+    use chrono::{Local, NaiveDate, TimeZone};
+
+    // Fri 02-27 and Sun 03-01 share ISO week 9; Mon 03-02 starts week 10
+    let days = ["2026-02-27", "2026-03-01", "2026-03-02", "2026-03-04"];
+    let mut sessions = generate_test_sessions(days.len(), 1);
+    for (session, day) in sessions.iter_mut().zip(days) {
+        let date = NaiveDate::parse_from_str(day, "%Y-%m-%d").unwrap();
+        let ts = Local
+            .from_local_datetime(&date.and_hms_opt(12, 0, 0).unwrap())
+            .unwrap()
+            .with_timezone(&Utc);
+        let session = Arc::get_mut(session).unwrap();
+        session.first_timestamp = Some(ts);
+        session.total_tokens = 100;
+    }
+
+    let period = Period::from_date_range(&DateRange::parse("2026-02-01..2026-03-31").unwrap());
+    let trends = compute_trends_for_period(&sessions, &period);
+
+    assert_eq!(trends.weeks, vec!["2026-W09", "2026-W10"]);
+    assert_eq!(trends.weekly_sessions, vec![2, 2]);
+    assert_eq!(trends.weekly_tokens, vec![200, 200]);
+    assert_eq!(trends.months, vec!["2026-02", "2026-03"]);
+    assert_eq!(trends.monthly_sessions, vec![1, 3]);
+    assert_eq!(trends.monthly_tokens, vec![100, 300]);
+}
+
 #[test]
 fn test_trends_hourly_distribution() {
     let sessions = generate_test_sessions(24, 1);
@@ -94,11 +178,302 @@ fn test_trends_model_usage() {
 
     let trends = compute_trends(&sessions, 30);
     assert!(
-        trends.model_usage_over_time.contains_key("opus"),
+        trends.model_usage_over_time.contains_key("claude-opus-4-5"),
         "Should track opus usage"
     );
 }
 
+#[test]
+fn test_trends_cache_hit_ratio() {
+    let mut sessions = generate_test_sessions(1, 1);
+    {
+        let session = Arc::get_mut(&mut sessions[0]).unwrap();
+        session.input_tokens = 200;
+        session.cache_read_tokens = 750;
+        session.cache_creation_tokens = 50;
+    }
+
+    let trends = compute_trends(&sessions, 7);
+    assert_eq!(trends.daily_cache_hit_ratio.len(), trends.dates.len());
+    assert!((trends.daily_cache_hit_ratio[0] - 0.75).abs() < 0.001);
+}
+
+#[test]
+fn test_insights_cache_hit_ratio_drop() {
+    let mut sessions = generate_test_sessions(14, 14);
+    // Session i is i days ago: the last 7 days lose most of their cache reads
+    for (i, session) in sessions.iter_mut().enumerate() {
+        let session = Arc::get_mut(session).unwrap();
+        session.input_tokens = 100;
+        session.cache_creation_tokens = 0;
+        session.cache_read_tokens = if i < 7 { 100 } else { 900 };
+    }
+
+    let trends = compute_trends(&sessions, 30);
+    let (previous, trailing) = trends.cache_hit_ratio_change(7).unwrap();
+    assert!((previous - 0.9).abs() < 0.001);
+    assert!((trailing - 0.5).abs() < 0.001);
+
+    // Windows are calendar days: idle days don't pull older days in
+    let sparse: Vec<_> = sessions
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i >= 7 || i % 2 == 0)
+        .map(|(_, s)| s.clone())
+        .collect();
+    let (previous, trailing) = compute_trends(&sparse, 30)
+        .cache_hit_ratio_change(7)
+        .unwrap();
+    assert!((previous - 0.9).abs() < 0.001);
+    assert!((trailing - 0.5 * 4.0 / 7.0).abs() < 0.001);
+
+    let patterns = detect_patterns(&sessions, 30);
+    let forecast = forecast_usage(&trends);
+    let insights = generate_insights(&trends, &patterns, &forecast, &Currency::default());
+    assert!(
+        insights
+            .iter()
+            .any(|i| i.starts_with("Cache hit ratio dropped")),
+        "Expected cache regression insight, got {:?}",
+        insights
+    );
+}
+
+#[test]
+fn test_patterns_compare_compacted_sessions() {
+    let mut sessions = generate_test_sessions(4, 4);
+    for (i, session) in sessions.iter_mut().enumerate() {
+        let session = Arc::get_mut(session).unwrap();
+        session.compaction_count = [2, 1, 0, 0][i];
+        let start = session.first_timestamp.unwrap();
+        session.last_timestamp = Some(start + chrono::Duration::minutes([90, 30, 10, 20][i]));
+    }
+
+    let patterns = detect_patterns(&sessions, 30);
+    let compaction = &patterns.compaction;
+    assert_eq!(compaction.compacted_sessions, 2);
+    assert_eq!(compaction.total_compactions, 3);
+    assert!((compaction.avg_minutes_compacted - 60.0).abs() < 1e-9);
+    assert!((compaction.avg_minutes_other - 15.0).abs() < 1e-9);
+
+    let trends = compute_trends(&sessions, 30);
+    let forecast = forecast_usage(&trends);
+    let insights = generate_insights(&trends, &patterns, &forecast, &Currency::default());
+    assert!(
+        insights
+            .iter()
+            .any(|i| i.starts_with("2 session(s) hit context compaction (3 total)")),
+        "Expected compaction insight, got {:?}",
+        insights
+    );
+}
+
+#[test]
+fn test_cache_efficiency_insights() {
+    let mut sessions = generate_test_sessions(3, 3);
+    for (session, (model, input, cache_read)) in sessions.iter_mut().zip([
+        // 20% cache reads on 2.5M input: flagged
+        ("claude-opus-4-5-20251101", 2_000_000, 500_000),
+        // Well cached
+        ("claude-sonnet-4-5", 1_000_000, 9_000_000),
+        // Poorly cached but low volume
+        ("claude-haiku-4-5", 900_000, 0),
+    ]) {
+        let session = Arc::get_mut(session).unwrap();
+        session.models_used = vec![model.to_string()];
+        session.model_segments = Vec::new();
+        session.input_tokens = input;
+        session.cache_read_tokens = cache_read;
+        session.cache_creation_tokens = 0;
+    }
+
+    let insights = cache_efficiency_insights(&sessions);
+    assert_eq!(
+        insights,
+        vec!["Model claude-opus-4-5: only 20% cache reads (overall 71%)—consider stable prompts."]
+    );
+    assert!(cache_efficiency_insights(&[]).is_empty());
+}
+
+#[test]
+fn test_insights_context_saturation() {
+    let mut sessions = generate_test_sessions(4, 4);
+    for (i, session) in sessions.iter_mut().enumerate() {
+        let session = Arc::get_mut(session).unwrap();
+        session.peak_context_pct = [92.5, 81.0, 40.0, 10.0][i];
+    }
+
+    let patterns = detect_patterns(&sessions, 30);
+    assert_eq!(patterns.context_saturated_sessions, 2);
+    assert!((patterns.max_context_pct - 92.5).abs() < 1e-9);
+
+    let trends = compute_trends(&sessions, 30);
+    let forecast = forecast_usage(&trends);
+    let insights = generate_insights(&trends, &patterns, &forecast, &Currency::default());
+    assert!(
+        insights
+            .iter()
+            .any(|i| i.starts_with("2 session(s) used >80% of the context window (peak 92%)")),
+        "Expected context insight, got {:?}",
+        insights
+    );
+}
+
+#[test]
+fn test_compute_streaks_with_gap() {
+    // 3-day run, a 2-day gap, then a 4-day run (unsorted, with a duplicate)
+    let dates = [
+        "2026-03-01",
+        "2026-03-03",
+        "2026-03-02",
+        "2026-03-06",
+        "2026-03-07",
+        "2026-03-08",
+        "2026-03-09",
+        "2026-03-09",
+    ];
+    let day = |d: &str| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").unwrap();
+    let streaks = trends::compute_streaks_on(dates, day("2026-03-10"));
+
+    assert_eq!(streaks.active_days, 7);
+    assert_eq!(streaks.longest, 4);
+    assert_eq!(streaks.current, 4);
+
+    // The run is broken once a full day passes without activity
+    let streaks = trends::compute_streaks_on(dates, day("2026-03-11"));
+    assert_eq!(streaks.longest, 4);
+    assert_eq!(streaks.current, 0);
+
+    // Month boundary counts as consecutive
+    let streaks = trends::compute_streaks_on(
+        ["2026-02-27", "2026-02-28", "2026-03-01", "2026-03-05"],
+        day("2026-03-05"),
+    );
+    assert_eq!(streaks.longest, 3);
+    assert_eq!(streaks.current, 1);
+
+    assert_eq!(compute_streaks([]), ActivityStreaks::default());
+}
+
+#[test]
+fn test_insights_activity_streak() {
+    // One session per day for the last 5 days
+    let sessions = generate_test_sessions(5, 5);
+    let trends = compute_trends(&sessions, 30);
+    assert_eq!(trends.activity_streaks().longest, 5);
+
+    let patterns = detect_patterns(&sessions, 30);
+    let forecast = forecast_usage(&trends);
+    let insights = generate_insights(&trends, &patterns, &forecast, &Currency::default());
+    assert!(
+        insights.iter().any(|i| i.starts_with("5-day streak")),
+        "Expected streak insight, got {:?}",
+        insights
+    );
+}
+
+#[test]
+fn test_budget_alerts_daily_and_block() {
+    use crate::models::CurrentSpend;
+
+    let sessions = generate_test_sessions(5, 5);
+    let trends = compute_trends(&sessions, 30);
+    let forecast = forecast_usage(&trends);
+    let spend = CurrentSpend {
+        today: 6.0,
+        today_projected: 12.0,
+        block: 2.0,
+        block_projected: 5.0,
+    };
+
+    // Unset daily / block budgets → monthly-only behavior
+    let alerts = generate_budget_alerts(
+        &trends,
+        &forecast,
+        None,
+        80.0,
+        &ShortTermBudget::default(),
+        &[],
+    );
+    assert!(!alerts.iter().any(|a| matches!(
+        a,
+        Alert::DailyBudgetExceeded { .. } | Alert::BlockBudgetExceeded { .. }
+    )));
+
+    let short_term = ShortTermBudget {
+        daily: Some(10.0),
+        per_block: Some(8.0),
+        spend,
+    };
+    let alerts = generate_budget_alerts(&trends, &forecast, None, 80.0, &short_term, &[]);
+    assert!(alerts.iter().any(|a| matches!(
+        a,
+        Alert::DailyBudgetExceeded { projected, budget, .. } if *projected == 12.0 && *budget == 10.0
+    )));
+    // Block projection ($5) stays under its $8 budget
+    assert!(!alerts
+        .iter()
+        .any(|a| matches!(a, Alert::BlockBudgetExceeded { .. })));
+}
+
+#[test]
+fn test_token_percentiles() {
+    assert_eq!(
+        TokenPercentiles::from_tokens(Vec::new()),
+        TokenPercentiles::default()
+    );
+
+    // 100..=1 (unsorted input): nearest-rank percentiles are the values themselves
+    let tokens: Vec<u64> = (1..=100).rev().collect();
+    let pct = TokenPercentiles::from_tokens(tokens);
+    assert_eq!((pct.p50, pct.p90, pct.p95, pct.p99), (50, 90, 95, 99));
+    assert_eq!(pct.session_count, 100);
+
+    let single = TokenPercentiles::from_tokens(vec![42]);
+    assert_eq!((single.p50, single.p99), (42, 42));
+}
+
+#[test]
+fn test_budget_alerts_per_project() {
+    let sessions = generate_test_sessions(5, 5);
+    let trends = compute_trends(&sessions, 30);
+    let forecast = forecast_usage(&trends);
+    let projects = [
+        ProjectBudgetStatus {
+            project: "/work/client-a".to_string(),
+            spent: 45.0,
+            budget: 50.0,
+            alert_threshold_pct: 80.0,
+        },
+        ProjectBudgetStatus {
+            project: "/work/client-b".to_string(),
+            spent: 10.0,
+            budget: 50.0,
+            alert_threshold_pct: 80.0,
+        },
+    ];
+
+    let alerts = generate_budget_alerts(
+        &trends,
+        &forecast,
+        None,
+        80.0,
+        &ShortTermBudget::default(),
+        &projects,
+    );
+    let warned: Vec<&str> = alerts
+        .iter()
+        .filter_map(|a| match a {
+            Alert::ProjectBudgetWarning { project, pct, .. } => {
+                assert_eq!(*pct, 90.0);
+                Some(project.as_str())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(warned, ["/work/client-a"]);
+}
+
 // ============================================================================
 // Forecast Tests (4 tests)
 // ============================================================================
@@ -114,6 +489,33 @@ fn test_forecast_insufficient_data() {
         "Should be unavailable"
     );
     assert_eq!(forecast.confidence, 0.0);
+    assert!(forecast.monthly_cost_low.is_none() && forecast.monthly_cost_high.is_none());
+    assert_eq!(forecast.monthly_cost_display(&Currency::default()), "$0.00");
+}
+
+#[test]
+fn test_forecast_cost_bounds() {
+    // Perfectly flat usage: zero residuals, zero-width band
+    let mut sessions = generate_test_sessions(30, 30);
+    for session in sessions.iter_mut() {
+        Arc::get_mut(session).unwrap().total_tokens = 1000;
+    }
+    let forecast = forecast_usage(&compute_trends(&sessions, 30));
+    assert_eq!(forecast.monthly_cost_margin(), Some(0.0));
+
+    // Noisy usage: band brackets the estimate
+    for (i, session) in sessions.iter_mut().enumerate() {
+        Arc::get_mut(session).unwrap().total_tokens = if i % 2 == 0 { 500 } else { 1500 };
+    }
+    let forecast = forecast_usage(&compute_trends(&sessions, 30));
+    let (low, high) = (
+        forecast.monthly_cost_low.unwrap(),
+        forecast.monthly_cost_high.unwrap(),
+    );
+    assert!(low < forecast.monthly_cost_estimate && forecast.monthly_cost_estimate < high);
+    assert!(forecast
+        .monthly_cost_display(&Currency::default())
+        .contains("(±$"));
 }
 
 #[test]
@@ -205,6 +607,27 @@ fn test_patterns_model_distribution_sums_to_one() {
     }
 }
 
+#[test]
+fn test_cost_heatmap_attributes_cost_to_start_hour() {
+    use chrono::{Datelike, Local, Timelike};
+
+    let mut sessions = generate_test_sessions(3, 3);
+    // Timestamp-less sessions can't be placed and are skipped
+    let mut undated = (*sessions[0]).clone();
+    undated.first_timestamp = None;
+    sessions.push(Arc::new(undated));
+
+    let heatmap = cost_heatmap(&sessions);
+
+    let total: f64 = heatmap.iter().flatten().sum();
+    let expected: f64 = sessions[..3].iter().map(|s| s.estimated_cost()).sum();
+    assert!((total - expected).abs() < 1e-9);
+
+    let start = sessions[0].first_timestamp.unwrap().with_timezone(&Local);
+    let cell = heatmap[start.weekday().num_days_from_monday() as usize][start.hour() as usize];
+    assert!(cell >= sessions[0].estimated_cost());
+}
+
 #[test]
 fn test_patterns_multi_model_session_no_double_count() {
     // Test fix for double-counting bug when session uses multiple models
@@ -227,20 +650,36 @@ fn test_patterns_multi_model_session_no_double_count() {
         first_user_message: None,
         has_subagents: false,
         parent_session_id: None,
+        resumed_from: None,
+        compaction_count: 0,
         duration_seconds: Some(1800),
         branch: None,
         tool_usage: std::collections::HashMap::new(),
         tool_token_usage: std::collections::HashMap::new(),
+        tool_followup_tokens: std::collections::HashMap::new(),
         source_tool: Default::default(),
         lines_added: 0,
         lines_removed: 0,
+        tool_result_bytes: 0,
+        peak_context_tokens: 0,
+        peak_context_pct: 0.0,
+        malformed_lines: 0,
+        estimated_extra_tokens: 0,
+        cwd: None,
+        author: None,
     })];
 
     let patterns = detect_patterns(&sessions, 7);
 
     // Each model should get 500 tokens (1000 / 2)
-    let sonnet_pct = patterns.model_distribution.get("sonnet").unwrap_or(&0.0);
-    let haiku_pct = patterns.model_distribution.get("haiku").unwrap_or(&0.0);
+    let sonnet_pct = patterns
+        .model_distribution
+        .get("claude-sonnet-4-5")
+        .unwrap_or(&0.0);
+    let haiku_pct = patterns
+        .model_distribution
+        .get("claude-haiku-4-5")
+        .unwrap_or(&0.0);
 
     assert!(
         (*sonnet_pct - 0.5).abs() < 0.01,
@@ -310,3 +749,89 @@ fn test_period_display() {
     assert_eq!(period_7d.display(100), "Last 7 days");
     assert_eq!(period_available.display(1000), "All loaded (1000 sessions)");
 }
+
+#[test]
+fn test_period_parse() {
+    assert_eq!(Period::parse("30d", None, None), Ok(Period::last_30d()));
+    assert_eq!(Period::parse("ALL", None, None), Ok(Period::Available));
+    let custom = Period::parse("custom", Some("2026-03-01"), Some("2026-03-31")).unwrap();
+    assert_eq!(custom.days(), 31);
+
+    assert!(Period::parse("custom", Some("2026-03-01"), None).is_err());
+    assert!(Period::parse("custom", Some("2026-03-31"), Some("2026-03-01")).is_err());
+    assert!(Period::parse("0d", None, None).is_err());
+    assert_eq!(
+        Period::parse("36500d", None, None),
+        Ok(Period::Days(Period::Available.days()))
+    );
+    // Larger spans overflow the date arithmetic in `bounds`
+    assert!(Period::parse("36501d", None, None).is_err());
+    assert!(Period::parse("99999999999d", None, None).is_err());
+    assert!(Period::parse("month", None, None).is_err());
+}
+
+#[test]
+fn test_period_preceding_window() {
+    let range = DateRange::parse("2026-03-08..2026-03-14").unwrap();
+    let week = Period::from_date_range(&range);
+    let prior = week.preceding().unwrap();
+    assert_eq!(prior.days(), 7);
+    assert_eq!(prior.bounds().1, week.bounds().0);
+
+    let (start, end) = Period::Days(7).preceding().unwrap().bounds();
+    assert_eq!((end - start).num_days(), 7);
+    assert!(Period::Available.preceding().is_none());
+}
+
+#[test]
+fn test_budget_watch_reports_each_crossing_once() {
+    let monthly = |pct: f64| Alert::BudgetWarning {
+        current: pct,
+        budget: 100.0,
+        pct,
+    };
+    let daily = |spent: f64| Alert::DailyBudgetExceeded {
+        spent,
+        projected: 12.0,
+        budget: 10.0,
+    };
+    let spike = Alert::UsageSpike {
+        day: "2026-03-01".to_string(),
+        tokens: 10,
+        avg: 1,
+    };
+    assert_eq!(monthly(80.0).level(90.0), AlertLevel::Warning);
+    assert_eq!(monthly(95.0).level(90.0), AlertLevel::Critical);
+    assert_eq!(monthly(100.0).level(90.0), AlertLevel::Exceeded);
+    assert_eq!(daily(11.0).level(90.0), AlertLevel::Exceeded);
+
+    let mut watch = BudgetWatch::new();
+    let keys = |alerts: Vec<Alert>| -> Vec<String> {
+        alerts.iter().filter_map(Alert::budget_key).collect()
+    };
+
+    // Usage spikes never notify
+    assert_eq!(
+        keys(watch.escalations(&[monthly(80.0), daily(6.0), spike.clone()], 90.0)),
+        ["monthly", "daily"]
+    );
+    // Same levels on the next refresh: nothing new
+    assert!(watch
+        .escalations(&[monthly(85.0), daily(7.0), spike], 90.0)
+        .is_empty());
+    // Escalations only
+    assert_eq!(
+        keys(watch.escalations(&[monthly(92.0), daily(7.0)], 90.0)),
+        ["monthly"]
+    );
+    assert_eq!(
+        keys(watch.escalations(&[monthly(92.0), daily(10.5)], 90.0)),
+        ["daily"]
+    );
+    // Cleared (new day), then crossed again
+    assert!(watch.escalations(&[monthly(92.0)], 90.0).is_empty());
+    assert_eq!(
+        keys(watch.escalations(&[monthly(92.0), daily(6.0)], 90.0)),
+        ["daily"]
+    );
+}
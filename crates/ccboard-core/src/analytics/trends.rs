@@ -2,14 +2,17 @@
 //!
 //! Aggregates session data by day, hour, and weekday to identify usage patterns over time.
 
-use chrono::{Datelike, Local, Timelike};
-use std::collections::{BTreeMap, HashMap};
+use chrono::{Datelike, Local, NaiveDate, Timelike};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::Arc;
 
+use super::Period;
 use crate::models::session::SessionMetadata;
+use crate::pricing::normalize_model_id;
 
 /// Session duration statistics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionDurationStats {
     /// Average duration in seconds
     pub avg_duration_secs: f64,
@@ -39,8 +42,48 @@ impl SessionDurationStats {
     }
 }
 
+/// Per-session token usage percentiles (nearest-rank)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenPercentiles {
+    /// Median tokens per session
+    pub p50: u64,
+    /// 90th percentile tokens per session
+    pub p90: u64,
+    /// 95th percentile tokens per session
+    pub p95: u64,
+    /// 99th percentile tokens per session
+    pub p99: u64,
+    /// Number of sessions the percentiles were computed over
+    pub session_count: usize,
+}
+
+impl TokenPercentiles {
+    /// Compute from per-session token totals with a single sort
+    ///
+    /// Returns all zeros when `tokens` is empty.
+    pub fn from_tokens(mut tokens: Vec<u64>) -> Self {
+        if tokens.is_empty() {
+            return Self::default();
+        }
+        tokens.sort_unstable();
+
+        let rank = |pct: f64| {
+            let idx = ((tokens.len() as f64) * pct).ceil() as usize;
+            tokens[idx.saturating_sub(1).min(tokens.len() - 1)]
+        };
+
+        Self {
+            p50: rank(0.50),
+            p90: rank(0.90),
+            p95: rank(0.95),
+            p99: rank(0.99),
+            session_count: tokens.len(),
+        }
+    }
+}
+
 /// Time series trends data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrendsData {
     /// Dates in "YYYY-MM-DD" format (sorted chronologically)
     pub dates: Vec<String>,
@@ -50,6 +93,9 @@ pub struct TrendsData {
     pub daily_sessions: Vec<usize>,
     /// Daily cost estimates (aligned with dates)
     pub daily_cost: Vec<f64>,
+    /// Daily cache hit ratio, 0.0-1.0 (see `ccboard_types::cache_hit_ratio`;
+    /// aligned with dates)
+    pub daily_cache_hit_ratio: Vec<f64>,
     /// Hourly distribution (0-23)
     pub hourly_distribution: [usize; 24],
     /// Weekday distribution (0=Monday, 6=Sunday)
@@ -58,6 +104,18 @@ pub struct TrendsData {
     pub model_usage_over_time: HashMap<String, Vec<usize>>,
     /// Session duration statistics
     pub duration_stats: SessionDurationStats,
+    /// ISO weeks (Monday-based) in "YYYY-Www" format (sorted chronologically)
+    pub weeks: Vec<String>,
+    /// Weekly token counts (aligned with weeks)
+    pub weekly_tokens: Vec<u64>,
+    /// Weekly session counts (aligned with weeks)
+    pub weekly_sessions: Vec<usize>,
+    /// Months in "YYYY-MM" format (sorted chronologically)
+    pub months: Vec<String>,
+    /// Monthly token counts (aligned with months)
+    pub monthly_tokens: Vec<u64>,
+    /// Monthly session counts (aligned with months)
+    pub monthly_sessions: Vec<usize>,
 }
 
 impl TrendsData {
@@ -71,6 +129,36 @@ impl TrendsData {
         Some((self.dates.get(idx)?, self.daily_tokens[idx]))
     }
 
+    /// Average cache hit ratio of the previous window vs the trailing window
+    ///
+    /// Returns `(previous, trailing)` averages over two consecutive windows of `window`
+    /// calendar days ending at the most recent date, or `None` if the history spans
+    /// fewer days. Days without activity count as zero in their window's average.
+    pub fn cache_hit_ratio_change(&self, window: usize) -> Option<(f64, f64)> {
+        let parse = |d: &String| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok();
+        let first = self.dates.first().and_then(parse)?;
+        let last = self.dates.last().and_then(parse)?;
+        if window == 0 || ((last - first).num_days() + 1) < (window * 2) as i64 {
+            return None;
+        }
+
+        // [trailing, previous] ratio sums
+        let mut sums = [0.0; 2];
+        for (date, ratio) in self.dates.iter().zip(&self.daily_cache_hit_ratio) {
+            let Some(date) = parse(date) else { continue };
+            let days_back = (last - date).num_days() as usize;
+            if let Some(sum) = sums.get_mut(days_back / window) {
+                *sum += ratio;
+            }
+        }
+        Some((sums[1] / window as f64, sums[0] / window as f64))
+    }
+
+    /// Active days and consecutive-day streaks over the period
+    pub fn activity_streaks(&self) -> ActivityStreaks {
+        compute_streaks(self.dates.iter().map(String::as_str))
+    }
+
     /// Empty placeholder for no data
     pub fn empty() -> Self {
         Self {
@@ -78,20 +166,78 @@ impl TrendsData {
             daily_tokens: Vec::new(),
             daily_sessions: Vec::new(),
             daily_cost: Vec::new(),
+            daily_cache_hit_ratio: Vec::new(),
             hourly_distribution: [0; 24],
             weekday_distribution: [0; 7],
             model_usage_over_time: HashMap::new(),
             duration_stats: SessionDurationStats::empty(),
+            weeks: Vec::new(),
+            weekly_tokens: Vec::new(),
+            weekly_sessions: Vec::new(),
+            months: Vec::new(),
+            monthly_tokens: Vec::new(),
+            monthly_sessions: Vec::new(),
         }
     }
 }
 
+/// Consecutive-day activity streaks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActivityStreaks {
+    /// Number of distinct days with activity
+    pub active_days: usize,
+    /// Longest run of consecutive active days
+    pub longest: usize,
+    /// Run of consecutive active days ending today or yesterday (0 once broken)
+    pub current: usize,
+}
+
+/// Compute activity streaks from the "YYYY-MM-DD" dates of active days
+///
+/// Dates may be unsorted or repeated; unparseable entries are ignored.
+pub fn compute_streaks<'a>(dates: impl IntoIterator<Item = &'a str>) -> ActivityStreaks {
+    compute_streaks_on(dates, Local::now().date_naive())
+}
+
+/// [`compute_streaks`] as seen on `today`
+pub(crate) fn compute_streaks_on<'a>(
+    dates: impl IntoIterator<Item = &'a str>,
+    today: NaiveDate,
+) -> ActivityStreaks {
+    let days: BTreeSet<NaiveDate> = dates
+        .into_iter()
+        .filter_map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+        .collect();
+
+    let mut streaks = ActivityStreaks {
+        active_days: days.len(),
+        ..Default::default()
+    };
+    let mut previous: Option<NaiveDate> = None;
+    for day in days {
+        streaks.current = match previous {
+            Some(prev) if prev.succ_opt() == Some(day) => streaks.current + 1,
+            _ => 1,
+        };
+        streaks.longest = streaks.longest.max(streaks.current);
+        previous = Some(day);
+    }
+    // A run that ended before yesterday is no longer current
+    if previous.is_some_and(|last| last < today - chrono::Duration::days(1)) {
+        streaks.current = 0;
+    }
+    streaks
+}
+
 /// Daily aggregate helper
 #[derive(Default)]
 struct DailyAggregate {
     tokens: u64,
     sessions: usize,
     cost: f64,
+    input_tokens: u64,
+    cache_read_tokens: u64,
+    cache_creation_tokens: u64,
 }
 
 /// Estimate cost from session
@@ -114,29 +260,32 @@ fn estimate_cost(session: &SessionMetadata) -> f64 {
 /// - Missing timestamps: Session skipped with warning
 /// - Empty models_used: Counted but not tracked per-model
 pub fn compute_trends(sessions: &[Arc<SessionMetadata>], days: usize) -> TrendsData {
+    compute_trends_for_period(sessions, &Period::Days(days))
+}
+
+/// Same as `compute_trends`, keeping sessions that start within `period`
+/// (both bounds are honored for `Period::Custom`)
+pub fn compute_trends_for_period(sessions: &[Arc<SessionMetadata>], period: &Period) -> TrendsData {
     let mut daily_map: BTreeMap<String, DailyAggregate> = BTreeMap::new();
     let mut hourly_counts = [0usize; 24];
     let mut weekday_counts = [0usize; 7];
     let mut model_usage: HashMap<String, BTreeMap<String, usize>> = HashMap::new();
     let mut durations_secs: Vec<u64> = Vec::new();
 
-    let now = Local::now();
-    let cutoff = now - chrono::Duration::days(days as i64);
-
     for session in sessions {
         let Some(ts) = session.first_timestamp else {
             tracing::warn!("Session {} missing timestamp, skipping", session.id);
             continue;
         };
 
-        // Convert UTC → Local for grouping
-        let local_ts = ts.with_timezone(&Local);
-
         // Filter by period
-        if local_ts < cutoff {
+        if !period.contains(&ts) {
             continue;
         }
 
+        // Convert UTC → Local for grouping
+        let local_ts = ts.with_timezone(&Local);
+
         let date_key = local_ts.format("%Y-%m-%d").to_string();
 
         // Aggregate daily
@@ -144,6 +293,9 @@ pub fn compute_trends(sessions: &[Arc<SessionMetadata>], days: usize) -> TrendsD
         agg.tokens += session.total_tokens;
         agg.sessions += 1;
         agg.cost += estimate_cost(session);
+        agg.input_tokens += session.input_tokens;
+        agg.cache_read_tokens += session.cache_read_tokens;
+        agg.cache_creation_tokens += session.cache_creation_tokens;
 
         // Hourly distribution
         hourly_counts[local_ts.hour() as usize] += 1;
@@ -154,7 +306,7 @@ pub fn compute_trends(sessions: &[Arc<SessionMetadata>], days: usize) -> TrendsD
         // Model usage over time
         for model in &session.models_used {
             model_usage
-                .entry(model.clone())
+                .entry(normalize_model_id(model))
                 .or_default()
                 .entry(date_key.clone())
                 .and_modify(|count| *count += 1)
@@ -174,6 +326,17 @@ pub fn compute_trends(sessions: &[Arc<SessionMetadata>], days: usize) -> TrendsD
     let daily_tokens: Vec<u64> = daily_map.values().map(|a| a.tokens).collect();
     let daily_sessions: Vec<usize> = daily_map.values().map(|a| a.sessions).collect();
     let daily_cost: Vec<f64> = daily_map.values().map(|a| a.cost).collect();
+    let daily_cache_hit_ratio: Vec<f64> = daily_map
+        .values()
+        .map(|a| {
+            ccboard_types::cache_hit_ratio(
+                a.input_tokens,
+                a.cache_read_tokens,
+                a.cache_creation_tokens,
+            )
+            .unwrap_or(0.0)
+        })
+        .collect();
 
     // Align model usage with dates
     let model_usage_over_time: HashMap<String, Vec<usize>> = model_usage
@@ -187,6 +350,34 @@ pub fn compute_trends(sessions: &[Arc<SessionMetadata>], days: usize) -> TrendsD
         })
         .collect();
 
+    // Roll days up into ISO weeks and calendar months (partial ones included)
+    let mut weekly_map: BTreeMap<(i32, u32), (u64, usize)> = BTreeMap::new();
+    let mut monthly_map: BTreeMap<(i32, u32), (u64, usize)> = BTreeMap::new();
+    for (date_key, agg) in &daily_map {
+        let Ok(date) = NaiveDate::parse_from_str(date_key, "%Y-%m-%d") else {
+            continue;
+        };
+        let iso = date.iso_week();
+        let week = weekly_map.entry((iso.year(), iso.week())).or_default();
+        week.0 += agg.tokens;
+        week.1 += agg.sessions;
+        let month = monthly_map.entry((date.year(), date.month())).or_default();
+        month.0 += agg.tokens;
+        month.1 += agg.sessions;
+    }
+    let weeks: Vec<String> = weekly_map
+        .keys()
+        .map(|(year, week)| format!("{}-W{:02}", year, week))
+        .collect();
+    let weekly_tokens: Vec<u64> = weekly_map.values().map(|v| v.0).collect();
+    let weekly_sessions: Vec<usize> = weekly_map.values().map(|v| v.1).collect();
+    let months: Vec<String> = monthly_map
+        .keys()
+        .map(|(year, month)| format!("{}-{:02}", year, month))
+        .collect();
+    let monthly_tokens: Vec<u64> = monthly_map.values().map(|v| v.0).collect();
+    let monthly_sessions: Vec<usize> = monthly_map.values().map(|v| v.1).collect();
+
     // Compute session duration statistics
     let duration_stats = compute_duration_stats(&durations_secs);
 
@@ -195,10 +386,17 @@ pub fn compute_trends(sessions: &[Arc<SessionMetadata>], days: usize) -> TrendsD
         daily_tokens,
         daily_sessions,
         daily_cost,
+        daily_cache_hit_ratio,
         hourly_distribution: hourly_counts,
         weekday_distribution: weekday_counts,
         model_usage_over_time,
         duration_stats,
+        weeks,
+        weekly_tokens,
+        weekly_sessions,
+        months,
+        monthly_tokens,
+        monthly_sessions,
     }
 }
 
@@ -128,25 +128,7 @@ impl BookmarkStore {
     // ── Persistence ─────────────────────────────────────────────────────────
 
     fn save(&self) -> Result<()> {
-        // Ensure parent directory exists
-        if let Some(parent) = self.path.parent() {
-            std::fs::create_dir_all(parent)
-                .with_context(|| format!("Failed to create {}", parent.display()))?;
-        }
-        let json =
-            serde_json::to_string_pretty(&self.entries).context("Failed to serialise bookmarks")?;
-        // Atomic write: tmp file → rename
-        let tmp = self.path.with_extension("json.tmp");
-        std::fs::write(&tmp, &json)
-            .with_context(|| format!("Failed to write {}", tmp.display()))?;
-        std::fs::rename(&tmp, &self.path).with_context(|| {
-            format!(
-                "Failed to rename {} → {}",
-                tmp.display(),
-                self.path.display()
-            )
-        })?;
-        Ok(())
+        crate::persist::write_json_atomic(&self.path, &self.entries)
     }
 }
 
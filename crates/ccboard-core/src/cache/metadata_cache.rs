@@ -7,29 +7,28 @@
 //! - session_metadata: parsed metadata + mtime + cache_version
 //! - activity_cache: serialized ActivitySummary + mtime per session file
 //! - activity_alerts: searchable alert records (severity/category) across all sessions
+//! - analytics_cache: serialized trends/patterns/forecast per period, keyed by the
+//!   latest session timestamp they were computed from
 //! - Indexes: project, mtime, session_id, severity for fast queries
 //!
 //! Invalidation:
 //! - File watcher detects modification → delete session + activity cache entries
+//! - File watcher detects a new session file → clear analytics_cache
 //! - Startup: compare mtime → rescan if stale
 //! - Startup: compare cache_version → auto-clear ALL tables if mismatch
 //!
-//! Cache Version History:
-//! - v1: Initial version (pre-TokenUsage fix)
-//! - v2: Fixed TokenUsage::total() to include cache_read_tokens + cache_write_tokens
-//! - v3: Added token breakdown fields (input_tokens, output_tokens, cache_creation_tokens,
-//!   cache_read_tokens) to SessionMetadata + real pricing calculation
-//! - v4: Added branch field to SessionMetadata
-//! - v5: Added activity_cache + activity_alerts tables for Phase 2 activity module
-//! - v6: Added aggregate_stats table with triggers + FTS5 session_fts table
-//! - v7: Added tool_token_usage field to SessionMetadata (Phase K analytics)
+//! Version history: see `CACHE_VERSION`.
 
+use crate::analytics::{ForecastData, Period, TrendsData, UsagePatterns};
 use crate::models::activity::ActivitySummary;
 use crate::models::SessionMetadata;
 use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::SystemTime;
 use tracing::{debug, warn};
 
@@ -53,13 +52,28 @@ use tracing::{debug, warn};
 /// - v7: Added tool_token_usage field to SessionMetadata (Phase K analytics)
 /// - v8: Added source_tool field to SessionMetadata (multi-LLM support)
 /// - v9: Added lines_added/lines_removed fields to SessionMetadata (code metrics)
-const CACHE_VERSION: i32 = 9;
+/// - v10: Added tool_result_bytes field to SessionMetadata (tool output sizing)
+/// - v11: Added analytics_cache table (serialized trends/patterns/forecast)
+/// - v12: Added peak_context_tokens/peak_context_pct fields to SessionMetadata
+/// - v13: Added malformed_lines field to SessionMetadata
+/// - v14: Added estimated_extra_tokens field to SessionMetadata
+/// - v15: Added tool_followup_tokens field to SessionMetadata
+/// - v16: Added resumed_from field to SessionMetadata (session chains)
+/// - v17: Added compaction_count field to SessionMetadata
+/// - v18: Added cwd and author fields to SessionMetadata
+/// - v19: Added weekly/monthly series to cached TrendsData
+/// - v20: Added branch_distribution to cached UsagePatterns
+/// - v21: Added CompactionStats to cached UsagePatterns
+/// - v22: Cache hit ratio trend counts cache writes (shared `cache_hit_ratio`)
+const CACHE_VERSION: i32 = 22;
 
 /// SQLite-based metadata cache (thread-safe)
 pub struct MetadataCache {
     conn: Mutex<Connection>,
     #[allow(dead_code)]
     cache_path: PathBuf,
+    /// Analytics lookups served from `analytics_cache` since startup
+    analytics_hits: AtomicUsize,
 }
 
 impl MetadataCache {
@@ -128,6 +142,14 @@ impl MetadataCache {
             CREATE INDEX IF NOT EXISTS idx_alerts_session ON activity_alerts(session_path);
             CREATE INDEX IF NOT EXISTS idx_alerts_severity ON activity_alerts(severity);
 
+            CREATE TABLE IF NOT EXISTS analytics_cache (
+                period TEXT PRIMARY KEY,
+                max_session_timestamp TEXT,
+                session_count INTEGER NOT NULL,
+                computed_on TEXT NOT NULL,
+                data BLOB NOT NULL
+            );
+
             CREATE TABLE IF NOT EXISTS aggregate_stats (
                 key   TEXT PRIMARY KEY,
                 value INTEGER NOT NULL DEFAULT 0
@@ -209,6 +231,8 @@ impl MetadataCache {
                     .context("Failed to clear stale activity alerts")?;
                 conn.execute("DELETE FROM aggregate_stats", [])
                     .context("Failed to clear stale aggregate stats")?;
+                conn.execute("DELETE FROM analytics_cache", [])
+                    .context("Failed to clear stale analytics cache")?;
                 conn.execute(
                     "INSERT OR IGNORE INTO aggregate_stats (key, value) VALUES ('total_sessions', 0)",
                     [],
@@ -247,6 +271,7 @@ impl MetadataCache {
         let cache = Self {
             conn: Mutex::new(conn),
             cache_path: cache_path.clone(),
+            analytics_hits: AtomicUsize::new(0),
         };
 
         debug!(path = %cache_path.display(), "Metadata cache initialized");
@@ -439,13 +464,45 @@ impl MetadataCache {
             )
             .context("Failed to count projects")?;
 
+        let rebuild_value = |key: &str| -> Result<Option<i64>> {
+            conn.query_row(
+                "SELECT value FROM cache_metadata WHERE key = ?",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()
+            .context("Failed to query last rebuild")
+        };
+        let last_rebuild_duration = rebuild_value("last_rebuild_ms")?
+            .map(|ms| std::time::Duration::from_millis(ms.max(0) as u64));
+        let last_rebuild_files = rebuild_value("last_rebuild_files")?.map(|n| n.max(0) as usize);
+
         Ok(CacheStats {
             total_entries: total_entries as usize,
             total_size_bytes: total_size as usize,
             project_count: project_count as usize,
+            analytics_hits: self.analytics_hits.load(Ordering::Relaxed),
+            last_rebuild_duration,
+            last_rebuild_files,
         })
     }
 
+    /// Record a scan that parsed `files` session files into the cache
+    pub fn record_rebuild(&self, duration: std::time::Duration, files: usize) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Metadata cache lock poisoned: {}", e))?;
+
+        conn.execute(
+            "INSERT OR REPLACE INTO cache_metadata (key, value) VALUES ('last_rebuild_ms', ?), ('last_rebuild_files', ?)",
+            params![duration.as_millis() as i64, files as i64],
+        )
+        .context("Failed to record cache rebuild")?;
+
+        Ok(())
+    }
+
     /// Clear all cache entries (for testing or rebuild)
     pub fn clear(&self) -> Result<()> {
         let conn = self
@@ -851,6 +908,104 @@ impl MetadataCache {
             critical_alerts: critical_alerts as usize,
         })
     }
+
+    // ─── Analytics cache methods ──────────────────────────────────────────────
+
+    /// Get analytics cached for `period` today.
+    ///
+    /// Rows computed on an earlier day are ignored: relative periods and streaks
+    /// shift with the date. Callers check [`CachedAnalytics::is_fresh_for`]
+    /// before reusing the result.
+    pub fn load_analytics(&self, period: &Period) -> Result<Option<CachedAnalytics>> {
+        let key = analytics_period_key(period);
+        let today = Local::now().date_naive().to_string();
+
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Metadata cache lock poisoned: {}", e))?;
+
+        let result: Option<Vec<u8>> = conn
+            .query_row(
+                "SELECT data FROM analytics_cache WHERE period = ? AND computed_on = ?",
+                params![key, today],
+                |row| row.get(0),
+            )
+            .optional()
+            .context("Failed to query analytics cache")?;
+
+        match result {
+            Some(bytes) => {
+                let cached: CachedAnalytics = bincode::deserialize(&bytes)
+                    .context("Failed to deserialize cached analytics")?;
+                Ok(Some(cached))
+            }
+            None => {
+                debug!(period = %key, "Analytics cache miss");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Store analytics for `period`, replacing any previous entry
+    pub fn store_analytics(&self, period: &Period, data: &CachedAnalytics) -> Result<()> {
+        let key = analytics_period_key(period);
+        let bytes = bincode::serialize(data).context("Failed to serialize analytics")?;
+
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Metadata cache lock poisoned: {}", e))?;
+
+        conn.execute(
+            r#"
+            INSERT OR REPLACE INTO analytics_cache
+            (period, max_session_timestamp, session_count, computed_on, data)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+            params![
+                key,
+                data.max_session_timestamp.map(|ts| ts.to_rfc3339()),
+                data.session_count as i64,
+                data.computed_on.to_string(),
+                bytes,
+            ],
+        )
+        .context("Failed to insert analytics cache entry")?;
+
+        debug!(period = %key, "Analytics cached");
+        Ok(())
+    }
+
+    /// Drop all cached analytics (a new session file appeared)
+    pub fn invalidate_analytics(&self) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Metadata cache lock poisoned: {}", e))?;
+
+        conn.execute("DELETE FROM analytics_cache", [])
+            .context("Failed to clear analytics cache")?;
+
+        debug!("Analytics cache invalidated");
+        Ok(())
+    }
+
+    /// Count a cached analytics result being reused (reported in [`CacheStats`])
+    pub fn record_analytics_hit(&self) {
+        self.analytics_hits.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Stable `analytics_cache` key of a period
+fn analytics_period_key(period: &Period) -> String {
+    match period {
+        Period::Days(days) => format!("days:{}", days),
+        Period::Available => "available".to_string(),
+        Period::Custom { start, end } => {
+            format!("custom:{}..{}", start.to_rfc3339(), end.to_rfc3339())
+        }
+    }
 }
 
 impl Drop for MetadataCache {
@@ -873,6 +1028,58 @@ pub struct CacheStats {
     pub total_entries: usize,
     pub total_size_bytes: usize,
     pub project_count: usize,
+    /// Analytics computations skipped thanks to `analytics_cache`
+    pub analytics_hits: usize,
+    /// How long the last scan that parsed files into the cache took
+    pub last_rebuild_duration: Option<std::time::Duration>,
+    /// Session files parsed into the cache by that scan
+    pub last_rebuild_files: Option<usize>,
+}
+
+/// Expensive analytics parts persisted in `analytics_cache`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedAnalytics {
+    /// Latest session timestamp of the input sessions
+    pub max_session_timestamp: Option<DateTime<Utc>>,
+    /// Number of input sessions
+    pub session_count: usize,
+    /// Local date of computation
+    pub computed_on: NaiveDate,
+    pub trends: TrendsData,
+    pub patterns: UsagePatterns,
+    pub forecast: ForecastData,
+}
+
+impl CachedAnalytics {
+    /// Wrap freshly computed parts with the fingerprint of `sessions`
+    pub fn new(
+        sessions: &[Arc<SessionMetadata>],
+        trends: TrendsData,
+        patterns: UsagePatterns,
+        forecast: ForecastData,
+    ) -> Self {
+        Self {
+            max_session_timestamp: max_session_timestamp(sessions),
+            session_count: sessions.len(),
+            computed_on: Local::now().date_naive(),
+            trends,
+            patterns,
+            forecast,
+        }
+    }
+
+    /// Whether no session was added or updated since this was computed
+    pub fn is_fresh_for(&self, sessions: &[Arc<SessionMetadata>]) -> bool {
+        self.session_count == sessions.len()
+            && self.max_session_timestamp == max_session_timestamp(sessions)
+    }
+}
+
+fn max_session_timestamp(sessions: &[Arc<SessionMetadata>]) -> Option<DateTime<Utc>> {
+    sessions
+        .iter()
+        .filter_map(|s| s.last_timestamp.or(s.first_timestamp))
+        .max()
 }
 
 /// Aggregate statistics from O(1) table
@@ -1037,6 +1244,17 @@ mod tests {
         assert_eq!(stats.total_entries, 10);
         assert!(stats.total_size_bytes > 0);
         assert_eq!(stats.project_count, 1);
+        assert_eq!(stats.last_rebuild_files, None);
+
+        cache
+            .record_rebuild(std::time::Duration::from_millis(1500), 10)
+            .unwrap();
+        let stats = cache.stats().unwrap();
+        assert_eq!(
+            stats.last_rebuild_duration,
+            Some(std::time::Duration::from_millis(1500))
+        );
+        assert_eq!(stats.last_rebuild_files, Some(10));
     }
 
     #[test]
@@ -1240,4 +1458,44 @@ mod tests {
         let alerts = cache.get_all_alerts(None).unwrap();
         assert_eq!(alerts.len(), 0, "Stale alerts should be replaced");
     }
+
+    #[test]
+    fn test_analytics_store_load_and_invalidate() {
+        use crate::analytics::{compute_trends, detect_patterns, forecast_usage};
+
+        let dir = tempdir().unwrap();
+        let cache = MetadataCache::new(dir.path()).unwrap();
+        let period = Period::Days(30);
+
+        let mut meta = SessionMetadata::from_path(PathBuf::from("/tmp/a.jsonl"), "/test".into());
+        meta.first_timestamp = Some(Utc::now());
+        meta.total_tokens = 1000;
+        let mut sessions = vec![Arc::new(meta)];
+
+        let trends = compute_trends(&sessions, 30);
+        let forecast = forecast_usage(&trends);
+        let patterns = detect_patterns(&sessions, 30);
+        let entry = CachedAnalytics::new(&sessions, trends, patterns, forecast);
+
+        assert!(cache.load_analytics(&period).unwrap().is_none());
+        cache.store_analytics(&period, &entry).unwrap();
+
+        let loaded = cache.load_analytics(&period).unwrap().unwrap();
+        assert!(loaded.is_fresh_for(&sessions));
+        assert_eq!(loaded.trends.daily_tokens, entry.trends.daily_tokens);
+        // Other periods have their own entry
+        assert!(cache.load_analytics(&Period::Days(7)).unwrap().is_none());
+
+        // A newer session makes the entry stale
+        let mut newer = SessionMetadata::from_path(PathBuf::from("/tmp/b.jsonl"), "/test".into());
+        newer.first_timestamp = Some(Utc::now() + chrono::Duration::seconds(5));
+        sessions.push(Arc::new(newer));
+        assert!(!loaded.is_fresh_for(&sessions));
+
+        cache.record_analytics_hit();
+        assert_eq!(cache.stats().unwrap().analytics_hits, 1);
+
+        cache.invalidate_analytics().unwrap();
+        assert!(cache.load_analytics(&period).unwrap().is_none());
+    }
 }
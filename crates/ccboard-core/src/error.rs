@@ -63,6 +63,25 @@ pub enum CoreError {
     #[error("Invalid frontmatter in {path}: {message}")]
     FrontmatterParse { path: PathBuf, message: String },
 
+    #[error("Invalid JSON in {path} at line {line}, column {column}: {message}")]
+    SettingsParse {
+        path: PathBuf,
+        line: usize,
+        column: usize,
+        message: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    // ===================
+    // Cache / Pricing Errors
+    // ===================
+    #[error("Metadata cache unavailable at {path}: {message}")]
+    CacheIo { path: PathBuf, message: String },
+
+    #[error("Pricing fetch failed: {0}")]
+    PricingFetch(#[from] crate::pricing::litellm::PricingFetchError),
+
     // ===================
     // Watch Errors
     // ===================
@@ -107,6 +126,59 @@ pub enum CoreError {
     CircuitBreakerOpen { operation: String, failures: u32 },
 }
 
+/// Failure kind of a [`CoreError`], for matching without the payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Io,
+    SessionParse,
+    SettingsParse,
+    OtherParse,
+    CacheIo,
+    PricingFetch,
+    Watch,
+    Store,
+    Config,
+    Timeout,
+}
+
+impl ErrorKind {
+    /// Whether ccboard keeps working (with less data) after this kind of failure
+    ///
+    /// Only a missing Claude home or an unusable config or store stop the load.
+    pub fn is_recoverable(self) -> bool {
+        !matches!(self, ErrorKind::Store | ErrorKind::Config)
+    }
+}
+
+impl CoreError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            CoreError::FileRead { .. }
+            | CoreError::FileWrite { .. }
+            | CoreError::FileNotFound { .. }
+            | CoreError::DirectoryNotFound { .. }
+            | CoreError::InvalidPath { .. } => ErrorKind::Io,
+            CoreError::JsonlParse { .. } => ErrorKind::SessionParse,
+            CoreError::SettingsParse { .. } => ErrorKind::SettingsParse,
+            CoreError::JsonParse { .. }
+            | CoreError::YamlParse { .. }
+            | CoreError::FrontmatterParse { .. } => ErrorKind::OtherParse,
+            CoreError::CacheIo { .. } => ErrorKind::CacheIo,
+            CoreError::PricingFetch(_) => ErrorKind::PricingFetch,
+            CoreError::WatchError { .. } => ErrorKind::Watch,
+            CoreError::StoreNotInitialized
+            | CoreError::SessionNotFound { .. }
+            | CoreError::LockTimeout => ErrorKind::Store,
+            CoreError::InvalidConfig { .. } | CoreError::ClaudeHomeNotFound => ErrorKind::Config,
+            CoreError::Timeout { .. } | CoreError::CircuitBreakerOpen { .. } => ErrorKind::Timeout,
+        }
+    }
+
+    pub fn is_recoverable(&self) -> bool {
+        self.kind().is_recoverable()
+    }
+}
+
 /// Severity level for errors during load
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ErrorSeverity {
@@ -126,6 +198,8 @@ pub struct LoadError {
     pub severity: ErrorSeverity,
     /// Actionable suggestion for user (optional)
     pub suggestion: Option<String>,
+    /// Failure kind, when built from a [`CoreError`]
+    pub kind: Option<ErrorKind>,
 }
 
 impl LoadError {
@@ -135,6 +209,7 @@ impl LoadError {
             message: message.into(),
             severity: ErrorSeverity::Warning,
             suggestion: None,
+            kind: None,
         }
     }
 
@@ -144,6 +219,7 @@ impl LoadError {
             message: message.into(),
             severity: ErrorSeverity::Error,
             suggestion: None,
+            kind: None,
         }
     }
 
@@ -153,6 +229,7 @@ impl LoadError {
             message: message.into(),
             severity: ErrorSeverity::Fatal,
             suggestion: None,
+            kind: None,
         }
     }
 
@@ -199,6 +276,31 @@ impl LoadError {
                     path.display()
                 )),
             ),
+            CoreError::SettingsParse {
+                path, line, column, ..
+            } => (
+                format!(
+                    "{} is invalid JSON at line {}, column {}",
+                    file_name(path),
+                    line,
+                    column
+                ),
+                Some(format!(
+                    "Fix the syntax, defaults are used meanwhile: jq . {}",
+                    path.display()
+                )),
+            ),
+            CoreError::CacheIo { path, message } => (
+                format!("Metadata cache unavailable: {}", message),
+                Some(format!(
+                    "Running without cache (slower startup); check {} is writable",
+                    path.display()
+                )),
+            ),
+            CoreError::PricingFetch(e) => (
+                format!("Pricing fetch failed: {}", e),
+                Some("Embedded prices are used; retry with: ccboard pricing update".to_string()),
+            ),
             CoreError::ClaudeHomeNotFound => (
                 "Claude home directory not found".to_string(),
                 Some("Run 'claude' CLI at least once to initialize ~/.claude".to_string()),
@@ -206,11 +308,17 @@ impl LoadError {
             _ => (error.to_string(), None),
         };
 
+        let kind = error.kind();
         Self {
             source,
             message,
-            severity: ErrorSeverity::Error,
+            severity: if kind.is_recoverable() {
+                ErrorSeverity::Error
+            } else {
+                ErrorSeverity::Fatal
+            },
             suggestion,
+            kind: Some(kind),
         }
     }
 }
@@ -226,6 +334,8 @@ pub struct LoadReport {
     pub settings_loaded: bool,
     pub sessions_scanned: usize,
     pub sessions_failed: usize,
+    /// Unparseable JSONL lines skipped across all session files
+    pub malformed_lines: u64,
 }
 
 impl LoadReport {
@@ -245,6 +355,32 @@ impl LoadReport {
         self.errors.push(LoadError::fatal(source, message));
     }
 
+    /// Record `count` skipped lines of a session file as a warning
+    ///
+    /// No-op for a clean file.
+    pub fn add_malformed_lines(&mut self, path: &std::path::Path, count: u64) {
+        if count == 0 {
+            return;
+        }
+        self.malformed_lines += count;
+        self.add_error(
+            LoadError::warning(
+                format!("session:{}", path.display()),
+                format!(
+                    "{} malformed line{} skipped, data may be incomplete",
+                    count,
+                    if count == 1 { "" } else { "s" }
+                ),
+            )
+            .with_suggestion(format!("Validate lines with: jq -c . {}", path.display())),
+        );
+    }
+
+    /// Errors of the given kind
+    pub fn errors_of(&self, kind: ErrorKind) -> impl Iterator<Item = &LoadError> {
+        self.errors.iter().filter(move |e| e.kind == Some(kind))
+    }
+
     /// Returns true if there are any fatal errors
     pub fn has_fatal_errors(&self) -> bool {
         self.errors
@@ -291,9 +427,17 @@ impl LoadReport {
         self.settings_loaded = self.settings_loaded || other.settings_loaded;
         self.sessions_scanned += other.sessions_scanned;
         self.sessions_failed += other.sessions_failed;
+        self.malformed_lines += other.malformed_lines;
     }
 }
 
+/// File name of `path` for messages, falling back to the full path
+fn file_name(path: &std::path::Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
 /// Degraded state indicator for the data store
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DegradedState {
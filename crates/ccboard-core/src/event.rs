@@ -3,6 +3,7 @@
 //! Provides a publish-subscribe mechanism for data updates.
 
 use crate::models::SessionId;
+use std::path::PathBuf;
 use tokio::sync::broadcast;
 
 /// Events emitted by the data layer
@@ -24,6 +25,44 @@ pub enum DataEvent {
     WatcherError(String),
     /// Hook-based live session status changed (live-sessions.json updated)
     LiveSessionStatusChanged,
+    /// A batch of watched files changed; published once per batch, after the
+    /// store has been updated
+    FilesChanged(Vec<PathBuf>),
+}
+
+impl DataEvent {
+    /// Stable snake_case name shared by SSE, WebSocket and `ccboard events`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DataEvent::StatsUpdated => "stats_updated",
+            DataEvent::SessionCreated(_) => "session_created",
+            DataEvent::SessionUpdated(_) => "session_updated",
+            DataEvent::ConfigChanged(_) => "config_changed",
+            DataEvent::AnalyticsUpdated => "analytics_updated",
+            DataEvent::LoadCompleted => "load_completed",
+            DataEvent::WatcherError(_) => "watcher_error",
+            DataEvent::LiveSessionStatusChanged => "live_session_status_changed",
+            DataEvent::FilesChanged(_) => "files_changed",
+        }
+    }
+
+    /// JSON payload sent alongside `kind()` (`{}` for events without data)
+    pub fn payload(&self) -> serde_json::Value {
+        use serde_json::json;
+
+        match self {
+            DataEvent::SessionCreated(id) | DataEvent::SessionUpdated(id) => {
+                json!({ "id": id.to_string() })
+            }
+            DataEvent::ConfigChanged(scope) => json!({ "scope": format!("{:?}", scope) }),
+            DataEvent::WatcherError(msg) => json!({ "message": msg }),
+            DataEvent::FilesChanged(paths) => json!({ "paths": paths }),
+            DataEvent::StatsUpdated
+            | DataEvent::AnalyticsUpdated
+            | DataEvent::LoadCompleted
+            | DataEvent::LiveSessionStatusChanged => json!({}),
+        }
+    }
 }
 
 /// Scope of configuration change
@@ -107,6 +146,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_event_kind_and_payload() {
+        let event = DataEvent::SessionUpdated(SessionId::from("abc"));
+        assert_eq!(event.kind(), "session_updated");
+        assert_eq!(event.payload(), serde_json::json!({ "id": "abc" }));
+
+        let event = DataEvent::WatcherError("boom".to_string());
+        assert_eq!(event.kind(), "watcher_error");
+        assert_eq!(event.payload()["message"], "boom");
+
+        assert_eq!(DataEvent::StatsUpdated.payload(), serde_json::json!({}));
+
+        let event = DataEvent::FilesChanged(vec![PathBuf::from("/h/.claude/stats-cache.json")]);
+        assert_eq!(event.kind(), "files_changed");
+        assert_eq!(
+            event.payload(),
+            serde_json::json!({ "paths": ["/h/.claude/stats-cache.json"] })
+        );
+    }
+
     #[tokio::test]
     async fn test_event_bus_multiple_subscribers() {
         let bus = EventBus::default_capacity();
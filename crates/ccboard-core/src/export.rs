@@ -3,14 +3,19 @@
 //! Provides simple, testable export with proper error handling.
 
 use anyhow::{Context, Result};
+use ccboard_types::Currency;
 use std::fs::File;
-use std::io::{BufWriter, Write};
+use std::io::{BufRead, BufReader, BufWriter, Write};
 use std::path::Path;
 use std::sync::Arc;
 
+use crate::analytics::{MetricDelta, PeriodComparison, UsagePatterns};
+use crate::graph::TaskGraph;
+use crate::models::plan::PhaseStatus;
 use crate::models::{
     BillingBlockManager, ConversationMessage, MessageRole, SessionMetadata, StatsCache,
 };
+use std::collections::HashMap;
 
 /// Export billing blocks to CSV format matching TUI table display
 ///
@@ -20,6 +25,7 @@ use crate::models::{
 /// # Arguments
 /// * `manager` - Reference to BillingBlockManager
 /// * `path` - Destination file path (created/overwritten)
+/// * `currency` - Display currency for the cost column
 ///
 /// # Errors
 /// Returns error if file creation or write operations fail
@@ -29,13 +35,18 @@ use crate::models::{
 /// ```no_run
 /// use ccboard_core::models::BillingBlockManager;
 /// use ccboard_core::export::export_billing_blocks_to_csv;
+/// use ccboard_types::Currency;
 /// use std::path::Path;
 ///
 /// let manager = BillingBlockManager::new();
 /// let path = Path::new("billing-blocks.csv");
-/// export_billing_blocks_to_csv(&manager, &path).unwrap();
+/// export_billing_blocks_to_csv(&manager, &path, &Currency::default()).unwrap();
 /// ```
-pub fn export_billing_blocks_to_csv(manager: &BillingBlockManager, path: &Path) -> Result<()> {
+pub fn export_billing_blocks_to_csv(
+    manager: &BillingBlockManager,
+    path: &Path,
+    currency: &Currency,
+) -> Result<()> {
     // Create parent directory if needed
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)
@@ -46,7 +57,15 @@ pub fn export_billing_blocks_to_csv(manager: &BillingBlockManager, path: &Path)
         .with_context(|| format!("Failed to create CSV file: {}", path.display()))?;
 
     let mut writer = BufWriter::new(file);
+    write_billing_blocks_csv(manager, &mut writer, currency)
+}
 
+/// Write the same CSV as `export_billing_blocks_to_csv` to any writer (e.g. stdout)
+pub fn write_billing_blocks_csv<W: Write>(
+    manager: &BillingBlockManager,
+    writer: &mut W,
+    currency: &Currency,
+) -> Result<()> {
     // Write header
     writeln!(writer, "Date,Block (UTC),Tokens,Sessions,Cost")
         .context("Failed to write CSV header")?;
@@ -59,12 +78,12 @@ pub fn export_billing_blocks_to_csv(manager: &BillingBlockManager, path: &Path)
     for (block, usage) in blocks {
         writeln!(
             writer,
-            "\"{}\",\"{}\",{},{},\"${:.3}\"",
+            "\"{}\",\"{}\",{},{},\"{}\"",
             block.date.format("%Y-%m-%d"), // "2026-02-03"
             block.label(),                 // "10:00-14:59"
             usage.total_tokens(),
             usage.session_count,
-            usage.total_cost
+            currency.format(usage.total_cost, 3)
         )
         .with_context(|| format!("Failed to write row for block {:?}", block))?;
     }
@@ -109,7 +128,14 @@ pub fn export_sessions_to_csv(sessions: &[Arc<SessionMetadata>], path: &Path) ->
         .with_context(|| format!("Failed to create CSV file: {}", path.display()))?;
 
     let mut writer = BufWriter::new(file);
+    write_sessions_csv(sessions, &mut writer)
+}
 
+/// Write the same CSV as `export_sessions_to_csv` to any writer (e.g. stdout)
+pub fn write_sessions_csv<W: Write>(
+    sessions: &[Arc<SessionMetadata>],
+    writer: &mut W,
+) -> Result<()> {
     // Write header
     writeln!(
         writer,
@@ -190,6 +216,18 @@ pub fn export_sessions_to_json(sessions: &[Arc<SessionMetadata>], path: &Path) -
             .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
     }
 
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create JSON file: {}", path.display()))?;
+
+    let mut writer = BufWriter::new(file);
+    write_sessions_json(sessions, &mut writer)
+}
+
+/// Write the same JSON as `export_sessions_to_json` to any writer (e.g. stdout)
+pub fn write_sessions_json<W: Write>(
+    sessions: &[Arc<SessionMetadata>],
+    writer: &mut W,
+) -> Result<()> {
     // Dereference Arc to get &SessionMetadata for serialization
     let sessions_ref: Vec<&SessionMetadata> = sessions.iter().map(|s| s.as_ref()).collect();
 
@@ -197,9 +235,45 @@ pub fn export_sessions_to_json(sessions: &[Arc<SessionMetadata>], path: &Path) -
     let json = serde_json::to_string_pretty(&sessions_ref)
         .context("Failed to serialize sessions to JSON")?;
 
-    // Write to file
-    std::fs::write(path, json)
-        .with_context(|| format!("Failed to write JSON file: {}", path.display()))?;
+    writer
+        .write_all(json.as_bytes())
+        .context("Failed to write JSON")?;
+    writer.flush().context("Failed to flush JSON writer")?;
+
+    Ok(())
+}
+
+/// Export sessions as JSON Lines (one compact JSON object per line)
+///
+/// Streams sessions through a `BufWriter` one at a time, so memory stays flat
+/// even for very large session sets. Field names match `export_sessions_to_json`.
+///
+/// # Errors
+/// Returns error if serialization or file write fails
+pub fn export_sessions_to_jsonl(sessions: &[Arc<SessionMetadata>], path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create JSONL file: {}", path.display()))?;
+
+    let mut writer = BufWriter::new(file);
+    write_sessions_jsonl(sessions, &mut writer)
+}
+
+/// Write the same JSON Lines as `export_sessions_to_jsonl` to any writer (e.g. stdout)
+pub fn write_sessions_jsonl<W: Write>(
+    sessions: &[Arc<SessionMetadata>],
+    writer: &mut W,
+) -> Result<()> {
+    for session in sessions {
+        serde_json::to_writer(&mut *writer, session.as_ref())
+            .with_context(|| format!("Failed to serialize session {}", session.id))?;
+        writer.write_all(b"\n").context("Failed to write JSONL")?;
+    }
+    writer.flush().context("Failed to flush JSONL writer")?;
 
     Ok(())
 }
@@ -235,12 +309,12 @@ pub fn export_stats_to_csv(stats: &StatsCache, path: &Path) -> Result<()> {
         .with_context(|| format!("Failed to create CSV file: {}", path.display()))?;
 
     let mut writer = BufWriter::new(file);
+    write_stats_csv(stats, &mut writer)
+}
 
-    writeln!(
-        writer,
-        "Model,Input Tokens,Output Tokens,Cache Read,Cache Write,Total Tokens,Cost (USD)"
-    )
-    .context("Failed to write CSV header")?;
+/// Write the same CSV as `export_stats_to_csv` to any writer (e.g. stdout)
+pub fn write_stats_csv<W: Write>(stats: &StatsCache, writer: &mut W) -> Result<()> {
+    writeln!(writer, "{}", STATS_CSV_HEADER).context("Failed to write CSV header")?;
 
     let mut models: Vec<_> = stats
         .model_usage
@@ -269,6 +343,88 @@ pub fn export_stats_to_csv(stats: &StatsCache, path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Header written by `write_stats_csv`
+const STATS_CSV_HEADER: &str =
+    "Model,Input Tokens,Output Tokens,Cache Read,Cache Write,Total Tokens,Cost (USD)";
+
+/// Rebuild a `StatsCache` from a CSV written by `export_stats_to_csv`
+///
+/// Only `model_usage` is populated (tokens and cost per model); the CSV carries
+/// no session, message or daily data. Lets stats exported on one machine be
+/// analyzed on another without `~/.claude`.
+pub fn import_stats_from_csv(path: &Path) -> Result<StatsCache> {
+    let file =
+        File::open(path).with_context(|| format!("Failed to open CSV file: {}", path.display()))?;
+    read_stats_csv(BufReader::new(file))
+        .with_context(|| format!("Failed to import stats from {}", path.display()))
+}
+
+/// Parse the per-model stats CSV from any reader (see `import_stats_from_csv`)
+pub fn read_stats_csv<R: BufRead>(reader: R) -> Result<StatsCache> {
+    let mut lines = reader.lines();
+    let header = lines
+        .next()
+        .transpose()
+        .context("Failed to read CSV header")?
+        .unwrap_or_default();
+    if header.trim_start_matches('\u{feff}').trim() != STATS_CSV_HEADER {
+        anyhow::bail!("Not a ccboard stats CSV (unexpected header: {:?})", header);
+    }
+
+    let mut stats = StatsCache::default();
+    for (index, line) in lines.enumerate() {
+        let line = line.context("Failed to read CSV row")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let row = index + 2; // 1-based, after the header
+        let fields = split_csv_line(&line);
+        if fields.len() != 7 {
+            anyhow::bail!("Row {}: expected 7 columns, found {}", row, fields.len());
+        }
+
+        let number = |column: usize| -> Result<u64> {
+            fields[column]
+                .trim()
+                .parse()
+                .with_context(|| format!("Row {}: invalid number {:?}", row, fields[column]))
+        };
+        let usage = stats.model_usage.entry(fields[0].clone()).or_default();
+        usage.input_tokens += number(1)?;
+        usage.output_tokens += number(2)?;
+        usage.cache_read_input_tokens += number(3)?;
+        usage.cache_creation_input_tokens += number(4)?;
+        usage.cost_usd += fields[6]
+            .trim()
+            .parse::<f64>()
+            .with_context(|| format!("Row {}: invalid cost {:?}", row, fields[6]))?;
+    }
+
+    Ok(stats)
+}
+
+/// Split one CSV line into fields, honoring double quotes (`""` escapes a quote)
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
 /// Export usage statistics to JSON format (full StatsCache)
 pub fn export_stats_to_json(stats: &StatsCache, path: &Path) -> Result<()> {
     if let Some(parent) = path.parent() {
@@ -276,10 +432,21 @@ pub fn export_stats_to_json(stats: &StatsCache, path: &Path) -> Result<()> {
             .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
     }
 
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create JSON file: {}", path.display()))?;
+
+    let mut writer = BufWriter::new(file);
+    write_stats_json(stats, &mut writer)
+}
+
+/// Write the same JSON as `export_stats_to_json` to any writer (e.g. stdout)
+pub fn write_stats_json<W: Write>(stats: &StatsCache, writer: &mut W) -> Result<()> {
     let json = serde_json::to_string_pretty(stats).context("Failed to serialize stats to JSON")?;
 
-    std::fs::write(path, json)
-        .with_context(|| format!("Failed to write JSON file: {}", path.display()))?;
+    writer
+        .write_all(json.as_bytes())
+        .context("Failed to write JSON")?;
+    writer.flush().context("Failed to flush JSON writer")?;
 
     Ok(())
 }
@@ -290,7 +457,11 @@ pub fn export_stats_to_json(stats: &StatsCache, path: &Path) -> Result<()> {
 /// - Summary totals (tokens, sessions, messages, cache ratio)
 /// - Per-model breakdown table
 /// - Daily activity for last 30 days
-pub fn export_stats_to_markdown(stats: &StatsCache, path: &Path) -> Result<()> {
+pub fn export_stats_to_markdown(
+    stats: &StatsCache,
+    path: &Path,
+    currency: &Currency,
+) -> Result<()> {
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)
             .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
@@ -300,7 +471,15 @@ pub fn export_stats_to_markdown(stats: &StatsCache, path: &Path) -> Result<()> {
         .with_context(|| format!("Failed to create Markdown file: {}", path.display()))?;
 
     let mut writer = BufWriter::new(file);
+    write_stats_markdown(stats, &mut writer, currency)
+}
 
+/// Write the same Markdown as `export_stats_to_markdown` to any writer (e.g. stdout)
+pub fn write_stats_markdown<W: Write>(
+    stats: &StatsCache,
+    writer: &mut W,
+    currency: &Currency,
+) -> Result<()> {
     writeln!(writer, "# Claude Code Statistics Report")?;
     writeln!(writer)?;
 
@@ -374,14 +553,14 @@ pub fn export_stats_to_markdown(stats: &StatsCache, path: &Path) -> Result<()> {
         for (name, usage) in &models {
             writeln!(
                 writer,
-                "| {} | {} | {} | {} | {} | {} | ${:.4} |",
+                "| {} | {} | {} | {} | {} | {} | {} |",
                 name,
                 fmt_num(usage.input_tokens),
                 fmt_num(usage.output_tokens),
                 fmt_num(usage.cache_read_input_tokens),
                 fmt_num(usage.cache_creation_input_tokens),
                 fmt_num(usage.total_tokens()),
-                usage.cost_usd
+                currency.format(usage.cost_usd, 4)
             )
             .with_context(|| format!("Failed to write row for model {}", name))?;
         }
@@ -428,7 +607,14 @@ pub fn export_sessions_to_markdown(sessions: &[Arc<SessionMetadata>], path: &Pat
         .with_context(|| format!("Failed to create Markdown file: {}", path.display()))?;
 
     let mut writer = BufWriter::new(file);
+    write_sessions_markdown(sessions, &mut writer)
+}
 
+/// Write the same Markdown as `export_sessions_to_markdown` to any writer (e.g. stdout)
+pub fn write_sessions_markdown<W: Write>(
+    sessions: &[Arc<SessionMetadata>],
+    writer: &mut W,
+) -> Result<()> {
     writeln!(writer, "# Session List")?;
     writeln!(writer)?;
     writeln!(writer, "**Total**: {} sessions", sessions.len())?;
@@ -494,6 +680,18 @@ pub fn export_billing_blocks_to_json(manager: &BillingBlockManager, path: &Path)
             .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
     }
 
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create JSON file: {}", path.display()))?;
+
+    let mut writer = BufWriter::new(file);
+    write_billing_blocks_json(manager, &mut writer)
+}
+
+/// Write the same JSON as `export_billing_blocks_to_json` to any writer (e.g. stdout)
+pub fn write_billing_blocks_json<W: Write>(
+    manager: &BillingBlockManager,
+    writer: &mut W,
+) -> Result<()> {
     let mut blocks = manager.get_all_blocks();
     blocks.reverse(); // Most recent first
 
@@ -517,14 +715,20 @@ pub fn export_billing_blocks_to_json(manager: &BillingBlockManager, path: &Path)
     let json = serde_json::to_string_pretty(&json_array)
         .context("Failed to serialize billing blocks to JSON")?;
 
-    std::fs::write(path, json)
-        .with_context(|| format!("Failed to write JSON file: {}", path.display()))?;
+    writer
+        .write_all(json.as_bytes())
+        .context("Failed to write JSON")?;
+    writer.flush().context("Failed to flush JSON writer")?;
 
     Ok(())
 }
 
 /// Export billing blocks to Markdown table
-pub fn export_billing_blocks_to_markdown(manager: &BillingBlockManager, path: &Path) -> Result<()> {
+pub fn export_billing_blocks_to_markdown(
+    manager: &BillingBlockManager,
+    path: &Path,
+    currency: &Currency,
+) -> Result<()> {
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)
             .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
@@ -534,7 +738,15 @@ pub fn export_billing_blocks_to_markdown(manager: &BillingBlockManager, path: &P
         .with_context(|| format!("Failed to create Markdown file: {}", path.display()))?;
 
     let mut writer = BufWriter::new(file);
+    write_billing_blocks_markdown(manager, &mut writer, currency)
+}
 
+/// Write the same Markdown as `export_billing_blocks_to_markdown` to any writer (e.g. stdout)
+pub fn write_billing_blocks_markdown<W: Write>(
+    manager: &BillingBlockManager,
+    writer: &mut W,
+    currency: &Currency,
+) -> Result<()> {
     writeln!(writer, "# Billing Blocks Report")?;
     writeln!(writer)?;
     writeln!(writer, "| Date | Block (UTC) | Tokens | Sessions | Cost |")?;
@@ -546,12 +758,12 @@ pub fn export_billing_blocks_to_markdown(manager: &BillingBlockManager, path: &P
     for (block, usage) in &blocks {
         writeln!(
             writer,
-            "| {} | {} | {} | {} | ${:.3} |",
+            "| {} | {} | {} | {} | {} |",
             block.date.format("%Y-%m-%d"),
             block.label(),
             fmt_num(usage.total_tokens()),
             usage.session_count,
-            usage.total_cost
+            currency.format(usage.total_cost, 3)
         )
         .with_context(|| format!("Failed to write row for block {:?}", block))?;
     }
@@ -561,6 +773,267 @@ pub fn export_billing_blocks_to_markdown(manager: &BillingBlockManager, path: &P
     Ok(())
 }
 
+// ============================================================================
+// Period Comparison Export Functions
+// ============================================================================
+
+/// Format a metric delta percentage ("+12.5%", or "n/a" without baseline data)
+fn fmt_delta_pct(delta: &MetricDelta) -> String {
+    delta
+        .delta_pct
+        .map(|pct| format!("{:+.1}%", pct))
+        .unwrap_or_else(|| "n/a".to_string())
+}
+
+/// Export a period comparison to JSON (both periods, metrics, series and deltas)
+pub fn export_comparison_to_json(comparison: &PeriodComparison, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    let json = serde_json::to_string_pretty(&serde_json::json!({
+        "a": comparison.a,
+        "b": comparison.b,
+        "metrics_a": comparison.metrics_a,
+        "metrics_b": comparison.metrics_b,
+        "deltas": comparison.deltas(),
+    }))
+    .context("Failed to serialize period comparison to JSON")?;
+
+    std::fs::write(path, json)
+        .with_context(|| format!("Failed to write JSON file: {}", path.display()))?;
+
+    Ok(())
+}
+
+/// Export a period comparison to CSV
+///
+/// CSV columns: Metric, A, B, Delta, Delta %
+pub fn export_comparison_to_csv(comparison: &PeriodComparison, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create CSV file: {}", path.display()))?;
+    let mut writer = BufWriter::new(file);
+
+    writeln!(
+        writer,
+        "Metric,A ({}),B ({}),Delta,Delta %",
+        comparison.a.label, comparison.b.label
+    )
+    .context("Failed to write CSV header")?;
+    for delta in comparison.deltas() {
+        writeln!(
+            writer,
+            "{},{:.2},{:.2},{:.2},{}",
+            delta.metric,
+            delta.a,
+            delta.b,
+            delta.delta,
+            fmt_delta_pct(&delta)
+        )
+        .with_context(|| format!("Failed to write row for {}", delta.metric))?;
+    }
+
+    writer.flush().context("Failed to flush CSV writer")?;
+
+    Ok(())
+}
+
+/// Export a period comparison to a Markdown report
+pub fn export_comparison_to_markdown(
+    comparison: &PeriodComparison,
+    path: &Path,
+    currency: &Currency,
+) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create Markdown file: {}", path.display()))?;
+    let mut writer = BufWriter::new(file);
+
+    writeln!(
+        writer,
+        "# Period Comparison: {} vs {}",
+        comparison.a.label, comparison.b.label
+    )?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "- **A**: {} → {}",
+        comparison.a.start, comparison.a.end
+    )?;
+    writeln!(
+        writer,
+        "- **B**: {} → {}",
+        comparison.b.start, comparison.b.end
+    )?;
+    writeln!(writer)?;
+    writeln!(writer, "| Metric | A | B | Delta | Delta % |")?;
+    writeln!(writer, "|--------|---|---|-------|---------|")?;
+
+    for delta in comparison.deltas() {
+        let (a, b, d) = if delta.metric == "Cost" {
+            (
+                currency.format_cost(delta.a),
+                currency.format_cost(delta.b),
+                format!(
+                    "{}{}",
+                    if delta.delta < 0.0 { '-' } else { '+' },
+                    currency.format(delta.delta.abs(), 2)
+                ),
+            )
+        } else {
+            (
+                fmt_num(delta.a as u64),
+                fmt_num(delta.b as u64),
+                format!("{:+}", delta.delta as i64),
+            )
+        };
+        writeln!(
+            writer,
+            "| {} | {} | {} | {} | {} |",
+            delta.metric,
+            a,
+            b,
+            d,
+            fmt_delta_pct(&delta)
+        )
+        .with_context(|| format!("Failed to write row for {}", delta.metric))?;
+    }
+
+    writer.flush().context("Failed to flush Markdown writer")?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Activity Heatmap Export Functions
+// ============================================================================
+
+/// Weekday labels in heatmap row order (Mon-Sun, as in the TUI)
+const HEATMAP_WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Export the activity heatmap (Patterns view) to CSV
+///
+/// CSV columns: Weekday, Hour, Activity Count
+/// 168 rows (7 weekdays × 24 hours), Monday first, hours 0-23 in local time
+pub fn export_heatmap_to_csv(patterns: &UsagePatterns, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create CSV file: {}", path.display()))?;
+
+    let mut writer = BufWriter::new(file);
+    write_heatmap_csv(patterns, &mut writer)
+}
+
+/// Write the same CSV as `export_heatmap_to_csv` to any writer (e.g. stdout)
+pub fn write_heatmap_csv<W: Write>(patterns: &UsagePatterns, writer: &mut W) -> Result<()> {
+    writeln!(writer, "Weekday,Hour,Activity Count").context("Failed to write CSV header")?;
+
+    for (weekday, hours) in HEATMAP_WEEKDAYS.iter().zip(&patterns.activity_heatmap) {
+        for (hour, count) in hours.iter().enumerate() {
+            writeln!(writer, "{},{},{}", weekday, hour, count)
+                .with_context(|| format!("Failed to write row for {} {}:00", weekday, hour))?;
+        }
+    }
+
+    writer.flush().context("Failed to flush CSV writer")?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Task Graph Export Functions
+// ============================================================================
+
+/// Export the task dependency graph as Graphviz DOT
+///
+/// Nodes are labeled `ID: title` and filled by status (green = complete,
+/// amber = in progress, grey = future, white = unknown). Edges point from a
+/// task to the tasks it blocks. Render with `dot -Tpng graph.dot -o graph.png`.
+pub fn export_task_graph_to_dot(
+    graph: &TaskGraph,
+    statuses: &HashMap<String, PhaseStatus>,
+    path: &Path,
+) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create DOT file: {}", path.display()))?;
+
+    let mut writer = BufWriter::new(file);
+    write_task_graph_dot(graph, statuses, &mut writer)
+}
+
+/// Write the same DOT as `export_task_graph_to_dot` to any writer (e.g. stdout)
+pub fn write_task_graph_dot<W: Write>(
+    graph: &TaskGraph,
+    statuses: &HashMap<String, PhaseStatus>,
+    writer: &mut W,
+) -> Result<()> {
+    let mut dot = String::from("digraph tasks {\n");
+    dot.push_str("    rankdir=LR;\n");
+    dot.push_str("    node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+
+    let tasks = graph.tasks();
+    for task in &tasks {
+        let fill = match statuses.get(&task.id) {
+            Some(PhaseStatus::Complete) => "#bbf7d0",
+            Some(PhaseStatus::InProgress) => "#fde68a",
+            Some(PhaseStatus::Future) => "#e5e7eb",
+            None => "#ffffff",
+        };
+        let label = format!("{}: {}", task.id, task.title);
+        let mut attrs = format!("label={}, fillcolor=\"{}\"", dot_quote(&label), fill);
+        if let Some(description) = &task.description {
+            attrs.push_str(&format!(", tooltip={}", dot_quote(description)));
+        }
+        dot.push_str(&format!("    {} [{}];\n", dot_quote(&task.id), attrs));
+    }
+
+    for task in &tasks {
+        for dependent in graph.dependents(&task.id) {
+            dot.push_str(&format!(
+                "    {} -> {};\n",
+                dot_quote(&task.id),
+                dot_quote(&dependent)
+            ));
+        }
+    }
+    dot.push_str("}\n");
+
+    writer
+        .write_all(dot.as_bytes())
+        .context("Failed to write DOT")?;
+    writer.flush().context("Failed to flush DOT writer")?;
+
+    Ok(())
+}
+
+/// Quote a string as a DOT identifier
+fn dot_quote(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
 // ============================================================================
 // Conversation Export Functions
 // ============================================================================
@@ -603,7 +1076,36 @@ pub fn export_conversation_to_markdown(
         .with_context(|| format!("Failed to create Markdown file: {}", path.display()))?;
 
     let mut writer = BufWriter::new(file);
+    write_conversation_markdown(messages, metadata, &mut writer)
+}
 
+/// Render conversation as a Markdown string
+///
+/// Same layout as `export_conversation_to_markdown`, for the clipboard.
+///
+/// # Errors
+/// Returns error if formatting fails
+pub fn conversation_to_markdown(
+    messages: &[ConversationMessage],
+    metadata: &SessionMetadata,
+) -> Result<String> {
+    let mut buf = Vec::new();
+    write_conversation_markdown(messages, metadata, &mut buf)?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Write conversation as Markdown to any writer
+///
+/// Same layout as `export_conversation_to_markdown`, used for stdout output
+/// (`ccboard cat`) as well as file export.
+///
+/// # Errors
+/// Returns error if a write operation fails
+pub fn write_conversation_markdown<W: Write>(
+    messages: &[ConversationMessage],
+    metadata: &SessionMetadata,
+    writer: &mut W,
+) -> Result<()> {
     // Write header
     writeln!(writer, "# Session: {}", metadata.id)?;
     writeln!(writer, "**Project**: {}", metadata.project_path)?;
@@ -654,6 +1156,81 @@ pub fn export_conversation_to_markdown(
     Ok(())
 }
 
+/// Write conversation as plain text to any writer
+///
+/// Format: one `[role] timestamp` header per message followed by its content,
+/// without any Markdown markup (grep-friendly).
+///
+/// # Errors
+/// Returns error if a write operation fails
+pub fn write_conversation_text<W: Write>(
+    messages: &[ConversationMessage],
+    metadata: &SessionMetadata,
+    writer: &mut W,
+) -> Result<()> {
+    writeln!(writer, "Session: {}", metadata.id)?;
+    writeln!(writer, "Project: {}", metadata.project_path)?;
+    writeln!(writer)?;
+
+    for msg in messages {
+        let role = match msg.role {
+            MessageRole::User => "user",
+            MessageRole::Assistant => "assistant",
+            MessageRole::System => "system",
+        };
+
+        match msg.timestamp {
+            Some(ts) => writeln!(writer, "[{}] {}", role, ts.format("%Y-%m-%d %H:%M:%S"))?,
+            None => writeln!(writer, "[{}]", role)?,
+        }
+        writeln!(writer, "{}\n", msg.content)?;
+    }
+
+    writer.flush().context("Failed to flush text writer")?;
+
+    Ok(())
+}
+
+/// Write conversation as pretty-printed JSON to any writer
+///
+/// Same structure as `export_conversation_to_json`.
+///
+/// # Errors
+/// Returns error if serialization or write fails
+pub fn write_conversation_json<W: Write>(
+    messages: &[ConversationMessage],
+    metadata: &SessionMetadata,
+    writer: &mut W,
+) -> Result<()> {
+    serde_json::to_writer_pretty(&mut *writer, &conversation_json(messages, metadata))
+        .context("Failed to serialize conversation")?;
+    writeln!(writer)?;
+    writer.flush().context("Failed to flush JSON writer")?;
+
+    Ok(())
+}
+
+/// Structured JSON value shared by conversation JSON writers
+fn conversation_json(
+    messages: &[ConversationMessage],
+    metadata: &SessionMetadata,
+) -> serde_json::Value {
+    serde_json::json!({
+        "session_id": metadata.id,
+        "project_path": metadata.project_path,
+        "metadata": {
+            "first_timestamp": metadata.first_timestamp,
+            "last_timestamp": metadata.last_timestamp,
+            "message_count": metadata.message_count,
+            "total_tokens": metadata.total_tokens,
+            "models_used": metadata.models_used,
+            "duration_seconds": metadata.duration_seconds,
+            "branch": metadata.branch,
+        },
+        "messages": messages,
+    })
+}
+
 /// Export conversation to JSON format
 ///
 /// Format:
@@ -686,8 +1263,6 @@ pub fn export_conversation_to_json(
     metadata: &SessionMetadata,
     path: &Path,
 ) -> Result<()> {
-    use serde_json::json;
-
     // Create parent directories if needed
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)
@@ -695,20 +1270,7 @@ pub fn export_conversation_to_json(
     }
 
     // Build structured JSON
-    let export_data = json!({
-        "session_id": metadata.id,
-        "project_path": metadata.project_path,
-        "metadata": {
-            "first_timestamp": metadata.first_timestamp,
-            "last_timestamp": metadata.last_timestamp,
-            "message_count": metadata.message_count,
-            "total_tokens": metadata.total_tokens,
-            "models_used": metadata.models_used,
-            "duration_seconds": metadata.duration_seconds,
-            "branch": metadata.branch,
-        },
-        "messages": messages,
-    });
+    let export_data = conversation_json(messages, metadata);
 
     // Serialize to JSON (pretty print)
     let json_str =
@@ -1019,7 +1581,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let csv_path = temp_dir.path().join("test.csv");
 
-        export_billing_blocks_to_csv(&manager, &csv_path).unwrap();
+        export_billing_blocks_to_csv(&manager, &csv_path, &Currency::default()).unwrap();
 
         let contents = std::fs::read_to_string(&csv_path).unwrap();
         assert_eq!(contents, "Date,Block (UTC),Tokens,Sessions,Cost\n");
@@ -1039,7 +1601,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let csv_path = temp_dir.path().join("billing.csv");
 
-        export_billing_blocks_to_csv(&manager, &csv_path).unwrap();
+        export_billing_blocks_to_csv(&manager, &csv_path, &Currency::default()).unwrap();
 
         let contents = std::fs::read_to_string(&csv_path).unwrap();
         let lines: Vec<&str> = contents.lines().collect();
@@ -1051,13 +1613,34 @@ mod tests {
         assert!(lines[2].contains("10:00-14:59"));
     }
 
+    #[test]
+    fn test_export_labels_follow_block_duration() {
+        let mut manager = BillingBlockManager::new_with_duration(8);
+        let ts = Utc.with_ymd_and_hms(2026, 2, 3, 9, 0, 0).unwrap();
+        manager.add_usage(&ts, 1000, 500, 0, 0, 0.5);
+
+        let mut csv = Vec::new();
+        write_billing_blocks_csv(&manager, &mut csv, &Currency::default()).unwrap();
+        assert!(String::from_utf8(csv).unwrap().contains("08:00-15:59"));
+
+        let mut json = Vec::new();
+        write_billing_blocks_json(&manager, &mut json).unwrap();
+        assert!(String::from_utf8(json)
+            .unwrap()
+            .contains("\"block\": \"08:00-15:59\""));
+
+        let mut md = Vec::new();
+        write_billing_blocks_markdown(&manager, &mut md, &Currency::default()).unwrap();
+        assert!(String::from_utf8(md).unwrap().contains("| 08:00-15:59 |"));
+    }
+
     #[test]
     fn test_creates_parent_directory() {
         let manager = BillingBlockManager::new();
         let temp_dir = TempDir::new().unwrap();
         let nested_path = temp_dir.path().join("exports/nested/test.csv");
 
-        export_billing_blocks_to_csv(&manager, &nested_path).unwrap();
+        export_billing_blocks_to_csv(&manager, &nested_path, &Currency::default()).unwrap();
 
         assert!(nested_path.exists());
     }
@@ -1073,7 +1656,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let csv_path = temp_dir.path().join("cost.csv");
 
-        export_billing_blocks_to_csv(&manager, &csv_path).unwrap();
+        export_billing_blocks_to_csv(&manager, &csv_path, &Currency::default()).unwrap();
 
         let contents = std::fs::read_to_string(&csv_path).unwrap();
         assert!(contents.contains("\"$1.235\""));
@@ -1096,7 +1679,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let csv_path = temp_dir.path().join("sorted.csv");
 
-        export_billing_blocks_to_csv(&manager, &csv_path).unwrap();
+        export_billing_blocks_to_csv(&manager, &csv_path, &Currency::default()).unwrap();
 
         let contents = std::fs::read_to_string(&csv_path).unwrap();
         let lines: Vec<&str> = contents.lines().collect();
@@ -1132,13 +1715,23 @@ mod tests {
             first_user_message: Some("Test message".to_string()),
             has_subagents: false,
             parent_session_id: None,
+            resumed_from: None,
+            compaction_count: 0,
             duration_seconds: Some(2700),
             branch: None,
             tool_usage: std::collections::HashMap::new(),
             tool_token_usage: std::collections::HashMap::new(),
+            tool_followup_tokens: std::collections::HashMap::new(),
             source_tool: Default::default(),
             lines_added: 0,
             lines_removed: 0,
+            tool_result_bytes: 0,
+            peak_context_tokens: 0,
+            peak_context_pct: 0.0,
+            malformed_lines: 0,
+            estimated_extra_tokens: 0,
+            cwd: None,
+            author: None,
         }
     }
 
@@ -1232,6 +1825,155 @@ mod tests {
         assert_eq!(parsed[0].total_tokens, 15000);
     }
 
+    #[test]
+    fn test_write_task_graph_dot() {
+        use crate::models::plan::Task;
+
+        let mut graph = TaskGraph::new();
+        for (id, title) in [("F.1", "Design \"api\""), ("F.2", "Build")] {
+            graph.add_task(Task {
+                id: id.to_string(),
+                title: title.to_string(),
+                ..Default::default()
+            });
+        }
+        graph.add_dependency("F.1", "F.2").unwrap();
+        let statuses = HashMap::from([("F.1".to_string(), PhaseStatus::Complete)]);
+
+        let mut buf = Vec::new();
+        super::write_task_graph_dot(&graph, &statuses, &mut buf).unwrap();
+        let dot = String::from_utf8(buf).unwrap();
+
+        assert!(dot.starts_with("digraph tasks {"));
+        assert!(dot.contains(r##""F.1" [label="F.1: Design \"api\"", fillcolor="#bbf7d0"];"##));
+        assert!(dot.contains(r##""F.2" [label="F.2: Build", fillcolor="#ffffff"];"##));
+        assert!(dot.contains(r#""F.1" -> "F.2";"#));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_export_sessions_jsonl_matches_json_fields() {
+        let sessions = vec![
+            Arc::new(create_test_session("abc123", "/p1", 25, 15000)),
+            Arc::new(create_test_session("def456", "/p2", 3, 900)),
+        ];
+
+        let mut json = Vec::new();
+        super::write_sessions_json(&sessions, &mut json).unwrap();
+        let json: Vec<serde_json::Value> = serde_json::from_slice(&json).unwrap();
+
+        let mut jsonl = Vec::new();
+        super::write_sessions_jsonl(&sessions, &mut jsonl).unwrap();
+        let jsonl = String::from_utf8(jsonl).unwrap();
+
+        let lines: Vec<&str> = jsonl.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for (line, expected) in lines.iter().zip(&json) {
+            let parsed: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(&parsed, expected);
+        }
+        assert!(jsonl.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_write_sessions_matches_file_export() {
+        let sessions = vec![Arc::new(create_test_session("abc123", "/p", 3, 900))];
+        let temp_dir = TempDir::new().unwrap();
+
+        for (ext, export, write) in [
+            (
+                "csv",
+                super::export_sessions_to_csv as fn(&[Arc<SessionMetadata>], &Path) -> Result<()>,
+                super::write_sessions_csv::<Vec<u8>>
+                    as fn(&[Arc<SessionMetadata>], &mut Vec<u8>) -> Result<()>,
+            ),
+            (
+                "json",
+                super::export_sessions_to_json,
+                super::write_sessions_json::<Vec<u8>>,
+            ),
+            (
+                "jsonl",
+                super::export_sessions_to_jsonl,
+                super::write_sessions_jsonl::<Vec<u8>>,
+            ),
+            (
+                "md",
+                super::export_sessions_to_markdown,
+                super::write_sessions_markdown::<Vec<u8>>,
+            ),
+        ] {
+            let path = temp_dir.path().join(format!("sessions.{}", ext));
+            export(&sessions, &path).unwrap();
+            let mut buf = Vec::new();
+            write(&sessions, &mut buf).unwrap();
+            assert_eq!(std::fs::read(&path).unwrap(), buf, "{} output differs", ext);
+        }
+    }
+
+    #[test]
+    fn test_stats_csv_round_trip() {
+        use crate::models::ModelUsage;
+
+        let mut stats = StatsCache::default();
+        stats.model_usage.insert(
+            "claude-sonnet-4-5-20250929".to_string(),
+            ModelUsage {
+                input_tokens: 12_345,
+                output_tokens: 6_789,
+                cache_read_input_tokens: 1_000_000,
+                cache_creation_input_tokens: 42_000,
+                cost_usd: 1.234_567,
+                ..Default::default()
+            },
+        );
+        stats.model_usage.insert(
+            "claude-haiku-4-5".to_string(),
+            ModelUsage {
+                input_tokens: 10,
+                output_tokens: 5,
+                cost_usd: 0.000_125,
+                ..Default::default()
+            },
+        );
+
+        let temp_dir = TempDir::new().unwrap();
+        let csv_path = temp_dir.path().join("stats.csv");
+        export_stats_to_csv(&stats, &csv_path).unwrap();
+        let imported = import_stats_from_csv(&csv_path).unwrap();
+
+        assert_eq!(imported.model_usage.len(), 2);
+        for (model, original) in &stats.model_usage {
+            let usage = &imported.model_usage[model];
+            assert_eq!(usage.input_tokens, original.input_tokens);
+            assert_eq!(usage.output_tokens, original.output_tokens);
+            assert_eq!(
+                usage.cache_read_input_tokens,
+                original.cache_read_input_tokens
+            );
+            assert_eq!(
+                usage.cache_creation_input_tokens,
+                original.cache_creation_input_tokens
+            );
+            assert!((usage.cost_usd - original.cost_usd).abs() < 1e-6);
+        }
+        assert_eq!(imported.total_tokens(), stats.total_tokens());
+    }
+
+    #[test]
+    fn test_read_stats_csv_rejects_other_csv() {
+        let sessions_csv = "Date,Time,Project,Session ID,Messages,Tokens,Models,Duration (min)\n";
+        assert!(read_stats_csv(sessions_csv.as_bytes()).is_err());
+
+        let bad_row = format!("{}\n\"opus\",1,2\n", STATS_CSV_HEADER);
+        let err = read_stats_csv(bad_row.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("Row 2"));
+
+        let quoted = format!("{}\n\"a,\"\"b\"\"\",1,2,3,4,3,\"0.5\"\n", STATS_CSV_HEADER);
+        let stats = read_stats_csv(quoted.as_bytes()).unwrap();
+        assert_eq!(stats.model_usage["a,\"b\""].cache_creation_input_tokens, 4);
+    }
+
     #[test]
     fn test_export_sessions_creates_dirs() {
         let sessions = vec![Arc::new(create_test_session("test", "/test", 1, 100))];
@@ -1291,6 +2033,29 @@ mod tests {
         assert!(contents.contains("## Assistant (claude-sonnet-4-5-20250929)"));
         assert!(contents.contains("Sure! I'd be happy to help."));
         assert!(contents.contains("*Tokens: 100 input, 50 output*"));
+
+        let rendered = super::conversation_to_markdown(&messages, &metadata).unwrap();
+        assert_eq!(rendered, contents);
+    }
+
+    #[test]
+    fn test_write_conversation_text_and_json() {
+        let messages = create_test_messages();
+        let metadata = create_test_session("test-conv", "/test/project", 2, 150);
+
+        let mut text = Vec::new();
+        super::write_conversation_text(&messages, &metadata, &mut text).unwrap();
+        let text = String::from_utf8(text).unwrap();
+        assert!(text.starts_with("Session: test-conv"));
+        assert!(text.contains("[user]"));
+        assert!(text.contains("Hello, can you help me with Rust?"));
+        assert!(!text.contains("## "));
+
+        let mut json = Vec::new();
+        super::write_conversation_json(&messages, &metadata, &mut json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(parsed["session_id"], "test-conv");
+        assert_eq!(parsed["messages"].as_array().unwrap().len(), 2);
     }
 
     #[test]
@@ -1430,4 +2195,82 @@ mod tests {
         // syntect produces inline-style highlighted HTML inside the pre block
         assert!(contents.contains("<pre"));
     }
+
+    #[test]
+    fn test_export_comparison_without_baseline() {
+        use crate::analytics::{compare_periods, DateRange};
+
+        let mut session = create_test_session("cmp", "/test", 4, 1000);
+        session.first_timestamp = Some(Utc.with_ymd_and_hms(2026, 2, 10, 12, 0, 0).unwrap());
+        let comparison = compare_periods(
+            &[Arc::new(session)],
+            &DateRange::parse("2026-01").unwrap(),
+            &DateRange::parse("2026-02").unwrap(),
+        );
+
+        let temp_dir = TempDir::new().unwrap();
+        let md_path = temp_dir.path().join("comparison.md");
+        super::export_comparison_to_markdown(&comparison, &md_path, &Currency::default()).unwrap();
+        let md = std::fs::read_to_string(&md_path).unwrap();
+        assert!(md.contains("# Period Comparison: 2026-01 vs 2026-02"));
+        assert!(md.contains("| Sessions | 0 | 1 | +1 | n/a |"));
+        assert!(!md.contains("NaN"));
+
+        let json_path = temp_dir.path().join("comparison.json");
+        super::export_comparison_to_json(&comparison, &json_path).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+        assert_eq!(json["metrics_b"]["sessions"], 1);
+        assert!(json["deltas"][0]["delta_pct"].is_null());
+    }
+
+    #[test]
+    fn test_export_comparison_cost_in_currency() {
+        use crate::analytics::{compare_periods, DateRange};
+
+        let mut session = create_test_session("cmp", "/test", 4, 1_000_000);
+        session.first_timestamp = Some(Utc.with_ymd_and_hms(2026, 1, 10, 12, 0, 0).unwrap());
+        let comparison = compare_periods(
+            &[Arc::new(session)],
+            &DateRange::parse("2026-01").unwrap(),
+            &DateRange::parse("2026-02").unwrap(),
+        );
+        let cost = comparison.metrics_a.cost;
+        assert!(cost > 0.0);
+
+        let euro = Currency {
+            symbol: "€".to_string(),
+            rate: 2.0,
+        };
+        let temp_dir = TempDir::new().unwrap();
+        let md_path = temp_dir.path().join("comparison.md");
+        super::export_comparison_to_markdown(&comparison, &md_path, &euro).unwrap();
+        let md = std::fs::read_to_string(&md_path).unwrap();
+        assert!(md.contains(&format!(
+            "| Cost | €{:.2} | €0.00 | -€{:.2} |",
+            cost * 2.0,
+            cost * 2.0
+        )));
+        assert!(!md.contains('$'));
+    }
+
+    #[test]
+    fn test_export_heatmap_to_csv() {
+        let mut patterns = UsagePatterns::empty();
+        patterns.activity_heatmap[0][9] = 4; // Mon 09:00
+        patterns.activity_heatmap[6][23] = 2; // Sun 23:00
+
+        let temp_dir = TempDir::new().unwrap();
+        let csv_path = temp_dir.path().join("heatmap.csv");
+        export_heatmap_to_csv(&patterns, &csv_path).unwrap();
+
+        let contents = std::fs::read_to_string(&csv_path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 1 + 7 * 24);
+        assert_eq!(lines[0], "Weekday,Hour,Activity Count");
+        assert_eq!(lines[1], "Mon,0,0");
+        assert_eq!(lines[10], "Mon,9,4");
+        assert_eq!(lines[25], "Tue,0,0");
+        assert_eq!(lines[168], "Sun,23,2");
+    }
 }
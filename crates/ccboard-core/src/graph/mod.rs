@@ -1,5 +1,7 @@
 //! Graph algorithms and data structures
 
+pub mod session_chain;
 pub mod task_dag;
 
+pub use session_chain::{build_session_chains, ChainLink, ChainNode, SessionChain};
 pub use task_dag::{DependencyEdge, TaskGraph};
@@ -0,0 +1,251 @@
+//! Session continuation chains
+//!
+//! A task often spans several session files: the user resumes a conversation
+//! (`claude --resume`) or starts a fresh one right after the previous ended.
+//! Chains link those sessions into a tree per project:
+//! - **Resumed**: the file records the session it resumed (`resumed_from`)
+//! - **Inferred**: no explicit link, but the session started in the same
+//!   project (and branch, when known) shortly after another one ended
+//!
+//! Subagent sessions are left out; they hang off their parent separately.
+
+use crate::models::SessionMetadata;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Largest gap between a session's end and the next one's start for the
+/// two to be treated as one task
+pub const INFERRED_CONTINUATION_GAP_MINUTES: i64 = 30;
+
+/// How a session is linked to its parent in a chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainLink {
+    /// The session file records the session it resumed
+    Resumed,
+    /// Guessed from project, branch and timing
+    Inferred,
+}
+
+/// A session within a chain
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChainNode {
+    pub session_id: String,
+    /// `None` for the chain root
+    pub parent_id: Option<String>,
+    /// How this session continues its parent (`None` for the root)
+    pub link: Option<ChainLink>,
+    /// Distance from the root
+    pub depth: usize,
+}
+
+/// Sessions that continue one another, as a tree
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionChain {
+    pub project: String,
+    /// Nodes in depth-first order, siblings sorted by start time; the root
+    /// comes first
+    pub nodes: Vec<ChainNode>,
+    pub first_timestamp: Option<DateTime<Utc>>,
+    pub last_timestamp: Option<DateTime<Utc>>,
+}
+
+impl SessionChain {
+    /// ID of the first session of the chain
+    pub fn root_id(&self) -> &str {
+        &self.nodes[0].session_id
+    }
+
+    /// Number of sessions in the chain (always at least 2)
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Always `false`: single sessions do not form a chain
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Whether `session_id` is part of this chain
+    pub fn contains(&self, session_id: &str) -> bool {
+        self.nodes.iter().any(|n| n.session_id == session_id)
+    }
+
+    /// Whether any link of the chain was inferred rather than recorded
+    pub fn has_inferred_links(&self) -> bool {
+        self.nodes
+            .iter()
+            .any(|n| n.link == Some(ChainLink::Inferred))
+    }
+}
+
+/// Group sessions into continuation chains, most recently active first
+///
+/// Only chains of two or more sessions are returned.
+pub fn build_session_chains(sessions: &[Arc<SessionMetadata>]) -> Vec<SessionChain> {
+    let mut candidates: Vec<&Arc<SessionMetadata>> = sessions
+        .iter()
+        .filter(|s| s.parent_session_id.is_none() && s.first_timestamp.is_some())
+        .collect();
+    candidates.sort_by_key(|s| s.first_timestamp);
+
+    let by_id: HashMap<&str, &Arc<SessionMetadata>> =
+        candidates.iter().map(|s| (s.id.as_str(), *s)).collect();
+
+    // child ID → (parent ID, link)
+    let mut parents: HashMap<&str, (&str, ChainLink)> = HashMap::new();
+    for session in &candidates {
+        if let Some(parent) = session.resumed_from.as_deref() {
+            if parent != session.id.as_str() && by_id.contains_key(parent) {
+                parents.insert(session.id.as_str(), (parent, ChainLink::Resumed));
+            }
+        }
+    }
+
+    // Inferred links: per project, attach each unlinked session to the
+    // closest earlier session that ended within the gap and has no
+    // continuation yet
+    let max_gap = Duration::minutes(INFERRED_CONTINUATION_GAP_MINUTES);
+    let mut continued: HashSet<&str> = parents.values().map(|(p, _)| *p).collect();
+    for (i, session) in candidates.iter().enumerate() {
+        if parents.contains_key(session.id.as_str()) {
+            continue;
+        }
+        let Some(start) = session.first_timestamp else {
+            continue;
+        };
+        let previous = candidates[..i]
+            .iter()
+            .filter(|prev| {
+                prev.project_path == session.project_path
+                    && !continued.contains(prev.id.as_str())
+                    && !(prev.branch.is_some()
+                        && session.branch.is_some()
+                        && prev.branch != session.branch)
+            })
+            .filter_map(|prev| {
+                let gap = start - prev.last_timestamp?;
+                (gap >= Duration::zero() && gap <= max_gap).then_some((gap, prev))
+            })
+            .min_by_key(|(gap, _)| *gap);
+        if let Some((_, prev)) = previous {
+            parents.insert(session.id.as_str(), (prev.id.as_str(), ChainLink::Inferred));
+            continued.insert(prev.id.as_str());
+        }
+    }
+
+    // Children lists keep the start-time order of `candidates`
+    let mut children: HashMap<&str, Vec<(&str, ChainLink)>> = HashMap::new();
+    for session in &candidates {
+        if let Some((parent, link)) = parents.get(session.id.as_str()) {
+            children
+                .entry(parent)
+                .or_default()
+                .push((session.id.as_str(), *link));
+        }
+    }
+
+    let mut chains = Vec::new();
+    for root in candidates
+        .iter()
+        .filter(|s| !parents.contains_key(s.id.as_str()) && children.contains_key(s.id.as_str()))
+    {
+        let mut nodes = Vec::new();
+        let mut stack: Vec<(&str, Option<&str>, Option<ChainLink>, usize)> =
+            vec![(root.id.as_str(), None, None, 0)];
+        while let Some((id, parent_id, link, depth)) = stack.pop() {
+            nodes.push(ChainNode {
+                session_id: id.to_string(),
+                parent_id: parent_id.map(str::to_string),
+                link,
+                depth,
+            });
+            if let Some(kids) = children.get(id) {
+                for (child, link) in kids.iter().rev() {
+                    stack.push((child, Some(id), Some(*link), depth + 1));
+                }
+            }
+        }
+
+        let members = nodes
+            .iter()
+            .filter_map(|n| by_id.get(n.session_id.as_str()));
+        let last_timestamp = members.clone().filter_map(|s| s.last_timestamp).max();
+        chains.push(SessionChain {
+            project: root.project_path.as_str().to_string(),
+            first_timestamp: members.filter_map(|s| s.first_timestamp).min(),
+            last_timestamp,
+            nodes,
+        });
+    }
+
+    chains.sort_by_key(|c| std::cmp::Reverse(c.last_timestamp));
+    chains
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::session::ProjectId;
+    use chrono::TimeZone;
+    use std::path::PathBuf;
+
+    fn session(
+        id: &str,
+        project: &str,
+        start_min: i64,
+        end_min: i64,
+        resumed_from: Option<&str>,
+    ) -> Arc<SessionMetadata> {
+        let base = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        let mut meta = SessionMetadata::from_path(
+            PathBuf::from(format!("/tmp/{id}.jsonl")),
+            ProjectId::from(project),
+        );
+        meta.first_timestamp = Some(base + Duration::minutes(start_min));
+        meta.last_timestamp = Some(base + Duration::minutes(end_min));
+        meta.resumed_from = resumed_from.map(str::to_string);
+        Arc::new(meta)
+    }
+
+    #[test]
+    fn test_build_session_chains() {
+        let sessions = vec![
+            session("a", "/p", 0, 60, None),
+            // Resumed twice from the same session: branches of one tree
+            session("b", "/p", 120, 150, Some("a")),
+            session("c", "/p", 300, 320, Some("a")),
+            // Started 10 minutes after b ended: inferred continuation of b
+            session("d", "/p", 160, 200, None),
+            // Other project, same timing: not linked to anything
+            session("x", "/other", 65, 90, None),
+            // Resumes a session that isn't loaded: stays alone
+            session("y", "/other", 500, 510, Some("gone")),
+        ];
+
+        let chains = build_session_chains(&sessions);
+        assert_eq!(chains.len(), 1);
+        let chain = &chains[0];
+        assert_eq!(chain.root_id(), "a");
+        assert!(chain.has_inferred_links());
+
+        let order: Vec<(&str, usize, Option<ChainLink>)> = chain
+            .nodes
+            .iter()
+            .map(|n| (n.session_id.as_str(), n.depth, n.link))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("a", 0, None),
+                ("b", 1, Some(ChainLink::Resumed)),
+                ("d", 2, Some(ChainLink::Inferred)),
+                ("c", 1, Some(ChainLink::Resumed)),
+            ]
+        );
+        assert_eq!(chain.last_timestamp, sessions[2].last_timestamp);
+        assert!(!chain.contains("x"));
+    }
+}
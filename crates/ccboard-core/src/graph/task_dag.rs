@@ -46,7 +46,7 @@
 //! assert_eq!(critical, vec!["T1", "T2"]); // Both on critical path
 //! ```
 
-use crate::models::plan::Task;
+use crate::models::plan::{PlanFile, Task};
 use anyhow::{Context, Result};
 use petgraph::graph::{DiGraph, NodeIndex};
 use petgraph::visit::EdgeRef;
@@ -83,6 +83,58 @@ impl TaskGraph {
         }
     }
 
+    /// Build a graph from a parsed PLAN.md
+    ///
+    /// Adds every task of every phase, then reads `Depends on: F.1, F.2` lines
+    /// from each task's section in the raw `content` to add dependency edges.
+    /// Dependencies on unknown task IDs are ignored.
+    pub fn from_plan(plan: &PlanFile, content: &str) -> Self {
+        let mut graph = Self::new();
+
+        for phase in &plan.phases {
+            for task in &phase.tasks {
+                graph.add_task(task.clone());
+            }
+        }
+
+        for phase in &plan.phases {
+            for task in &phase.tasks {
+                let task_header = format!("#### Task {}:", task.id);
+                let Some(pos) = content.find(&task_header) else {
+                    continue;
+                };
+
+                // Extract content until next task or section
+                let rest = &content[pos..];
+                // Use char boundary-safe slicing
+                let skip_offset = rest
+                    .char_indices()
+                    .nth(100)
+                    .map(|(i, _)| i)
+                    .unwrap_or(rest.len());
+                let end_pos = rest
+                    .get(skip_offset..)
+                    .and_then(|s| s.find("\n####").map(|p| p + skip_offset))
+                    .or_else(|| {
+                        rest.get(skip_offset..)
+                            .and_then(|s| s.find("\n###").map(|p| p + skip_offset))
+                    })
+                    .or_else(|| {
+                        rest.get(skip_offset..)
+                            .and_then(|s| s.find("\n##").map(|p| p + skip_offset))
+                    })
+                    .unwrap_or(rest.len());
+
+                for dep_id in extract_dependencies(&rest[..end_pos]) {
+                    // Add dependency: dep_id blocks task_id
+                    let _ = graph.add_dependency(&dep_id, &task.id);
+                }
+            }
+        }
+
+        graph
+    }
+
     /// Add a task to the graph
     ///
     /// Returns the node index for the added task.
@@ -291,6 +343,37 @@ impl TaskGraph {
     }
 }
 
+/// Extract task dependencies from a task's PLAN.md section
+///
+/// Recognizes `Depends on: F.1, F.2` (letter.number IDs). Numeric references
+/// such as `Blocked by: #6` are skipped since they need an issue-to-task mapping.
+fn extract_dependencies(description: &str) -> Vec<String> {
+    let mut deps = Vec::new();
+
+    if let Some(start) = description.find("Depends on:") {
+        let rest = &description[start + "Depends on:".len()..];
+        // Take until newline or end
+        let dep_text = rest.lines().next().unwrap_or("");
+
+        for part in dep_text.split(',') {
+            let trimmed = part.trim();
+            // Match pattern: Letter.Number (e.g., F.1, H.2)
+            if trimmed
+                .chars()
+                .next()
+                .map(|c| c.is_alphabetic())
+                .unwrap_or(false)
+            {
+                if let Some(task_id) = trimmed.split_whitespace().next() {
+                    deps.push(task_id.to_string());
+                }
+            }
+        }
+    }
+
+    deps
+}
+
 impl Default for TaskGraph {
     fn default() -> Self {
         Self::new()
@@ -374,6 +457,39 @@ mod tests {
         assert_eq!(cycles[0].len(), 2);
     }
 
+    #[test]
+    fn test_from_plan_reads_dependencies() {
+        use crate::models::plan::{Phase, PhaseStatus, PlanMetadata};
+
+        let plan = PlanFile {
+            metadata: PlanMetadata::default(),
+            phases: vec![Phase {
+                id: "F".to_string(),
+                title: "Foundation".to_string(),
+                status: PhaseStatus::InProgress,
+                tasks: vec![
+                    create_test_task("F.1", "Design", None),
+                    create_test_task("F.2", "Build", None),
+                ],
+                estimated_duration: None,
+                priority: None,
+                version_target: None,
+            }],
+        };
+        // Sections are searched for the next header only after their first
+        // 100 chars, so give each task a realistic body.
+        let body = "Lorem ipsum dolor sit amet. ".repeat(5);
+        let content = format!(
+            "#### Task F.1: Design\n{body}\n\n#### Task F.2: Build\n{body}\nDepends on: F.1, X.9\n"
+        );
+
+        let graph = TaskGraph::from_plan(&plan, &content);
+
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph.dependencies("F.2"), vec!["F.1"]);
+        assert!(graph.dependencies("F.1").is_empty());
+    }
+
     #[test]
     fn test_parse_duration() {
         assert_eq!(TaskGraph::parse_duration(&Some("3-4h".to_string())), 3.0);
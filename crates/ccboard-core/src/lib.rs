@@ -14,11 +14,18 @@ pub mod hook_state;
 pub mod live_monitor;
 pub mod models;
 pub mod parsers;
+mod persist;
+pub mod pins;
 pub mod preferences;
 pub mod pricing;
+pub mod project_display;
+pub mod project_filter;
 pub mod quota;
+pub mod resume_log;
+pub mod search_index;
 pub mod store;
 pub mod summaries;
+pub mod tags;
 pub mod usage_estimator;
 pub mod watcher;
 
@@ -29,25 +36,32 @@ pub use analytics::{
     SuggestionCategory, TrendDirection, TrendsData, UsagePatterns,
 };
 pub use cache::{AggregateStats, InsightsDb, SearchResult, StoredAlert};
-pub use error::{CoreError, DegradedState, LoadReport};
+pub use error::{CoreError, DegradedState, ErrorKind, LoadReport};
 pub use event::{DataEvent, EventBus};
 pub use export::{
     export_billing_blocks_to_csv, export_billing_blocks_to_json, export_billing_blocks_to_markdown,
-    export_sessions_to_csv, export_sessions_to_json, export_sessions_to_markdown,
-    export_stats_to_csv, export_stats_to_json, export_stats_to_markdown,
+    export_comparison_to_csv, export_comparison_to_json, export_comparison_to_markdown,
+    export_heatmap_to_csv, export_sessions_to_csv, export_sessions_to_json,
+    export_sessions_to_jsonl, export_sessions_to_markdown, export_stats_to_csv,
+    export_stats_to_json, export_stats_to_markdown, export_task_graph_to_dot,
+    import_stats_from_csv, read_stats_csv, write_billing_blocks_csv, write_billing_blocks_json,
+    write_billing_blocks_markdown, write_heatmap_csv, write_sessions_csv, write_sessions_json,
+    write_sessions_jsonl, write_sessions_markdown, write_stats_csv, write_stats_json,
+    write_stats_markdown, write_task_graph_dot,
 };
 pub use hook_event::{status_from_event, HookPayload};
 pub use hook_state::{
     make_session_key, HookSession, HookSessionStatus, LiveSessionFile, SessionKey,
 };
 pub use live_monitor::{
-    detect_live_sessions, merge_live_sessions, LiveMonitorState, LiveSession,
-    LiveSessionDisplayStatus, MergedLiveSession, SessionType,
+    detect_live_sessions, detect_live_sessions_with, merge_live_sessions, LiveMonitorConfig,
+    LiveMonitorState, LiveSession, LiveSessionDisplayStatus, MergedLiveSession, SessionType,
 };
 pub use models::activity::{
     ActivitySummary, Alert, AlertCategory, AlertSeverity, BashCommand, FileAccess, FileOperation,
     NetworkCall, NetworkTool, ToolCall as ActivityToolCall,
 };
+pub use project_display::ProjectDisplay;
 pub use quota::{calculate_quota_status, AlertLevel, QuotaStatus};
 pub use store::{DataStore, McpCallStat, ProjectLeaderboardEntry};
 pub use usage_estimator::{calculate_usage_estimate, SubscriptionPlan, UsageEstimate};
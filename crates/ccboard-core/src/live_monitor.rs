@@ -7,10 +7,10 @@
 //! (from ~/.ccboard/live-sessions.json) with ps-based process data.
 
 use crate::hook_state::{HookSessionStatus, LiveSessionFile};
+use crate::parsers::SessionContentParser;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Local, TimeZone};
 use std::collections::HashMap;
-use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
 use std::path::Path;
 use std::process::Command;
 
@@ -130,6 +130,62 @@ pub struct LiveSession {
     pub total_cache_create: u64,
 }
 
+/// Process names detected when nothing else is configured
+const DEFAULT_PROCESS_NAMES: &[&str] = &["claude", "claude-code"];
+
+/// Interpreters whose first argument is the script actually being run
+/// (e.g. `/bin/sh /usr/local/bin/cc` for a wrapper script)
+const SCRIPT_INTERPRETERS: &[&str] = &["sh", "bash", "zsh", "fish", "node", "bun", "deno"];
+
+/// Which processes count as Claude Code sessions
+///
+/// Names are matched against the executable basename (or the script run by
+/// a shell/JS interpreter) and support `*` and `?` wildcards. Extra names come
+/// from `liveMonitor.processNames` in settings.json:
+///
+/// ```json
+/// { "liveMonitor": { "processNames": ["cc", "claude-*-wrapper"] } }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveMonitorConfig {
+    pub process_names: Vec<String>,
+}
+
+impl Default for LiveMonitorConfig {
+    fn default() -> Self {
+        Self {
+            process_names: DEFAULT_PROCESS_NAMES
+                .iter()
+                .map(|n| n.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl LiveMonitorConfig {
+    /// Default names (`claude`, `claude-code`) plus `extra`
+    pub fn with_extra_names<S: AsRef<str>>(extra: &[S]) -> Self {
+        let mut config = Self::default();
+        for name in extra {
+            let name = name.as_ref().trim();
+            if !name.is_empty() && !config.process_names.iter().any(|n| n == name) {
+                config.process_names.push(name.to_string());
+            }
+        }
+        config
+    }
+
+    /// Whether an executable (path or basename) matches a configured name
+    pub fn matches_executable(&self, executable: &str) -> bool {
+        let base = executable.rsplit(['/', '\\']).next().unwrap_or(executable);
+        let base_no_exe = base.strip_suffix(".exe").unwrap_or(base);
+        self.process_names.iter().any(|name| {
+            crate::project_filter::wildcard_match(name, base)
+                || crate::project_filter::wildcard_match(name, base_no_exe)
+        })
+    }
+}
+
 /// Detect all running Claude Code processes on the system
 ///
 /// Uses platform-specific commands:
@@ -140,19 +196,24 @@ pub struct LiveSession {
 /// Vector of LiveSession structs, one per detected Claude process.
 /// Returns empty vector on error or if no Claude processes are running.
 pub fn detect_live_sessions() -> Result<Vec<LiveSession>> {
+    detect_live_sessions_with(&LiveMonitorConfig::default())
+}
+
+/// Same as `detect_live_sessions`, matching the process names of `config`
+pub fn detect_live_sessions_with(config: &LiveMonitorConfig) -> Result<Vec<LiveSession>> {
     #[cfg(unix)]
     {
-        detect_live_sessions_unix()
+        detect_live_sessions_unix(config)
     }
 
     #[cfg(windows)]
     {
-        detect_live_sessions_windows()
+        detect_live_sessions_windows(config)
     }
 }
 
 #[cfg(unix)]
-fn detect_live_sessions_unix() -> Result<Vec<LiveSession>> {
+fn detect_live_sessions_unix(config: &LiveMonitorConfig) -> Result<Vec<LiveSession>> {
     // Run ps aux to get all processes
     let output = Command::new("ps")
         .args(["aux"])
@@ -166,7 +227,7 @@ fn detect_live_sessions_unix() -> Result<Vec<LiveSession>> {
     let stdout = String::from_utf8_lossy(&output.stdout);
     let sessions: Vec<LiveSession> = stdout
         .lines()
-        .filter(|line| is_claude_process_line(line))
+        .filter(|line| is_claude_process_line(line, config))
         .filter_map(parse_ps_line)
         .collect();
 
@@ -175,10 +236,11 @@ fn detect_live_sessions_unix() -> Result<Vec<LiveSession>> {
 
 /// Returns true if a `ps aux` line belongs to a Claude Code process.
 ///
-/// Checks the COMMAND column (field 10, 0-indexed) basename is exactly `claude`
-/// or `claude-code`, avoiding false matches on `claude-desktop`, scripts, grep, etc.
+/// Checks the COMMAND column (field 10, 0-indexed) basename matches one of the
+/// configured names (by default exactly `claude` or `claude-code`), avoiding
+/// false matches on `claude-desktop`, scripts, grep, etc.
 #[cfg(unix)]
-fn is_claude_process_line(line: &str) -> bool {
+fn is_claude_process_line(line: &str, config: &LiveMonitorConfig) -> bool {
     if line.contains("grep") || line.contains("ccboard") {
         return false;
     }
@@ -192,8 +254,15 @@ fn is_claude_process_line(line: &str) -> bool {
     }
     // First token of COMMAND is the binary (possibly a full path)
     let binary = fields.next().unwrap_or("");
+    if config.matches_executable(binary) {
+        return true;
+    }
+    // Wrapper scripts show up as `<interpreter> <script>`
     let base = binary.rsplit('/').next().unwrap_or(binary);
-    base == "claude" || base == "claude-code"
+    SCRIPT_INTERPRETERS.contains(&base)
+        && fields
+            .next()
+            .is_some_and(|script| config.matches_executable(script))
 }
 
 #[cfg(unix)]
@@ -316,10 +385,11 @@ fn get_cwd_for_pid(pid: u32) -> Option<String> {
 }
 
 #[cfg(windows)]
-fn detect_live_sessions_windows() -> Result<Vec<LiveSession>> {
-    // Run tasklist with CSV output for parsing
+fn detect_live_sessions_windows(config: &LiveMonitorConfig) -> Result<Vec<LiveSession>> {
+    // List all processes once and filter by image name (tasklist filters
+    // only accept a single IMAGENAME)
     let output = Command::new("tasklist")
-        .args(&["/FI", "IMAGENAME eq claude.exe", "/FO", "CSV", "/NH"])
+        .args(&["/FO", "CSV", "/NH"])
         .output()
         .context("Failed to run tasklist command")?;
 
@@ -331,6 +401,10 @@ fn detect_live_sessions_windows() -> Result<Vec<LiveSession>> {
     let sessions: Vec<LiveSession> = stdout
         .lines()
         .filter(|line| !line.is_empty())
+        .filter(|line| {
+            let image = line.split(',').next().unwrap_or("").trim_matches('"');
+            config.matches_executable(image)
+        })
         .filter_map(|line| parse_tasklist_csv(line))
         .collect();
 
@@ -759,125 +833,55 @@ fn parse_transcript_delta(path: &Path, from_offset: u64) -> TranscriptCache {
         ..Default::default()
     };
 
-    let Ok(file) = std::fs::File::open(path) else {
+    let Ok(parsed) = SessionContentParser::parse_appended(path, from_offset) else {
         return result;
     };
-    let file_len = file.metadata().map(|m| m.len()).unwrap_or(0);
-    if file_len == from_offset {
-        result.new_offset = file_len;
-        return result;
-    }
-    // File shrank or rotated — reparse from start
-    let effective_offset = if file_len < from_offset {
-        0
-    } else {
-        from_offset
-    };
-
-    let mut reader = BufReader::new(file);
-    if effective_offset > 0 {
-        let _ = reader.seek(SeekFrom::Start(effective_offset));
-    }
 
-    const MAX_LINE: usize = 10 * 1024 * 1024; // 10 MB guard
-    let mut bytes_read = effective_offset;
-    let mut line_buf = String::new();
-
-    loop {
-        line_buf.clear();
-        match reader
-            .by_ref()
-            .take(MAX_LINE as u64 + 1)
-            .read_line(&mut line_buf)
-        {
-            Ok(0) => break,
-            Ok(n) => {
-                if line_buf.len() > MAX_LINE && !line_buf.ends_with('\n') {
-                    bytes_read = file_len;
-                    break;
-                }
-                let has_newline = line_buf.ends_with('\n');
-                let line = line_buf.trim();
-                if line.is_empty() {
-                    if has_newline {
-                        bytes_read += n as u64;
-                    }
-                    continue;
-                }
-                let val = match serde_json::from_str::<serde_json::Value>(line) {
-                    Ok(v) => v,
-                    Err(_) => {
-                        if has_newline {
-                            bytes_read += n as u64;
-                        } else {
-                            break; // incomplete line, defer to next poll
-                        }
-                        continue;
-                    }
-                };
-                bytes_read += n as u64;
-
-                if let Some("assistant") = val.get("type").and_then(|t| t.as_str()) {
-                    result.turn_count += 1;
-                    result.current_task = String::new();
-                    if let Some(msg) = val.get("message") {
-                        if let Some(m) = msg.get("model").and_then(|m| m.as_str()) {
-                            result.model = m.to_string();
-                        }
-                        if let Some(usage) = msg.get("usage") {
-                            let inp = usage
-                                .get("input_tokens")
-                                .and_then(|v| v.as_u64())
-                                .unwrap_or(0);
-                            let out = usage
-                                .get("output_tokens")
-                                .and_then(|v| v.as_u64())
-                                .unwrap_or(0);
-                            let cr = usage
-                                .get("cache_read_input_tokens")
-                                .and_then(|v| v.as_u64())
-                                .unwrap_or(0);
-                            let cc = usage
-                                .get("cache_creation_input_tokens")
-                                .and_then(|v| v.as_u64())
-                                .unwrap_or(0);
-                            result.total_input += inp;
-                            result.total_output += out;
-                            result.total_cache_read += cr;
-                            result.total_cache_create += cc;
-                            let prev = result.last_context_tokens;
-                            result.last_context_tokens = inp + cr;
-                            if result.last_context_tokens > result.max_context_tokens {
-                                result.max_context_tokens = result.last_context_tokens;
-                            }
-                            if prev > 0 && result.last_context_tokens < prev * 7 / 10 {
-                                result.compaction_count += 1;
-                            }
-                            if result.context_history.len() < 200 {
-                                result.context_history.push(result.last_context_tokens);
-                            }
-                            if result.token_history.len() < 200 {
-                                result.token_history.push(inp + out + cr + cc);
-                            }
-                        }
-                        // Last tool_use name as current task
-                        if let Some(content) = msg.get("content").and_then(|c| c.as_array()) {
-                            for item in content {
-                                if item.get("type").and_then(|t| t.as_str()) == Some("tool_use") {
-                                    let tool =
-                                        item.get("name").and_then(|n| n.as_str()).unwrap_or("?");
-                                    result.current_task = tool.to_string();
-                                }
-                            }
-                        }
-                    }
+    for line in parsed.lines.iter().filter(|l| l.line_type == "assistant") {
+        result.turn_count += 1;
+        result.current_task = String::new();
+        let Some(msg) = &line.message else {
+            continue;
+        };
+        if let Some(m) = &msg.model {
+            result.model = m.clone();
+        }
+        if let Some(usage) = &msg.usage {
+            let inp = usage.input_tokens;
+            let out = usage.output_tokens;
+            let cr = usage.cache_read_tokens;
+            let cc = usage.cache_write_tokens;
+            result.total_input += inp;
+            result.total_output += out;
+            result.total_cache_read += cr;
+            result.total_cache_create += cc;
+            let prev = result.last_context_tokens;
+            result.last_context_tokens = inp + cr;
+            if result.last_context_tokens > result.max_context_tokens {
+                result.max_context_tokens = result.last_context_tokens;
+            }
+            if prev > 0 && result.last_context_tokens < prev * 7 / 10 {
+                result.compaction_count += 1;
+            }
+            if result.context_history.len() < 200 {
+                result.context_history.push(result.last_context_tokens);
+            }
+            if result.token_history.len() < 200 {
+                result.token_history.push(inp + out + cr + cc);
+            }
+        }
+        // Last tool_use name as current task
+        if let Some(content) = msg.content.as_ref().and_then(|c| c.as_array()) {
+            for item in content {
+                if item.get("type").and_then(|t| t.as_str()) == Some("tool_use") {
+                    let tool = item.get("name").and_then(|n| n.as_str()).unwrap_or("?");
+                    result.current_task = tool.to_string();
                 }
             }
-            Err(_) => break,
         }
     }
 
-    result.new_offset = bytes_read;
+    result.new_offset = parsed.offset;
     result
 }
 
@@ -887,19 +891,26 @@ fn parse_transcript_delta(path: &Path, from_offset: u64) -> TranscriptCache {
 /// (O(delta) instead of O(file_size)). Persist this struct across calls.
 pub struct LiveMonitorState {
     cache: HashMap<String, TranscriptCache>,
+    config: LiveMonitorConfig,
 }
 
 impl LiveMonitorState {
     pub fn new() -> Self {
         Self {
             cache: HashMap::new(),
+            config: LiveMonitorConfig::default(),
         }
     }
 
+    /// Replace the process names matched by subsequent polls
+    pub fn set_config(&mut self, config: LiveMonitorConfig) {
+        self.config = config;
+    }
+
     /// Detect live sessions and enrich them with incremental transcript data.
     /// Call this instead of `detect_live_sessions()` for efficient repeated polling.
     pub fn detect_sessions(&mut self) -> Vec<LiveSession> {
-        let mut sessions = detect_live_sessions().unwrap_or_default();
+        let mut sessions = detect_live_sessions_with(&self.config).unwrap_or_default();
 
         // Evict cache entries for sessions no longer alive
         let active_ids: std::collections::HashSet<String> = sessions
@@ -1095,14 +1106,14 @@ mod tests {
     #[cfg(unix)]
     fn test_is_claude_process_line_match() {
         let line = "user  12345  0.0  0.1  123456  78910  ttys001  S+   14:30   0:05.23  /usr/local/bin/claude --resume abc";
-        assert!(is_claude_process_line(line));
+        assert!(is_claude_process_line(line, &LiveMonitorConfig::default()));
     }
 
     #[test]
     #[cfg(unix)]
     fn test_is_claude_process_line_bare_claude() {
         let line = "user  12345  0.0  0.1  123456  78910  ttys001  S+   14:30   0:05.23  claude";
-        assert!(is_claude_process_line(line));
+        assert!(is_claude_process_line(line, &LiveMonitorConfig::default()));
     }
 
     #[test]
@@ -1110,7 +1121,7 @@ mod tests {
     fn test_is_claude_process_line_rejects_desktop() {
         // claude-desktop should NOT match
         let line = "user  99999  0.0  0.1  123456  78910  ttys001  S+   14:30   0:05.23  /Applications/Claude.app/claude-desktop";
-        assert!(!is_claude_process_line(line));
+        assert!(!is_claude_process_line(line, &LiveMonitorConfig::default()));
     }
 
     #[test]
@@ -1118,21 +1129,44 @@ mod tests {
     fn test_is_claude_process_line_rejects_grep() {
         let line =
             "user  99999  0.0  0.1  123456  78910  ttys001  S+   14:30   0:05.23  grep claude";
-        assert!(!is_claude_process_line(line));
+        assert!(!is_claude_process_line(line, &LiveMonitorConfig::default()));
     }
 
     #[test]
     #[cfg(unix)]
     fn test_is_claude_process_line_rejects_ccboard() {
         let line = "user  99999  0.0  0.1  123456  78910  ttys001  S+   14:30   0:05.23  ccboard hook PreToolUse";
-        assert!(!is_claude_process_line(line));
+        assert!(!is_claude_process_line(line, &LiveMonitorConfig::default()));
     }
 
     #[test]
     #[cfg(unix)]
     fn test_is_claude_process_line_rejects_script_with_claude_in_name() {
         let line = "user  88888  0.0  0.1  123456  78910  ttys001  S+   14:30   0:05.23  python3 claude_runner.py";
-        assert!(!is_claude_process_line(line));
+        assert!(!is_claude_process_line(line, &LiveMonitorConfig::default()));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_is_claude_process_line_configured_names() {
+        let config = LiveMonitorConfig::with_extra_names(&["cc", "claude-*-wrapper"]);
+        assert_eq!(config.process_names.len(), 4);
+
+        let line = "user  12345  0.0  0.1  123456  78910  ttys001  S+   14:30   0:05.23  /usr/local/bin/cc --resume abc";
+        assert!(is_claude_process_line(line, &config));
+        assert!(!is_claude_process_line(line, &LiveMonitorConfig::default()));
+
+        // Wrapper script run through a shell
+        let line = "user  12345  0.0  0.1  123456  78910  ttys001  S+   14:30   0:05.23  /bin/bash /home/me/bin/claude-work-wrapper";
+        assert!(is_claude_process_line(line, &config));
+
+        // Defaults still match
+        let line = "user  12345  0.0  0.1  123456  78910  ttys001  S+   14:30   0:05.23  claude";
+        assert!(is_claude_process_line(line, &config));
+
+        // Interpreter running an unrelated script
+        let line = "user  12345  0.0  0.1  123456  78910  ttys001  S+   14:30   0:05.23  /bin/bash ccache-cleanup";
+        assert!(!is_claude_process_line(line, &config));
     }
 
     #[test]
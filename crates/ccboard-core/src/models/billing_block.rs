@@ -2,24 +2,37 @@ use chrono::{DateTime, Timelike, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-/// Represents a 5-hour billing block as used by Claude Code pricing.
+/// Default billing block length in hours
+pub const DEFAULT_BLOCK_HOURS: u8 = 5;
+
+fn default_block_hours() -> u8 {
+    DEFAULT_BLOCK_HOURS
+}
+
+/// Represents a billing block as used by Claude Code pricing.
 ///
-/// Claude Code charges based on 5-hour blocks in UTC time:
+/// Claude Code charges based on 5-hour blocks in UTC time by default:
 /// - Block 1: 00:00-04:59 UTC
 /// - Block 2: 05:00-09:59 UTC
 /// - Block 3: 10:00-14:59 UTC
 /// - Block 4: 15:00-19:59 UTC
 /// - Block 5: 20:00-23:59 UTC
+///
+/// Other window lengths (`billingBlockHours` in settings.json) split the day
+/// the same way, the last block being cut short at midnight.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct BillingBlock {
     /// Date of the block (YYYY-MM-DD)
     pub date: chrono::NaiveDate,
-    /// Starting hour of the 5-hour block (0, 5, 10, 15, 20)
+    /// Starting hour of the block (0, 5, 10, 15, 20 for 5-hour blocks)
     pub block_hour: u8,
+    /// Configured block length in hours
+    #[serde(default = "default_block_hours")]
+    pub window_hours: u8,
 }
 
 impl BillingBlock {
-    /// Create a BillingBlock from a timestamp
+    /// Create a 5-hour BillingBlock from a timestamp
     ///
     /// # Examples
     ///
@@ -33,29 +46,40 @@ impl BillingBlock {
     /// assert_eq!(block.block_hour, 10); // 14:30 falls in 10:00-14:59 block
     /// ```
     pub fn from_timestamp(timestamp: &DateTime<Utc>) -> Self {
+        Self::from_timestamp_with_duration(timestamp, DEFAULT_BLOCK_HOURS)
+    }
+
+    /// Create a BillingBlock of `window_hours` (clamped to 1-24) from a timestamp
+    pub fn from_timestamp_with_duration(timestamp: &DateTime<Utc>, window_hours: u8) -> Self {
+        let window_hours = window_hours.clamp(1, 24);
         let date = timestamp.date_naive();
         let hour = timestamp.hour() as u8;
 
-        // Normalize to 5-hour block: block_hour = (hour / 5) * 5
-        // 0-4 → 0, 5-9 → 5, 10-14 → 10, 15-19 → 15, 20-23 → 20
-        let block_hour = (hour / 5) * 5;
+        // Normalize to the block start: block_hour = (hour / window) * window
+        // 5h: 0-4 → 0, 5-9 → 5, 10-14 → 10, 15-19 → 15, 20-23 → 20
+        let block_hour = (hour / window_hours) * window_hours;
 
-        BillingBlock { date, block_hour }
+        BillingBlock {
+            date,
+            block_hour,
+            window_hours,
+        }
     }
 
     /// Get the block label (e.g., "00:00-04:59", "05:00-09:59")
     pub fn label(&self) -> String {
-        let end_hour = if self.block_hour == 20 {
-            23
-        } else {
-            self.block_hour + 4
-        };
+        let end_hour = self.block_hour + self.duration_hours() - 1;
         format!("{:02}:00-{:02}:59", self.block_hour, end_hour)
     }
 
-    /// Get the block number (1-5)
+    /// Get the block number (1-5 for 5-hour blocks)
     pub fn block_number(&self) -> u8 {
-        (self.block_hour / 5) + 1
+        (self.block_hour / self.window_hours.max(1)) + 1
+    }
+
+    /// Length of the block in hours (the last block of the day may be shorter)
+    pub fn duration_hours(&self) -> u8 {
+        self.window_hours.max(1).min(24 - self.block_hour)
     }
 }
 
@@ -96,20 +120,61 @@ impl BillingBlockUsage {
     }
 }
 
+/// Spend of the current UTC day and billing block, with linear projections
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CurrentSpend {
+    /// Cost so far today (UTC)
+    pub today: f64,
+    /// Today's cost extrapolated to the end of the day
+    pub today_projected: f64,
+    /// Cost so far in the current billing block
+    pub block: f64,
+    /// Current block's cost extrapolated to the end of the block
+    pub block_projected: f64,
+}
+
+/// Minimum elapsed fraction used for projections, so a few minutes into a
+/// window a single request does not extrapolate to an absurd total
+const MIN_ELAPSED_FRACTION: f64 = 0.1;
+
+fn project(cost: f64, elapsed_secs: i64, window_secs: i64) -> f64 {
+    let fraction = (elapsed_secs as f64 / window_secs as f64).clamp(MIN_ELAPSED_FRACTION, 1.0);
+    cost / fraction
+}
+
 /// Manager for billing block tracking
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct BillingBlockManager {
     /// Map of (date, block_hour) to usage
     blocks: HashMap<BillingBlock, BillingBlockUsage>,
+    /// Block length in hours (1-24)
+    block_duration_hours: u8,
+}
+
+impl Default for BillingBlockManager {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl BillingBlockManager {
     pub fn new() -> Self {
+        Self::new_with_duration(DEFAULT_BLOCK_HOURS)
+    }
+
+    /// Manager with `hours`-long blocks (clamped to 1-24)
+    pub fn new_with_duration(hours: u8) -> Self {
         Self {
             blocks: HashMap::new(),
+            block_duration_hours: hours.clamp(1, 24),
         }
     }
 
+    /// Block length in hours
+    pub fn block_duration_hours(&self) -> u8 {
+        self.block_duration_hours
+    }
+
     /// Add usage to a billing block
     pub fn add_usage(
         &mut self,
@@ -120,7 +185,8 @@ impl BillingBlockManager {
         cache_read_tokens: u64,
         cost: f64,
     ) {
-        let block = BillingBlock::from_timestamp(timestamp);
+        let block =
+            BillingBlock::from_timestamp_with_duration(timestamp, self.block_duration_hours);
         let usage = self.blocks.entry(block).or_default();
 
         usage.input_tokens += input_tokens;
@@ -169,6 +235,28 @@ impl BillingBlockManager {
         blocks
     }
 
+    /// Spend of the UTC day and billing block containing `now`
+    pub fn current_spend(&self, now: DateTime<Utc>) -> CurrentSpend {
+        let current = BillingBlock::from_timestamp_with_duration(&now, self.block_duration_hours);
+        let today: f64 = self
+            .blocks
+            .iter()
+            .filter(|(block, _)| block.date == current.date)
+            .map(|(_, usage)| usage.total_cost)
+            .sum();
+        let block = self.get_usage(&current).map_or(0.0, |u| u.total_cost);
+
+        let secs_today = now.num_seconds_from_midnight() as i64;
+        let block_elapsed = secs_today - current.block_hour as i64 * 3600;
+
+        CurrentSpend {
+            today,
+            today_projected: project(today, secs_today, 24 * 3600),
+            block,
+            block_projected: project(block, block_elapsed, current.duration_hours() as i64 * 3600),
+        }
+    }
+
     /// Get color coding for a block based on cost thresholds
     ///
     /// - Green: < $2.5
@@ -267,6 +355,34 @@ mod tests {
         assert_eq!(all_blocks[1].0.block_hour, 5); // Block 2 second
     }
 
+    #[test]
+    fn test_custom_block_duration() {
+        let mut manager = BillingBlockManager::new_with_duration(8);
+        assert_eq!(manager.block_duration_hours(), 8);
+
+        let ts = Utc.with_ymd_and_hms(2026, 2, 2, 9, 30, 0).unwrap();
+        manager.add_usage(&ts, 1000, 0, 0, 0, 1.0);
+        let ts = Utc.with_ymd_and_hms(2026, 2, 2, 15, 59, 0).unwrap();
+        manager.add_usage(&ts, 1000, 0, 0, 0, 1.0);
+        let ts = Utc.with_ymd_and_hms(2026, 2, 2, 16, 0, 0).unwrap();
+        manager.add_usage(&ts, 1000, 0, 0, 0, 1.0);
+
+        let blocks = manager.get_all_blocks();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].0.block_hour, 8);
+        assert_eq!(blocks[0].0.label(), "08:00-15:59");
+        assert_eq!(blocks[0].1.session_count, 2);
+        assert_eq!(blocks[1].0.label(), "16:00-23:59");
+        assert_eq!(blocks[1].0.block_number(), 3);
+
+        // 7-hour blocks: the last one is cut short at midnight
+        let ts = Utc.with_ymd_and_hms(2026, 2, 2, 22, 0, 0).unwrap();
+        let block = BillingBlock::from_timestamp_with_duration(&ts, 7);
+        assert_eq!(block.block_hour, 21);
+        assert_eq!(block.duration_hours(), 3);
+        assert_eq!(block.label(), "21:00-23:59");
+    }
+
     #[test]
     fn test_color_coding() {
         assert_eq!(BillingBlockManager::get_color_for_cost(1.0), "green");
@@ -305,4 +421,30 @@ mod tests {
         assert_eq!(blocks.len(), 1);
         assert_eq!(blocks[0].0.block_hour, 5); // Block 2
     }
+
+    #[test]
+    fn test_current_spend_projection() {
+        let mut manager = BillingBlockManager::new();
+        // Yesterday: excluded
+        let ts = Utc.with_ymd_and_hms(2026, 2, 1, 22, 0, 0).unwrap();
+        manager.add_usage(&ts, 0, 0, 0, 0, 9.0);
+        // Today, earlier block
+        let ts = Utc.with_ymd_and_hms(2026, 2, 2, 3, 0, 0).unwrap();
+        manager.add_usage(&ts, 0, 0, 0, 0, 4.0);
+        // Today, current block (10:00-14:59)
+        let ts = Utc.with_ymd_and_hms(2026, 2, 2, 10, 30, 0).unwrap();
+        manager.add_usage(&ts, 0, 0, 0, 0, 2.0);
+
+        // 12:00 → half the day and 2h of the 5h block elapsed
+        let now = Utc.with_ymd_and_hms(2026, 2, 2, 12, 0, 0).unwrap();
+        let spend = manager.current_spend(now);
+        assert!((spend.today - 6.0).abs() < 1e-9);
+        assert!((spend.today_projected - 12.0).abs() < 1e-9);
+        assert!((spend.block - 2.0).abs() < 1e-9);
+        assert!((spend.block_projected - 5.0).abs() < 1e-9);
+
+        // Right after the block starts, projection is capped at 10x
+        let now = Utc.with_ymd_and_hms(2026, 2, 2, 10, 1, 0).unwrap();
+        assert!((manager.current_spend(now).block_projected - 20.0).abs() < 1e-9);
+    }
 }
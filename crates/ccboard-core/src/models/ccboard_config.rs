@@ -3,6 +3,7 @@
 //! Separate from Claude Code's `settings.json` to avoid polluting
 //! the Claude settings namespace with ccboard-only options.
 
+use super::config::ColorScheme;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -27,6 +28,23 @@ pub struct CcboardConfig {
     /// Max number of observations to load from claude-mem (default: 200)
     #[serde(default = "default_claude_mem_limit")]
     pub claude_mem_limit: usize,
+
+    /// TUI theme (`[theme]` table)
+    #[serde(default)]
+    pub theme: ThemeConfig,
+}
+
+/// `[theme]` table of `config.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    /// Color scheme applied at startup, overriding the one saved by the theme
+    /// toggle: `dark`, `light` or `colorblind-safe`
+    #[serde(
+        default,
+        rename = "colorScheme",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color_scheme: Option<ColorScheme>,
 }
 
 impl Default for CcboardConfig {
@@ -35,6 +53,7 @@ impl Default for CcboardConfig {
             claude_mem_enabled: false,
             claude_mem_db_path: None,
             claude_mem_limit: 200,
+            theme: ThemeConfig::default(),
         }
     }
 }
@@ -76,3 +95,20 @@ impl CcboardConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_color_scheme_key() {
+        let cfg: CcboardConfig =
+            toml::from_str("[theme]\ncolorScheme = \"colorblind-safe\"\n").unwrap();
+        assert_eq!(cfg.theme.color_scheme, Some(ColorScheme::ColorBlindSafe));
+        assert_eq!(cfg.claude_mem_limit, 200);
+
+        let cfg: CcboardConfig = toml::from_str("claude_mem_enabled = true\n").unwrap();
+        assert_eq!(cfg.theme.color_scheme, None);
+        assert!(!toml::to_string(&cfg).unwrap().contains("colorScheme"));
+    }
+}
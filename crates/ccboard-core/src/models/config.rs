@@ -1,7 +1,7 @@
 //! Configuration models for Claude Code settings
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Color scheme for TUI
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -12,6 +12,92 @@ pub enum ColorScheme {
     Dark,
     /// Light theme: White bg, Black fg
     Light,
+    /// Dark theme with a blue→orange status scale, safe for red-green color blindness
+    #[serde(rename = "colorblind-safe", alias = "colorblindsafe")]
+    ColorBlindSafe,
+}
+
+impl ColorScheme {
+    /// Every scheme, in toggle order
+    pub const ALL: [ColorScheme; 3] = [
+        ColorScheme::Dark,
+        ColorScheme::Light,
+        ColorScheme::ColorBlindSafe,
+    ];
+
+    /// Name used in settings and on the command line
+    pub fn slug(self) -> &'static str {
+        match self {
+            ColorScheme::Dark => "dark",
+            ColorScheme::Light => "light",
+            ColorScheme::ColorBlindSafe => "colorblind-safe",
+        }
+    }
+
+    /// Next scheme in the theme toggle cycle
+    pub fn next(self) -> Self {
+        match self {
+            ColorScheme::Dark => ColorScheme::Light,
+            ColorScheme::Light => ColorScheme::ColorBlindSafe,
+            ColorScheme::ColorBlindSafe => ColorScheme::Dark,
+        }
+    }
+
+    /// Display name
+    pub fn label(self) -> &'static str {
+        match self {
+            ColorScheme::Dark => "Dark",
+            ColorScheme::Light => "Light",
+            ColorScheme::ColorBlindSafe => "Color-blind safe",
+        }
+    }
+}
+
+impl std::str::FromStr for ColorScheme {
+    type Err = String;
+
+    /// Parse a scheme name (case-insensitive); "colorblindsafe" is accepted
+    /// like in settings
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.trim().to_ascii_lowercase();
+        if name == "colorblindsafe" {
+            return Ok(ColorScheme::ColorBlindSafe);
+        }
+        ColorScheme::ALL
+            .into_iter()
+            .find(|scheme| scheme.slug() == name)
+            .ok_or_else(|| {
+                let valid: Vec<&str> = ColorScheme::ALL.iter().map(|s| s.slug()).collect();
+                format!("unknown theme '{}' (valid: {})", name, valid.join(", "))
+            })
+    }
+}
+
+/// How project paths are displayed in tabs and CLI output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectDisplayMode {
+    /// Full decoded path
+    Full,
+    /// Last two components (`.../parent/project`) (default)
+    #[default]
+    Short,
+    /// Last component only
+    Basename,
+    /// Friendly names from `~/.claude/ccboard-aliases.json`, short path otherwise
+    Alias,
+}
+
+/// Where session token totals come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenSource {
+    /// Only `usage` blocks reported by the API (default)
+    #[default]
+    Reported,
+    /// Reported usage plus a chars/4 estimate for assistant messages without
+    /// a `usage` block; totals are shown with a `~` prefix when estimated
+    Hybrid,
 }
 
 /// Claude Code settings (from settings.json)
@@ -62,10 +148,51 @@ pub struct Settings {
     #[serde(default)]
     pub keybindings: Option<HashMap<String, String>>,
 
+    /// Project path display mode (full, short, basename, alias)
+    #[serde(default)]
+    pub project_display: Option<ProjectDisplayMode>,
+
     /// Anomaly detection thresholds (optional overrides)
     #[serde(default)]
     pub anomaly_thresholds: Option<AnomalyThresholds>,
 
+    /// Max session files scanned in parallel by `ccboard search --content` (default: 8)
+    #[serde(default)]
+    pub content_search_concurrency: Option<usize>,
+
+    /// Projects with a 14-day token sparkline on the dashboard (default: 5, 0 hides them)
+    #[serde(default)]
+    pub dashboard_projects: Option<usize>,
+
+    /// Token totals: reported usage only, or hybrid with estimates (default: reported)
+    #[serde(default)]
+    pub token_source: Option<TokenSource>,
+
+    /// Project path patterns excluded from stats, analytics, costs and leaderboard
+    #[serde(default)]
+    pub exclude_projects: Option<Vec<String>>,
+
+    /// Billing block length in hours (default: 5)
+    #[serde(default)]
+    pub billing_block_hours: Option<u8>,
+
+    /// Attribute sessions to `git config user.email` at their project path
+    /// (opt-in, since it runs git once per project)
+    #[serde(default)]
+    pub track_author: Option<bool>,
+
+    /// Live session detection options
+    #[serde(default)]
+    pub live_monitor: Option<LiveMonitorSettings>,
+
+    /// Desktop notifications (`tui` / `both`, needs the `notifications` build feature)
+    #[serde(default)]
+    pub notifications: Option<NotificationSettings>,
+
+    /// Display currency for costs (`{ "symbol": "€", "rate": 0.92 }`), USD if unset
+    #[serde(default)]
+    pub currency: Option<ccboard_types::Currency>,
+
     /// Auto mode permission rules (v2.1.136+)
     #[serde(default)]
     pub auto_mode: Option<AutoModeConfig>,
@@ -101,6 +228,24 @@ pub struct AutoModeConfig {
     pub environment: Vec<serde_json::Value>,
 }
 
+/// Live session detection options
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveMonitorSettings {
+    /// Extra process names/patterns detected as Claude Code (e.g. wrapper scripts)
+    #[serde(default)]
+    pub process_names: Vec<String>,
+}
+
+/// Desktop notification options
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSettings {
+    /// Notify when a budget alert reaches a new level (warning, critical, exceeded)
+    #[serde(default)]
+    pub budget: bool,
+}
+
 /// Worktree behaviour configuration (v2.1.133+)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -116,15 +261,80 @@ pub struct WorktreeConfig {
 #[serde(rename_all = "camelCase")]
 pub struct BudgetConfig {
     /// Monthly budget limit in USD (optional, no limit if None)
+    #[serde(default, alias = "monthlyBudgetUsd")]
     pub monthly_limit: Option<f64>,
 
+    /// Daily budget limit in USD (UTC day, optional)
+    #[serde(default)]
+    pub daily_budget_usd: Option<f64>,
+
+    /// Per billing block (5h) budget limit in USD (optional)
+    #[serde(default)]
+    pub per_block_budget_usd: Option<f64>,
+
     /// Warning threshold percentage (0-100), defaults to 75%
-    #[serde(default = "default_warning_threshold")]
+    #[serde(default = "default_warning_threshold", alias = "alertThresholdPct")]
     pub warning_threshold: f64,
 
     /// Critical threshold percentage (0-100), defaults to 90%
     #[serde(default = "default_critical_threshold")]
     pub critical_threshold: f64,
+
+    /// Monthly budgets per project path (`~/` expanded); projects without an
+    /// entry fall back to the global budget
+    #[serde(default)]
+    pub per_project: HashMap<String, ProjectBudget>,
+}
+
+/// Monthly budget for a single project (`budget.perProject` entry)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectBudget {
+    /// Monthly budget limit in USD
+    #[serde(default)]
+    pub monthly_budget_usd: Option<f64>,
+
+    /// Alert threshold percentage (0-100), defaults to the global warning threshold
+    #[serde(default)]
+    pub alert_threshold_pct: Option<f64>,
+}
+
+/// Monthly budget that applies to a project after fallback
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolvedProjectBudget {
+    /// Monthly budget limit in USD
+    pub monthly_usd: f64,
+    /// Alert threshold percentage (0-100)
+    pub alert_threshold_pct: f64,
+    /// True when set in `perProject`, false when inherited from the global budget
+    pub explicit: bool,
+}
+
+impl BudgetConfig {
+    /// Budget for `project_path`: its `perProject` entry, else the global monthly limit
+    pub fn project_budget(&self, project_path: &str) -> Option<ResolvedProjectBudget> {
+        let path = project_path.trim_end_matches('/');
+        let explicit = self.per_project.iter().find_map(|(key, entry)| {
+            let monthly_usd = entry.monthly_budget_usd?;
+            (crate::project_filter::normalize_pattern(key) == path).then_some(
+                ResolvedProjectBudget {
+                    monthly_usd,
+                    alert_threshold_pct: entry
+                        .alert_threshold_pct
+                        .unwrap_or(self.warning_threshold),
+                    explicit: true,
+                },
+            )
+        });
+
+        explicit.or_else(|| {
+            self.monthly_limit.map(|monthly_usd| ResolvedProjectBudget {
+                monthly_usd,
+                alert_threshold_pct: self.warning_threshold,
+                explicit: false,
+            })
+        })
+    }
 }
 
 fn default_warning_threshold() -> f64 {
@@ -295,6 +505,32 @@ pub struct HookDefinition {
     pub file_path: Option<std::path::PathBuf>,
 }
 
+/// Settings file a resolved value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigLayer {
+    /// `~/.claude/settings.json`
+    Global,
+    /// `~/.claude/settings.local.json`
+    GlobalLocal,
+    /// `<project>/.claude/settings.json`
+    Project,
+    /// `<project>/.claude/settings.local.json`
+    ProjectLocal,
+}
+
+impl ConfigLayer {
+    /// Short label for display
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfigLayer::Global => "global",
+            ConfigLayer::GlobalLocal => "global local",
+            ConfigLayer::Project => "project",
+            ConfigLayer::ProjectLocal => "local",
+        }
+    }
+}
+
 /// Merged configuration from all levels
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MergedConfig {
@@ -305,11 +541,20 @@ pub struct MergedConfig {
 
     /// Final merged result
     pub merged: Settings,
+
+    /// Layer each resolved value came from (see [`MergedConfig::source_of`])
+    #[serde(default)]
+    pub sources: BTreeMap<String, ConfigLayer>,
 }
 
 impl MergedConfig {
     /// Create merged config from three levels
     /// Priority: local > project > global
+    ///
+    /// Permission lists are unioned across layers, then any `allow` rule that
+    /// a `deny` rule (from any layer) covers is dropped: deny always wins.
+    /// Coverage follows Claude Code's rule syntax, so `Bash(rm:*)` also removes
+    /// `Bash(rm -rf /tmp)`.
     pub fn from_layers(
         global: Option<Settings>,
         global_local: Option<Settings>,
@@ -317,25 +562,23 @@ impl MergedConfig {
         project_local: Option<Settings>,
     ) -> Self {
         let mut merged = Settings::default();
-
-        // Merge global first
-        if let Some(ref g) = global {
-            Self::merge_into(&mut merged, g);
-        }
-
-        // Then global local (overrides global)
-        if let Some(ref gl) = global_local {
-            Self::merge_into(&mut merged, gl);
-        }
-
-        // Then project (overrides global layers)
-        if let Some(ref p) = project {
-            Self::merge_into(&mut merged, p);
+        let mut sources = BTreeMap::new();
+
+        // Lowest priority first: global, global local, project, project local
+        for (layer, settings) in [
+            (ConfigLayer::Global, &global),
+            (ConfigLayer::GlobalLocal, &global_local),
+            (ConfigLayer::Project, &project),
+            (ConfigLayer::ProjectLocal, &project_local),
+        ] {
+            if let Some(settings) = settings {
+                Self::merge_into(&mut merged, settings);
+                Self::record_sources(&mut sources, layer, settings);
+            }
         }
 
-        // Finally project local (highest priority)
-        if let Some(ref pl) = project_local {
-            Self::merge_into(&mut merged, pl);
+        if let Some(ref mut perms) = merged.permissions {
+            Self::apply_deny_precedence(perms, &mut sources);
         }
 
         // Store sources for debugging (merge global_local into global for compatibility)
@@ -352,6 +595,104 @@ impl MergedConfig {
             project,
             local: project_local, // Rename semantics: local now means project_local
             merged,
+            sources,
+        }
+    }
+
+    /// Layer a resolved value came from
+    ///
+    /// Keys use the JSON names: `"model"`, `"env.<VAR>"`,
+    /// `"permissions.autoApprove"`, and `"permissions.<list>.<rule>"` for rules in
+    /// `allow`, `deny`, `allowBash` and `denyBash` (the first layer that listed
+    /// the rule). Returns `None` for values no layer set.
+    pub fn source_of(&self, key: &str) -> Option<ConfigLayer> {
+        self.sources.get(key).copied()
+    }
+
+    /// Record which keys `settings` sets, as seen after merging it on top
+    fn record_sources(
+        sources: &mut BTreeMap<String, ConfigLayer>,
+        layer: ConfigLayer,
+        settings: &Settings,
+    ) {
+        // Scalars: the last layer setting them wins
+        let scalars = [
+            ("model", settings.model.is_some()),
+            ("apiKey", settings.api_key.is_some()),
+            ("customInstructions", settings.custom_instructions.is_some()),
+            ("theme", settings.theme.is_some()),
+            ("subscriptionPlan", settings.subscription_plan.is_some()),
+            ("budget", settings.budget.is_some()),
+            ("projectDisplay", settings.project_display.is_some()),
+            (
+                "contentSearchConcurrency",
+                settings.content_search_concurrency.is_some(),
+            ),
+            ("dashboardProjects", settings.dashboard_projects.is_some()),
+            ("tokenSource", settings.token_source.is_some()),
+            ("billingBlockHours", settings.billing_block_hours.is_some()),
+            ("trackAuthor", settings.track_author.is_some()),
+            ("notifications", settings.notifications.is_some()),
+            ("currency", settings.currency.is_some()),
+        ];
+        for (key, present) in scalars {
+            if present {
+                sources.insert(key.to_string(), layer);
+            }
+        }
+
+        if let Some(ref env) = settings.env {
+            for key in env.keys() {
+                sources.insert(format!("env.{}", key), layer);
+            }
+        }
+
+        if let Some(ref perms) = settings.permissions {
+            // Lists are unions: credit the first layer that added each rule
+            let lists = [
+                ("allow", &perms.allow),
+                ("deny", &perms.deny),
+                ("allowBash", &perms.allow_bash),
+                ("denyBash", &perms.deny_bash),
+            ];
+            for (list, rules) in lists {
+                for rule in rules.iter().flatten() {
+                    sources
+                        .entry(format!("permissions.{}.{}", list, rule))
+                        .or_insert(layer);
+                }
+            }
+            if perms.auto_approve.is_some() {
+                sources.insert("permissions.autoApprove".to_string(), layer);
+            }
+            if perms.trust_project.is_some() {
+                sources.insert("permissions.trustProject".to_string(), layer);
+            }
+        }
+    }
+
+    /// Drop allow rules that a deny rule covers, whichever layer denied them
+    fn apply_deny_precedence(perms: &mut Permissions, sources: &mut BTreeMap<String, ConfigLayer>) {
+        for (allow_key, allow, deny) in [
+            ("allow", &mut perms.allow, &perms.deny),
+            ("allowBash", &mut perms.allow_bash, &perms.deny_bash),
+        ] {
+            // `allowBash`/`denyBash` hold bare commands, not `Tool(...)` rules
+            let covers = if allow_key == "allow" {
+                deny_rule_covers
+            } else {
+                command_pattern_covers
+            };
+            let (Some(allow), Some(deny)) = (allow.as_mut(), deny.as_ref()) else {
+                continue;
+            };
+            allow.retain(|rule| {
+                let denied = deny.iter().any(|d| covers(d, rule));
+                if denied {
+                    sources.remove(&format!("permissions.{}.{}", allow_key, rule));
+                }
+                !denied
+            });
         }
     }
 
@@ -376,6 +717,53 @@ impl MergedConfig {
         if source.budget.is_some() {
             target.budget = source.budget.clone();
         }
+        if source.project_display.is_some() {
+            target.project_display = source.project_display;
+        }
+        if source.content_search_concurrency.is_some() {
+            target.content_search_concurrency = source.content_search_concurrency;
+        }
+        if source.dashboard_projects.is_some() {
+            target.dashboard_projects = source.dashboard_projects;
+        }
+        if source.token_source.is_some() {
+            target.token_source = source.token_source;
+        }
+        if source.billing_block_hours.is_some() {
+            target.billing_block_hours = source.billing_block_hours;
+        }
+        if source.track_author.is_some() {
+            target.track_author = source.track_author;
+        }
+        if source.notifications.is_some() {
+            target.notifications = source.notifications.clone();
+        }
+        if source.currency.is_some() {
+            target.currency = source.currency.clone();
+        }
+
+        // Excluded projects: extend (a project-level file can only add exclusions)
+        if let Some(ref src_excludes) = source.exclude_projects {
+            let target_excludes = target.exclude_projects.get_or_insert_with(Vec::new);
+            for pattern in src_excludes {
+                if !target_excludes.contains(pattern) {
+                    target_excludes.push(pattern.clone());
+                }
+            }
+        }
+
+        // Live monitor process names: extend (defaults to `claude` are always kept)
+        if let Some(ref src_live) = source.live_monitor {
+            let target_names = &mut target
+                .live_monitor
+                .get_or_insert_with(LiveMonitorSettings::default)
+                .process_names;
+            for name in &src_live.process_names {
+                if !target_names.contains(name) {
+                    target_names.push(name.clone());
+                }
+            }
+        }
 
         // Keybindings: merge maps (custom keybindings override defaults)
         if let Some(ref src_keybindings) = source.keybindings {
@@ -468,10 +856,77 @@ impl MergedConfig {
     }
 }
 
+/// Whether the `deny` permission rule blocks everything `allow` permits
+///
+/// Rules follow Claude Code's `Tool` / `Tool(specifier)` syntax: a bare tool
+/// covers every use of it (`mcp__server` covers all of that server's tools),
+/// and specifiers are compared with [`command_pattern_covers`].
+fn deny_rule_covers(deny: &str, allow: &str) -> bool {
+    let (deny_tool, deny_spec) = split_rule(deny);
+    let (allow_tool, allow_spec) = split_rule(allow);
+
+    let server_wide = deny_spec.is_none()
+        && deny_tool.starts_with("mcp__")
+        && allow_tool
+            .strip_prefix(deny_tool)
+            .is_some_and(|rest| rest.starts_with("__"));
+    if !server_wide && !crate::project_filter::wildcard_match(deny_tool, allow_tool) {
+        return false;
+    }
+    match (deny_spec, allow_spec) {
+        (None, _) => true,
+        // A bare allow permits more than any specifier can deny
+        (Some(_), None) => false,
+        (Some(deny_spec), Some(allow_spec)) => command_pattern_covers(deny_spec, allow_spec),
+    }
+}
+
+/// Tool name and specifier of a `Tool(specifier)` rule
+fn split_rule(rule: &str) -> (&str, Option<&str>) {
+    match rule.split_once('(') {
+        Some((tool, spec)) => (tool, spec.strip_suffix(')')),
+        None => (rule, None),
+    }
+}
+
+/// Whether the `deny` pattern matches everything the `allow` pattern does
+///
+/// A trailing `:*` is a word prefix (`rm:*` covers `rm -rf /tmp` but not
+/// `rmdir`), and `*` matches any run of characters (`git push *`).
+fn command_pattern_covers(deny: &str, allow: &str) -> bool {
+    if deny == allow {
+        return true;
+    }
+    match deny.strip_suffix(":*") {
+        Some(prefix) => allow
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', ':'])),
+        None => crate::project_filter::wildcard_match(deny, allow),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_color_scheme_parse() {
+        for scheme in ColorScheme::ALL {
+            // Slugs are the settings names
+            assert_eq!(
+                serde_json::to_value(scheme).unwrap(),
+                serde_json::json!(scheme.slug())
+            );
+            assert_eq!(scheme.slug().parse::<ColorScheme>(), Ok(scheme));
+        }
+        assert_eq!("Light".parse(), Ok(ColorScheme::Light));
+        assert_eq!("colorblindsafe".parse(), Ok(ColorScheme::ColorBlindSafe));
+        assert_eq!(
+            "solarized".parse::<ColorScheme>(),
+            Err("unknown theme 'solarized' (valid: dark, light, colorblind-safe)".to_string())
+        );
+    }
+
     #[test]
     fn test_merge_scalar_override() {
         let global = Settings {
@@ -542,4 +997,147 @@ mod tests {
         let deny = perms.deny.unwrap();
         assert!(deny.contains(&"Bash".to_string()));
     }
+
+    #[test]
+    fn test_merge_permissions_deny_wins_across_layers() {
+        let perms = |allow: &[&str], deny: &[&str]| {
+            Some(Permissions {
+                allow: Some(allow.iter().map(|r| r.to_string()).collect()),
+                deny: Some(deny.iter().map(|r| r.to_string()).collect()),
+                ..Default::default()
+            })
+        };
+        // Global denies a rule that local allows; project allows a rule local denies
+        let global = Settings {
+            model: Some("opus".to_string()),
+            permissions: perms(&["Read"], &["Bash(rm:*)"]),
+            ..Default::default()
+        };
+        let project = Settings {
+            model: Some("sonnet".to_string()),
+            permissions: perms(&["Write", "WebFetch"], &[]),
+            ..Default::default()
+        };
+        let local = Settings {
+            permissions: perms(&["Bash(rm:*)", "Read"], &["WebFetch"]),
+            ..Default::default()
+        };
+
+        let merged = MergedConfig::from_layers(Some(global), None, Some(project), Some(local));
+        let perms = merged.merged.permissions.as_ref().unwrap();
+
+        assert_eq!(
+            perms.allow.as_deref(),
+            Some(&["Read".to_string(), "Write".to_string()][..])
+        );
+        assert_eq!(
+            perms.deny.as_deref(),
+            Some(&["Bash(rm:*)".to_string(), "WebFetch".to_string()][..])
+        );
+
+        assert_eq!(merged.source_of("model"), Some(ConfigLayer::Project));
+        assert_eq!(
+            merged.source_of("permissions.allow.Read"),
+            Some(ConfigLayer::Global)
+        );
+        assert_eq!(
+            merged.source_of("permissions.deny.WebFetch"),
+            Some(ConfigLayer::ProjectLocal)
+        );
+        // Dropped allow rules have no provenance
+        assert_eq!(merged.source_of("permissions.allow.WebFetch"), None);
+        assert_eq!(merged.source_of("theme"), None);
+    }
+
+    #[test]
+    fn test_deny_patterns_cover_narrower_allow_rules() {
+        let rules = |rules: &[&str]| Some(rules.iter().map(|r| r.to_string()).collect());
+        let global = Settings {
+            permissions: Some(Permissions {
+                deny: rules(&["Bash(rm:*)", "Bash(git push *)", "WebFetch", "mcp__github"]),
+                deny_bash: rules(&["sudo:*"]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let local = Settings {
+            permissions: Some(Permissions {
+                allow: rules(&[
+                    "Bash(rm -rf /tmp/build)",
+                    "Bash(rm -rf:*)",
+                    "Bash(rmdir build)",
+                    "Bash(git push origin main)",
+                    "Bash",
+                    "WebFetch(domain:docs.rs)",
+                    "mcp__github__create_issue",
+                    "mcp__github_enterprise__search",
+                ]),
+                allow_bash: rules(&["sudo apt update", "sudoedit /etc/hosts"]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let merged = MergedConfig::from_layers(Some(global), None, None, Some(local));
+        let perms = merged.merged.permissions.as_ref().unwrap();
+
+        assert_eq!(
+            perms.allow.as_deref(),
+            Some(
+                &[
+                    "Bash(rmdir build)".to_string(),
+                    "Bash".to_string(),
+                    "mcp__github_enterprise__search".to_string(),
+                ][..]
+            )
+        );
+        assert_eq!(
+            perms.allow_bash.as_deref(),
+            Some(&["sudoedit /etc/hosts".to_string()][..])
+        );
+        assert_eq!(merged.source_of("permissions.allow.Bash(rm -rf:*)"), None);
+    }
+
+    #[test]
+    fn test_project_budget_falls_back_to_global() {
+        let settings: Settings = serde_json::from_str(
+            r#"{
+                "budget": {
+                    "monthlyBudgetUsd": 100.0,
+                    "alertThresholdPct": 80.0,
+                    "perProject": {
+                        "/work/client-a/": { "monthlyBudgetUsd": 30.0, "alertThresholdPct": 90.0 },
+                        "/work/client-b": { "monthlyBudgetUsd": 20.0 }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let budget = settings.budget.unwrap();
+
+        let a = budget.project_budget("/work/client-a").unwrap();
+        assert_eq!(
+            (a.monthly_usd, a.alert_threshold_pct, a.explicit),
+            (30.0, 90.0, true)
+        );
+
+        // Threshold inherits the global one
+        let b = budget.project_budget("/work/client-b").unwrap();
+        assert_eq!(
+            (b.monthly_usd, b.alert_threshold_pct, b.explicit),
+            (20.0, 80.0, true)
+        );
+
+        let other = budget.project_budget("/work/internal").unwrap();
+        assert_eq!(
+            (other.monthly_usd, other.alert_threshold_pct, other.explicit),
+            (100.0, 80.0, false)
+        );
+
+        let no_global = BudgetConfig {
+            per_project: budget.per_project.clone(),
+            ..Default::default()
+        };
+        assert!(no_global.project_budget("/work/internal").is_none());
+    }
 }
@@ -29,6 +29,10 @@ pub struct InvocationStats {
 
     /// Number of sessions analyzed
     pub sessions_analyzed: usize,
+
+    /// Breakdown by project path (each session attributed to its project)
+    #[serde(default)]
+    pub per_project: HashMap<String, InvocationStats>,
 }
 
 impl InvocationStats {
@@ -41,9 +45,15 @@ impl InvocationStats {
             agent_token_stats: HashMap::new(),
             last_computed: Utc::now(),
             sessions_analyzed: 0,
+            per_project: HashMap::new(),
         }
     }
 
+    /// Stats for a single project, if any of its sessions were analyzed
+    pub fn for_project(&self, project_path: &str) -> Option<&InvocationStats> {
+        self.per_project.get(project_path)
+    }
+
     /// Get total number of invocations across all types
     pub fn total_invocations(&self) -> usize {
         self.agents.values().sum::<usize>()
@@ -66,6 +76,12 @@ impl InvocationStats {
             *self.agent_token_stats.entry(name.clone()).or_insert(0) += tokens;
         }
         self.sessions_analyzed += other.sessions_analyzed;
+        for (project, stats) in &other.per_project {
+            self.per_project
+                .entry(project.clone())
+                .or_default()
+                .merge(stats);
+        }
         // Keep the most recent timestamp
         if other.last_computed > self.last_computed {
             self.last_computed = other.last_computed;
@@ -107,4 +123,23 @@ mod tests {
         assert_eq!(stats1.commands.get("/commit"), Some(&12));
         assert_eq!(stats1.sessions_analyzed, 15);
     }
+
+    #[test]
+    fn test_merge_per_project() {
+        let mut project = InvocationStats::new();
+        project.skills.insert("pdf-generator".to_string(), 2);
+
+        let mut stats1 = InvocationStats::new();
+        stats1
+            .per_project
+            .insert("/work/app".to_string(), project.clone());
+        let mut stats2 = InvocationStats::new();
+        stats2.per_project.insert("/work/app".to_string(), project);
+
+        stats1.merge(&stats2);
+
+        let app = stats1.for_project("/work/app").unwrap();
+        assert_eq!(app.skills.get("pdf-generator"), Some(&4));
+        assert!(stats1.for_project("/work/other").is_none());
+    }
 }
@@ -11,17 +11,21 @@ pub mod plan;
 pub mod session;
 pub mod stats;
 
-pub use billing_block::{BillingBlock, BillingBlockManager, BillingBlockUsage};
+pub use billing_block::{
+    BillingBlock, BillingBlockManager, BillingBlockUsage, CurrentSpend, DEFAULT_BLOCK_HOURS,
+};
 pub use ccboard_config::CcboardConfig;
 pub use claude_mem::ClaudeMemSummary;
 pub use config::{
-    AnomalyThresholds, HookDefinition, HookGroup, MergedConfig, Permissions, Settings,
+    AnomalyThresholds, ConfigLayer, HookDefinition, HookGroup, MergedConfig, Permissions,
+    ProjectDisplayMode, Settings, TokenSource,
 };
 pub use insight::{Insight, InsightType};
 pub use invocations::InvocationStats;
 pub use plan::{Phase, PhaseStatus, PlanFile, PlanMetadata, Task};
 pub use session::{
-    ConversationMessage, MessageRole, ProjectId, SessionContent, SessionId, SessionLine,
-    SessionMessage, SessionMetadata, SessionSummary, SourceTool, TokenUsage, ToolCall, ToolResult,
+    CompactMetadata, ConversationMessage, MessageRole, ModelCost, ProjectId, SessionContent,
+    SessionCostBreakdown, SessionDiff, SessionId, SessionLine, SessionMessage, SessionMetadata,
+    SessionSummary, SourceTool, TokenCosts, TokenUsage, ToolCall, ToolResult,
 };
 pub use stats::{ContextWindowStats, DailyActivity, ModelUsage, StatsCache};
@@ -1,6 +1,7 @@
 //! Data models for PLAN.md files
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Full PLAN.md file structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +13,21 @@ pub struct PlanFile {
     pub phases: Vec<Phase>,
 }
 
+impl PlanFile {
+    /// Status of each task, inherited from the phase it belongs to
+    pub fn task_statuses(&self) -> HashMap<String, PhaseStatus> {
+        self.phases
+            .iter()
+            .flat_map(|phase| {
+                phase
+                    .tasks
+                    .iter()
+                    .map(move |task| (task.id.clone(), phase.status))
+            })
+            .collect()
+    }
+}
+
 /// YAML frontmatter metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlanMetadata {
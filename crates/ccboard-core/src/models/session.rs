@@ -1,5 +1,7 @@
 //! Session models for JSONL session files
 
+use crate::analytics::MetricDelta;
+use crate::models::config::TokenSource;
 use chrono::{DateTime, Utc};
 use rusqlite::types::{FromSql, FromSqlError, ToSql, ToSqlOutput, ValueRef};
 use serde::{Deserialize, Serialize};
@@ -298,6 +300,37 @@ pub struct SessionLine {
     /// Parent session ID (for subagents)
     #[serde(default)]
     pub parent_session_id: Option<String>,
+
+    /// Subtype of `system` lines (`compact_boundary` marks a compaction)
+    #[serde(default)]
+    pub subtype: Option<String>,
+
+    /// Set on the user message holding the summary a compaction left behind
+    #[serde(default)]
+    pub is_compact_summary: bool,
+
+    /// Trigger and context size of a compaction (on `compact_boundary` lines)
+    #[serde(default)]
+    pub compact_metadata: Option<CompactMetadata>,
+}
+
+impl SessionLine {
+    /// Whether this line marks a context compaction
+    pub fn is_compact_boundary(&self) -> bool {
+        self.subtype.as_deref() == Some("compact_boundary")
+    }
+}
+
+/// Details recorded with a compaction
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactMetadata {
+    /// `auto` (context full) or `manual` (`/compact`)
+    #[serde(default)]
+    pub trigger: Option<String>,
+    /// Context size in tokens right before compacting
+    #[serde(default)]
+    pub pre_tokens: Option<u64>,
 }
 
 /// Message content in a session
@@ -359,6 +392,20 @@ impl TokenUsage {
     pub fn total(&self) -> u64 {
         self.input_tokens + self.output_tokens + self.cache_read_tokens + self.cache_write_tokens
     }
+
+    /// Estimated cost in USD at `model` rates
+    ///
+    /// Delegates to `pricing::calculate_cost` (dynamic pricing when loaded,
+    /// embedded table otherwise), so every view prices tokens the same way.
+    pub fn cost(&self, model: &str) -> f64 {
+        crate::pricing::calculate_cost(
+            model,
+            self.input_tokens,
+            self.output_tokens,
+            self.cache_write_tokens,
+            self.cache_read_tokens,
+        )
+    }
 }
 
 /// Summary at session end
@@ -469,6 +516,11 @@ pub struct SessionMetadata {
     #[serde(default)]
     pub parent_session_id: Option<String>,
 
+    /// Session this one resumed, when the file starts with lines carrying
+    /// another `sessionId` (Claude Code copies the resumed conversation over)
+    #[serde(default)]
+    pub resumed_from: Option<String>,
+
     /// Duration in seconds (from summary)
     pub duration_seconds: Option<u64>,
 
@@ -484,6 +536,13 @@ pub struct SessionMetadata {
     #[serde(default)]
     pub tool_token_usage: std::collections::HashMap<String, u64>,
 
+    /// Tokens spent on assistant turns that follow a tool result: tool name -> tokens
+    ///
+    /// Approximate: the whole turn is charged to the tool(s) whose results it
+    /// answers, split evenly when several results come back together.
+    #[serde(default)]
+    pub tool_followup_tokens: std::collections::HashMap<String, u64>,
+
     /// Which AI coding tool produced this session
     #[serde(default)]
     pub source_tool: SourceTool,
@@ -495,8 +554,48 @@ pub struct SessionMetadata {
     /// Lines removed in this session (from Edit old_string)
     #[serde(default)]
     pub lines_removed: u64,
+
+    /// Total size of tool_result content fed back to the model, in bytes
+    #[serde(default)]
+    pub tool_result_bytes: u64,
+
+    /// Largest prompt sent in one request (input + cache read + cache write)
+    #[serde(default)]
+    pub peak_context_tokens: u64,
+
+    /// `peak_context_tokens` as a percentage of that model's context window (0-100)
+    #[serde(default)]
+    pub peak_context_pct: f64,
+
+    /// Times Claude Code compacted the context (auto or `/compact`)
+    #[serde(default)]
+    pub compaction_count: u32,
+
+    /// JSONL lines that could not be parsed and were skipped
+    #[serde(default)]
+    pub malformed_lines: u64,
+
+    /// Rough token estimate (chars / 4) for assistant messages without a
+    /// `usage` block; not part of `total_tokens`, added in `TokenSource::Hybrid`
+    #[serde(default)]
+    pub estimated_extra_tokens: u64,
+
+    /// Working directory recorded in the JSONL (first `cwd` seen); unlike
+    /// `project_path`, which is decoded from the directory name, it keeps any
+    /// `-` in the project's path
+    #[serde(default)]
+    pub cwd: Option<String>,
+
+    /// `git config user.email` in the session's working directory, when
+    /// `trackAuthor` is on (filled in by the store after scanning, never
+    /// parsed from the JSONL)
+    #[serde(default)]
+    pub author: Option<String>,
 }
 
+/// Peak context utilization (percent) above which a session is flagged
+pub const CONTEXT_WARNING_PCT: f64 = 80.0;
+
 impl SessionMetadata {
     /// Create a minimal metadata from just file path
     pub fn from_path(path: PathBuf, project_path: ProjectId) -> Self {
@@ -527,13 +626,269 @@ impl SessionMetadata {
             first_user_message: None,
             has_subagents: false,
             parent_session_id: None,
+            resumed_from: None,
+            compaction_count: 0,
             duration_seconds: None,
             branch: None,
             tool_usage: std::collections::HashMap::new(),
             tool_token_usage: std::collections::HashMap::new(),
+            tool_followup_tokens: std::collections::HashMap::new(),
             source_tool: SourceTool::ClaudeCode,
             lines_added: 0,
             lines_removed: 0,
+            tool_result_bytes: 0,
+            peak_context_tokens: 0,
+            peak_context_pct: 0.0,
+            malformed_lines: 0,
+            estimated_extra_tokens: 0,
+            cwd: None,
+            author: None,
+        }
+    }
+
+    /// Whether the context window came close to the model's limit
+    /// (compaction and quality loss are likely past `CONTEXT_WARNING_PCT`)
+    pub fn context_near_limit(&self) -> bool {
+        self.peak_context_pct >= CONTEXT_WARNING_PCT
+    }
+
+    /// Token total under `source`: reported usage, plus the estimate in hybrid mode
+    pub fn tokens_for(&self, source: TokenSource) -> u64 {
+        match source {
+            TokenSource::Reported => self.total_tokens,
+            TokenSource::Hybrid => self.total_tokens + self.estimated_extra_tokens,
+        }
+    }
+
+    /// Whether [`tokens_for`](Self::tokens_for) includes estimated tokens
+    pub fn tokens_estimated(&self, source: TokenSource) -> bool {
+        source == TokenSource::Hybrid && self.estimated_extra_tokens > 0
+    }
+
+    /// Estimated input tokens contributed by tool results (~4 bytes per token)
+    pub fn tool_result_tokens(&self) -> u64 {
+        self.tool_result_bytes / 4
+    }
+
+    /// Estimated cost in USD from the token breakdown
+    ///
+    /// Per-message usage is not kept, so sessions that switched models split
+    /// their tokens across models in proportion to assistant messages
+    /// (`model_segments`). That split is an approximation, flagged by
+    /// [`is_multi_model`](Self::is_multi_model); single-model sessions are exact.
+    /// Tokens are priced at the rates in effect on the session's start date.
+    pub fn estimated_cost(&self) -> f64 {
+        self.model_shares()
+            .into_iter()
+            .map(|(model, share)| {
+                self.priced(
+                    model,
+                    self.input_tokens,
+                    self.output_tokens,
+                    self.cache_creation_tokens,
+                    self.cache_read_tokens,
+                ) * share
+            })
+            .sum()
+    }
+
+    /// Cost of the given tokens at `model` rates in effect when the session
+    /// started (today's rates for sessions without a timestamp)
+    pub(crate) fn priced(
+        &self,
+        model: &str,
+        input: u64,
+        output: u64,
+        cache_write: u64,
+        cache_read: u64,
+    ) -> f64 {
+        match self.first_timestamp {
+            Some(ts) => crate::pricing::calculate_cost_at(
+                model,
+                ts.date_naive(),
+                input,
+                output,
+                cache_write,
+                cache_read,
+            ),
+            None => crate::pricing::calculate_cost(model, input, output, cache_write, cache_read),
+        }
+    }
+
+    /// Share of the session's tokens attributed to each model, in order of
+    /// first use
+    ///
+    /// Proportional to assistant messages (`model_segments`); a model that
+    /// comes back after a switch (Opus → Sonnet → Opus) gets one entry.
+    /// Without segments the first model (or `unknown`) takes everything.
+    pub fn model_shares(&self) -> Vec<(&str, f64)> {
+        let segment_total: usize = self.model_segments.iter().map(|(_, n)| n).sum();
+        if segment_total == 0 {
+            return vec![(
+                self.models_used.first().map_or("unknown", |m| m.as_str()),
+                1.0,
+            )];
+        }
+        let mut shares: Vec<(&str, f64)> = Vec::new();
+        for (model, n) in &self.model_segments {
+            let share = *n as f64 / segment_total as f64;
+            match shares.iter_mut().find(|(m, _)| m == model) {
+                Some((_, total)) => *total += share,
+                None => shares.push((model.as_str(), share)),
+            }
+        }
+        shares
+    }
+
+    /// Estimated cost per token kind, overall and per model
+    ///
+    /// Splits tokens across models like [`estimated_cost`](Self::estimated_cost),
+    /// so the total matches it. Per-model token counts are rounded shares.
+    pub fn cost_breakdown(&self) -> SessionCostBreakdown {
+        let price = |model: &str, input, output, cache_write, cache_read, share: f64| {
+            self.priced(model, input, output, cache_write, cache_read) * share
+        };
+        let models: Vec<ModelCost> = self
+            .model_shares()
+            .into_iter()
+            .map(|(model, share)| {
+                let scaled = |tokens: u64| (tokens as f64 * share).round() as u64;
+                let costs = TokenCosts {
+                    input_tokens: scaled(self.input_tokens),
+                    output_tokens: scaled(self.output_tokens),
+                    cache_read_tokens: scaled(self.cache_read_tokens),
+                    cache_write_tokens: scaled(self.cache_creation_tokens),
+                    input_cost: price(model, self.input_tokens, 0, 0, 0, share),
+                    output_cost: price(model, 0, self.output_tokens, 0, 0, share),
+                    cache_read_cost: price(model, 0, 0, 0, self.cache_read_tokens, share),
+                    cache_write_cost: price(model, 0, 0, self.cache_creation_tokens, 0, share),
+                    total_cost: 0.0,
+                }
+                .with_total();
+                ModelCost {
+                    model: model.to_string(),
+                    share,
+                    costs,
+                }
+            })
+            .collect();
+
+        let sum = |cost: fn(&TokenCosts) -> f64| models.iter().map(|m| cost(&m.costs)).sum();
+        let total = TokenCosts {
+            input_tokens: self.input_tokens,
+            output_tokens: self.output_tokens,
+            cache_read_tokens: self.cache_read_tokens,
+            cache_write_tokens: self.cache_creation_tokens,
+            input_cost: sum(|c| c.input_cost),
+            output_cost: sum(|c| c.output_cost),
+            cache_read_cost: sum(|c| c.cache_read_cost),
+            cache_write_cost: sum(|c| c.cache_write_cost),
+            total_cost: 0.0,
+        }
+        .with_total();
+
+        SessionCostBreakdown {
+            total,
+            models,
+            approximate: self.is_multi_model(),
+        }
+    }
+
+    /// Whether more than one model answered (cost split is approximate)
+    pub fn is_multi_model(&self) -> bool {
+        self.models_used.len() > 1
+    }
+
+    /// Estimated cost per message, `None` for sessions without messages
+    pub fn cost_per_message(&self) -> Option<f64> {
+        (self.message_count > 0).then(|| self.estimated_cost() / self.message_count as f64)
+    }
+
+    /// Estimated cost per token, `None` for sessions without tokens
+    pub fn cost_per_token(&self) -> Option<f64> {
+        (self.total_tokens > 0).then(|| self.estimated_cost() / self.total_tokens as f64)
+    }
+
+    /// Compare against `other`, with `self` as the baseline
+    ///
+    /// Metric deltas are `other - self`. Duration falls back to the span
+    /// between first and last timestamps when no summary recorded it.
+    pub fn diff(&self, other: &SessionMetadata) -> SessionDiff {
+        let duration = |s: &SessionMetadata| {
+            s.duration_seconds
+                .or_else(|| {
+                    let span = s.last_timestamp? - s.first_timestamp?;
+                    Some(span.num_seconds().max(0) as u64)
+                })
+                .unwrap_or(0)
+        };
+
+        let metrics = [
+            (
+                "Tokens",
+                self.total_tokens as f64,
+                other.total_tokens as f64,
+            ),
+            (
+                "Messages",
+                self.message_count as f64,
+                other.message_count as f64,
+            ),
+            ("Duration", duration(self) as f64, duration(other) as f64),
+            ("Cost", self.estimated_cost(), other.estimated_cost()),
+        ]
+        .into_iter()
+        .map(|(metric, a, b)| MetricDelta {
+            metric: metric.to_string(),
+            a,
+            b,
+            delta: b - a,
+            delta_pct: (a > 0.0).then(|| (b - a) / a * 100.0),
+        })
+        .collect();
+
+        let split_models = |from: &[String], against: &[String]| -> Vec<String> {
+            let mut models: Vec<String> = from
+                .iter()
+                .filter(|m| !against.contains(m))
+                .cloned()
+                .collect();
+            models.sort();
+            models
+        };
+        let mut models_common: Vec<String> = self
+            .models_used
+            .iter()
+            .filter(|m| other.models_used.contains(m))
+            .cloned()
+            .collect();
+        models_common.sort();
+
+        let mut tools: Vec<&String> = self
+            .tool_usage
+            .keys()
+            .chain(other.tool_usage.keys())
+            .collect();
+        tools.sort();
+        tools.dedup();
+        let mut tool_usage: Vec<(String, usize, usize)> = tools
+            .into_iter()
+            .map(|tool| {
+                (
+                    tool.clone(),
+                    self.tool_usage.get(tool).copied().unwrap_or(0),
+                    other.tool_usage.get(tool).copied().unwrap_or(0),
+                )
+            })
+            .collect();
+        tool_usage.sort_by_key(|(_, a, b)| std::cmp::Reverse(a.abs_diff(*b)));
+
+        SessionDiff {
+            metrics,
+            models_only_a: split_models(&self.models_used, &other.models_used),
+            models_only_b: split_models(&other.models_used, &self.models_used),
+            models_common,
+            tool_usage,
         }
     }
 
@@ -560,6 +915,64 @@ impl SessionMetadata {
     }
 }
 
+/// Tokens and estimated cost (USD) per token kind
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TokenCosts {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_write_tokens: u64,
+    pub input_cost: f64,
+    pub output_cost: f64,
+    pub cache_read_cost: f64,
+    pub cache_write_cost: f64,
+    /// Sum of the four costs
+    pub total_cost: f64,
+}
+
+impl TokenCosts {
+    fn with_total(mut self) -> Self {
+        self.total_cost =
+            self.input_cost + self.output_cost + self.cache_read_cost + self.cache_write_cost;
+        self
+    }
+}
+
+/// One model's part of a session's cost
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelCost {
+    pub model: String,
+    /// Share of assistant messages answered by this model (1.0 if alone)
+    pub share: f64,
+    #[serde(flatten)]
+    pub costs: TokenCosts,
+}
+
+/// Session cost per token kind, see [`SessionMetadata::cost_breakdown`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionCostBreakdown {
+    pub total: TokenCosts,
+    /// In order of first use
+    pub models: Vec<ModelCost>,
+    /// Several models answered: tokens were split by message share
+    pub approximate: bool,
+}
+
+/// Side-by-side differences between two sessions, see [`SessionMetadata::diff`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionDiff {
+    /// Tokens, messages, duration (seconds) and estimated cost
+    pub metrics: Vec<MetricDelta>,
+    /// Models only the baseline (`a`) used
+    pub models_only_a: Vec<String>,
+    /// Models only the compared session (`b`) used
+    pub models_only_b: Vec<String>,
+    /// Models both sessions used
+    pub models_common: Vec<String>,
+    /// Per-tool call counts `(tool, a, b)`, largest change first
+    pub tool_usage: Vec<(String, usize, usize)>,
+}
+
 /// A single conversation message extracted from session JSONL
 ///
 /// Simplified representation for display in conversation viewer.
@@ -641,6 +1054,123 @@ mod tests {
         assert_eq!(usage.total(), 150);
     }
 
+    #[test]
+    fn test_token_usage_cost() {
+        let usage = TokenUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 100_000,
+            cache_read_tokens: 1_000_000,
+            cache_write_tokens: 1_000_000,
+        };
+        // Sonnet 4.5: $3 in, $15 out, cache read 0.1x, cache write 1.25x (per 1M)
+        let expected = 3.0 + 1.5 + 0.3 + 3.75;
+        assert!((usage.cost("claude-sonnet-4-5-20250929") - expected).abs() < 1e-9);
+        assert_eq!(TokenUsage::default().cost("opus"), 0.0);
+    }
+
+    #[test]
+    fn test_session_metadata_cost_metrics() {
+        let mut meta =
+            SessionMetadata::from_path(PathBuf::from("/test.jsonl"), ProjectId::from("test"));
+        assert_eq!(meta.cost_per_message(), None);
+        assert_eq!(meta.cost_per_token(), None);
+
+        // Sonnet 4.5: $3 in, $15 out per 1M
+        meta.models_used = vec!["claude-sonnet-4-5".to_string()];
+        meta.input_tokens = 1_000_000;
+        meta.output_tokens = 100_000;
+        meta.total_tokens = 1_100_000;
+        meta.message_count = 10;
+        assert!((meta.estimated_cost() - 4.5).abs() < 1e-9);
+        assert!((meta.cost_per_message().unwrap() - 0.45).abs() < 1e-9);
+        assert!(!meta.is_multi_model());
+
+        // Opus 4.5 ($5 in, $25 out) for 1 of 4 assistant messages
+        meta.models_used.push("claude-opus-4-5".to_string());
+        meta.model_segments = vec![
+            ("claude-sonnet-4-5".to_string(), 3),
+            ("claude-opus-4-5".to_string(), 1),
+        ];
+        assert!((meta.estimated_cost() - (4.5 * 0.75 + 7.5 * 0.25)).abs() < 1e-9);
+        assert!(meta.is_multi_model());
+    }
+
+    #[test]
+    fn test_session_cost_breakdown() {
+        let mut meta =
+            SessionMetadata::from_path(PathBuf::from("/test.jsonl"), ProjectId::from("test"));
+        meta.models_used = vec!["claude-sonnet-4-5".to_string()];
+        meta.input_tokens = 1_000_000;
+        meta.output_tokens = 100_000;
+        meta.cache_read_tokens = 1_000_000;
+        meta.cache_creation_tokens = 1_000_000;
+
+        // Sonnet 4.5: $3 in, $15 out, cache read 0.1x, cache write 1.25x (per 1M)
+        let single = meta.cost_breakdown();
+        assert!(!single.approximate);
+        assert_eq!(single.models.len(), 1);
+        assert!((single.total.input_cost - 3.0).abs() < 1e-9);
+        assert!((single.total.output_cost - 1.5).abs() < 1e-9);
+        assert!((single.total.cache_read_cost - 0.3).abs() < 1e-9);
+        assert!((single.total.cache_write_cost - 3.75).abs() < 1e-9);
+        assert!((single.total.total_cost - meta.estimated_cost()).abs() < 1e-9);
+
+        // Opus → Sonnet → Opus: Opus segments merge into one row
+        meta.models_used.insert(0, "claude-opus-4-5".to_string());
+        meta.model_segments = vec![
+            ("claude-opus-4-5".to_string(), 1),
+            ("claude-sonnet-4-5".to_string(), 2),
+            ("claude-opus-4-5".to_string(), 1),
+        ];
+        let split = meta.cost_breakdown();
+        assert!(split.approximate);
+        let rows: Vec<(&str, f64, u64)> = split
+            .models
+            .iter()
+            .map(|m| (m.model.as_str(), m.share, m.costs.input_tokens))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("claude-opus-4-5", 0.5, 500_000),
+                ("claude-sonnet-4-5", 0.5, 500_000)
+            ]
+        );
+        assert_eq!(split.total.input_tokens, 1_000_000);
+        assert!((split.total.total_cost - meta.estimated_cost()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_session_metadata_diff() {
+        let mut a = SessionMetadata::from_path(PathBuf::from("/a.jsonl"), ProjectId::from("test"));
+        a.total_tokens = 1_000;
+        a.message_count = 10;
+        a.duration_seconds = Some(600);
+        a.models_used = vec!["claude-sonnet-4-5".to_string()];
+        a.tool_usage = [("Read".to_string(), 4), ("Bash".to_string(), 1)].into();
+
+        let mut b = a.clone();
+        b.total_tokens = 3_000;
+        b.message_count = 0;
+        b.duration_seconds = None;
+        b.models_used.push("claude-opus-4-5".to_string());
+        b.tool_usage = [("Read".to_string(), 4), ("Edit".to_string(), 6)].into();
+
+        let diff = a.diff(&b);
+        let tokens = &diff.metrics[0];
+        assert_eq!(tokens.metric, "Tokens");
+        assert_eq!(tokens.delta, 2_000.0);
+        assert_eq!(tokens.delta_pct, Some(200.0));
+        assert_eq!(diff.metrics[2].b, 0.0); // No duration, no timestamps
+
+        assert_eq!(diff.models_common, vec!["claude-sonnet-4-5"]);
+        assert!(diff.models_only_a.is_empty());
+        assert_eq!(diff.models_only_b, vec!["claude-opus-4-5"]);
+
+        assert_eq!(diff.tool_usage[0], ("Edit".to_string(), 0, 6));
+        assert_eq!(diff.tool_usage.last().unwrap(), &("Read".to_string(), 4, 4));
+    }
+
     #[test]
     fn test_session_metadata_duration_display() {
         let mut meta =
@@ -4,6 +4,7 @@
 //! Key fields: dailyActivity (array), dailyModelTokens (array), modelUsage (object),
 //! totalSessions, totalMessages, hourCounts.
 
+use super::session::{SessionMetadata, TokenUsage};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -101,6 +102,16 @@ pub struct ModelUsage {
 }
 
 impl ModelUsage {
+    /// Token counts as a `TokenUsage`, for pricing via `TokenUsage::cost`
+    pub fn as_token_usage(&self) -> TokenUsage {
+        TokenUsage {
+            input_tokens: self.input_tokens,
+            output_tokens: self.output_tokens,
+            cache_read_tokens: self.cache_read_input_tokens,
+            cache_write_tokens: self.cache_creation_input_tokens,
+        }
+    }
+
     pub fn total_tokens(&self) -> u64 {
         self.input_tokens + self.output_tokens
     }
@@ -111,6 +122,42 @@ impl ModelUsage {
             + self.cache_read_input_tokens
             + self.cache_creation_input_tokens
     }
+
+    /// Model family of a model ID: `claude-<opus|sonnet|haiku>-<major>`
+    ///
+    /// Collapses dated and minor revisions, so `claude-sonnet-4-5-20250929` and
+    /// `claude-sonnet-4-20250514` both map to `claude-sonnet-4`, and the legacy
+    /// `claude-3-5-sonnet-20241022` maps to `claude-sonnet-3`. Non-Claude IDs
+    /// are returned as-is.
+    pub fn family(model_id: &str) -> String {
+        let canonical = crate::pricing::normalize_model_id(model_id).to_ascii_lowercase();
+        let parts: Vec<&str> = canonical.split(['-', '.']).collect();
+        let Some(family) = parts
+            .iter()
+            .find(|part| crate::pricing::MODEL_FAMILIES.contains(part))
+        else {
+            return model_id.to_string();
+        };
+        // First short numeric part is the major version (skips -YYYYMMDD dates)
+        match parts.iter().find(|part| {
+            !part.is_empty() && part.len() < 8 && part.chars().all(|c| c.is_ascii_digit())
+        }) {
+            Some(major) => format!("claude-{}-{}", family, major),
+            None => format!("claude-{}", family),
+        }
+    }
+
+    /// Add another entry's counts into this one (same model or same group)
+    fn absorb(&mut self, other: &ModelUsage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_read_input_tokens += other.cache_read_input_tokens;
+        self.cache_creation_input_tokens += other.cache_creation_input_tokens;
+        self.web_search_requests += other.web_search_requests;
+        self.cost_usd += other.cost_usd;
+        self.context_window = self.context_window.max(other.context_window);
+        self.max_output_tokens = self.max_output_tokens.max(other.max_output_tokens);
+    }
 }
 
 /// Longest session info
@@ -189,6 +236,113 @@ impl StatsCache {
         }
     }
 
+    /// Merge model entries that are aliases of the same model
+    ///
+    /// Keys in `model_usage` and `daily_model_tokens` are rewritten to their canonical
+    /// form (see `pricing::normalize_model_id`) so e.g. `claude-opus-4-5` and
+    /// `claude-opus-4-5-20251101` show up as a single row.
+    pub fn normalize_model_ids(&mut self) {
+        let mut merged: HashMap<String, ModelUsage> = HashMap::new();
+        for (model_name, usage) in self.model_usage.drain() {
+            merged
+                .entry(crate::pricing::normalize_model_id(&model_name))
+                .or_default()
+                .absorb(&usage);
+        }
+        self.model_usage = merged;
+
+        for day in &mut self.daily_model_tokens {
+            let mut tokens: HashMap<String, u64> = HashMap::new();
+            for (model_name, count) in day.tokens_by_model.drain() {
+                *tokens
+                    .entry(crate::pricing::normalize_model_id(&model_name))
+                    .or_default() += count;
+            }
+            day.tokens_by_model = tokens;
+        }
+    }
+
+    /// `model_usage` aggregated by model family (see [`ModelUsage::family`])
+    ///
+    /// `cost_usd` is summed from the per-model entries, so each revision keeps
+    /// its own pricing.
+    pub fn usage_by_family(&self) -> HashMap<String, ModelUsage> {
+        let mut families: HashMap<String, ModelUsage> = HashMap::new();
+        for (model_name, usage) in &self.model_usage {
+            families
+                .entry(ModelUsage::family(model_name))
+                .or_default()
+                .absorb(usage);
+        }
+        families
+    }
+
+    /// Remove the contribution of `sessions` from the aggregates
+    ///
+    /// stats-cache.json has no per-project breakdown, so excluded sessions are
+    /// subtracted using their own metadata: counts from the session's local start
+    /// date, tokens split across models like [`SessionMetadata::estimated_cost`].
+    /// Sessions started after `last_computed_date` were never counted in the
+    /// cache and are skipped. Costs are recalculated afterwards.
+    pub fn exclude_sessions<'a>(
+        &mut self,
+        sessions: impl IntoIterator<Item = &'a SessionMetadata>,
+    ) {
+        for session in sessions {
+            let date = session.first_timestamp.map(|ts| {
+                ts.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d")
+                    .to_string()
+            });
+            // Dates are ISO, so string order is chronological
+            if let (Some(date), Some(cutoff)) = (&date, &self.last_computed_date) {
+                if date > cutoff {
+                    continue;
+                }
+            }
+
+            self.total_sessions = self.total_sessions.saturating_sub(1);
+            self.total_messages = self.total_messages.saturating_sub(session.message_count);
+
+            let day_index = date
+                .as_ref()
+                .and_then(|date| self.daily_activity.iter().position(|d| &d.date == date));
+            if let Some(day) = day_index.map(|i| &mut self.daily_activity[i]) {
+                day.session_count = day.session_count.saturating_sub(1);
+                day.message_count = day.message_count.saturating_sub(session.message_count);
+            }
+
+            for (model, share) in session.model_shares() {
+                let model = crate::pricing::normalize_model_id(model);
+                let scaled = |tokens: u64| (tokens as f64 * share).round() as u64;
+
+                if let Some(usage) = self.model_usage.get_mut(&model) {
+                    usage.input_tokens = usage
+                        .input_tokens
+                        .saturating_sub(scaled(session.input_tokens));
+                    usage.output_tokens = usage
+                        .output_tokens
+                        .saturating_sub(scaled(session.output_tokens));
+                    usage.cache_read_input_tokens = usage
+                        .cache_read_input_tokens
+                        .saturating_sub(scaled(session.cache_read_tokens));
+                    usage.cache_creation_input_tokens = usage
+                        .cache_creation_input_tokens
+                        .saturating_sub(scaled(session.cache_creation_tokens));
+                }
+
+                let day = date
+                    .as_ref()
+                    .and_then(|date| self.daily_model_tokens.iter_mut().find(|d| &d.date == date));
+                if let Some(tokens) = day.and_then(|d| d.tokens_by_model.get_mut(&model)) {
+                    *tokens =
+                        tokens.saturating_sub(scaled(session.input_tokens + session.output_tokens));
+                }
+            }
+        }
+        self.recalculate_costs();
+    }
+
     /// Get session count
     pub fn session_count(&self) -> u64 {
         self.total_sessions
@@ -235,6 +389,26 @@ impl StatsCache {
     /// Context window size for Sonnet 4.5 (200K tokens)
     pub const CONTEXT_WINDOW: u64 = 200_000;
 
+    /// Context window limits by model family, first substring match wins
+    const CONTEXT_WINDOWS: &'static [(&'static str, u64)] = &[
+        ("claude", 200_000),
+        ("gemini", 1_048_576),
+        ("gpt-4.1", 1_047_576),
+        ("gpt-5", 400_000),
+        ("gpt-4o", 128_000),
+        ("o3", 200_000),
+        ("o4-mini", 200_000),
+    ];
+
+    /// Context window limit for `model` (falls back to `CONTEXT_WINDOW`)
+    pub fn context_window_for(model: &str) -> u64 {
+        let model = model.to_ascii_lowercase();
+        Self::CONTEXT_WINDOWS
+            .iter()
+            .find(|(family, _)| model.contains(family))
+            .map_or(Self::CONTEXT_WINDOW, |(_, limit)| *limit)
+    }
+
     /// Calculate context window saturation from session metadata
     ///
     /// NOTE: Requires session metadata to be passed from DataStore
@@ -343,6 +517,20 @@ pub struct ContextWindowStats {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_context_window_for_model() {
+        assert_eq!(
+            StatsCache::context_window_for("claude-sonnet-4-5-20250929"),
+            200_000
+        );
+        assert_eq!(StatsCache::context_window_for("gemini-2.5-pro"), 1_048_576);
+        assert_eq!(StatsCache::context_window_for("GPT-4o-mini"), 128_000);
+        assert_eq!(
+            StatsCache::context_window_for("unknown-model"),
+            StatsCache::CONTEXT_WINDOW
+        );
+    }
+
     #[test]
     fn test_stats_cache_defaults() {
         let stats = StatsCache::default();
@@ -350,6 +538,140 @@ mod tests {
         assert!(stats.model_usage.is_empty());
     }
 
+    #[test]
+    fn test_normalize_model_ids_merges_aliases() {
+        let mut stats = StatsCache::default();
+        stats.model_usage.insert(
+            "claude-opus-4-5-20251101".to_string(),
+            ModelUsage {
+                input_tokens: 100,
+                output_tokens: 50,
+                context_window: 200_000,
+                ..Default::default()
+            },
+        );
+        stats.model_usage.insert(
+            "claude-opus-4.5".to_string(),
+            ModelUsage {
+                input_tokens: 10,
+                output_tokens: 5,
+                ..Default::default()
+            },
+        );
+        stats.model_usage.insert(
+            "claude-sonnet-4-5".to_string(),
+            ModelUsage {
+                input_tokens: 1,
+                ..Default::default()
+            },
+        );
+        stats.daily_model_tokens.push(DailyModelTokens {
+            date: "2026-01-01".to_string(),
+            tokens_by_model: HashMap::from([
+                ("opus-4".to_string(), 7),
+                ("claude-opus-4-5".to_string(), 3),
+            ]),
+        });
+
+        stats.normalize_model_ids();
+
+        assert_eq!(stats.model_usage.len(), 2);
+        let opus = &stats.model_usage["claude-opus-4-5"];
+        assert_eq!(opus.input_tokens, 110);
+        assert_eq!(opus.output_tokens, 55);
+        assert_eq!(opus.context_window, 200_000);
+        assert_eq!(
+            stats.daily_model_tokens[0].tokens_by_model["claude-opus-4-5"],
+            10
+        );
+    }
+
+    #[test]
+    fn test_exclude_sessions_splits_models_and_skips_uncounted() {
+        use crate::models::session::{ProjectId, SessionMetadata};
+        use chrono::TimeZone;
+
+        let usage = |input| ModelUsage {
+            input_tokens: input,
+            ..Default::default()
+        };
+        let mut stats = StatsCache {
+            last_computed_date: Some("2026-01-15".to_string()),
+            total_sessions: 10,
+            total_messages: 100,
+            model_usage: HashMap::from([
+                ("claude-opus-4-5".to_string(), usage(1_000)),
+                ("claude-sonnet-4-5".to_string(), usage(1_000)),
+            ]),
+            ..Default::default()
+        };
+
+        // Opus for 1 message, Sonnet for 3: a quarter of the tokens are Opus
+        let mut counted =
+            SessionMetadata::from_path("/a.jsonl".into(), ProjectId::from("/excluded"));
+        counted.first_timestamp =
+            Some(chrono::Utc.with_ymd_and_hms(2026, 1, 10, 12, 0, 0).unwrap());
+        counted.message_count = 8;
+        counted.input_tokens = 400;
+        counted.models_used = vec!["claude-opus-4-5".into(), "claude-sonnet-4-5".into()];
+        counted.model_segments = vec![
+            ("claude-opus-4-5".to_string(), 1),
+            ("claude-sonnet-4-5".to_string(), 3),
+        ];
+
+        // Started after the cache was computed: never part of the totals
+        let mut uncounted = counted.clone();
+        uncounted.first_timestamp =
+            Some(chrono::Utc.with_ymd_and_hms(2026, 1, 20, 12, 0, 0).unwrap());
+
+        stats.exclude_sessions([&counted, &uncounted]);
+
+        assert_eq!(stats.total_sessions, 9);
+        assert_eq!(stats.total_messages, 92);
+        assert_eq!(stats.model_usage["claude-opus-4-5"].input_tokens, 900);
+        assert_eq!(stats.model_usage["claude-sonnet-4-5"].input_tokens, 700);
+    }
+
+    #[test]
+    fn test_usage_by_family() {
+        assert_eq!(
+            ModelUsage::family("claude-sonnet-4-5-20250929"),
+            "claude-sonnet-4"
+        );
+        assert_eq!(
+            ModelUsage::family("claude-sonnet-4-20250514"),
+            "claude-sonnet-4"
+        );
+        assert_eq!(ModelUsage::family("opus"), "claude-opus-4");
+        assert_eq!(
+            ModelUsage::family("claude-3-5-sonnet-20241022"),
+            "claude-sonnet-3"
+        );
+        assert_eq!(ModelUsage::family("gpt-5.4"), "gpt-5.4");
+
+        let mut stats = StatsCache::default();
+        for (model, cost) in [
+            ("claude-sonnet-4-5-20250929", 1.5),
+            ("claude-sonnet-4-5-20251001", 0.5),
+            ("claude-haiku-4-5", 0.25),
+        ] {
+            stats.model_usage.insert(
+                model.to_string(),
+                ModelUsage {
+                    input_tokens: 10,
+                    cost_usd: cost,
+                    ..Default::default()
+                },
+            );
+        }
+
+        let families = stats.usage_by_family();
+        assert_eq!(families.len(), 2);
+        let sonnet = &families["claude-sonnet-4"];
+        assert_eq!(sonnet.input_tokens, 20);
+        assert!((sonnet.cost_usd - 2.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_model_usage_total() {
         let usage = ModelUsage {
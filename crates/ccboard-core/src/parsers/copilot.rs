@@ -140,12 +140,22 @@ impl CopilotParser {
                     first_user_message: None,
                     has_subagents: false,
                     parent_session_id: None,
+                    resumed_from: None,
+                    compaction_count: 0,
                     duration_seconds,
                     branch: None,
                     tool_usage: HashMap::new(),
                     tool_token_usage: HashMap::new(),
+                    tool_followup_tokens: HashMap::new(),
                     lines_added: 0,
                     lines_removed: 0,
+                    tool_result_bytes: 0,
+                    peak_context_tokens: 0,
+                    peak_context_pct: 0.0,
+                    malformed_lines: 0,
+                    estimated_extra_tokens: 0,
+                    cwd: None,
+                    author: None,
                 }
             })
             .collect();
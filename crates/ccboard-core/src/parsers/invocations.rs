@@ -171,6 +171,45 @@ impl InvocationParser {
         }
     }
 
+    /// Scan `(session file, project path)` pairs and aggregate stats
+    ///
+    /// Same totals as [`Self::scan_sessions`], plus a `per_project` breakdown.
+    pub async fn scan_sessions_by_project(
+        &self,
+        sessions: &[(impl AsRef<Path>, String)],
+    ) -> InvocationStats {
+        let mut aggregated = InvocationStats::new();
+
+        for (path, project) in sessions {
+            match self.scan_session(path.as_ref()).await {
+                Ok(stats) => {
+                    aggregated.merge(&stats);
+                    aggregated
+                        .per_project
+                        .entry(project.clone())
+                        .or_default()
+                        .merge(&stats);
+                }
+                Err(e) => {
+                    trace!(
+                        path = %path.as_ref().display(),
+                        error = %e,
+                        "Failed to scan session for invocations"
+                    );
+                }
+            }
+        }
+
+        debug!(
+            sessions = aggregated.sessions_analyzed,
+            projects = aggregated.per_project.len(),
+            total = aggregated.total_invocations(),
+            "Aggregated invocation stats by project"
+        );
+
+        aggregated
+    }
+
     /// Scan multiple session files and aggregate stats
     pub async fn scan_sessions(&self, paths: &[impl AsRef<Path>]) -> InvocationStats {
         let mut aggregated = InvocationStats::new();
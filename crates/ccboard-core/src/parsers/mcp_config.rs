@@ -1,12 +1,16 @@
 //! Parser for Claude Desktop MCP server configuration
 //!
-//! Parses `~/.claude/claude_desktop_config.json` to extract MCP server definitions.
+//! Parses `~/.claude/claude_desktop_config.json` to extract MCP server definitions,
+//! and probes servers with an `initialize` handshake (`check_server_health`).
 
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 
 /// MCP server configuration from claude_desktop_config.json
 #[derive(Debug, Clone, Deserialize)]
@@ -170,10 +174,192 @@ impl McpConfig {
     }
 }
 
+/// How long a health check waits for the `initialize` response
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Result of an MCP liveness check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    /// Server answered the `initialize` handshake
+    Reachable,
+    /// Spawn/connection failed, the process exited, or the server returned an error
+    Unreachable,
+    /// No answer within the timeout
+    Timeout,
+}
+
+impl HealthStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            HealthStatus::Reachable => "Reachable",
+            HealthStatus::Unreachable => "Unreachable",
+            HealthStatus::Timeout => "Timeout",
+        }
+    }
+}
+
+/// JSON-RPC `initialize` request sent by health checks
+fn initialize_request() -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "ccboard", "version": env!("CARGO_PKG_VERSION") }
+        }
+    })
+}
+
+/// Check whether an MCP server answers an `initialize` handshake
+///
+/// Stdio servers are spawned (and killed afterwards); HTTP servers get the
+/// request POSTed; SSE servers only need to accept the event-stream connection.
+/// Gives up after `HEALTH_CHECK_TIMEOUT`.
+pub async fn check_server_health(server: &McpServer) -> HealthStatus {
+    check_server_health_with_timeout(server, HEALTH_CHECK_TIMEOUT).await
+}
+
+/// Same as `check_server_health` with a custom timeout
+pub async fn check_server_health_with_timeout(
+    server: &McpServer,
+    timeout: Duration,
+) -> HealthStatus {
+    let probe = async {
+        if server.is_http() {
+            probe_http(server).await
+        } else {
+            probe_stdio(server).await
+        }
+    };
+    match tokio::time::timeout(timeout, probe).await {
+        Ok(true) => HealthStatus::Reachable,
+        Ok(false) => HealthStatus::Unreachable,
+        Err(_) => HealthStatus::Timeout,
+    }
+}
+
+/// Spawn the server, send `initialize` and wait for the matching response
+async fn probe_stdio(server: &McpServer) -> bool {
+    if server.command.is_empty() {
+        return false;
+    }
+    let child = tokio::process::Command::new(&server.command)
+        .args(&server.args)
+        .envs(&server.env)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        // Dropped on timeout too, so the server never outlives the check
+        .kill_on_drop(true)
+        .spawn();
+    let Ok(mut child) = child else {
+        return false;
+    };
+    let (Some(mut stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+        return false;
+    };
+
+    let mut request = initialize_request().to_string();
+    request.push('\n');
+    if stdin.write_all(request.as_bytes()).await.is_err() {
+        return false;
+    }
+
+    // Skip notifications/log lines until the response to our request
+    let mut lines = BufReader::new(stdout).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let Ok(message) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        if message.get("id") == Some(&serde_json::json!(1)) {
+            return message.get("result").is_some();
+        }
+    }
+    false
+}
+
+/// POST `initialize` (streamable HTTP) or open the event stream (SSE)
+async fn probe_http(server: &McpServer) -> bool {
+    let Some(url) = &server.url else {
+        return false;
+    };
+    let is_sse = server
+        .server_type
+        .as_deref()
+        .is_some_and(|t| t.eq_ignore_ascii_case("sse"));
+
+    let client = reqwest::Client::new();
+    let mut request = if is_sse {
+        client.get(url).header("Accept", "text/event-stream")
+    } else {
+        client
+            .post(url)
+            .header("Accept", "application/json, text/event-stream")
+            .json(&initialize_request())
+    };
+    for (key, value) in server.headers.iter().flatten() {
+        request = request.header(key, value);
+    }
+
+    // Headers are enough: an SSE body never ends
+    request
+        .send()
+        .await
+        .is_ok_and(|response| response.status().is_success())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_check_server_health_stdio() {
+        let server = |script: &str| McpServer {
+            server_type: None,
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+            env: HashMap::new(),
+            url: None,
+            headers: None,
+            always_load: false,
+        };
+        let timeout = Duration::from_millis(500);
+
+        let responds = server(
+            r#"read line; echo '{"jsonrpc":"2.0","method":"notifications/message"}'; echo '{"jsonrpc":"2.0","id":1,"result":{}}'"#,
+        );
+        assert_eq!(
+            check_server_health_with_timeout(&responds, timeout).await,
+            HealthStatus::Reachable
+        );
+
+        let exits = server("exit 1");
+        assert_eq!(
+            check_server_health_with_timeout(&exits, timeout).await,
+            HealthStatus::Unreachable
+        );
+
+        let hangs = server("sleep 5");
+        assert_eq!(
+            check_server_health_with_timeout(&hangs, Duration::from_millis(100)).await,
+            HealthStatus::Timeout
+        );
+
+        let missing = McpServer {
+            command: "ccboard-no-such-mcp-server".to_string(),
+            args: Vec::new(),
+            ..responds
+        };
+        assert_eq!(
+            check_server_health_with_timeout(&missing, timeout).await,
+            HealthStatus::Unreachable
+        );
+    }
+
     #[test]
     fn test_parse_mcp_config() {
         let json = r#"{
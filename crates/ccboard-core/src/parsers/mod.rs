@@ -12,6 +12,7 @@ pub mod invocations;
 pub mod mcp_config;
 pub mod opencode;
 pub mod plan_parser;
+pub mod plugins;
 pub mod rules;
 pub mod session_content;
 pub mod session_index;
@@ -34,9 +35,12 @@ pub use invocations::InvocationParser;
 pub use mcp_config::McpConfig;
 pub use opencode::OpenCodeParser;
 pub use plan_parser::PlanParser;
+pub use plugins::{load_plugins, PluginInfo, PluginStatus};
 pub use rules::Rules;
-pub use session_content::SessionContentParser;
-pub use session_index::SessionIndexParser;
+pub use session_content::{
+    ContentMatch, GrepPattern, ParsedLines, SessionContentParser, ToolTimeline,
+};
+pub use session_index::{ScanProgress, ScanProgressFn, SessionIndexParser, SessionScanState};
 pub use settings::SettingsParser;
 pub use stats::StatsParser;
 pub use task::{Task, TaskParser, TaskStatus};
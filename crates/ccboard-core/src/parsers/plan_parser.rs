@@ -7,7 +7,7 @@ use crate::models::plan::{Phase, PhaseStatus, PlanFile, PlanMetadata, Task};
 use anyhow::{Context, Result};
 use regex::Regex;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Task metadata tuple (issue, duration, difficulty, crate_name, description)
 type TaskMetadata = (
@@ -22,6 +22,21 @@ type TaskMetadata = (
 pub struct PlanParser;
 
 impl PlanParser {
+    /// Locate the PLAN.md to use, by priority
+    ///
+    /// Checks `<cwd>/claudedocs/PLAN.md`, `<cwd>/.claude/PLAN.md`, then
+    /// `<home>/.claude/claudedocs/PLAN.md`, falling back to `<cwd>/PLAN.md`.
+    pub fn locate(cwd: &Path, home: &Path) -> PathBuf {
+        [
+            cwd.join("claudedocs/PLAN.md"),
+            cwd.join(".claude/PLAN.md"),
+            home.join(".claude/claudedocs/PLAN.md"),
+        ]
+        .into_iter()
+        .find(|p| p.exists())
+        .unwrap_or_else(|| cwd.join("PLAN.md"))
+    }
+
     /// Parse a PLAN.md file from a path
     pub fn parse_file(path: &Path) -> Result<Option<PlanFile>> {
         if !path.exists() {
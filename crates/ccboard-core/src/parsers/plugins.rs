@@ -0,0 +1,243 @@
+//! Parser for installed Claude Code plugins
+//!
+//! Installed plugins are recorded in `~/.claude/plugins/installed_plugins.json`
+//! (keyed `name@marketplace`), and switched on or off by `enabledPlugins` in
+//! settings. Both files are cross-checked so broken installs can be flagged:
+//! a plugin enabled but never installed, an install directory that no longer
+//! exists, or an unparseable `.claude-plugin/plugin.json` manifest.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Plugin registry, relative to the Claude home
+const REGISTRY_PATH: &str = "plugins/installed_plugins.json";
+
+/// Plugin manifest, relative to the plugin's install directory
+const MANIFEST_PATH: &str = ".claude-plugin/plugin.json";
+
+/// An installed (or enabled but missing) plugin
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PluginInfo {
+    /// Registry key, e.g. `code-review@claude-plugins`
+    pub name: String,
+    /// `enabledPlugins` says `true`
+    pub enabled: bool,
+    /// Install directory, when the registry records one
+    pub path: Option<PathBuf>,
+    /// Why the plugin can't load, if anything is wrong with it
+    pub error: Option<String>,
+}
+
+/// Load state shown in the plugin lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PluginStatus {
+    Enabled,
+    Disabled,
+    Error,
+}
+
+impl PluginStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Enabled => "enabled",
+            Self::Disabled => "disabled",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl PluginInfo {
+    /// `Error` wins over the enabled flag
+    pub fn status(&self) -> PluginStatus {
+        if self.error.is_some() {
+            PluginStatus::Error
+        } else if self.enabled {
+            PluginStatus::Enabled
+        } else {
+            PluginStatus::Disabled
+        }
+    }
+}
+
+/// `installed_plugins.json`
+///
+/// Version 1 maps each plugin to one install; version 2 to a list of installs
+/// (one per scope). Only the install path matters here.
+#[derive(Debug, Deserialize)]
+struct Registry {
+    #[serde(default)]
+    plugins: HashMap<String, RegistryEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RegistryEntry {
+    Single(Install),
+    Scoped(Vec<Install>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Install {
+    install_path: Option<PathBuf>,
+}
+
+impl RegistryEntry {
+    fn install_path(self) -> Option<PathBuf> {
+        match self {
+            Self::Single(install) => install.install_path,
+            Self::Scoped(installs) => installs.into_iter().find_map(|i| i.install_path),
+        }
+    }
+}
+
+/// Installed and enabled plugins, sorted by name
+///
+/// `enabled_plugins` is the merged `enabledPlugins` setting. A missing
+/// registry just means nothing is installed; an unreadable one is reported on
+/// every plugin the settings mention, since none of them can be verified.
+pub fn load_plugins(
+    claude_home: &Path,
+    enabled_plugins: Option<&HashMap<String, bool>>,
+) -> Vec<PluginInfo> {
+    let registry_path = claude_home.join(REGISTRY_PATH);
+    let (installed, registry_error) = match std::fs::read_to_string(&registry_path) {
+        Ok(raw) => match serde_json::from_str::<Registry>(&raw) {
+            Ok(registry) => (registry.plugins, None),
+            Err(e) => (
+                HashMap::new(),
+                Some(format!("Invalid {}: {}", REGISTRY_PATH, e)),
+            ),
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => (HashMap::new(), None),
+        Err(e) => (
+            HashMap::new(),
+            Some(format!("Cannot read {}: {}", REGISTRY_PATH, e)),
+        ),
+    };
+
+    let is_enabled = |name: &str| {
+        enabled_plugins
+            .and_then(|m| m.get(name))
+            .copied()
+            .unwrap_or(false)
+    };
+
+    let mut plugins: BTreeMap<String, PluginInfo> = installed
+        .into_iter()
+        .map(|(name, entry)| {
+            let path = entry.install_path();
+            let error = path.as_deref().and_then(check_install);
+            let info = PluginInfo {
+                enabled: is_enabled(&name),
+                name: name.clone(),
+                path,
+                error,
+            };
+            (name, info)
+        })
+        .collect();
+
+    for (name, _) in enabled_plugins
+        .into_iter()
+        .flatten()
+        .filter(|(_, enabled)| **enabled)
+    {
+        plugins.entry(name.clone()).or_insert_with(|| PluginInfo {
+            name: name.clone(),
+            enabled: true,
+            path: None,
+            error: Some(
+                registry_error
+                    .clone()
+                    .unwrap_or_else(|| "Enabled in settings but not installed".to_string()),
+            ),
+        });
+    }
+
+    plugins.into_values().collect()
+}
+
+/// Problem with an install directory, if any
+fn check_install(path: &Path) -> Option<String> {
+    if !path.is_dir() {
+        return Some(format!("Plugin directory missing: {}", path.display()));
+    }
+    let manifest = path.join(MANIFEST_PATH);
+    let raw = std::fs::read_to_string(&manifest).ok()?;
+    serde_json::from_str::<serde_json::Value>(&raw)
+        .err()
+        .map(|e| format!("Invalid {}: {}", MANIFEST_PATH, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_load_plugins() {
+        let dir = tempfile::tempdir().unwrap();
+        let home = dir.path();
+        let ok = home.join("plugins/cache/mkt/ok");
+        let broken = home.join("plugins/cache/mkt/broken");
+        fs::create_dir_all(ok.join(".claude-plugin")).unwrap();
+        fs::write(ok.join(".claude-plugin/plugin.json"), r#"{"name": "ok"}"#).unwrap();
+        fs::create_dir_all(broken.join(".claude-plugin")).unwrap();
+        fs::write(broken.join(".claude-plugin/plugin.json"), "{ nope").unwrap();
+
+        let registry = serde_json::json!({
+            "version": 2,
+            "plugins": {
+                "ok@mkt": [{ "scope": "user", "installPath": ok }],
+                "broken@mkt": { "installPath": broken },
+                "gone@mkt": { "installPath": home.join("plugins/cache/mkt/gone") },
+                "off@mkt": { "installPath": ok },
+            }
+        });
+        fs::write(home.join(REGISTRY_PATH), registry.to_string()).unwrap();
+
+        let enabled: HashMap<String, bool> = [
+            ("ok@mkt", true),
+            ("broken@mkt", true),
+            ("gone@mkt", true),
+            ("off@mkt", false),
+            ("ghost@mkt", true),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+
+        let plugins = load_plugins(home, Some(&enabled));
+        let statuses: Vec<(&str, PluginStatus)> = plugins
+            .iter()
+            .map(|p| (p.name.as_str(), p.status()))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("broken@mkt", PluginStatus::Error),
+                ("ghost@mkt", PluginStatus::Error),
+                ("gone@mkt", PluginStatus::Error),
+                ("off@mkt", PluginStatus::Disabled),
+                ("ok@mkt", PluginStatus::Enabled),
+            ]
+        );
+        assert_eq!(plugins[4].path.as_deref(), Some(ok.as_path()));
+        assert!(plugins[1]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("not installed"));
+        assert!(plugins[2].error.as_deref().unwrap().contains("missing"));
+
+        // No registry: only enabled plugins show up, flagged as not installed
+        let empty = tempfile::tempdir().unwrap();
+        let plugins = load_plugins(empty.path(), Some(&enabled));
+        assert_eq!(plugins.len(), 4);
+        assert!(plugins.iter().all(|p| p.status() == PluginStatus::Error));
+        assert!(load_plugins(empty.path(), None).is_empty());
+    }
+}
@@ -5,7 +5,10 @@
 
 use crate::error::CoreError;
 use crate::models::{ConversationMessage, MessageRole, SessionLine, SessionMetadata};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
 use std::path::Path;
+use std::sync::Arc;
 use tokio::fs::File;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tracing::{trace, warn};
@@ -16,6 +19,153 @@ const MAX_LINE_SIZE: usize = 10 * 1024 * 1024;
 /// Maximum lines to parse (circuit breaker for malformed/infinite files)
 const MAX_LINES: usize = 50_000;
 
+/// Lines parsed from part of a session file
+#[derive(Debug, Default)]
+pub struct ParsedLines {
+    pub lines: Vec<SessionLine>,
+    /// Byte offset to resume from with [`SessionContentParser::parse_appended`]
+    pub offset: u64,
+    /// Unparseable lines that were skipped
+    pub malformed_lines: usize,
+}
+
+/// Tool calls of a session in chronological order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToolTimeline {
+    /// (call time, tool name)
+    pub calls: Vec<(DateTime<Utc>, String)>,
+    /// First timestamped line of the session
+    pub start: Option<DateTime<Utc>>,
+    /// Last timestamped line of the session
+    pub end: Option<DateTime<Utc>>,
+}
+
+impl ToolTimeline {
+    /// Collect `tool_use` blocks of assistant messages in `lines`
+    pub fn from_lines(lines: &[SessionLine]) -> Self {
+        let mut timeline = Self::default();
+        for line in lines {
+            let Some(ts) = line.timestamp else {
+                continue;
+            };
+            timeline.start = Some(timeline.start.map_or(ts, |s| s.min(ts)));
+            timeline.end = Some(timeline.end.map_or(ts, |e| e.max(ts)));
+
+            let blocks = line
+                .message
+                .as_ref()
+                .and_then(|m| m.content.as_ref())
+                .and_then(|c| c.as_array());
+            for block in blocks.into_iter().flatten() {
+                if block.get("type").and_then(|t| t.as_str()) != Some("tool_use") {
+                    continue;
+                }
+                if let Some(name) = block.get("name").and_then(|n| n.as_str()) {
+                    timeline.calls.push((ts, name.to_string()));
+                }
+            }
+        }
+        timeline.calls.sort_by_key(|(ts, _)| *ts);
+        timeline
+    }
+
+    /// Bucket index of `ts` when the session span is split into `buckets`
+    fn bucket_of(&self, ts: DateTime<Utc>, buckets: usize) -> usize {
+        let (Some(start), Some(end)) = (self.start, self.end) else {
+            return 0;
+        };
+        let span = (end - start).num_milliseconds();
+        if span <= 0 {
+            return 0;
+        }
+        let offset = (ts - start).num_milliseconds().clamp(0, span);
+        ((offset as i128 * buckets as i128 / (span as i128 + 1)) as usize).min(buckets - 1)
+    }
+
+    /// Tool calls per equal slice of the session's duration
+    pub fn density(&self, buckets: usize) -> Vec<usize> {
+        let mut counts = vec![0; buckets];
+        if buckets == 0 {
+            return counts;
+        }
+        for (ts, _) in &self.calls {
+            counts[self.bucket_of(*ts, buckets)] += 1;
+        }
+        counts
+    }
+
+    /// Most-called tool within `bucket`, with its count (ties: alphabetical)
+    pub fn top_tool_in(&self, bucket: usize, buckets: usize) -> Option<(&str, usize)> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for (ts, name) in &self.calls {
+            if buckets > 0 && self.bucket_of(*ts, buckets) == bucket {
+                *counts.entry(name.as_str()).or_default() += 1;
+            }
+        }
+        counts.into_iter().rev().max_by_key(|(_, count)| *count)
+    }
+}
+
+/// Pattern for [`SessionContentParser::grep_sessions`]
+#[derive(Debug, Clone)]
+pub struct GrepPattern {
+    regex: regex::Regex,
+    /// Lowercased, JSON-escaped phrase for the raw-line prefilter (`None`
+    /// for regexes, which can't be checked against escaped JSON)
+    literal: Option<String>,
+}
+
+impl GrepPattern {
+    /// Case-insensitive phrase
+    pub fn literal(phrase: &str) -> Self {
+        let regex = regex::RegexBuilder::new(&regex::escape(phrase))
+            .case_insensitive(true)
+            .build()
+            .expect("escaped pattern is a valid regex");
+        Self {
+            regex,
+            literal: Some(json_escape(&phrase.to_lowercase())),
+        }
+    }
+
+    /// Case-insensitive regular expression
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        let regex = regex::RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .build()?;
+        Ok(Self {
+            regex,
+            literal: None,
+        })
+    }
+
+    /// Byte range of the first match in `text`
+    pub fn find(&self, text: &str) -> Option<std::ops::Range<usize>> {
+        self.regex.find(text).map(|m| m.range())
+    }
+}
+
+/// `text` as it appears inside a JSON string literal, so a phrase containing
+/// quotes, backslashes or control characters still finds its raw line
+fn json_escape(text: &str) -> String {
+    let quoted = serde_json::to_string(text).expect("strings always serialize");
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+/// A message line matching a [`GrepPattern`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentMatch {
+    pub role: MessageRole,
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Line of the message text containing the match
+    pub line: String,
+    /// Byte range of the first match within `line`
+    pub range: std::ops::Range<usize>,
+    /// Non-empty lines right before and after it in the same message
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
 /// Parser for full session content
 pub struct SessionContentParser;
 
@@ -128,6 +278,434 @@ impl SessionContentParser {
         Ok(messages)
     }
 
+    /// Parse only the lines appended to a session file since `from_offset`
+    ///
+    /// Returns the newly completed lines, the byte offset to pass on the next
+    /// call and how many malformed lines were skipped. A trailing line without
+    /// a newline is usually still being written, so it is left for the next
+    /// call; when reading from the start of the file it is kept if it already
+    /// parses, since a session whose last write had no newline would otherwise
+    /// lose its last message. If the file shrank (truncated or replaced),
+    /// parsing restarts from the beginning. Lines over 10MB are skipped without
+    /// being buffered, even while still being written: the offset moves past
+    /// them and the rest of such a line is dropped on the next call.
+    ///
+    /// Uses blocking I/O: deltas are small, and the live monitor polls from a
+    /// blocking context.
+    pub fn parse_appended(session_path: &Path, from_offset: u64) -> Result<ParsedLines, CoreError> {
+        use std::io::{BufRead, Read, Seek, SeekFrom};
+
+        let read_err = |e: std::io::Error| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                CoreError::FileNotFound {
+                    path: session_path.to_path_buf(),
+                }
+            } else {
+                CoreError::FileRead {
+                    path: session_path.to_path_buf(),
+                    source: e,
+                }
+            }
+        };
+
+        let mut file = std::fs::File::open(session_path).map_err(read_err)?;
+        let file_len = file.metadata().map_err(read_err)?.len();
+
+        // File shrank or was replaced: start over
+        let mut offset = if file_len < from_offset {
+            0
+        } else {
+            from_offset
+        };
+        if offset == file_len {
+            return Ok(ParsedLines {
+                offset,
+                ..Default::default()
+            });
+        }
+        // Resuming in the middle of a line means an oversized line was skipped
+        // while still being written: drop the rest of it
+        let mid_line = offset > 0 && {
+            file.seek(SeekFrom::Start(offset - 1)).map_err(read_err)?;
+            let mut prev = [0u8; 1];
+            file.read_exact(&mut prev).map_err(read_err)?;
+            prev[0] != b'\n'
+        };
+        file.seek(SeekFrom::Start(offset)).map_err(read_err)?;
+        let from_start = offset == 0;
+
+        let mut reader = std::io::BufReader::with_capacity(64 * 1024, file);
+        let mut lines = Vec::new();
+        let mut malformed_lines = 0;
+        let mut buf = Vec::new();
+        let mut line_num = 0;
+
+        if mid_line {
+            let (skipped, _) = skip_line(&mut reader).map_err(read_err)?;
+            offset += skipped;
+        }
+
+        loop {
+            buf.clear();
+            // Bounded so an oversized line is never held in memory whole
+            let n = (&mut reader)
+                .take(MAX_LINE_SIZE as u64 + 1)
+                .read_until(b'\n', &mut buf)
+                .map_err(read_err)?;
+            // EOF, or a partial line still being written: resume here next time
+            if n == 0 {
+                break;
+            }
+            if n > MAX_LINE_SIZE && buf.last() != Some(&b'\n') {
+                let (skipped, terminated) = skip_line(&mut reader).map_err(read_err)?;
+                warn!(
+                    path = %session_path.display(),
+                    size = n as u64 + skipped,
+                    "Skipping oversized appended line (>10MB)"
+                );
+                offset += n as u64 + skipped;
+                if !terminated {
+                    break;
+                }
+                line_num += 1;
+                continue;
+            }
+            if buf.last() != Some(&b'\n') {
+                // A complete JSON object is a finished line, just unterminated
+                if from_start && n <= MAX_LINE_SIZE {
+                    if let Ok(line) = serde_json::from_slice::<SessionLine>(buf.trim_ascii()) {
+                        lines.push(line);
+                        offset += n as u64;
+                    }
+                }
+                break;
+            }
+            offset += n as u64;
+            line_num += 1;
+
+            // Circuit breaker: the rest is picked up on the next call
+            if line_num > MAX_LINES {
+                offset -= n as u64;
+                break;
+            }
+
+            if buf.len() > MAX_LINE_SIZE {
+                warn!(
+                    path = %session_path.display(),
+                    size = buf.len(),
+                    "Skipping oversized appended line (>10MB)"
+                );
+                continue;
+            }
+
+            let text = String::from_utf8_lossy(&buf);
+            let text = text.trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            match serde_json::from_str::<SessionLine>(text) {
+                Ok(line) => lines.push(line),
+                Err(e) => {
+                    trace!(
+                        path = %session_path.display(),
+                        error = %e,
+                        "Skipping malformed appended line"
+                    );
+                    malformed_lines += 1;
+                }
+            }
+        }
+
+        Ok(ParsedLines {
+            lines,
+            offset,
+            malformed_lines,
+        })
+    }
+
+    /// Parse only the last `n` lines of a session file
+    ///
+    /// Reads the file backwards in 64KB chunks to find where the last `n` complete
+    /// lines start, then parses forward from there, so the cost depends on the
+    /// size of the tail rather than the whole file. The returned offset is the one
+    /// to pass to [`Self::parse_appended`] to keep following the file.
+    /// `n` counts raw JSONL lines, so fewer displayable messages may come back.
+    pub fn parse_session_tail(session_path: &Path, n: usize) -> Result<ParsedLines, CoreError> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        const CHUNK_SIZE: u64 = 64 * 1024;
+
+        let read_err = |e: std::io::Error| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                CoreError::FileNotFound {
+                    path: session_path.to_path_buf(),
+                }
+            } else {
+                CoreError::FileRead {
+                    path: session_path.to_path_buf(),
+                    source: e,
+                }
+            }
+        };
+
+        let mut file = std::fs::File::open(session_path).map_err(read_err)?;
+        let mut pos = file.metadata().map_err(read_err)?.len();
+
+        // The newline ending the last complete line is the first one found, so
+        // the tail starts right after the (n + 1)-th newline from the end.
+        let mut start = 0;
+        let mut newlines = 0;
+        let mut chunk = vec![0u8; CHUNK_SIZE as usize];
+        'scan: while pos > 0 {
+            let len = pos.min(CHUNK_SIZE);
+            pos -= len;
+            file.seek(SeekFrom::Start(pos)).map_err(read_err)?;
+            let buf = &mut chunk[..len as usize];
+            file.read_exact(buf).map_err(read_err)?;
+
+            for (i, &byte) in buf.iter().enumerate().rev() {
+                if byte == b'\n' {
+                    newlines += 1;
+                    if newlines > n {
+                        start = pos + i as u64 + 1;
+                        break 'scan;
+                    }
+                }
+            }
+        }
+
+        Self::parse_appended(session_path, start)
+    }
+
+    /// Parse a whole session file into its [`ToolTimeline`]
+    pub fn tool_timeline(session_path: &Path) -> Result<ToolTimeline, CoreError> {
+        let parsed = Self::parse_appended(session_path, 0)?;
+        Ok(ToolTimeline::from_lines(&parsed.lines))
+    }
+
+    /// Check whether any message text of a session contains `needle`
+    ///
+    /// `needle` must already be lowercased; matching is case-insensitive. Streams
+    /// the file and stops at the first hit. Lines are only parsed when the raw JSON
+    /// contains the (JSON-escaped) needle, so non-matching sessions stay cheap.
+    pub fn contains_text(session_path: &Path, needle: &str) -> Result<bool, CoreError> {
+        use std::io::BufRead;
+
+        let file = std::fs::File::open(session_path).map_err(|e| CoreError::FileRead {
+            path: session_path.to_path_buf(),
+            source: e,
+        })?;
+        let mut reader = std::io::BufReader::with_capacity(64 * 1024, file);
+        let mut buf = Vec::new();
+        let raw_needle = json_escape(needle);
+
+        for _ in 0..MAX_LINES {
+            buf.clear();
+            let n = reader
+                .read_until(b'\n', &mut buf)
+                .map_err(|e| CoreError::FileRead {
+                    path: session_path.to_path_buf(),
+                    source: e,
+                })?;
+            if n == 0 {
+                break;
+            }
+            if buf.len() > MAX_LINE_SIZE {
+                continue;
+            }
+
+            let raw = String::from_utf8_lossy(&buf);
+            if !raw.to_lowercase().contains(&raw_needle) {
+                continue;
+            }
+            let Ok(line) = serde_json::from_str::<SessionLine>(raw.trim()) else {
+                continue;
+            };
+            if Self::convert_to_message(line)
+                .is_some_and(|m| m.content.to_lowercase().contains(needle))
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Message lines of a session matching `pattern`, at most `max_matches`
+    ///
+    /// Streams the file like [`Self::contains_text`]; phrases skip lines whose
+    /// raw JSON doesn't contain them.
+    pub fn grep_file(
+        session_path: &Path,
+        pattern: &GrepPattern,
+        max_matches: usize,
+    ) -> Result<Vec<ContentMatch>, CoreError> {
+        use std::io::BufRead;
+
+        let file = std::fs::File::open(session_path).map_err(|e| CoreError::FileRead {
+            path: session_path.to_path_buf(),
+            source: e,
+        })?;
+        let mut reader = std::io::BufReader::with_capacity(64 * 1024, file);
+        let mut buf = Vec::new();
+        let mut matches = Vec::new();
+
+        for _ in 0..MAX_LINES {
+            buf.clear();
+            let n = reader
+                .read_until(b'\n', &mut buf)
+                .map_err(|e| CoreError::FileRead {
+                    path: session_path.to_path_buf(),
+                    source: e,
+                })?;
+            if n == 0 || matches.len() >= max_matches {
+                break;
+            }
+            if buf.len() > MAX_LINE_SIZE {
+                continue;
+            }
+
+            let raw = String::from_utf8_lossy(&buf);
+            if let Some(ref literal) = pattern.literal {
+                if !raw.to_lowercase().contains(literal.as_str()) {
+                    continue;
+                }
+            }
+            let Ok(line) = serde_json::from_str::<SessionLine>(raw.trim()) else {
+                continue;
+            };
+            let Some(message) = Self::convert_to_message(line) else {
+                continue;
+            };
+
+            let lines: Vec<&str> = message
+                .content
+                .lines()
+                .filter(|l| !l.trim().is_empty())
+                .collect();
+            for (i, text) in lines.iter().enumerate() {
+                if matches.len() >= max_matches {
+                    break;
+                }
+                if let Some(range) = pattern.find(text) {
+                    matches.push(ContentMatch {
+                        role: message.role,
+                        timestamp: message.timestamp,
+                        line: text.to_string(),
+                        range,
+                        before: i.checked_sub(1).map(|j| lines[j].to_string()),
+                        after: lines.get(i + 1).map(|l| l.to_string()),
+                    });
+                }
+            }
+        }
+
+        Ok(matches)
+    }
+
+    /// Scan session bodies for `query`, at most `concurrency` files at a time
+    ///
+    /// Results keep the order of `sessions` (callers pass them most recent first)
+    /// even though files are scanned in parallel. Scanning stops as soon as the
+    /// first `limit` matches in that order are known. `on_progress` receives
+    /// `(scanned, total)` after each file. Unreadable files count as non-matches.
+    pub async fn search_sessions(
+        sessions: &[Arc<SessionMetadata>],
+        query: &str,
+        limit: usize,
+        concurrency: usize,
+        on_progress: impl FnMut(usize, usize),
+    ) -> Vec<Arc<SessionMetadata>> {
+        let needle: Arc<str> = Arc::from(query.to_lowercase());
+        Self::scan_ordered(sessions, limit, concurrency, on_progress, move |path| {
+            Self::contains_text(path, &needle)
+                .unwrap_or(false)
+                .then_some(())
+        })
+        .await
+        .into_iter()
+        .map(|(session, ())| session)
+        .collect()
+    }
+
+    /// Matching lines of every session, same ordering and early stop as
+    /// [`Self::search_sessions`]
+    ///
+    /// Returns up to `limit` sessions, each with at most `max_per_session` matches.
+    pub async fn grep_sessions(
+        sessions: &[Arc<SessionMetadata>],
+        pattern: &GrepPattern,
+        limit: usize,
+        max_per_session: usize,
+        concurrency: usize,
+        on_progress: impl FnMut(usize, usize),
+    ) -> Vec<(Arc<SessionMetadata>, Vec<ContentMatch>)> {
+        let pattern = Arc::new(pattern.clone());
+        Self::scan_ordered(sessions, limit, concurrency, on_progress, move |path| {
+            Self::grep_file(path, &pattern, max_per_session)
+                .ok()
+                .filter(|matches| !matches.is_empty())
+        })
+        .await
+    }
+
+    /// Run `scan` over session files in parallel, committing hits in input order
+    async fn scan_ordered<T: Send + 'static>(
+        sessions: &[Arc<SessionMetadata>],
+        limit: usize,
+        concurrency: usize,
+        mut on_progress: impl FnMut(usize, usize),
+        scan: impl Fn(&Path) -> Option<T> + Send + Sync + 'static,
+    ) -> Vec<(Arc<SessionMetadata>, T)> {
+        let scan = Arc::new(scan);
+        let total = sessions.len();
+        let concurrency = concurrency.max(1);
+
+        let mut tasks = tokio::task::JoinSet::new();
+        // Out-of-order results waiting for the ones before them
+        let mut pending: BTreeMap<usize, Option<T>> = BTreeMap::new();
+        let mut next_spawn = 0;
+        let mut next_commit = 0;
+        let mut scanned = 0;
+        let mut results = Vec::new();
+
+        while results.len() < limit && next_commit < total {
+            while next_spawn < total && tasks.len() < concurrency {
+                let idx = next_spawn;
+                let path = sessions[idx].file_path.clone();
+                let scan = Arc::clone(&scan);
+                tasks.spawn(async move {
+                    let hit = tokio::task::spawn_blocking(move || scan(&path))
+                        .await
+                        .unwrap_or(None);
+                    (idx, hit)
+                });
+                next_spawn += 1;
+            }
+
+            let Some(Ok((idx, hit))) = tasks.join_next().await else {
+                break;
+            };
+            scanned += 1;
+            on_progress(scanned, total);
+            pending.insert(idx, hit);
+
+            while let Some(hit) = pending.remove(&next_commit) {
+                if let Some(value) = hit {
+                    results.push((Arc::clone(&sessions[next_commit]), value));
+                }
+                next_commit += 1;
+                if results.len() >= limit {
+                    break;
+                }
+            }
+        }
+
+        tasks.abort_all();
+        results
+    }
+
     /// Convert SessionLine to ConversationMessage
     ///
     /// Extracts role, content, timestamp, model from SessionLine.
@@ -264,7 +842,8 @@ impl SessionContentParser {
     /// Filter messages to only user/assistant interactions (legacy method)
     ///
     /// Removes system events like "file-history-snapshot", "session_end", etc.
-    /// Useful for replay viewer to focus on conversation flow.
+    /// Useful for replay viewer to focus on conversation flow. Compaction
+    /// boundaries are kept so the replay can show where context was dropped.
     ///
     /// NOTE: Prefer parse_conversation() for new code, which returns ConversationMessage.
     pub fn filter_messages(lines: Vec<SessionLine>) -> Vec<SessionLine> {
@@ -274,12 +853,37 @@ impl SessionContentParser {
                 matches!(
                     line.line_type.as_str(),
                     "user" | "assistant" | "tool_use" | "tool_result"
-                )
+                ) || line.is_compact_boundary()
             })
             .collect()
     }
 }
 
+/// Consume bytes up to and including the next newline without buffering them
+///
+/// Returns how many bytes were consumed and whether a newline was reached
+/// before EOF.
+fn skip_line(reader: &mut impl std::io::BufRead) -> std::io::Result<(u64, bool)> {
+    let mut skipped = 0;
+    loop {
+        let chunk = reader.fill_buf()?;
+        if chunk.is_empty() {
+            return Ok((skipped, false));
+        }
+        match chunk.iter().position(|&b| b == b'\n') {
+            Some(i) => {
+                reader.consume(i + 1);
+                return Ok((skipped + i as u64 + 1, true));
+            }
+            None => {
+                let len = chunk.len();
+                reader.consume(len);
+                skipped += len as u64;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -314,6 +918,205 @@ mod tests {
         assert_eq!(result.unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_parse_appended_skips_and_counts_malformed_lines() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"type":"user","message":{{"content":"first"}}}}"#).unwrap();
+        writeln!(file, "garbage{{{{").unwrap();
+        writeln!(
+            file,
+            r#"{{"type":"assistant","message":{{"content":"second"}}}}"#
+        )
+        .unwrap();
+        writeln!(file, r#"{{"type":"user","message":"#).unwrap();
+        writeln!(file).unwrap();
+        writeln!(file, r#"{{"type":"user","message":{{"content":"third"}}}}"#).unwrap();
+        file.flush().unwrap();
+
+        let parsed = SessionContentParser::parse_appended(file.path(), 0).unwrap();
+        assert_eq!(parsed.malformed_lines, 2);
+        let contents: Vec<_> = parsed
+            .lines
+            .iter()
+            .map(|l| l.message.as_ref().unwrap().content.clone().unwrap())
+            .collect();
+        assert_eq!(
+            contents,
+            vec![
+                serde_json::json!("first"),
+                serde_json::json!("second"),
+                serde_json::json!("third"),
+            ]
+        );
+        assert_eq!(parsed.offset, std::fs::metadata(file.path()).unwrap().len());
+    }
+
+    #[test]
+    fn test_parse_appended_returns_only_new_lines() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"type":"user","message":{{"content":"first"}}}}"#).unwrap();
+        writeln!(
+            file,
+            r#"{{"type":"assistant","message":{{"content":"second"}}}}"#
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let ParsedLines { lines, offset, .. } =
+            SessionContentParser::parse_appended(file.path(), 0).unwrap();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(offset, std::fs::metadata(file.path()).unwrap().len());
+
+        // Nothing new yet
+        let ParsedLines {
+            lines,
+            offset: same,
+            ..
+        } = SessionContentParser::parse_appended(file.path(), offset).unwrap();
+        assert!(lines.is_empty());
+        assert_eq!(same, offset);
+
+        // One complete line plus a partial one still being written
+        writeln!(file, r#"{{"type":"user","message":{{"content":"third"}}}}"#).unwrap();
+        write!(file, r#"{{"type":"assistant","mess"#).unwrap();
+        file.flush().unwrap();
+
+        let ParsedLines {
+            lines,
+            offset: offset2,
+            ..
+        } = SessionContentParser::parse_appended(file.path(), offset).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(
+            lines[0].message.as_ref().unwrap().content,
+            Some(serde_json::json!("third"))
+        );
+
+        // Completing the partial line yields it on the next call
+        writeln!(file, r#"age":{{"content":"fourth"}}}}"#).unwrap();
+        file.flush().unwrap();
+
+        let ParsedLines { lines, .. } =
+            SessionContentParser::parse_appended(file.path(), offset2).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].line_type, "assistant");
+    }
+
+    #[test]
+    fn test_parse_appended_skips_growing_oversized_tail() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"type":"user","message":{{"content":"first"}}}}"#).unwrap();
+        write!(file, r#"{{"type":"assistant","message":{{"content":""#).unwrap();
+        file.write_all(&vec![b'x'; MAX_LINE_SIZE + 1]).unwrap();
+        file.flush().unwrap();
+
+        // The oversized tail is skipped while still being written
+        let ParsedLines { lines, offset, .. } =
+            SessionContentParser::parse_appended(file.path(), 0).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(offset, std::fs::metadata(file.path()).unwrap().len());
+
+        // The rest of it is dropped once it completes, later lines still parse
+        file.write_all(&vec![b'x'; 1024]).unwrap();
+        writeln!(file, r#""}}}}"#).unwrap();
+        writeln!(
+            file,
+            r#"{{"type":"user","message":{{"content":"second"}}}}"#
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let parsed = SessionContentParser::parse_appended(file.path(), offset).unwrap();
+        assert_eq!(parsed.malformed_lines, 0);
+        assert_eq!(parsed.lines.len(), 1);
+        assert_eq!(
+            parsed.lines[0].message.as_ref().unwrap().content,
+            Some(serde_json::json!("second"))
+        );
+        assert_eq!(parsed.offset, std::fs::metadata(file.path()).unwrap().len());
+    }
+
+    #[test]
+    fn test_parse_appended_keeps_unterminated_last_line_on_open() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"type":"user","message":{{"content":"first"}}}}"#).unwrap();
+        write!(
+            file,
+            r#"{{"type":"assistant","message":{{"content":"last"}}}}"#
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let ParsedLines { lines, offset, .. } =
+            SessionContentParser::parse_appended(file.path(), 0).unwrap();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].line_type, "assistant");
+        assert_eq!(offset, std::fs::metadata(file.path()).unwrap().len());
+
+        // The newline arriving later doesn't produce a duplicate
+        writeln!(file).unwrap();
+        file.flush().unwrap();
+        let ParsedLines { lines, .. } =
+            SessionContentParser::parse_appended(file.path(), offset).unwrap();
+        assert!(lines.is_empty());
+    }
+
+    #[test]
+    fn test_parse_appended_restarts_after_truncation() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"type":"user"}}"#).unwrap();
+        file.flush().unwrap();
+
+        let ParsedLines { lines, .. } =
+            SessionContentParser::parse_appended(file.path(), 10_000).unwrap();
+        assert_eq!(lines.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_session_tail_returns_last_lines() {
+        let mut file = NamedTempFile::new().unwrap();
+        for i in 0..5_000 {
+            writeln!(
+                file,
+                r#"{{"type":"user","message":{{"content":"msg {}"}}}}"#,
+                i
+            )
+            .unwrap();
+        }
+        write!(file, r#"{{"type":"assistant","mess"#).unwrap();
+        file.flush().unwrap();
+
+        let ParsedLines { lines, offset, .. } =
+            SessionContentParser::parse_session_tail(file.path(), 3).unwrap();
+        let contents: Vec<_> = lines
+            .iter()
+            .map(|l| l.message.as_ref().unwrap().content.clone().unwrap())
+            .collect();
+        assert_eq!(
+            contents,
+            vec![
+                serde_json::json!("msg 4997"),
+                serde_json::json!("msg 4998"),
+                serde_json::json!("msg 4999"),
+            ]
+        );
+        // Offset stops before the partial line, same as parse_appended
+        let ParsedLines {
+            offset: full_offset,
+            ..
+        } = SessionContentParser::parse_appended(file.path(), 0).unwrap();
+        assert_eq!(offset, full_offset);
+
+        // Asking for more lines than the file has returns everything
+        let ParsedLines { lines, .. } =
+            SessionContentParser::parse_session_tail(file.path(), 10_000).unwrap();
+        assert_eq!(lines.len(), 5_000);
+
+        let ParsedLines { lines, .. } =
+            SessionContentParser::parse_session_tail(file.path(), 0).unwrap();
+        assert!(lines.is_empty());
+    }
+
     #[tokio::test]
     async fn test_parse_conversation_full() {
         // Create temp file with user + assistant messages
@@ -382,11 +1185,211 @@ mod tests {
                 line_type: "session_end".to_string(),
                 ..Default::default()
             },
+            SessionLine {
+                line_type: "system".to_string(),
+                subtype: Some("compact_boundary".to_string()),
+                ..Default::default()
+            },
+            SessionLine {
+                line_type: "system".to_string(),
+                subtype: Some("informational".to_string()),
+                ..Default::default()
+            },
         ];
 
         let filtered = SessionContentParser::filter_messages(lines);
-        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered.len(), 3);
         assert_eq!(filtered[0].line_type, "user");
         assert_eq!(filtered[1].line_type, "assistant");
+        assert!(filtered[2].is_compact_boundary());
+    }
+
+    #[test]
+    fn test_contains_text_matches_message_text_only() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("s.jsonl");
+        std::fs::write(
+            &path,
+            concat!(
+                r#"{"type":"file-history-snapshot","note":"Refactor"}"#,
+                "\n",
+                r#"{"type":"user","message":{"role":"user","content":"Please REFACTOR the parser"}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        assert!(SessionContentParser::contains_text(&path, "refactor the").unwrap());
+        // Present in the raw JSON but not in any message text
+        assert!(!SessionContentParser::contains_text(&path, "snapshot").unwrap());
+    }
+
+    #[test]
+    fn test_text_search_matches_json_escaped_characters() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("s.jsonl");
+        std::fs::write(
+            &path,
+            concat!(
+                r#"{"type":"user","message":{"role":"user","content":"Run \"cargo test\" in C:\\src\tnow"}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        for needle in ["\"cargo test\"", "c:\\src", "src\tnow"] {
+            assert!(
+                SessionContentParser::contains_text(&path, needle).unwrap(),
+                "{needle:?}"
+            );
+            let matches =
+                SessionContentParser::grep_file(&path, &GrepPattern::literal(needle), 10).unwrap();
+            assert_eq!(matches.len(), 1, "{needle:?}");
+        }
+    }
+
+    #[test]
+    fn test_tool_timeline_density() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("s.jsonl");
+        let mut content = String::from(
+            "{\"type\":\"user\",\"timestamp\":\"2025-01-01T10:00:00Z\",\"message\":{\"content\":\"go\"}}\n",
+        );
+        let call = |minute: u32, tools: &[&str]| {
+            let blocks: Vec<String> = tools
+                .iter()
+                .map(|t| format!("{{\"type\":\"tool_use\",\"name\":\"{}\"}}", t))
+                .collect();
+            format!(
+                "{{\"type\":\"assistant\",\"timestamp\":\"2025-01-01T10:{:02}:00Z\",\"message\":{{\"content\":[{}]}}}}\n",
+                minute,
+                blocks.join(",")
+            )
+        };
+        content.push_str(&call(1, &["Read"]));
+        content.push_str(&call(55, &["Bash", "Bash"]));
+        content.push_str(&call(59, &["Bash", "Edit"]));
+        std::fs::write(&path, content).unwrap();
+
+        let timeline = SessionContentParser::tool_timeline(&path).unwrap();
+        assert_eq!(timeline.calls.len(), 5);
+        assert_eq!(timeline.density(4), vec![1, 0, 0, 4]);
+        assert_eq!(timeline.top_tool_in(3, 4), Some(("Bash", 3)));
+        assert_eq!(timeline.top_tool_in(1, 4), None);
+        assert!(timeline.density(0).is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_search_sessions_ordered_and_limited() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let sessions: Vec<Arc<SessionMetadata>> = (0..12)
+            .map(|i| {
+                let path = temp_dir.path().join(format!("s{}.jsonl", i));
+                let text = if i % 3 == 0 { "needle here" } else { "hay" };
+                std::fs::write(
+                    &path,
+                    format!(
+                        "{{\"type\":\"user\",\"message\":{{\"content\":\"{}\"}}}}\n",
+                        text
+                    ),
+                )
+                .unwrap();
+                Arc::new(SessionMetadata::from_path(path, ProjectId::from("/p")))
+            })
+            .collect();
+
+        let mut progress = Vec::new();
+        let all = SessionContentParser::search_sessions(
+            &sessions,
+            "Needle",
+            usize::MAX,
+            4,
+            |n, total| progress.push((n, total)),
+        )
+        .await;
+        let names: Vec<_> = all
+            .iter()
+            .map(|s| {
+                s.file_path
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(names, ["s0.jsonl", "s3.jsonl", "s6.jsonl", "s9.jsonl"]);
+        assert_eq!(progress.last(), Some(&(12, 12)));
+
+        // Early termination keeps the first matches in input order
+        let limited =
+            SessionContentParser::search_sessions(&sessions, "needle", 2, 4, |_, _| {}).await;
+        assert_eq!(limited.len(), 2);
+        assert_eq!(limited[0].file_path, sessions[0].file_path);
+        assert_eq!(limited[1].file_path, sessions[3].file_path);
+    }
+
+    #[tokio::test]
+    async fn test_grep_sessions_with_context() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, lines: &[&str]| {
+            let path = temp_dir.path().join(name);
+            std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+            Arc::new(SessionMetadata::from_path(path, ProjectId::from("/p")))
+        };
+        let sessions = vec![
+            write(
+                "a.jsonl",
+                &[
+                    r#"{"type":"user","timestamp":"2026-03-01T10:00:00Z","message":{"content":"Why does the build fail?"}}"#,
+                    r#"{"type":"assistant","message":{"content":[{"type":"text","text":"Looking at it.\n\nThe Borrow Checker rejects line 12.\nMove the clone up."}]}}"#,
+                    r#"{"type":"file-history-snapshot","note":"borrow checker"}"#,
+                ],
+            ),
+            write(
+                "b.jsonl",
+                &[r#"{"type":"user","message":{"content":"nothing relevant"}}"#],
+            ),
+            write(
+                "c.jsonl",
+                &[
+                    r#"{"type":"user","message":{"content":"borrow checker again"}}"#,
+                    r#"{"type":"user","message":{"content":"and the borrow checker once more"}}"#,
+                ],
+            ),
+        ];
+
+        let hits = SessionContentParser::grep_sessions(
+            &sessions,
+            &GrepPattern::literal("borrow checker"),
+            10,
+            1,
+            2,
+            |_, _| {},
+        )
+        .await;
+        assert_eq!(hits.len(), 2);
+        let (session, matches) = &hits[0];
+        assert_eq!(session.file_path, sessions[0].file_path);
+        assert_eq!(
+            matches,
+            &[ContentMatch {
+                role: MessageRole::Assistant,
+                timestamp: None,
+                line: "The Borrow Checker rejects line 12.".to_string(),
+                range: 4..18,
+                before: Some("Looking at it.".to_string()),
+                after: Some("Move the clone up.".to_string()),
+            }]
+        );
+        // Capped at one match per session
+        assert_eq!(hits[1].1.len(), 1);
+
+        let pattern = GrepPattern::regex(r"line \d+").unwrap();
+        let hits =
+            SessionContentParser::grep_sessions(&sessions, &pattern, 10, 5, 2, |_, _| {}).await;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].1[0].range, 27..34);
+
+        assert!(GrepPattern::regex("(unclosed").is_err());
     }
 }
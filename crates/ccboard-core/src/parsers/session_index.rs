@@ -7,14 +7,16 @@
 //! Solution: Stream until session_end event, extracting metadata along the way.
 
 use crate::cache::MetadataCache;
-use crate::error::{CoreError, LoadError, LoadReport};
-use crate::models::{session::SessionSummary, SessionLine, SessionMetadata};
+use crate::error::{CoreError, ErrorSeverity, LoadError, LoadReport};
+use crate::models::{session::SessionSummary, SessionLine, SessionMetadata, StatsCache};
 use crate::parsers::filters::is_meaningful_user_message;
-use std::collections::HashSet;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::io::SeekFrom;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::fs::File;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncSeekExt, BufReader};
 use tracing::{debug, trace, warn};
 use walkdir::WalkDir;
 
@@ -27,6 +29,20 @@ const MAX_SCAN_LINES: usize = 10_000;
 /// Maximum line size in bytes (10MB) - OOM protection
 const MAX_LINE_SIZE: usize = 10 * 1024 * 1024;
 
+/// Progress of a [`SessionIndexParser::scan_all`] run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScanProgress {
+    /// Files handled so far (parsed, served from cache, or failed)
+    pub done: usize,
+    /// Session files discovered
+    pub total: usize,
+    /// Of `done`, files served from the metadata cache
+    pub cached: usize,
+}
+
+/// Callback invoked after each file of a scan
+pub type ScanProgressFn = Arc<dyn Fn(ScanProgress) + Send + Sync>;
+
 /// Parser for discovering and indexing sessions
 #[derive(Clone)]
 pub struct SessionIndexParser {
@@ -35,6 +51,9 @@ pub struct SessionIndexParser {
 
     /// Optional metadata cache for 90% speedup
     cache: Option<Arc<MetadataCache>>,
+
+    /// Optional per-file progress callback for `scan_all`
+    progress: Option<ScanProgressFn>,
 }
 
 impl Default for SessionIndexParser {
@@ -42,6 +61,7 @@ impl Default for SessionIndexParser {
         Self {
             max_concurrent: 8,
             cache: None,
+            progress: None,
         }
     }
 }
@@ -62,6 +82,12 @@ impl SessionIndexParser {
         self
     }
 
+    /// Report `scan_all` progress to `progress` after each file
+    pub fn with_progress(mut self, progress: ScanProgressFn) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
     /// Discover all session files under a projects directory
     pub fn discover_sessions(&self, projects_dir: &Path) -> Vec<PathBuf> {
         let mut sessions = Vec::new();
@@ -201,6 +227,18 @@ impl SessionIndexParser {
     ///
     /// If cache is enabled, checks cache first (90% speedup).
     pub async fn scan_session(&self, path: &Path) -> Result<SessionMetadata, CoreError> {
+        self.scan_session_tracked(path).await.map(|(meta, _)| meta)
+    }
+
+    /// [`scan_session`](Self::scan_session), also telling whether the
+    /// metadata came from the cache
+    ///
+    /// A parsed file is written to the cache right away, so an interrupted
+    /// scan keeps everything indexed so far and the next one resumes from it.
+    async fn scan_session_tracked(
+        &self,
+        path: &Path,
+    ) -> Result<(SessionMetadata, bool), CoreError> {
         let path_buf = path.to_path_buf();
 
         // Check cache first if available (in blocking task for SQLite)
@@ -220,7 +258,7 @@ impl SessionIndexParser {
 
                     if let Some(cached) = cached_result {
                         trace!(path = %path.display(), "Using cached metadata");
-                        return Ok(cached);
+                        return Ok((cached, true));
                     }
                 }
             }
@@ -249,275 +287,510 @@ impl SessionIndexParser {
             }
         }
 
-        Ok(metadata)
+        Ok((metadata, false))
     }
 
     /// Scan session without cache (internal)
     async fn scan_session_uncached(&self, path: &Path) -> Result<SessionMetadata, CoreError> {
+        let mut state = self.start_scan(path);
+        Self::resume_scan(path, &mut state).await
+    }
+
+    /// Scan a session file, resuming from a previous scan when possible
+    ///
+    /// Only lines appended since `state` was captured are parsed. Starts over
+    /// when there is no previous state or the file shrank (truncated/rewritten).
+    /// `state` is updated in place for the next call. Bypasses the metadata cache.
+    pub async fn scan_session_incremental(
+        &self,
+        path: &Path,
+        state: &mut Option<SessionScanState>,
+    ) -> Result<SessionMetadata, CoreError> {
+        let len = tokio::fs::metadata(path)
+            .await
+            .map_err(|e| open_error(path, e))?
+            .len();
+
+        if !matches!(state, Some(previous) if previous.offset <= len) {
+            trace!(path = %path.display(), "Full session rescan");
+            *state = None;
+        }
+        let state = state.get_or_insert_with(|| self.start_scan(path));
+        Self::resume_scan(path, state).await
+    }
+
+    /// Empty scan state for `path`
+    fn start_scan(&self, path: &Path) -> SessionScanState {
         let project_path = self.extract_project_path(path);
-        let mut metadata = SessionMetadata::from_path(path.to_path_buf(), project_path.into());
+        SessionScanState::new(SessionMetadata::from_path(
+            path.to_path_buf(),
+            project_path.into(),
+        ))
+    }
 
-        let file = File::open(path).await.map_err(|e| {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                CoreError::FileNotFound {
-                    path: path.to_path_buf(),
-                }
-            } else {
-                CoreError::FileRead {
-                    path: path.to_path_buf(),
-                    source: e,
-                }
+    /// Feed the lines after `state.offset` into `state`
+    async fn resume_scan(
+        path: &Path,
+        state: &mut SessionScanState,
+    ) -> Result<SessionMetadata, CoreError> {
+        let mut file = File::open(path).await.map_err(|e| open_error(path, e))?;
+        if state.finished {
+            return Ok(state.to_metadata());
+        }
+
+        file.seek(SeekFrom::Start(state.offset))
+            .await
+            .map_err(|e| CoreError::FileRead {
+                path: path.to_path_buf(),
+                source: e,
+            })?;
+
+        let mut reader = BufReader::new(file);
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            let read =
+                reader
+                    .read_until(b'\n', &mut buf)
+                    .await
+                    .map_err(|e| CoreError::FileRead {
+                        path: path.to_path_buf(),
+                        source: e,
+                    })?;
+            if read == 0 {
+                break;
             }
-        })?;
-
-        let reader = BufReader::new(file);
-        let mut lines = reader.lines();
-        let mut line_number = 0;
-        let mut models_seen: HashSet<String> = HashSet::new();
-        let mut model_segments: Vec<(String, usize)> = Vec::new();
-        let mut current_segment_model: Option<String> = None;
-        let mut current_segment_count: usize = 0;
-        let mut first_timestamp = None;
-        let mut last_timestamp = None;
-        let mut message_count = 0u64;
-        let mut total_tokens = 0u64;
-        let mut input_tokens = 0u64;
-        let mut output_tokens = 0u64;
-        let mut cache_creation_tokens = 0u64;
-        let mut cache_read_tokens = 0u64;
-        let mut branch: Option<String> = None;
-        let mut tool_usage: std::collections::HashMap<String, usize> =
-            std::collections::HashMap::new();
-        let mut tool_token_usage: std::collections::HashMap<String, u64> =
-            std::collections::HashMap::new();
-        let mut lines_added: u64 = 0;
-        let mut lines_removed: u64 = 0;
-
-        while let Some(line_result) = lines.next_line().await.map_err(|e| CoreError::FileRead {
-            path: path.to_path_buf(),
-            source: e,
-        })? {
-            line_number += 1;
 
-            // Circuit breaker: stop if file is unexpectedly large
-            if line_number > MAX_SCAN_LINES {
-                warn!(
-                    path = %path.display(),
-                    lines = line_number,
-                    "Session scan hit line limit, terminating early"
-                );
+            let line = String::from_utf8_lossy(&buf);
+            if !buf.ends_with(b"\n") {
+                // Unterminated last line: Claude may still be writing it, so
+                // count it now but read it again on the next resume
+                let mut pending = state.clone();
+                pending.push_line(path, &line);
+                return Ok(pending.to_metadata());
+            }
+
+            state.offset += read as u64;
+            state.push_line(path, line.trim_end_matches(['\n', '\r']));
+            if state.finished {
                 break;
             }
+        }
+
+        Ok(state.to_metadata())
+    }
+
+    /// Apply summary data to metadata
+    fn apply_summary(metadata: &mut SessionMetadata, summary: &SessionSummary) {
+        // Only use summary values if they are non-zero (summary might be incomplete)
+        if summary.total_tokens > 0 {
+            metadata.total_tokens = summary.total_tokens;
+        }
+        if summary.message_count > 0 {
+            metadata.message_count = summary.message_count;
+        }
+        metadata.duration_seconds = summary.duration_seconds;
+
+        if let Some(ref models) = summary.models_used {
+            metadata.models_used = models.to_vec();
+        }
+    }
+}
+
+/// Resumable progress of a session scan
+///
+/// Running totals plus the byte offset of the last complete line, so a growing
+/// session file can be scanned again from where the previous scan stopped.
+#[derive(Debug, Clone)]
+pub struct SessionScanState {
+    /// Byte offset just past the last newline-terminated line
+    offset: u64,
+    line_number: usize,
+    /// Summary/session_end reached or line limit hit: nothing more to read
+    finished: bool,
+    /// Fields set while scanning (ID, preview, parent, summary values)
+    metadata: SessionMetadata,
+    models_seen: HashSet<String>,
+    model_segments: Vec<(String, usize)>,
+    current_segment_model: Option<String>,
+    current_segment_count: usize,
+    first_timestamp: Option<DateTime<Utc>>,
+    last_timestamp: Option<DateTime<Utc>>,
+    message_count: u64,
+    total_tokens: u64,
+    input_tokens: u64,
+    output_tokens: u64,
+    cache_creation_tokens: u64,
+    cache_read_tokens: u64,
+    branch: Option<String>,
+    tool_usage: HashMap<String, usize>,
+    tool_token_usage: HashMap<String, u64>,
+    tool_followup_tokens: HashMap<String, u64>,
+    /// tool_use id -> tool name, to resolve tool_result blocks
+    tool_use_names: HashMap<String, String>,
+    /// Tools whose results the next assistant turn answers
+    pending_result_tools: Vec<String>,
+    lines_added: u64,
+    lines_removed: u64,
+    tool_result_bytes: u64,
+    peak_context_tokens: u64,
+    peak_context_pct: f64,
+    /// `compact_boundary` system lines seen
+    compact_boundaries: u32,
+    /// Compaction summary messages seen
+    compact_summaries: u32,
+    malformed_lines: u64,
+    estimated_extra_tokens: u64,
+}
+
+impl SessionScanState {
+    fn new(metadata: SessionMetadata) -> Self {
+        Self {
+            offset: 0,
+            line_number: 0,
+            finished: false,
+            metadata,
+            models_seen: HashSet::new(),
+            model_segments: Vec::new(),
+            current_segment_model: None,
+            current_segment_count: 0,
+            first_timestamp: None,
+            last_timestamp: None,
+            message_count: 0,
+            total_tokens: 0,
+            input_tokens: 0,
+            output_tokens: 0,
+            cache_creation_tokens: 0,
+            cache_read_tokens: 0,
+            branch: None,
+            tool_usage: HashMap::new(),
+            tool_token_usage: HashMap::new(),
+            tool_followup_tokens: HashMap::new(),
+            tool_use_names: HashMap::new(),
+            pending_result_tools: Vec::new(),
+            lines_added: 0,
+            lines_removed: 0,
+            tool_result_bytes: 0,
+            peak_context_tokens: 0,
+            peak_context_pct: 0.0,
+            compact_boundaries: 0,
+            compact_summaries: 0,
+            malformed_lines: 0,
+            estimated_extra_tokens: 0,
+        }
+    }
+
+    /// Bytes of the file consumed so far
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Accumulate one JSONL line
+    fn push_line(&mut self, path: &Path, line: &str) {
+        self.line_number += 1;
+
+        // Circuit breaker: stop if file is unexpectedly large
+        if self.line_number > MAX_SCAN_LINES {
+            warn!(
+                path = %path.display(),
+                lines = self.line_number,
+                "Session scan hit line limit, terminating early"
+            );
+            self.finished = true;
+            return;
+        }
 
-            // SECURITY: OOM protection - skip oversized lines
-            if line_result.len() > MAX_LINE_SIZE {
-                warn!(
+        // SECURITY: OOM protection - skip oversized lines
+        if line.len() > MAX_LINE_SIZE {
+            warn!(
+                path = %path.display(),
+                line = self.line_number,
+                size_mb = line.len() / (1024 * 1024),
+                "Skipping oversized line (potential attack or corruption)"
+            );
+            return;
+        }
+
+        // Parse line (skip malformed)
+        let session_line: SessionLine = match serde_json::from_str(line) {
+            Ok(l) => l,
+            Err(e) => {
+                trace!(
                     path = %path.display(),
-                    line = line_number,
-                    size_mb = line_result.len() / (1024 * 1024),
-                    "Skipping oversized line (potential attack or corruption)"
+                    line = self.line_number,
+                    error = %e,
+                    "Skipping malformed JSONL line"
                 );
-                continue;
+                self.malformed_lines += 1;
+                return;
             }
+        };
 
-            // Parse line (skip malformed)
-            let session_line: SessionLine = match serde_json::from_str(&line_result) {
-                Ok(l) => l,
-                Err(e) => {
-                    trace!(
-                        path = %path.display(),
-                        line = line_number,
-                        error = %e,
-                        "Skipping malformed JSONL line"
-                    );
-                    continue;
-                }
-            };
+        // Track timestamps
+        if let Some(ts) = session_line.timestamp {
+            if self.first_timestamp.is_none() {
+                self.first_timestamp = Some(ts);
+            }
+            self.last_timestamp = Some(ts);
+        }
 
-            // Track timestamps
-            if let Some(ts) = session_line.timestamp {
-                if first_timestamp.is_none() {
-                    first_timestamp = Some(ts);
+        // Extract session ID from first line with it
+        // Prefer sessionId from content over filename-derived ID
+        if let Some(ref id) = session_line.session_id {
+            if self.metadata.id.is_empty()
+                || self.metadata.id == "unknown"
+                || !self
+                    .metadata
+                    .id
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '-')
+                || self.metadata.id.starts_with(".tmp")
+            {
+                self.metadata.id = id.clone().into();
+            }
+        }
+
+        // Track models and compute switching segments.
+        // Claude Code v2.1.92+ moved model from top-level to message.model;
+        // fall back to the nested field when the top-level field is absent.
+        let effective_model = session_line
+            .model
+            .as_ref()
+            .or_else(|| session_line.message.as_ref().and_then(|m| m.model.as_ref()));
+        if let Some(model) = effective_model {
+            self.models_seen.insert(model.clone());
+            // Segment tracking: detect transitions between models
+            if self.current_segment_model.as_deref() == Some(model.as_str()) {
+                self.current_segment_count += 1;
+            } else {
+                if let Some(prev) = self.current_segment_model.take() {
+                    self.model_segments.push((prev, self.current_segment_count));
                 }
-                last_timestamp = Some(ts);
+                self.current_segment_model = Some(model.clone());
+                self.current_segment_count = 1;
             }
+        }
 
-            // Extract session ID from first line with it
-            // Prefer sessionId from content over filename-derived ID
+        // A resumed session's file opens with the earlier conversation, whose
+        // lines keep the original sessionId. Subagent files (`agent-*`) carry
+        // their parent's ID instead and are linked via parentSessionId.
+        if self.metadata.resumed_from.is_none() && !self.metadata.id.starts_with("agent-") {
             if let Some(ref id) = session_line.session_id {
-                if metadata.id.is_empty()
-                    || metadata.id == "unknown"
-                    || !metadata.id.chars().all(|c| c.is_alphanumeric() || c == '-')
-                    || metadata.id.starts_with(".tmp")
-                {
-                    metadata.id = id.clone().into();
+                if self.metadata.id != *id && session_line.parent_session_id.is_none() {
+                    self.metadata.resumed_from = Some(id.clone());
                 }
             }
+        }
 
-            // Track models and compute switching segments.
-            // Claude Code v2.1.92+ moved model from top-level to message.model;
-            // fall back to the nested field when the top-level field is absent.
-            let effective_model = session_line
-                .model
-                .as_ref()
-                .or_else(|| session_line.message.as_ref().and_then(|m| m.model.as_ref()));
-            if let Some(model) = effective_model {
-                models_seen.insert(model.clone());
-                // Segment tracking: detect transitions between models
-                if current_segment_model.as_deref() == Some(model.as_str()) {
-                    current_segment_count += 1;
-                } else {
-                    if let Some(prev) = current_segment_model.take() {
-                        model_segments.push((prev, current_segment_count));
-                    }
-                    current_segment_model = Some(model.clone());
-                    current_segment_count = 1;
-                }
+        // Context compactions
+        if session_line.is_compact_boundary() {
+            self.compact_boundaries += 1;
+        }
+        if session_line.is_compact_summary {
+            self.compact_summaries += 1;
+        }
+
+        // Capture parent session ID (first non-null occurrence wins)
+        if self.metadata.parent_session_id.is_none() {
+            if let Some(ref pid) = session_line.parent_session_id {
+                self.metadata.parent_session_id = Some(pid.clone());
             }
+        }
 
-            // Capture parent session ID (first non-null occurrence wins)
-            if metadata.parent_session_id.is_none() {
-                if let Some(ref pid) = session_line.parent_session_id {
-                    metadata.parent_session_id = Some(pid.clone());
-                }
+        // Capture the working directory (first occurrence wins)
+        if self.metadata.cwd.is_none() {
+            if let Some(ref cwd) = session_line.cwd {
+                self.metadata.cwd = Some(cwd.clone());
             }
+        }
 
-            // Extract git branch (first occurrence wins)
-            if branch.is_none() {
-                if let Some(ref git_branch) = session_line.git_branch {
-                    branch = Some(normalize_branch(git_branch));
-                }
+        // Extract git branch (first occurrence wins)
+        if self.branch.is_none() {
+            if let Some(ref git_branch) = session_line.git_branch {
+                self.branch = Some(normalize_branch(git_branch));
             }
+        }
 
-            // Count user messages and extract first preview (filtered)
-            if session_line.line_type == "user" {
-                message_count += 1;
-
-                if metadata.first_user_message.is_none() {
-                    if let Some(ref msg) = session_line.message {
-                        if let Some(ref content) = msg.content {
-                            // Content can be String (old format) or Array (new format with content blocks)
-                            let text = match content {
-                                serde_json::Value::String(s) => s.clone(),
-                                serde_json::Value::Array(blocks) => {
-                                    // Extract text from content blocks
-                                    blocks
-                                        .iter()
-                                        .filter_map(|block| {
-                                            block.get("text").and_then(|t| t.as_str())
-                                        })
-                                        .collect::<Vec<_>>()
-                                        .join(" ")
-                                }
-                                _ => String::new(),
-                            };
-
-                            // Filter out system/protocol messages for cleaner previews
-                            if is_meaningful_user_message(&text) {
-                                let preview: String =
-                                    text.chars().take(PREVIEW_MAX_CHARS).collect();
-                                metadata.first_user_message = Some(preview);
+        // Count user messages and extract first preview (filtered)
+        if session_line.line_type == "user" {
+            self.message_count += 1;
+
+            // Measure tool outputs fed back to the model (they dominate input tokens)
+            if let Some(blocks) = session_line
+                .message
+                .as_ref()
+                .and_then(|m| m.content.as_ref())
+                .and_then(|c| c.as_array())
+            {
+                self.tool_result_bytes += blocks
+                    .iter()
+                    .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("tool_result"))
+                    .map(tool_result_size)
+                    .sum::<u64>();
+            }
+
+            // The next assistant turn is charged to the tools answered here
+            // (a plain prompt starts a turn no tool is responsible for)
+            self.pending_result_tools = session_line
+                .message
+                .as_ref()
+                .and_then(|m| m.content.as_ref())
+                .and_then(|c| c.as_array())
+                .map(|blocks| {
+                    blocks
+                        .iter()
+                        .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("tool_result"))
+                        .filter_map(|b| b.get("tool_use_id").and_then(|id| id.as_str()))
+                        .filter_map(|id| self.tool_use_names.get(id).cloned())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            if self.metadata.first_user_message.is_none() {
+                if let Some(ref msg) = session_line.message {
+                    if let Some(ref content) = msg.content {
+                        // Content can be String (old format) or Array (new format with content blocks)
+                        let text = match content {
+                            serde_json::Value::String(s) => s.clone(),
+                            serde_json::Value::Array(blocks) => {
+                                // Extract text from content blocks
+                                blocks
+                                    .iter()
+                                    .filter_map(|block| block.get("text").and_then(|t| t.as_str()))
+                                    .collect::<Vec<_>>()
+                                    .join(" ")
                             }
+                            _ => String::new(),
+                        };
+
+                        // Filter out system/protocol messages for cleaner previews
+                        if is_meaningful_user_message(&text) {
+                            let preview: String = text.chars().take(PREVIEW_MAX_CHARS).collect();
+                            self.metadata.first_user_message = Some(preview);
                         }
                     }
                 }
             }
+        }
 
-            // Count assistant messages
-            if session_line.line_type == "assistant" {
-                message_count += 1;
+        // Count assistant messages
+        if session_line.line_type == "assistant" {
+            self.message_count += 1;
 
-                // Accumulate tokens from either root usage or message.usage
-                let usage_opt = session_line
-                    .usage
+            // Accumulate tokens from either root usage or message.usage
+            let usage_opt = session_line
+                .usage
+                .as_ref()
+                .or_else(|| session_line.message.as_ref().and_then(|m| m.usage.as_ref()));
+
+            if usage_opt.is_none() {
+                // No usage block: keep a rough estimate for `TokenSource::Hybrid`
+                if let Some(content) = session_line
+                    .message
                     .as_ref()
-                    .or_else(|| session_line.message.as_ref().and_then(|m| m.usage.as_ref()));
-
-                if let Some(usage) = usage_opt {
-                    total_tokens += usage.total();
-                    input_tokens += usage.input_tokens;
-                    output_tokens += usage.output_tokens;
-                    cache_creation_tokens += usage.cache_write_tokens;
-                    cache_read_tokens += usage.cache_read_tokens;
+                    .and_then(|m| m.content.as_ref())
+                {
+                    self.estimated_extra_tokens += content_chars(content) as u64 / 4;
                 }
+            }
 
-                // Extract tool calls from message
-                if let Some(ref msg) = session_line.message {
-                    // Try tool_calls field first (if present)
-                    if let Some(ref tool_calls) = msg.tool_calls {
-                        for tool_call in tool_calls {
-                            // Format: {"type": "function", "function": {"name": "Read", ...}}
-                            if let Some(function) = tool_call.get("function") {
-                                if let Some(name) = function.get("name").and_then(|n| n.as_str()) {
-                                    *tool_usage.entry(name.to_string()).or_default() += 1;
-                                }
+            if let Some(usage) = usage_opt {
+                self.total_tokens += usage.total();
+                distribute_tokens(
+                    &mut self.tool_followup_tokens,
+                    &self.pending_result_tools,
+                    usage.total(),
+                );
+                self.input_tokens += usage.input_tokens;
+                self.output_tokens += usage.output_tokens;
+                self.cache_creation_tokens += usage.cache_write_tokens;
+                self.cache_read_tokens += usage.cache_read_tokens;
+
+                // Prompt size of this request against the answering model's window
+                let context =
+                    usage.input_tokens + usage.cache_read_tokens + usage.cache_write_tokens;
+                let model = effective_model
+                    .map(String::as_str)
+                    .or(self.current_segment_model.as_deref())
+                    .unwrap_or_default();
+                let pct = context as f64 / StatsCache::context_window_for(model) as f64 * 100.0;
+                if pct > self.peak_context_pct {
+                    self.peak_context_pct = pct;
+                    self.peak_context_tokens = context;
+                }
+            }
+
+            // Extract tool calls from message
+            if let Some(ref msg) = session_line.message {
+                // Try tool_calls field first (if present)
+                if let Some(ref tool_calls) = msg.tool_calls {
+                    for tool_call in tool_calls {
+                        // Format: {"type": "function", "function": {"name": "Read", ...}}
+                        if let Some(function) = tool_call.get("function") {
+                            if let Some(name) = function.get("name").and_then(|n| n.as_str()) {
+                                *self.tool_usage.entry(name.to_string()).or_default() += 1;
                             }
                         }
                     }
+                }
 
-                    // Also check content array for tool_use blocks (real Claude Code format)
-                    if let Some(ref content) = msg.content {
-                        if let Some(blocks) = content.as_array() {
-                            // Collect tool names in this message for proportional token distribution
-                            let message_tools: Vec<String> = blocks
-                                .iter()
-                                .filter(|block| {
-                                    block.get("type").and_then(|t| t.as_str()) == Some("tool_use")
-                                })
-                                .filter_map(|block| {
-                                    block.get("name").and_then(|n| n.as_str()).map(String::from)
-                                })
-                                .collect();
-
-                            // Distribute message tokens proportionally across tools
-                            if !message_tools.is_empty() {
-                                let message_tokens = usage_opt.map(|u| u.total()).unwrap_or(0);
-                                if message_tokens > 0 {
-                                    let tool_count = message_tools.len() as u64;
-                                    let tokens_per_tool = message_tokens / tool_count;
-                                    let remainder = message_tokens % tool_count;
-                                    for (i, tool_name) in message_tools.iter().enumerate() {
-                                        let extra = if i == 0 { remainder } else { 0 };
-                                        *tool_token_usage.entry(tool_name.clone()).or_default() +=
-                                            tokens_per_tool + extra;
-                                    }
-                                }
-                            }
+                // Also check content array for tool_use blocks (real Claude Code format)
+                if let Some(ref content) = msg.content {
+                    if let Some(blocks) = content.as_array() {
+                        // Collect tool names in this message for proportional token distribution
+                        let message_tools: Vec<String> = blocks
+                            .iter()
+                            .filter(|block| {
+                                block.get("type").and_then(|t| t.as_str()) == Some("tool_use")
+                            })
+                            .filter_map(|block| {
+                                block.get("name").and_then(|n| n.as_str()).map(String::from)
+                            })
+                            .collect();
+
+                        // Distribute message tokens proportionally across tools
+                        let message_tokens = usage_opt.map(|u| u.total()).unwrap_or(0);
+                        distribute_tokens(
+                            &mut self.tool_token_usage,
+                            &message_tools,
+                            message_tokens,
+                        );
+
+                        for block in blocks {
+                            if let Some(block_type) = block.get("type").and_then(|t| t.as_str()) {
+                                if block_type == "tool_use" {
+                                    if let Some(name) = block.get("name").and_then(|n| n.as_str()) {
+                                        *self.tool_usage.entry(name.to_string()).or_default() += 1;
+                                        if let Some(id) = block.get("id").and_then(|i| i.as_str()) {
+                                            self.tool_use_names
+                                                .insert(id.to_string(), name.to_string());
+                                        }
 
-                            for block in blocks {
-                                if let Some(block_type) = block.get("type").and_then(|t| t.as_str())
-                                {
-                                    if block_type == "tool_use" {
-                                        if let Some(name) =
-                                            block.get("name").and_then(|n| n.as_str())
-                                        {
-                                            *tool_usage.entry(name.to_string()).or_default() += 1;
-
-                                            // Extract code metrics from Edit/Write tool inputs
-                                            if let Some(input) = block.get("input") {
-                                                match name {
-                                                    "Edit" => {
-                                                        let old = input
-                                                            .get("old_string")
-                                                            .and_then(|v| v.as_str())
-                                                            .unwrap_or("");
-                                                        let new = input
-                                                            .get("new_string")
-                                                            .and_then(|v| v.as_str())
-                                                            .unwrap_or("");
-                                                        lines_removed += old.lines().count() as u64;
-                                                        lines_added += new.lines().count() as u64;
-                                                    }
-                                                    "Write" => {
-                                                        let content = input
-                                                            .get("content")
-                                                            .and_then(|v| v.as_str())
-                                                            .unwrap_or("");
-                                                        lines_added +=
-                                                            content.lines().count() as u64;
-                                                    }
-                                                    _ => {}
+                                        // Extract code metrics from Edit/Write tool inputs
+                                        if let Some(input) = block.get("input") {
+                                            match name {
+                                                "Edit" => {
+                                                    let old = input
+                                                        .get("old_string")
+                                                        .and_then(|v| v.as_str())
+                                                        .unwrap_or("");
+                                                    let new = input
+                                                        .get("new_string")
+                                                        .and_then(|v| v.as_str())
+                                                        .unwrap_or("");
+                                                    self.lines_removed +=
+                                                        old.lines().count() as u64;
+                                                    self.lines_added += new.lines().count() as u64;
+                                                }
+                                                "Write" => {
+                                                    let content = input
+                                                        .get("content")
+                                                        .and_then(|v| v.as_str())
+                                                        .unwrap_or("");
+                                                    self.lines_added +=
+                                                        content.lines().count() as u64;
                                                 }
+                                                _ => {}
                                             }
                                         }
                                     }
@@ -527,71 +800,128 @@ impl SessionIndexParser {
                     }
                 }
             }
+        }
 
-            // Early termination on session_end
-            if session_line.line_type == "summary" || session_line.line_type == "session_end" {
-                if let Some(summary) = session_line.summary {
-                    // Use summary data which is more accurate
-                    Self::apply_summary(&mut metadata, &summary);
-                }
-                break;
+        // Early termination on session_end
+        if session_line.line_type == "summary" || session_line.line_type == "session_end" {
+            if let Some(summary) = session_line.summary {
+                // Use summary data which is more accurate
+                SessionIndexParser::apply_summary(&mut self.metadata, &summary);
             }
+            self.finished = true;
         }
+    }
+
+    /// Metadata for everything scanned so far
+    fn to_metadata(&self) -> SessionMetadata {
+        let mut metadata = self.metadata.clone();
 
-        // Flush the last model segment
-        if let Some(model) = current_segment_model {
-            model_segments.push((model, current_segment_count));
+        // Flush the current model segment
+        let mut model_segments = self.model_segments.clone();
+        if let Some(ref model) = self.current_segment_model {
+            model_segments.push((model.clone(), self.current_segment_count));
         }
 
         // Apply collected data
-        metadata.first_timestamp = first_timestamp;
-        metadata.last_timestamp = last_timestamp;
-        metadata.models_used = models_seen.into_iter().collect();
+        metadata.first_timestamp = self.first_timestamp;
+        metadata.last_timestamp = self.last_timestamp;
+        metadata.models_used = self.models_seen.iter().cloned().collect();
         metadata.model_segments = model_segments;
 
         // Only use counted values if summary didn't provide them
         if metadata.message_count == 0 {
-            metadata.message_count = message_count;
+            metadata.message_count = self.message_count;
         }
         if metadata.total_tokens == 0 {
-            metadata.total_tokens = total_tokens;
+            metadata.total_tokens = self.total_tokens;
         }
 
         // Apply token breakdown (always use counted values, summary doesn't have these)
-        metadata.input_tokens = input_tokens;
-        metadata.output_tokens = output_tokens;
-        metadata.cache_creation_tokens = cache_creation_tokens;
-        metadata.cache_read_tokens = cache_read_tokens;
+        metadata.input_tokens = self.input_tokens;
+        metadata.output_tokens = self.output_tokens;
+        metadata.cache_creation_tokens = self.cache_creation_tokens;
+        metadata.cache_read_tokens = self.cache_read_tokens;
 
         // Apply branch
-        metadata.branch = branch;
+        metadata.branch = self.branch.clone();
 
         // Apply tool usage
-        metadata.tool_usage = tool_usage;
+        metadata.tool_usage = self.tool_usage.clone();
 
         // Apply per-tool token usage
-        metadata.tool_token_usage = tool_token_usage;
+        metadata.tool_token_usage = self.tool_token_usage.clone();
+        metadata.tool_followup_tokens = self.tool_followup_tokens.clone();
 
         // Apply code metrics
-        metadata.lines_added = lines_added;
-        metadata.lines_removed = lines_removed;
+        metadata.lines_added = self.lines_added;
+        metadata.lines_removed = self.lines_removed;
+
+        // Apply tool output size
+        metadata.tool_result_bytes = self.tool_result_bytes;
+
+        // Apply peak context utilization
+        metadata.peak_context_tokens = self.peak_context_tokens;
+        metadata.peak_context_pct = self.peak_context_pct;
 
-        Ok(metadata)
+        // Each compaction writes a boundary line followed by a summary
+        // message; count either, as some versions only write the summary
+        metadata.compaction_count = self.compact_boundaries.max(self.compact_summaries);
+
+        metadata.malformed_lines = self.malformed_lines;
+        metadata.estimated_extra_tokens = self.estimated_extra_tokens;
+
+        metadata
     }
+}
 
-    /// Apply summary data to metadata
-    fn apply_summary(metadata: &mut SessionMetadata, summary: &SessionSummary) {
-        // Only use summary values if they are non-zero (summary might be incomplete)
-        if summary.total_tokens > 0 {
-            metadata.total_tokens = summary.total_tokens;
-        }
-        if summary.message_count > 0 {
-            metadata.message_count = summary.message_count;
-        }
-        metadata.duration_seconds = summary.duration_seconds;
+/// Split `tokens` evenly across `tools` (remainder to the first one)
+fn distribute_tokens(totals: &mut HashMap<String, u64>, tools: &[String], tokens: u64) {
+    if tools.is_empty() || tokens == 0 {
+        return;
+    }
+    let tool_count = tools.len() as u64;
+    let tokens_per_tool = tokens / tool_count;
+    let remainder = tokens % tool_count;
+    for (i, tool_name) in tools.iter().enumerate() {
+        let extra = if i == 0 { remainder } else { 0 };
+        *totals.entry(tool_name.clone()).or_default() += tokens_per_tool + extra;
+    }
+}
 
-        if let Some(ref models) = summary.models_used {
-            metadata.models_used = models.to_vec();
+/// Characters of message content: plain text, text/thinking blocks and
+/// tool_use inputs (as JSON)
+fn content_chars(content: &serde_json::Value) -> usize {
+    match content {
+        serde_json::Value::String(s) => s.chars().count(),
+        serde_json::Value::Array(blocks) => blocks
+            .iter()
+            .map(|block| {
+                let text = ["text", "thinking"]
+                    .iter()
+                    .filter_map(|key| block.get(key).and_then(|t| t.as_str()))
+                    .map(|t| t.chars().count())
+                    .sum::<usize>();
+                let input = block
+                    .get("input")
+                    .map(|i| i.to_string().chars().count())
+                    .unwrap_or(0);
+                text + input
+            })
+            .sum(),
+        _ => 0,
+    }
+}
+
+/// Map a file open error, distinguishing missing files
+fn open_error(path: &Path, e: std::io::Error) -> CoreError {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        CoreError::FileNotFound {
+            path: path.to_path_buf(),
+        }
+    } else {
+        CoreError::FileRead {
+            path: path.to_path_buf(),
+            source: e,
         }
     }
 }
@@ -624,6 +954,22 @@ fn normalize_branch(raw: &str) -> String {
     normalized.to_string()
 }
 
+/// Size in bytes of the content carried by a `tool_result` block.
+///
+/// Content is either a plain string or an array of content blocks; only
+/// text blocks are counted (images are billed differently).
+fn tool_result_size(block: &serde_json::Value) -> u64 {
+    match block.get("content") {
+        Some(serde_json::Value::String(s)) => s.len() as u64,
+        Some(serde_json::Value::Array(parts)) => parts
+            .iter()
+            .filter_map(|p| p.get("text").and_then(|t| t.as_str()))
+            .map(|t| t.len() as u64)
+            .sum(),
+        _ => 0,
+    }
+}
+
 impl SessionIndexParser {
     /// Scan session with graceful degradation
     pub async fn scan_session_graceful(
@@ -634,27 +980,38 @@ impl SessionIndexParser {
         match self.scan_session(path).await {
             Ok(meta) => {
                 report.sessions_scanned += 1;
+                report.add_malformed_lines(path, meta.malformed_lines);
                 Some(meta)
             }
             Err(e) => {
                 report.sessions_failed += 1;
-                report.add_warning(
-                    format!("session:{}", path.display()),
-                    format!("Failed to scan: {}", e),
-                );
+                report.add_error(LoadError {
+                    severity: ErrorSeverity::Warning,
+                    ..LoadError::from_core_error(format!("session:{}", path.display()), &e)
+                });
                 None
             }
         }
     }
 
     /// Scan all sessions in a directory with parallel processing
+    ///
+    /// Reports progress after each file when built `with_progress`. When files
+    /// had to be parsed into the cache, records the run's duration and file
+    /// count as the cache's last rebuild.
     pub async fn scan_all(
         &self,
         projects_dir: &Path,
         report: &mut LoadReport,
     ) -> Vec<SessionMetadata> {
+        let started = std::time::Instant::now();
         let paths = self.discover_sessions(projects_dir);
         let mut results = Vec::with_capacity(paths.len());
+        let mut progress = ScanProgress {
+            total: paths.len(),
+            ..Default::default()
+        };
+        let mut indexed = 0;
 
         // Use semaphore for bounded concurrency
         let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(self.max_concurrent));
@@ -674,21 +1031,31 @@ impl SessionIndexParser {
                         return Err(CoreError::LockTimeout);
                     }
                 };
-                parser.scan_session(&path).await
+                parser.scan_session_tracked(&path).await
             });
 
             handles.push(handle);
         }
 
         for handle in handles {
+            progress.done += 1;
             match handle.await {
-                Ok(Ok(meta)) => {
+                Ok(Ok((meta, cached))) => {
+                    if cached {
+                        progress.cached += 1;
+                    } else {
+                        indexed += 1;
+                    }
                     report.sessions_scanned += 1;
+                    report.add_malformed_lines(&meta.file_path, meta.malformed_lines);
                     results.push(meta);
                 }
                 Ok(Err(e)) => {
                     report.sessions_failed += 1;
-                    report.add_warning("session_scan", e.to_string());
+                    report.add_error(LoadError {
+                        severity: ErrorSeverity::Warning,
+                        ..LoadError::from_core_error("session_scan", &e)
+                    });
                 }
                 Err(e) => {
                     report.sessions_failed += 1;
@@ -698,6 +1065,19 @@ impl SessionIndexParser {
                     ));
                 }
             }
+            if let Some(ref callback) = self.progress {
+                callback(progress);
+            }
+        }
+
+        if let (Some(cache), true) = (&self.cache, indexed > 0) {
+            let cache = cache.clone();
+            let elapsed = started.elapsed();
+            if let Ok(Err(e)) =
+                tokio::task::spawn_blocking(move || cache.record_rebuild(elapsed, indexed)).await
+            {
+                warn!(error = %e, "Failed to record cache rebuild");
+            }
         }
 
         debug!(
@@ -713,6 +1093,7 @@ impl SessionIndexParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::TokenSource;
     use std::io::Write;
     use tempfile::{tempdir, NamedTempFile};
 
@@ -799,12 +1180,217 @@ mod tests {
             .contains(&"claude-sonnet-4-20250514".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_scan_all_resumes_from_cache_with_progress() {
+        let home = tempdir().unwrap();
+        let project = home.path().join("projects").join("-tmp-app");
+        std::fs::create_dir_all(&project).unwrap();
+        for id in ["s1", "s2", "s3"] {
+            std::fs::write(
+                project.join(format!("{}.jsonl", id)),
+                format!(
+                    "{{\"type\": \"user\", \"sessionId\": \"{}\", \"timestamp\": \"2025-01-15T10:00:00Z\", \"message\": {{\"content\": \"Hi\"}}}}\n",
+                    id
+                ),
+            )
+            .unwrap();
+        }
+        let cache = Arc::new(MetadataCache::new(&home.path().join("cache")).unwrap());
+
+        // An earlier, interrupted rebuild got as far as one file
+        let parser = SessionIndexParser::new().with_cache(cache.clone());
+        parser
+            .scan_session(&project.join("s1.jsonl"))
+            .await
+            .unwrap();
+
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let parser = parser.with_progress(Arc::new(move |p| sink.lock().unwrap().push(p)));
+        let mut report = LoadReport::new();
+        let sessions = parser
+            .scan_all(&home.path().join("projects"), &mut report)
+            .await;
+        assert_eq!(sessions.len(), 3);
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 3);
+        assert_eq!(
+            seen.last(),
+            Some(&ScanProgress {
+                done: 3,
+                total: 3,
+                cached: 1
+            })
+        );
+        // Only the two files left were parsed
+        assert_eq!(cache.stats().unwrap().last_rebuild_files, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_scan_session_counts_malformed_lines() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"type": "user", "sessionId": "test-123", "timestamp": "2025-01-15T10:01:00Z", "message": {{"content": "Hello"}}}}"#
+        )
+        .unwrap();
+        writeln!(file, "not json at all").unwrap();
+        writeln!(
+            file,
+            r#"{{"type": "assistant", "timestamp": "2025-01-15T10:02:00Z", "message": {{"model": "claude-sonnet-4-5", "usage": {{"input_tokens": 100, "output_tokens": 50}}}}}}"#
+        )
+        .unwrap();
+        writeln!(file, r#"{{"type": "assistant", "message": {{"#).unwrap();
+
+        let parser = SessionIndexParser::new();
+        let mut report = LoadReport::new();
+        let meta = parser
+            .scan_session_graceful(file.path(), &mut report)
+            .await
+            .unwrap();
+
+        // Valid lines around the garbage are still counted
+        assert_eq!(meta.message_count, 2);
+        assert_eq!(meta.output_tokens, 50);
+        assert_eq!(meta.malformed_lines, 2);
+
+        assert_eq!(report.malformed_lines, 2);
+        let warning = report.warnings().next().unwrap();
+        assert!(warning.source.ends_with(&file.path().display().to_string()));
+        assert!(warning.message.starts_with("2 malformed lines skipped"));
+    }
+
+    #[tokio::test]
+    async fn test_scan_session_estimates_tokens_without_usage() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"type": "assistant", "message": {{"content": "abcdefgh", "usage": {{"input_tokens": 100, "output_tokens": 50}}}}}}"#
+        )
+        .unwrap();
+        // 40 chars of text + 13 chars of tool input JSON, no usage block
+        writeln!(
+            file,
+            r#"{{"type": "assistant", "message": {{"content": [{{"type": "text", "text": "{}"}}, {{"type": "tool_use", "name": "Read", "input": {{"a": "12345"}}}}]}}}}"#,
+            "x".repeat(40)
+        )
+        .unwrap();
+
+        let meta = SessionIndexParser::new()
+            .scan_session(file.path())
+            .await
+            .unwrap();
+        assert_eq!(meta.total_tokens, 150);
+        assert_eq!(meta.estimated_extra_tokens, 13);
+
+        assert_eq!(meta.tokens_for(TokenSource::Reported), 150);
+        assert!(!meta.tokens_estimated(TokenSource::Reported));
+        assert_eq!(meta.tokens_for(TokenSource::Hybrid), 163);
+        assert!(meta.tokens_estimated(TokenSource::Hybrid));
+    }
+
+    #[tokio::test]
+    async fn test_scan_session_charges_followup_turn_to_tool() {
+        let mut file = NamedTempFile::new().unwrap();
+        let lines = [
+            r#"{"type": "user", "message": {"content": "Look at main.rs"}}"#,
+            r#"{"type": "assistant", "message": {"content": [{"type": "tool_use", "id": "t1", "name": "Read", "input": {}}, {"type": "tool_use", "id": "t2", "name": "Bash", "input": {}}], "usage": {"input_tokens": 10, "output_tokens": 5}}}"#,
+            r#"{"type": "user", "message": {"content": [{"type": "tool_result", "tool_use_id": "t1", "content": "..."}, {"type": "tool_result", "tool_use_id": "t2", "content": "..."}]}}"#,
+            // The turn answering both results is split between Read and Bash
+            r#"{"type": "assistant", "message": {"content": "ok", "usage": {"input_tokens": 1000, "output_tokens": 1}}}"#,
+            r#"{"type": "assistant", "message": {"content": [{"type": "tool_use", "id": "t3", "name": "Read", "input": {}}], "usage": {"input_tokens": 200, "output_tokens": 0}}}"#,
+            r#"{"type": "user", "message": {"content": [{"type": "tool_result", "tool_use_id": "t3", "content": "..."}]}}"#,
+            r#"{"type": "assistant", "message": {"content": "done", "usage": {"input_tokens": 4000, "output_tokens": 0}}}"#,
+            // Plain prompt: the next turn is not charged to any tool
+            r#"{"type": "user", "message": {"content": "thanks"}}"#,
+            r#"{"type": "assistant", "message": {"content": "np", "usage": {"input_tokens": 9999, "output_tokens": 0}}}"#,
+        ];
+        for line in lines {
+            writeln!(file, "{}", line).unwrap();
+        }
+
+        let meta = SessionIndexParser::new()
+            .scan_session(file.path())
+            .await
+            .unwrap();
+        // Read: 501 (half + remainder) + 100 (half of the same turn) + 4000
+        assert_eq!(meta.tool_followup_tokens.get("Read"), Some(&4601));
+        assert_eq!(meta.tool_followup_tokens.get("Bash"), Some(&600));
+        assert_eq!(meta.tool_followup_tokens.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_scan_session_incremental() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"type": "user", "sessionId": "inc", "message": {{"content": "Start"}}}}"#
+        )
+        .unwrap();
+        writeln!(
+            file,
+            r#"{{"type": "assistant", "usage": {{"input_tokens": 100, "output_tokens": 50}}}}"#
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let parser = SessionIndexParser::new();
+        let mut state = None;
+        let meta = parser
+            .scan_session_incremental(file.path(), &mut state)
+            .await
+            .unwrap();
+        assert_eq!(meta.message_count, 2);
+        assert_eq!(meta.total_tokens, 150);
+        let offset = state.as_ref().unwrap().offset();
+
+        // Append a complete line plus a partial one still being written
+        writeln!(
+            file,
+            r#"{{"type": "assistant", "usage": {{"input_tokens": 10, "output_tokens": 5}}}}"#
+        )
+        .unwrap();
+        write!(file, r#"{{"type": "user", "message""#).unwrap();
+        file.flush().unwrap();
+
+        let meta = parser
+            .scan_session_incremental(file.path(), &mut state)
+            .await
+            .unwrap();
+        assert_eq!(meta.message_count, 3);
+        assert_eq!(meta.total_tokens, 165);
+        assert_eq!(meta.first_user_message.as_deref(), Some("Start"));
+        assert!(state.as_ref().unwrap().offset() > offset);
+
+        // Finishing the partial line picks it up on the next resume
+        writeln!(file, r#": {{"content": "More"}}}}"#).unwrap();
+        file.flush().unwrap();
+        let meta = parser
+            .scan_session_incremental(file.path(), &mut state)
+            .await
+            .unwrap();
+        assert_eq!(meta.message_count, 4);
+        let full = parser.scan_session(file.path()).await.unwrap();
+        assert_eq!(meta.total_tokens, full.total_tokens);
+        assert_eq!(meta.message_count, full.message_count);
+
+        // Truncation forces a full rescan
+        file.as_file().set_len(0).unwrap();
+        let meta = parser
+            .scan_session_incremental(file.path(), &mut state)
+            .await
+            .unwrap();
+        assert_eq!(meta.message_count, 0);
+        assert_eq!(state.unwrap().offset(), 0);
+    }
+
     #[tokio::test]
     async fn test_scan_session_with_branch() {
         let mut file = NamedTempFile::new().unwrap();
         writeln!(
             file,
-            r#"{{"type": "user", "sessionId": "test-branch", "gitBranch": "worktrees/feature-cli (dirty)", "message": {{"content": "Test"}}}}"#
+            r#"{{"type": "user", "sessionId": "test-branch", "cwd": "/Users/me/code/legacy-api", "gitBranch": "worktrees/feature-cli (dirty)", "message": {{"content": "Test"}}}}"#
         )
         .unwrap();
         writeln!(
@@ -822,6 +1408,7 @@ mod tests {
         let meta = parser.scan_session(file.path()).await.unwrap();
 
         assert_eq!(meta.branch, Some("feature-cli".to_string()));
+        assert_eq!(meta.cwd.as_deref(), Some("/Users/me/code/legacy-api"));
     }
 
     #[tokio::test]
@@ -854,6 +1441,63 @@ mod tests {
         assert!(meta.first_user_message.unwrap().contains("First"));
     }
 
+    #[tokio::test]
+    async fn test_scan_session_counts_compactions() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("long-session.jsonl");
+        std::fs::write(
+            &path,
+            concat!(
+                r#"{"type": "user", "message": {"content": "Refactor the parser"}}"#,
+                "\n",
+                r#"{"type": "system", "subtype": "compact_boundary", "content": "Conversation compacted", "compactMetadata": {"trigger": "auto", "preTokens": 155000}}"#,
+                "\n",
+                r#"{"type": "user", "isCompactSummary": true, "message": {"content": "This session is being continued..."}}"#,
+                "\n",
+                r#"{"type": "system", "subtype": "compact_boundary", "content": "Conversation compacted"}"#,
+                "\n",
+                r#"{"type": "user", "isCompactSummary": true, "message": {"content": "This session is being continued..."}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        let parser = SessionIndexParser::new();
+        let meta = parser.scan_session(&path).await.unwrap();
+        assert_eq!(meta.compaction_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_scan_session_detects_resumed_from() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("new-session.jsonl");
+        std::fs::write(
+            &path,
+            concat!(
+                r#"{"type": "user", "sessionId": "old-session", "message": {"content": "Earlier"}}"#,
+                "\n",
+                r#"{"type": "user", "sessionId": "new-session", "message": {"content": "Go on"}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        let parser = SessionIndexParser::new();
+        let meta = parser.scan_session(&path).await.unwrap();
+        assert_eq!(meta.id, "new-session");
+        assert_eq!(meta.resumed_from.as_deref(), Some("old-session"));
+
+        // Subagent files share their parent's sessionId without resuming it
+        let agent = dir.path().join("agent-a1b2.jsonl");
+        std::fs::write(
+            &agent,
+            r#"{"type": "user", "sessionId": "old-session", "message": {"content": "Task"}}"#,
+        )
+        .unwrap();
+        let meta = parser.scan_session(&agent).await.unwrap();
+        assert_eq!(meta.resumed_from, None);
+    }
+
     #[test]
     fn test_discover_sessions() {
         let dir = tempdir().unwrap();
@@ -901,6 +1545,11 @@ mod tests {
         // Message 2: input(200) + output(75) = 275
         // Total = 1650 + 275 = 1925
         assert_eq!(meta.total_tokens, 1925);
+
+        // Largest prompt: 100 + 1000 + 500 against Sonnet's 200K window
+        assert_eq!(meta.peak_context_tokens, 1600);
+        assert!((meta.peak_context_pct - 0.8).abs() < 1e-9);
+        assert!(!meta.context_near_limit());
     }
 
     #[test]
@@ -1027,6 +1676,35 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_tool_result_bytes_extraction() {
+        let mut file = NamedTempFile::new().unwrap();
+
+        writeln!(
+            file,
+            r#"{{"type": "assistant", "message": {{"content": [{{"type": "tool_use", "name": "Read", "id": "call_1"}}, {{"type": "tool_use", "name": "WebFetch", "id": "call_2"}}]}}}}"#
+        )
+        .unwrap();
+        // String content (10 bytes) + array content with text (5 + 3 bytes) and an image
+        writeln!(
+            file,
+            r#"{{"type": "user", "message": {{"content": [{{"type": "tool_result", "tool_use_id": "call_1", "content": "0123456789"}}, {{"type": "tool_result", "tool_use_id": "call_2", "content": [{{"type": "text", "text": "hello"}}, {{"type": "image", "source": {{}}}}, {{"type": "text", "text": "abc"}}]}}]}}}}"#
+        )
+        .unwrap();
+        // Plain user text is not a tool result
+        writeln!(
+            file,
+            r#"{{"type": "user", "message": {{"content": [{{"type": "text", "text": "thanks"}}]}}}}"#
+        )
+        .unwrap();
+
+        let parser = SessionIndexParser::new();
+        let meta = parser.scan_session(file.path()).await.unwrap();
+
+        assert_eq!(meta.tool_result_bytes, 18);
+        assert_eq!(meta.tool_result_tokens(), 4);
+    }
+
     #[tokio::test]
     async fn test_message_filtering_excludes_system_messages() {
         let mut file = NamedTempFile::new().unwrap();
@@ -36,8 +36,10 @@ impl SettingsParser {
             }
         })?;
 
-        serde_json::from_str(&content).map_err(|e| CoreError::JsonParse {
+        serde_json::from_str(&content).map_err(|e| CoreError::SettingsParse {
             path: path.to_path_buf(),
+            line: e.line(),
+            column: e.column(),
             message: e.to_string(),
             source: e,
         })
@@ -62,7 +64,7 @@ impl SettingsParser {
             }
             Err(e) => {
                 warn!(?path, error = %e, "Failed to parse settings");
-                report.add_error(LoadError::error(source_name, e.to_string()));
+                report.add_error(LoadError::from_core_error(source_name, &e));
                 None
             }
         }
@@ -257,6 +259,30 @@ mod tests {
         assert!(!report.has_errors() || report.warnings().count() == 0);
     }
 
+    #[tokio::test]
+    async fn test_parse_invalid_settings_reports_line() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("settings.json");
+        std::fs::write(&path, "{\n  \"model\": \"opus\",\n  \"env\": {,}\n}\n").unwrap();
+
+        let parser = SettingsParser::new();
+        let err = parser.parse(&path).await.unwrap_err();
+        assert!(matches!(err, CoreError::SettingsParse { line: 3, .. }));
+        assert!(err.is_recoverable());
+
+        let mut report = LoadReport::new();
+        assert!(parser
+            .parse_graceful(&path, "settings.global", &mut report)
+            .await
+            .is_none());
+        let error = &report.errors[0];
+        assert_eq!(error.kind, Some(crate::error::ErrorKind::SettingsParse));
+        assert!(error
+            .message
+            .starts_with("settings.json is invalid JSON at line 3, column"));
+        assert!(!report.has_fatal_errors());
+    }
+
     #[tokio::test]
     async fn test_load_merged_hierarchy() {
         let dir = tempdir().unwrap();
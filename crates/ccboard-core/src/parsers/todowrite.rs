@@ -269,6 +269,9 @@ mod tests {
             usage: None,
             summary: None,
             parent_session_id: None,
+            subtype: None,
+            is_compact_summary: false,
+            compact_metadata: None,
         };
 
         let events = TodoWriteParser::extract_task_events(&line);
@@ -309,6 +312,9 @@ mod tests {
             usage: None,
             summary: None,
             parent_session_id: None,
+            subtype: None,
+            is_compact_summary: false,
+            compact_metadata: None,
         };
 
         let events = TodoWriteParser::extract_task_events(&line);
@@ -0,0 +1,25 @@
+//! Atomic JSON persistence for ccboard's own state files in `~/.ccboard`
+//!
+//! Bookmarks, pins, tags and the resume log are each one small JSON file,
+//! rewritten in full on every change.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+/// Write `value` as pretty JSON to `path` (tmp file → rename), creating the
+/// parent directory if needed
+pub(crate) fn write_json_atomic<T: Serialize + ?Sized>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let json = serde_json::to_string_pretty(value)
+        .with_context(|| format!("Failed to serialise {}", path.display()))?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, &json).with_context(|| format!("Failed to write {}", tmp.display()))?;
+    std::fs::rename(&tmp, path)
+        .with_context(|| format!("Failed to rename {} → {}", tmp.display(), path.display()))?;
+    Ok(())
+}
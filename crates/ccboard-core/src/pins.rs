@@ -0,0 +1,106 @@
+//! Pinned sessions — persisted to ~/.ccboard/pinned.json
+//!
+//! Stored with the bookmarks in `~/.ccboard`, not under `~/.claude`, so
+//! ccboard's own state stays out of Claude Code's directory.
+//!
+//! A plain set of session IDs the user keeps coming back to. IDs are kept even
+//! when the session file disappears, so a pin survives a session being moved
+//! or re-indexed; callers resolve them against loaded sessions and skip misses.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// Persisted pin set
+///
+/// Backed by `~/.ccboard/pinned.json` (a JSON array of session IDs).
+/// `toggle` persists immediately (atomic write); reads are in-memory.
+#[derive(Debug, Default)]
+pub struct PinStore {
+    path: PathBuf,
+    ids: BTreeSet<String>,
+}
+
+impl PinStore {
+    /// Load from `path`.  If the file does not exist, an empty store is returned.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let ids = if path.exists() {
+            let raw = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            serde_json::from_str::<BTreeSet<String>>(&raw)
+                .with_context(|| format!("Failed to parse {}", path.display()))?
+        } else {
+            BTreeSet::new()
+        };
+        Ok(Self { path, ids })
+    }
+
+    /// Return the filesystem path backing this store
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Pin if absent, unpin if present.  Persists immediately.
+    /// Returns `true` if the session is now pinned.
+    pub fn toggle(&mut self, session_id: &str) -> Result<bool> {
+        let pinned = if self.ids.remove(session_id) {
+            false
+        } else {
+            self.ids.insert(session_id.to_string());
+            true
+        };
+        self.save()?;
+        Ok(pinned)
+    }
+
+    /// Return `true` if the session is pinned
+    pub fn is_pinned(&self, session_id: &str) -> bool {
+        self.ids.contains(session_id)
+    }
+
+    /// All pinned session IDs, sorted
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.ids.iter().map(|s| s.as_str())
+    }
+
+    /// Number of pins
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether the store is empty
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    fn save(&self) -> Result<()> {
+        crate::persist::write_json_atomic(&self.path, &self.ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pinned.json");
+
+        let mut pins = PinStore::load(&path).unwrap();
+        assert!(pins.is_empty());
+
+        assert!(pins.toggle("sess-b").unwrap());
+        assert!(pins.toggle("sess-a").unwrap());
+        assert!(pins.toggle("sess-c").unwrap());
+        assert!(!pins.toggle("sess-c").unwrap());
+
+        let reloaded = PinStore::load(&path).unwrap();
+        assert_eq!(reloaded.len(), 2);
+        assert!(reloaded.is_pinned("sess-a"));
+        assert!(!reloaded.is_pinned("sess-c"));
+        assert_eq!(reloaded.ids().collect::<Vec<_>>(), ["sess-a", "sess-b"]);
+    }
+}
@@ -5,8 +5,8 @@
 use super::litellm::{CachedPricing, LITELLM_PRICING_URL};
 use crate::pricing::ModelPricing;
 use anyhow::{Context, Result};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
 /// Cache expiration duration (7 days)
 const CACHE_TTL_DAYS: i64 = 7;
@@ -27,22 +27,16 @@ pub fn cache_path() -> Result<PathBuf> {
 pub fn load_cached_pricing() -> Result<Option<HashMap<String, ModelPricing>>> {
     let path = cache_path()?;
 
-    if !path.exists() {
+    let Some(cached) = read_cache_file(&path)? else {
         tracing::debug!("No pricing cache found at {}", path.display());
         return Ok(None);
-    }
-
-    let content = std::fs::read_to_string(&path)
-        .with_context(|| format!("Failed to read pricing cache: {}", path.display()))?;
-
-    let cached: CachedPricing = serde_json::from_str(&content)
-        .with_context(|| format!("Failed to parse pricing cache: {}", path.display()))?;
+    };
 
     // Check if cache is expired
     let now = chrono::Utc::now();
     let age = now.signed_duration_since(cached.last_updated);
 
-    if age.num_days() > CACHE_TTL_DAYS {
+    if is_expired(age) {
         tracing::info!(
             "Pricing cache expired ({} days old, TTL: {} days)",
             age.num_days(),
@@ -60,14 +54,67 @@ pub fn load_cached_pricing() -> Result<Option<HashMap<String, ModelPricing>>> {
     Ok(Some(cached.models))
 }
 
+/// Age of the pricing cache, `None` if it has never been written
+pub fn cached_pricing_age() -> Result<Option<chrono::Duration>> {
+    let path = cache_path()?;
+    Ok(read_cache_file(&path)?
+        .map(|cached| chrono::Utc::now().signed_duration_since(cached.last_updated)))
+}
+
+/// Whether a cache this old is past its TTL (ignored in favor of embedded prices)
+pub fn is_expired(age: chrono::Duration) -> bool {
+    age.num_days() > CACHE_TTL_DAYS
+}
+
+/// Load the date-keyed pricing history from cache
+///
+/// Unlike `load_cached_pricing`, this ignores the TTL: old snapshots are
+/// exactly what historical cost reconciliation needs.
+pub fn load_pricing_history() -> Result<BTreeMap<chrono::NaiveDate, HashMap<String, ModelPricing>>>
+{
+    let path = cache_path()?;
+    Ok(read_cache_file(&path)?
+        .map(|cached| cached.history)
+        .unwrap_or_default())
+}
+
+/// Read the cache file, if present
+fn read_cache_file(path: &Path) -> Result<Option<CachedPricing>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read pricing cache: {}", path.display()))?;
+
+    let cached: CachedPricing = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse pricing cache: {}", path.display()))?;
+
+    Ok(Some(cached))
+}
+
 /// Save pricing to cache
+///
+/// Keeps the history from the previous cache file and records `models` as
+/// today's snapshot when they differ from the latest one.
 pub fn save_pricing_cache(models: HashMap<String, ModelPricing>) -> Result<()> {
     let path = cache_path()?;
 
+    let now = chrono::Utc::now();
+    let mut history = match read_cache_file(&path) {
+        Ok(previous) => previous.map(|cached| cached.history).unwrap_or_default(),
+        Err(e) => {
+            tracing::warn!("Discarding unreadable pricing history: {}", e);
+            BTreeMap::new()
+        }
+    };
+    record_snapshot(&mut history, now.date_naive(), &models);
+
     let cached = CachedPricing {
-        last_updated: chrono::Utc::now(),
+        last_updated: now,
         models,
         source: LITELLM_PRICING_URL.to_string(),
+        history,
     };
 
     let json =
@@ -81,6 +128,24 @@ pub fn save_pricing_cache(models: HashMap<String, ModelPricing>) -> Result<()> {
     Ok(())
 }
 
+/// Add `models` to `history` under `date`, unless prices are unchanged
+/// since the latest earlier snapshot
+fn record_snapshot(
+    history: &mut BTreeMap<chrono::NaiveDate, HashMap<String, ModelPricing>>,
+    date: chrono::NaiveDate,
+    models: &HashMap<String, ModelPricing>,
+) {
+    let unchanged = history
+        .range(..date)
+        .next_back()
+        .is_some_and(|(_, latest)| latest == models);
+    if unchanged {
+        history.remove(&date);
+    } else {
+        history.insert(date, models.clone());
+    }
+}
+
 /// Clear pricing cache
 pub fn clear_pricing_cache() -> Result<()> {
     let path = cache_path()?;
@@ -104,4 +169,28 @@ mod tests {
         assert!(path.to_string_lossy().contains("ccboard"));
         assert!(path.to_string_lossy().ends_with("pricing.json"));
     }
+
+    #[test]
+    fn test_record_snapshot_only_on_change() {
+        let day = |d| chrono::NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+        let prices = |input| {
+            HashMap::from([(
+                "claude-opus-4-5".to_string(),
+                ModelPricing {
+                    input_price_per_million: input,
+                    ..ModelPricing::default_average()
+                },
+            )])
+        };
+
+        let mut history = BTreeMap::new();
+        record_snapshot(&mut history, day(1), &prices(5.0));
+        record_snapshot(&mut history, day(8), &prices(5.0));
+        record_snapshot(&mut history, day(15), &prices(4.0));
+
+        assert_eq!(
+            history.keys().copied().collect::<Vec<_>>(),
+            [day(1), day(15)]
+        );
+    }
 }
@@ -30,7 +30,7 @@ use std::collections::HashMap;
 /// Pricing structure for a Claude model
 ///
 /// All prices are per million tokens (M). Cache multipliers are applied to input price.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModelPricing {
     /// Price per million input tokens ($/M)
     pub input_price_per_million: f64,
@@ -98,7 +98,9 @@ static PRICING_TABLE: Lazy<HashMap<&'static str, ModelPricing>> = Lazy::new(|| {
         cache_write_multiplier: 1.25, // $18.75/M (125% of $15)
     };
     m.insert("claude-opus-4-0-20250514", opus_legacy_pricing.clone());
-    m.insert("claude-opus-4-1-20250805", opus_legacy_pricing);
+    m.insert("claude-opus-4-0", opus_legacy_pricing.clone()); // canonical (normalize_model_id)
+    m.insert("claude-opus-4-1-20250805", opus_legacy_pricing.clone());
+    m.insert("claude-opus-4-1", opus_legacy_pricing); // canonical (normalize_model_id)
 
     // Claude Sonnet 4.5/4.6 - Balanced model (default in Claude Code)
     let sonnet_pricing = ModelPricing {
@@ -113,6 +115,7 @@ static PRICING_TABLE: Lazy<HashMap<&'static str, ModelPricing>> = Lazy::new(|| {
     m.insert("claude-sonnet-4-6-20250514", sonnet_pricing.clone()); // Sonnet 4.6 (current default)
     m.insert("claude-sonnet-4-6", sonnet_pricing.clone()); // Sonnet 4.6 alias
     m.insert("claude-sonnet-4.6", sonnet_pricing.clone()); // dot-style alias
+    m.insert("claude-sonnet-4-0", sonnet_pricing.clone()); // canonical (normalize_model_id)
     m.insert("sonnet-4", sonnet_pricing.clone());
     m.insert("claude-sonnet-4", sonnet_pricing);
 
@@ -142,7 +145,8 @@ pub static MODEL_PRICING: Lazy<HashMap<String, ModelPricing>> = Lazy::new(|| {
 
 /// Get pricing for a specific model
 ///
-/// Returns the pricing structure for the given model ID. If the model is not recognized,
+/// Returns the pricing structure for the given model ID. Unknown IDs are retried with
+/// their canonical form (see `normalize_model_id`). If the model is still not recognized,
 /// returns a default weighted average pricing based on typical usage patterns.
 ///
 /// # Examples
@@ -159,6 +163,7 @@ pub static MODEL_PRICING: Lazy<HashMap<String, ModelPricing>> = Lazy::new(|| {
 pub fn get_model_pricing(model: &str) -> ModelPricing {
     PRICING_TABLE
         .get(model)
+        .or_else(|| PRICING_TABLE.get(super::normalize_model_id(model).as_str()))
         .cloned()
         .unwrap_or_else(ModelPricing::default_average)
 }
@@ -4,14 +4,36 @@
 //! Source: https://raw.githubusercontent.com/BerriAI/litellm/main/model_prices_and_context_window.json
 
 use crate::pricing::ModelPricing;
-use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+use thiserror::Error;
 
 /// LiteLLM pricing source URL
 pub const LITELLM_PRICING_URL: &str =
     "https://raw.githubusercontent.com/BerriAI/litellm/main/model_prices_and_context_window.json";
 
+/// Attempts made before giving up on a network failure
+const FETCH_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry, doubled after each failed attempt
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Why fetching LiteLLM pricing failed
+#[derive(Debug, Error)]
+pub enum PricingFetchError {
+    /// Request failed (connection, timeout, HTTP error) after every retry
+    #[error("network failure after {attempts} attempt(s): {source}")]
+    Network {
+        attempts: u32,
+        #[source]
+        source: reqwest::Error,
+    },
+    /// Response was received but is not the LiteLLM pricing JSON
+    #[error("malformed LiteLLM response: {0}")]
+    Malformed(#[from] serde_json::Error),
+}
+
 /// LiteLLM model entry (simplified, only what we need)
 #[derive(Debug, Deserialize)]
 struct LiteLLMModelEntry {
@@ -51,28 +73,74 @@ pub struct CachedPricing {
 
     /// Source URL
     pub source: String,
+
+    /// Pricing snapshots keyed by the date they were fetched
+    ///
+    /// A snapshot is only added when prices change, so each entry is in effect
+    /// from its date until the next one.
+    #[serde(default)]
+    pub history: BTreeMap<chrono::NaiveDate, HashMap<String, ModelPricing>>,
 }
 
 /// Fetch pricing from LiteLLM API
-pub async fn fetch_litellm_pricing() -> Result<HashMap<String, ModelPricing>> {
+///
+/// Transient network failures (connection errors, timeouts, 5xx and 429
+/// responses) are retried with exponential backoff, up to `FETCH_ATTEMPTS`
+/// attempts. A malformed body is not retried.
+pub async fn fetch_litellm_pricing() -> Result<HashMap<String, ModelPricing>, PricingFetchError> {
     tracing::info!("Fetching pricing from LiteLLM: {}", LITELLM_PRICING_URL);
 
-    let response = reqwest::get(LITELLM_PRICING_URL)
-        .await
-        .context("Failed to fetch LiteLLM pricing")?;
+    let mut attempt = 1;
+    let json_text = loop {
+        match fetch_text().await {
+            Ok(text) => break text,
+            Err(e) if attempt < FETCH_ATTEMPTS && is_retryable(&e) => {
+                let delay = backoff_delay(attempt);
+                tracing::warn!(
+                    attempt,
+                    delay_ms = delay.as_millis() as u64,
+                    "LiteLLM pricing fetch failed, retrying: {}",
+                    e
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(source) => {
+                return Err(PricingFetchError::Network {
+                    attempts: attempt,
+                    source,
+                })
+            }
+        }
+    };
+
+    parse_litellm_json(&json_text)
+}
 
-    let json_text = response
+/// One GET of the pricing file, treating HTTP error statuses as failures
+async fn fetch_text() -> reqwest::Result<String> {
+    reqwest::get(LITELLM_PRICING_URL)
+        .await?
+        .error_for_status()?
         .text()
         .await
-        .context("Failed to read LiteLLM response")?;
+}
 
-    parse_litellm_json(&json_text)
+/// Client errors (404, 403, ...) won't fix themselves on retry
+fn is_retryable(error: &reqwest::Error) -> bool {
+    error.status().is_none_or(|status| {
+        status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+    })
+}
+
+/// Delay before retrying after failed attempt number `attempt` (1-based)
+fn backoff_delay(attempt: u32) -> Duration {
+    INITIAL_BACKOFF * 2u32.pow(attempt.saturating_sub(1))
 }
 
 /// Parse LiteLLM JSON into ModelPricing map
-fn parse_litellm_json(json: &str) -> Result<HashMap<String, ModelPricing>> {
-    let entries: HashMap<String, LiteLLMModelEntry> =
-        serde_json::from_str(json).context("Failed to parse LiteLLM JSON")?;
+fn parse_litellm_json(json: &str) -> Result<HashMap<String, ModelPricing>, PricingFetchError> {
+    let entries: HashMap<String, LiteLLMModelEntry> = serde_json::from_str(json)?;
 
     let mut pricing_map = HashMap::new();
 
@@ -187,4 +255,15 @@ mod tests {
         assert_eq!(sonnet.input_price_per_million, 3.0);
         assert_eq!(sonnet.output_price_per_million, 15.0);
     }
+
+    #[test]
+    fn test_malformed_response_and_backoff() {
+        let err = parse_litellm_json("<html>rate limited</html>").unwrap_err();
+        assert!(matches!(err, PricingFetchError::Malformed(_)));
+        assert!(err.to_string().starts_with("malformed LiteLLM response"));
+
+        assert_eq!(backoff_delay(1), Duration::from_millis(500));
+        assert_eq!(backoff_delay(2), Duration::from_secs(1));
+        assert_eq!(backoff_delay(3), Duration::from_secs(2));
+    }
 }
@@ -6,45 +6,195 @@
 //!
 //! The system automatically merges cached pricing with embedded pricing, preferring
 //! cached values for known models and falling back to embedded pricing for unknown models.
+//!
+//! Each LiteLLM update also records a dated snapshot in the cache, so old sessions can
+//! be priced at the rates in effect at the time with `get_model_pricing_at`.
 
 pub mod cache;
 pub mod embedded;
 pub mod litellm;
 
 use anyhow::Result;
+use chrono::NaiveDate;
 pub use embedded::{ModelPricing, MODEL_PRICING};
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
-use std::sync::RwLock;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Mutex, RwLock};
 
 /// Dynamic pricing map (merged from cache + embedded)
 ///
 /// This is loaded at startup and used by `calculate_cost()`. It combines:
 /// - Cached pricing from LiteLLM (if available and not expired)
 /// - Embedded pricing as fallback
-static DYNAMIC_PRICING: Lazy<RwLock<HashMap<String, ModelPricing>>> = Lazy::new(|| {
-    let mut pricing = embedded::MODEL_PRICING.clone();
-
-    // Try to load from cache
-    if let Ok(Some(cached)) = cache::load_cached_pricing() {
-        tracing::info!(
-            "Merging {} cached prices with {} embedded prices",
-            cached.len(),
-            pricing.len()
-        );
-        pricing.extend(cached);
-    } else {
-        tracing::debug!("Using embedded pricing only (no cache available)");
+///
+/// Each entry remembers which of the two it came from (see `pricing_table`).
+static DYNAMIC_PRICING: Lazy<RwLock<HashMap<String, (ModelPricing, PricingSource)>>> =
+    Lazy::new(|| {
+        let cached = cache::load_cached_pricing().ok().flatten();
+        match &cached {
+            Some(cached) => tracing::info!(
+                "Merging {} cached prices with {} embedded prices",
+                cached.len(),
+                embedded::MODEL_PRICING.len()
+            ),
+            None => tracing::debug!("Using embedded pricing only (no cache available)"),
+        }
+        RwLock::new(merge_pricing(cached.unwrap_or_default()))
+    });
+
+/// Where the rates in use for a model come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PricingSource {
+    /// LiteLLM prices cached in `~/.cache/ccboard/pricing.json`
+    Cache,
+    /// Prices compiled into ccboard
+    Embedded,
+}
+
+impl PricingSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PricingSource::Cache => "cache",
+            PricingSource::Embedded => "embedded",
+        }
+    }
+}
+
+/// One row of the merged pricing table
+#[derive(Debug, Clone, Serialize)]
+pub struct PricingEntry {
+    pub model: String,
+    #[serde(flatten)]
+    pub pricing: ModelPricing,
+    pub source: PricingSource,
+}
+
+/// Embedded prices overlaid with `cached` ones
+fn merge_pricing(
+    cached: HashMap<String, ModelPricing>,
+) -> HashMap<String, (ModelPricing, PricingSource)> {
+    let mut merged: HashMap<_, _> = embedded::MODEL_PRICING
+        .iter()
+        .map(|(model, pricing)| (model.clone(), (pricing.clone(), PricingSource::Embedded)))
+        .collect();
+    merged.extend(
+        cached
+            .into_iter()
+            .map(|(model, pricing)| (model, (pricing, PricingSource::Cache))),
+    );
+    merged
+}
+
+/// Every model with known rates, sorted by model ID
+///
+/// This is the table `get_model_pricing` resolves against (aliases are
+/// normalized before lookup, so they don't appear here).
+pub fn pricing_table() -> Vec<PricingEntry> {
+    let mut entries: Vec<PricingEntry> = DYNAMIC_PRICING
+        .read()
+        .map(|guard| {
+            guard
+                .iter()
+                .map(|(model, (pricing, source))| PricingEntry {
+                    model: model.clone(),
+                    pricing: pricing.clone(),
+                    source: *source,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    entries.sort_by(|a, b| a.model.cmp(&b.model));
+    entries
+}
+
+/// Date-keyed pricing snapshots from the LiteLLM cache
+static PRICING_HISTORY: Lazy<RwLock<BTreeMap<NaiveDate, HashMap<String, ModelPricing>>>> =
+    Lazy::new(|| {
+        let history = cache::load_pricing_history().unwrap_or_else(|e| {
+            tracing::debug!("No pricing history available: {}", e);
+            BTreeMap::new()
+        });
+        RwLock::new(history)
+    });
+
+/// Models already warned about missing historical pricing
+static HISTORY_FALLBACK_WARNED: Lazy<Mutex<HashSet<String>>> =
+    Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Model families recognized by `normalize_model_id`
+pub(crate) const MODEL_FAMILIES: &[&str] = &["opus", "sonnet", "haiku"];
+
+/// Canonicalize a model ID so aliases of the same model share one key
+///
+/// Claude Code reports the same model in several forms (`opus-4`, `claude-opus-4-5`,
+/// `claude-opus-4.5`, `claude-opus-4-5-20251101`). All of them collapse to
+/// `claude-<family>-<major>-<minor>`:
+///
+/// - Date suffixes (`-20251101`) are dropped
+/// - Dot-style versions (`4.5`) become dash-style (`4-5`)
+/// - Short aliases (`opus`, `opus-4`, `claude-opus-4`) resolve to the current 4.5 generation
+/// - Dated IDs without a minor version (`claude-opus-4-20250514`) are the x.0 release
+///
+/// Non-Claude models (e.g. `gpt-5.4`, `gemini`) are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use ccboard_core::pricing::normalize_model_id;
+///
+/// assert_eq!(normalize_model_id("opus-4"), "claude-opus-4-5");
+/// assert_eq!(normalize_model_id("claude-opus-4-5-20251101"), "claude-opus-4-5");
+/// assert_eq!(normalize_model_id("gpt-5.4"), "gpt-5.4");
+/// ```
+pub fn normalize_model_id(model: &str) -> String {
+    let lowered = model.trim().to_ascii_lowercase();
+
+    // Strip trailing release date (-YYYYMMDD)
+    let (base, dated) = match lowered.rsplit_once('-') {
+        Some((head, tail)) if tail.len() == 8 && tail.chars().all(|c| c.is_ascii_digit()) => {
+            (head, true)
+        }
+        _ => (lowered.as_str(), false),
+    };
+
+    let rest = base
+        .strip_prefix("claude-")
+        .unwrap_or(base)
+        .replace('.', "-");
+    let mut parts = rest.split('-');
+    let family = parts.next().unwrap_or_default();
+    if !MODEL_FAMILIES.contains(&family) {
+        return model.to_string();
+    }
+
+    let versions: Vec<&str> = parts.collect();
+    if !versions
+        .iter()
+        .all(|v| !v.is_empty() && v.chars().all(|c| c.is_ascii_digit()))
+    {
+        return model.to_string();
     }
 
-    RwLock::new(pricing)
-});
+    match versions.as_slice() {
+        [major] if dated => format!("claude-{}-{}-0", family, major),
+        [] | ["4"] => format!("claude-{}-4-5", family),
+        [major] => format!("claude-{}-{}", family, major),
+        [major, minor, ..] => format!("claude-{}-{}-{}", family, major, minor),
+    }
+}
 
 /// Get pricing for a model (checks dynamic pricing first, then embedded)
+///
+/// Exact IDs are tried first, then the canonical form from `normalize_model_id`.
 pub fn get_model_pricing(model_id: &str) -> ModelPricing {
     // Try dynamic pricing first
     if let Ok(guard) = DYNAMIC_PRICING.read() {
-        if let Some(pricing) = guard.get(model_id) {
+        if let Some((pricing, _)) = guard
+            .get(model_id)
+            .or_else(|| guard.get(&normalize_model_id(model_id)))
+        {
             return pricing.clone();
         }
     }
@@ -53,12 +203,101 @@ pub fn get_model_pricing(model_id: &str) -> ModelPricing {
     embedded::get_model_pricing(model_id)
 }
 
+/// Get pricing for a model as it was on `date`
+///
+/// Uses the latest cached LiteLLM snapshot taken on or before `date`. Falls back
+/// to embedded pricing when no snapshot covers that date or the snapshot doesn't
+/// know the model, warning once per model.
+pub fn get_model_pricing_at(model_id: &str, date: NaiveDate) -> ModelPricing {
+    if let Ok(guard) = PRICING_HISTORY.read() {
+        if let Some(pricing) = pricing_from_history(&guard, model_id, date) {
+            return pricing;
+        }
+    }
+
+    let first_fallback = HISTORY_FALLBACK_WARNED
+        .lock()
+        .map(|mut warned| warned.insert(model_id.to_string()))
+        .unwrap_or(false);
+    if first_fallback {
+        tracing::warn!(
+            model = model_id,
+            %date,
+            "No historical pricing entry, using embedded pricing"
+        );
+    }
+    embedded::get_model_pricing(model_id)
+}
+
+/// Look up a model in the snapshot in effect on `date`
+fn pricing_from_history(
+    history: &BTreeMap<NaiveDate, HashMap<String, ModelPricing>>,
+    model_id: &str,
+    date: NaiveDate,
+) -> Option<ModelPricing> {
+    let (_, snapshot) = history.range(..=date).next_back()?;
+    snapshot
+        .get(model_id)
+        .or_else(|| snapshot.get(&normalize_model_id(model_id)))
+        .cloned()
+}
+
+/// Outcome of `update_pricing_from_litellm`
+#[derive(Debug, Clone, Default)]
+pub struct PricingUpdate {
+    /// Models whose rates are new or differ from the pricing in use before
+    pub updated: Vec<String>,
+    /// Models fetched with the same rates as before
+    pub unchanged: Vec<String>,
+    /// Age of the cache when the fetch was skipped because it was still fresh
+    pub skipped_fresh: Option<chrono::Duration>,
+}
+
 /// Update pricing from LiteLLM and save to cache
-pub async fn update_pricing_from_litellm() -> Result<usize> {
+///
+/// Skips the download while the cache is within its 7-day TTL unless `force`
+/// is set. Network and parse failures surface as `CoreError::PricingFetch`
+/// inside the returned error.
+pub async fn update_pricing_from_litellm(force: bool) -> Result<PricingUpdate> {
+    if !force {
+        if let Ok(Some(age)) = cache::cached_pricing_age() {
+            if !cache::is_expired(age) {
+                tracing::info!(
+                    "Pricing cache is fresh ({} days old), skipping LiteLLM fetch",
+                    age.num_days()
+                );
+                return Ok(PricingUpdate {
+                    skipped_fresh: Some(age),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
     tracing::info!("Updating pricing from LiteLLM");
 
-    let fetched = litellm::fetch_litellm_pricing().await?;
-    let count = fetched.len();
+    let fetched = litellm::fetch_litellm_pricing()
+        .await
+        .map_err(crate::error::CoreError::from)?;
+
+    let mut update = PricingUpdate::default();
+    if let Ok(guard) = DYNAMIC_PRICING.read() {
+        for (model, pricing) in &fetched {
+            if guard.get(model).map(|(p, _)| p) == Some(pricing) {
+                update.unchanged.push(model.clone());
+            } else {
+                update.updated.push(model.clone());
+            }
+        }
+    }
+    update.updated.sort();
+    update.unchanged.sort();
+    tracing::info!(
+        updated = ?update.updated,
+        unchanged = update.unchanged.len(),
+        "Fetched {} model prices from LiteLLM",
+        fetched.len()
+    );
 
     // Save to cache
     cache::save_pricing_cache(fetched.clone())?;
@@ -66,12 +305,17 @@ pub async fn update_pricing_from_litellm() -> Result<usize> {
     // Update in-memory pricing
     if let Ok(mut guard) = DYNAMIC_PRICING.write() {
         // Merge with embedded (keep embedded as fallback)
-        let mut merged = embedded::MODEL_PRICING.clone();
-        merged.extend(fetched);
-        *guard = merged;
+        *guard = merge_pricing(fetched);
+    }
+
+    // Pick up the snapshot recorded by save_pricing_cache
+    if let Ok(history) = cache::load_pricing_history() {
+        if let Ok(mut guard) = PRICING_HISTORY.write() {
+            *guard = history;
+        }
     }
 
-    Ok(count)
+    Ok(update)
 }
 
 /// Clear pricing cache
@@ -111,8 +355,46 @@ pub fn calculate_cost(
     cache_create: u64,
     cache_read: u64,
 ) -> f64 {
-    let pricing = get_model_pricing(model);
+    calculate_cost_with_pricing(
+        input,
+        output,
+        cache_create,
+        cache_read,
+        &get_model_pricing(model),
+    )
+}
 
+/// Calculate cost for token usage at the rates in effect on `date`
+///
+/// See `get_model_pricing_at` for how historical rates are resolved.
+pub fn calculate_cost_at(
+    model: &str,
+    date: NaiveDate,
+    input: u64,
+    output: u64,
+    cache_create: u64,
+    cache_read: u64,
+) -> f64 {
+    calculate_cost_with_pricing(
+        input,
+        output,
+        cache_create,
+        cache_read,
+        &get_model_pricing_at(model, date),
+    )
+}
+
+/// Calculate cost for token usage with explicit pricing
+///
+/// Same arithmetic as `calculate_cost`, for callers that resolved pricing
+/// themselves (e.g. historical rates from `get_model_pricing_at`).
+pub fn calculate_cost_with_pricing(
+    input: u64,
+    output: u64,
+    cache_create: u64,
+    cache_read: u64,
+    pricing: &ModelPricing,
+) -> f64 {
     let input_cost = (input as f64 / 1_000_000.0) * pricing.input_price_per_million;
     let output_cost = (output as f64 / 1_000_000.0) * pricing.output_price_per_million;
     let cache_create_cost = (cache_create as f64 / 1_000_000.0)
@@ -136,6 +418,26 @@ mod tests {
         assert_eq!(pricing.output_price_per_million, 25.0);
     }
 
+    #[test]
+    fn test_merge_pricing_tracks_source() {
+        let cached_price = ModelPricing {
+            input_price_per_million: 4.0,
+            ..embedded::get_model_pricing("claude-opus-4-5")
+        };
+        let merged = merge_pricing(HashMap::from([
+            ("claude-opus-4-5".to_string(), cached_price.clone()),
+            ("claude-new-model".to_string(), cached_price.clone()),
+        ]));
+
+        assert_eq!(
+            merged["claude-opus-4-5"],
+            (cached_price, PricingSource::Cache)
+        );
+        assert_eq!(merged["claude-new-model"].1, PricingSource::Cache);
+        assert_eq!(merged["claude-haiku-4-5"].1, PricingSource::Embedded);
+        assert_eq!(merged.len(), embedded::MODEL_PRICING.len() + 1);
+    }
+
     #[test]
     fn test_get_model_pricing_unknown() {
         let pricing = get_model_pricing("unknown-model");
@@ -143,6 +445,90 @@ mod tests {
         assert!(pricing.input_price_per_million > 0.0);
     }
 
+    #[test]
+    fn test_normalize_model_id_aliases() {
+        let cases = [
+            // Opus 4.5 variants
+            ("opus", "claude-opus-4-5"),
+            ("opus-4", "claude-opus-4-5"),
+            ("claude-opus-4", "claude-opus-4-5"),
+            ("claude-opus-4-5", "claude-opus-4-5"),
+            ("claude-opus-4.5", "claude-opus-4-5"),
+            ("claude-opus-4-5-20251101", "claude-opus-4-5"),
+            ("claude-opus-4-5-20251001", "claude-opus-4-5"),
+            // Opus 4.6 stays distinct from 4.5
+            ("claude-opus-4-6-20250212", "claude-opus-4-6"),
+            ("claude-opus-4.6", "claude-opus-4-6"),
+            // Legacy Opus releases
+            ("claude-opus-4-20250514", "claude-opus-4-0"),
+            ("claude-opus-4-0-20250514", "claude-opus-4-0"),
+            ("claude-opus-4-1-20250805", "claude-opus-4-1"),
+            // Sonnet
+            ("sonnet-4", "claude-sonnet-4-5"),
+            ("claude-sonnet-4-5-20250929", "claude-sonnet-4-5"),
+            ("claude-sonnet-4.6", "claude-sonnet-4-6"),
+            ("claude-sonnet-4-20250514", "claude-sonnet-4-0"),
+            // Haiku
+            ("haiku", "claude-haiku-4-5"),
+            ("claude-haiku-4-5-20251001", "claude-haiku-4-5"),
+            ("claude-haiku-4.5", "claude-haiku-4-5"),
+            // Case and whitespace
+            (" Claude-Sonnet-4-5 ", "claude-sonnet-4-5"),
+            // Non-Claude models pass through untouched
+            ("gpt-5.4", "gpt-5.4"),
+            ("gemini", "gemini"),
+            ("<synthetic>", "<synthetic>"),
+            ("claude-3-5-sonnet-20241022", "claude-3-5-sonnet-20241022"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(normalize_model_id(input), expected, "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_get_model_pricing_via_alias() {
+        // Dated legacy ID not in the table resolves through normalization
+        let pricing = get_model_pricing("claude-opus-4-20250514");
+        assert_eq!(pricing.input_price_per_million, 15.0);
+
+        let pricing = get_model_pricing("claude-haiku-4-5-20991231");
+        assert_eq!(pricing.input_price_per_million, 1.0);
+    }
+
+    #[test]
+    fn test_pricing_from_history_uses_snapshot_in_effect() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+        let snapshot = |input| {
+            HashMap::from([(
+                "claude-opus-4-5".to_string(),
+                ModelPricing {
+                    input_price_per_million: input,
+                    ..ModelPricing::default_average()
+                },
+            )])
+        };
+        let history = BTreeMap::from([(day(10), snapshot(15.0)), (day(20), snapshot(5.0))]);
+
+        let at = |model, d| {
+            pricing_from_history(&history, model, day(d)).map(|p| p.input_price_per_million)
+        };
+        assert_eq!(at("claude-opus-4-5", 9), None);
+        assert_eq!(at("claude-opus-4-5", 10), Some(15.0));
+        assert_eq!(at("opus-4", 19), Some(15.0));
+        assert_eq!(at("claude-opus-4-5-20251101", 25), Some(5.0));
+        assert_eq!(at("gpt-5.4", 25), None);
+    }
+
+    #[test]
+    fn test_calculate_cost_with_pricing_matches_calculate_cost() {
+        let pricing = get_model_pricing("opus-4");
+        assert_eq!(
+            calculate_cost_with_pricing(1_000_000, 0, 1_000_000, 10_000_000, &pricing),
+            calculate_cost("opus-4", 1_000_000, 0, 1_000_000, 10_000_000)
+        );
+    }
+
     #[test]
     fn test_calculate_cost_opus_basic() {
         // Opus-4.5: 1M input + 1M output = $5 + $25 = $30
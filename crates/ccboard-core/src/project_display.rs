@@ -0,0 +1,187 @@
+//! Project path display formatting
+//!
+//! Controls how project paths render across tabs and CLI output. The mode comes from
+//! `projectDisplay` in `settings.json`; friendly names come from an optional alias map
+//! at `~/.claude/ccboard-aliases.json`:
+//!
+//! ```json
+//! {
+//!   "/Users/me/code/my-app": "My App",
+//!   "-Users-me-code-legacy-api": "Legacy API",
+//!   "~/work/infra": "Infra"
+//! }
+//! ```
+//!
+//! Keys may be decoded paths, `~/` paths, or the encoded directory names used
+//! under `~/.claude/projects/`. Matching happens on the encoded form: decoding a
+//! directory name can't tell a `-` in a project name from a path separator, so
+//! `/Users/me/code/my-app` and `-Users-me-code-my-app` both name the same project.
+
+use crate::models::config::ProjectDisplayMode;
+use std::collections::HashMap;
+use std::path::Path;
+use tracing::warn;
+
+/// File name of the alias map inside the Claude home directory
+pub const ALIASES_FILE: &str = "ccboard-aliases.json";
+
+/// Project path formatter (display mode + alias map)
+#[derive(Debug, Clone, Default)]
+pub struct ProjectDisplay {
+    /// Display mode
+    pub mode: ProjectDisplayMode,
+    /// Encoded project directory name → friendly name
+    aliases: HashMap<String, String>,
+}
+
+impl ProjectDisplay {
+    /// Create a formatter from a mode and a raw alias map (keys are encoded)
+    pub fn new(mode: ProjectDisplayMode, aliases: HashMap<String, String>) -> Self {
+        let aliases = aliases
+            .into_iter()
+            .map(|(key, name)| (normalize_alias_key(&key), name))
+            .collect();
+        Self { mode, aliases }
+    }
+
+    /// Load the alias map from `<claude_home>/ccboard-aliases.json`
+    ///
+    /// Returns an empty map if the file is absent or invalid (graceful degradation).
+    pub fn load_aliases(claude_home: &Path) -> HashMap<String, String> {
+        let path = claude_home.join(ALIASES_FILE);
+        match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                warn!(path = %path.display(), error = %e, "Failed to parse project aliases, ignoring");
+                HashMap::new()
+            }),
+            Err(_) => HashMap::new(), // File absent is fine
+        }
+    }
+
+    /// Friendly name for a project path, if one is configured
+    pub fn alias(&self, path: &str) -> Option<&str> {
+        self.aliases.get(&encode_key(path)).map(String::as_str)
+    }
+
+    /// Format a project path according to the display mode
+    pub fn format(&self, path: &str) -> String {
+        match self.mode {
+            ProjectDisplayMode::Full => path.to_string(),
+            ProjectDisplayMode::Short => shorten(path),
+            ProjectDisplayMode::Basename => basename(path).to_string(),
+            ProjectDisplayMode::Alias => self
+                .alias(path)
+                .map(str::to_string)
+                .unwrap_or_else(|| shorten(path)),
+        }
+    }
+}
+
+/// Shorten a path to its last two components (`.../parent/project`)
+fn shorten(path: &str) -> String {
+    let parts: Vec<&str> = path.split('/').collect();
+    if parts.len() <= 3 {
+        path.to_string()
+    } else {
+        format!(".../{}", parts[parts.len() - 2..].join("/"))
+    }
+}
+
+/// Last non-empty path component
+fn basename(path: &str) -> &str {
+    path.split('/')
+        .rfind(|part| !part.is_empty())
+        .unwrap_or(path)
+}
+
+/// Normalize alias keys (expanding `~/`) to the encoded directory name
+fn normalize_alias_key(key: &str) -> String {
+    let key = key.trim();
+    match key.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .map(|home| encode_key(&home.join(rest).to_string_lossy()))
+            .unwrap_or_else(|| encode_key(key)),
+        None => encode_key(key),
+    }
+}
+
+/// Encode a path the way Claude Code names `~/.claude/projects/` directories
+///
+/// Every non-alphanumeric character becomes `-`, so a real path, its encoded
+/// directory name and the lossily decoded `project_path` all map to one key.
+fn encode_key(path: &str) -> String {
+    path.trim_end_matches('/')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &str = "/Users/me/code/my-app";
+
+    #[test]
+    fn test_format_modes() {
+        let display = |mode| ProjectDisplay::new(mode, HashMap::new());
+
+        assert_eq!(display(ProjectDisplayMode::Full).format(PATH), PATH);
+        assert_eq!(
+            display(ProjectDisplayMode::Short).format(PATH),
+            ".../code/my-app"
+        );
+        assert_eq!(
+            display(ProjectDisplayMode::Short).format("/tmp/x"),
+            "/tmp/x"
+        );
+        assert_eq!(display(ProjectDisplayMode::Basename).format(PATH), "my-app");
+        // Alias mode without a matching alias falls back to short
+        assert_eq!(
+            display(ProjectDisplayMode::Alias).format(PATH),
+            ".../code/my-app"
+        );
+    }
+
+    #[test]
+    fn test_alias_keys_normalized() {
+        let aliases = HashMap::from([
+            ("/Users/me/code/my-app/".to_string(), "My App".to_string()),
+            ("-Users-me-code-api".to_string(), "API".to_string()),
+        ]);
+        let display = ProjectDisplay::new(ProjectDisplayMode::Alias, aliases);
+
+        assert_eq!(display.format(PATH), "My App");
+        assert_eq!(display.format("/Users/me/code/api"), "API");
+        assert_eq!(display.alias("/elsewhere"), None);
+    }
+
+    #[test]
+    fn test_alias_dashed_project_name() {
+        let aliases = HashMap::from([
+            (
+                "-Users-me-code-legacy-api".to_string(),
+                "Legacy API".to_string(),
+            ),
+            ("/Users/me/code/my-app".to_string(), "My App".to_string()),
+        ]);
+        let display = ProjectDisplay::new(ProjectDisplayMode::Alias, aliases);
+
+        // Session project paths are decoded from the directory name, dashes and all
+        assert_eq!(
+            display.alias("/Users/me/code/legacy/api"),
+            Some("Legacy API")
+        );
+        assert_eq!(
+            display.alias("/Users/me/code/legacy-api"),
+            Some("Legacy API")
+        );
+        assert_eq!(display.alias("/Users/me/code/my/app"), Some("My App"));
+        assert_eq!(display.alias("/Users/me/code/legacy"), None);
+    }
+
+    #[test]
+    fn test_default_is_short() {
+        assert_eq!(ProjectDisplay::default().format(PATH), ".../code/my-app");
+    }
+}
@@ -0,0 +1,187 @@
+//! Project exclusion from aggregates
+//!
+//! Projects matching `excludeProjects` in `settings.json` are left out of stats,
+//! analytics, costs and the leaderboard, but their sessions stay browsable:
+//!
+//! ```json
+//! {
+//!   "excludeProjects": ["~/scratch/*", "/tmp/*", "playground"]
+//! }
+//! ```
+//!
+//! Patterns support `*` (any run of characters, including `/`) and `?`. A
+//! pattern containing `/` is matched against the full project path (`~/` is
+//! expanded); otherwise it is matched against the project directory name.
+//!
+//! [`ProjectGlobFilter`] (`--project-glob` / `--exclude-glob`) goes further and
+//! keeps non-matching sessions out of the index entirely.
+
+/// Compiled `excludeProjects` patterns
+#[derive(Debug, Clone, Default)]
+pub struct ProjectExclusions {
+    patterns: Vec<String>,
+}
+
+impl ProjectExclusions {
+    /// Build from raw settings patterns (`~/` is expanded, trailing `/` dropped)
+    pub fn new<S: AsRef<str>>(patterns: &[S]) -> Self {
+        let patterns = patterns
+            .iter()
+            .map(|p| normalize_pattern(p.as_ref()))
+            .filter(|p| !p.is_empty())
+            .collect();
+        Self { patterns }
+    }
+
+    /// Whether no pattern is configured
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Whether `project_path` matches any pattern
+    pub fn is_excluded(&self, project_path: &str) -> bool {
+        if self.patterns.is_empty() {
+            return false;
+        }
+        let path = project_path.trim_end_matches('/');
+        let name = path.rsplit('/').next().unwrap_or(path);
+        self.patterns.iter().any(|pattern| {
+            if pattern.contains('/') {
+                wildcard_match(pattern, path)
+            } else {
+                wildcard_match(pattern, name)
+            }
+        })
+    }
+}
+
+/// Include/exclude globs restricting which projects get indexed at all
+///
+/// Set from `--project-glob` / `--exclude-glob`. Unlike [`ProjectExclusions`],
+/// non-matching sessions are never loaded. A project is kept when it matches
+/// any include pattern (or none are given) and no exclude pattern. Patterns use
+/// the same wildcards; a relative pattern with `/` (`services/*`) matches
+/// anywhere in the path.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectGlobFilter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl ProjectGlobFilter {
+    pub fn new<S: AsRef<str>>(include: &[S], exclude: &[S]) -> Self {
+        let compile = |patterns: &[S]| -> Vec<String> {
+            patterns
+                .iter()
+                .map(|p| normalize_pattern(p.as_ref()))
+                .filter(|p| !p.is_empty())
+                .map(|p| {
+                    if p.contains('/') && !p.starts_with('/') && !p.starts_with('*') {
+                        format!("*/{}", p)
+                    } else {
+                        p
+                    }
+                })
+                .collect()
+        };
+        Self {
+            include: compile(include),
+            exclude: compile(exclude),
+        }
+    }
+
+    /// Whether no pattern is configured (everything is indexed)
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Whether sessions of `project_path` should be indexed
+    pub fn matches(&self, project_path: &str) -> bool {
+        let path = project_path.trim_end_matches('/');
+        let name = path.rsplit('/').next().unwrap_or(path);
+        let hit = |pattern: &String| {
+            if pattern.contains('/') {
+                wildcard_match(pattern, path)
+            } else {
+                wildcard_match(pattern, name)
+            }
+        };
+        (self.include.is_empty() || self.include.iter().any(hit)) && !self.exclude.iter().any(hit)
+    }
+}
+
+pub(crate) fn normalize_pattern(pattern: &str) -> String {
+    let pattern = pattern.trim();
+    let expanded = match pattern.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .map(|home| home.join(rest).to_string_lossy().to_string())
+            .unwrap_or_else(|| pattern.to_string()),
+        None => pattern.to_string(),
+    };
+    expanded.trim_end_matches('/').to_string()
+}
+
+/// Match `text` against a pattern with `*` and `?` wildcards
+pub(crate) fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    // Position of the last `*` and the text index it is currently matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            backtrack = Some((pi, ti));
+            pi += 1;
+        } else if let Some((star, matched)) = backtrack {
+            pi = star + 1;
+            ti = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exclusion_patterns() {
+        let exclusions = ProjectExclusions::new(&["/tmp/*", "scratch-?", "playground/"]);
+
+        assert!(exclusions.is_excluded("/tmp/foo"));
+        assert!(exclusions.is_excluded("/tmp/foo/bar"));
+        assert!(exclusions.is_excluded("/Users/me/scratch-1"));
+        assert!(exclusions.is_excluded("/Users/me/code/playground"));
+
+        assert!(!exclusions.is_excluded("/Users/me/scratch-12"));
+        assert!(!exclusions.is_excluded("/Users/me/tmp/foo"));
+        assert!(!exclusions.is_excluded("/Users/me/code/api"));
+
+        assert!(ProjectExclusions::default().is_empty());
+        assert!(!ProjectExclusions::default().is_excluded("/tmp/foo"));
+    }
+
+    #[test]
+    fn test_glob_filter() {
+        let filter = ProjectGlobFilter::new(&["services/*", "web"], &["*legacy*"]);
+
+        assert!(filter.matches("/repo/services/api"));
+        assert!(filter.matches("/repo/apps/web"));
+        assert!(!filter.matches("/repo/services/legacy-billing"));
+        assert!(!filter.matches("/repo/apps/mobile"));
+
+        // Exclude-only keeps everything else
+        let filter = ProjectGlobFilter::new(&[], &["/tmp/*"]);
+        assert!(filter.matches("/repo/apps/mobile"));
+        assert!(!filter.matches("/tmp/scratch"));
+
+        assert!(ProjectGlobFilter::default().matches("/anything"));
+    }
+}
@@ -216,6 +216,7 @@ mod tests {
             monthly_limit: Some(50.0),
             warning_threshold: 75.0,
             critical_threshold: 90.0,
+            ..Default::default()
         };
         let status = calculate_quota_status(&stats, &budget);
 
@@ -231,6 +232,7 @@ mod tests {
             monthly_limit: Some(100.0),
             warning_threshold: 75.0,
             critical_threshold: 90.0,
+            ..Default::default()
         };
         let status = calculate_quota_status(&stats, &budget);
 
@@ -249,6 +251,7 @@ mod tests {
             monthly_limit: None,
             warning_threshold: 75.0,
             critical_threshold: 90.0,
+            ..Default::default()
         };
         let status = calculate_quota_status(&stats, &budget);
 
@@ -263,6 +266,7 @@ mod tests {
             monthly_limit: Some(100.0),
             warning_threshold: 75.0,
             critical_threshold: 90.0,
+            ..Default::default()
         };
 
         assert_eq!(determine_alert_level(50.0, &budget), AlertLevel::Safe);
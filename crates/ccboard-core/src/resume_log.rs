@@ -0,0 +1,98 @@
+//! Session resume log — persisted to ~/.ccboard/resume-log.json
+//!
+//! Records when a session was last resumed from ccboard (TUI or `ccboard resume`)
+//! so the Sessions tab can surface the threads the user actually went back to,
+//! independently of their last activity timestamp.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+
+/// Persisted resume log
+///
+/// Backed by `~/.ccboard/resume-log.json` (session_id → last resume time).
+/// `record` persists immediately (atomic write); reads are in-memory.
+#[derive(Debug, Default)]
+pub struct ResumeLog {
+    path: PathBuf,
+    entries: HashMap<String, DateTime<Utc>>,
+}
+
+impl ResumeLog {
+    /// Load from `path`.  If the file does not exist, an empty log is returned.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let entries = if path.exists() {
+            let raw = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            serde_json::from_str::<HashMap<String, DateTime<Utc>>>(&raw)
+                .with_context(|| format!("Failed to parse {}", path.display()))?
+        } else {
+            HashMap::new()
+        };
+        Ok(Self { path, entries })
+    }
+
+    /// Return the filesystem path backing this log
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record that `session_id` was resumed now.  Persists immediately.
+    pub fn record(&mut self, session_id: &str) -> Result<()> {
+        self.record_at(session_id, Utc::now())
+    }
+
+    /// Record a resume at an explicit time.  Persists immediately.
+    pub fn record_at(&mut self, session_id: &str, at: DateTime<Utc>) -> Result<()> {
+        self.entries.insert(session_id.to_string(), at);
+        self.save()
+    }
+
+    /// When the session was last resumed, if ever
+    pub fn last_resumed(&self, session_id: &str) -> Option<DateTime<Utc>> {
+        self.entries.get(session_id).copied()
+    }
+
+    /// Number of sessions with a recorded resume
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the log is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn save(&self) -> Result<()> {
+        crate::persist::write_json_atomic(&self.path, &self.entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("resume-log.json");
+
+        let mut log = ResumeLog::load(&path).unwrap();
+        assert!(log.is_empty());
+
+        let first = Utc::now() - chrono::Duration::hours(2);
+        log.record_at("sess-1", first).unwrap();
+        log.record("sess-2").unwrap();
+        // Resuming again moves the timestamp forward
+        log.record("sess-1").unwrap();
+
+        let reloaded = ResumeLog::load(&path).unwrap();
+        assert_eq!(reloaded.len(), 2);
+        assert!(reloaded.last_resumed("sess-1").unwrap() > first);
+        assert!(reloaded.last_resumed("sess-2").is_some());
+        assert!(reloaded.last_resumed("unknown").is_none());
+    }
+}
@@ -0,0 +1,272 @@
+//! In-memory inverted index over session metadata
+//!
+//! Backs [`DataStore::search`](crate::store::DataStore::search). Session ID,
+//! project path, first user message, branch and models are split into
+//! lowercase alphanumeric tokens. The index narrows a query down to sessions
+//! where every query token appears inside one of their tokens ("board" finds
+//! "ccboard" and "boarding"); [`matches_text`] then keeps the sessions where the
+//! query appears as typed, so multi-word queries still match as a phrase.
+
+use crate::models::{SessionId, SessionMetadata};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Token → sessions index, updated one session at a time
+#[derive(Debug, Default)]
+pub struct SessionSearchIndex {
+    /// Token → document numbers (BTreeMap so prefix lookups are range scans)
+    postings: BTreeMap<String, HashSet<u32>>,
+    /// Session → (document number, tokens), to unindex on update
+    documents: HashMap<SessionId, (u32, Vec<String>)>,
+    /// Document number → session
+    ids: Vec<SessionId>,
+    /// Indexed tokens, newline-separated, for substring lookups. Tokens that
+    /// lose their last session stay until [`compact_vocabulary`] drops them.
+    ///
+    /// [`compact_vocabulary`]: Self::compact_vocabulary
+    vocabulary: String,
+    /// Lines in `vocabulary`, live or not
+    vocabulary_tokens: usize,
+}
+
+impl SessionSearchIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an index over `sessions`
+    pub fn build<'a>(sessions: impl IntoIterator<Item = &'a SessionMetadata>) -> Self {
+        let mut index = Self::new();
+        for session in sessions {
+            index.insert(session);
+        }
+        index
+    }
+
+    /// Number of indexed sessions
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Index `session`, replacing its previous tokens if already indexed
+    pub fn insert(&mut self, session: &SessionMetadata) {
+        let doc = match self.documents.remove(&session.id) {
+            Some((doc, old_tokens)) => {
+                self.unlink(doc, &old_tokens);
+                self.compact_vocabulary();
+                doc
+            }
+            None => {
+                self.ids.push(session.id.clone());
+                (self.ids.len() - 1) as u32
+            }
+        };
+
+        let mut tokens: Vec<String> = tokenize(&session.id)
+            .chain(tokenize(&session.project_path))
+            .chain(session.first_user_message.iter().flat_map(|m| tokenize(m)))
+            .chain(session.branch.iter().flat_map(|b| tokenize(b)))
+            .chain(session.models_used.iter().flat_map(|m| tokenize(m)))
+            .collect();
+        tokens.sort_unstable();
+        tokens.dedup();
+
+        for token in &tokens {
+            let postings = self.postings.entry(token.clone()).or_insert_with(|| {
+                self.vocabulary.push_str(token);
+                self.vocabulary.push('\n');
+                self.vocabulary_tokens += 1;
+                HashSet::new()
+            });
+            postings.insert(doc);
+        }
+        self.documents.insert(session.id.clone(), (doc, tokens));
+    }
+
+    /// IDs of sessions with every token of `query` inside one of their tokens
+    ///
+    /// A superset of the sessions matching `query` as a phrase; filter with
+    /// [`matches_text`]. `None` for a query without tokens (everything matches).
+    pub fn query(&self, query: &str) -> Option<Vec<SessionId>> {
+        let mut terms: Vec<String> = tokenize(query).collect();
+        if terms.is_empty() {
+            return None;
+        }
+        terms.sort_unstable();
+        terms.dedup();
+
+        let mut matches = terms.iter().map(|t| self.docs_for(t));
+        let mut docs = matches.next()?;
+        for other in matches {
+            for (word, other) in docs.iter_mut().zip(other) {
+                *word &= other;
+            }
+        }
+
+        Some(
+            docs.iter()
+                .enumerate()
+                .flat_map(|(i, &word)| {
+                    (0..64)
+                        .filter(move |bit| word & (1 << bit) != 0)
+                        .map(move |bit| i * 64 + bit)
+                })
+                .map(|doc| self.ids[doc].clone())
+                .collect(),
+        )
+    }
+
+    /// Bitset of documents with a token containing `term`
+    ///
+    /// A substring scan of the vocabulary, which covers prefix matches too.
+    fn docs_for(&self, term: &str) -> Vec<u64> {
+        let mut docs = vec![0u64; self.ids.len().div_ceil(64)];
+        let mut last_start = None;
+        for (pos, _) in self.vocabulary.match_indices(term) {
+            let start = self.vocabulary[..pos].rfind('\n').map_or(0, |i| i + 1);
+            if last_start == Some(start) {
+                continue;
+            }
+            last_start = Some(start);
+            let end = pos + self.vocabulary[pos..].find('\n').unwrap_or(0);
+            for &doc in self
+                .postings
+                .get(&self.vocabulary[start..end])
+                .into_iter()
+                .flatten()
+            {
+                docs[doc as usize / 64] |= 1 << (doc % 64);
+            }
+        }
+        docs
+    }
+
+    /// Rewrite `vocabulary` from live tokens once over half of it is stale
+    fn compact_vocabulary(&mut self) {
+        if self.vocabulary_tokens <= 2 * self.postings.len() + 64 {
+            return;
+        }
+        self.vocabulary.clear();
+        for token in self.postings.keys() {
+            self.vocabulary.push_str(token);
+            self.vocabulary.push('\n');
+        }
+        self.vocabulary_tokens = self.postings.len();
+    }
+
+    fn unlink(&mut self, doc: u32, tokens: &[String]) {
+        for token in tokens {
+            if let Some(postings) = self.postings.get_mut(token) {
+                postings.remove(&doc);
+                if postings.is_empty() {
+                    self.postings.remove(token);
+                }
+            }
+        }
+    }
+}
+
+/// Whether `query` appears, case-insensitively, in the session's ID, project,
+/// first message, branch or models
+///
+/// Run on index hits so a multi-word query matches as a phrase ("fix the
+/// parser"), not as words scattered across fields.
+pub fn matches_text(session: &SessionMetadata, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    let contains = |text: &str| text.to_lowercase().contains(&query);
+    contains(&session.id)
+        || contains(&session.project_path)
+        || session.first_user_message.as_deref().is_some_and(contains)
+        || session.branch.as_deref().is_some_and(contains)
+        || session.models_used.iter().any(|m| contains(m))
+}
+
+/// Lowercase alphanumeric runs of `text`
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn session(id: &str, project: &str, message: &str, branch: Option<&str>) -> SessionMetadata {
+        let mut s = SessionMetadata::from_path(
+            PathBuf::from(format!("/nonexistent/{}.jsonl", id)),
+            project.into(),
+        );
+        s.first_user_message = Some(message.to_string());
+        s.branch = branch.map(str::to_string);
+        s.models_used = vec!["claude-sonnet-4-5".to_string()];
+        s
+    }
+
+    fn ids(result: Option<Vec<SessionId>>) -> Vec<String> {
+        let mut ids: Vec<String> = result
+            .unwrap()
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_query_tokens_prefix_and_substring() {
+        let mut index = SessionSearchIndex::build(&[
+            session(
+                "a1",
+                "/Users/me/ccboard",
+                "Fix the Parser bug",
+                Some("fix/parser"),
+            ),
+            session("b2", "/Users/me/webapp", "Add login page", Some("main")),
+        ]);
+        assert_eq!(index.len(), 2);
+
+        assert_eq!(ids(index.query("parser")), ["a1"]);
+        // Prefix of a token, case-insensitive
+        assert_eq!(ids(index.query("LOG")), ["b2"]);
+        // Every token must match
+        assert_eq!(ids(index.query("users parser")), ["a1"]);
+        assert!(index.query("parser login").unwrap().is_empty());
+        // Substring matches, even when another token has the term as prefix
+        assert_eq!(ids(index.query("board")), ["a1"]);
+        assert_eq!(ids(index.query("sonnet")), ["a1", "b2"]);
+        index.insert(&session("c3", "/Users/me/boarding", "Onboarding", None));
+        assert_eq!(ids(index.query("board")), ["a1", "c3"]);
+        assert!(index.query("  ").is_none());
+
+        // Re-indexing a session drops its old tokens
+        index.insert(&session("b2", "/Users/me/webapp", "Refactor auth", None));
+        assert!(index.query("login").unwrap().is_empty());
+        assert_eq!(ids(index.query("auth")), ["b2"]);
+        assert_eq!(index.len(), 3);
+    }
+
+    #[test]
+    fn test_vocabulary_compacts_on_reindex() {
+        let mut index = SessionSearchIndex::new();
+        for i in 0..200 {
+            index.insert(&session("a1", "/p", &format!("word{}", i), None));
+        }
+        assert!(index.vocabulary_tokens <= 2 * index.postings.len() + 64);
+        assert!(!index.vocabulary.contains("word0\n"));
+        assert_eq!(ids(index.query("word199")), ["a1"]);
+    }
+
+    #[test]
+    fn test_matches_text_phrase() {
+        let s = session("a1", "/Users/me/ccboard", "Fix the Parser bug", None);
+        assert!(matches_text(&s, "the parser"));
+        assert!(matches_text(&s, "CCBOARD"));
+        // Both words are there, but not as a phrase
+        assert!(!matches_text(&s, "parser fix"));
+    }
+}
@@ -5,19 +5,26 @@
 
 use crate::analytics::{AnalyticsData, Period};
 use crate::bookmarks::BookmarkStore;
-use crate::cache::{ClaudeMemDb, MetadataCache, StoredAlert};
-use crate::error::{CoreError, DegradedState, LoadReport};
+use crate::cache::{CachedAnalytics, ClaudeMemDb, MetadataCache, StoredAlert};
+use crate::error::{CoreError, DegradedState, ErrorKind, ErrorSeverity, LoadError, LoadReport};
 use crate::event::{DataEvent, EventBus};
 use crate::models::activity::ActivitySummary;
 use crate::models::{
     BillingBlockManager, CcboardConfig, ClaudeMemSummary, InvocationStats, MergedConfig, SessionId,
-    SessionMetadata, StatsCache,
+    SessionMetadata, StatsCache, DEFAULT_BLOCK_HOURS,
 };
 use crate::parsers::{
     classify_tool_calls, parse_claude_global, parse_tool_calls, ClaudeGlobalStats, CodexParser,
     CopilotParser, CursorParser, GeminiParser, InvocationParser, McpConfig, OpenCodeParser, Rules,
-    SessionContentParser, SessionIndexParser, SettingsParser, StatsParser,
+    ScanProgress, ScanProgressFn, SessionContentParser, SessionIndexParser, SessionScanState,
+    SettingsParser, StatsParser,
 };
+use crate::pins::PinStore;
+use crate::project_display::ProjectDisplay;
+use crate::project_filter::{ProjectExclusions, ProjectGlobFilter};
+use crate::resume_log::ResumeLog;
+use crate::search_index::SessionSearchIndex;
+use crate::tags::TagStore;
 use dashmap::DashMap;
 use moka::future::Cache;
 use parking_lot::RwLock; // parking_lot > std::sync::RwLock: smaller (40B vs 72B), no poisoning, better fairness
@@ -74,9 +81,18 @@ pub struct DataStore {
     /// Path to Claude home directory
     claude_home: PathBuf,
 
+    /// Additional Claude homes whose sessions are merged in (e.g. synced from
+    /// another machine); stats, settings and the cache come from `claude_home`
+    extra_homes: Vec<PathBuf>,
+
     /// Current project path (if focused)
     project_path: Option<PathBuf>,
 
+    /// `--project-glob` / `--exclude-glob` filter; non-matching sessions are
+    /// never indexed, so every view and aggregate built from sessions skips them,
+    /// and they are subtracted from stats-cache totals
+    project_filter: ProjectGlobFilter,
+
     /// Configuration
     config: DataStoreConfig,
 
@@ -86,6 +102,9 @@ pub struct DataStore {
     /// Merged settings
     settings: RwLock<MergedConfig>,
 
+    /// Project path aliases from ~/.claude/ccboard-aliases.json
+    project_aliases: RwLock<std::collections::HashMap<String, String>>,
+
     /// MCP server configuration
     mcp_config: RwLock<Option<McpConfig>>,
 
@@ -104,6 +123,10 @@ pub struct DataStore {
     /// Discover pattern analysis cache
     discover_cache: RwLock<Option<Vec<crate::analytics::DiscoverSuggestion>>>,
 
+    /// Token percentiles of aggregated sessions (cleared when sessions or
+    /// project exclusions change)
+    token_percentiles_cache: RwLock<Option<crate::analytics::TokenPercentiles>>,
+
     /// Session metadata (high contention with many entries)
     /// Arc<SessionMetadata> for cheap cloning (8 bytes vs ~400 bytes)
     ///
@@ -112,6 +135,14 @@ pub struct DataStore {
     /// cloning entire struct on each frontend access.
     sessions: DashMap<SessionId, Arc<SessionMetadata>>,
 
+    /// Sessions dropped by `project_filter`, kept only to subtract them from
+    /// stats-cache totals (which have no per-project breakdown)
+    glob_filtered: DashMap<SessionId, Arc<SessionMetadata>>,
+
+    /// Inverted index over session metadata for `search` (rebuilt by
+    /// `initial_load`, updated by `update_session`)
+    search_index: RwLock<SessionSearchIndex>,
+
     /// Session content cache (LRU for on-demand loading)
     #[allow(dead_code)]
     session_content_cache: Cache<SessionId, Vec<String>>,
@@ -125,6 +156,12 @@ pub struct DataStore {
     /// Metadata cache for 90% startup speedup (optional)
     metadata_cache: Option<Arc<MetadataCache>>,
 
+    /// Why the metadata cache couldn't be opened, reported on each load
+    cache_error: Option<String>,
+
+    /// Per-file progress callback for session scans (see `set_scan_progress`)
+    scan_progress: RwLock<Option<ScanProgressFn>>,
+
     /// In-memory activity analysis results (populated by analyze_session)
     activity_results: DashMap<String, ActivitySummary>,
 
@@ -137,6 +174,15 @@ pub struct DataStore {
     /// Session bookmarks persisted to ~/.ccboard/bookmarks.json
     bookmark_store: RwLock<BookmarkStore>,
 
+    /// Last resume time per session, persisted to ~/.ccboard/resume-log.json
+    resume_log: RwLock<ResumeLog>,
+
+    /// Pinned session IDs persisted to ~/.ccboard/pinned.json
+    pin_store: RwLock<PinStore>,
+
+    /// Session tags persisted to ~/.ccboard/tags.json
+    tag_store: RwLock<TagStore>,
+
     /// Summary store — reads cached summaries from ~/.ccboard/summaries/
     summary_store: crate::summaries::SummaryStore,
 
@@ -151,16 +197,30 @@ pub struct DataStore {
 
     /// Stateful live session monitor with incremental transcript parsing
     live_monitor_state: parking_lot::Mutex<crate::live_monitor::LiveMonitorState>,
+
+    /// Resumable scan state per session file changed since startup, so the
+    /// watcher only parses appended lines
+    session_scans: DashMap<PathBuf, SessionScanState>,
+
+    /// `git config user.email` per session working directory (`trackAuthor`),
+    /// `None` when git has no answer there
+    dir_authors: DashMap<String, Option<String>>,
 }
 
 /// Project leaderboard entry with aggregated metrics
 #[derive(Debug, Clone)]
 pub struct ProjectLeaderboardEntry {
     pub project_name: String,
+    /// Full project path (key for `budget.perProject`)
+    pub project_path: String,
     pub total_sessions: usize,
     pub total_tokens: u64,
     pub total_cost: f64,
     pub avg_session_cost: f64,
+    /// Cost of sessions started this calendar month (local time)
+    pub month_cost: f64,
+    /// Token-dominant model (canonical ID) and its share of tokens (0.0-1.0)
+    pub primary_model: Option<(String, f64)>,
 }
 
 impl DataStore {
@@ -193,52 +253,95 @@ impl DataStore {
             }
         };
 
+        // Load resume log from ~/.ccboard/resume-log.json
+        let resume_log = match ResumeLog::load(ccboard_dir.join("resume-log.json")) {
+            Ok(log) => log,
+            Err(e) => {
+                warn!(error = %e, "Failed to load resume log, starting empty");
+                ResumeLog::default()
+            }
+        };
+
+        // Load pinned sessions from ~/.ccboard/pinned.json
+        let pin_store = match PinStore::load(ccboard_dir.join("pinned.json")) {
+            Ok(pins) => pins,
+            Err(e) => {
+                warn!(error = %e, "Failed to load pinned sessions, starting empty");
+                PinStore::default()
+            }
+        };
+
+        // Load session tags from ~/.ccboard/tags.json
+        let tag_store = match TagStore::load(ccboard_dir.join("tags.json")) {
+            Ok(tags) => tags,
+            Err(e) => {
+                warn!(error = %e, "Failed to load session tags, starting empty");
+                TagStore::default()
+            }
+        };
+
         // Load ccboard config from ~/.ccboard/config.toml
         let ccboard_config = CcboardConfig::load(&ccboard_dir);
 
+        // Load project aliases from ~/.claude/ccboard-aliases.json
+        let project_aliases = ProjectDisplay::load_aliases(&claude_home);
+
         // Create metadata cache in ~/.claude/cache/
-        let metadata_cache = {
+        let (metadata_cache, cache_error) = {
             let cache_dir = claude_home.join("cache");
             match MetadataCache::new(&cache_dir) {
                 Ok(cache) => {
                     debug!(path = %cache_dir.display(), "Metadata cache enabled");
-                    Some(Arc::new(cache))
+                    (Some(Arc::new(cache)), None)
                 }
                 Err(e) => {
                     warn!(error = %e, "Failed to create metadata cache, running without cache");
-                    None
+                    (None, Some(format!("{:#}", e)))
                 }
             }
         };
 
         Self {
             claude_home,
+            extra_homes: Vec::new(),
             project_path,
+            project_filter: ProjectGlobFilter::default(),
             config,
             stats: RwLock::new(None),
             settings: RwLock::new(MergedConfig::default()),
+            project_aliases: RwLock::new(project_aliases),
             mcp_config: RwLock::new(None),
             rules: RwLock::new(Rules::default()),
             invocation_stats: RwLock::new(InvocationStats::new()),
             billing_blocks: RwLock::new(BillingBlockManager::new()),
             analytics_cache: RwLock::new(None),
             discover_cache: RwLock::new(None),
+            token_percentiles_cache: RwLock::new(None),
             ccboard_dir: ccboard_dir.clone(),
             ccboard_config: RwLock::new(ccboard_config),
             claude_mem_summaries: RwLock::new(Vec::new()),
             sessions: DashMap::new(),
+            glob_filtered: DashMap::new(),
+            search_index: RwLock::new(SessionSearchIndex::new()),
             session_content_cache,
             event_bus: EventBus::default_capacity(),
             degraded_state: RwLock::new(DegradedState::Healthy),
             metadata_cache,
+            cache_error,
+            scan_progress: RwLock::new(None),
             activity_results: DashMap::new(),
             live_hook_sessions: RwLock::new(crate::hook_state::LiveSessionFile::default()),
             claude_global_stats: RwLock::new(None),
             bookmark_store: RwLock::new(bookmark_store),
+            resume_log: RwLock::new(resume_log),
+            pin_store: RwLock::new(pin_store),
+            tag_store: RwLock::new(tag_store),
             summary_store: crate::summaries::SummaryStore::new(&ccboard_dir),
             live_monitor_state: parking_lot::Mutex::new(
                 crate::live_monitor::LiveMonitorState::new(),
             ),
+            session_scans: DashMap::new(),
+            dir_authors: DashMap::new(),
         }
     }
 
@@ -247,11 +350,66 @@ impl DataStore {
         Self::new(claude_home, project_path, DataStoreConfig::default())
     }
 
+    /// Create with default configuration, merging sessions from several Claude homes
+    ///
+    /// The first home is the primary one (stats, settings, cache, aliases). Sessions
+    /// found in more than one home are de-duplicated by ID, keeping the copy with
+    /// the later `last_timestamp`. An empty list falls back to `~/.claude`.
+    pub fn with_multiple_homes(homes: Vec<PathBuf>, project_path: Option<PathBuf>) -> Self {
+        let mut homes = homes.into_iter();
+        let primary = homes.next().unwrap_or_else(|| {
+            dirs::home_dir()
+                .map(|home| home.join(".claude"))
+                .unwrap_or_else(|| PathBuf::from(".claude"))
+        });
+
+        let mut store = Self::with_defaults(primary, project_path);
+        for home in homes {
+            if home != store.claude_home && !store.extra_homes.contains(&home) {
+                store.extra_homes.push(home);
+            }
+        }
+        store
+    }
+
+    /// Only index sessions whose project matches `filter`
+    ///
+    /// `stats-cache.json` totals are Claude Code's own and stay unfiltered.
+    pub fn with_project_filter(mut self, filter: ProjectGlobFilter) -> Self {
+        self.project_filter = filter;
+        self
+    }
+
+    /// Active `--project-glob` / `--exclude-glob` filter
+    pub fn project_filter(&self) -> &ProjectGlobFilter {
+        &self.project_filter
+    }
+
+    /// All Claude homes sessions are loaded from (primary first)
+    pub fn claude_homes(&self) -> Vec<&Path> {
+        std::iter::once(self.claude_home.as_path())
+            .chain(self.extra_homes.iter().map(PathBuf::as_path))
+            .collect()
+    }
+
     /// Get the event bus for subscribing to updates
     pub fn event_bus(&self) -> &EventBus {
         &self.event_bus
     }
 
+    /// Report session scan progress (files done / total) to `progress`
+    ///
+    /// Used by the CLI to drive a determinate progress bar during the initial
+    /// load; `None` stops reporting.
+    pub fn set_scan_progress(&self, progress: Option<ScanProgressFn>) {
+        *self.scan_progress.write() = progress;
+    }
+
+    /// Statistics of the metadata cache, `None` when running without it
+    pub fn cache_stats(&self) -> Option<crate::cache::CacheStats> {
+        self.metadata_cache.as_ref()?.stats().ok()
+    }
+
     /// Get current degraded state
     pub fn degraded_state(&self) -> DegradedState {
         self.degraded_state.read().clone()
@@ -263,6 +421,17 @@ impl DataStore {
 
         info!(claude_home = %self.claude_home.display(), "Starting initial data load");
 
+        if let Some(message) = &self.cache_error {
+            let error = CoreError::CacheIo {
+                path: self.claude_home.join("cache"),
+                message: message.clone(),
+            };
+            report.add_error(LoadError {
+                severity: ErrorSeverity::Warning,
+                ..LoadError::from_core_error("cache", &error)
+            });
+        }
+
         // Load stats
         self.load_stats(&mut report).await;
 
@@ -289,6 +458,23 @@ impl DataStore {
         // Scan third-party AI tool sessions (Codex, OpenCode, Cursor)
         self.scan_third_party_sessions(&mut report).await;
 
+        // Gemini/Copilot/third-party sessions are inserted unfiltered
+        if !self.project_filter.is_empty() {
+            self.sessions
+                .retain(|_, s| self.project_filter.matches(s.project_path.as_str()));
+        }
+
+        self.rebuild_search_index();
+        self.invalidate_session_aggregates();
+
+        // Needs settings (trackAuthor) and sessions
+        self.attribute_authors().await;
+
+        // Subtract excluded projects from stats (needs sessions + settings)
+        if let Some(stats) = self.stats.write().as_mut() {
+            self.exclude_projects_from_stats(stats);
+        }
+
         // Determine degraded state
         self.update_degraded_state(&report);
 
@@ -313,6 +499,71 @@ impl DataStore {
         report
     }
 
+    /// Re-scan everything from scratch and swap it in
+    ///
+    /// The load runs into a separate store, so readers keep seeing the previous
+    /// data until it's done. Each lock-guarded value is then replaced under its
+    /// own write lock; sessions are upserted before vanished ones are dropped, so
+    /// a reader never sees a session missing that exists on disk. Invocation
+    /// stats are kept (recompute with `compute_invocations`); billing blocks are
+    /// rebuilt. Publishes `LoadCompleted` once swapped.
+    pub async fn reload(&self) -> LoadReport {
+        let mut fresh = Self::new(
+            self.claude_home.clone(),
+            self.project_path.clone(),
+            self.config.clone(),
+        );
+        fresh.extra_homes = self.extra_homes.clone();
+        fresh.project_filter = self.project_filter.clone();
+        fresh.metadata_cache = self.metadata_cache.clone();
+        fresh.cache_error = self.cache_error.clone();
+
+        let report = fresh.initial_load().await;
+        fresh.compute_billing_blocks().await;
+
+        *self.stats.write() = fresh.stats.into_inner();
+        *self.settings.write() = fresh.settings.into_inner();
+        *self.project_aliases.write() = fresh.project_aliases.into_inner();
+        *self.mcp_config.write() = fresh.mcp_config.into_inner();
+        *self.rules.write() = fresh.rules.into_inner();
+        *self.billing_blocks.write() = fresh.billing_blocks.into_inner();
+        *self.ccboard_config.write() = fresh.ccboard_config.into_inner();
+        *self.claude_mem_summaries.write() = fresh.claude_mem_summaries.into_inner();
+        *self.claude_global_stats.write() = fresh.claude_global_stats.into_inner();
+        *self.bookmark_store.write() = fresh.bookmark_store.into_inner();
+        *self.resume_log.write() = fresh.resume_log.into_inner();
+        *self.pin_store.write() = fresh.pin_store.into_inner();
+        *self.tag_store.write() = fresh.tag_store.into_inner();
+
+        for entry in fresh.sessions.iter() {
+            self.sessions
+                .insert(entry.key().clone(), Arc::clone(entry.value()));
+        }
+        *self.search_index.write() = fresh.search_index.into_inner();
+        self.sessions
+            .retain(|id, _| fresh.sessions.contains_key(id));
+        self.glob_filtered.clear();
+        for entry in fresh.glob_filtered.iter() {
+            self.glob_filtered
+                .insert(entry.key().clone(), Arc::clone(entry.value()));
+        }
+        self.session_scans.clear();
+        self.session_content_cache.invalidate_all();
+
+        *self.analytics_cache.write() = None;
+        *self.discover_cache.write() = None;
+        self.invalidate_session_aggregates();
+        *self.degraded_state.write() = fresh.degraded_state.into_inner();
+
+        self.event_bus.publish(DataEvent::LoadCompleted);
+        info!(
+            sessions = self.sessions.len(),
+            errors = report.errors.len(),
+            "Reload complete"
+        );
+        report
+    }
+
     /// Load stats cache
     async fn load_stats(&self, report: &mut LoadReport) {
         let stats_path = self.claude_home.join("stats-cache.json");
@@ -320,7 +571,8 @@ impl DataStore {
             .with_retries(self.config.stats_retry_count, self.config.stats_retry_delay);
 
         if let Some(mut stats) = parser.parse_graceful(&stats_path, report).await {
-            // Recalculate costs using accurate pricing
+            // Merge model aliases, then recalculate costs using accurate pricing
+            stats.normalize_model_ids();
             stats.recalculate_costs();
             let mut guard = self.stats.write();
             *guard = Some(stats);
@@ -356,7 +608,6 @@ impl DataStore {
                 debug!("No MCP config found (optional)");
             }
             Err(e) => {
-                use crate::error::LoadError;
                 report.add_error(LoadError::error(
                     "mcp_config",
                     format!("Failed to parse MCP config: {}", e),
@@ -376,7 +627,6 @@ impl DataStore {
                 debug!(has_global, has_project, "Rules loaded");
             }
             Err(e) => {
-                use crate::error::LoadError;
                 report.add_error(LoadError::error(
                     "rules",
                     format!("Failed to load rules: {}", e),
@@ -387,13 +637,23 @@ impl DataStore {
 
     /// Scan all sessions
     async fn scan_sessions(&self, report: &mut LoadReport) {
-        let projects_dir = self.claude_home.join("projects");
+        let projects_dirs: Vec<PathBuf> = self
+            .claude_homes()
+            .into_iter()
+            .map(|home| home.join("projects"))
+            .filter(|dir| {
+                let exists = dir.exists();
+                if !exists {
+                    report.add_warning(
+                        "sessions",
+                        format!("Projects directory not found: {}", dir.display()),
+                    );
+                }
+                exists
+            })
+            .collect();
 
-        if !projects_dir.exists() {
-            report.add_warning(
-                "sessions",
-                format!("Projects directory not found: {}", projects_dir.display()),
-            );
+        if projects_dirs.is_empty() {
             return;
         }
 
@@ -405,7 +665,51 @@ impl DataStore {
             parser = parser.with_cache(cache.clone());
         }
 
-        let sessions = parser.scan_all(&projects_dir, report).await;
+        // Progress of earlier homes is added in, so counts span all homes
+        let progress = self.scan_progress.read().clone();
+        let homes_done = Arc::new(parking_lot::Mutex::new((
+            ScanProgress::default(), // finished homes
+            ScanProgress::default(), // current home
+        )));
+        if let Some(callback) = progress {
+            let homes_done = homes_done.clone();
+            parser = parser.with_progress(Arc::new(move |p: ScanProgress| {
+                let before = {
+                    let mut guard = homes_done.lock();
+                    guard.1 = p;
+                    guard.0
+                };
+                callback(ScanProgress {
+                    done: before.done + p.done,
+                    total: before.total + p.total,
+                    cached: before.cached + p.cached,
+                })
+            }));
+        }
+
+        let mut sessions = Vec::new();
+        for projects_dir in &projects_dirs {
+            sessions.extend(parser.scan_all(projects_dir, report).await);
+            let mut guard = homes_done.lock();
+            let (finished, current) = &mut *guard;
+            finished.done += current.done;
+            finished.total += current.total;
+            finished.cached += current.cached;
+            *current = ScanProgress::default();
+        }
+        if projects_dirs.len() > 1 {
+            sessions = dedup_sessions_by_id(sessions);
+        }
+        if !self.project_filter.is_empty() {
+            let (kept, dropped): (Vec<_>, Vec<_>) = sessions
+                .into_iter()
+                .partition(|s| self.project_filter.matches(s.project_path.as_str()));
+            sessions = kept;
+            for session in dropped {
+                self.glob_filtered
+                    .insert(session.id.clone(), Arc::new(session));
+            }
+        }
 
         // Enforce max count limit
         let sessions_to_add: Vec<_> = if sessions.len() > self.config.max_session_metadata_count {
@@ -507,9 +811,16 @@ impl DataStore {
     fn update_degraded_state(&self, report: &LoadReport) {
         let mut state = self.degraded_state.write();
 
-        if report.has_fatal_errors() {
+        if let Some(fatal) = report
+            .errors
+            .iter()
+            .find(|e| e.severity == ErrorSeverity::Fatal)
+        {
             *state = DegradedState::ReadOnly {
-                reason: "Fatal errors during load".to_string(),
+                reason: match fatal.kind {
+                    Some(_) => fatal.message.clone(),
+                    None => "Fatal errors during load".to_string(),
+                },
             };
             return;
         }
@@ -519,20 +830,26 @@ impl DataStore {
         if !report.stats_loaded {
             missing.push("stats".to_string());
         }
-        if !report.settings_loaded {
+        let invalid_settings = report.errors_of(ErrorKind::SettingsParse).next();
+        if !report.settings_loaded || invalid_settings.is_some() {
             missing.push("settings".to_string());
         }
         if report.sessions_failed > 0 {
             missing.push(format!("{} sessions", report.sessions_failed));
         }
+        if report.errors_of(ErrorKind::CacheIo).next().is_some() {
+            missing.push("cache".to_string());
+        }
 
         if missing.is_empty() {
             *state = DegradedState::Healthy;
         } else {
-            *state = DegradedState::PartialData {
-                missing: missing.clone(),
-                reason: format!("Missing: {}", missing.join(", ")),
+            // Name the broken file rather than just what's missing
+            let reason = match invalid_settings {
+                Some(error) => format!("{} ({})", error.message, missing.join(", ")),
+                None => format!("Missing: {}", missing.join(", ")),
             };
+            *state = DegradedState::PartialData { missing, reason };
         }
     }
 
@@ -563,6 +880,37 @@ impl DataStore {
         self.settings.read().clone()
     }
 
+    /// Display currency for costs (`currency` setting, USD if unset)
+    pub fn currency(&self) -> ccboard_types::Currency {
+        self.settings
+            .read()
+            .merged
+            .currency
+            .clone()
+            .unwrap_or_default()
+    }
+
+    /// Get project path formatter (display mode from settings + alias map)
+    pub fn project_display(&self) -> ProjectDisplay {
+        let mode = self
+            .settings
+            .read()
+            .merged
+            .project_display
+            .unwrap_or_default();
+        ProjectDisplay::new(mode, self.project_aliases.read().clone())
+    }
+
+    /// Max session files scanned in parallel by content search (settings, default 8)
+    pub fn content_search_concurrency(&self) -> usize {
+        self.settings
+            .read()
+            .merged
+            .content_search_concurrency
+            .unwrap_or(8)
+            .max(1)
+    }
+
     /// Get MCP server configuration
     pub fn mcp_config(&self) -> Option<McpConfig> {
         self.mcp_config.read().clone()
@@ -573,6 +921,15 @@ impl DataStore {
         self.rules.read().clone()
     }
 
+    /// Installed Claude Code plugins with their enable state and load errors
+    ///
+    /// Read from disk on each call (the registry is small), so it follows
+    /// `enabledPlugins` changes without a reload.
+    pub fn plugins(&self) -> Vec<crate::parsers::PluginInfo> {
+        let settings = self.settings.read();
+        crate::parsers::load_plugins(&self.claude_home, settings.merged.enabled_plugins.as_ref())
+    }
+
     /// Get invocation statistics
     pub fn invocation_stats(&self) -> InvocationStats {
         self.invocation_stats.read().clone()
@@ -594,7 +951,21 @@ impl DataStore {
     /// Uses `LiveMonitorState` to parse only new JSONL bytes since last call —
     /// O(delta) instead of O(file_size). Returns empty vec if no processes found.
     pub fn live_sessions(&self) -> Vec<crate::live_monitor::LiveSession> {
-        self.live_monitor_state.lock().detect_sessions()
+        let mut state = self.live_monitor_state.lock();
+        state.set_config(self.live_monitor_config());
+        state.detect_sessions()
+    }
+
+    /// Process names detected as live sessions (`liveMonitor.processNames` + `claude`)
+    pub fn live_monitor_config(&self) -> crate::live_monitor::LiveMonitorConfig {
+        let settings = self.settings.read();
+        let extra = settings
+            .merged
+            .live_monitor
+            .as_ref()
+            .map(|l| l.process_names.as_slice())
+            .unwrap_or_default();
+        crate::live_monitor::LiveMonitorConfig::with_extra_names(extra)
     }
 
     /// Get merged live sessions: hook data + ps-based fallback with transcript enrichment.
@@ -602,7 +973,7 @@ impl DataStore {
     /// Hook sessions are prioritized; unmatched ps sessions appear as ProcessOnly.
     pub fn merged_live_sessions(&self) -> Vec<crate::live_monitor::MergedLiveSession> {
         let hook_file = self.live_hook_sessions.read().clone();
-        let ps_sessions = self.live_monitor_state.lock().detect_sessions();
+        let ps_sessions = self.live_sessions();
         crate::live_monitor::merge_live_sessions(&hook_file, &ps_sessions)
     }
 
@@ -747,6 +1118,15 @@ impl DataStore {
         Ok(messages)
     }
 
+    /// Compare two date ranges (period `a` is the baseline)
+    pub fn compare_periods(
+        &self,
+        a: &crate::analytics::DateRange,
+        b: &crate::analytics::DateRange,
+    ) -> crate::analytics::PeriodComparison {
+        crate::analytics::compare_periods(&self.aggregate_sessions(), a, b)
+    }
+
     /// Get analytics data for a period (cached)
     ///
     /// Returns cached analytics if available, otherwise None.
@@ -767,18 +1147,31 @@ impl DataStore {
     /// to a blocking task.
     ///
     /// Cache is invalidated on stats reload or session updates (EventBus pattern).
+    /// Trends, patterns and forecast are also persisted in the metadata cache
+    /// and reused as long as no newer session has been loaded.
     pub async fn compute_analytics(&self, period: Period) {
-        let sessions: Vec<_> = self
-            .sessions
-            .iter()
-            .map(|r| Arc::clone(r.value()))
-            .collect();
+        info!(period = ?period, "compute_analytics() ENTRY");
 
-        info!(
-            session_count = sessions.len(),
-            period = ?period,
-            "compute_analytics() ENTRY"
-        );
+        if let Some(data) = self.analytics_for(period).await {
+            info!(
+                insights_count = data.insights.len(),
+                "compute_analytics() computed data"
+            );
+            let mut guard = self.analytics_cache.write();
+            *guard = Some(data);
+            self.event_bus.publish(DataEvent::AnalyticsUpdated);
+            info!("compute_analytics() EXIT - cached and event published");
+        }
+    }
+
+    /// Compute analytics for a period without replacing the cached ones
+    ///
+    /// Same computation as `compute_analytics()` (settings thresholds,
+    /// persisted trends/patterns/forecast reuse), for callers such as the web
+    /// API that serve arbitrary periods. `None` if the computation panicked.
+    pub async fn analytics_for(&self, period: Period) -> Option<AnalyticsData> {
+        let sessions = self.aggregate_sessions();
+        debug!(session_count = sessions.len(), period = ?period, "Computing analytics");
 
         // Pick up custom anomaly thresholds from merged settings (if configured)
         let thresholds = self
@@ -788,25 +1181,67 @@ impl DataStore {
             .and_then(|s| s.anomaly_thresholds.clone())
             .unwrap_or_default();
 
+        let currency = self.currency();
+
+        let cached =
+            self.metadata_cache
+                .as_ref()
+                .and_then(|cache| match cache.load_analytics(&period) {
+                    Ok(cached) => cached.filter(|c| c.is_fresh_for(&sessions)),
+                    Err(e) => {
+                        warn!(error = %e, "Failed to load cached analytics");
+                        None
+                    }
+                });
+        let from_cache = cached.is_some();
+        if from_cache {
+            if let Some(cache) = &self.metadata_cache {
+                cache.record_analytics_hit();
+            }
+            debug!(period = ?period, "Reusing cached analytics");
+        }
+
         // Offload to blocking task for CPU-intensive computation
         let analytics = tokio::task::spawn_blocking(move || {
-            AnalyticsData::compute_with_thresholds(&sessions, period, &thresholds)
+            let data = match cached {
+                Some(c) => AnalyticsData::compute_with_cached(
+                    &sessions,
+                    period,
+                    &thresholds,
+                    &currency,
+                    c.trends,
+                    c.patterns,
+                    c.forecast,
+                ),
+                None => AnalyticsData::compute_with_thresholds(
+                    &sessions,
+                    period,
+                    &thresholds,
+                    &currency,
+                ),
+            };
+            (data, sessions)
         })
         .await;
 
         match analytics {
-            Ok(data) => {
-                info!(
-                    insights_count = data.insights.len(),
-                    "compute_analytics() computed data"
-                );
-                let mut guard = self.analytics_cache.write();
-                *guard = Some(data);
-                self.event_bus.publish(DataEvent::AnalyticsUpdated);
-                info!("compute_analytics() EXIT - cached and event published");
+            Ok((data, sessions)) => {
+                if let (false, Some(cache)) = (from_cache, &self.metadata_cache) {
+                    let entry = CachedAnalytics::new(
+                        &sessions,
+                        data.trends.clone(),
+                        data.patterns.clone(),
+                        data.forecast.clone(),
+                    );
+                    if let Err(e) = cache.store_analytics(&period, &entry) {
+                        warn!(error = %e, "Failed to persist analytics");
+                    }
+                }
+                Some(data)
             }
             Err(e) => {
                 warn!(error = %e, "Failed to compute analytics (task panicked)");
+                None
             }
         }
     }
@@ -920,6 +1355,11 @@ impl DataStore {
         debug!("Analytics cache invalidated");
     }
 
+    /// Drop aggregates cached over sessions (called when sessions change)
+    fn invalidate_session_aggregates(&self) {
+        *self.token_percentiles_cache.write() = None;
+    }
+
     /// Get all session IDs
     pub fn session_ids(&self) -> Vec<SessionId> {
         self.sessions.iter().map(|r| r.key().clone()).collect()
@@ -963,6 +1403,112 @@ impl DataStore {
             .collect()
     }
 
+    /// Project exclusions from `excludeProjects` in merged settings
+    pub fn project_exclusions(&self) -> ProjectExclusions {
+        self.settings
+            .read()
+            .merged
+            .exclude_projects
+            .as_deref()
+            .map(ProjectExclusions::new)
+            .unwrap_or_default()
+    }
+
+    /// Sessions counted in aggregates (stats, analytics, costs, leaderboard)
+    ///
+    /// Same as `all_sessions` minus sessions of excluded projects, which stay
+    /// reachable through `all_sessions` / `sessions_by_project`.
+    pub fn aggregate_sessions(&self) -> Vec<Arc<SessionMetadata>> {
+        let exclusions = self.project_exclusions();
+        self.sessions
+            .iter()
+            .filter(|r| !exclusions.is_excluded(r.value().project_path.as_str()))
+            .map(|r| Arc::clone(r.value()))
+            .collect()
+    }
+
+    /// Number of distinct projects excluded from aggregates
+    pub fn excluded_project_count(&self) -> usize {
+        let exclusions = self.project_exclusions();
+        if exclusions.is_empty() {
+            return 0;
+        }
+        self.sessions
+            .iter()
+            .map(|r| r.value().project_path.as_str().to_string())
+            .filter(|p| exclusions.is_excluded(p))
+            .collect::<std::collections::HashSet<_>>()
+            .len()
+    }
+
+    /// Where session token totals come from (settings, default reported)
+    pub fn token_source(&self) -> crate::models::TokenSource {
+        self.settings.read().merged.token_source.unwrap_or_default()
+    }
+
+    /// Projects shown with a sparkline on the dashboard (settings, default 5)
+    pub fn dashboard_projects(&self) -> usize {
+        self.settings.read().merged.dashboard_projects.unwrap_or(5)
+    }
+
+    /// Daily token sums of `project`'s sessions over the last `days` local days
+    ///
+    /// Oldest first, today last. Sessions count on the day they started.
+    pub fn project_daily_tokens(&self, project: &str, days: usize) -> Vec<u64> {
+        let today = chrono::Local::now().date_naive();
+        let mut daily = vec![0; days];
+        for entry in self.sessions.iter() {
+            if entry.value().project_path.as_str() == project {
+                add_to_day(&mut daily, entry.value(), today);
+            }
+        }
+        daily
+    }
+
+    /// Up to `limit` projects with the most tokens over the last `days`, each
+    /// with its [`project_daily_tokens`](Self::project_daily_tokens)
+    ///
+    /// Excluded projects and projects idle over the window are left out.
+    pub fn top_project_daily_tokens(&self, limit: usize, days: usize) -> Vec<(String, Vec<u64>)> {
+        let today = chrono::Local::now().date_naive();
+        let mut by_project: std::collections::HashMap<String, Vec<u64>> =
+            std::collections::HashMap::new();
+        for session in self.aggregate_sessions() {
+            let daily = by_project
+                .entry(session.project_path.as_str().to_string())
+                .or_insert_with(|| vec![0; days]);
+            add_to_day(daily, &session, today);
+        }
+
+        let mut top: Vec<(String, Vec<u64>)> = by_project
+            .into_iter()
+            .filter(|(_, daily)| daily.iter().any(|&t| t > 0))
+            .collect();
+        top.sort_by(|a, b| {
+            let total = |daily: &[u64]| daily.iter().sum::<u64>();
+            total(&b.1).cmp(&total(&a.1)).then_with(|| a.0.cmp(&b.0))
+        });
+        top.truncate(limit);
+        top
+    }
+
+    /// Subtract sessions of excluded or glob-filtered projects from freshly
+    /// parsed stats
+    fn exclude_projects_from_stats(&self, stats: &mut StatsCache) {
+        let exclusions = self.project_exclusions();
+        if exclusions.is_empty() && self.glob_filtered.is_empty() {
+            return;
+        }
+        let excluded: Vec<_> = self
+            .sessions
+            .iter()
+            .filter(|r| exclusions.is_excluded(r.value().project_path.as_str()))
+            .chain(self.glob_filtered.iter())
+            .map(|r| Arc::clone(r.value()))
+            .collect();
+        stats.exclude_sessions(excluded.iter().map(|s| s.as_ref()));
+    }
+
     /// Get recent sessions (sorted by last timestamp, newest first)
     /// Returns Arc<SessionMetadata> for cheap cloning
     pub fn recent_sessions(&self, limit: usize) -> Vec<Arc<SessionMetadata>> {
@@ -972,6 +1518,44 @@ impl DataStore {
         sessions
     }
 
+    /// Search session ID, project path, first message, branch and models
+    ///
+    /// Case-insensitive substring match of the whole query, so several words
+    /// match as a phrase. The in-memory token index narrows the candidates
+    /// first. `tag:<name>` words keep only sessions with that tag. Most recent
+    /// first; a blank query returns all sessions.
+    pub fn search(&self, query: &str) -> Vec<Arc<SessionMetadata>> {
+        let (tags, text) = crate::tags::parse_tag_filters(query);
+        let mut sessions = match self.search_index.read().query(&text) {
+            Some(ids) => {
+                let mut sessions: Vec<_> = ids
+                    .iter()
+                    .filter_map(|id| self.sessions.get(id).map(|s| Arc::clone(s.value())))
+                    .filter(|s| crate::search_index::matches_text(s, &text))
+                    .collect();
+                sessions.sort_by_key(|s| std::cmp::Reverse(s.last_timestamp));
+                sessions
+            }
+            None => self.recent_sessions(usize::MAX),
+        };
+        if !tags.is_empty() {
+            let store = self.tag_store.read();
+            sessions.retain(|s| tags.iter().all(|tag| store.has_tag(&s.id, tag)));
+        }
+        sessions
+    }
+
+    /// Re-index every loaded session
+    fn rebuild_search_index(&self) {
+        let sessions: Vec<_> = self
+            .sessions
+            .iter()
+            .map(|entry| Arc::clone(entry.value()))
+            .collect();
+        *self.search_index.write() = SessionSearchIndex::build(sessions.iter().map(|s| s.as_ref()));
+        debug!(count = sessions.len(), "Search index built");
+    }
+
     /// Search sessions using FTS5 full-text search.
     ///
     /// Returns relevance-ranked results. Returns empty vec if FTS5 not initialized.
@@ -1189,11 +1773,7 @@ impl DataStore {
     /// Get top sessions by total tokens (sorted descending)
     /// Returns Arc<SessionMetadata> for cheap cloning
     pub fn top_sessions_by_tokens(&self, limit: usize) -> Vec<Arc<SessionMetadata>> {
-        let mut sessions: Vec<_> = self
-            .sessions
-            .iter()
-            .map(|r| Arc::clone(r.value()))
-            .collect();
+        let mut sessions = self.aggregate_sessions();
         sessions.sort_by(|a, b| b.total_tokens.cmp(&a.total_tokens));
         sessions.truncate(limit);
         sessions
@@ -1205,9 +1785,9 @@ impl DataStore {
         let mut model_totals = std::collections::HashMap::new();
 
         // Aggregate tokens per model across all sessions
-        for session in self.sessions.iter() {
-            for model in &session.value().models_used {
-                *model_totals.entry(model.clone()).or_insert(0) += session.value().total_tokens;
+        for session in self.aggregate_sessions() {
+            for model in &session.models_used {
+                *model_totals.entry(model.clone()).or_insert(0) += session.total_tokens;
             }
         }
 
@@ -1224,10 +1804,10 @@ impl DataStore {
         let mut day_totals = std::collections::HashMap::new();
 
         // Aggregate tokens per day across all sessions
-        for session in self.sessions.iter() {
-            if let Some(timestamp) = &session.value().first_timestamp {
+        for session in self.aggregate_sessions() {
+            if let Some(timestamp) = &session.first_timestamp {
                 let date = timestamp.format("%Y-%m-%d").to_string();
-                *day_totals.entry(date).or_insert(0) += session.value().total_tokens;
+                *day_totals.entry(date).or_insert(0) += session.total_tokens;
             }
         }
 
@@ -1238,16 +1818,58 @@ impl DataStore {
         results
     }
 
+    /// Estimated tokens contributed by tool outputs vs. fresh input tokens
+    ///
+    /// Returns `(tool_result_tokens, input_tokens)` summed across all sessions, where
+    /// input counts uncached input plus cache writes (each tool result enters the
+    /// context once; later turns re-read it from cache).
+    pub fn tool_result_token_share(&self) -> (u64, u64) {
+        self.aggregate_sessions()
+            .iter()
+            .fold((0u64, 0u64), |(tool, input), s| {
+                (
+                    tool + s.tool_result_tokens(),
+                    input + s.input_tokens + s.cache_creation_tokens,
+                )
+            })
+    }
+
+    /// p50/p90/p95/p99 of `total_tokens` across aggregated sessions
+    ///
+    /// Zeros when no sessions are loaded. Cached until sessions change.
+    pub fn token_percentiles(&self) -> crate::analytics::TokenPercentiles {
+        if let Some(cached) = *self.token_percentiles_cache.read() {
+            return cached;
+        }
+        let percentiles = crate::analytics::TokenPercentiles::from_tokens(
+            self.aggregate_sessions()
+                .iter()
+                .map(|s| s.total_tokens)
+                .collect(),
+        );
+        *self.token_percentiles_cache.write() = Some(percentiles);
+        percentiles
+    }
+
     /// Get project leaderboard with aggregated metrics
     ///
     /// Returns all projects with session count, total tokens, total cost, and average session cost.
     /// Cost is calculated using accurate model-based pricing from the pricing module.
     pub fn projects_leaderboard(&self) -> Vec<ProjectLeaderboardEntry> {
+        use chrono::Datelike;
+
         let mut project_metrics = std::collections::HashMap::new();
+        let now = chrono::Local::now();
+        let in_current_month = |metadata: &SessionMetadata| {
+            metadata.first_timestamp.is_some_and(|ts| {
+                let local = ts.with_timezone(&chrono::Local);
+                local.year() == now.year() && local.month() == now.month()
+            })
+        };
 
         // Aggregate metrics per project
-        for session in self.sessions.iter() {
-            let metadata = session.value();
+        for session in self.aggregate_sessions() {
+            let metadata = session.as_ref();
             let project_path = &metadata.project_path;
 
             // Get model for this session (use first model, or "unknown")
@@ -1257,8 +1879,8 @@ impl DataStore {
                 .map(|s| s.as_str())
                 .unwrap_or("unknown");
 
-            // Calculate cost using accurate pricing
-            let cost = crate::pricing::calculate_cost(
+            // Calculate cost using accurate pricing (rates in effect on the session date)
+            let cost = metadata.priced(
                 model,
                 metadata.input_tokens,
                 metadata.output_tokens,
@@ -1266,20 +1888,31 @@ impl DataStore {
                 metadata.cache_read_tokens,
             );
 
-            let entry = project_metrics
-                .entry(project_path.clone())
-                .or_insert((0, 0u64, 0.0f64)); // (session_count, total_tokens, total_cost)
+            let entry = project_metrics.entry(project_path.clone()).or_insert((
+                0,
+                0u64,
+                0.0f64,
+                0.0f64,
+                Vec::new(),
+            )); // (session_count, total_tokens, total_cost, month_cost, sessions)
 
             entry.0 += 1; // session count
             entry.1 += metadata.total_tokens; // total tokens
             entry.2 += cost; // total cost
+            if in_current_month(metadata) {
+                entry.3 += cost; // month-to-date cost
+            }
+            entry.4.push(Arc::clone(&session));
         }
 
         // Convert to leaderboard entries
         let mut results: Vec<_> = project_metrics
             .into_iter()
             .map(
-                |(project_path, (session_count, total_tokens, total_cost))| {
+                |(
+                    project_path,
+                    (session_count, total_tokens, total_cost, month_cost, sessions),
+                )| {
                     let avg_session_cost = if session_count > 0 {
                         total_cost / session_count as f64
                     } else {
@@ -1295,10 +1928,15 @@ impl DataStore {
 
                     ProjectLeaderboardEntry {
                         project_name,
+                        project_path: project_path.as_str().to_string(),
                         total_sessions: session_count,
                         total_tokens,
                         total_cost,
                         avg_session_cost,
+                        month_cost,
+                        primary_model: crate::analytics::primary_model_by_tokens(
+                            sessions.iter().map(|s| s.as_ref()),
+                        ),
                     }
                 },
             )
@@ -1314,6 +1952,57 @@ impl DataStore {
         results
     }
 
+    /// Budget alerts for `data` under the merged `budget` settings
+    ///
+    /// Empty when no budget is configured.
+    pub fn budget_alerts(&self, data: &AnalyticsData) -> Vec<crate::analytics::Alert> {
+        use crate::analytics::{generate_budget_alerts, ShortTermBudget};
+
+        let Some(config) = self.settings().merged.budget else {
+            return Vec::new();
+        };
+        generate_budget_alerts(
+            &data.trends,
+            &data.forecast,
+            config.monthly_limit,
+            config.warning_threshold,
+            &ShortTermBudget {
+                daily: config.daily_budget_usd,
+                per_block: config.per_block_budget_usd,
+                spend: self.current_spend(),
+            },
+            &self.project_budget_statuses(),
+        )
+    }
+
+    /// Month-to-date spend of projects with an explicit `budget.perProject` entry
+    ///
+    /// Projects that only inherit the global budget are covered by the global
+    /// budget alerts and are not listed.
+    pub fn project_budget_statuses(&self) -> Vec<crate::analytics::ProjectBudgetStatus> {
+        let Some(budget) = self.settings().merged.budget else {
+            return Vec::new();
+        };
+        if budget.per_project.is_empty() {
+            return Vec::new();
+        }
+
+        self.projects_leaderboard()
+            .into_iter()
+            .filter_map(|entry| {
+                let resolved = budget.project_budget(&entry.project_path)?;
+                resolved
+                    .explicit
+                    .then_some(crate::analytics::ProjectBudgetStatus {
+                        project: entry.project_path,
+                        spent: entry.month_cost,
+                        budget: resolved.monthly_usd,
+                        alert_threshold_pct: resolved.alert_threshold_pct,
+                    })
+            })
+            .collect()
+    }
+
     // ===================
     // Update methods (called by watcher)
     // ===================
@@ -1326,8 +2015,10 @@ impl DataStore {
 
         let mut report = LoadReport::new();
         if let Some(mut stats) = parser.parse_graceful(&stats_path, &mut report).await {
-            // Recalculate costs using accurate pricing
+            // Merge model aliases, then recalculate costs using accurate pricing
+            stats.normalize_model_ids();
             stats.recalculate_costs();
+            self.exclude_projects_from_stats(&mut stats);
             let mut guard = self.stats.write();
             *guard = Some(stats);
 
@@ -1349,9 +2040,25 @@ impl DataStore {
             )
             .await;
 
-        {
+        let (exclusions_changed, block_hours_changed) = {
             let mut guard = self.settings.write();
+            let changed = (
+                guard.merged.exclude_projects != merged.merged.exclude_projects,
+                guard.merged.billing_block_hours != merged.merged.billing_block_hours,
+            );
             *guard = merged;
+            changed
+        };
+        *self.project_aliases.write() = ProjectDisplay::load_aliases(&self.claude_home);
+
+        // Stats have excluded projects subtracted: reload them from disk
+        if exclusions_changed {
+            self.reload_stats().await;
+            self.invalidate_analytics_cache();
+            self.invalidate_session_aggregates();
+        }
+        if exclusions_changed || block_hours_changed {
+            self.compute_billing_blocks().await;
         }
 
         // Note: caller (watcher handle_event) publishes ConfigChanged after this returns.
@@ -1359,21 +2066,49 @@ impl DataStore {
     }
 
     /// Add or update a session (called when session file changes)
+    ///
+    /// Only lines appended since the previous update are parsed; the first
+    /// update of a file, or one that shrank, parses it in full.
     pub async fn update_session(&self, path: &Path) {
         let parser = SessionIndexParser::new();
+        let mut scan = self.session_scans.remove(path).map(|(_, state)| state);
 
-        match parser.scan_session(path).await {
+        match parser.scan_session_incremental(path, &mut scan).await {
             Ok(meta) => {
+                if let Some(state) = scan {
+                    self.session_scans.insert(path.to_path_buf(), state);
+                }
+                if !self.project_filter.matches(meta.project_path.as_str()) {
+                    self.glob_filtered.insert(meta.id.clone(), Arc::new(meta));
+                    return;
+                }
+
+                let mut meta = meta;
+                if self.track_author() {
+                    let dir = author_dir(&meta).to_string();
+                    self.resolve_dir_authors(vec![dir.clone()]).await;
+                    meta.author = self.dir_authors.get(&dir).and_then(|a| a.clone());
+                }
+
                 let id = meta.id.clone();
                 let is_new = !self.sessions.contains_key(&id);
 
+                self.search_index.write().insert(&meta);
                 self.sessions.insert(id.clone(), Arc::new(meta));
+                self.invalidate_session_aggregates();
 
                 // Don't invalidate analytics on every session update - too aggressive
                 // Analytics will be recomputed on demand or periodically
                 // Only invalidate on significant changes (detected by UI)
 
                 if is_new {
+                    // Persisted analytics predate this session file
+                    if let Some(cache) = &self.metadata_cache {
+                        if let Err(e) = cache.invalidate_analytics() {
+                            warn!(error = %e, "Failed to invalidate persisted analytics");
+                        }
+                    }
+
                     self.event_bus.publish(DataEvent::SessionCreated(id));
                 } else {
                     self.event_bus.publish(DataEvent::SessionUpdated(id));
@@ -1390,16 +2125,22 @@ impl DataStore {
     /// This scans all session files to count agent/command/skill invocations.
     /// Should be called after initial load or when sessions are updated.
     pub async fn compute_invocations(&self) {
-        let paths: Vec<_> = self
+        let sessions: Vec<_> = self
             .sessions
             .iter()
-            .map(|r| r.value().file_path.clone())
+            .map(|r| {
+                let session = r.value();
+                (
+                    session.file_path.clone(),
+                    session.project_path.as_str().to_string(),
+                )
+            })
             .collect();
 
-        debug!(session_count = paths.len(), "Computing invocation stats");
+        debug!(session_count = sessions.len(), "Computing invocation stats");
 
         let parser = InvocationParser::new();
-        let mut stats = parser.scan_sessions(&paths).await;
+        let mut stats = parser.scan_sessions_by_project(&sessions).await;
 
         // Populate agent_token_stats from session tool_token_usage
         // The Task tool tokens serve as a proxy for agent token consumption
@@ -1414,6 +2155,12 @@ impl DataStore {
                 for agent_type in stats.agents.keys().cloned().collect::<Vec<_>>() {
                     *stats.agent_token_stats.entry(agent_type).or_insert(0) += tokens_per_agent;
                 }
+                if let Some(project) = stats.per_project.get_mut(session.project_path.as_str()) {
+                    for agent_type in project.agents.keys().cloned().collect::<Vec<_>>() {
+                        *project.agent_token_stats.entry(agent_type).or_insert(0) +=
+                            tokens_per_agent;
+                    }
+                }
             }
         }
 
@@ -1434,17 +2181,24 @@ impl DataStore {
 
     /// Compute billing blocks from all sessions
     ///
-    /// This scans all sessions with timestamps and aggregates usage into 5-hour billing blocks.
+    /// This scans all sessions with timestamps and aggregates usage into billing blocks
+    /// (5 hours unless `billingBlockHours` is set in settings).
     /// Uses real model pricing based on token breakdown for accurate cost calculation.
     pub async fn compute_billing_blocks(&self) {
         debug!("Computing billing blocks from sessions with real pricing");
 
-        let mut manager = BillingBlockManager::new();
+        let block_hours = self
+            .settings
+            .read()
+            .merged
+            .billing_block_hours
+            .unwrap_or(DEFAULT_BLOCK_HOURS);
+        let mut manager = BillingBlockManager::new_with_duration(block_hours);
         let mut sessions_with_timestamps = 0;
         let mut sessions_without_timestamps = 0;
 
-        for session in self.sessions.iter() {
-            let metadata = session.value();
+        for session in self.aggregate_sessions() {
+            let metadata = session.as_ref();
 
             // Skip sessions without timestamps
             let Some(timestamp) = &metadata.first_timestamp else {
@@ -1461,8 +2215,8 @@ impl DataStore {
                 .map(|s| s.as_str())
                 .unwrap_or("unknown");
 
-            // Calculate real cost using pricing table
-            let cost = crate::pricing::calculate_cost(
+            // Calculate real cost using pricing table (rates in effect on the session date)
+            let cost = metadata.priced(
                 model,
                 metadata.input_tokens,
                 metadata.output_tokens,
@@ -1498,32 +2252,48 @@ impl DataStore {
         self.billing_blocks.read()
     }
 
-    /// Calculate usage estimate based on billing blocks and subscription plan
-    pub fn usage_estimate(&self) -> crate::usage_estimator::UsageEstimate {
+    /// Spend of the current UTC day and billing block (for daily / per-block budgets)
+    pub fn current_spend(&self) -> crate::models::CurrentSpend {
+        self.billing_blocks.read().current_spend(chrono::Utc::now())
+    }
+
+    /// Subscription plan from settings.json, else auto-detected from ~/.claude.json
+    pub fn subscription_plan(&self) -> crate::usage_estimator::SubscriptionPlan {
         use crate::parsers::claude_global::DetectedPlan;
         use crate::usage_estimator::SubscriptionPlan;
 
         let settings = self.settings();
 
         // Priority 1: explicit override in settings.json (ccboard field)
-        let plan = if let Some(s) = settings.merged.subscription_plan.as_ref() {
-            SubscriptionPlan::parse(s)
-        } else {
-            // Priority 2: auto-detect from ~/.claude.json account fields
-            let detected = self
-                .claude_global_stats
-                .read()
-                .as_ref()
-                .and_then(|g| g.detected_plan.clone());
+        if let Some(s) = settings.merged.subscription_plan.as_ref() {
+            return SubscriptionPlan::parse(s);
+        }
 
-            match detected {
-                Some(DetectedPlan::Pro) => SubscriptionPlan::Pro,
-                Some(DetectedPlan::Max) => SubscriptionPlan::Max5x, // can't distinguish 5x vs 20x
-                Some(DetectedPlan::Api) => SubscriptionPlan::Api,
-                None => SubscriptionPlan::Unknown,
-            }
-        };
+        // Priority 2: auto-detect from ~/.claude.json account fields
+        let detected = self
+            .claude_global_stats
+            .read()
+            .as_ref()
+            .and_then(|g| g.detected_plan.clone());
 
+        match detected {
+            Some(DetectedPlan::Pro) => SubscriptionPlan::Pro,
+            Some(DetectedPlan::Max) => SubscriptionPlan::Max5x, // can't distinguish 5x vs 20x
+            Some(DetectedPlan::Api) => SubscriptionPlan::Api,
+            None => SubscriptionPlan::Unknown,
+        }
+    }
+
+    /// Calculate usage estimate based on billing blocks and subscription plan
+    pub fn usage_estimate(&self) -> crate::usage_estimator::UsageEstimate {
+        self.usage_estimate_for(self.subscription_plan())
+    }
+
+    /// Calculate usage estimate against an explicit plan
+    pub fn usage_estimate_for(
+        &self,
+        plan: crate::usage_estimator::SubscriptionPlan,
+    ) -> crate::usage_estimator::UsageEstimate {
         let billing_blocks = self.billing_blocks.read();
         crate::usage_estimator::calculate_usage_estimate(&billing_blocks, plan)
     }
@@ -1582,6 +2352,59 @@ impl DataStore {
         self.bookmark_store.read().len()
     }
 
+    // ── Resume log accessors ─────────────────────────────────────────────────
+
+    /// Record that a session was resumed now (persists immediately)
+    pub fn record_resume(&self, session_id: &str) -> anyhow::Result<()> {
+        self.resume_log.write().record(session_id)
+    }
+
+    /// When the session was last resumed from ccboard, if ever
+    pub fn last_resumed(&self, session_id: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.resume_log.read().last_resumed(session_id)
+    }
+
+    // ── Pinned sessions ──────────────────────────────────────────────────────
+
+    /// Pin or unpin a session (persists immediately).
+    /// Returns `true` if the session is now pinned.
+    pub fn toggle_pin(&self, session_id: &str) -> anyhow::Result<bool> {
+        self.pin_store.write().toggle(session_id)
+    }
+
+    /// Returns true if the session is pinned
+    pub fn is_pinned(&self, session_id: &str) -> bool {
+        self.pin_store.read().is_pinned(session_id)
+    }
+
+    /// Pinned sessions that are still loaded, most recent activity first
+    ///
+    /// Pins whose session no longer exists are skipped but kept on disk.
+    pub fn pinned_sessions(&self) -> Vec<Arc<SessionMetadata>> {
+        let mut sessions: Vec<Arc<SessionMetadata>> = self
+            .pin_store
+            .read()
+            .ids()
+            .filter_map(|id| self.get_session(id))
+            .collect();
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.last_timestamp));
+        sessions
+    }
+
+    // ── Session tags ─────────────────────────────────────────────────────────
+
+    /// Tags of a session, empty when untagged
+    pub fn tags_for(&self, session_id: &str) -> Vec<String> {
+        self.tag_store.read().tags_for(session_id).to_vec()
+    }
+
+    /// Replace a session's tags (persists immediately; empty clears them)
+    ///
+    /// Tags are trimmed, lowercased and deduplicated.
+    pub fn set_tags(&self, session_id: &str, tags: &[String]) -> anyhow::Result<()> {
+        self.tag_store.write().set(session_id, tags)
+    }
+
     /// True if a cached LLM summary exists for this session
     pub fn has_summary(&self, session_id: &str) -> bool {
         self.summary_store.has_summary(session_id)
@@ -1609,6 +2432,88 @@ impl DataStore {
             .collect()
     }
 
+    /// Whether sessions are attributed to git authors (`trackAuthor`)
+    fn track_author(&self) -> bool {
+        self.settings.read().merged.track_author == Some(true)
+    }
+
+    /// Look up `git config user.email` for directories not resolved yet
+    async fn resolve_dir_authors(&self, dirs: Vec<String>) {
+        let missing: Vec<String> = dirs
+            .into_iter()
+            .filter(|d| !self.dir_authors.contains_key(d))
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+        let resolved = tokio::task::spawn_blocking(move || {
+            missing
+                .into_iter()
+                .map(|dir| {
+                    let author = git_user_email(Path::new(&dir));
+                    (dir, author)
+                })
+                .collect::<Vec<_>>()
+        })
+        .await
+        .unwrap_or_default();
+        for (dir, author) in resolved {
+            self.dir_authors.insert(dir, author);
+        }
+    }
+
+    /// Set `author` on every session from the git config where it ran
+    ///
+    /// No-op unless `trackAuthor` is enabled. Sessions whose directory is gone
+    /// or has no `user.email` leave `author` unset.
+    pub async fn attribute_authors(&self) {
+        if !self.track_author() {
+            return;
+        }
+        let dirs: std::collections::HashSet<String> = self
+            .sessions
+            .iter()
+            .map(|entry| author_dir(entry.value()).to_string())
+            .collect();
+        self.resolve_dir_authors(dirs.into_iter().collect()).await;
+
+        for mut entry in self.sessions.iter_mut() {
+            let author = self
+                .dir_authors
+                .get(author_dir(entry.value()))
+                .and_then(|a| a.clone());
+            if entry.value().author != author {
+                let updated = SessionMetadata {
+                    author,
+                    ..entry.value().as_ref().clone()
+                };
+                *entry.value_mut() = Arc::new(updated);
+            }
+        }
+    }
+
+    /// Sessions that continue one another (resumed or inferred), most
+    /// recently active chain first
+    pub fn session_chains(&self) -> Vec<crate::graph::SessionChain> {
+        crate::graph::build_session_chains(&self.all_sessions())
+    }
+
+    /// The chain `session_id` belongs to, if any
+    ///
+    /// Chains never cross projects, so only that project's sessions are walked.
+    pub fn session_chain(&self, session_id: &str) -> Option<crate::graph::SessionChain> {
+        let project = self.get_session(session_id)?.project_path.clone();
+        let sessions: Vec<Arc<SessionMetadata>> = self
+            .sessions
+            .iter()
+            .filter(|entry| entry.value().project_path == project)
+            .map(|entry| Arc::clone(entry.value()))
+            .collect();
+        crate::graph::build_session_chains(&sessions)
+            .into_iter()
+            .find(|chain| chain.contains(session_id))
+    }
+
     /// Backfills `has_subagents` on all sessions based on cross-references.
     /// A session has subagents if any other session has `parent_session_id == this_id`.
     /// Called once after initial_load() completes.
@@ -1638,6 +2543,64 @@ impl DataStore {
     }
 }
 
+/// Directory to ask git about for a session's author: the recorded `cwd`,
+/// else the decoded project path (which loses any `-` in directory names)
+fn author_dir(session: &SessionMetadata) -> &str {
+    session
+        .cwd
+        .as_deref()
+        .unwrap_or(session.project_path.as_str())
+}
+
+/// `git config user.email` as seen from `dir`, if it's a directory and set
+fn git_user_email(dir: &Path) -> Option<String> {
+    if !dir.is_dir() {
+        return None;
+    }
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["config", "user.email"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let email = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!email.is_empty()).then_some(email)
+}
+
+/// Add `session`'s tokens to its start day in `daily` (last entry = `today`)
+///
+/// Sessions without a timestamp or outside the window are ignored.
+fn add_to_day(daily: &mut [u64], session: &SessionMetadata, today: chrono::NaiveDate) {
+    let Some(started) = session.first_timestamp else {
+        return;
+    };
+    let age = (today - started.with_timezone(&chrono::Local).date_naive()).num_days();
+    if age >= 0 && (age as usize) < daily.len() {
+        let last = daily.len() - 1;
+        daily[last - age as usize] += session.total_tokens;
+    }
+}
+
+/// Keep one session per ID, preferring the later `last_timestamp`
+///
+/// Used when merging homes that share synced session files.
+fn dedup_sessions_by_id(sessions: Vec<SessionMetadata>) -> Vec<SessionMetadata> {
+    let mut by_id: std::collections::HashMap<SessionId, SessionMetadata> =
+        std::collections::HashMap::with_capacity(sessions.len());
+    for session in sessions {
+        match by_id.get(&session.id) {
+            Some(existing) if existing.last_timestamp >= session.last_timestamp => {}
+            _ => {
+                by_id.insert(session.id.clone(), session);
+            }
+        }
+    }
+    by_id.into_values().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1665,6 +2628,319 @@ mod tests {
         assert!(store.degraded_state().is_degraded());
     }
 
+    #[tokio::test]
+    async fn test_invalid_settings_named_in_degraded_state() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("settings.json"), "{\n  \"model\": }\n").unwrap();
+        let store = DataStore::with_defaults(dir.path().to_path_buf(), None);
+
+        store.initial_load().await;
+
+        match store.degraded_state() {
+            DegradedState::PartialData { missing, reason } => {
+                assert!(missing.contains(&"settings".to_string()));
+                assert!(
+                    reason.starts_with("settings.json is invalid JSON at line 2"),
+                    "{}",
+                    reason
+                );
+            }
+            other => panic!("expected partial data, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_project_daily_tokens() {
+        let dir = tempdir().unwrap();
+        let store = DataStore::with_defaults(dir.path().to_path_buf(), None);
+        let now = chrono::Utc::now();
+        for (id, project, days_ago, tokens) in [
+            ("a", "/work/api", 0, 100),
+            ("b", "/work/api", 0, 50),
+            ("c", "/work/api", 3, 10),
+            ("d", "/work/web", 1, 500),
+            ("e", "/work/old", 30, 9_999),
+        ] {
+            let mut s = SessionMetadata::from_path(
+                PathBuf::from(format!("/tmp/{}.jsonl", id)),
+                project.into(),
+            );
+            s.first_timestamp = Some(now - chrono::Duration::days(days_ago));
+            s.total_tokens = tokens;
+            store.sessions.insert(s.id.clone(), Arc::new(s));
+        }
+
+        let api = store.project_daily_tokens("/work/api", 14);
+        assert_eq!(api.len(), 14);
+        assert_eq!(api[13], 150);
+        assert_eq!(api[10], 10);
+        assert_eq!(api.iter().sum::<u64>(), 160);
+
+        // Idle projects are dropped, busiest first
+        let top = store.top_project_daily_tokens(5, 14);
+        let names: Vec<&str> = top.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(names, ["/work/web", "/work/api"]);
+        assert_eq!(store.top_project_daily_tokens(1, 14).len(), 1);
+        assert_eq!(store.dashboard_projects(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_pins_survive_restart_and_deleted_sessions() {
+        let dir = tempdir().unwrap();
+        let claude_home = dir.path().join(".claude");
+        let insert = |store: &DataStore, id: &str, hours_ago: i64| {
+            let mut s = SessionMetadata::from_path(
+                PathBuf::from(format!("/tmp/{}.jsonl", id)),
+                "/work/api".into(),
+            );
+            s.last_timestamp = Some(chrono::Utc::now() - chrono::Duration::hours(hours_ago));
+            store.sessions.insert(s.id.clone(), Arc::new(s));
+        };
+
+        let store = DataStore::with_defaults(claude_home.clone(), None);
+        insert(&store, "old", 5);
+        insert(&store, "new", 1);
+        assert!(store.toggle_pin("old").unwrap());
+        assert!(store.toggle_pin("new").unwrap());
+        assert!(store.toggle_pin("gone").unwrap());
+        assert!(dir.path().join(".ccboard/pinned.json").exists());
+
+        // "gone" was never loaded: skipped, newest first
+        let ids: Vec<String> = store
+            .pinned_sessions()
+            .iter()
+            .map(|s| s.id.to_string())
+            .collect();
+        assert_eq!(ids, ["new", "old"]);
+
+        let restarted = DataStore::with_defaults(claude_home, None);
+        assert!(restarted.is_pinned("gone"));
+        assert!(restarted.pinned_sessions().is_empty());
+        insert(&restarted, "old", 5);
+        assert_eq!(restarted.pinned_sessions().len(), 1);
+        assert!(!restarted.toggle_pin("old").unwrap());
+        assert!(restarted.pinned_sessions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_filters_by_tag() {
+        let dir = tempdir().unwrap();
+        let claude_home = dir.path().join(".claude");
+        let store = DataStore::with_defaults(claude_home.clone(), None);
+        for (id, project) in [
+            ("a1", "/work/api"),
+            ("a2", "/work/api"),
+            ("w1", "/work/web"),
+        ] {
+            let s = SessionMetadata::from_path(
+                PathBuf::from(format!("/tmp/{}.jsonl", id)),
+                project.into(),
+            );
+            store.sessions.insert(s.id.clone(), Arc::new(s));
+        }
+        store.rebuild_search_index();
+
+        let tags = |t: &[&str]| t.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+        store.set_tags("a1", &tags(&["Bug", "spike"])).unwrap();
+        store.set_tags("w1", &tags(&["bug"])).unwrap();
+        store.set_tags("gone", &tags(&["bug"])).unwrap();
+
+        let ids = |query: &str| {
+            let mut ids: Vec<String> = store
+                .search(query)
+                .iter()
+                .map(|s| s.id.to_string())
+                .collect();
+            ids.sort();
+            ids
+        };
+        // The stale "gone" entry never shows up
+        assert_eq!(ids("tag:bug"), ["a1", "w1"]);
+        assert_eq!(ids("tag:bug tag:spike"), ["a1"]);
+        assert_eq!(ids("tag:bug api"), ["a1"]);
+        assert!(ids("tag:unknown").is_empty());
+
+        let restarted = DataStore::with_defaults(claude_home, None);
+        assert_eq!(restarted.tags_for("a1"), ["bug", "spike"]);
+        assert!(restarted.tags_for("a2").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_multiple_homes_merge_and_dedup() {
+        let work = tempdir().unwrap();
+        let laptop = tempdir().unwrap();
+        let write_session = |home: &Path, id: &str, last_ts: &str| {
+            let project = home.join("projects").join("-code-api");
+            std::fs::create_dir_all(&project).unwrap();
+            let lines = [("2026-01-10T10:00:00Z", "Hello"), (last_ts, "Again")].map(|(ts, text)| {
+                format!(
+                    r#"{{"type": "user", "sessionId": "{}", "timestamp": "{}", "message": {{"content": "{}"}}}}"#,
+                    id, ts, text
+                )
+            });
+            std::fs::write(project.join(format!("{}.jsonl", id)), lines.join("\n")).unwrap();
+        };
+        write_session(work.path(), "shared", "2026-01-10T11:00:00Z");
+        write_session(laptop.path(), "shared", "2026-01-10T12:00:00Z");
+        write_session(laptop.path(), "other", "2026-01-10T12:00:00Z");
+
+        let store = DataStore::with_multiple_homes(
+            vec![
+                work.path().to_path_buf(),
+                laptop.path().to_path_buf(),
+                work.path().to_path_buf(),
+            ],
+            None,
+        );
+        assert_eq!(store.claude_homes().len(), 2);
+        store.initial_load().await;
+
+        assert_eq!(store.session_count(), 2);
+        let shared = store.get_session("shared").unwrap();
+        assert_eq!(
+            shared.last_timestamp.unwrap().to_rfc3339(),
+            "2026-01-10T12:00:00+00:00"
+        );
+        let by_project = store.sessions_by_project();
+        assert_eq!(by_project.len(), 1);
+        assert_eq!(by_project.values().next().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_reload_swaps_in_fresh_sessions() {
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("projects").join("-code-api");
+        std::fs::create_dir_all(&project).unwrap();
+        let write_session = |id: &str, text: &str| {
+            std::fs::write(
+                project.join(format!("{}.jsonl", id)),
+                format!(
+                    r#"{{"type": "user", "sessionId": "{}", "message": {{"content": "{}"}}}}"#,
+                    id, text
+                ),
+            )
+            .unwrap();
+        };
+        write_session("kept", "Hello");
+        write_session("removed", "Bye");
+
+        let store = DataStore::with_defaults(dir.path().to_path_buf(), None);
+        store.initial_load().await;
+        assert_eq!(store.session_count(), 2);
+        let mut events = store.event_bus().subscribe();
+
+        std::fs::remove_file(project.join("removed.jsonl")).unwrap();
+        write_session("added", "Reticulating splines");
+        let report = store.reload().await;
+
+        assert_eq!(report.sessions_scanned, 2);
+        assert_eq!(store.session_count(), 2);
+        assert!(store.get_session("removed").is_none());
+        assert!(store.get_session("added").is_some());
+        assert_eq!(store.search("splines").len(), 1);
+        assert!(matches!(events.try_recv(), Ok(DataEvent::LoadCompleted)));
+    }
+
+    #[tokio::test]
+    async fn test_project_glob_filter_limits_index() {
+        let dir = tempdir().unwrap();
+        for (project, id) in [
+            ("-repo-services-api", "api"),
+            ("-repo-services-legacy", "legacy"),
+            ("-repo-apps-web", "web"),
+        ] {
+            let project_dir = dir.path().join("projects").join(project);
+            std::fs::create_dir_all(&project_dir).unwrap();
+            std::fs::write(
+                project_dir.join(format!("{}.jsonl", id)),
+                format!(
+                    r#"{{"type": "user", "sessionId": "{}", "message": {{"content": "Hi"}}}}"#,
+                    id
+                ),
+            )
+            .unwrap();
+        }
+
+        let store = DataStore::with_defaults(dir.path().to_path_buf(), None)
+            .with_project_filter(ProjectGlobFilter::new(&["services/*"], &["legacy"]));
+        store.initial_load().await;
+
+        assert_eq!(store.session_count(), 1);
+        assert!(store.get_session("api").is_some());
+        assert_eq!(store.projects_leaderboard().len(), 1);
+
+        // Watcher updates for filtered-out projects are ignored too
+        store
+            .update_session(&dir.path().join("projects/-repo-apps-web/web.jsonl"))
+            .await;
+        assert!(store.get_session("web").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_token_percentiles_follow_session_updates() {
+        let dir = tempdir().unwrap();
+        let project_dir = dir.path().join("projects").join("-repo-api");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let write = |id: &str, tokens: u64| {
+            std::fs::write(
+                project_dir.join(format!("{}.jsonl", id)),
+                format!(
+                    r#"{{"type": "assistant", "sessionId": "{}", "message": {{"model": "claude-sonnet-4-5", "usage": {{"input_tokens": {}, "output_tokens": 0}}}}}}"#,
+                    id, tokens
+                ),
+            )
+            .unwrap();
+        };
+        write("small", 100);
+
+        let store = DataStore::with_defaults(dir.path().to_path_buf(), None);
+        store.initial_load().await;
+        assert_eq!(store.token_percentiles().p99, 100);
+
+        write("large", 5_000);
+        store.update_session(&project_dir.join("large.jsonl")).await;
+        assert_eq!(store.token_percentiles().p99, 5_000);
+    }
+
+    #[tokio::test]
+    async fn test_search_uses_index_and_tracks_watcher_updates() {
+        let dir = tempdir().unwrap();
+        let project_dir = dir.path().join("projects").join("-repo-ccboard");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let write = |id: &str, message: &str| {
+            std::fs::write(
+                project_dir.join(format!("{}.jsonl", id)),
+                format!(
+                    r#"{{"type": "user", "sessionId": "{}", "gitBranch": "feat/search", "message": {{"content": "{}"}}}}"#,
+                    id, message
+                ),
+            )
+            .unwrap();
+        };
+        write("alpha", "Tokenize the parser output");
+
+        let store = DataStore::with_defaults(dir.path().to_path_buf(), None);
+        store.initial_load().await;
+
+        let found = store.search("pars");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id.as_str(), "alpha");
+        // Several words match as a phrase, not scattered across fields
+        assert_eq!(store.search("the parser").len(), 1);
+        assert_eq!(store.search("feat/search").len(), 1);
+        assert!(store.search("feat search ccboard").is_empty());
+        assert!(store.search("websocket").is_empty());
+        assert_eq!(store.search("").len(), 1);
+
+        // Sessions added by the watcher are searchable right away
+        write("beta", "Add websocket reconnect");
+        store.update_session(&project_dir.join("beta.jsonl")).await;
+        let found = store.search("websocket");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id.as_str(), "beta");
+        assert_eq!(store.search("board").len(), 2);
+    }
+
     #[tokio::test]
     async fn test_initial_load_with_stats() {
         let dir = tempdir().unwrap();
@@ -1689,6 +2965,92 @@ mod tests {
         assert_eq!(stats.session_count(), 5);
     }
 
+    #[tokio::test]
+    async fn test_excluded_projects_drop_out_of_stats() {
+        use crate::models::session::{ProjectId, SessionMetadata};
+
+        let dir = tempdir().unwrap();
+        let claude_home = dir.path();
+        std::fs::write(
+            claude_home.join("stats-cache.json"),
+            r#"{"version": 2, "totalSessions": 5, "totalMessages": 100, "modelUsage": {"claude-sonnet-4-5": {"inputTokens": 6000, "outputTokens": 4000}}}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            claude_home.join("settings.json"),
+            r#"{"excludeProjects": ["/tmp/scratch*"]}"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(claude_home.join("projects")).unwrap();
+
+        let store = DataStore::with_defaults(claude_home.to_path_buf(), None);
+        store.initial_load().await;
+
+        for (id, project) in [("scratch", "/tmp/scratch-1"), ("api", "/code/api")] {
+            let mut meta = SessionMetadata::from_path(
+                PathBuf::from(format!("/{}.jsonl", id)),
+                ProjectId::from(project),
+            );
+            meta.id = id.into();
+            meta.models_used = vec!["claude-sonnet-4-5".to_string()];
+            meta.message_count = 10;
+            meta.input_tokens = 1000;
+            meta.output_tokens = 500;
+            meta.total_tokens = 1500;
+            store.sessions.insert(meta.id.clone(), Arc::new(meta));
+        }
+        store.reload_stats().await;
+
+        // Excluded session no longer counts toward StatsCache totals
+        let stats = store.stats().unwrap();
+        assert_eq!(stats.session_count(), 4);
+        assert_eq!(stats.message_count(), 90);
+        assert_eq!(stats.total_tokens(), 8500);
+        assert_eq!(store.excluded_project_count(), 1);
+        assert_eq!(store.aggregate_sessions().len(), 1);
+        assert!(store
+            .projects_leaderboard()
+            .iter()
+            .all(|e| e.project_name != "scratch-1"));
+
+        // ...but stays browsable
+        assert!(store.sessions_by_project().contains_key("/tmp/scratch-1"));
+    }
+
+    #[tokio::test]
+    async fn test_glob_filtered_projects_drop_out_of_stats() {
+        let dir = tempdir().unwrap();
+        let claude_home = dir.path();
+        std::fs::write(
+            claude_home.join("stats-cache.json"),
+            r#"{"version": 2, "totalSessions": 5, "totalMessages": 100, "modelUsage": {"claude-sonnet-4-5": {"inputTokens": 6000, "outputTokens": 4000}}}"#,
+        )
+        .unwrap();
+        for (project, id) in [("-repo-services-api", "api"), ("-repo-apps-web", "web")] {
+            let project_dir = claude_home.join("projects").join(project);
+            std::fs::create_dir_all(&project_dir).unwrap();
+            std::fs::write(
+                project_dir.join(format!("{}.jsonl", id)),
+                format!(
+                    r#"{{"type": "user", "sessionId": "{}", "message": {{"content": "Hi"}}}}"#,
+                    id
+                ),
+            )
+            .unwrap();
+        }
+
+        let store = DataStore::with_defaults(claude_home.to_path_buf(), None)
+            .with_project_filter(ProjectGlobFilter::new(&["services/*"], &[]));
+        store.initial_load().await;
+
+        assert_eq!(store.session_count(), 1);
+        assert_eq!(store.stats().unwrap().session_count(), 4);
+
+        // Still subtracted when stats-cache.json is reloaded
+        store.reload_stats().await;
+        assert_eq!(store.stats().unwrap().session_count(), 4);
+    }
+
     #[tokio::test]
     async fn test_event_bus_subscription() {
         let dir = tempdir().unwrap();
@@ -1734,13 +3096,23 @@ mod tests {
                 first_user_message: None,
                 has_subagents: false,
                 parent_session_id: None,
+                resumed_from: None,
+                compaction_count: 0,
                 duration_seconds: Some(1800),
                 branch: None,
                 tool_usage: std::collections::HashMap::new(),
                 tool_token_usage: std::collections::HashMap::new(),
+                tool_followup_tokens: std::collections::HashMap::new(),
                 source_tool: Default::default(),
                 lines_added: 0,
                 lines_removed: 0,
+                tool_result_bytes: 0,
+                peak_context_tokens: 0,
+                peak_context_pct: 0.0,
+                malformed_lines: 0,
+                estimated_extra_tokens: 0,
+                cwd: None,
+                author: None,
             };
             store.sessions.insert(session.id.clone(), Arc::new(session));
         }
@@ -1804,13 +3176,23 @@ mod tests {
                 first_user_message: None,
                 has_subagents: false,
                 parent_session_id: None,
+                resumed_from: None,
+                compaction_count: 0,
                 duration_seconds: Some(1800),
                 branch: None,
                 tool_usage: std::collections::HashMap::new(),
                 tool_token_usage: std::collections::HashMap::new(),
+                tool_followup_tokens: std::collections::HashMap::new(),
                 source_tool: Default::default(),
                 lines_added: 0,
                 lines_removed: 0,
+                tool_result_bytes: 0,
+                peak_context_tokens: 0,
+                peak_context_pct: 0.0,
+                malformed_lines: 0,
+                estimated_extra_tokens: 0,
+                cwd: None,
+                author: None,
             };
             store.sessions.insert(session.id.clone(), Arc::new(session));
         }
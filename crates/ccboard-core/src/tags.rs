@@ -0,0 +1,181 @@
+//! Session tags — persisted to ~/.ccboard/tags.json
+//!
+//! Free-form labels ("bug", "spike", "client-acme") keyed by session ID. Like
+//! pins, entries are kept when the session file disappears; callers only look
+//! tags up for loaded sessions, so stale entries are simply never shown.
+//!
+//! Stored in `~/.ccboard` with the pins and bookmarks, not under `~/.claude`.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// Search prefix that filters sessions by tag (`tag:bug`)
+pub const TAG_FILTER_PREFIX: &str = "tag:";
+
+/// Persisted session → tags map
+///
+/// Backed by `~/.ccboard/tags.json` (a JSON object of session ID → array of
+/// tags). `set` persists immediately (atomic write); reads are in-memory.
+#[derive(Debug, Default)]
+pub struct TagStore {
+    path: PathBuf,
+    tags: BTreeMap<String, Vec<String>>,
+}
+
+impl TagStore {
+    /// Load from `path`.  If the file does not exist, an empty store is returned.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let tags = if path.exists() {
+            let raw = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            serde_json::from_str::<BTreeMap<String, Vec<String>>>(&raw)
+                .with_context(|| format!("Failed to parse {}", path.display()))?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self { path, tags })
+    }
+
+    /// Return the filesystem path backing this store
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Tags of a session, in the order they were entered
+    pub fn tags_for(&self, session_id: &str) -> &[String] {
+        self.tags.get(session_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Return `true` if the session carries `tag` (case-insensitive)
+    pub fn has_tag(&self, session_id: &str, tag: &str) -> bool {
+        self.tags_for(session_id)
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Replace a session's tags (normalized with [`normalize_tags`]).
+    /// An empty list removes the entry.  Persists immediately.
+    pub fn set(&mut self, session_id: &str, tags: &[String]) -> Result<()> {
+        let tags = normalize_tags(tags);
+        if tags.is_empty() {
+            self.tags.remove(session_id);
+        } else {
+            self.tags.insert(session_id.to_string(), tags);
+        }
+        self.save()
+    }
+
+    /// Number of tagged sessions
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    /// Whether no session is tagged
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    fn save(&self) -> Result<()> {
+        crate::persist::write_json_atomic(&self.path, &self.tags)
+    }
+}
+
+/// Clean up user-entered tags: trimmed, lowercase, a leading `#` dropped,
+/// inner whitespace replaced by `-`, blanks and duplicates removed
+pub fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag
+            .trim()
+            .trim_start_matches('#')
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join("-")
+            .to_lowercase();
+        if !tag.is_empty() && !out.contains(&tag) {
+            out.push(tag);
+        }
+    }
+    out
+}
+
+/// Split a search query into its `tag:` filters and the remaining text
+///
+/// `"tag:bug parser tag:client-acme"` → `(["bug", "client-acme"], "parser")`.
+pub fn parse_tag_filters(query: &str) -> (Vec<String>, String) {
+    let mut tags = Vec::new();
+    let mut rest = Vec::new();
+    for word in query.split_whitespace() {
+        match word
+            .get(..TAG_FILTER_PREFIX.len())
+            .filter(|p| p.eq_ignore_ascii_case(TAG_FILTER_PREFIX))
+        {
+            Some(_) if word.len() > TAG_FILTER_PREFIX.len() => {
+                tags.push(word[TAG_FILTER_PREFIX.len()..].to_lowercase())
+            }
+            _ => rest.push(word),
+        }
+    }
+    (tags, rest.join(" "))
+}
+
+/// Stable index in `0..palette_len` for a tag, so a tag keeps its chip color
+/// across runs and between the TUI and the web UI
+pub fn tag_color_index(tag: &str, palette_len: usize) -> usize {
+    // FNV-1a: std's hasher is randomly seeded
+    let hash = tag.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    (hash % palette_len.max(1) as u64) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn test_set_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tags.json");
+
+        let mut store = TagStore::load(&path).unwrap();
+        assert!(store.is_empty());
+
+        store
+            .set(
+                "sess-a",
+                &strings(&["Bug", " #spike ", "bug", "client acme", ""]),
+            )
+            .unwrap();
+        store.set("sess-b", &strings(&["spike"])).unwrap();
+        store.set("sess-b", &[]).unwrap();
+
+        let reloaded = TagStore::load(&path).unwrap();
+        assert_eq!(reloaded.len(), 1);
+        assert_eq!(reloaded.tags_for("sess-a"), ["bug", "spike", "client-acme"]);
+        assert!(reloaded.has_tag("sess-a", "SPIKE"));
+        assert!(reloaded.tags_for("sess-b").is_empty());
+    }
+
+    #[test]
+    fn test_parse_tag_filters() {
+        assert_eq!(
+            parse_tag_filters("tag:bug parser TAG:Client-Acme"),
+            (strings(&["bug", "client-acme"]), "parser".to_string())
+        );
+        // A bare prefix is searched as text
+        assert_eq!(
+            parse_tag_filters("tag: login"),
+            (Vec::new(), "tag: login".to_string())
+        );
+        assert_eq!(tag_color_index("bug", 6), tag_color_index("bug", 6));
+        assert!(tag_color_index("spike", 6) < 6);
+    }
+}
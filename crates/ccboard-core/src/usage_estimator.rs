@@ -49,6 +49,17 @@ impl SubscriptionPlan {
         }
     }
 
+    /// Short identifier as written in settings.json (`pro`, `max5x`, ...)
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Pro => "pro",
+            Self::Max5x => "max5x",
+            Self::Max20x => "max20x",
+            Self::Api => "api",
+            Self::Unknown => "unknown",
+        }
+    }
+
     /// Get display name
     pub fn display_name(self) -> &'static str {
         match self {
@@ -94,6 +105,29 @@ impl UsageEstimate {
         self.budget_usd
             .map(|budget| (self.cost_month / budget * 100.0).min(100.0))
     }
+
+    /// Month-to-date cost extrapolated linearly to the end of `today`'s month
+    pub fn projected_month_cost(&self, today: NaiveDate) -> f64 {
+        self.cost_month / today.day() as f64 * days_in_month(today) as f64
+    }
+
+    /// Projected month cost as a percentage of the budget, not capped at 100
+    pub fn percent_projected(&self, today: NaiveDate) -> Option<f64> {
+        self.budget_usd
+            .map(|budget| self.projected_month_cost(today) / budget * 100.0)
+    }
+}
+
+/// Number of days in the month containing `date`
+pub fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|next| next.pred_opt())
+        .map_or(31, |last| last.day())
 }
 
 /// Calculate usage estimate from billing blocks
@@ -175,6 +209,30 @@ mod tests {
         assert_eq!(estimate.percent_month(), Some(80.0));
     }
 
+    #[test]
+    fn test_projected_month_cost() {
+        let estimate = UsageEstimate {
+            cost_month: 10.0,
+            plan: SubscriptionPlan::Pro,
+            budget_usd: Some(20.0),
+            ..Default::default()
+        };
+
+        // 10 days into a 30-day month: $1/day → $30
+        let today = NaiveDate::from_ymd_opt(2025, 6, 10).unwrap();
+        assert!((estimate.projected_month_cost(today) - 30.0).abs() < 1e-9);
+        assert!((estimate.percent_projected(today).unwrap() - 150.0).abs() < 1e-9);
+
+        assert_eq!(
+            days_in_month(NaiveDate::from_ymd_opt(2024, 2, 3).unwrap()),
+            29
+        );
+        assert_eq!(
+            days_in_month(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()),
+            31
+        );
+    }
+
     #[test]
     fn test_no_budget() {
         let estimate = UsageEstimate {
@@ -1,28 +1,30 @@
 //! File watcher for Claude Code data changes
 //!
-//! Uses notify with adaptive debouncing for efficient change detection.
+//! Changes are batched: events are collected until the files go quiet for
+//! `debounce_delay` (or `max_debounce_delay` passes during a continuous
+//! burst, such as a git checkout), then each affected path is handled once and
+//! a single `DataEvent::FilesChanged` lists them.
 
 use crate::event::{ConfigScope, DataEvent, EventBus};
 use crate::store::DataStore;
 use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
+use tokio::time::Instant;
 use tracing::{debug, error, info, trace};
 
 /// Configuration for the file watcher
 #[derive(Debug, Clone)]
 pub struct WatcherConfig {
-    /// Base debounce delay
+    /// Quiet period that closes a batch of changes
     pub debounce_delay: Duration,
 
-    /// Maximum debounce delay during burst
+    /// Longest a batch is held while changes keep arriving
     pub max_debounce_delay: Duration,
 
-    /// Burst detection threshold (events per second)
-    pub burst_threshold: u32,
-
     /// Additional paths to watch (e.g. ~/.ccboard/ for live-sessions.json)
     pub extra_watch_paths: Vec<PathBuf>,
 }
@@ -42,9 +44,8 @@ impl Default for WatcherConfig {
             .unwrap_or_default();
 
         Self {
-            debounce_delay: Duration::from_millis(500),
+            debounce_delay: Duration::from_millis(300),
             max_debounce_delay: Duration::from_secs(3),
-            burst_threshold: 10,
             extra_watch_paths,
         }
     }
@@ -83,32 +84,16 @@ impl FileWatcher {
             shutdown_tx,
         };
 
-        // Watch paths - non-recursive for performance (26k+ files in ~/.claude)
-        // Watch top-level files (stats-cache.json, settings.json)
-        file_watcher.watch_path(&claude_home, RecursiveMode::NonRecursive)?;
-
-        // Watch projects directory non-recursively to detect new projects
-        let projects_dir = claude_home.join("projects");
-        if projects_dir.exists() {
-            file_watcher.watch_path(&projects_dir, RecursiveMode::NonRecursive)?;
-
-            // Watch each project directory non-recursively to detect new sessions
-            if let Ok(entries) = std::fs::read_dir(&projects_dir) {
-                for entry in entries.flatten() {
-                    if entry.path().is_dir() {
-                        // Watch project directory non-recursively (only .jsonl files at this level)
-                        let _ = file_watcher.watch_path(&entry.path(), RecursiveMode::NonRecursive);
-                    }
+        // Every home the store loads from, so sessions in extra homes live-update too
+        file_watcher.watch_claude_home(&claude_home)?;
+        for home in store.claude_homes() {
+            if home != claude_home {
+                if let Err(e) = file_watcher.watch_claude_home(home) {
+                    debug!(path = %home.display(), error = %e, "Cannot watch extra Claude home");
                 }
             }
         }
 
-        // Watch cache directory for SQLite changes
-        let cache_dir = claude_home.join("cache");
-        if cache_dir.exists() {
-            file_watcher.watch_path(&cache_dir, RecursiveMode::NonRecursive)?;
-        }
-
         // Watch project-specific .claude directory non-recursively
         if let Some(ref proj) = project_path {
             let claude_dir = proj.join(".claude");
@@ -138,18 +123,16 @@ impl FileWatcher {
         // Spawn event processor
         let event_bus = store.event_bus().clone();
         tokio::spawn(async move {
-            let mut debounce_state = DebounceState::new(config);
+            let mut batch = ChangeBatch::default();
 
             loop {
+                let deadline = batch.deadline(&config);
                 tokio::select! {
                     Some(result) = event_rx.recv() => {
                         match result {
                             Ok(event) => {
                                 if let Some((data_event, path)) = Self::process_event(&event, &claude_home, project_path.as_deref()) {
-                                    if debounce_state.should_emit(&data_event) {
-                                        debug!(?data_event, "Emitting file change event");
-                                        Self::handle_event(data_event, Some(&path), &store, &event_bus).await;
-                                    }
+                                    batch.push(data_event, path, Instant::now());
                                 }
                             }
                             Err(e) => {
@@ -158,6 +141,15 @@ impl FileWatcher {
                             }
                         }
                     }
+                    _ = tokio::time::sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => {
+                        let changes = batch.take();
+                        debug!(count = changes.len(), "Emitting batched file changes");
+                        let paths = changes.iter().map(|(_, path)| path.clone()).collect();
+                        for (data_event, path) in changes {
+                            Self::handle_event(data_event, Some(&path), &store, &event_bus).await;
+                        }
+                        event_bus.publish(DataEvent::FilesChanged(paths));
+                    }
                     _ = shutdown_rx.recv() => {
                         info!("File watcher shutting down");
                         break;
@@ -169,6 +161,36 @@ impl FileWatcher {
         Ok(file_watcher)
     }
 
+    /// Watch a Claude home's top-level files, projects and cache
+    fn watch_claude_home(&mut self, claude_home: &Path) -> Result<(), notify::Error> {
+        // Non-recursive for performance (26k+ files in ~/.claude)
+        // Watch top-level files (stats-cache.json, settings.json)
+        self.watch_path(claude_home, RecursiveMode::NonRecursive)?;
+
+        // Watch projects directory non-recursively to detect new projects
+        let projects_dir = claude_home.join("projects");
+        if projects_dir.exists() {
+            self.watch_path(&projects_dir, RecursiveMode::NonRecursive)?;
+
+            // Watch each project directory non-recursively to detect new sessions
+            if let Ok(entries) = std::fs::read_dir(&projects_dir) {
+                for entry in entries.flatten() {
+                    if entry.path().is_dir() {
+                        // Watch project directory non-recursively (only .jsonl files at this level)
+                        let _ = self.watch_path(&entry.path(), RecursiveMode::NonRecursive);
+                    }
+                }
+            }
+        }
+
+        // Watch cache directory for SQLite changes
+        let cache_dir = claude_home.join("cache");
+        if cache_dir.exists() {
+            self.watch_path(&cache_dir, RecursiveMode::NonRecursive)?;
+        }
+        Ok(())
+    }
+
     fn watch_path(&mut self, path: &Path, mode: RecursiveMode) -> Result<(), notify::Error> {
         self._watcher.watch(path, mode)?;
         debug!(path = %path.display(), "Watching path");
@@ -223,7 +245,9 @@ impl FileWatcher {
         }
 
         // Global settings
-        if *path == claude_home.join("settings.json") {
+        if *path == claude_home.join("settings.json")
+            || *path == claude_home.join(crate::project_display::ALIASES_FILE)
+        {
             return Some((DataEvent::ConfigChanged(ConfigScope::Global), path.clone()));
         }
 
@@ -271,9 +295,11 @@ impl FileWatcher {
                 store.reload_stats().await;
             }
             DataEvent::SessionUpdated(_id) | DataEvent::SessionCreated(_id) => {
-                // Update session with path
+                // update_session publishes SessionCreated/SessionUpdated itself,
+                // keyed by the parsed session ID, so don't publish twice
                 if let Some(p) = path {
                     store.update_session(p).await;
+                    return;
                 }
             }
             DataEvent::ConfigChanged(_scope) => {
@@ -298,68 +324,44 @@ impl FileWatcher {
     }
 }
 
-/// Debounce state for adaptive debouncing
-struct DebounceState {
-    config: WatcherConfig,
-    last_events: std::collections::HashMap<String, std::time::Instant>,
-    event_count_window: std::collections::VecDeque<std::time::Instant>,
+/// File changes collected since the last flush
+///
+/// Each path is kept once, with the event of its first change: handling a
+/// path re-reads it in full (or from its last parsed offset), so later
+/// changes to the same file are covered.
+#[derive(Default)]
+struct ChangeBatch {
+    changes: Vec<(DataEvent, PathBuf)>,
+    seen: HashSet<PathBuf>,
+    first_at: Option<Instant>,
+    last_at: Option<Instant>,
 }
 
-impl DebounceState {
-    fn new(config: WatcherConfig) -> Self {
-        Self {
-            config,
-            last_events: std::collections::HashMap::new(),
-            event_count_window: std::collections::VecDeque::new(),
-        }
-    }
-
-    fn should_emit(&mut self, event: &DataEvent) -> bool {
-        let now = std::time::Instant::now();
-        let key = Self::event_key(event);
-
-        // Track event rate for burst detection
-        self.event_count_window.push_back(now);
-        while self
-            .event_count_window
-            .front()
-            .map(|t| now.duration_since(*t) > Duration::from_secs(1))
-            .unwrap_or(false)
-        {
-            self.event_count_window.pop_front();
-        }
-
-        // Calculate adaptive delay
-        let delay = if self.event_count_window.len() as u32 > self.config.burst_threshold {
-            self.config.max_debounce_delay
+impl ChangeBatch {
+    fn push(&mut self, event: DataEvent, path: PathBuf, now: Instant) {
+        self.first_at.get_or_insert(now);
+        self.last_at = Some(now);
+        if self.seen.insert(path.clone()) {
+            self.changes.push((event, path));
         } else {
-            self.config.debounce_delay
-        };
-
-        // Check if enough time has passed
-        if let Some(last) = self.last_events.get(&key) {
-            if now.duration_since(*last) < delay {
-                trace!(key = %key, "Debouncing event");
-                return false;
-            }
+            trace!(path = %path.display(), "Coalescing file change");
         }
+    }
 
-        self.last_events.insert(key, now);
-        true
+    /// When the batch is due: after a quiet `debounce_delay`, at most
+    /// `max_debounce_delay` after its first change. `None` when empty.
+    fn deadline(&self, config: &WatcherConfig) -> Option<Instant> {
+        let first = self.first_at?;
+        let last = self.last_at?;
+        Some((last + config.debounce_delay).min(first + config.max_debounce_delay))
     }
 
-    fn event_key(event: &DataEvent) -> String {
-        match event {
-            DataEvent::StatsUpdated => "stats".to_string(),
-            DataEvent::SessionCreated(id) | DataEvent::SessionUpdated(id) => {
-                format!("session:{}", id)
-            }
-            DataEvent::ConfigChanged(scope) => format!("config:{:?}", scope),
-            DataEvent::AnalyticsUpdated => "analytics".to_string(),
-            DataEvent::LoadCompleted => "load".to_string(),
-            DataEvent::WatcherError(_) => "error".to_string(),
-            DataEvent::LiveSessionStatusChanged => "live_sessions".to_string(),
-        }
+    /// Changes in arrival order, leaving the batch empty
+    fn take(&mut self) -> Vec<(DataEvent, PathBuf)> {
+        self.seen.clear();
+        self.first_at = None;
+        self.last_at = None;
+        std::mem::take(&mut self.changes)
     }
 }
 
@@ -368,23 +370,45 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_debounce_state_basic() {
+    fn test_change_batch_coalesces() {
         let config = WatcherConfig {
             debounce_delay: Duration::from_millis(100),
             max_debounce_delay: Duration::from_millis(500),
-            burst_threshold: 5,
             extra_watch_paths: vec![],
         };
-        let mut state = DebounceState::new(config);
-
-        // First event should pass
-        assert!(state.should_emit(&DataEvent::StatsUpdated));
-
-        // Immediate second should be debounced
-        assert!(!state.should_emit(&DataEvent::StatsUpdated));
-
-        // Different event type should pass
-        assert!(state.should_emit(&DataEvent::SessionUpdated("test".into())));
+        let mut batch = ChangeBatch::default();
+        assert!(batch.deadline(&config).is_none());
+
+        let start = Instant::now();
+        let session = PathBuf::from("/home/user/.claude/projects/-p/a.jsonl");
+        let stats = PathBuf::from("/home/user/.claude/stats-cache.json");
+        batch.push(
+            DataEvent::SessionUpdated("a".into()),
+            session.clone(),
+            start,
+        );
+        assert_eq!(
+            batch.deadline(&config),
+            Some(start + Duration::from_millis(100))
+        );
+
+        // A steady stream keeps pushing the quiet deadline back, up to the cap
+        for ms in (50..=450).step_by(50) {
+            let path = if ms == 200 { &stats } else { &session };
+            batch.push(
+                DataEvent::SessionUpdated("a".into()),
+                path.clone(),
+                start + Duration::from_millis(ms),
+            );
+        }
+        assert_eq!(
+            batch.deadline(&config),
+            Some(start + Duration::from_millis(500))
+        );
+
+        let paths: Vec<PathBuf> = batch.take().into_iter().map(|(_, p)| p).collect();
+        assert_eq!(paths, vec![session, stats]);
+        assert!(batch.deadline(&config).is_none());
     }
 
     #[test]
@@ -1,10 +1,14 @@
 //! TUI Application state and event loop
 
-use crate::components::{CommandPalette, ConfirmDialog, HelpModal, Spinner, ToastManager};
+use crate::components::{
+    CommandPalette, ConfirmDialog, ExportPrompt, ExportTarget, HelpModal, Spinner, ToastManager,
+};
 use crate::keybindings::{KeyAction, KeyBindings};
 use ccboard_core::models::config::ColorScheme;
-use ccboard_core::{DataEvent, DataStore};
+use ccboard_core::models::SessionMetadata;
+use ccboard_core::{DataEvent, DataStore, LoadReport};
 use std::collections::VecDeque;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 
@@ -104,6 +108,25 @@ impl Tab {
         }
     }
 
+    /// Lowercase name accepted by `ccboard tui --tab`
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Tab::Dashboard => "dashboard",
+            Tab::Sessions => "sessions",
+            Tab::Config => "config",
+            Tab::Hooks => "hooks",
+            Tab::Agents => "agents",
+            Tab::Costs => "costs",
+            Tab::History => "history",
+            Tab::Mcp => "mcp",
+            Tab::Analytics => "analytics",
+            Tab::Plugins => "plugins",
+            Tab::Activity => "activity",
+            Tab::Search => "search",
+            Tab::Brain => "brain",
+        }
+    }
+
     pub fn shortcut(&self) -> char {
         match self {
             Tab::Dashboard => '1',
@@ -141,6 +164,58 @@ impl Tab {
     }
 }
 
+impl std::str::FromStr for Tab {
+    type Err = String;
+
+    /// Parse a tab slug (case-insensitive); "tools" is accepted for Agents
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.trim().to_ascii_lowercase();
+        if name == "tools" {
+            return Ok(Tab::Agents);
+        }
+        Tab::all()
+            .iter()
+            .copied()
+            .find(|tab| tab.slug() == name)
+            .ok_or_else(|| {
+                let valid: Vec<&str> = Tab::all().iter().map(Tab::slug).collect();
+                format!("unknown tab '{}' (valid: {})", name, valid.join(", "))
+            })
+    }
+}
+
+/// Tab (and sub-view) the TUI opens on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InitialView {
+    pub tab: Tab,
+    pub analytics_view: Option<crate::tabs::analytics::AnalyticsView>,
+    /// Theme for this run (`--theme`), over config.toml and saved preferences
+    pub color_scheme: Option<ColorScheme>,
+}
+
+impl InitialView {
+    /// Validate `sub_view` against `tab`
+    ///
+    /// Only the Analytics tab has named sub-views.
+    pub fn new(tab: Tab, sub_view: Option<&str>) -> Result<Self, String> {
+        let analytics_view = match (tab, sub_view) {
+            (_, None) => None,
+            (Tab::Analytics, Some(name)) => Some(name.parse()?),
+            (tab, Some(_)) => {
+                return Err(format!(
+                    "--sub-view is only supported with --tab analytics (got --tab {})",
+                    tab.slug()
+                ))
+            }
+        };
+        Ok(Self {
+            tab,
+            analytics_view,
+            color_scheme: None,
+        })
+    }
+}
+
 /// TUI Application state
 pub struct App {
     /// Data store reference
@@ -164,6 +239,16 @@ pub struct App {
     /// Command palette (k9s-style `:` prefix)
     pub command_palette: CommandPalette,
 
+    /// Destination prompt for palette exports
+    pub export_prompt: ExportPrompt,
+
+    /// Result of a running `DataStore::reload` (R / "Reload all")
+    reload_rx: Option<tokio::sync::oneshot::Receiver<LoadReport>>,
+
+    /// Session selected in the active tab (Sessions/History), captured when the
+    /// command palette opens
+    pub selected_session: Option<Arc<SessionMetadata>>,
+
     /// Help modal (toggle with `?`)
     pub help_modal: HelpModal,
 
@@ -215,8 +300,14 @@ impl App {
             keybindings.load_custom(custom_keybindings);
         }
 
-        // Load persisted color scheme (fallback to Dark if missing)
+        // Load persisted color scheme (fallback to Dark if missing);
+        // `[theme] colorScheme` in config.toml takes precedence
         let prefs = store.load_preferences();
+        let color_scheme = store
+            .ccboard_config()
+            .theme
+            .color_scheme
+            .unwrap_or(prefs.color_scheme);
 
         Self {
             store,
@@ -226,6 +317,9 @@ impl App {
             needs_refresh: true,
             status_message: None,
             command_palette: CommandPalette::new(),
+            export_prompt: ExportPrompt::new(),
+            reload_rx: None,
+            selected_session: None,
             help_modal: HelpModal::new(),
             is_loading: true,
             loading_message: Some("Loading sessions...".to_string()),
@@ -235,7 +329,7 @@ impl App {
             live_sessions_cache: Vec::new(),
             last_live_refresh: std::time::Instant::now(),
             search_history: VecDeque::with_capacity(50),
-            color_scheme: prefs.color_scheme,
+            color_scheme,
             keybindings,
             search_tab: crate::tabs::SearchTab::new(),
             brain_tab: crate::tabs::BrainTab::new(),
@@ -262,6 +356,14 @@ impl App {
     ) -> bool {
         use crate::components::command_palette::CommandAction;
 
+        // Export destination prompt captures all keys while open
+        if self.export_prompt.is_visible() {
+            if let Some((target, path)) = self.export_prompt.handle_key(key) {
+                self.run_export(target, &path);
+            }
+            return true;
+        }
+
         // If command palette is visible, handle keys there first
         if self.command_palette.is_visible() {
             if let Some(action) = self.command_palette.handle_key(key) {
@@ -269,6 +371,7 @@ impl App {
                 match action {
                     CommandAction::Quit => self.should_quit = true,
                     CommandAction::RefreshData => self.needs_refresh = true,
+                    CommandAction::ReloadAll => self.start_reload(),
                     CommandAction::GoToTab(tab) => self.active_tab = tab,
                     CommandAction::Search(query) => {
                         // TODO: Implement search functionality when History/Sessions support it
@@ -278,6 +381,13 @@ impl App {
                         // Show palette with empty query to list all commands
                         self.command_palette.show();
                     }
+                    CommandAction::Export(target) => {
+                        let exports_dir = dirs::home_dir()
+                            .unwrap_or_else(|| std::path::PathBuf::from("."))
+                            .join(".claude/exports");
+                        self.export_prompt
+                            .show(target, &target.default_path(&exports_dir));
+                    }
                 }
             }
             return true;
@@ -316,15 +426,9 @@ impl App {
                 self.store.clear_session_content_cache();
                 self.info_toast("♻ Reloading data...");
             }
+            KeyAction::ReloadAll => self.start_reload(),
             KeyAction::ThemeToggle => {
-                self.color_scheme = match self.color_scheme {
-                    ColorScheme::Dark => ColorScheme::Light,
-                    ColorScheme::Light => ColorScheme::Dark,
-                };
-                let theme_name = match self.color_scheme {
-                    ColorScheme::Dark => "Dark",
-                    ColorScheme::Light => "Light",
-                };
+                self.color_scheme = self.color_scheme.next();
                 // Persist the new color scheme
                 let prefs = ccboard_core::preferences::CcboardPreferences {
                     color_scheme: self.color_scheme,
@@ -332,7 +436,7 @@ impl App {
                 if let Err(e) = self.store.save_preferences(&prefs) {
                     tracing::warn!(error = %e, "Failed to persist color scheme preference");
                 }
-                self.info_toast(format!("Theme: {}", theme_name));
+                self.info_toast(format!("Theme: {}", self.color_scheme.label()));
             }
             KeyAction::NextTab => {
                 self.next_tab();
@@ -376,6 +480,60 @@ impl App {
         self.active_tab = Tab::from_index((idx + Tab::all().len() - 1) % Tab::all().len());
     }
 
+    /// Write a palette export and report the outcome as a toast
+    fn run_export(&mut self, target: ExportTarget, path: &Path) {
+        let result = match target.format_for(path) {
+            Ok(format) => self.write_export(target, format, path),
+            Err(e) => Err(anyhow::anyhow!(e)),
+        };
+        match result {
+            Ok(()) => self.success_toast(format!("✓ Exported to {}", path.display())),
+            Err(e) => self.error_toast(format!("✗ Export failed: {:#}", e)),
+        }
+    }
+
+    fn write_export(&self, target: ExportTarget, format: &str, path: &Path) -> anyhow::Result<()> {
+        use ccboard_core::export;
+
+        match target {
+            ExportTarget::Sessions => {
+                let sessions = self.store.recent_sessions(usize::MAX);
+                match format {
+                    "json" => export::export_sessions_to_json(&sessions, path),
+                    "md" => export::export_sessions_to_markdown(&sessions, path),
+                    _ => export::export_sessions_to_csv(&sessions, path),
+                }
+            }
+            ExportTarget::Stats => {
+                let stats = self
+                    .store
+                    .stats()
+                    .ok_or_else(|| anyhow::anyhow!("no stats loaded"))?;
+                match format {
+                    "json" => export::export_stats_to_json(&stats, path),
+                    "md" => export::export_stats_to_markdown(&stats, path, &self.store.currency()),
+                    _ => export::export_stats_to_csv(&stats, path),
+                }
+            }
+            ExportTarget::Conversation => {
+                let session = self
+                    .selected_session
+                    .clone()
+                    .ok_or_else(|| anyhow::anyhow!("no session selected"))?;
+                // Same blocking load as the conversation viewer
+                let messages = tokio::task::block_in_place(|| {
+                    tokio::runtime::Handle::current()
+                        .block_on(self.store.load_session_content(&session.id))
+                })?;
+                match format {
+                    "json" => export::export_conversation_to_json(&messages, &session, path),
+                    "html" => export::export_conversation_to_html(&messages, &session, path),
+                    _ => export::export_conversation_to_markdown(&messages, &session, path),
+                }
+            }
+        }
+    }
+
     /// Add success toast notification
     pub fn success_toast(&mut self, message: impl Into<String>) {
         self.toast_manager
@@ -400,29 +558,70 @@ impl App {
             .push(crate::components::Toast::info(message));
     }
 
+    /// Re-scan everything in the background, unless a reload is running
+    pub fn start_reload(&mut self) {
+        if self.reload_rx.is_some() {
+            self.info_toast("Reload already in progress");
+            return;
+        }
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let store = Arc::clone(&self.store);
+        tokio::spawn(async move {
+            let _ = tx.send(store.reload().await);
+        });
+        self.reload_rx = Some(rx);
+        self.info_toast("♻ Reloading all data...");
+    }
+
+    /// Toast the outcome of a finished `start_reload`
+    fn poll_reload(&mut self) {
+        let Some(rx) = self.reload_rx.as_mut() else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(report) => {
+                self.reload_rx = None;
+                let errors = report.errors.len();
+                if errors == 0 {
+                    self.success_toast(format!("✓ Reloaded {} sessions", report.sessions_scanned));
+                } else {
+                    self.warning_toast(format!(
+                        "Reloaded {} sessions with {} error(s)",
+                        report.sessions_scanned, errors
+                    ));
+                }
+            }
+            Err(tokio::sync::oneshot::error::TryRecvError::Empty) => {}
+            Err(tokio::sync::oneshot::error::TryRecvError::Closed) => {
+                self.reload_rx = None;
+                self.error_toast("Reload failed");
+            }
+        }
+    }
+
     /// Check for data events (non-blocking)
     pub fn poll_events(&mut self) {
+        self.poll_reload();
         while let Ok(event) = self.event_rx.try_recv() {
             match event {
+                // Per-file watcher events: the batch's FilesChanged follows and
+                // refreshes once for all of them
                 DataEvent::StatsUpdated
                 | DataEvent::SessionCreated(_)
-                | DataEvent::SessionUpdated(_)
-                | DataEvent::AnalyticsUpdated => {
-                    self.needs_refresh = true;
-                }
+                | DataEvent::SessionUpdated(_) => {}
                 DataEvent::ConfigChanged(_) => {
-                    self.needs_refresh = true;
                     self.info_toast("Settings reloaded");
                 }
                 DataEvent::WatcherError(msg) => {
                     self.status_message = Some(format!("Watcher error: {}", msg));
                 }
-                DataEvent::LoadCompleted => {
+                DataEvent::AnalyticsUpdated
+                | DataEvent::LoadCompleted
+                | DataEvent::FilesChanged(_) => {
                     self.needs_refresh = true;
                 }
                 DataEvent::LiveSessionStatusChanged => {
                     // Trigger immediate live session cache refresh on next render
-                    self.needs_refresh = true;
                     self.last_live_refresh = std::time::Instant::now()
                         .checked_sub(std::time::Duration::from_secs(10))
                         .unwrap_or_else(std::time::Instant::now);
@@ -455,3 +654,35 @@ impl App {
         &self.live_sessions_cache
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tabs::analytics::AnalyticsView;
+
+    #[test]
+    fn test_initial_view_from_cli_names() {
+        assert_eq!("Costs".parse::<Tab>(), Ok(Tab::Costs));
+        assert_eq!("tools".parse::<Tab>(), Ok(Tab::Agents));
+        for tab in Tab::all() {
+            assert_eq!(tab.slug().parse::<Tab>(), Ok(*tab));
+        }
+        let err = "billing".parse::<Tab>().unwrap_err();
+        assert!(err.contains("unknown tab 'billing'"));
+        assert!(err.contains("dashboard, sessions, analytics, costs"));
+
+        let initial = InitialView::new(Tab::Analytics, Some("heatmap")).unwrap();
+        assert_eq!(initial.analytics_view, Some(AnalyticsView::Heatmap));
+        assert_eq!(
+            InitialView::new(Tab::Analytics, Some("insights"))
+                .unwrap()
+                .analytics_view,
+            Some(AnalyticsView::Insights)
+        );
+        assert!(InitialView::new(Tab::Analytics, Some("weekly"))
+            .unwrap_err()
+            .contains("valid: overview, trends"));
+        assert!(InitialView::new(Tab::Costs, Some("trends")).is_err());
+        assert_eq!(InitialView::new(Tab::Costs, None).unwrap().tab, Tab::Costs);
+    }
+}
@@ -0,0 +1,153 @@
+//! Fenced code block rendering with syntect highlighting
+//!
+//! Shared by the conversation viewer and the session replay popup.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use std::sync::OnceLock;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Theme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+const THEME_NAME: &str = "base16-ocean.dark";
+
+/// Syntax definitions, loaded once on first use (loading takes a few ms)
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn theme() -> &'static Theme {
+    static THEME: OnceLock<Theme> = OnceLock::new();
+    THEME.get_or_init(|| {
+        ThemeSet::load_defaults()
+            .themes
+            .remove(THEME_NAME)
+            .unwrap_or_default()
+    })
+}
+
+/// Background used behind code lines so blocks stand out from prose
+fn code_background() -> Color {
+    theme()
+        .settings
+        .background
+        .map(syntect_to_ratatui_color)
+        .unwrap_or(Color::Rgb(43, 48, 59))
+}
+
+/// Whether `content` contains a triple-backtick fence
+pub fn has_code_block(content: &str) -> bool {
+    content.contains("```")
+}
+
+/// Render markdown-style content, highlighting fenced code blocks
+///
+/// Text outside fences is returned unstyled, one `Line` per input line. Code
+/// lines get language-aware colors (from the fence info string) on a distinct
+/// background; fences themselves become a dimmed language label. Unclosed
+/// fences run to the end of the content.
+pub fn render_code_blocks(content: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut code: Option<(String, Vec<&str>)> = None;
+
+    for line in content.lines() {
+        match (line.trim_start().strip_prefix("```"), code.take()) {
+            (Some(_), Some((lang, code_lines))) => {
+                lines.extend(highlight_code(&code_lines, &lang));
+            }
+            (Some(rest), None) => {
+                let lang = rest.trim().to_string();
+                if !lang.is_empty() {
+                    lines.push(Line::from(Span::styled(
+                        format!(" {} ", lang),
+                        Style::default()
+                            .fg(Color::DarkGray)
+                            .add_modifier(Modifier::ITALIC),
+                    )));
+                }
+                code = Some((lang, Vec::new()));
+            }
+            (None, Some((lang, mut code_lines))) => {
+                code_lines.push(line);
+                code = Some((lang, code_lines));
+            }
+            (None, None) => lines.push(Line::from(line.to_string())),
+        }
+    }
+
+    if let Some((lang, code_lines)) = code {
+        lines.extend(highlight_code(&code_lines, &lang));
+    }
+
+    lines
+}
+
+/// Highlight code lines for `lang` (token or file extension, plain text if unknown)
+fn highlight_code(code_lines: &[&str], lang: &str) -> Vec<Line<'static>> {
+    let syntax_set = syntax_set();
+    let syntax = syntax_set
+        .find_syntax_by_token(lang)
+        .or_else(|| syntax_set.find_syntax_by_extension(lang))
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+    let mut highlighter = HighlightLines::new(syntax, theme());
+    let bg = code_background();
+
+    let code = code_lines.join("\n");
+    LinesWithEndings::from(&code)
+        .map(|line_text| {
+            let ranges = highlighter
+                .highlight_line(line_text, syntax_set)
+                .unwrap_or_default();
+            let spans: Vec<Span<'static>> = ranges
+                .into_iter()
+                .map(|(style, text)| {
+                    Span::styled(
+                        text.trim_end_matches('\n').to_string(),
+                        Style::default()
+                            .fg(syntect_to_ratatui_color(style.foreground))
+                            .bg(bg),
+                    )
+                })
+                .filter(|span| !span.content.is_empty())
+                .collect();
+            Line::from(spans).style(Style::default().bg(bg))
+        })
+        .collect()
+}
+
+/// Convert syntect color to ratatui color
+fn syntect_to_ratatui_color(color: syntect::highlighting::Color) -> Color {
+    Color::Rgb(color.r, color.g, color.b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_code_blocks_styles_only_code() {
+        let content = "Here is the fix:\n```rust\nfn main() {}\n```\nDone.";
+        let lines = render_code_blocks(content);
+
+        // prose, language label, one code line, prose
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0].to_string(), "Here is the fix:");
+        assert_eq!(lines[0].style, Style::default());
+        assert_eq!(lines[1].to_string(), " rust ");
+        assert_eq!(lines[2].to_string(), "fn main() {}");
+        assert_eq!(lines[2].style.bg, Some(code_background()));
+        assert!(lines[2].spans.len() > 1, "rust code should be tokenized");
+        assert_eq!(lines[3].to_string(), "Done.");
+    }
+
+    #[test]
+    fn test_render_code_blocks_unclosed_fence() {
+        let lines = render_code_blocks("```\nline one\nline two");
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|l| l.style.bg == Some(code_background())));
+    }
+}
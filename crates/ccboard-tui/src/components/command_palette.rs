@@ -1,4 +1,5 @@
 use crate::app::Tab;
+use crate::components::export_prompt::ExportTarget;
 use crate::theme::Palette;
 use ccboard_core::models::config::ColorScheme;
 use crossterm::event::KeyCode;
@@ -19,10 +20,14 @@ pub enum CommandAction {
     Search(String),
     /// Refresh all data from ~/.claude
     RefreshData,
+    /// Re-scan everything from scratch (`DataStore::reload`)
+    ReloadAll,
     /// Quit the application
     Quit,
     /// Show help/all commands
     ShowHelp,
+    /// Prompt for a destination and export
+    Export(ExportTarget),
 }
 
 /// A single command definition
@@ -70,6 +75,8 @@ pub struct CommandPalette {
     visible: bool,
     /// List state for rendering
     list_state: ListState,
+    /// Whether the active tab has a selected session (enables conversation export)
+    session_selected: bool,
 }
 
 impl Default for CommandPalette {
@@ -94,6 +101,7 @@ impl CommandPalette {
             selected: 0,
             visible: false,
             list_state,
+            session_selected: false,
         }
     }
 
@@ -116,6 +124,14 @@ impl CommandPalette {
                 action: CommandAction::RefreshData,
                 tags: vec!["reload".to_string(), "update".to_string()],
             },
+            // Full reload
+            Command {
+                name: "reload-all".to_string(),
+                shortcut: "R".to_string(),
+                description: "Reload all: re-scan sessions, stats and settings".to_string(),
+                action: CommandAction::ReloadAll,
+                tags: vec!["rescan".to_string(), "settings".to_string()],
+            },
             // Help
             Command {
                 name: "help".to_string(),
@@ -124,6 +140,28 @@ impl CommandPalette {
                 action: CommandAction::ShowHelp,
                 tags: vec!["commands".to_string(), "list".to_string()],
             },
+            // Exports
+            Command {
+                name: "export-sessions".to_string(),
+                shortcut: "es".to_string(),
+                description: "Export sessions (CSV/JSON/MD)".to_string(),
+                action: CommandAction::Export(ExportTarget::Sessions),
+                tags: vec!["save".to_string(), "csv".to_string(), "json".to_string()],
+            },
+            Command {
+                name: "export-stats".to_string(),
+                shortcut: "et".to_string(),
+                description: "Export stats (CSV/JSON/MD)".to_string(),
+                action: CommandAction::Export(ExportTarget::Stats),
+                tags: vec!["save".to_string(), "usage".to_string()],
+            },
+            Command {
+                name: "export-conversation".to_string(),
+                shortcut: "ec".to_string(),
+                description: "Export current conversation (MD/JSON/HTML)".to_string(),
+                action: CommandAction::Export(ExportTarget::Conversation),
+                tags: vec!["save".to_string(), "transcript".to_string()],
+            },
             // Tab navigation
             Command {
                 name: "dashboard".to_string(),
@@ -192,9 +230,20 @@ impl CommandPalette {
     pub fn show(&mut self) {
         self.visible = true;
         self.query.clear();
-        self.results = self.commands.clone();
-        self.selected = 0;
-        self.list_state.select(Some(0));
+        self.filter_results();
+    }
+
+    /// Tell the palette whether a session is selected
+    pub fn set_session_selected(&mut self, selected: bool) {
+        self.session_selected = selected;
+    }
+
+    /// Whether a command applies in the current context
+    fn is_available(&self, cmd: &Command) -> bool {
+        match cmd.action {
+            CommandAction::Export(ExportTarget::Conversation) => self.session_selected,
+            _ => true,
+        }
     }
 
     /// Hide the command palette
@@ -254,16 +303,13 @@ impl CommandPalette {
 
     /// Filter results based on current query
     fn filter_results(&mut self) {
-        if self.query.is_empty() {
-            self.results = self.commands.clone();
-        } else {
-            self.results = self
-                .commands
-                .iter()
-                .filter(|cmd| cmd.matches(&self.query))
-                .cloned()
-                .collect();
-        }
+        self.results = self
+            .commands
+            .iter()
+            .filter(|cmd| self.is_available(cmd))
+            .filter(|cmd| self.query.is_empty() || cmd.matches(&self.query))
+            .cloned()
+            .collect();
 
         // Reset selection to first result
         self.selected = 0;
@@ -400,6 +446,29 @@ mod tests {
         assert_eq!(palette.results[0].name, "quit");
     }
 
+    #[test]
+    fn test_conversation_export_needs_selected_session() {
+        let mut palette = CommandPalette::new();
+        let names = |p: &CommandPalette| -> Vec<String> {
+            p.results.iter().map(|c| c.name.clone()).collect()
+        };
+
+        palette.show();
+        palette.query = "export".to_string();
+        palette.filter_results();
+        assert_eq!(names(&palette), ["export-sessions", "export-stats"]);
+
+        palette.set_session_selected(true);
+        palette.show();
+        assert!(names(&palette).contains(&"export-conversation".to_string()));
+        palette.query = "conversation".to_string();
+        palette.filter_results();
+        assert!(matches!(
+            palette.selected_command().map(|c| &c.action),
+            Some(CommandAction::Export(ExportTarget::Conversation))
+        ));
+    }
+
     #[test]
     fn test_show_hide() {
         let mut palette = CommandPalette::new();
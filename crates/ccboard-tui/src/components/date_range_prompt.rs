@@ -0,0 +1,212 @@
+//! Start/end date prompt for the Analytics custom range (`c` key)
+
+use crate::theme::Palette;
+use ccboard_core::analytics::DateRange;
+use ccboard_core::models::config::ColorScheme;
+use chrono::NaiveDate;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Field being edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Field {
+    #[default]
+    Start,
+    End,
+}
+
+/// Two-field date input, validated with [`DateRange::parse`]
+#[derive(Debug, Default)]
+pub struct DateRangePrompt {
+    visible: bool,
+    start: String,
+    end: String,
+    field: Field,
+    error: Option<String>,
+}
+
+impl DateRangePrompt {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the prompt pre-filled with `start` and `end`
+    pub fn show(&mut self, start: NaiveDate, end: NaiveDate) {
+        self.visible = true;
+        self.start = start.format("%Y-%m-%d").to_string();
+        self.end = end.format("%Y-%m-%d").to_string();
+        self.field = Field::Start;
+        self.error = None;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Handle key input, returns the range on Enter
+    ///
+    /// Tab / ↑↓ switch fields. Invalid dates keep the prompt open with an
+    /// inline error.
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<DateRange> {
+        if !self.visible {
+            return None;
+        }
+        match key {
+            KeyCode::Esc => self.hide(),
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                self.field = match self.field {
+                    Field::Start => Field::End,
+                    Field::End => Field::Start,
+                };
+            }
+            KeyCode::Enter => {
+                match DateRange::parse(&format!("{}..{}", self.start.trim(), self.end.trim())) {
+                    Ok(range) => {
+                        self.hide();
+                        return Some(range);
+                    }
+                    Err(e) => self.error = Some(e),
+                }
+            }
+            KeyCode::Backspace => {
+                self.input().pop();
+                self.error = None;
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() || c == '-' => {
+                let input = self.input();
+                if input.len() < 10 {
+                    input.push(c);
+                }
+                self.error = None;
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn input(&mut self) -> &mut String {
+        match self.field {
+            Field::Start => &mut self.start,
+            Field::End => &mut self.end,
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, scheme: ColorScheme) {
+        if !self.visible {
+            return;
+        }
+        let p = Palette::new(scheme);
+
+        let width = 44.min(area.width);
+        let height = 8.min(area.height);
+        let popup = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let field_line = |label: &'static str, value: &str, active: bool| {
+            let mut spans = vec![
+                Span::styled(label, Style::default().fg(p.muted)),
+                Span::styled(
+                    value.to_string(),
+                    Style::default().fg(if active { p.focus } else { p.fg }),
+                ),
+            ];
+            if active {
+                spans.push(Span::styled("█", Style::default().fg(p.focus)));
+            }
+            Line::from(spans)
+        };
+        let hint = match &self.error {
+            Some(e) => Span::styled(e.clone(), Style::default().fg(p.error)),
+            None => Span::styled("Dates are inclusive", Style::default().fg(p.muted)),
+        };
+        let lines = vec![
+            field_line("Start: ", &self.start, self.field == Field::Start),
+            field_line("End:   ", &self.end, self.field == Field::End),
+            Line::from(""),
+            Line::from(hint),
+            Line::from(Span::styled(
+                "Tab: switch · Enter: apply · Esc: cancel",
+                Style::default().fg(p.muted),
+            )),
+        ];
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(p.focus))
+                .title(Span::styled(
+                    " Custom Range (YYYY-MM-DD) ",
+                    Style::default().fg(p.focus).add_modifier(Modifier::BOLD),
+                )),
+        );
+        frame.render_widget(paragraph, popup);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_str(prompt: &mut DateRangePrompt, text: &str) {
+        for c in text.chars() {
+            prompt.handle_key(KeyCode::Char(c));
+        }
+    }
+
+    fn clear(prompt: &mut DateRangePrompt) {
+        for _ in 0..10 {
+            prompt.handle_key(KeyCode::Backspace);
+        }
+    }
+
+    #[test]
+    fn test_prompt_validates_and_submits() {
+        let mut prompt = DateRangePrompt::new();
+        let day = |d| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        prompt.show(day(1), day(31));
+        assert!(prompt.is_visible());
+
+        // End before start: stays open with an inline error
+        prompt.handle_key(KeyCode::Tab);
+        clear(&mut prompt);
+        type_str(&mut prompt, "2026-02-10");
+        assert_eq!(prompt.handle_key(KeyCode::Enter), None);
+        assert!(prompt.is_visible());
+        assert!(prompt.error.as_deref().unwrap().contains("ends before"));
+
+        // Malformed date
+        clear(&mut prompt);
+        type_str(&mut prompt, "2026-13");
+        assert_eq!(prompt.handle_key(KeyCode::Enter), None);
+        assert!(prompt
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("Invalid end date"));
+
+        clear(&mut prompt);
+        type_str(&mut prompt, "2026-03-14x");
+        let range = prompt.handle_key(KeyCode::Enter).unwrap();
+        assert_eq!((range.start, range.end), (day(1), day(14)));
+        assert!(!prompt.is_visible());
+
+        prompt.show(day(1), day(2));
+        assert_eq!(prompt.handle_key(KeyCode::Esc), None);
+        assert!(!prompt.is_visible());
+    }
+}
@@ -1,31 +1,40 @@
 //! Error panel component for displaying LoadReport errors
 
+use crate::theme::Palette;
 use ccboard_core::error::{ErrorSeverity, LoadError};
+use ccboard_core::models::config::ColorScheme;
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
 /// Render error panel showing LoadReport errors
-pub fn render_error_panel(frame: &mut Frame, area: Rect, errors: &[LoadError], title: &str) {
+pub fn render_error_panel(
+    frame: &mut Frame,
+    area: Rect,
+    errors: &[LoadError],
+    title: &str,
+    scheme: ColorScheme,
+) {
+    let p = Palette::new(scheme);
     if errors.is_empty() {
         // No errors to show
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Green))
+            .border_style(Style::default().fg(p.success))
             .title(Span::styled(
                 format!(" {} ", title),
-                Style::default().fg(Color::Green).bold(),
+                Style::default().fg(p.success).bold(),
             ));
 
         let empty = Paragraph::new(vec![
             Line::from(""),
             Line::from(Span::styled(
                 "✓ All data loaded successfully",
-                Style::default().fg(Color::Green),
+                Style::default().fg(p.success),
             )),
         ])
         .block(block);
@@ -38,11 +47,11 @@ pub fn render_error_panel(frame: &mut Frame, area: Rect, errors: &[LoadError], t
     let has_fatal = errors.iter().any(|e| e.severity == ErrorSeverity::Fatal);
     let has_error = errors.iter().any(|e| e.severity == ErrorSeverity::Error);
     let border_color = if has_fatal {
-        Color::Red
+        p.error
     } else if has_error {
-        Color::Yellow
+        p.warning
     } else {
-        Color::Blue
+        p.focus
     };
 
     let block = Block::default()
@@ -60,9 +69,9 @@ pub fn render_error_panel(frame: &mut Frame, area: Rect, errors: &[LoadError], t
 
             // Severity icon + source
             let (icon, color) = match error.severity {
-                ErrorSeverity::Fatal => ("✗", Color::Red),
-                ErrorSeverity::Error => ("⚠", Color::Yellow),
-                ErrorSeverity::Warning => ("ⓘ", Color::Blue),
+                ErrorSeverity::Fatal => ("✗", p.error),
+                ErrorSeverity::Error => ("⚠", p.warning),
+                ErrorSeverity::Warning => ("ⓘ", p.focus),
             };
 
             lines.push(Line::from(vec![
@@ -73,14 +82,14 @@ pub fn render_error_panel(frame: &mut Frame, area: Rect, errors: &[LoadError], t
             // Message
             lines.push(Line::from(vec![
                 Span::raw("    "),
-                Span::styled(&error.message, Style::default().fg(Color::White)),
+                Span::styled(&error.message, Style::default().fg(p.fg)),
             ]));
 
             // Suggestion if available
             if let Some(ref suggestion) = error.suggestion {
                 lines.push(Line::from(vec![
-                    Span::styled("    💡 ", Style::default().fg(Color::Cyan)),
-                    Span::styled(suggestion, Style::default().fg(Color::Cyan)),
+                    Span::styled("    💡 ", Style::default().fg(p.focus)),
+                    Span::styled(suggestion, Style::default().fg(p.focus)),
                 ]));
             }
 
@@ -96,11 +105,12 @@ pub fn render_error_panel(frame: &mut Frame, area: Rect, errors: &[LoadError], t
 }
 
 /// Render error summary bar (compact, for status bar)
-pub fn render_error_summary(errors: &[LoadError]) -> Line<'static> {
+pub fn render_error_summary(errors: &[LoadError], scheme: ColorScheme) -> Line<'static> {
+    let p = Palette::new(scheme);
     if errors.is_empty() {
         return Line::from(vec![
-            Span::styled(" ✓ ", Style::default().fg(Color::Green)),
-            Span::styled("No errors", Style::default().fg(Color::DarkGray)),
+            Span::styled(" ✓ ", Style::default().fg(p.success)),
+            Span::styled("No errors", Style::default().fg(p.muted)),
         ]);
     }
 
@@ -120,28 +130,28 @@ pub fn render_error_summary(errors: &[LoadError]) -> Line<'static> {
     let mut spans = Vec::new();
 
     if fatal > 0 {
-        spans.push(Span::styled(" ✗ ", Style::default().fg(Color::Red).bold()));
+        spans.push(Span::styled(" ✗ ", Style::default().fg(p.error).bold()));
         spans.push(Span::styled(
             format!("{} fatal", fatal),
-            Style::default().fg(Color::Red),
+            Style::default().fg(p.error),
         ));
         spans.push(Span::raw(" "));
     }
 
     if errors_count > 0 {
-        spans.push(Span::styled(" ⚠ ", Style::default().fg(Color::Yellow)));
+        spans.push(Span::styled(" ⚠ ", Style::default().fg(p.warning)));
         spans.push(Span::styled(
             format!("{} errors", errors_count),
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(p.warning),
         ));
         spans.push(Span::raw(" "));
     }
 
     if warnings > 0 {
-        spans.push(Span::styled(" ⓘ ", Style::default().fg(Color::Blue)));
+        spans.push(Span::styled(" ⓘ ", Style::default().fg(p.focus)));
         spans.push(Span::styled(
             format!("{} warnings", warnings),
-            Style::default().fg(Color::Blue),
+            Style::default().fg(p.focus),
         ));
     }
 
@@ -0,0 +1,240 @@
+//! Destination prompt for command palette exports
+
+use crate::theme::Palette;
+use ccboard_core::models::config::ColorScheme;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use std::path::{Path, PathBuf};
+
+/// What a palette export writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportTarget {
+    /// Every loaded session's metadata
+    Sessions,
+    /// Global stats from stats-cache.json
+    Stats,
+    /// Messages of the selected session
+    Conversation,
+}
+
+impl ExportTarget {
+    /// Prompt title
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportTarget::Sessions => "Export Sessions",
+            ExportTarget::Stats => "Export Stats",
+            ExportTarget::Conversation => "Export Conversation",
+        }
+    }
+
+    /// Supported file extensions, default first
+    pub fn formats(self) -> &'static [&'static str] {
+        match self {
+            ExportTarget::Sessions | ExportTarget::Stats => &["csv", "json", "md"],
+            ExportTarget::Conversation => &["md", "json", "html"],
+        }
+    }
+
+    /// `<exports_dir>/<kind>_<timestamp>.<default ext>`
+    pub fn default_path(self, exports_dir: &Path) -> PathBuf {
+        let prefix = match self {
+            ExportTarget::Sessions => "sessions",
+            ExportTarget::Stats => "stats",
+            ExportTarget::Conversation => "conversation",
+        };
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        exports_dir.join(format!("{}_{}.{}", prefix, timestamp, self.formats()[0]))
+    }
+
+    /// Format picked from the path's extension (case-insensitive)
+    pub fn format_for(self, path: &Path) -> Result<&'static str, String> {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase)
+            .unwrap_or_default();
+        self.formats()
+            .iter()
+            .find(|f| **f == ext)
+            .copied()
+            .ok_or_else(|| format!("Use a .{} file", self.formats().join(" / .")))
+    }
+}
+
+/// Single-line path input shown after picking an export command
+#[derive(Debug, Default)]
+pub struct ExportPrompt {
+    target: Option<ExportTarget>,
+    path: String,
+    error: Option<String>,
+}
+
+impl ExportPrompt {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the prompt pre-filled with `default_path`
+    pub fn show(&mut self, target: ExportTarget, default_path: &Path) {
+        self.target = Some(target);
+        self.path = default_path.display().to_string();
+        self.error = None;
+    }
+
+    pub fn hide(&mut self) {
+        self.target = None;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Handle key input, returns the target and destination on Enter
+    ///
+    /// The prompt stays open with an inline error when the extension isn't a
+    /// supported format. A leading `~/` is expanded to the home directory.
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<(ExportTarget, PathBuf)> {
+        let target = self.target?;
+        match key {
+            KeyCode::Esc => self.hide(),
+            KeyCode::Enter => {
+                let path = expand_home(self.path.trim());
+                match target.format_for(&path) {
+                    Ok(_) => {
+                        self.hide();
+                        return Some((target, path));
+                    }
+                    Err(e) => self.error = Some(e),
+                }
+            }
+            KeyCode::Backspace => {
+                self.path.pop();
+                self.error = None;
+            }
+            KeyCode::Char(c) => {
+                self.path.push(c);
+                self.error = None;
+            }
+            _ => {}
+        }
+        None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, scheme: ColorScheme) {
+        let Some(target) = self.target else {
+            return;
+        };
+        let p = Palette::new(scheme);
+
+        let width = (area.width * 7 / 10).max(50).min(area.width);
+        let height = 7.min(area.height);
+        let popup = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let hint = match &self.error {
+            Some(e) => Span::styled(e.clone(), Style::default().fg(p.error)),
+            None => Span::styled(
+                format!("Format from extension: .{}", target.formats().join(" / .")),
+                Style::default().fg(p.muted),
+            ),
+        };
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("Path: ", Style::default().fg(p.muted)),
+                Span::styled(&self.path, Style::default().fg(p.fg)),
+                Span::styled("█", Style::default().fg(p.focus)),
+            ]),
+            Line::from(""),
+            Line::from(hint),
+            Line::from(Span::styled(
+                "Enter: export · Esc: cancel",
+                Style::default().fg(p.muted),
+            )),
+        ];
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(p.focus))
+                .title(Span::styled(
+                    format!(" {} ", target.label()),
+                    Style::default().fg(p.focus).add_modifier(Modifier::BOLD),
+                )),
+        );
+        frame.render_widget(paragraph, popup);
+    }
+}
+
+/// Expand a leading `~/` to the home directory
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_for_extension() {
+        let sessions = ExportTarget::Sessions;
+        assert_eq!(sessions.format_for(Path::new("a/b.CSV")), Ok("csv"));
+        assert_eq!(sessions.format_for(Path::new("b.md")), Ok("md"));
+        assert!(sessions.format_for(Path::new("b.html")).is_err());
+        assert!(sessions.format_for(Path::new("noext")).is_err());
+
+        let conversation = ExportTarget::Conversation;
+        assert_eq!(conversation.format_for(Path::new("c.html")), Ok("html"));
+
+        let default = ExportTarget::Stats.default_path(Path::new("/x/exports"));
+        assert!(default.starts_with("/x/exports"));
+        assert_eq!(ExportTarget::Stats.format_for(&default), Ok("csv"));
+    }
+
+    #[test]
+    fn test_prompt_edit_and_submit() {
+        let mut prompt = ExportPrompt::new();
+        prompt.show(ExportTarget::Sessions, Path::new("/tmp/out.csv"));
+        assert!(prompt.is_visible());
+
+        // Switch to an unsupported extension: prompt stays open with an error
+        for _ in 0..3 {
+            prompt.handle_key(KeyCode::Backspace);
+        }
+        for c in "txt".chars() {
+            prompt.handle_key(KeyCode::Char(c));
+        }
+        assert_eq!(prompt.handle_key(KeyCode::Enter), None);
+        assert!(prompt.is_visible());
+        assert!(prompt.error.is_some());
+
+        for _ in 0..3 {
+            prompt.handle_key(KeyCode::Backspace);
+        }
+        for c in "json".chars() {
+            prompt.handle_key(KeyCode::Char(c));
+        }
+        assert_eq!(
+            prompt.handle_key(KeyCode::Enter),
+            Some((ExportTarget::Sessions, PathBuf::from("/tmp/out.json")))
+        );
+        assert!(!prompt.is_visible());
+
+        prompt.show(ExportTarget::Stats, Path::new("/tmp/s.csv"));
+        assert_eq!(prompt.handle_key(KeyCode::Esc), None);
+        assert!(!prompt.is_visible());
+    }
+}
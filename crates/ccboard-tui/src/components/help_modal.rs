@@ -145,9 +145,14 @@ impl HelpModal {
         add_key_line(&mut lines, KeyAction::ShowCommandPalette, keybindings);
         add_key_line(&mut lines, KeyAction::Refresh, keybindings);
         add_key_line(&mut lines, KeyAction::ForceRefresh, keybindings);
+        add_key_line(&mut lines, KeyAction::ReloadAll, keybindings);
         add_key_line(&mut lines, KeyAction::NextTab, keybindings);
         add_key_line(&mut lines, KeyAction::PrevTab, keybindings);
         add_key_line(&mut lines, KeyAction::ThemeToggle, keybindings);
+        lines.push(Line::from(Span::styled(
+            "              Startup theme: [theme] colorScheme in ~/.ccboard/config.toml",
+            Style::default().fg(p.muted),
+        )));
 
         // Show tab jump shortcuts
         if let Some(key_str) = keybindings.get_key_for_action(KeyAction::JumpTab0) {
@@ -187,7 +192,19 @@ impl HelpModal {
                 ]));
                 lines.push(Line::from(vec![
                     Span::styled("  /           ", Style::default().fg(focus_color)),
-                    Span::raw("Search sessions"),
+                    Span::raw("Search sessions (tag:<name> filters by tag)"),
+                ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  T           ", Style::default().fg(focus_color)),
+                    Span::raw("Edit tags of the selected session"),
+                ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  y / Y / P   ", Style::default().fg(focus_color)),
+                    Span::raw("Copy session ID / conversation (Markdown) / file path"),
+                ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  1-9 / '1-9  ", Style::default().fg(focus_color)),
+                    Span::raw("Replay: bookmark message / jump to bookmark"),
                 ]));
             }
             Tab::Config => {
@@ -227,11 +244,27 @@ impl HelpModal {
                     Span::styled("  Enter       ", Style::default().fg(focus_color)),
                     Span::raw("View details"),
                 ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  /           ", Style::default().fg(focus_color)),
+                    Span::raw("Fuzzy filter by name, description or tools"),
+                ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  p           ", Style::default().fg(focus_color)),
+                    Span::raw("Counts for project selected in Sessions / all"),
+                ]));
             }
             Tab::Costs => {
                 lines.push(Line::from(vec![
                     Span::styled("  Tab/←/→/h/l ", Style::default().fg(focus_color)),
-                    Span::raw("Switch between Overview/Billing/Models"),
+                    Span::raw("Switch between Overview/Billing/Models/Heatmap"),
+                ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  ↑/↓ m       ", Style::default().fg(focus_color)),
+                    Span::raw("What-If: pick source / cycle target model"),
+                ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  f           ", Style::default().fg(focus_color)),
+                    Span::raw("By Model: group by model family / raw IDs"),
                 ]));
             }
             Tab::History => {
@@ -255,6 +288,18 @@ impl HelpModal {
                     Span::styled("  x           ", Style::default().fg(focus_color)),
                     Span::raw("Export filtered sessions (CSV/JSON)"),
                 ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  Y           ", Style::default().fg(focus_color)),
+                    Span::raw("Copy last export path (or session file path)"),
+                ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  Space       ", Style::default().fg(focus_color)),
+                    Span::raw("Mark session for comparison (A/B)"),
+                ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  D           ", Style::default().fg(focus_color)),
+                    Span::raw("Compare the two marked sessions"),
+                ]));
             }
             Tab::Mcp => {
                 lines.push(Line::from(vec![
@@ -279,7 +324,7 @@ impl HelpModal {
                 ]));
                 lines.push(Line::from(vec![
                     Span::styled("  r           ", Style::default().fg(focus_color)),
-                    Span::raw("Refresh server status"),
+                    Span::raw("Refresh server status and health checks"),
                 ]));
             }
             Tab::Analytics => {
@@ -287,6 +332,14 @@ impl HelpModal {
                     Span::styled("  F1-F4       ", Style::default().fg(focus_color)),
                     Span::raw("Select period (7d/30d/90d/All)"),
                 ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  F5          ", Style::default().fg(focus_color)),
+                    Span::raw("Calendar month (press again for the previous one)"),
+                ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  c           ", Style::default().fg(focus_color)),
+                    Span::raw("Custom date range (start/end, YYYY-MM-DD)"),
+                ]));
                 lines.push(Line::from(vec![
                     Span::styled("  ←→ or h/l   ", Style::default().fg(focus_color)),
                     Span::raw("Switch between sub-views"),
@@ -295,6 +348,10 @@ impl HelpModal {
                     Span::styled("  j/k or ↑/↓  ", Style::default().fg(focus_color)),
                     Span::raw("Scroll insights list"),
                 ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  d/w/m       ", Style::default().fg(focus_color)),
+                    Span::raw("Trends by day/week/month"),
+                ]));
                 lines.push(Line::from(vec![
                     Span::styled("  r           ", Style::default().fg(focus_color)),
                     Span::raw("Recompute analytics"),
@@ -303,7 +360,7 @@ impl HelpModal {
             Tab::Plugins => {
                 lines.push(Line::from(vec![
                     Span::styled("  Tab         ", Style::default().fg(focus_color)),
-                    Span::raw("Cycle between columns"),
+                    Span::raw("Cycle between columns and installed plugins"),
                 ]));
                 lines.push(Line::from(vec![
                     Span::styled("  j/k or ↑/↓  ", Style::default().fg(focus_color)),
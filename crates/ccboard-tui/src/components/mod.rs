@@ -1,21 +1,29 @@
 pub mod breadcrumbs;
+pub mod code_block;
 pub mod command_palette;
 pub mod confirm_dialog;
+pub mod date_range_prompt;
 pub mod detail_pane;
 pub mod error_panel;
+pub mod export_prompt;
 pub mod help_modal;
 pub mod list_pane;
 pub mod search_bar;
 pub mod spinner;
+pub mod tag_prompt;
 pub mod toast;
 
 pub use breadcrumbs::{Breadcrumb, Breadcrumbs};
+pub use code_block::{has_code_block, render_code_blocks};
 pub use command_palette::CommandPalette;
 pub use confirm_dialog::{ConfirmDialog, ConfirmResult};
+pub use date_range_prompt::DateRangePrompt;
 pub use detail_pane::DetailPane;
 pub use error_panel::{render_error_panel, render_error_summary};
+pub use export_prompt::{ExportPrompt, ExportTarget};
 pub use help_modal::HelpModal;
 pub use list_pane::ListPane;
 pub use search_bar::{highlight_matches, SearchBar};
 pub use spinner::{Spinner, SpinnerStyle};
+pub use tag_prompt::TagPrompt;
 pub use toast::{Toast, ToastManager, ToastType};
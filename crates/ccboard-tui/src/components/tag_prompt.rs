@@ -0,0 +1,132 @@
+//! Tag editor for the selected session (`T` in the Sessions tab)
+
+use crate::theme::Palette;
+use ccboard_core::models::config::ColorScheme;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Single-line, comma-separated tag input
+#[derive(Debug, Default)]
+pub struct TagPrompt {
+    session_id: Option<String>,
+    input: String,
+}
+
+impl TagPrompt {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the prompt for `session_id`, pre-filled with its current tags
+    pub fn show(&mut self, session_id: String, tags: &[String]) {
+        self.session_id = Some(session_id);
+        self.input = tags.join(", ");
+    }
+
+    pub fn hide(&mut self) {
+        self.session_id = None;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.session_id.is_some()
+    }
+
+    /// Handle key input, returns the session and its new tags on Enter
+    ///
+    /// Tags are split on commas; an empty input clears them.
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<(String, Vec<String>)> {
+        self.session_id.as_ref()?;
+        match key {
+            KeyCode::Esc => self.hide(),
+            KeyCode::Enter => {
+                let tags = self
+                    .input
+                    .split(',')
+                    .map(|t| t.trim().to_string())
+                    .filter(|t| !t.is_empty())
+                    .collect();
+                return self.session_id.take().map(|id| (id, tags));
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) => self.input.push(c),
+            _ => {}
+        }
+        None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, scheme: ColorScheme) {
+        let Some(session_id) = &self.session_id else {
+            return;
+        };
+        let p = Palette::new(scheme);
+
+        let width = (area.width * 6 / 10).max(50).min(area.width);
+        let height = 7.min(area.height);
+        let popup = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let lines = vec![
+            Line::from(Span::styled(
+                format!("Session {}", &session_id[..8.min(session_id.len())]),
+                Style::default().fg(p.muted),
+            )),
+            Line::from(vec![
+                Span::styled(self.input.clone(), Style::default().fg(p.fg)),
+                Span::styled("█", Style::default().fg(p.focus)),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Comma-separated · Enter: save · Esc: cancel",
+                Style::default().fg(p.muted),
+            )),
+        ];
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(p.focus))
+                .title(Span::styled(
+                    " Edit Tags ",
+                    Style::default().fg(p.focus).add_modifier(Modifier::BOLD),
+                )),
+        );
+        frame.render_widget(paragraph, popup);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_edits_and_submits() {
+        let mut prompt = TagPrompt::new();
+        prompt.show("sess-1".to_string(), &["bug".to_string()]);
+        assert!(prompt.is_visible());
+
+        for c in ", spike,, client acme ".chars() {
+            prompt.handle_key(KeyCode::Char(c));
+        }
+        let (id, tags) = prompt.handle_key(KeyCode::Enter).unwrap();
+        assert_eq!(id, "sess-1");
+        assert_eq!(tags, ["bug", "spike", "client acme"]);
+        assert!(!prompt.is_visible());
+
+        prompt.show("sess-1".to_string(), &[]);
+        assert_eq!(prompt.handle_key(KeyCode::Esc), None);
+        assert!(!prompt.is_visible());
+    }
+}
@@ -21,12 +21,12 @@ pub enum ToastType {
 }
 
 impl ToastType {
-    pub fn color(&self) -> Color {
+    pub fn color(&self, p: &Palette) -> Color {
         match self {
-            Self::Success => Color::Green,
-            Self::Warning => Color::Yellow,
-            Self::Error => Color::Red,
-            Self::Info => Color::Cyan,
+            Self::Success => p.success,
+            Self::Warning => p.warning,
+            Self::Error => p.error,
+            Self::Info => p.focus,
         }
     }
 
@@ -142,7 +142,7 @@ impl ToastManager {
 }
 
 fn render_single_toast(frame: &mut Frame, area: Rect, toast: &Toast, p: &Palette) {
-    let color = toast.toast_type.color();
+    let color = toast.toast_type.color(p);
     let icon = toast.toast_type.icon();
 
     let block = Block::default()
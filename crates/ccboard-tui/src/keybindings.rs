@@ -21,6 +21,8 @@ pub enum KeyAction {
     Refresh,
     /// Force refresh and clear caches
     ForceRefresh,
+    /// Re-scan everything from disk and swap it in
+    ReloadAll,
     /// Toggle color scheme (Dark/Light)
     ThemeToggle,
     /// Navigate to next tab
@@ -54,6 +56,7 @@ impl KeyAction {
             KeyAction::ForceQuit,
             KeyAction::Refresh,
             KeyAction::ForceRefresh,
+            KeyAction::ReloadAll,
             KeyAction::ThemeToggle,
             KeyAction::NextTab,
             KeyAction::PrevTab,
@@ -80,6 +83,7 @@ impl KeyAction {
             KeyAction::ForceQuit => "force_quit",
             KeyAction::Refresh => "refresh",
             KeyAction::ForceRefresh => "force_refresh",
+            KeyAction::ReloadAll => "reload_all",
             KeyAction::ThemeToggle => "theme_toggle",
             KeyAction::NextTab => "next_tab",
             KeyAction::PrevTab => "prev_tab",
@@ -106,7 +110,8 @@ impl KeyAction {
             KeyAction::ForceQuit => "Force quit without confirmation",
             KeyAction::Refresh => "Refresh data",
             KeyAction::ForceRefresh => "Force refresh + clear cache",
-            KeyAction::ThemeToggle => "Toggle theme (Dark/Light)",
+            KeyAction::ReloadAll => "Reload all data from disk",
+            KeyAction::ThemeToggle => "Cycle theme (Dark/Light/Color-blind safe)",
             KeyAction::NextTab => "Next tab",
             KeyAction::PrevTab => "Previous tab",
             KeyAction::JumpTab0 => "Jump to Dashboard",
@@ -132,6 +137,7 @@ impl KeyAction {
             "force_quit" => Some(KeyAction::ForceQuit),
             "refresh" => Some(KeyAction::Refresh),
             "force_refresh" => Some(KeyAction::ForceRefresh),
+            "reload_all" => Some(KeyAction::ReloadAll),
             "theme_toggle" => Some(KeyAction::ThemeToggle),
             "next_tab" => Some(KeyAction::NextTab),
             "prev_tab" => Some(KeyAction::PrevTab),
@@ -215,6 +221,15 @@ impl KeyBindings {
             KeyAction::ForceRefresh,
         );
 
+        // Reload all (R)
+        defaults.insert(
+            KeyWithMods {
+                code: KeyCode::Char('R'),
+                modifiers: KeyModifiers::NONE,
+            },
+            KeyAction::ReloadAll,
+        );
+
         // Theme toggle (Ctrl+T)
         defaults.insert(
             KeyWithMods {
@@ -390,7 +405,18 @@ impl KeyBindings {
         }
 
         // Fall back to defaults
-        self.defaults.get(&key).copied()
+        if let Some(action) = self.defaults.get(&key) {
+            return Some(*action);
+        }
+
+        // Most terminals report Shift with uppercase chars; the char already
+        // carries it, so "R" bindings also match Shift+R
+        match code {
+            KeyCode::Char(c) if modifiers == KeyModifiers::SHIFT && !c.is_lowercase() => {
+                self.get_action(code, KeyModifiers::NONE)
+            }
+            _ => None,
+        }
     }
 
     /// Get key string for an action (for help modal)
@@ -630,6 +656,11 @@ mod tests {
         // Test refresh
         let action = kb.get_action(KeyCode::F(5), KeyModifiers::NONE);
         assert_eq!(action, Some(KeyAction::Refresh));
+
+        // Uppercase chars match with or without a reported Shift
+        let action = kb.get_action(KeyCode::Char('R'), KeyModifiers::SHIFT);
+        assert_eq!(action, Some(KeyAction::ReloadAll));
+        assert_eq!(kb.get_action(KeyCode::Char('r'), KeyModifiers::SHIFT), None);
     }
 
     #[test]
@@ -10,7 +10,7 @@ pub mod theme;
 pub mod ui;
 pub mod widgets;
 
-pub use app::App;
+pub use app::{App, InitialView, Tab};
 
 #[cfg(test)]
 mod tests;
@@ -29,11 +29,12 @@ use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::oneshot;
 
-/// Run the TUI application
+/// Run the TUI application, opening on `initial`
 pub async fn run(
     store: Arc<DataStore>,
     claude_home: PathBuf,
     project_path: Option<PathBuf>,
+    initial: InitialView,
 ) -> Result<()> {
     // Install panic hook so any unexpected panic restores the terminal before printing
     // the panic message — without this, raw mode is left active and the terminal breaks.
@@ -53,9 +54,16 @@ pub async fn run(
 
     // Create app state (starts in loading mode)
     let mut app = App::new(store.clone());
+    app.active_tab = initial.tab;
+    if let Some(scheme) = initial.color_scheme {
+        app.color_scheme = scheme;
+    }
 
     // Create UI (will initialize after data loads)
     let mut ui = ui::Ui::new();
+    if let Some(view) = initial.analytics_view {
+        ui.set_analytics_view(view);
+    }
 
     // Channel to signal when loading completes
     let (load_tx, mut load_rx) = oneshot::channel();
@@ -162,12 +170,23 @@ where
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
                     // If any viewer is open (conversation or replay), handle keys there first (highest priority)
-                    if ui.is_conversation_open() || ui.is_replay_open() {
+                    if ui.is_conversation_open()
+                        || ui.is_replay_open()
+                        || ui.is_text_input_focused(app)
+                    {
                         ui.handle_tab_key(key.code, app);
                     } else {
                         // Otherwise check for global keys
+                        let palette_was_open = app.command_palette.is_visible();
                         let handled = app.handle_key(key.code, key.modifiers);
 
+                        // Palette commands act on the selection when it opened
+                        if !palette_was_open && app.command_palette.is_visible() {
+                            app.selected_session = ui.selected_session(app);
+                            app.command_palette
+                                .set_session_selected(app.selected_session.is_some());
+                        }
+
                         // If not a global key and not loading, pass to active tab
                         if !handled && !app.is_loading {
                             ui.handle_tab_key(key.code, app);
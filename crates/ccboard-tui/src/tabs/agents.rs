@@ -62,11 +62,11 @@ impl AgentType {
         }
     }
 
-    fn color(&self) -> Color {
+    fn color(&self, p: &Palette) -> Color {
         match self {
-            AgentType::Agent => Color::Rgb(80, 190, 210), // p.focus
-            AgentType::Command => Color::Rgb(80, 200, 120), // p.success
-            AgentType::Skill => Color::Rgb(220, 175, 60), // p.warning
+            AgentType::Agent => p.focus,
+            AgentType::Command => p.success,
+            AgentType::Skill => p.warning,
         }
     }
 }
@@ -211,9 +211,24 @@ fn parse_frontmatter(path: &Path) -> Frontmatter {
         .get(2)
         .map_or(false, |body| body.contains("${CLAUDE_EFFORT}"));
 
+    // Key whose YAML block list we're currently reading (e.g. "allowed-tools:\n  - Read")
+    let mut list_key: Option<&str> = None;
+
     for line in yaml_block.lines() {
         let line = line.trim();
 
+        if let Some(item) = line.strip_prefix("- ") {
+            let item = item.trim().trim_matches('"').trim_matches('\'').to_string();
+            match list_key {
+                Some("allowed-tools") => fm.allowed_tools.push(item),
+                Some("disallowed-tools") => fm.disallowed_tools.push(item),
+                Some("skills") => fm.skills.push(item),
+                _ => {}
+            }
+            continue;
+        }
+        list_key = line.split_once(':').map(|(k, _)| k.trim());
+
         if let Some(v) = scalar_value(line, "description:") {
             fm.description = Some(v);
         } else if let Some(v) = scalar_value(line, "context:") {
@@ -227,11 +242,6 @@ fn parse_frontmatter(path: &Path) -> Frontmatter {
         } else if let Some(v) = scalar_value(line, "skills:") {
             fm.skills = split_tools(&v);
         }
-        // List items for allowed-tools / disallowed-tools / skills
-        else if line.starts_with("- ") {
-            // We can't know which field we're under without tracking state,
-            // so skip bare list items — scalar parsing covers the common inline format.
-        }
     }
 
     fm
@@ -243,12 +253,57 @@ fn scalar_value<'a>(line: &'a str, key: &str) -> Option<String> {
 }
 
 fn split_tools(raw: &str) -> Vec<String> {
-    raw.split([',', ' '])
-        .map(|s| s.trim().to_string())
+    raw.trim_start_matches('[')
+        .trim_end_matches(']')
+        .split([',', ' '])
+        .map(|s| s.trim().trim_matches('"').trim_matches('\'').to_string())
         .filter(|s| !s.is_empty())
         .collect()
 }
 
+/// Score how well an entry matches a fuzzy query (higher is better)
+///
+/// Name substring matches rank first (earlier position is better), then
+/// in-order character matches on the name, then substring matches on the
+/// description or allowed tools.
+fn match_score(entry: &AgentEntry, query: &str) -> Option<u32> {
+    let query = query.to_lowercase();
+    let name = entry.name.to_lowercase();
+
+    if let Some(pos) = name.find(&query) {
+        return Some(300u32.saturating_sub(pos as u32));
+    }
+    if let Some(gaps) = subsequence_gaps(&name, &query) {
+        return Some(200u32.saturating_sub(gaps));
+    }
+
+    let in_description = entry
+        .description
+        .as_deref()
+        .is_some_and(|d| d.to_lowercase().contains(&query));
+    let in_tools = entry
+        .allowed_tools
+        .iter()
+        .any(|t| t.to_lowercase().contains(&query));
+    (in_description || in_tools).then_some(100)
+}
+
+/// Number of skipped characters if `needle` is a subsequence of `haystack`
+fn subsequence_gaps(haystack: &str, needle: &str) -> Option<u32> {
+    let mut chars = haystack.chars();
+    let mut gaps = 0u32;
+    for n in needle.chars() {
+        loop {
+            match chars.next() {
+                Some(c) if c == n => break,
+                Some(_) => gaps += 1,
+                None => return None,
+            }
+        }
+    }
+    Some(gaps)
+}
+
 /// Agents tab state
 pub struct AgentsTab {
     /// Current sub-tab (0=Agents, 1=Commands, 2=Skills)
@@ -261,8 +316,16 @@ pub struct AgentsTab {
     skills: Vec<AgentEntry>,
     /// Show detail panel
     show_detail: bool,
+    /// Fuzzy filter applied to the current sub-tab
+    search_query: String,
+    /// Whether the search input is capturing keystrokes
+    search_focused: bool,
     /// Error message to display (if any)
     error_message: Option<String>,
+    /// Invocation stats last received (global totals plus per-project breakdown)
+    invocation_stats: ccboard_core::models::InvocationStats,
+    /// Project whose invocation counts are shown (None = all projects)
+    project_scope: Option<String>,
 }
 
 impl Default for AgentsTab {
@@ -285,10 +348,19 @@ impl AgentsTab {
             commands: Vec::new(),
             skills: Vec::new(),
             show_detail: false,
+            search_query: String::new(),
+            search_focused: false,
             error_message: None,
+            invocation_stats: ccboard_core::models::InvocationStats::new(),
+            project_scope: None,
         }
     }
 
+    /// Whether the search input is capturing keystrokes (global keys must be bypassed)
+    pub fn is_search_focused(&self) -> bool {
+        self.search_focused
+    }
+
     /// Get current sub-tab index
     pub fn current_sub_tab(&self) -> usize {
         self.sub_tab
@@ -317,7 +389,36 @@ impl AgentsTab {
     }
 
     /// Update invocation counts from stats and sort by usage
+    ///
+    /// Counts follow the current project scope, if one is set.
     pub fn update_invocation_counts(&mut self, stats: &ccboard_core::models::InvocationStats) {
+        self.invocation_stats = stats.clone();
+        self.apply_invocation_counts();
+    }
+
+    /// Toggle between global counts and counts for `project`
+    ///
+    /// Turning the scope on needs a project; turning it off ignores `project`.
+    pub fn toggle_project_scope(&mut self, project: Option<String>) {
+        self.project_scope = match self.project_scope {
+            Some(_) => None,
+            None => project,
+        };
+        self.apply_invocation_counts();
+    }
+
+    /// Project whose invocation counts are shown, if scoped
+    pub fn project_scope(&self) -> Option<&str> {
+        self.project_scope.as_deref()
+    }
+
+    fn apply_invocation_counts(&mut self) {
+        let empty = ccboard_core::models::InvocationStats::new();
+        let all = std::mem::take(&mut self.invocation_stats);
+        let stats = match &self.project_scope {
+            Some(project) => all.for_project(project).unwrap_or(&empty),
+            None => &all,
+        };
         // Update counts for entries that have local frontmatter files
         for agent in &mut self.agents {
             agent.invocation_count = stats.agents.get(&agent.name).copied().unwrap_or(0);
@@ -417,13 +518,43 @@ impl AgentsTab {
                 .cmp(&a.invocation_count)
                 .then(a.name.cmp(&b.name))
         });
+
+        self.invocation_stats = all;
     }
 
     /// Handle key input
     pub fn handle_key(&mut self, key: crossterm::event::KeyCode) {
         use crossterm::event::KeyCode;
 
+        if self.search_focused {
+            match key {
+                KeyCode::Char(c) => {
+                    self.search_query.push(c);
+                    self.list_states[self.sub_tab].select(Some(0));
+                }
+                KeyCode::Backspace => {
+                    self.search_query.pop();
+                    self.list_states[self.sub_tab].select(Some(0));
+                }
+                KeyCode::Up => self.move_selection(-1),
+                KeyCode::Down => self.move_selection(1),
+                KeyCode::Enter => {
+                    // Keep the filter, return to list navigation
+                    self.search_focused = false;
+                }
+                KeyCode::Esc => {
+                    self.search_query.clear();
+                    self.search_focused = false;
+                }
+                _ => {}
+            }
+            return;
+        }
+
         match key {
+            KeyCode::Char('/') => {
+                self.search_focused = true;
+            }
             KeyCode::Left | KeyCode::Char('h') => {
                 self.sub_tab = self.sub_tab.saturating_sub(1);
             }
@@ -463,6 +594,8 @@ impl AgentsTab {
             KeyCode::Esc => {
                 if self.error_message.is_some() {
                     self.error_message = None;
+                } else if !self.search_query.is_empty() {
+                    self.search_query.clear();
                 } else {
                     self.show_detail = false;
                 }
@@ -473,13 +606,14 @@ impl AgentsTab {
 
     /// Get currently selected entry
     fn get_selected_entry(&self) -> Option<&AgentEntry> {
-        let list = self.current_list();
         let state = &self.list_states[self.sub_tab];
-        state.selected().and_then(|idx| list.get(idx))
+        state
+            .selected()
+            .and_then(|idx| self.visible_entries().get(idx).copied())
     }
 
     fn move_selection(&mut self, delta: i32) {
-        let list_len = self.current_list().len();
+        let list_len = self.visible_entries().len();
         if list_len == 0 {
             return;
         }
@@ -498,19 +632,45 @@ impl AgentsTab {
         }
     }
 
+    /// Entries of the current sub-tab matching the search query, best match first
+    ///
+    /// Ties keep the usage-based order from `update_invocation_counts`.
+    fn visible_entries(&self) -> Vec<&AgentEntry> {
+        let list = self.current_list();
+        if self.search_query.is_empty() {
+            return list.iter().collect();
+        }
+
+        let mut scored: Vec<(u32, &AgentEntry)> = list
+            .iter()
+            .filter_map(|e| match_score(e, &self.search_query).map(|score| (score, e)))
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, e)| e).collect()
+    }
+
     /// Render the agents tab
     pub fn render(&mut self, frame: &mut Frame, area: Rect, scheme: ColorScheme) {
         let p = Palette::new(scheme);
 
-        // Layout: sub-tabs header (2 lines with padding) | content
+        // Layout: sub-tabs header (2 lines with padding) | search (when active) | content
+        let show_search = self.search_focused || !self.search_query.is_empty();
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(4), Constraint::Min(0)])
+            .constraints([
+                Constraint::Length(4),
+                Constraint::Length(if show_search { 3 } else { 0 }),
+                Constraint::Min(0),
+            ])
             .split(area);
 
         // Render sub-tabs
         self.render_sub_tabs(frame, chunks[0], &p);
 
+        if show_search {
+            self.render_search(frame, chunks[1], &p);
+        }
+
         // Content area
         let content_constraints = if self.show_detail {
             vec![Constraint::Percentage(50), Constraint::Percentage(50)]
@@ -521,7 +681,7 @@ impl AgentsTab {
         let content_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints(content_constraints)
-            .split(chunks[1]);
+            .split(chunks[2]);
 
         // Render list
         self.render_list(frame, content_chunks[0], &p);
@@ -548,7 +708,7 @@ impl AgentsTab {
         .map(|(i, (t, count))| {
             let style = if i == self.sub_tab {
                 Style::default()
-                    .fg(t.color())
+                    .fg(t.color(p))
                     .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
             } else {
                 Style::default().fg(p.muted)
@@ -572,6 +732,46 @@ impl AgentsTab {
         frame.render_widget(tabs, area);
     }
 
+    fn render_search(&self, frame: &mut Frame, area: Rect, p: &Palette) {
+        let border_color = if self.search_focused {
+            p.focus
+        } else {
+            p.border
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(border_color))
+            .style(Style::default().bg(p.surface))
+            .title(Span::styled(
+                format!(
+                    " / Filter ({} of {}) • Esc:clear ",
+                    self.visible_entries().len(),
+                    self.current_list().len()
+                ),
+                Style::default().fg(p.fg).bold(),
+            ));
+
+        let line = if self.search_query.is_empty() {
+            Line::from(Span::styled(
+                "Type to filter by name, description or tools...",
+                Style::default().fg(p.muted),
+            ))
+        } else {
+            Line::from(vec![
+                Span::styled(&self.search_query, Style::default().fg(p.fg)),
+                if self.search_focused {
+                    Span::styled("▌", Style::default().fg(p.focus))
+                } else {
+                    Span::raw("")
+                },
+            ])
+        };
+
+        frame.render_widget(Paragraph::new(line).block(block), area);
+    }
+
     fn render_list(&mut self, frame: &mut Frame, area: Rect, p: &Palette) {
         let entry_type = match self.sub_tab {
             0 => AgentType::Agent,
@@ -581,15 +781,30 @@ impl AgentsTab {
         };
 
         // Get list length first for selection clamping
-        let list_len = self.current_list().len();
+        let list_len = self.visible_entries().len();
 
+        let scope = match &self.project_scope {
+            Some(project) => format!(
+                " [{}]",
+                std::path::Path::new(project)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| project.clone())
+            ),
+            None => String::new(),
+        };
         let title_text = if entry_type == AgentType::Command {
             format!(
-                " {} - Press / in Claude Code to use • e:edit o:reveal ",
-                entry_type.label()
+                " {}{} - Press / in Claude Code to use • e:edit o:reveal /:filter p:project ",
+                entry_type.label(),
+                scope
             )
         } else {
-            format!(" {} • e:edit o:reveal ", entry_type.label())
+            format!(
+                " {}{} • e:edit o:reveal /:filter p:project ",
+                entry_type.label(),
+                scope
+            )
         };
 
         let block = Block::default()
@@ -599,6 +814,16 @@ impl AgentsTab {
             .style(Style::default().bg(p.surface))
             .title(Span::styled(title_text, Style::default().fg(p.fg).bold()));
 
+        if list_len == 0 && !self.search_query.is_empty() {
+            let empty = Paragraph::new(Line::from(Span::styled(
+                format!("No matches for \"{}\"", self.search_query),
+                Style::default().fg(p.muted),
+            )))
+            .block(block);
+            frame.render_widget(empty, area);
+            return;
+        }
+
         if list_len == 0 {
             let empty = Paragraph::new(vec![
                 Line::from(""),
@@ -632,7 +857,7 @@ impl AgentsTab {
         }
 
         // Now get the list reference for rendering
-        let list = self.current_list();
+        let list = self.visible_entries();
         let items: Vec<ListItem> = list
             .iter()
             .enumerate()
@@ -641,7 +866,7 @@ impl AgentsTab {
 
                 let style = if is_selected {
                     Style::default()
-                        .fg(entry_type.color())
+                        .fg(entry_type.color(p))
                         .add_modifier(Modifier::BOLD)
                 } else {
                     Style::default().fg(p.muted)
@@ -664,7 +889,7 @@ impl AgentsTab {
                 let mut spans = vec![
                     Span::styled(
                         format!(" {} ", entry_type.icon()),
-                        Style::default().fg(entry_type.color()),
+                        Style::default().fg(entry_type.color(p)),
                     ),
                     Span::styled(entry.name.clone(), style),
                 ];
@@ -708,10 +933,7 @@ impl AgentsTab {
     }
 
     fn render_detail(&self, frame: &mut Frame, area: Rect, p: &Palette) {
-        let list = self.current_list();
-        let selected = self.list_states[self.sub_tab]
-            .selected()
-            .and_then(|i| list.get(i));
+        let selected = self.get_selected_entry();
 
         let block = Block::default()
             .borders(Borders::ALL)
@@ -754,7 +976,7 @@ impl AgentsTab {
                 Span::styled("Name: ", Style::default().fg(p.muted)),
                 Span::styled(
                     &entry.name,
-                    Style::default().fg(entry.entry_type.color()).bold(),
+                    Style::default().fg(entry.entry_type.color(p)).bold(),
                 ),
             ]),
             Line::from(vec![
@@ -765,6 +987,17 @@ impl AgentsTab {
                 Span::styled("Path: ", Style::default().fg(p.muted)),
                 Span::styled(&entry.file_path, Style::default().fg(p.warning)),
             ]),
+            Line::from(vec![
+                Span::styled("Used: ", Style::default().fg(p.muted)),
+                if entry.invocation_count > 0 {
+                    Span::styled(
+                        format!("× {}", entry.invocation_count),
+                        Style::default().fg(p.warning),
+                    )
+                } else {
+                    Span::styled("never", Style::default().fg(p.muted))
+                },
+            ]),
             Line::from(""),
             Line::from(Span::styled("Description:", Style::default().fg(p.muted))),
             Line::from(Span::styled(
@@ -893,3 +1126,89 @@ impl AgentsTab {
         frame.render_widget(paragraph, inner);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, description: Option<&str>, tools: &[&str]) -> AgentEntry {
+        AgentEntry {
+            name: name.to_string(),
+            file_path: String::new(),
+            description: description.map(String::from),
+            entry_type: AgentType::Agent,
+            invocation_count: 0,
+            allowed_tools: tools.iter().map(|t| t.to_string()).collect(),
+            disallowed_tools: Vec::new(),
+            context_mode: None,
+            effort: None,
+            skills: Vec::new(),
+            uses_effort_var: false,
+        }
+    }
+
+    #[test]
+    fn test_parse_frontmatter_block_lists() {
+        let path = std::env::temp_dir().join(format!("ccboard-agent-{}.md", std::process::id()));
+        std::fs::write(
+            &path,
+            "---\nname: reviewer\ndescription: \"Reviews code\"\nallowed-tools:\n  - Read\n  - Grep\nskills: [lint, test]\neffort: high\n---\nBody\n",
+        )
+        .unwrap();
+
+        let fm = parse_frontmatter(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(fm.description.as_deref(), Some("Reviews code"));
+        assert_eq!(fm.allowed_tools, vec!["Read", "Grep"]);
+        assert_eq!(fm.skills, vec!["lint", "test"]);
+        assert_eq!(fm.effort.as_deref(), Some("high"));
+    }
+
+    #[test]
+    fn test_match_score_ranking() {
+        let reviewer = entry("code-reviewer", Some("Reviews diffs"), &["Read"]);
+        let writer = entry("doc-writer", Some("Writes docs"), &["Write"]);
+        let planner = entry("planner", None, &["WebFetch"]);
+
+        // Substring on name beats subsequence, which beats description/tools
+        let exact = match_score(&reviewer, "review").unwrap();
+        let fuzzy = match_score(&reviewer, "crvw").unwrap();
+        let by_desc = match_score(&reviewer, "diffs").unwrap();
+        let by_tool = match_score(&planner, "webfetch").unwrap();
+        assert!(exact > fuzzy);
+        assert!(fuzzy > by_desc);
+        assert_eq!(by_tool, by_desc);
+
+        assert_eq!(match_score(&writer, "zzz"), None);
+        assert_eq!(match_score(&reviewer, "REVIEW"), Some(exact));
+    }
+
+    #[test]
+    fn test_project_scope_switches_counts() {
+        use ccboard_core::models::InvocationStats;
+
+        let mut project = InvocationStats::new();
+        project.skills.insert("pdf-generator".to_string(), 2);
+        let mut stats = InvocationStats::new();
+        stats.skills.insert("pdf-generator".to_string(), 5);
+        stats.per_project.insert("/work/app".to_string(), project);
+
+        let mut tab = AgentsTab::new();
+        tab.update_invocation_counts(&stats);
+        assert_eq!(tab.skills[0].invocation_count, 5);
+
+        tab.toggle_project_scope(Some("/work/app".to_string()));
+        assert_eq!(tab.project_scope(), Some("/work/app"));
+        assert_eq!(tab.skills[0].invocation_count, 2);
+
+        // Toggling again returns to global totals
+        tab.toggle_project_scope(Some("/work/app".to_string()));
+        assert_eq!(tab.project_scope(), None);
+        assert_eq!(tab.skills[0].invocation_count, 5);
+
+        // A project without sessions shows zero counts
+        tab.toggle_project_scope(Some("/work/other".to_string()));
+        assert_eq!(tab.skills[0].invocation_count, 0);
+    }
+}
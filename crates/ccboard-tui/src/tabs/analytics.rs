@@ -1,9 +1,13 @@
 //! Analytics tab - Trends, forecasting, patterns, insights, anomalies with 5 sub-views
 
+use crate::components::DateRangePrompt;
 use crate::empty_state;
 use crate::theme::Palette;
-use ccboard_core::analytics::{AnalyticsData, AnomalySeverity, Period};
+use ccboard_core::analytics::{
+    AnalyticsData, AnomalySeverity, DateRange, Period, NO_BRANCH_LABEL, UNKNOWN_AUTHOR_LABEL,
+};
 use ccboard_core::store::DataStore;
+use ccboard_types::Currency;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -34,6 +38,18 @@ pub enum AnalyticsView {
 }
 
 impl AnalyticsView {
+    /// Every view, in Tab order
+    pub const ALL: [Self; 8] = [
+        Self::Overview,
+        Self::Trends,
+        Self::Patterns,
+        Self::Insights,
+        Self::Anomalies,
+        Self::Costs,
+        Self::Heatmap,
+        Self::Discover,
+    ];
+
     /// Cycle to next view
     pub fn next(self) -> Self {
         match self {
@@ -77,6 +93,62 @@ impl AnalyticsView {
     }
 }
 
+impl std::str::FromStr for AnalyticsView {
+    type Err = String;
+
+    /// Parse a view name (case-insensitive); "insights" is accepted for Summary
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.trim().to_ascii_lowercase();
+        if name == "insights" {
+            return Ok(Self::Insights);
+        }
+        Self::ALL
+            .into_iter()
+            .find(|view| view.name().eq_ignore_ascii_case(&name))
+            .ok_or_else(|| {
+                let valid: Vec<String> = Self::ALL
+                    .iter()
+                    .map(|view| view.name().to_ascii_lowercase())
+                    .collect();
+                format!(
+                    "unknown analytics view '{}' (valid: {})",
+                    name,
+                    valid.join(", ")
+                )
+            })
+    }
+}
+
+/// Time bucket of the Trends chart (d/w/m keys)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrendGranularity {
+    #[default]
+    Daily,
+    /// ISO weeks, starting Monday
+    Weekly,
+    Monthly,
+}
+
+impl TrendGranularity {
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Daily => "Daily",
+            Self::Weekly => "Weekly",
+            Self::Monthly => "Monthly",
+        }
+    }
+
+    /// X-axis title
+    fn axis_title(&self) -> &'static str {
+        match self {
+            Self::Daily => "Days",
+            Self::Weekly => "Weeks",
+            Self::Monthly => "Months",
+        }
+    }
+}
+
 /// State for the Discover sub-view
 #[derive(Debug, Clone)]
 struct DiscoverState {
@@ -145,6 +217,12 @@ pub struct AnalyticsTab {
     tool_cost_scroll: usize,
     /// Discover sub-view state
     discover: DiscoverState,
+    /// Bucket size of the Trends chart
+    trend_granularity: TrendGranularity,
+    /// Start/end input opened with `c`
+    range_prompt: DateRangePrompt,
+    /// Range last applied from the prompt (shown in the header while active)
+    picked_range: Option<Period>,
 }
 
 impl Default for AnalyticsTab {
@@ -163,6 +241,9 @@ impl AnalyticsTab {
             leaderboard_sort_desc: true,
             tool_cost_scroll: 0,
             discover: DiscoverState::default(),
+            trend_granularity: TrendGranularity::default(),
+            range_prompt: DateRangePrompt::new(),
+            picked_range: None,
         }
     }
 
@@ -171,11 +252,62 @@ impl AnalyticsTab {
         self.current_period
     }
 
-    /// Set period (F1-F4 keys)
+    /// Set period (F1-F5 keys)
     pub fn set_period(&mut self, period: Period) {
         self.current_period = period;
     }
 
+    /// Calendar month before the selected one (F5), or the current month when
+    /// no month is selected yet
+    pub fn previous_month_period(&self) -> Period {
+        use chrono::{Datelike, Local};
+
+        let (year, month) = match self.current_period {
+            Period::Custom { start, .. } => {
+                let start = start.with_timezone(&Local);
+                if start.month() == 1 {
+                    (start.year() - 1, 12)
+                } else {
+                    (start.year(), start.month() - 1)
+                }
+            }
+            _ => {
+                let today = Local::now();
+                (today.year(), today.month())
+            }
+        };
+        DateRange::parse(&format!("{:04}-{:02}", year, month))
+            .map(|range| Period::from_date_range(&range))
+            .unwrap_or(self.current_period)
+    }
+
+    /// Open the custom range prompt (c key), pre-filled with the current period
+    pub fn open_range_picker(&mut self) {
+        use chrono::Local;
+
+        let (start, end) = self.current_period.bounds();
+        // `end` is exclusive: pre-fill the last day it covers
+        let last = (end - chrono::Duration::seconds(1)).max(start);
+        self.range_prompt.show(
+            start.with_timezone(&Local).date_naive(),
+            last.with_timezone(&Local).date_naive(),
+        );
+    }
+
+    /// Whether the custom range prompt is capturing keys
+    pub fn is_range_picker_open(&self) -> bool {
+        self.range_prompt.is_visible()
+    }
+
+    /// Feed a key to the range prompt; returns the new period once applied
+    pub fn handle_range_key(&mut self, key: crossterm::event::KeyCode) -> Option<Period> {
+        let range = self.range_prompt.handle_key(key)?;
+        let period = Period::from_date_range(&range);
+        self.current_period = period;
+        self.picked_range = Some(period);
+        Some(period)
+    }
+
     /// Cycle to next view (Tab key)
     pub fn next_view(&mut self) {
         self.current_view = self.current_view.next();
@@ -183,6 +315,13 @@ impl AnalyticsTab {
         self.tool_cost_scroll = 0;
     }
 
+    /// Jump straight to `view`
+    pub fn set_view(&mut self, view: AnalyticsView) {
+        self.current_view = view;
+        self.scroll_offset = 0;
+        self.tool_cost_scroll = 0;
+    }
+
     /// Cycle to previous view (Shift+Tab key)
     pub fn prev_view(&mut self) {
         self.current_view = self.current_view.prev();
@@ -226,6 +365,16 @@ impl AnalyticsTab {
         self.leaderboard_sort_desc = !self.leaderboard_sort_desc;
     }
 
+    /// Set the Trends chart granularity (d/w/m keys)
+    pub fn set_trend_granularity(&mut self, granularity: TrendGranularity) {
+        self.trend_granularity = granularity;
+    }
+
+    /// Get the Trends chart granularity
+    pub fn trend_granularity(&self) -> TrendGranularity {
+        self.trend_granularity
+    }
+
     /// Get current view
     pub fn current_view(&self) -> AnalyticsView {
         self.current_view
@@ -249,11 +398,12 @@ impl AnalyticsTab {
         area: Rect,
         analytics: Option<&AnalyticsData>,
         store: Option<&Arc<DataStore>>,
-        _scheme: ccboard_core::models::config::ColorScheme,
+        scheme: ccboard_core::models::config::ColorScheme,
     ) {
         use tracing::debug;
 
-        let p = Palette::new(_scheme);
+        let p = Palette::new(scheme);
+        let currency = store.map(|s| s.currency()).unwrap_or_default();
 
         debug!(
             has_analytics = analytics.is_some(),
@@ -274,10 +424,13 @@ impl AnalyticsTab {
         // Discover view renders independently of analytics data
         if self.current_view == AnalyticsView::Discover {
             self.render_discover(frame, chunks[1], store, &p);
+            self.range_prompt.render(frame, area, scheme);
             return;
         }
 
-        match analytics {
+        // Data of another period is stale: show "Computing..." until the
+        // background computation for the selected period lands
+        match analytics.filter(|data| data.period == self.current_period) {
             Some(data) => {
                 debug!(
                     insights_count = data.insights.len(),
@@ -285,13 +438,19 @@ impl AnalyticsTab {
                 );
                 match self.current_view {
                     AnalyticsView::Overview => {
-                        self.render_overview(frame, chunks[1], data, store, &p)
+                        self.render_overview(frame, chunks[1], data, store, &currency, &p)
                     }
                     AnalyticsView::Trends => self.render_trends(frame, chunks[1], data, &p),
-                    AnalyticsView::Patterns => self.render_patterns(frame, chunks[1], data, &p),
+                    AnalyticsView::Patterns => {
+                        self.render_patterns(frame, chunks[1], data, &currency, &p)
+                    }
                     AnalyticsView::Insights => self.render_insights(frame, chunks[1], data, &p),
-                    AnalyticsView::Anomalies => self.render_anomalies(frame, chunks[1], data, &p),
-                    AnalyticsView::Costs => self.render_costs(frame, chunks[1], data, &p),
+                    AnalyticsView::Anomalies => {
+                        self.render_anomalies(frame, chunks[1], data, &currency, &p)
+                    }
+                    AnalyticsView::Costs => {
+                        self.render_costs(frame, chunks[1], data, &currency, &p)
+                    }
                     AnalyticsView::Heatmap => self.render_heatmap(frame, chunks[1], data, &p),
                     AnalyticsView::Discover => unreachable!("handled above"),
                 }
@@ -301,6 +460,8 @@ impl AnalyticsTab {
                 self.render_loading(frame, chunks[1], &p)
             }
         }
+
+        self.range_prompt.render(frame, area, scheme);
     }
 
     /// Render header with period selector and view tabs
@@ -312,18 +473,40 @@ impl AnalyticsTab {
         _store: Option<&Arc<DataStore>>,
         p: &Palette,
     ) {
-        let chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Length(35), Constraint::Min(0)])
-            .split(area);
-
-        // Period selector (left)
+        // Period selector (left); F5 steps back one calendar month per press,
+        // `c` replaces it with the range typed in the prompt
+        let picked = self.picked_range == Some(self.current_period);
+        let month_label = match self.current_period {
+            Period::Custom { .. } if picked => format!("c:{}", self.current_period.display(0)),
+            Period::Custom { start, .. } => {
+                format!("F5:{}", start.with_timezone(&chrono::Local).format("%Y-%m"))
+            }
+            _ => "F5:Month".to_string(),
+        };
         let periods: &[(&str, bool)] = &[
             ("F1:7d", matches!(self.current_period, Period::Days(7))),
             ("F2:30d", matches!(self.current_period, Period::Days(30))),
             ("F3:90d", matches!(self.current_period, Period::Days(90))),
             ("F4:All", matches!(self.current_period, Period::Available)),
+            (
+                &month_label,
+                matches!(self.current_period, Period::Custom { .. }),
+            ),
         ];
+        // 1 leading space, " label " + 1 space per period, 2 border columns
+        let selector_width = periods
+            .iter()
+            .map(|(label, _)| label.chars().count() as u16 + 3)
+            .sum::<u16>()
+            + 3;
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(selector_width.max(48)),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
         let mut period_text = vec![Span::styled(" ", Style::default())];
         for (label, is_active) in periods {
             if *is_active {
@@ -421,6 +604,7 @@ impl AnalyticsTab {
         area: Rect,
         data: &AnalyticsData,
         store: Option<&Arc<DataStore>>,
+        currency: &Currency,
         p: &Palette,
     ) {
         let chunks = Layout::default()
@@ -436,11 +620,11 @@ impl AnalyticsTab {
             .split(area);
 
         // Summary cards
-        self.render_summary_cards(frame, chunks[0], data, p);
+        self.render_summary_cards(frame, chunks[0], data, currency, p);
 
         // Budget status (if configured)
         if let Some(store) = store {
-            self.render_budget_status(frame, chunks[1], data, store, p);
+            self.render_budget_status(frame, chunks[1], data, store, currency, p);
         }
 
         // Token sparkline
@@ -448,7 +632,7 @@ impl AnalyticsTab {
 
         // Project leaderboard
         if let Some(store) = store {
-            self.render_project_leaderboard(frame, chunks[3], store, p);
+            self.render_project_leaderboard(frame, chunks[3], store, currency, p);
         }
 
         // Top insights preview
@@ -461,6 +645,7 @@ impl AnalyticsTab {
         frame: &mut Frame,
         area: Rect,
         data: &AnalyticsData,
+        currency: &Currency,
         p: &Palette,
     ) {
         let chunks = Layout::default()
@@ -499,14 +684,18 @@ impl AnalyticsTab {
         );
 
         // Monthly cost estimate
-        let cost_display = format!("${:.2}", data.forecast.monthly_cost_estimate);
+        let cost_display = currency.format_cost(data.forecast.monthly_cost_estimate);
+        let cost_subtitle = match data.forecast.monthly_cost_margin() {
+            Some(margin) => format!("monthly ±{}", currency.format_cost(margin)),
+            None => "monthly".to_string(),
+        };
         self.render_stat_card(
             frame,
             chunks[2],
-            "$ Cost Est",
+            &format!("{} Cost Est", currency.symbol),
             &cost_display,
             p.warning,
-            "monthly",
+            &cost_subtitle,
             p,
         );
 
@@ -537,20 +726,14 @@ impl AnalyticsTab {
         area: Rect,
         data: &AnalyticsData,
         store: &Arc<DataStore>,
+        currency: &Currency,
         p: &Palette,
     ) {
-        use ccboard_core::analytics::generate_budget_alerts;
-
         let settings = store.settings();
         let budget_config = settings.merged.budget.as_ref();
 
         if let Some(config) = budget_config {
-            let alerts = generate_budget_alerts(
-                &data.trends,
-                &data.forecast,
-                config.monthly_limit,
-                config.warning_threshold,
-            );
+            let alerts = store.budget_alerts(data);
 
             let current_cost = data.forecast.monthly_cost_estimate;
             let budget = config.monthly_limit.unwrap_or(0.0);
@@ -578,13 +761,13 @@ impl AnalyticsTab {
                 Line::from(vec![
                     Span::styled("Monthly Est: ", Style::default().fg(p.muted)),
                     Span::styled(
-                        format!("${:.2}", current_cost),
+                        currency.format_cost(current_cost),
                         Style::default().fg(p.focus).bold(),
                     ),
                 ]),
                 Line::from(vec![
                     Span::styled("Budget:      ", Style::default().fg(p.muted)),
-                    Span::styled(format!("${:.2}", budget), Style::default().fg(p.fg)),
+                    Span::styled(currency.format_cost(budget), Style::default().fg(p.fg)),
                     Span::raw(" "),
                     Span::styled(bar, Style::default().fg(bar_color)),
                     Span::raw(" "),
@@ -596,7 +779,7 @@ impl AnalyticsTab {
                 Line::from(vec![
                     Span::styled("Remaining:   ", Style::default().fg(p.muted)),
                     Span::styled(
-                        format!("${:.2} ({:.0}%)", remaining, 100.0 - pct),
+                        format!("{} ({:.0}%)", currency.format_cost(remaining), 100.0 - pct),
                         Style::default().fg(p.fg),
                     ),
                 ]),
@@ -619,12 +802,54 @@ impl AnalyticsTab {
                     Alert::ProjectedOverage { overage, .. } => {
                         lines.push(Line::from(vec![Span::styled(
                             format!(
-                                "💡 TIP: Projected overage: ${:.2} if trend continues",
-                                overage
+                                "💡 TIP: Projected overage: {} if trend continues",
+                                currency.format_cost(*overage)
                             ),
                             Style::default().fg(p.warning),
                         )]));
                     }
+                    Alert::DailyBudgetExceeded {
+                        projected, budget, ..
+                    } => {
+                        lines.push(Line::from(vec![Span::styled(
+                            format!(
+                                "⚠️ Today on track for {} (daily budget {})",
+                                currency.format_cost(*projected),
+                                currency.format_cost(*budget)
+                            ),
+                            Style::default().fg(p.error),
+                        )]));
+                    }
+                    Alert::BlockBudgetExceeded {
+                        projected, budget, ..
+                    } => {
+                        lines.push(Line::from(vec![Span::styled(
+                            format!(
+                                "⚠️ Current block on track for {} (block budget {})",
+                                currency.format_cost(*projected),
+                                currency.format_cost(*budget)
+                            ),
+                            Style::default().fg(p.error),
+                        )]));
+                    }
+                    Alert::ProjectBudgetWarning {
+                        project,
+                        spent,
+                        budget,
+                        pct,
+                    } => {
+                        let name = project.rsplit('/').next().unwrap_or(project);
+                        lines.push(Line::from(vec![Span::styled(
+                            format!(
+                                "⚠️ {}: {} of {} project budget ({:.0}%)",
+                                name,
+                                currency.format_cost(*spent),
+                                currency.format_cost(*budget),
+                                pct
+                            ),
+                            Style::default().fg(p.error),
+                        )]));
+                    }
                     _ => {}
                 }
             }
@@ -828,31 +1053,46 @@ impl AnalyticsTab {
 
     /// Render trends sub-view (time series charts)
     fn render_trends(&self, frame: &mut Frame, area: Rect, data: &AnalyticsData, p: &Palette) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(9)])
+            .split(area);
+        let area = chunks[0];
+        self.render_cache_hit_ratio(frame, chunks[1], data, p);
+
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .style(Style::default().bg(p.surface))
-            .title("Trends - Token & Session Activity Over Time");
+            .title(format!(
+                "Trends - {} Token & Session Activity (d/w/m)",
+                self.trend_granularity.name()
+            ));
+
+        let trends = &data.trends;
+        let (tokens, sessions) = match self.trend_granularity {
+            TrendGranularity::Daily => (&trends.daily_tokens, &trends.daily_sessions),
+            TrendGranularity::Weekly => (&trends.weekly_tokens, &trends.weekly_sessions),
+            TrendGranularity::Monthly => (&trends.monthly_tokens, &trends.monthly_sessions),
+        };
 
         // Prepare data points for chart
-        let token_data: Vec<(f64, f64)> = data
-            .trends
-            .daily_tokens
+        let token_data: Vec<(f64, f64)> = tokens
             .iter()
             .enumerate()
             .map(|(i, &tokens)| (i as f64, tokens as f64))
             .collect();
 
-        let session_data: Vec<(f64, f64)> = data
-            .trends
-            .daily_sessions
+        let session_data: Vec<(f64, f64)> = sessions
             .iter()
             .enumerate()
             .map(|(i, &sessions)| (i as f64, sessions as f64 * 100.0)) // Scale for visibility
             .collect();
 
-        // Forecast line using linear regression (30 days ahead)
-        let forecast_data = if data.forecast.unavailable_reason.is_none() && !token_data.is_empty()
+        // Forecast line using linear regression (30 days ahead, daily view only)
+        let forecast_data = if self.trend_granularity == TrendGranularity::Daily
+            && data.forecast.unavailable_reason.is_none()
+            && !token_data.is_empty()
         {
             let last_day = token_data.len() as f64 - 1.0;
             let last_tokens = token_data.last().map(|p| p.1).unwrap_or(0.0);
@@ -860,7 +1100,7 @@ impl AnalyticsTab {
             // Compute linear regression: y = slope * x + intercept
             let n = token_data.len() as f64;
             let sum_x: f64 = (0..token_data.len()).map(|i| i as f64).sum();
-            let sum_y: f64 = data.trends.daily_tokens.iter().map(|&t| t as f64).sum();
+            let sum_y: f64 = tokens.iter().map(|&t| t as f64).sum();
             let sum_xx: f64 = (0..token_data.len()).map(|i| (i as f64).powi(2)).sum();
             let sum_xy: f64 = token_data.iter().map(|(x, y)| x * y).sum();
 
@@ -879,12 +1119,31 @@ impl AnalyticsTab {
             vec![]
         };
 
-        let max_tokens = data.trends.daily_tokens.iter().max().copied().unwrap_or(1) as f64;
-        let max_with_forecast = if !forecast_data.is_empty() {
-            forecast_data.iter().map(|p| p.1).fold(max_tokens, f64::max)
-        } else {
-            max_tokens
+        // ±1 standard error band around the forecast, scaled from the monthly cost band
+        let band_ratio = data
+            .forecast
+            .monthly_cost_margin()
+            .filter(|_| data.forecast.monthly_cost_estimate > 0.0)
+            .map(|margin| margin / data.forecast.monthly_cost_estimate);
+        let band = |sign: f64| -> Vec<(f64, f64)> {
+            band_ratio
+                .map(|ratio| {
+                    forecast_data
+                        .iter()
+                        .skip(1)
+                        .map(|&(x, y)| (x, (y * (1.0 + sign * ratio)).max(0.0)))
+                        .collect()
+                })
+                .unwrap_or_default()
         };
+        let (band_high, band_low) = (band(1.0), band(-1.0));
+
+        let max_tokens = tokens.iter().max().copied().unwrap_or(1) as f64;
+        let max_with_forecast = forecast_data
+            .iter()
+            .chain(&band_high)
+            .map(|p| p.1)
+            .fold(max_tokens, f64::max);
 
         let mut datasets = vec![
             Dataset::default()
@@ -920,24 +1179,44 @@ impl AnalyticsTab {
                     .style(Style::default().fg(forecast_color))
                     .data(&forecast_data),
             );
+
+            if !band_high.is_empty() {
+                datasets.push(
+                    Dataset::default()
+                        .name("±1 std err")
+                        .marker(symbols::Marker::Dot)
+                        .graph_type(GraphType::Scatter)
+                        .style(Style::default().fg(p.muted))
+                        .data(&band_high),
+                );
+                datasets.push(
+                    Dataset::default()
+                        .marker(symbols::Marker::Dot)
+                        .graph_type(GraphType::Scatter)
+                        .style(Style::default().fg(p.muted))
+                        .data(&band_low),
+                );
+            }
         }
 
         // X-axis bounds: historical + 30 days forecast
         let x_max = if !forecast_data.is_empty() {
-            data.trends.dates.len() as f64 + 30.0
+            tokens.len() as f64 + 30.0
         } else {
-            data.trends.dates.len() as f64
+            tokens.len() as f64
         };
 
-        let x_labels = vec![
+        let mut x_labels = vec![
             Span::raw("0"),
-            Span::raw(format!("{}", data.trends.dates.len() / 2)),
-            Span::raw(format!("{}", data.trends.dates.len())),
-            Span::styled(
+            Span::raw(format!("{}", tokens.len() / 2)),
+            Span::raw(format!("{}", tokens.len())),
+        ];
+        if !forecast_data.is_empty() {
+            x_labels.push(Span::styled(
                 "+30d",
                 Style::default().fg(p.muted).add_modifier(Modifier::ITALIC),
-            ),
-        ];
+            ));
+        }
 
         let y_labels = vec![
             Span::raw("0"),
@@ -949,7 +1228,7 @@ impl AnalyticsTab {
             .block(block)
             .x_axis(
                 Axis::default()
-                    .title("Days")
+                    .title(self.trend_granularity.axis_title())
                     .style(Style::default().fg(p.muted))
                     .labels(x_labels)
                     .bounds([0.0, x_max]),
@@ -965,14 +1244,78 @@ impl AnalyticsTab {
         frame.render_widget(chart, area);
     }
 
+    /// Render daily cache hit ratio (0-100%) below the token trends chart
+    fn render_cache_hit_ratio(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        data: &AnalyticsData,
+        p: &Palette,
+    ) {
+        let ratios = &data.trends.daily_cache_hit_ratio;
+        let current = ratios.last().copied().unwrap_or(0.0) * 100.0;
+
+        // Declining ratio = losing caching benefits → highlight in warning color
+        let (title_color, trend_label) = match data.trends.cache_hit_ratio_change(7) {
+            Some((previous, trailing)) if previous - trailing > 0.1 => (p.warning, " ↓ declining"),
+            Some((previous, trailing)) if trailing - previous > 0.1 => (p.success, " ↑ improving"),
+            _ => (p.fg, ""),
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().bg(p.surface))
+            .title(Span::styled(
+                format!("Cache Hit Ratio - {:.0}% latest{}", current, trend_label),
+                Style::default().fg(title_color),
+            ));
+
+        let ratio_data: Vec<(f64, f64)> = ratios
+            .iter()
+            .enumerate()
+            .map(|(i, &ratio)| (i as f64, ratio * 100.0))
+            .collect();
+
+        let datasets = vec![Dataset::default()
+            .name("Cache read / input")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(p.important))
+            .data(&ratio_data)];
+
+        let chart = Chart::new(datasets)
+            .block(block)
+            .x_axis(
+                Axis::default()
+                    .style(Style::default().fg(p.muted))
+                    .bounds([0.0, ratios.len().saturating_sub(1).max(1) as f64]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(Style::default().fg(p.muted))
+                    .labels(vec![Span::raw("0%"), Span::raw("50%"), Span::raw("100%")])
+                    .bounds([0.0, 100.0]),
+            );
+
+        frame.render_widget(chart, area);
+    }
+
     /// Render patterns sub-view (bar charts)
-    fn render_patterns(&self, frame: &mut Frame, area: Rect, data: &AnalyticsData, p: &Palette) {
+    fn render_patterns(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        data: &AnalyticsData,
+        currency: &Currency,
+        p: &Palette,
+    ) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(1)
             .constraints([
                 Constraint::Min(10),    // Activity Heatmap — takes all available space
-                Constraint::Length(12), // Most Used Tools — fixed
+                Constraint::Length(12), // Most Used Tools + Top Branches — fixed
                 Constraint::Length(12), // Model Distribution + Duration — fixed
             ])
             .split(area);
@@ -980,8 +1323,37 @@ impl AnalyticsTab {
         // Activity Heatmap (GitHub-style)
         self.render_activity_heatmap(frame, chunks[0], data, p);
 
-        // Most Used Tools (horizontal bar chart)
-        self.render_most_used_tools(frame, chunks[1], data, p);
+        // Most Used Tools & Cost by Tool (horizontal bar charts), top branches
+        // (side by side), plus top authors when `trackAuthor` attributed any session
+        if data.patterns.per_author.is_empty() {
+            let middle_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(33),
+                    Constraint::Percentage(33),
+                    Constraint::Percentage(34),
+                ])
+                .split(chunks[1]);
+
+            self.render_most_used_tools(frame, middle_chunks[0], data, p);
+            self.render_tool_cost(frame, middle_chunks[1], data, currency, p);
+            self.render_top_branches(frame, middle_chunks[2], data, currency, p);
+        } else {
+            let middle_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(25),
+                ])
+                .split(chunks[1]);
+
+            self.render_most_used_tools(frame, middle_chunks[0], data, p);
+            self.render_tool_cost(frame, middle_chunks[1], data, currency, p);
+            self.render_top_branches(frame, middle_chunks[2], data, currency, p);
+            self.render_top_authors(frame, middle_chunks[3], data, currency, p);
+        }
 
         // Model distribution & duration stats (side by side)
         let bottom_chunks = Layout::default()
@@ -1207,6 +1579,188 @@ impl AnalyticsTab {
         frame.render_widget(paragraph, area);
     }
 
+    /// Render estimated cost per tool (horizontal bar chart)
+    ///
+    /// Tokens of the assistant turn answering a tool result are charged to
+    /// that tool, so this is an approximation of which tools drive spend.
+    fn render_tool_cost(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        data: &AnalyticsData,
+        currency: &Currency,
+        p: &Palette,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().bg(p.surface))
+            .title("Cost by Tool (est.)");
+
+        let top_tools: Vec<_> = data.patterns.tool_cost.iter().take(6).collect();
+        if top_tools.is_empty() {
+            let paragraph = Paragraph::new(Line::from(Span::styled(
+                "No tool cost data available",
+                Style::default().fg(p.muted),
+            )))
+            .block(block)
+            .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let max_cost = top_tools
+            .iter()
+            .map(|t| t.cost)
+            .fold(0.0_f64, f64::max)
+            .max(f64::EPSILON);
+        // Name (12) + cost (9) + tokens (8) + borders
+        let bar_width = area.width.saturating_sub(31).max(4) as f64;
+        let colors = [p.warning, p.important, p.focus, p.success, p.focus, p.muted];
+
+        let lines: Vec<Line> = top_tools
+            .iter()
+            .enumerate()
+            .map(|(i, tool)| {
+                let bar_len = ((tool.cost / max_cost) * bar_width) as usize;
+                let color = colors[i % colors.len()];
+                Line::from(vec![
+                    Span::styled(format!("{:<12}", tool.tool), Style::default().fg(p.fg)),
+                    Span::styled("━".repeat(bar_len), Style::default().fg(color)),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("{} ", currency.format_cost(tool.cost)),
+                        Style::default().fg(color).bold(),
+                    ),
+                    Span::styled(
+                        Self::format_number(tool.tokens),
+                        Style::default().fg(p.muted),
+                    ),
+                ])
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    /// Render the most expensive git branches (sessions, tokens, cost)
+    fn render_top_branches(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        data: &AnalyticsData,
+        currency: &Currency,
+        p: &Palette,
+    ) {
+        let rows: Vec<_> = data
+            .patterns
+            .branch_distribution
+            .iter()
+            .map(|b| (b.branch.as_str(), b.sessions, b.tokens, b.cost))
+            .collect();
+        Self::render_usage_table(
+            frame,
+            area,
+            ("Top Branches (by cost)", "Branch", NO_BRANCH_LABEL),
+            "No branch data available",
+            &rows,
+            currency,
+            p,
+        );
+    }
+
+    /// Render the most expensive git authors (`trackAuthor`)
+    fn render_top_authors(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        data: &AnalyticsData,
+        currency: &Currency,
+        p: &Palette,
+    ) {
+        let rows: Vec<_> = data
+            .patterns
+            .per_author
+            .iter()
+            .map(|a| (a.author.as_str(), a.sessions, a.tokens, a.cost))
+            .collect();
+        Self::render_usage_table(
+            frame,
+            area,
+            ("Top Authors (by cost)", "Author", UNKNOWN_AUTHOR_LABEL),
+            "No author data available",
+            &rows,
+            currency,
+            p,
+        );
+    }
+
+    /// Table of (name, sessions, tokens, cost) rows; `labels` is (title, name
+    /// column, placeholder name shown muted)
+    fn render_usage_table(
+        frame: &mut Frame,
+        area: Rect,
+        (title, name_column, placeholder): (&str, &str, &str),
+        empty_message: &str,
+        usage: &[(&str, usize, u64, f64)],
+        currency: &Currency,
+        p: &Palette,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().bg(p.surface))
+            .title(title.to_string());
+
+        if usage.is_empty() {
+            let paragraph = Paragraph::new(Line::from(Span::styled(
+                empty_message.to_string(),
+                Style::default().fg(p.muted),
+            )))
+            .block(block)
+            .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let header = Row::new(vec![name_column, "Sessions", "Tokens", "Cost"])
+            .style(Style::default().fg(p.muted).add_modifier(Modifier::BOLD));
+
+        // Borders + header leave room for the rest
+        let visible = area.height.saturating_sub(3) as usize;
+        let rows: Vec<Row> = usage
+            .iter()
+            .take(visible)
+            .map(|&(name, sessions, tokens, cost)| {
+                let name_style = if name == placeholder {
+                    Style::default().fg(p.muted).add_modifier(Modifier::ITALIC)
+                } else {
+                    Style::default().fg(p.fg)
+                };
+                Row::new(vec![
+                    Cell::from(name.to_string()).style(name_style),
+                    Cell::from(sessions.to_string()),
+                    Cell::from(Self::format_number(tokens)),
+                    Cell::from(currency.format_cost(cost)).style(Style::default().fg(p.warning)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(12),
+                Constraint::Length(8),
+                Constraint::Length(8),
+                Constraint::Length(9),
+            ],
+        )
+        .header(header)
+        .block(block);
+
+        frame.render_widget(table, area);
+    }
+
     /// Render hourly distribution bar chart
     fn _render_hourly_distribution(
         &self,
@@ -1458,10 +2012,12 @@ impl AnalyticsTab {
         frame: &mut Frame,
         area: Rect,
         store: &Arc<DataStore>,
+        currency: &Currency,
         p: &Palette,
     ) {
         // Get leaderboard data
         let mut entries = store.projects_leaderboard();
+        let budget_config = store.settings().merged.budget;
 
         // Sort based on current selection
         match self.leaderboard_sort {
@@ -1543,7 +2099,10 @@ impl AnalyticsTab {
             };
 
             Cell::from(header).style(Style::default().fg(p.warning).add_modifier(Modifier::BOLD))
-        });
+        })
+        .chain(["Budget (month)", "Primary Model"].map(|title| {
+            Cell::from(title).style(Style::default().fg(p.warning).add_modifier(Modifier::BOLD))
+        }));
 
         let header = Row::new(header_cells).height(1).bottom_margin(1);
 
@@ -1553,13 +2112,29 @@ impl AnalyticsTab {
             .take(5)
             .enumerate()
             .map(|(idx, entry)| {
-                // Highlight top 3 with different colors
+                // Project budget (explicit or global fallback) for this month
+                let budget = budget_config
+                    .as_ref()
+                    .and_then(|b| b.project_budget(&entry.project_path));
+                let over_budget = budget.is_some_and(|b| entry.month_cost > b.monthly_usd);
+
+                // Highlight top 3 with different colors, over-budget rows in red
                 let row_color = match idx {
+                    _ if over_budget => p.error,
                     0 => p.success,
                     1 => p.focus,
                     2 => p.warning,
                     _ => p.fg,
                 };
+                let budget_display = budget
+                    .map(|b| {
+                        format!(
+                            "{}/{}",
+                            currency.format(entry.month_cost, 0),
+                            currency.format(b.monthly_usd, 0)
+                        )
+                    })
+                    .unwrap_or_else(|| "—".to_string());
 
                 let cells = vec![
                     Cell::from(entry.project_name.clone()).style(Style::default().fg(row_color)),
@@ -1567,10 +2142,25 @@ impl AnalyticsTab {
                         .style(Style::default().fg(row_color)),
                     Cell::from(Self::format_number(entry.total_tokens))
                         .style(Style::default().fg(row_color)),
-                    Cell::from(format!("${:.2}", entry.total_cost))
+                    Cell::from(currency.format_cost(entry.total_cost))
                         .style(Style::default().fg(row_color)),
-                    Cell::from(format!("${:.2}", entry.avg_session_cost))
+                    Cell::from(currency.format_cost(entry.avg_session_cost))
                         .style(Style::default().fg(row_color)),
+                    Cell::from(budget_display).style(Style::default().fg(row_color)),
+                    Cell::from(
+                        entry
+                            .primary_model
+                            .as_ref()
+                            .map(|(model, share)| {
+                                format!(
+                                    "{} {:.0}%",
+                                    model.trim_start_matches("claude-"),
+                                    share * 100.0
+                                )
+                            })
+                            .unwrap_or_else(|| "—".to_string()),
+                    )
+                    .style(Style::default().fg(row_color)),
                 ];
 
                 Row::new(cells).height(1)
@@ -1578,11 +2168,13 @@ impl AnalyticsTab {
             .collect();
 
         let widths = [
-            Constraint::Percentage(40), // Project name
-            Constraint::Percentage(15), // Sessions
-            Constraint::Percentage(15), // Tokens
-            Constraint::Percentage(15), // Cost
-            Constraint::Percentage(15), // Avg Cost
+            Constraint::Percentage(22), // Project name
+            Constraint::Percentage(10), // Sessions
+            Constraint::Percentage(11), // Tokens
+            Constraint::Percentage(11), // Cost
+            Constraint::Percentage(11), // Avg Cost
+            Constraint::Percentage(15), // Budget (month)
+            Constraint::Percentage(20), // Primary model
         ];
 
         let table = Table::new(rows, widths)
@@ -1600,7 +2192,14 @@ impl AnalyticsTab {
     }
 
     /// Render anomalies sub-view (Z-score based anomaly detection)
-    fn render_anomalies(&self, frame: &mut Frame, area: Rect, data: &AnalyticsData, p: &Palette) {
+    fn render_anomalies(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        data: &AnalyticsData,
+        currency: &Currency,
+        p: &Palette,
+    ) {
         let anomalies = &data.anomalies;
         let daily_spikes = &data.daily_spikes;
         let session_count = data.sessions_in_period;
@@ -1678,7 +2277,11 @@ impl AnalyticsTab {
                                 Style::default().fg(p.fg),
                             ),
                             Span::styled(
-                                format!("  {}  (avg ${:.3})", s.format_cost(), s.avg_cost),
+                                format!(
+                                    "  {}  (avg {})",
+                                    s.format_cost(currency),
+                                    currency.format(s.avg_cost, 3)
+                                ),
                                 Style::default().fg(p.muted),
                             ),
                             Span::styled(
@@ -1751,7 +2354,7 @@ impl AnalyticsTab {
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
-                    "Anomalies are flagged when tokens or cost exceed 2σ from mean",
+                    "Anomalies are flagged when tokens exceed 2σ or cache ratio drops 2σ",
                     Style::default().fg(p.muted).italic(),
                 )),
             ];
@@ -1811,7 +2414,7 @@ impl AnalyticsTab {
                     )),
                     Cell::from(anomaly.metric.name()),
                     Cell::from(Span::styled(
-                        anomaly.format_value(),
+                        anomaly.format_value(currency),
                         Style::default().fg(severity_color).bold(),
                     )),
                     Cell::from(Span::styled(
@@ -1832,7 +2435,7 @@ impl AnalyticsTab {
             Constraint::Length(4),      // Severity emoji
             Constraint::Length(16),     // Date
             Constraint::Length(10),     // Session ID (truncated)
-            Constraint::Length(8),      // Metric
+            Constraint::Length(11),     // Metric
             Constraint::Length(12),     // Value
             Constraint::Length(8),      // Z-Score
             Constraint::Percentage(15), // Deviation
@@ -1875,7 +2478,14 @@ impl AnalyticsTab {
     }
 
     /// Render Plugins sub-view: per-tool token usage + cost optimization suggestions
-    fn render_costs(&self, frame: &mut Frame, area: Rect, data: &AnalyticsData, p: &Palette) {
+    fn render_costs(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        data: &AnalyticsData,
+        currency: &Currency,
+        p: &Palette,
+    ) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -1885,7 +2495,7 @@ impl AnalyticsTab {
             .split(area);
 
         self.render_tool_token_chart(frame, chunks[0], data, p);
-        self.render_cost_suggestions(frame, chunks[1], data, p);
+        self.render_cost_suggestions(frame, chunks[1], data, currency, p);
     }
 
     /// Render per-tool token usage as a bar chart
@@ -1991,6 +2601,7 @@ impl AnalyticsTab {
         frame: &mut Frame,
         area: Rect,
         data: &AnalyticsData,
+        currency: &Currency,
         p: &Palette,
     ) {
         let title = format!(
@@ -2029,7 +2640,7 @@ impl AnalyticsTab {
             .take(visible)
             .map(|s| {
                 let savings_str = if s.potential_savings > 0.01 {
-                    format!(" [-${:.2}/mo]", s.potential_savings)
+                    format!(" [-{}/mo]", currency.format_cost(s.potential_savings))
                 } else {
                     String::new()
                 };
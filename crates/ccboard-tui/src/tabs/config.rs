@@ -2,7 +2,7 @@
 
 use crate::theme::Palette;
 use ccboard_core::models::config::ColorScheme;
-use ccboard_core::models::{MergedConfig, Settings};
+use ccboard_core::models::{ConfigLayer, MergedConfig, Settings};
 use ccboard_core::parsers::{McpConfig, Rules};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -11,6 +11,7 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
+use std::collections::BTreeMap;
 
 /// Config tab state
 pub struct ConfigTab {
@@ -210,6 +211,7 @@ impl ConfigTab {
             config.global.as_ref(),
             0,
             None,
+            None,
             rules,
             &p,
         );
@@ -220,6 +222,7 @@ impl ConfigTab {
             config.project.as_ref(),
             1,
             None,
+            None,
             rules,
             &p,
         );
@@ -230,6 +233,7 @@ impl ConfigTab {
             config.local.as_ref(),
             2,
             None,
+            None,
             rules,
             &p,
         );
@@ -239,6 +243,7 @@ impl ConfigTab {
             "Merged (Active)",
             Some(&config.merged),
             3,
+            Some(&config.sources),
             mcp_config,
             rules,
             &p,
@@ -263,6 +268,7 @@ impl ConfigTab {
         title: &str,
         settings: Option<&Settings>,
         col_index: usize,
+        sources: Option<&BTreeMap<String, ConfigLayer>>,
         mcp_config: Option<&McpConfig>,
         rules: &Rules,
         p: &Palette,
@@ -312,7 +318,7 @@ impl ConfigTab {
             return;
         };
 
-        let items = self.settings_to_items(settings, col_index == 3, mcp_config, rules, p);
+        let items = self.settings_to_items(settings, col_index == 3, sources, mcp_config, rules, p);
 
         // Clamp scroll state
         if let Some(sel) = self.scroll_states[col_index].selected() {
@@ -331,25 +337,47 @@ impl ConfigTab {
         &self,
         settings: &Settings,
         is_merged: bool,
+        sources: Option<&BTreeMap<String, ConfigLayer>>,
         mcp_config: Option<&McpConfig>,
         rules: &Rules,
         p: &Palette,
     ) -> Vec<ListItem<'static>> {
         let mut items = Vec::new();
 
+        // Layer suffix for a key, shown in the merged column only
+        let tag = |key: &str| {
+            sources
+                .and_then(|s| s.get(key))
+                .map(|layer| format!(" ({})", layer.label()))
+                .unwrap_or_default()
+        };
+        // Permission rules, each tagged with the layer that listed it
+        let rule_list = |list: &str, rules: &[String]| {
+            let rules: Vec<String> = rules
+                .iter()
+                .map(|rule| format!("{}{}", rule, tag(&format!("permissions.{}.{}", list, rule))))
+                .collect();
+            format!("[{}]", rules.join(", "))
+        };
+
         // Model
         if let Some(ref model) = settings.model {
-            items.push(self.make_item("model", model, p.focus, p));
+            items.push(self.make_item("model", &format!("{}{}", model, tag("model")), p.focus, p));
         }
 
         // Theme
         if let Some(ref theme) = settings.theme {
-            items.push(self.make_item("theme", theme, p.important, p));
+            items.push(self.make_item(
+                "theme",
+                &format!("{}{}", theme, tag("theme")),
+                p.important,
+                p,
+            ));
         }
 
         // API Key (masked)
         if settings.api_key.is_some() {
-            items.push(self.make_item("apiKey", "••••••••", p.error, p));
+            items.push(self.make_item("apiKey", &format!("••••••••{}", tag("apiKey")), p.error, p));
         }
 
         // Custom instructions
@@ -371,15 +399,10 @@ impl ConfigTab {
             ))));
 
             if let Some(ref allow) = perms.allow {
-                items.push(self.make_item(
-                    "  allow",
-                    &format!("[{}]", allow.join(", ")),
-                    p.success,
-                    p,
-                ));
+                items.push(self.make_item("  allow", &rule_list("allow", allow), p.success, p));
             }
             if let Some(ref deny) = perms.deny {
-                items.push(self.make_item("  deny", &format!("[{}]", deny.join(", ")), p.error, p));
+                items.push(self.make_item("  deny", &rule_list("deny", deny), p.error, p));
             }
             if let Some(ref allow_bash) = perms.allow_bash {
                 items.push(self.make_item(
@@ -400,7 +423,7 @@ impl ConfigTab {
             if let Some(auto) = perms.auto_approve {
                 items.push(self.make_item(
                     "  autoApprove",
-                    if auto { "true" } else { "false" },
+                    &format!("{}{}", auto, tag("permissions.autoApprove")),
                     if auto { p.success } else { p.warning },
                     p,
                 ));
@@ -408,7 +431,7 @@ impl ConfigTab {
             if let Some(trust) = perms.trust_project {
                 items.push(self.make_item(
                     "  trustProject",
-                    if trust { "true" } else { "false" },
+                    &format!("{}{}", trust, tag("permissions.trustProject")),
                     if trust { p.success } else { p.warning },
                     p,
                 ));
@@ -443,6 +466,7 @@ impl ConfigTab {
 
                 for (key, value) in env.iter().take(5) {
                     let display_val: String = value.chars().take(20).collect();
+                    let display_val = format!("{}{}", display_val, tag(&format!("env.{}", key)));
                     items.push(self.make_item(&format!("  {}", key), &display_val, Color::Blue, p));
                 }
                 if env.len() > 5 {
@@ -1,6 +1,6 @@
 //! Conversation viewer - Displays full session content with syntax highlighting
 
-use crate::components::highlight_matches;
+use crate::components::{has_code_block, highlight_matches, render_code_blocks};
 use crate::theme::Palette;
 use crate::widgets::ToolCallsViewer;
 use ccboard_core::models::config::ColorScheme;
@@ -15,10 +15,6 @@ use ratatui::{
     Frame,
 };
 use std::collections::HashMap;
-use syntect::easy::HighlightLines;
-use syntect::highlighting::ThemeSet;
-use syntect::parsing::SyntaxSet;
-use syntect::util::LinesWithEndings;
 
 /// Message filtering options
 #[derive(Debug, Clone)]
@@ -138,10 +134,6 @@ pub struct ConversationTab {
     /// Loading state
     is_loading: bool,
 
-    /// Syntax highlighting resources
-    syntax_set: SyntaxSet,
-    theme_set: ThemeSet,
-
     /// Message filtering
     filter: MessageFilter,
 
@@ -167,8 +159,6 @@ impl ConversationTab {
             search_results: Vec::new(),
             error: None,
             is_loading: false,
-            syntax_set: SyntaxSet::load_defaults_newlines(),
-            theme_set: ThemeSet::load_defaults(),
             filter: MessageFilter::default(),
             show_filter_panel: false,
             tool_viewers: HashMap::new(),
@@ -698,7 +688,7 @@ impl ConversationTab {
                 height: msg_height as u16,
             };
 
-            self.render_message(frame, msg, msg_area, &self.search_query);
+            self.render_message(frame, msg, msg_area, &self.search_query, &p);
 
             // Use saturating_add to prevent overflow
             y_offset = y_offset.saturating_add(msg_height).saturating_add(1);
@@ -895,10 +885,11 @@ impl ConversationTab {
         msg: &ConversationMessage,
         area: Rect,
         search_query: &str,
+        p: &Palette,
     ) {
         let (role_label, role_color, bg_color) = match msg.role {
             MessageRole::User => ("👤 User", Color::Blue, Color::Rgb(20, 30, 60)),
-            MessageRole::Assistant => ("🤖 Assistant", Color::Green, Color::Rgb(20, 50, 30)),
+            MessageRole::Assistant => ("🤖 Assistant", p.success, Color::Rgb(20, 50, 30)),
             MessageRole::System => ("⚙️ System", Color::Yellow, Color::Rgb(60, 50, 20)),
         };
 
@@ -956,8 +947,8 @@ impl ConversationTab {
     /// Apply syntax highlighting to message content
     fn highlight_content(&self, content: &str, search_query: &str) -> Text<'static> {
         // Check if content contains code blocks (```language)
-        if content.contains("```") {
-            self.highlight_code_blocks(content)
+        if has_code_block(content) {
+            Text::from(render_code_blocks(content))
         } else if !search_query.is_empty() {
             // Plain text with search highlights
             let spans = highlight_matches(content, search_query);
@@ -967,81 +958,6 @@ impl ConversationTab {
             Text::raw(content.to_string())
         }
     }
-
-    /// Highlight code blocks in markdown-style content
-    fn highlight_code_blocks(&self, content: &str) -> Text<'static> {
-        let mut lines = Vec::new();
-        let mut in_code_block = false;
-        let mut current_lang = String::new();
-        let mut code_lines = Vec::new();
-
-        for line in content.lines() {
-            if let Some(rest) = line.strip_prefix("```") {
-                if in_code_block {
-                    // End of code block - apply highlighting
-                    let highlighted = self.highlight_code(&code_lines.join("\n"), &current_lang);
-                    lines.extend(highlighted);
-                    code_lines.clear();
-                    in_code_block = false;
-                } else {
-                    // Start of code block
-                    current_lang = rest.trim().to_string();
-                    in_code_block = true;
-                }
-            } else if in_code_block {
-                code_lines.push(line);
-            } else {
-                // Regular text line
-                lines.push(Line::from(line.to_string()));
-            }
-        }
-
-        // Handle unclosed code block
-        if in_code_block && !code_lines.is_empty() {
-            let highlighted = self.highlight_code(&code_lines.join("\n"), &current_lang);
-            lines.extend(highlighted);
-        }
-
-        Text::from(lines)
-    }
-
-    /// Highlight code snippet with syntect
-    fn highlight_code(&self, code: &str, lang: &str) -> Vec<Line<'static>> {
-        // Find syntax by language name
-        let syntax = self
-            .syntax_set
-            .find_syntax_by_token(lang)
-            .or_else(|| self.syntax_set.find_syntax_by_extension(lang))
-            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
-
-        // Use base16-ocean.dark theme
-        let theme = &self.theme_set.themes["base16-ocean.dark"];
-        let mut highlighter = HighlightLines::new(syntax, theme);
-
-        let mut lines = Vec::new();
-        for line_text in LinesWithEndings::from(code) {
-            let ranges = highlighter
-                .highlight_line(line_text, &self.syntax_set)
-                .unwrap_or_default();
-
-            let spans: Vec<Span> = ranges
-                .into_iter()
-                .map(|(style, text)| {
-                    let fg = syntect_to_ratatui_color(style.foreground);
-                    Span::styled(text.to_string(), Style::default().fg(fg))
-                })
-                .collect();
-
-            lines.push(Line::from(spans));
-        }
-
-        lines
-    }
-}
-
-/// Convert syntect color to ratatui color
-fn syntect_to_ratatui_color(color: syntect::highlighting::Color) -> Color {
-    Color::Rgb(color.r, color.g, color.b)
 }
 
 impl Default for ConversationTab {
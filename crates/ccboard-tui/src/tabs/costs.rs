@@ -1,7 +1,8 @@
 //! Costs tab - Token usage and estimated costs by model
 
 use crate::theme::Palette;
-use ccboard_core::models::{BillingBlockManager, StatsCache};
+use ccboard_core::models::{BillingBlockManager, ModelUsage, StatsCache, TokenUsage};
+use ccboard_types::Currency;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -13,49 +14,32 @@ use ratatui::{
     Frame,
 };
 
-/// Model pricing (per 1M tokens) - approximate as of 2024
-#[derive(Debug, Clone, Copy)]
-struct ModelPricing {
-    input_per_million: f64,
-    output_per_million: f64,
-    cache_read_per_million: f64,
-}
-
-impl ModelPricing {
-    fn opus() -> Self {
-        Self {
-            input_per_million: 15.0,
-            output_per_million: 75.0,
-            cache_read_per_million: 1.5,
-        }
+/// Total, input, output and cache (read + write) cost of a model's usage
+///
+/// Priced through `TokenUsage::cost`, the same source as every other view.
+fn model_costs(model: &str, usage: &ModelUsage) -> (f64, f64, f64, f64) {
+    let tokens = usage.as_token_usage();
+    let input = TokenUsage {
+        input_tokens: tokens.input_tokens,
+        ..Default::default()
     }
-
-    fn sonnet() -> Self {
-        Self {
-            input_per_million: 3.0,
-            output_per_million: 15.0,
-            cache_read_per_million: 0.3,
-        }
+    .cost(model);
+    let output = TokenUsage {
+        output_tokens: tokens.output_tokens,
+        ..Default::default()
     }
+    .cost(model);
+    let total = tokens.cost(model);
+    (total, input, output, total - input - output)
+}
 
-    fn haiku() -> Self {
-        Self {
-            input_per_million: 0.25,
-            output_per_million: 1.25,
-            cache_read_per_million: 0.03,
-        }
-    }
-
-    fn for_model(model: &str) -> Self {
-        let model_lower = model.to_lowercase();
-        if model_lower.contains("opus") {
-            Self::opus()
-        } else if model_lower.contains("haiku") {
-            Self::haiku()
-        } else {
-            // Default to sonnet pricing
-            Self::sonnet()
-        }
+/// Heatmap intensity (0-4) of a cell's cost: 0 is no spend, then quarters
+/// of the most expensive cell
+fn cost_level(cost: f64, max: f64) -> usize {
+    if cost <= 0.0 || max <= 0.0 {
+        0
+    } else {
+        ((cost / max * 4.0).ceil() as usize).clamp(1, 4)
     }
 }
 
@@ -98,12 +82,28 @@ impl SortMode {
 pub struct CostsTab {
     /// Selected model index
     model_state: ListState,
-    /// View mode (0=Overview, 1=By Model, 2=Daily, 3=Billing Blocks, 4=Leaderboard)
+    /// View mode (0=Overview, 1=By Model, 2=Daily, 3=Billing Blocks, 4=Leaderboard,
+    /// 5=Per Project, 6=What-If, 7=Heatmap)
     view_mode: usize,
     /// Sort mode
     sort_mode: SortMode,
+    /// By Model view: group dated revisions into model families
+    group_by_family: bool,
+    /// What-If view: index into the models sessions actually ran on
+    swap_from: usize,
+    /// What-If view: index into the candidate target models
+    swap_to: usize,
+    /// Heatmap view: cost by start time, computed on the first render after
+    /// a data refresh
+    cost_heatmap: Option<[[f64; 24]; 7]>,
 }
 
+/// Number of view modes
+const VIEW_COUNT: usize = 8;
+
+/// Target models always offered in the What-If view (canonical IDs)
+const SWAP_TARGETS: &[&str] = &["claude-haiku-4-5", "claude-sonnet-4-5", "claude-opus-4-5"];
+
 impl Default for CostsTab {
     fn default() -> Self {
         Self::new()
@@ -119,19 +119,38 @@ impl CostsTab {
             model_state,
             view_mode: 0,
             sort_mode: SortMode::CostDesc,
+            group_by_family: false,
+            swap_from: 0,
+            swap_to: 1,
+            cost_heatmap: None,
         }
     }
 
+    /// Drop data derived from sessions, recomputed on next render
+    pub fn invalidate_cache(&mut self) {
+        self.cost_heatmap = None;
+    }
+
     /// Handle key input
     pub fn handle_key(&mut self, key: crossterm::event::KeyCode) {
         use crossterm::event::KeyCode;
 
         match key {
             KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => {
-                self.view_mode = (self.view_mode + 1) % 6;
+                self.view_mode = (self.view_mode + 1) % VIEW_COUNT;
             }
             KeyCode::Left | KeyCode::Char('h') => {
-                self.view_mode = (self.view_mode + 5) % 6;
+                self.view_mode = (self.view_mode + VIEW_COUNT - 1) % VIEW_COUNT;
+            }
+            // What-If view: ↑/↓ pick the source model, m cycles the target
+            KeyCode::Up | KeyCode::Char('k') if self.view_mode == 6 => {
+                self.swap_from = self.swap_from.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.view_mode == 6 => {
+                self.swap_from += 1;
+            }
+            KeyCode::Char('m') if self.view_mode == 6 => {
+                self.swap_to += 1;
             }
             KeyCode::Up | KeyCode::Char('k') => {
                 let current = self.model_state.selected().unwrap_or(0);
@@ -145,6 +164,10 @@ impl CostsTab {
                 // Toggle sort mode
                 self.sort_mode = self.sort_mode.next();
             }
+            KeyCode::Char('f') if self.view_mode == 1 => {
+                self.group_by_family = !self.group_by_family;
+                self.model_state.select(Some(0));
+            }
             _ => {}
         }
     }
@@ -160,6 +183,7 @@ impl CostsTab {
         store: Option<&ccboard_core::store::DataStore>,
     ) {
         let p = Palette::new(_scheme);
+        let currency = store.map(|s| s.currency()).unwrap_or_default();
 
         // Main layout
         let chunks = Layout::default()
@@ -175,12 +199,21 @@ impl CostsTab {
 
         // Render content based on view mode
         match self.view_mode {
-            0 => self.render_overview(frame, chunks[1], stats, store, &p),
-            1 => self.render_by_model(frame, chunks[1], stats, &p),
+            0 => self.render_overview(frame, chunks[1], stats, store, &currency, &p),
+            1 => self.render_by_model(frame, chunks[1], stats, &currency, &p),
             2 => self.render_daily(frame, chunks[1], stats, &p),
-            3 => self.render_billing_blocks(frame, chunks[1], billing_blocks, &p),
+            3 => self.render_billing_blocks(frame, chunks[1], billing_blocks, &currency, &p),
             4 => self.render_leaderboard(frame, chunks[1], store, &p),
-            5 => self.render_per_project(frame, chunks[1], store, &p),
+            5 => self.render_per_project(frame, chunks[1], store, &currency, &p),
+            6 => self.render_model_swap(frame, chunks[1], store, &currency, &p),
+            7 => {
+                let heatmap = *self.cost_heatmap.get_or_insert_with(|| {
+                    ccboard_core::analytics::cost_heatmap(
+                        &store.map(|s| s.aggregate_sessions()).unwrap_or_default(),
+                    )
+                });
+                self.render_cost_heatmap(frame, chunks[1], &heatmap, &currency, &p)
+            }
             _ => {}
         }
     }
@@ -193,6 +226,8 @@ impl CostsTab {
             "Usage Periods",
             "Top Sessions",
             "Per Project",
+            "What-If",
+            "Heatmap",
         ];
 
         let mut spans = Vec::new();
@@ -229,6 +264,7 @@ impl CostsTab {
         area: Rect,
         stats: Option<&StatsCache>,
         store: Option<&ccboard_core::store::DataStore>,
+        currency: &Currency,
         p: &Palette,
     ) {
         let chunks = Layout::default()
@@ -237,22 +273,70 @@ impl CostsTab {
             .constraints([
                 Constraint::Length(7),  // Total cost card
                 Constraint::Length(5),  // Quota gauge (NEW)
-                Constraint::Length(10), // Token breakdown
+                Constraint::Length(13), // Token breakdown
                 Constraint::Min(0),     // Model distribution
             ])
             .split(area);
 
         // Total cost card
-        self.render_total_cost(frame, chunks[0], stats, p);
+        self.render_total_cost(frame, chunks[0], stats, currency, p);
+
+        // Quota gauges: monthly, plus daily / per-block when configured
+        let budget = store.and_then(|s| s.settings().merged.budget);
+        let short_term: Vec<(&str, f64, f64, f64)> = match (budget.as_ref(), store) {
+            (Some(b), Some(s))
+                if b.daily_budget_usd.is_some() || b.per_block_budget_usd.is_some() =>
+            {
+                let spend = s.current_spend();
+                [
+                    (
+                        " 📅 Daily Budget ",
+                        b.daily_budget_usd,
+                        spend.today,
+                        spend.today_projected,
+                    ),
+                    (
+                        " ⏱ Block Budget ",
+                        b.per_block_budget_usd,
+                        spend.block,
+                        spend.block_projected,
+                    ),
+                ]
+                .into_iter()
+                .filter_map(|(title, limit, spent, projected)| {
+                    limit.map(|limit| (title, limit, spent, projected))
+                })
+                .collect()
+            }
+            _ => Vec::new(),
+        };
 
-        // Quota gauge
-        self.render_quota_gauge(frame, chunks[1], store, p);
+        match budget.as_ref() {
+            Some(budget) if !short_term.is_empty() => {
+                let gauge_areas = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints(vec![
+                        Constraint::Ratio(1, short_term.len() as u32 + 1);
+                        short_term.len() + 1
+                    ])
+                    .split(chunks[1]);
+                self.render_quota_gauge(frame, gauge_areas[0], store, currency, p);
+                for (area, (title, limit, spent, projected)) in
+                    gauge_areas[1..].iter().zip(short_term)
+                {
+                    self.render_spend_gauge(
+                        frame, *area, title, spent, projected, limit, budget, currency, p,
+                    );
+                }
+            }
+            _ => self.render_quota_gauge(frame, chunks[1], store, currency, p),
+        }
 
         // Token breakdown
-        self.render_token_breakdown(frame, chunks[2], stats, p);
+        self.render_token_breakdown(frame, chunks[2], stats, store, p);
 
         // Model distribution
-        self.render_model_distribution(frame, chunks[3], stats, p);
+        self.render_model_distribution(frame, chunks[3], stats, currency, p);
     }
 
     fn render_total_cost(
@@ -260,6 +344,7 @@ impl CostsTab {
         frame: &mut Frame,
         area: Rect,
         stats: Option<&StatsCache>,
+        currency: &Currency,
         p: &Palette,
     ) {
         let block = Block::default()
@@ -281,14 +366,7 @@ impl CostsTab {
                 let mut details = Vec::new();
 
                 for (model, usage) in &s.model_usage {
-                    let pricing = ModelPricing::for_model(model);
-                    let input_cost =
-                        usage.input_tokens as f64 / 1_000_000.0 * pricing.input_per_million;
-                    let output_cost =
-                        usage.output_tokens as f64 / 1_000_000.0 * pricing.output_per_million;
-                    let cache_cost = usage.cache_read_input_tokens as f64 / 1_000_000.0
-                        * pricing.cache_read_per_million;
-                    let model_total = input_cost + output_cost + cache_cost;
+                    let (model_total, ..) = model_costs(model, usage);
                     total += model_total;
                     details.push((model.clone(), model_total));
                 }
@@ -306,7 +384,7 @@ impl CostsTab {
         let cost_display = Paragraph::new(vec![
             Line::from(""),
             Line::from(Span::styled(
-                format!("${:.2}", total_cost),
+                currency.format_cost(total_cost),
                 Style::default().fg(p.success).add_modifier(Modifier::BOLD),
             )),
             Line::from(Span::styled(
@@ -327,7 +405,10 @@ impl CostsTab {
             .map(|(model, cost)| {
                 Line::from(vec![
                     Span::styled(Self::format_model_name(model), Style::default().fg(p.fg)),
-                    Span::styled(format!(" ${:.2}", cost), Style::default().fg(p.warning)),
+                    Span::styled(
+                        format!(" {}", currency.format_cost(*cost)),
+                        Style::default().fg(p.warning),
+                    ),
                 ])
             })
             .collect();
@@ -341,6 +422,7 @@ impl CostsTab {
         frame: &mut Frame,
         area: Rect,
         store: Option<&ccboard_core::store::DataStore>,
+        currency: &Currency,
         p: &Palette,
     ) {
         use ccboard_core::quota::AlertLevel;
@@ -362,10 +444,10 @@ impl CostsTab {
 
             // Build label with current cost and usage %
             let label = format!(
-                "${:.2} / {} ({:.1}%)",
-                q.current_cost,
+                "{} / {} ({:.1}%)",
+                currency.format_cost(q.current_cost),
                 q.budget_limit
-                    .map(|l| format!("${:.2}", l))
+                    .map(|c| currency.format_cost(c))
                     .unwrap_or_else(|| "∞".to_string()),
                 q.usage_pct
             );
@@ -373,11 +455,15 @@ impl CostsTab {
             // Subtitle with projection
             let sub = if let Some(overage) = q.projected_overage {
                 format!(
-                    "Projected: ${:.2} (${:.2} over)",
-                    q.projected_monthly_cost, overage
+                    "Projected: {} ({} over)",
+                    currency.format_cost(q.projected_monthly_cost),
+                    currency.format_cost(overage)
                 )
             } else {
-                format!("Projected: ${:.2}", q.projected_monthly_cost)
+                format!(
+                    "Projected: {}",
+                    currency.format_cost(q.projected_monthly_cost)
+                )
             };
 
             (ratio, color, label, sub)
@@ -424,11 +510,81 @@ impl CostsTab {
         frame.render_widget(subtitle_widget, chunks[1]);
     }
 
+    /// Gauge for a daily or per-block budget
+    #[allow(clippy::too_many_arguments)]
+    fn render_spend_gauge(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        title: &str,
+        spent: f64,
+        projected: f64,
+        limit: f64,
+        budget: &ccboard_core::models::config::BudgetConfig,
+        currency: &Currency,
+        p: &Palette,
+    ) {
+        let usage_pct = if limit > 0.0 {
+            spent / limit * 100.0
+        } else {
+            0.0
+        };
+        let color = if usage_pct >= 100.0 {
+            p.important
+        } else if usage_pct >= budget.critical_threshold {
+            p.error
+        } else if usage_pct >= budget.warning_threshold {
+            p.warning
+        } else {
+            p.success
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(p.border))
+            .style(Style::default().bg(p.surface))
+            .title(Span::styled(
+                title.to_string(),
+                Style::default().fg(p.fg).bold(),
+            ));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Length(1)])
+            .split(inner);
+
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(color))
+            .ratio((usage_pct / 100.0).clamp(0.0, 1.0))
+            .label(format!(
+                "{} / {} ({:.1}%)",
+                currency.format_cost(spent),
+                currency.format_cost(limit),
+                usage_pct
+            ));
+        frame.render_widget(gauge, chunks[0]);
+
+        let projected_style = if projected > limit {
+            Style::default().fg(p.error)
+        } else {
+            Style::default().fg(p.muted)
+        };
+        let subtitle = Paragraph::new(format!("Projected: {}", currency.format_cost(projected)))
+            .style(projected_style)
+            .alignment(Alignment::Center);
+        frame.render_widget(subtitle, chunks[1]);
+    }
+
     fn render_token_breakdown(
         &self,
         frame: &mut Frame,
         area: Rect,
         stats: Option<&StatsCache>,
+        store: Option<&ccboard_core::store::DataStore>,
         p: &Palette,
     ) {
         let block = Block::default()
@@ -471,6 +627,8 @@ impl CostsTab {
                 Constraint::Length(2),
                 Constraint::Length(2),
                 Constraint::Length(2),
+                Constraint::Length(2),
+                Constraint::Length(1),
             ])
             .split(inner);
 
@@ -511,6 +669,55 @@ impl CostsTab {
             .percent(20) // Visual only
             .label(format!("Cache Write: {}", Self::format_tokens(cache_write)));
         frame.render_widget(cache_write_gauge, chunks[3]);
+
+        // Tool results gauge: share of fresh input tokens that came from tool outputs
+        let (tool_tokens, fresh_input) =
+            store.map(|s| s.tool_result_token_share()).unwrap_or((0, 0));
+        let tool_pct = if fresh_input > 0 {
+            (tool_tokens as f64 / fresh_input as f64 * 100.0).min(100.0)
+        } else {
+            0.0
+        };
+        let tool_gauge = Gauge::default()
+            .gauge_style(Style::default().fg(p.error).bg(p.muted))
+            .percent(tool_pct as u16)
+            .label(format!(
+                "Tool Results: ~{} ({:.1}% of input)",
+                Self::format_tokens(tool_tokens),
+                tool_pct
+            ));
+        frame.render_widget(tool_gauge, chunks[4]);
+
+        // Per-session token percentiles
+        let pct = store.map(|s| s.token_percentiles()).unwrap_or_default();
+        let percentiles = if pct.session_count > 0 {
+            Line::from(vec![
+                Span::styled("Per session: ", Style::default().fg(p.muted)),
+                Span::styled(
+                    format!(
+                        "p50 {} · p90 {} · p95 {} · p99 {}",
+                        Self::format_tokens(pct.p50),
+                        Self::format_tokens(pct.p90),
+                        Self::format_tokens(pct.p95),
+                        Self::format_tokens(pct.p99)
+                    ),
+                    Style::default().fg(p.fg),
+                ),
+                Span::styled(
+                    format!(
+                        "  (95% of sessions use under {} tokens)",
+                        Self::format_tokens(pct.p95)
+                    ),
+                    Style::default().fg(p.muted),
+                ),
+            ])
+        } else {
+            Line::from(Span::styled(
+                "Per session: no sessions loaded",
+                Style::default().fg(p.muted),
+            ))
+        };
+        frame.render_widget(Paragraph::new(percentiles), chunks[5]);
     }
 
     fn render_model_distribution(
@@ -518,6 +725,7 @@ impl CostsTab {
         frame: &mut Frame,
         area: Rect,
         stats: Option<&StatsCache>,
+        currency: &Currency,
         p: &Palette,
     ) {
         let block = Block::default()
@@ -544,11 +752,7 @@ impl CostsTab {
             .model_usage
             .iter()
             .map(|(model, usage)| {
-                let pricing = ModelPricing::for_model(model);
-                let cost = usage.input_tokens as f64 / 1_000_000.0 * pricing.input_per_million
-                    + usage.output_tokens as f64 / 1_000_000.0 * pricing.output_per_million
-                    + usage.cache_read_input_tokens as f64 / 1_000_000.0
-                        * pricing.cache_read_per_million;
+                let (cost, ..) = model_costs(model, usage);
                 (model.clone(), cost, usage.input_tokens, usage.output_tokens)
             })
             .collect();
@@ -561,7 +765,7 @@ impl CostsTab {
             .map(|(model, cost, input, output)| {
                 Row::new(vec![
                     Self::format_model_name(model),
-                    format!("${:.2}", cost),
+                    currency.format_cost(*cost),
                     Self::format_tokens(*input),
                     Self::format_tokens(*output),
                 ])
@@ -591,11 +795,22 @@ impl CostsTab {
         frame: &mut Frame,
         area: Rect,
         stats: Option<&StatsCache>,
+        currency: &Currency,
         p: &Palette,
     ) {
         let title_text = format!(
-            " Cost by Model • Sort: {} (press 's') ",
-            self.sort_mode.label()
+            " Cost by {} • Sort: {} (press 's') • f: {} ",
+            if self.group_by_family {
+                "Model Family"
+            } else {
+                "Model"
+            },
+            self.sort_mode.label(),
+            if self.group_by_family {
+                "raw IDs"
+            } else {
+                "group families"
+            }
         );
         let block = Block::default()
             .borders(Borders::ALL)
@@ -617,18 +832,30 @@ impl CostsTab {
             .model_usage
             .iter()
             .map(|(model, usage)| {
-                let pricing = ModelPricing::for_model(model);
-                let input_cost =
-                    usage.input_tokens as f64 / 1_000_000.0 * pricing.input_per_million;
-                let output_cost =
-                    usage.output_tokens as f64 / 1_000_000.0 * pricing.output_per_million;
-                let cache_cost = usage.cache_read_input_tokens as f64 / 1_000_000.0
-                    * pricing.cache_read_per_million;
-                let total = input_cost + output_cost + cache_cost;
+                let (total, input_cost, output_cost, cache_cost) = model_costs(model, usage);
                 (model.clone(), total, input_cost, output_cost, cache_cost)
             })
             .collect();
 
+        if self.group_by_family {
+            // Price each revision with its own rates, then sum per family
+            let mut families: std::collections::HashMap<String, (f64, f64, f64, f64)> =
+                std::collections::HashMap::new();
+            for (model, total, input, output, cache) in model_data.drain(..) {
+                let entry = families.entry(ModelUsage::family(&model)).or_default();
+                entry.0 += total;
+                entry.1 += input;
+                entry.2 += output;
+                entry.3 += cache;
+            }
+            model_data = families
+                .into_iter()
+                .map(|(family, (total, input, output, cache))| {
+                    (family, total, input, output, cache)
+                })
+                .collect();
+        }
+
         self.sort_models_detailed(&mut model_data);
 
         // Clamp selection
@@ -657,17 +884,20 @@ impl CostsTab {
                         ),
                         Span::styled(Self::format_model_name(model), style),
                         Span::styled(
-                            format!("  ${:.2}", total),
+                            format!("  {}", currency.format_cost(*total)),
                             Style::default().fg(p.success).add_modifier(Modifier::BOLD),
                         ),
                     ]),
                     Line::from(vec![
                         Span::styled("    Input: ", Style::default().fg(p.muted)),
-                        Span::styled(format!("${:.2}", input), Style::default().fg(p.focus)),
+                        Span::styled(currency.format_cost(*input), Style::default().fg(p.focus)),
                         Span::styled("  Output: ", Style::default().fg(p.muted)),
-                        Span::styled(format!("${:.2}", output), Style::default().fg(p.important)),
+                        Span::styled(
+                            currency.format_cost(*output),
+                            Style::default().fg(p.important),
+                        ),
                         Span::styled("  Cache: ", Style::default().fg(p.muted)),
-                        Span::styled(format!("${:.2}", cache), Style::default().fg(p.warning)),
+                        Span::styled(currency.format_cost(*cache), Style::default().fg(p.warning)),
                     ]),
                     Line::from(""), // spacing
                 ])
@@ -830,6 +1060,11 @@ impl CostsTab {
             }
         }
 
+        // Family IDs (`claude-sonnet-4`) carry only a major version
+        if parts.len() == 2 && parts[1].chars().all(|c| c.is_ascii_digit()) {
+            return format!("{} {}", capitalized, parts[1]);
+        }
+
         capitalized
     }
 
@@ -838,13 +1073,14 @@ impl CostsTab {
         frame: &mut Frame,
         area: Rect,
         billing_blocks: Option<&BillingBlockManager>,
+        currency: &Currency,
         p: &Palette,
     ) {
         let Some(blocks_manager) = billing_blocks else {
             let no_data = Paragraph::new("No billing block data available")
                 .block(
                     Block::default()
-                        .title("Billing Blocks (5h)")
+                        .title("Billing Blocks")
                         .borders(Borders::ALL)
                         .border_type(BorderType::Rounded)
                         .style(Style::default().bg(p.surface)),
@@ -855,6 +1091,7 @@ impl CostsTab {
         };
 
         let all_blocks = blocks_manager.get_all_blocks();
+        let block_hours = blocks_manager.block_duration_hours();
 
         if all_blocks.is_empty() {
             let empty_msg = vec![
@@ -878,14 +1115,14 @@ impl CostsTab {
                     Style::default().fg(p.muted),
                 )),
                 Line::from(Span::styled(
-                    "   • Grouped in 5-hour billing blocks",
+                    format!("   • Grouped in {}-hour billing blocks", block_hours),
                     Style::default().fg(p.muted),
                 )),
             ];
             let no_data = Paragraph::new(empty_msg)
                 .block(
                     Block::default()
-                        .title("Billing Blocks (5h)")
+                        .title(format!("Billing Blocks ({}h)", block_hours))
                         .borders(Borders::ALL)
                         .border_type(BorderType::Rounded)
                         .style(Style::default().bg(p.surface)),
@@ -926,7 +1163,7 @@ impl CostsTab {
                         block.label(),
                         format!("{:>8}", Self::format_short(usage.total_tokens())),
                         format!("{:>6}", usage.session_count),
-                        format!("${:>6.2}", usage.total_cost),
+                        format!("{:>7}", currency.format_cost(usage.total_cost)),
                     ];
 
                     rows.push(
@@ -957,7 +1194,10 @@ impl CostsTab {
         .header(header)
         .block(
             Block::default()
-                .title("Billing Blocks (5h UTC) — Last 10 Days")
+                .title(format!(
+                    "Billing Blocks ({}h UTC) — Last 10 Days",
+                    block_hours
+                ))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(p.focus))
@@ -1242,6 +1482,7 @@ impl CostsTab {
         frame: &mut Frame,
         area: Rect,
         store: Option<&ccboard_core::store::DataStore>,
+        currency: &Currency,
         p: &Palette,
     ) {
         let block = Block::default()
@@ -1284,7 +1525,7 @@ impl CostsTab {
             ),
             Span::styled(" projects: ", Style::default().fg(p.muted)),
             Span::styled(
-                format!("${:.4}", global.total_last_cost),
+                currency.format(global.total_last_cost, 4),
                 Style::default().fg(p.important).bold(),
             ),
             Span::styled(
@@ -1324,7 +1565,7 @@ impl CostsTab {
                         } else {
                             m.as_str()
                         };
-                        format!("{} ${:.3}", short, cost)
+                        format!("{} {}", short, currency.format(*cost, 3))
                     })
                     .collect::<Vec<_>>()
                     .join(", ");
@@ -1341,7 +1582,7 @@ impl CostsTab {
 
                 Row::new(vec![
                     ratatui::text::Text::from(proj.name.clone()),
-                    ratatui::text::Text::from(format!("${:.4}", proj.last_cost))
+                    ratatui::text::Text::from(currency.format(proj.last_cost, 4))
                         .patch_style(cost_style),
                     ratatui::text::Text::from(format!("{}", total_tokens)),
                     ratatui::text::Text::from(model_str),
@@ -1367,4 +1608,293 @@ impl CostsTab {
 
         frame.render_widget(table, chunks[1]);
     }
+
+    fn render_model_swap(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        store: Option<&ccboard_core::store::DataStore>,
+        currency: &Currency,
+        p: &Palette,
+    ) {
+        use ccboard_core::analytics::{primary_models, simulate_model_swap};
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(p.border))
+            .style(Style::default().bg(p.surface))
+            .title(Span::styled(
+                " What-If: Model Swap • ↑↓:source m:target ",
+                Style::default().fg(p.fg).bold(),
+            ));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let sessions = store.map(|s| s.aggregate_sessions()).unwrap_or_default();
+        let sources = primary_models(&sessions);
+        if sources.is_empty() {
+            let msg = Paragraph::new("No sessions with model information")
+                .style(Style::default().fg(p.muted))
+                .alignment(Alignment::Center);
+            frame.render_widget(msg, inner);
+            return;
+        }
+
+        let mut targets: Vec<String> = SWAP_TARGETS.iter().map(|t| t.to_string()).collect();
+        for model in &sources {
+            if !targets.contains(model) {
+                targets.push(model.clone());
+            }
+        }
+
+        self.swap_from = self.swap_from.min(sources.len() - 1);
+        self.swap_to %= targets.len();
+        let from = &sources[self.swap_from];
+        let to = &targets[self.swap_to];
+
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(inner);
+
+        // Source model list
+        let items: Vec<ListItem> = sources
+            .iter()
+            .enumerate()
+            .map(|(i, model)| {
+                let style = if i == self.swap_from {
+                    Style::default().fg(p.focus).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(p.muted)
+                };
+                ListItem::new(Line::from(Span::styled(
+                    format!(
+                        " {} {}",
+                        if i == self.swap_from { "▶" } else { " " },
+                        Self::format_model_name(model)
+                    ),
+                    style,
+                )))
+            })
+            .collect();
+        let list = List::new(items).block(
+            Block::default()
+                .borders(Borders::RIGHT)
+                .border_style(Style::default().fg(p.border))
+                .title(Span::styled(" Ran on ", Style::default().fg(p.muted))),
+        );
+        frame.render_widget(list, chunks[0]);
+
+        // Simulation result
+        let result = simulate_model_swap(&sessions, from, to);
+        let savings_style = if result.savings >= 0.0 {
+            Style::default().fg(p.success).bold()
+        } else {
+            Style::default().fg(p.error).bold()
+        };
+        let savings_label = if result.savings >= 0.0 {
+            "Savings:    "
+        } else {
+            "Extra cost: "
+        };
+
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("  ", Style::default()),
+                Span::styled(
+                    Self::format_model_name(&result.from_model),
+                    Style::default().fg(p.fg).bold(),
+                ),
+                Span::styled("  →  ", Style::default().fg(p.muted)),
+                Span::styled(
+                    Self::format_model_name(&result.to_model),
+                    Style::default().fg(p.focus).bold(),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("  Sessions:   ", Style::default().fg(p.muted)),
+                Span::styled(
+                    format!(
+                        "{} ({} tokens)",
+                        result.sessions_matched,
+                        Self::format_tokens(result.tokens_matched)
+                    ),
+                    Style::default().fg(p.fg),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("  Actual:     ", Style::default().fg(p.muted)),
+                Span::styled(
+                    currency.format_cost(result.original_cost),
+                    Style::default().fg(p.fg),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("  Simulated:  ", Style::default().fg(p.muted)),
+                Span::styled(
+                    currency.format_cost(result.simulated_cost),
+                    Style::default().fg(p.fg),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(format!("  {}", savings_label), Style::default().fg(p.muted)),
+                Span::styled(
+                    format!(
+                        "{} ({:+.1}%)",
+                        currency.format_cost(result.savings.abs()),
+                        -result.savings_pct
+                    ),
+                    savings_style,
+                ),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(
+                "  Naive simulation: same token counts re-priced at the target model's rates.",
+                Style::default().fg(p.muted).italic(),
+            )),
+            Line::from(Span::styled(
+                "  A different model would produce different outputs, tool calls and caching.",
+                Style::default().fg(p.muted).italic(),
+            )),
+        ];
+
+        frame.render_widget(
+            Paragraph::new(lines).wrap(ratatui::widgets::Wrap { trim: false }),
+            chunks[1],
+        );
+    }
+
+    /// Render the cost heatmap: estimated cost per weekday × start hour
+    ///
+    /// Same grid as the Analytics activity heatmap, colored by spend instead
+    /// of session count.
+    fn render_cost_heatmap(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        heatmap: &[[f64; 24]; 7],
+        currency: &Currency,
+        p: &Palette,
+    ) {
+        let total: f64 = heatmap.iter().flatten().sum();
+        let max = heatmap.iter().flatten().copied().fold(0.0, f64::max);
+
+        let weekday_labels = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+        let level_colors = [p.muted, p.success, p.focus, p.warning, p.important];
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(p.border))
+            .style(Style::default().bg(p.surface))
+            .title(Span::styled(
+                format!(
+                    " {} Cost by Start Time • {} total ",
+                    currency.symbol,
+                    currency.format_cost(total)
+                ),
+                Style::default().fg(p.fg).bold(),
+            ));
+
+        if max <= 0.0 {
+            let msg = Paragraph::new("No session costs to show")
+                .style(Style::default().fg(p.muted))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(msg, area);
+            return;
+        }
+
+        // --- Responsive cell dimensions ---
+        let label_w = 4usize; // "Mon "
+        let inner_w = area.width.saturating_sub(2) as usize;
+        let cell_w = (inner_w.saturating_sub(label_w) / 24).max(1);
+        // Reserve: 1 header + 1 blank + 1 legend + 1 blank + 1 peak = 5 rows
+        let inner_h = area.height.saturating_sub(2) as usize;
+        let cell_h = (inner_h.saturating_sub(5) / 7).clamp(1, 4);
+
+        let mut lines = vec![];
+
+        // --- Header: hour labels at their column offsets ---
+        let mut header_chars: Vec<char> = vec![' '; label_w + 24 * cell_w];
+        for hour in (0..24).step_by(4) {
+            let pos = label_w + hour * cell_w;
+            let label = format!("{:02}", hour);
+            if pos + 2 <= header_chars.len() {
+                for (i, c) in label.chars().enumerate() {
+                    header_chars[pos + i] = c;
+                }
+            }
+        }
+        lines.push(Line::from(Span::styled(
+            header_chars.into_iter().collect::<String>(),
+            Style::default().fg(p.muted),
+        )));
+
+        // --- Rows: each day occupies cell_h lines ---
+        for (day_idx, day_label) in weekday_labels.iter().enumerate() {
+            for sub_row in 0..cell_h {
+                let prefix = if sub_row == cell_h / 2 {
+                    Span::styled(
+                        format!("{:<width$}", day_label, width = label_w),
+                        Style::default().fg(p.muted),
+                    )
+                } else {
+                    Span::raw(" ".repeat(label_w))
+                };
+                let mut row_spans = vec![prefix];
+                for &cost in &heatmap[day_idx] {
+                    let color = level_colors[cost_level(cost, max)];
+                    row_spans.push(Span::styled("█".repeat(cell_w), Style::default().fg(color)));
+                }
+                lines.push(Line::from(row_spans));
+            }
+        }
+
+        // --- Legend: upper bound of each level in the display currency ---
+        lines.push(Line::from(""));
+        let mut legend = vec![
+            Span::raw("  "),
+            Span::styled("██", Style::default().fg(level_colors[0])),
+            Span::styled(
+                format!(" {}   ", currency.format_cost(0.0)),
+                Style::default().fg(p.muted),
+            ),
+        ];
+        for (level, color) in level_colors.iter().enumerate().skip(1) {
+            legend.push(Span::styled("██", Style::default().fg(*color)));
+            legend.push(Span::styled(
+                format!(" ≤ {}   ", currency.format_cost(max * level as f64 / 4.0)),
+                Style::default().fg(p.muted),
+            ));
+        }
+        lines.push(Line::from(legend));
+
+        // --- Most expensive slot ---
+        let (peak_day, peak_hour) = (0..7)
+            .flat_map(|d| (0..24).map(move |h| (d, h)))
+            .max_by(|a, b| heatmap[a.0][a.1].total_cmp(&heatmap[b.0][b.1]))
+            .unwrap_or((0, 0));
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("  Most expensive slot: ", Style::default().fg(p.muted)),
+            Span::styled(
+                format!("{} {:02}:00", weekday_labels[peak_day], peak_hour),
+                Style::default().fg(p.fg).bold(),
+            ),
+            Span::styled(
+                format!(
+                    "  {} ({:.0}% of total)",
+                    currency.format_cost(max),
+                    max / total * 100.0
+                ),
+                Style::default().fg(p.warning),
+            ),
+        ]));
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
 }
@@ -1,6 +1,7 @@
 //! Dashboard tab - Overview with sparkline, stats, model gauges, activity
 
 use crate::theme::{ContextSaturationColor, Palette};
+use ccboard_core::analytics::compute_streaks;
 use ccboard_core::models::StatsCache;
 use ccboard_core::parsers::McpConfig;
 use ccboard_core::store::DataStore;
@@ -15,17 +16,29 @@ use ratatui::{
 };
 use std::sync::Arc;
 
+/// Days covered by the per-project sparklines
+const PROJECT_SPARKLINE_DAYS: usize = 14;
+
 /// Dashboard tab state
-pub struct DashboardTab;
+pub struct DashboardTab {
+    /// Busiest projects over the sparkline window, computed on the first
+    /// render after a data refresh
+    top_projects: Option<Vec<(String, Vec<u64>)>>,
+}
 
 impl DashboardTab {
     pub fn new() -> Self {
-        Self
+        Self { top_projects: None }
+    }
+
+    /// Drop data derived from sessions, recomputed on next render
+    pub fn invalidate_cache(&mut self) {
+        self.top_projects = None;
     }
 
     /// Render the dashboard
     pub fn render(
-        &self,
+        &mut self,
         frame: &mut Frame,
         area: Rect,
         stats: Option<&StatsCache>,
@@ -40,12 +53,29 @@ impl DashboardTab {
             .map(|s| s.total_tokens() == 0 && s.session_count() > 0)
             .unwrap_or(false);
 
+        // Busiest projects over the sparkline window
+        let top_projects = self
+            .top_projects
+            .get_or_insert_with(|| {
+                store
+                    .map(|s| {
+                        s.top_project_daily_tokens(s.dashboard_projects(), PROJECT_SPARKLINE_DAYS)
+                    })
+                    .unwrap_or_default()
+            })
+            .clone();
+
         // Main vertical layout
         let mut constraints = vec![
             Constraint::Length(7), // Stats cards row
             Constraint::Length(9), // Sparkline
         ];
 
+        if !top_projects.is_empty() {
+            constraints.push(Constraint::Length(top_projects.len() as u16 + 2));
+            // Project sparklines
+        }
+
         if show_hint {
             constraints.push(Constraint::Length(3)); // Cache hint
         }
@@ -65,8 +95,14 @@ impl DashboardTab {
         // Activity sparkline
         self.render_activity(frame, chunks[1], stats, &p);
 
-        // Cache hint (if needed)
+        // Per-project sparklines (if any project was active)
         let mut idx = 2;
+        if let Some(store) = store.filter(|_| !top_projects.is_empty()) {
+            self.render_project_sparklines(frame, chunks[idx], &top_projects, store, &p);
+            idx += 1;
+        }
+
+        // Cache hint (if needed)
         if show_hint {
             self.render_cache_hint(frame, chunks[idx], &p);
             idx += 1;
@@ -114,6 +150,14 @@ impl DashboardTab {
             })
             .unwrap_or_else(|| ("—".into(), "—".into(), "—".into(), "—".into()));
 
+        // Aggregates leave out projects matching excludeProjects
+        let excluded = store.map(|s| s.excluded_project_count()).unwrap_or(0);
+        let sessions_subtitle = match excluded {
+            0 => "tracked".to_string(),
+            1 => "excl. 1 project".to_string(),
+            n => format!("excl. {} projects", n),
+        };
+
         // MCP servers count
         let mcp_count = mcp_config.map(|config| config.servers.len()).unwrap_or(0);
         let mcp_color = if mcp_count > 0 { p.success } else { p.muted };
@@ -151,7 +195,7 @@ impl DashboardTab {
             "● Sessions",
             &sessions,
             p.success,
-            "tracked",
+            &sessions_subtitle,
             p,
         );
         self.render_stat_card(
@@ -252,7 +296,7 @@ impl DashboardTab {
         stats: Option<&StatsCache>,
         p: &Palette,
     ) {
-        let block = Block::default()
+        let mut block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(p.border))
@@ -262,6 +306,35 @@ impl DashboardTab {
                 Style::default().fg(p.fg).bold(),
             ));
 
+        // Streak badge: consecutive active days over the recorded history
+        if let Some(s) = stats {
+            let active: Vec<&str> = s
+                .daily_activity
+                .iter()
+                .filter(|d| d.message_count > 0 || d.session_count > 0)
+                .map(|d| d.date.as_str())
+                .collect();
+            let streaks = compute_streaks(active);
+            if streaks.active_days > 0 {
+                block = block.title_top(
+                    Line::from(vec![
+                        Span::styled(
+                            format!(" 🔥 {}d streak", streaks.current),
+                            Style::default().fg(p.warning).bold(),
+                        ),
+                        Span::styled(
+                            format!(
+                                " · best {}d · {} active days ",
+                                streaks.longest, streaks.active_days
+                            ),
+                            Style::default().fg(p.muted),
+                        ),
+                    ])
+                    .right_aligned(),
+                );
+            }
+        }
+
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
@@ -355,6 +428,74 @@ impl DashboardTab {
         }
     }
 
+    /// One row per project: name, daily-token sparkline, window total
+    fn render_project_sparklines(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        projects: &[(String, Vec<u64>)],
+        store: &Arc<DataStore>,
+        p: &Palette,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(p.border))
+            .style(Style::default().bg(p.surface))
+            .title(Span::styled(
+                format!(" ▤ Top Projects ({}d tokens) ", PROJECT_SPARKLINE_DAYS),
+                Style::default().fg(p.fg).bold(),
+            ));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let display = store.project_display();
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(1); projects.len()])
+            .split(inner);
+
+        for ((project, daily), row) in projects.iter().zip(rows.iter()) {
+            let cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Length(26), // name
+                    Constraint::Min(10),    // sparkline
+                    Constraint::Length(9),  // total
+                ])
+                .split(*row);
+
+            let name = display.format(project);
+            let name = if name.chars().count() > 24 {
+                let tail: String = name.chars().rev().take(23).collect();
+                format!("…{}", tail.chars().rev().collect::<String>())
+            } else {
+                name
+            };
+            frame.render_widget(
+                Paragraph::new(Span::styled(name, Style::default().fg(p.fg))),
+                cols[0],
+            );
+
+            let expanded = Self::expand_sparkline_data(daily, cols[1].width as usize);
+            let sparkline = Sparkline::default()
+                .data(&expanded)
+                .max(daily.iter().max().copied().unwrap_or(1).max(1))
+                .style(Style::default().fg(p.focus))
+                .bar_set(symbols::bar::NINE_LEVELS);
+            frame.render_widget(sparkline, cols[1]);
+
+            frame.render_widget(
+                Paragraph::new(Span::styled(
+                    Self::format_number(daily.iter().sum()),
+                    Style::default().fg(p.muted),
+                ))
+                .alignment(Alignment::Right),
+                cols[2],
+            );
+        }
+    }
+
     fn render_model_gauges(
         &self,
         frame: &mut Frame,
@@ -502,6 +643,7 @@ impl DashboardTab {
         p: &Palette,
     ) {
         let estimate = store.map(|s| s.usage_estimate());
+        let currency = store.map(|s| s.currency()).unwrap_or_default();
 
         let (plan_name, plan, cost_today, cost_week, cost_month, budget, pct_month) = estimate
             .as_ref()
@@ -529,24 +671,24 @@ impl DashboardTab {
         // Create 3 lines: Today, Week, Month
         let today_line = if let Some(budget) = budget {
             format!(
-                "Today:      ${:>6.2} / ${:<6.0}  ({:>5.1}%)",
-                cost_today,
-                budget,
+                "Today:      {:>7} / {:<7}  ({:>5.1}%)",
+                currency.format_cost(cost_today),
+                currency.format(budget, 0),
                 (cost_today / budget * 100.0).min(100.0)
             )
         } else {
-            format!("Today:      ${:>6.2}", cost_today)
+            format!("Today:      {:>7}", currency.format_cost(cost_today))
         };
 
         let week_line = if let Some(budget) = budget {
             format!(
-                "This week:  ${:>6.2} / ${:<6.0}  ({:>5.1}%)",
-                cost_week,
-                budget,
+                "This week:  {:>7} / {:<7}  ({:>5.1}%)",
+                currency.format_cost(cost_week),
+                currency.format(budget, 0),
                 (cost_week / budget * 100.0).min(100.0)
             )
         } else {
-            format!("This week:  ${:>6.2}", cost_week)
+            format!("This week:  {:>7}", currency.format_cost(cost_week))
         };
 
         let month_line = if let Some(budget) = budget {
@@ -560,17 +702,20 @@ impl DashboardTab {
             vec![
                 Span::raw("This month: "),
                 Span::styled(
-                    format!("${:>6.2}", cost_month),
+                    format!("{:>7}", currency.format_cost(cost_month)),
                     Style::default().fg(color).add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(format!(
-                    " / ${:<6.0}  ({:>5.1}%)",
-                    budget,
+                    " / {:<7}  ({:>5.1}%)",
+                    currency.format(budget, 0),
                     pct_month.unwrap_or(0.0)
                 )),
             ]
         } else {
-            vec![Span::raw(format!("This month: ${:>6.2}", cost_month))]
+            vec![Span::raw(format!(
+                "This month: {:>7}",
+                currency.format_cost(cost_month)
+            ))]
         };
 
         let mut text = vec![
@@ -3,6 +3,8 @@
 use crate::components::highlight_matches;
 use crate::theme::Palette;
 use ccboard_core::models::{SessionMetadata, StatsCache};
+use ccboard_core::ProjectDisplay;
+use ccboard_types::Currency;
 use chrono::Local;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -36,12 +38,18 @@ pub struct HistoryTab {
     show_export_dialog: bool,
     /// Export success/error message
     export_message: Option<String>,
+    /// File written by the last successful export (copied with `Y`)
+    last_export_path: Option<std::path::PathBuf>,
     /// Vim-style: waiting for second 'g' press
     pending_gg: bool,
     /// Current position in search history (None = not navigating history)
     history_index: Option<usize>,
     /// Temporary buffer for current input while navigating history
     history_buffer: String,
+    /// Sessions marked with Space for comparison (at most two, oldest mark first)
+    marked: Vec<Arc<SessionMetadata>>,
+    /// Show the side-by-side diff of the two marked sessions
+    show_diff: bool,
 }
 
 impl Default for HistoryTab {
@@ -65,9 +73,12 @@ impl HistoryTab {
             error_message: None,
             show_export_dialog: false,
             export_message: None,
+            last_export_path: None,
             pending_gg: false,
             history_index: None,
             history_buffer: String::new(),
+            marked: Vec::new(),
+            show_diff: false,
         }
     }
 
@@ -159,6 +170,10 @@ impl HistoryTab {
                 }
                 _ => {}
             }
+        } else if self.show_diff {
+            if matches!(key, KeyCode::Esc | KeyCode::Char('D') | KeyCode::Char('q')) {
+                self.show_diff = false;
+            }
         } else if self.show_export_dialog {
             match key {
                 KeyCode::Char('1') => {
@@ -179,6 +194,17 @@ impl HistoryTab {
                 KeyCode::Char('x') | KeyCode::Char('X') => {
                     self.show_export_dialog = true;
                 }
+                KeyCode::Char(' ') => {
+                    self.toggle_mark();
+                }
+                KeyCode::Char('D') => {
+                    if self.marked.len() == 2 {
+                        self.show_diff = true;
+                    } else {
+                        self.error_message =
+                            Some("Mark two sessions with Space, then press D to compare".into());
+                    }
+                }
                 KeyCode::Char('/') => {
                     self.search_focused = true;
                 }
@@ -211,6 +237,28 @@ impl HistoryTab {
                         }
                     }
                 }
+                KeyCode::Char('Y') => {
+                    // Copy the last export path, or the selected session file path
+                    let path = self
+                        .last_export_path
+                        .clone()
+                        .or_else(|| self.get_selected_session().map(|s| s.file_path.clone()));
+                    if let Some(path) = path {
+                        let path = path.display().to_string();
+                        match arboard::Clipboard::new() {
+                            Ok(mut clipboard) => {
+                                if let Err(e) = clipboard.set_text(&path) {
+                                    self.error_message = Some(format!("Failed to copy: {}", e));
+                                } else {
+                                    self.export_message = Some(format!("✓ Copied: {}", path));
+                                }
+                            }
+                            Err(e) => {
+                                self.error_message = Some(format!("Clipboard unavailable: {}", e));
+                            }
+                        }
+                    }
+                }
                 KeyCode::Esc => {
                     if self.error_message.is_some() {
                         self.error_message = None;
@@ -311,7 +359,30 @@ impl HistoryTab {
         }
     }
 
-    fn get_selected_session(&self) -> Option<&Arc<SessionMetadata>> {
+    /// Mark/unmark the selected session; a third mark replaces the oldest
+    fn toggle_mark(&mut self) {
+        let Some(session) = self.get_selected_session().cloned() else {
+            return;
+        };
+        if let Some(pos) = self.marked.iter().position(|m| m.id == session.id) {
+            self.marked.remove(pos);
+            return;
+        }
+        if self.marked.len() == 2 {
+            self.marked.remove(0);
+        }
+        self.marked.push(session);
+    }
+
+    /// "A"/"B" label of a marked session
+    fn mark_label(&self, session: &SessionMetadata) -> Option<&'static str> {
+        match self.marked.iter().position(|m| m.id == session.id)? {
+            0 => Some("A"),
+            _ => Some("B"),
+        }
+    }
+
+    pub fn get_selected_session(&self) -> Option<&Arc<SessionMetadata>> {
         let idx = self.results_state.selected()?;
         self.filtered_sessions.get(idx)
     }
@@ -327,10 +398,11 @@ impl HistoryTab {
         match ccboard_core::export_sessions_to_csv(&self.filtered_sessions, &export_path) {
             Ok(_) => {
                 self.export_message = Some(format!(
-                    "✓ Exported {} sessions to {}",
+                    "✓ Exported {} sessions to {} (Y to copy path)",
                     self.filtered_sessions.len(),
                     export_path.display()
                 ));
+                self.last_export_path = Some(export_path);
             }
             Err(e) => {
                 self.export_message = Some(format!("✗ Export failed: {}", e));
@@ -349,10 +421,11 @@ impl HistoryTab {
         match ccboard_core::export_sessions_to_json(&self.filtered_sessions, &export_path) {
             Ok(_) => {
                 self.export_message = Some(format!(
-                    "✓ Exported {} sessions to {}",
+                    "✓ Exported {} sessions to {} (Y to copy path)",
                     self.filtered_sessions.len(),
                     export_path.display()
                 ));
+                self.last_export_path = Some(export_path);
             }
             Err(e) => {
                 self.export_message = Some(format!("✗ Export failed: {}", e));
@@ -366,12 +439,15 @@ impl HistoryTab {
     }
 
     /// Render the history tab
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         &mut self,
         frame: &mut Frame,
         area: Rect,
         sessions: &[Arc<SessionMetadata>],
         stats: Option<&StatsCache>,
+        project_display: &ProjectDisplay,
+        currency: &Currency,
         _scheme: ccboard_core::models::config::ColorScheme,
     ) {
         let p = Palette::new(_scheme);
@@ -412,7 +488,7 @@ impl HistoryTab {
             .split(chunks[1]);
 
         // Results list
-        self.render_results(frame, content_chunks[0], &p);
+        self.render_results(frame, content_chunks[0], project_display, &p);
 
         // Detail popup if open
         let mut chunk_idx = 1;
@@ -430,6 +506,10 @@ impl HistoryTab {
             self.render_stats_panel(frame, content_chunks[chunk_idx], stats, &p);
         }
 
+        if self.show_diff {
+            self.render_diff(frame, area, project_display, currency, &p);
+        }
+
         // Render export dialog if open
         if self.show_export_dialog {
             self.render_export_dialog(frame, area, &p);
@@ -511,7 +591,13 @@ impl HistoryTab {
         frame.render_widget(results_widget, chunks[1]);
     }
 
-    fn render_results(&mut self, frame: &mut Frame, area: Rect, p: &Palette) {
+    fn render_results(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        project_display: &ProjectDisplay,
+        p: &Palette,
+    ) {
         const MAX_DISPLAY: usize = 500;
         let total_count = self.filtered_sessions.len();
         let display_count = total_count.min(MAX_DISPLAY);
@@ -626,7 +712,7 @@ impl HistoryTab {
                     })
                     .unwrap_or_else(|| "No preview".to_string());
 
-                let project_short = Self::shorten_path(session.project_path.as_str());
+                let project_short = project_display.format(session.project_path.as_str());
 
                 let style = if is_selected {
                     Style::default().fg(p.focus).add_modifier(Modifier::BOLD)
@@ -643,12 +729,18 @@ impl HistoryTab {
                     preview_line.push(Span::styled(preview, style));
                 }
 
+                let mark = self
+                    .mark_label(session)
+                    .map(|label| format!("[{}] ", label))
+                    .unwrap_or_default();
+
                 ListItem::new(vec![
                     Line::from(vec![
                         Span::styled(
                             if is_selected { "▶ " } else { "  " },
                             Style::default().fg(p.focus),
                         ),
+                        Span::styled(mark, Style::default().fg(p.important).bold()),
                         Span::styled(date_str, Style::default().fg(p.warning)),
                         Span::styled(" │ ", Style::default().fg(p.muted)),
                         Span::styled(project_short, Style::default().fg(p.success)),
@@ -875,15 +967,6 @@ impl HistoryTab {
         frame.render_widget(sparkline, sparkline_area);
     }
 
-    fn shorten_path(path: &str) -> String {
-        let parts: Vec<&str> = path.split('/').collect();
-        if parts.len() <= 2 {
-            path.to_string()
-        } else {
-            parts[parts.len() - 2..].join("/")
-        }
-    }
-
     fn format_tokens(n: u64) -> String {
         if n >= 1_000_000 {
             format!("{:.1}M", n as f64 / 1_000_000.0)
@@ -1026,6 +1109,182 @@ impl HistoryTab {
         frame.render_widget(detail, inner);
     }
 
+    /// Side-by-side comparison of the two marked sessions (A = baseline)
+    fn render_diff(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        project_display: &ProjectDisplay,
+        currency: &Currency,
+        p: &Palette,
+    ) {
+        use ratatui::widgets::Clear;
+
+        let [a, b] = self.marked.as_slice() else {
+            return;
+        };
+        let diff = a.diff(b);
+
+        let popup_width = (area.width as f32 * 0.8).max(60.0) as u16;
+        let popup_height = (area.height as f32 * 0.8).max(20.0) as u16;
+        let popup_area = Rect {
+            x: area.x + area.width.saturating_sub(popup_width) / 2,
+            y: area.y + area.height.saturating_sub(popup_height) / 2,
+            width: popup_width.min(area.width),
+            height: popup_height.min(area.height),
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(p.focus))
+            .style(Style::default().bg(p.surface))
+            .title(Span::styled(
+                " Compare Sessions (A → B) ",
+                Style::default().fg(p.focus).bold(),
+            ));
+        let inner = block.inner(popup_area);
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(block, popup_area);
+
+        let describe = |label: &str, session: &SessionMetadata| {
+            let date = session
+                .first_timestamp
+                .map(|ts| ts.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+            Line::from(vec![
+                Span::styled(
+                    format!("{}: ", label),
+                    Style::default().fg(p.important).bold(),
+                ),
+                Span::styled(date, Style::default().fg(p.warning)),
+                Span::styled(" │ ", Style::default().fg(p.muted)),
+                Span::styled(
+                    project_display.format(session.project_path.as_str()),
+                    Style::default().fg(p.success),
+                ),
+                Span::styled(
+                    format!("  {}", &session.id[..session.id.len().min(8)]),
+                    Style::default().fg(p.muted),
+                ),
+            ])
+        };
+
+        let mut lines = vec![describe("A", a), describe("B", b), Line::from("")];
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{:<10} {:>12} {:>12} {:>12} {:>8}",
+                "Metric", "A", "B", "Δ", "Δ%"
+            ),
+            Style::default().fg(p.muted).bold(),
+        )));
+        for delta in &diff.metrics {
+            let fmt = |v: f64| Self::format_metric(&delta.metric, v, currency);
+            let sign = if delta.delta > 0.0 { "+" } else { "" };
+            let pct = delta
+                .delta_pct
+                .map(|pct| format!("{}{:.0}%", if pct > 0.0 { "+" } else { "" }, pct))
+                .unwrap_or_else(|| "n/a".to_string());
+            // More tokens/cost/time is worse
+            let color = if delta.delta > 0.0 {
+                p.error
+            } else if delta.delta < 0.0 {
+                p.success
+            } else {
+                p.muted
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:<10} ", delta.metric), Style::default().fg(p.fg)),
+                Span::styled(
+                    format!("{:>12} {:>12} ", fmt(delta.a), fmt(delta.b)),
+                    Style::default().fg(p.fg),
+                ),
+                Span::styled(
+                    format!("{:>12} {:>8}", format!("{}{}", sign, fmt(delta.delta)), pct),
+                    Style::default().fg(color),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        let model_list = |models: &[String]| {
+            if models.is_empty() {
+                "—".to_string()
+            } else {
+                models.join(", ")
+            }
+        };
+        for (label, models) in [
+            ("Models (both): ", &diff.models_common),
+            ("Models (A only): ", &diff.models_only_a),
+            ("Models (B only): ", &diff.models_only_b),
+        ] {
+            lines.push(Line::from(vec![
+                Span::styled(label, Style::default().fg(p.muted)),
+                Span::styled(model_list(models), Style::default().fg(p.fg)),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("{:<20} {:>8} {:>8} {:>8}", "Tool", "A", "B", "Δ"),
+            Style::default().fg(p.muted).bold(),
+        )));
+        if diff.tool_usage.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No tool calls recorded",
+                Style::default().fg(p.muted),
+            )));
+        }
+        for (tool, count_a, count_b) in diff.tool_usage.iter().take(10) {
+            let delta = *count_b as i64 - *count_a as i64;
+            let color = match delta.signum() {
+                1 => p.warning,
+                -1 => p.success,
+                _ => p.muted,
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{:<20} {:>8} {:>8} ", tool, count_a, count_b),
+                    Style::default().fg(p.fg),
+                ),
+                Span::styled(
+                    format!("{:>8}", format!("{:+}", delta)),
+                    Style::default().fg(color),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Press Esc or D to close",
+            Style::default().fg(p.muted),
+        )));
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Format a diff metric value (tokens, messages, duration seconds, cost)
+    fn format_metric(metric: &str, value: f64, currency: &Currency) -> String {
+        let magnitude = value.abs();
+        let sign = if value < 0.0 { "-" } else { "" };
+        match metric {
+            "Tokens" => format!("{}{}", sign, Self::format_tokens(magnitude as u64)),
+            "Duration" => {
+                let secs = magnitude as u64;
+                if secs >= 3600 {
+                    format!("{}{}h {}m", sign, secs / 3600, (secs % 3600) / 60)
+                } else if secs >= 60 {
+                    format!("{}{}m {}s", sign, secs / 60, secs % 60)
+                } else {
+                    format!("{}{}s", sign, secs)
+                }
+            }
+            "Cost" => format!("{}{}", sign, currency.format_cost(magnitude)),
+            _ => format!("{}{}", sign, magnitude as u64),
+        }
+    }
+
     fn render_error_popup(&self, frame: &mut Frame, area: Rect, p: &Palette) {
         // Center popup (40% width, 30% height)
         let popup_width = (area.width as f32 * 0.4).max(40.0) as u16;
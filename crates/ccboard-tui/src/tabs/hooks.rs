@@ -414,7 +414,7 @@ impl HooksTab {
                     .map(|groups| groups.iter().map(|g| g.hooks.len()).sum())
                     .unwrap_or(0);
 
-                let (icon, color) = Self::event_style(event);
+                let (icon, color) = Self::event_style(event, p);
                 let style = if is_selected && is_focused {
                     Style::default().fg(color).add_modifier(Modifier::BOLD)
                 } else if is_selected {
@@ -585,7 +585,7 @@ impl HooksTab {
     }
 
     /// Basic bash syntax highlighting
-    fn highlight_bash_line(line: &str) -> Line<'_> {
+    fn highlight_bash_line<'a>(line: &'a str, p: &Palette) -> Line<'a> {
         let trimmed = line.trim_start();
 
         // Comments (entire line)
@@ -620,7 +620,7 @@ impl HooksTab {
                     current_word.push(ch);
                     spans.push(Span::styled(
                         current_word.clone(),
-                        Style::default().fg(Color::Green),
+                        Style::default().fg(p.success),
                     ));
                     current_word.clear();
                     in_string = false;
@@ -660,10 +660,7 @@ impl HooksTab {
         // Flush remaining
         if !current_word.is_empty() {
             if in_string {
-                spans.push(Span::styled(
-                    current_word,
-                    Style::default().fg(Color::Green),
-                ));
+                spans.push(Span::styled(current_word, Style::default().fg(p.success)));
             } else {
                 // Check if it's a common bash keyword
                 let keywords = [
@@ -773,7 +770,7 @@ impl HooksTab {
             .lines()
             .skip(self.content_scroll as usize)
             .take(available_for_code as usize)
-            .map(|line| Self::highlight_bash_line(line))
+            .map(|line| Self::highlight_bash_line(line, p))
             .collect();
 
         // Display hint at bottom if focused
@@ -849,18 +846,18 @@ impl HooksTab {
         frame.render_widget(empty, area);
     }
 
-    fn event_style(event: &str) -> (&'static str, Color) {
+    fn event_style(event: &str, p: &Palette) -> (&'static str, Color) {
         match event {
             "PreToolUse" => ("⚡", Color::Yellow),
-            "PostToolUse" => ("✓", Color::Green),
+            "PostToolUse" => ("✓", p.success),
             "PrePromptSubmit" | "PreSubmit" => ("→", Color::Cyan),
             "PostPromptSubmit" | "PostSubmit" => ("←", Color::Blue),
             "Notification" => ("🔔", Color::Magenta),
-            "Stop" => ("■", Color::Red),
-            "SubagentStop" => ("◼", Color::LightRed),
+            "Stop" => ("■", p.error),
+            "SubagentStop" => ("◼", p.error),
             "MessageDisplay" => ("💬", Color::Cyan),
             "PreCompact" => ("⟪", Color::LightYellow),
-            "PostCompact" => ("⟫", Color::LightGreen),
+            "PostCompact" => ("⟫", p.success),
             _ => ("●", Color::Gray),
         }
     }
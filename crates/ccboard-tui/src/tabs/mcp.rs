@@ -3,6 +3,7 @@
 //! Features:
 //! - Dual-pane layout (server list | details)
 //! - Status detection via process listing (Unix only)
+//! - Background `initialize` health checks (reachable/unreachable/timeout)
 //! - File operations (edit config, reveal file)
 //! - Empty state handling
 //! - Error popup for failed operations
@@ -12,12 +13,12 @@
 //! - Enter: Focus detail pane
 //! - e: Edit claude_desktop_config.json
 //! - o: Reveal config file in file manager
-//! - r: Refresh status detection
+//! - r: Refresh status detection and re-run health checks
 //! - Esc: Close error popup
 
 use crate::empty_state;
 use crate::theme::{Palette, ServerStatusColor};
-use ccboard_core::parsers::mcp_config::{McpConfig, McpServer};
+use ccboard_core::parsers::mcp_config::{check_server_health, HealthStatus, McpConfig, McpServer};
 use ccboard_core::store::McpCallStat;
 use crossterm::event::KeyCode;
 use ratatui::{
@@ -29,6 +30,7 @@ use ratatui::{
 };
 use std::collections::HashMap;
 use std::time::Instant;
+use tokio::sync::mpsc;
 
 /// Which pane has focus
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -94,6 +96,12 @@ pub struct McpTab {
     view: McpView,
     /// Scroll offset for stats table
     stats_scroll: usize,
+    /// Health check results received so far
+    health: HashMap<String, HealthStatus>,
+    /// Results of in-flight health checks
+    health_rx: Option<mpsc::UnboundedReceiver<(String, HealthStatus)>>,
+    /// Whether health checks ran for the current config
+    health_started: bool,
 }
 
 impl Default for McpTab {
@@ -117,6 +125,9 @@ impl McpTab {
             copy_message: None,
             view: McpView::Servers,
             stats_scroll: 0,
+            health: HashMap::new(),
+            health_rx: None,
+            health_started: false,
         }
     }
 
@@ -204,6 +215,7 @@ impl McpTab {
             // Refresh status
             KeyCode::Char('r') => {
                 self.refresh_status(mcp_config);
+                self.start_health_checks(mcp_config);
             }
 
             // Page navigation
@@ -238,6 +250,11 @@ impl McpTab {
 
         let p = Palette::new(scheme);
 
+        if !self.health_started {
+            self.start_health_checks(mcp_config);
+        }
+        self.poll_health();
+
         // Dual-pane layout: fixed list width | flexible details
         let chunks = Layout::default()
             .direction(ratatui::layout::Direction::Horizontal)
@@ -434,6 +451,11 @@ impl McpTab {
                 if server.always_load {
                     name_spans.push(Span::styled(" [A]", Style::default().fg(p.warning).bold()));
                 }
+                let (health_icon, health_color) = self.health_icon(name, p);
+                name_spans.push(Span::styled(
+                    format!(" {}", health_icon),
+                    Style::default().fg(health_color),
+                ));
 
                 ListItem::new(vec![
                     Line::from(name_spans),
@@ -528,6 +550,22 @@ impl McpTab {
             Span::styled(status_text, Style::default().fg(status_color)),
         ]));
 
+        // Health check line
+        let (health_icon, health_color) = self.health_icon(name, p);
+        let health_text = match self.health.get(name) {
+            Some(health) => health.label(),
+            None if self.health_rx.is_some() => "Checking…",
+            None => "Not checked",
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Health: ", Style::default().fg(p.warning).bold()),
+            Span::styled(
+                format!("{} ", health_icon),
+                Style::default().fg(health_color).bold(),
+            ),
+            Span::styled(health_text, Style::default().fg(health_color)),
+        ]));
+
         // Always Load indicator
         if server.always_load {
             lines.push(Line::from(vec![
@@ -615,7 +653,7 @@ impl McpTab {
                 Style::default().fg(p.warning).bold(),
             )));
             for arg in &server.args {
-                let spans = Self::highlight_arg(arg);
+                let spans = Self::highlight_arg(arg, p);
                 lines.push(Line::from(spans));
             }
             lines.push(Line::from(""));
@@ -691,6 +729,61 @@ impl McpTab {
         servers.get(idx).copied()
     }
 
+    /// Spawn one background `initialize` check per server
+    ///
+    /// Results arrive through `health_rx` and are picked up on the next render.
+    /// Does nothing outside a Tokio runtime (render tests).
+    fn start_health_checks(&mut self, mcp_config: Option<&McpConfig>) {
+        let Some(config) = mcp_config else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        self.health_started = true;
+        self.health.clear();
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        for (name, server) in &config.servers {
+            let (tx, name, server) = (tx.clone(), name.clone(), server.clone());
+            runtime.spawn(async move {
+                let status = check_server_health(&server).await;
+                let _ = tx.send((name, status));
+            });
+        }
+        self.health_rx = Some(rx);
+    }
+
+    /// Collect finished health checks without blocking
+    fn poll_health(&mut self) {
+        let Some(rx) = &mut self.health_rx else {
+            return;
+        };
+        loop {
+            match rx.try_recv() {
+                Ok((name, status)) => {
+                    self.health.insert(name, status);
+                }
+                Err(mpsc::error::TryRecvError::Empty) => break,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    self.health_rx = None;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Indicator for a server's health check (pending checks show `…`)
+    fn health_icon(&self, name: &str, p: &Palette) -> (&'static str, Color) {
+        match self.health.get(name) {
+            Some(HealthStatus::Reachable) => ("✓", p.success),
+            Some(HealthStatus::Unreachable) => ("✗", p.error),
+            Some(HealthStatus::Timeout) => ("⧗", p.warning),
+            None if self.health_rx.is_some() => ("…", p.muted),
+            None => ("-", p.muted),
+        }
+    }
+
     /// Refresh status detection for all servers
     fn refresh_status(&mut self, mcp_config: Option<&McpConfig>) {
         let Some(config) = mcp_config else {
@@ -885,7 +978,7 @@ impl McpTab {
     /// - Paths: /absolute, ./relative (Green)
     /// - URLs: http://, https:// (Magenta)
     /// - Values: normal (White)
-    fn highlight_arg(arg: &str) -> Vec<Span<'static>> {
+    fn highlight_arg(arg: &str, p: &Palette) -> Vec<Span<'static>> {
         let mut spans = vec![Span::raw("  ")];
 
         // Flag detection (--flag or -f)
@@ -908,7 +1001,7 @@ impl McpTab {
         else if arg.starts_with('/') || arg.starts_with("./") || arg.starts_with("../") {
             spans.push(Span::styled(
                 arg.to_string(),
-                Style::default().fg(Color::Green),
+                Style::default().fg(p.success),
             ));
         }
         // Regular value
@@ -1007,4 +1100,34 @@ mod tests {
         tab.handle_key(KeyCode::Left, None);
         assert_eq!(tab.focus, Focus::List);
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_health_checks_run_in_background() {
+        let config: McpConfig = serde_json::from_str(
+            r#"{"mcpServers": {
+                "ok": {"command": "sh", "args": ["-c", "read l; echo '{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":{}}'"]},
+                "broken": {"command": "sh", "args": ["-c", "exit 1"]}
+            }}"#,
+        )
+        .unwrap();
+
+        let mut tab = McpTab::new();
+        tab.start_health_checks(Some(&config));
+        assert!(tab.health_started);
+        assert_eq!(
+            tab.health_icon("ok", &Palette::new(Default::default())).0,
+            "…"
+        );
+
+        for _ in 0..100 {
+            tab.poll_health();
+            if tab.health_rx.is_none() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert_eq!(tab.health.get("ok"), Some(&HealthStatus::Reachable));
+        assert_eq!(tab.health.get("broken"), Some(&HealthStatus::Unreachable));
+    }
 }
@@ -5,9 +5,10 @@
 //! - Plugin classification (Skill, MCP, Agent, Command, Native)
 //! - Dead code detection
 //! - Sort modes (usage, cost, name)
+//! - Installed plugins with their status (enabled / disabled / error)
 //!
 //! Keybindings:
-//! - Tab: Cycle between columns
+//! - Tab: Cycle between columns and the installed list
 //! - j/k or Up/Down: Navigate within column
 //! - s: Toggle sort mode (usage → cost → name)
 //! - r: Refresh analytics
@@ -16,11 +17,14 @@ use crate::empty_state::EmptyState;
 use crate::theme::Palette;
 use ccboard_core::analytics::{aggregate_plugin_usage, PluginAnalytics};
 use ccboard_core::models::config::ColorScheme;
+use ccboard_core::parsers::{PluginInfo, PluginStatus};
 use ccboard_core::DataStore;
+use ccboard_types::Currency;
 use crossterm::event::KeyCode;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, BorderType, Borders, Gauge, List, ListItem, ListState, Paragraph},
     Frame,
 };
@@ -32,6 +36,7 @@ enum Focus {
     TopUsage,
     TopCost,
     DeadCode,
+    Installed,
 }
 
 /// Sort mode for plugin list
@@ -72,8 +77,12 @@ pub struct PluginsTab {
     top_cost_state: ListState,
     /// Dead code list state
     dead_code_state: ListState,
+    /// Installed plugins list state
+    installed_state: ListState,
     /// Cached analytics (recomputed on refresh)
     analytics: Option<PluginAnalytics>,
+    /// Installed plugins (reloaded on refresh)
+    installed: Vec<PluginInfo>,
 }
 
 impl Default for PluginsTab {
@@ -94,7 +103,9 @@ impl PluginsTab {
             top_usage_state,
             top_cost_state: ListState::default(),
             dead_code_state: ListState::default(),
+            installed_state: ListState::default(),
             analytics: None,
+            installed: Vec::new(),
         }
     }
 
@@ -106,7 +117,8 @@ impl PluginsTab {
                 self.focus = match self.focus {
                     Focus::TopUsage => Focus::TopCost,
                     Focus::TopCost => Focus::DeadCode,
-                    Focus::DeadCode => Focus::TopUsage,
+                    Focus::DeadCode => Focus::Installed,
+                    Focus::Installed => Focus::TopUsage,
                 };
                 true
             }
@@ -146,14 +158,12 @@ impl PluginsTab {
     /// Navigate down within current column
     fn navigate_down(&mut self) {
         // Calculate length first without holding borrow
-        let len = if let Some(analytics) = &self.analytics {
-            match self.focus {
-                Focus::TopUsage => analytics.top_by_usage.len(),
-                Focus::TopCost => analytics.top_by_cost.len(),
-                Focus::DeadCode => analytics.dead_plugins.len(),
-            }
-        } else {
-            0
+        let len = match (&self.analytics, self.focus) {
+            (_, Focus::Installed) => self.installed.len(),
+            (Some(analytics), Focus::TopUsage) => analytics.top_by_usage.len(),
+            (Some(analytics), Focus::TopCost) => analytics.top_by_cost.len(),
+            (Some(analytics), Focus::DeadCode) => analytics.dead_plugins.len(),
+            (None, _) => 0,
         };
 
         // Now get mutable state
@@ -171,6 +181,7 @@ impl PluginsTab {
             Focus::TopUsage => &mut self.top_usage_state,
             Focus::TopCost => &mut self.top_cost_state,
             Focus::DeadCode => &mut self.dead_code_state,
+            Focus::Installed => &mut self.installed_state,
         }
     }
 
@@ -186,11 +197,13 @@ impl PluginsTab {
 
         // Compute analytics
         self.analytics = Some(aggregate_plugin_usage(&sessions, &skills, &commands));
+        self.installed = store.plugins();
 
         // Reset selection to first item
         self.top_usage_state.select(Some(0));
         self.top_cost_state.select(Some(0));
         self.dead_code_state.select(Some(0));
+        self.installed_state.select(Some(0));
     }
 
     /// Render the plugins tab
@@ -217,12 +230,14 @@ impl PluginsTab {
             return;
         }
 
-        // Split layout: header + content
+        // Split layout: header + content + installed plugins
+        let installed_height = self.installed.len().clamp(1, 8) as u16 + 2;
         let chunks = Layout::default()
             .direction(ratatui::layout::Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // Stats header
-                Constraint::Min(0),    // Content
+                Constraint::Length(3),                // Stats header
+                Constraint::Min(0),                   // Content
+                Constraint::Length(installed_height), // Installed plugins
             ])
             .split(area);
 
@@ -230,7 +245,9 @@ impl PluginsTab {
         self.render_header(frame, chunks[0], scheme);
 
         // Render three-column layout
-        self.render_columns(frame, chunks[1], &p);
+        self.render_columns(frame, chunks[1], &store.currency(), &p);
+
+        self.render_installed(frame, chunks[2], &p);
     }
 
     /// Render stats header
@@ -283,7 +300,7 @@ impl PluginsTab {
     }
 
     /// Render three-column layout
-    fn render_columns(&mut self, frame: &mut Frame, area: Rect, p: &Palette) {
+    fn render_columns(&mut self, frame: &mut Frame, area: Rect, currency: &Currency, p: &Palette) {
         let chunks = Layout::default()
             .direction(ratatui::layout::Direction::Horizontal)
             .constraints([
@@ -294,7 +311,7 @@ impl PluginsTab {
             .split(area);
 
         self.render_top_usage(frame, chunks[0], p);
-        self.render_top_cost(frame, chunks[1], p);
+        self.render_top_cost(frame, chunks[1], currency, p);
         self.render_dead_code(frame, chunks[2], p);
     }
 
@@ -345,7 +362,7 @@ impl PluginsTab {
     }
 
     /// Render top cost column
-    fn render_top_cost(&mut self, frame: &mut Frame, area: Rect, p: &Palette) {
+    fn render_top_cost(&mut self, frame: &mut Frame, area: Rect, currency: &Currency, p: &Palette) {
         let analytics = self.analytics.as_ref().unwrap();
         let focused = self.focus == Focus::TopCost;
 
@@ -354,7 +371,12 @@ impl PluginsTab {
             .iter()
             .enumerate()
             .map(|(i, plugin)| {
-                let text = format!("{}. {} (${:.2})", i + 1, plugin.name, plugin.total_cost);
+                let text = format!(
+                    "{}. {} ({})",
+                    i + 1,
+                    plugin.name,
+                    currency.format_cost(plugin.total_cost)
+                );
                 ListItem::new(text)
             })
             .collect();
@@ -436,4 +458,71 @@ impl PluginsTab {
 
         frame.render_stateful_widget(list, area, &mut self.dead_code_state);
     }
+
+    /// Render installed plugins with their load status
+    fn render_installed(&mut self, frame: &mut Frame, area: Rect, p: &Palette) {
+        let focused = self.focus == Focus::Installed;
+        let title = format!(
+            " Installed Plugins ({}){} ",
+            self.installed.len(),
+            if focused { " [FOCUSED]" } else { "" }
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(if focused { p.focus } else { p.border }))
+            .title(title);
+
+        if self.installed.is_empty() {
+            let empty = Paragraph::new("No Claude Code plugins installed")
+                .style(Style::default().fg(p.muted))
+                .block(block);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let name_width = self
+            .installed
+            .iter()
+            .map(|plugin| plugin.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        let items: Vec<ListItem> = self
+            .installed
+            .iter()
+            .map(|plugin| {
+                let status = plugin.status();
+                let (icon, color) = match status {
+                    PluginStatus::Enabled => ("●", p.success),
+                    PluginStatus::Disabled => ("○", p.muted),
+                    PluginStatus::Error => ("✗", p.error),
+                };
+                let detail = match (&plugin.error, &plugin.path) {
+                    (Some(error), _) => error.clone(),
+                    (None, Some(path)) => path.display().to_string(),
+                    (None, None) => String::new(),
+                };
+                let detail_color = if plugin.error.is_some() {
+                    p.error
+                } else {
+                    p.muted
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{} ", icon), Style::default().fg(color)),
+                    Span::styled(
+                        format!("{:<width$}  ", plugin.name, width = name_width),
+                        Style::default().fg(p.fg),
+                    ),
+                    Span::styled(format!("{:<9}", status.label()), Style::default().fg(color)),
+                    Span::styled(detail, Style::default().fg(detail_color)),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+        frame.render_stateful_widget(list, area, &mut self.installed_state);
+    }
 }
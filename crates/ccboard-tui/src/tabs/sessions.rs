@@ -1,9 +1,11 @@
 //! Sessions tab - Project tree + session list + detail view
 
-use crate::components::highlight_matches;
+use crate::components::{has_code_block, highlight_matches, render_code_blocks, TagPrompt};
 use crate::theme::Palette;
-use ccboard_core::models::{SessionLine, SessionMetadata};
-use ccboard_core::parsers::SessionContentParser;
+use ccboard_core::graph::ChainLink;
+use ccboard_core::models::{SessionId, SessionLine, SessionMetadata, TokenSource};
+use ccboard_core::parsers::{SessionContentParser, ToolTimeline};
+use ccboard_core::ProjectDisplay;
 use chrono::{DateTime, Duration, Utc};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -33,12 +35,13 @@ pub enum DateFilter {
 /// Sort mode for session list
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SessionSortMode {
-    DateDesc,     // newest first (default)
-    DateAsc,      // oldest first
-    TokensDesc,   // most tokens first
-    TokensAsc,    // least tokens first
-    DurationDesc, // longest first
-    MessagesDesc, // most messages first
+    DateDesc,        // newest first (default)
+    DateAsc,         // oldest first
+    TokensDesc,      // most tokens first
+    TokensAsc,       // least tokens first
+    DurationDesc,    // longest first
+    MessagesDesc,    // most messages first
+    RecentlyResumed, // last resumed from ccboard first, then newest
 }
 
 impl SessionSortMode {
@@ -50,7 +53,8 @@ impl SessionSortMode {
             SessionSortMode::TokensDesc => SessionSortMode::TokensAsc,
             SessionSortMode::TokensAsc => SessionSortMode::DurationDesc,
             SessionSortMode::DurationDesc => SessionSortMode::MessagesDesc,
-            SessionSortMode::MessagesDesc => SessionSortMode::DateDesc,
+            SessionSortMode::MessagesDesc => SessionSortMode::RecentlyResumed,
+            SessionSortMode::RecentlyResumed => SessionSortMode::DateDesc,
         }
     }
 
@@ -63,6 +67,7 @@ impl SessionSortMode {
             SessionSortMode::TokensAsc => "Tokens ↑",
             SessionSortMode::DurationDesc => "Duration ↓",
             SessionSortMode::MessagesDesc => "Messages ↓",
+            SessionSortMode::RecentlyResumed => "Resumed ↓",
         }
     }
 }
@@ -111,6 +116,36 @@ impl DateFilter {
     }
 }
 
+/// Virtual project listing pinned sessions, shown first in the Projects pane
+pub const PINNED_PROJECT: &str = "📌 Pinned";
+
+/// Sessions grouped by project, plus a [`PINNED_PROJECT`] entry when any
+/// pinned session is loaded
+pub fn sessions_by_project_with_pins(
+    store: &ccboard_core::store::DataStore,
+) -> HashMap<String, Vec<Arc<SessionMetadata>>> {
+    let mut by_project = store.sessions_by_project();
+    let pinned = store.pinned_sessions();
+    if !pinned.is_empty() {
+        by_project.insert(PINNED_PROJECT.to_string(), pinned);
+    }
+    by_project
+}
+
+/// Colored chips for session tags; a tag keeps its color across sessions
+fn tag_chips(tags: &[String], p: &Palette) -> Vec<Span<'static>> {
+    let colors = [p.focus, p.success, p.warning, p.important];
+    tags.iter()
+        .flat_map(|tag| {
+            let color = colors[ccboard_core::tags::tag_color_index(tag, colors.len())];
+            [
+                Span::styled(format!(" {} ", tag), Style::default().fg(p.bg).bg(color)),
+                Span::raw(" "),
+            ]
+        })
+        .collect()
+}
+
 /// Sessions tab state
 pub struct SessionsTab {
     /// Project tree state (selected project index)
@@ -135,14 +170,29 @@ pub struct SessionsTab {
     date_filter: DateFilter,
     /// Sort mode for sessions
     sort_mode: SessionSortMode,
+    /// Session resumed since the last poll (recorded in the resume log by the UI)
+    resumed_session: Option<String>,
     /// Show detail popup for historical sessions
     show_detail: bool,
+    /// Tool-call timeline of the session in the detail pane, parsed on demand
+    /// and keyed by session ID and file size so it refreshes as the file grows
+    tool_timeline: Option<(SessionId, u64, ToolTimeline)>,
     /// Show detail popup for live sessions
     show_live_detail: bool,
     /// Show replay popup for selected session
     show_replay: bool,
     /// Replay messages cache (loaded on demand)
     replay_messages: Vec<SessionLine>,
+    /// Session file shown in the replay viewer (tailed for new lines)
+    replay_path: Option<std::path::PathBuf>,
+    /// Byte offset up to which the replay file has been parsed
+    replay_offset: u64,
+    /// Last time the replay file was polled for appended lines
+    replay_last_poll: Option<Instant>,
+    /// Whether only the tail of the session was loaded into the replay viewer
+    replay_tail_only: bool,
+    /// Unparseable lines skipped while loading the replay file
+    replay_malformed_lines: usize,
     /// Replay scroll state
     replay_scroll: ListState,
     /// Expanded tool results (set of message indices)
@@ -155,6 +205,10 @@ pub struct SessionsTab {
     replay_search_hits: Vec<usize>,
     /// Current position within search hits (for n/N navigation)
     replay_search_hit_idx: usize,
+    /// Replay bookmarks: message index per slot (`1`–`9` set, `'` + digit jumps)
+    replay_marks: [Option<usize>; 9],
+    /// `'` was pressed in the replay viewer: the next digit jumps to a bookmark
+    pending_mark_jump: bool,
     /// Error message to display
     error_message: Option<String>,
     /// Last refresh timestamp
@@ -174,6 +228,8 @@ pub struct SessionsTab {
     /// Set when user tries to open a session above the complexity threshold.
     /// Awaits [Enter] to confirm load or [Esc]/[n] to cancel.
     complexity_warning: Option<(std::path::PathBuf, usize)>,
+    /// Tag editor for the selected session (`T`)
+    tag_prompt: TagPrompt,
 }
 
 impl Default for SessionsTab {
@@ -205,16 +261,25 @@ impl SessionsTab {
             search_global: false,
             date_filter: DateFilter::All,
             sort_mode: SessionSortMode::DateDesc,
+            resumed_session: None,
             show_detail: false,
+            tool_timeline: None,
             show_live_detail: false,
             show_replay: false,
             replay_messages: Vec::new(),
+            replay_path: None,
+            replay_offset: 0,
+            replay_last_poll: None,
+            replay_tail_only: false,
+            replay_malformed_lines: 0,
             replay_scroll,
             replay_expanded: HashSet::new(),
             replay_search_query: String::new(),
             replay_search_active: false,
             replay_search_hits: Vec::new(),
             replay_search_hit_idx: 0,
+            replay_marks: [None; 9],
+            pending_mark_jump: false,
             error_message: None,
             last_refresh: Instant::now(),
             refresh_message: None,
@@ -223,15 +288,44 @@ impl SessionsTab {
             pending_gg: false,
             show_bookmarks_only: false,
             complexity_warning: None,
+            tag_prompt: TagPrompt::new(),
         }
     }
 
+    /// Take the ID of the session resumed since the last call, if any
+    pub fn take_resumed_session(&mut self) -> Option<String> {
+        self.resumed_session.take()
+    }
+
     /// Set a short notification message (auto-clears after 2s)
     pub fn set_notification(&mut self, msg: &str) {
         self.refresh_message = Some(msg.to_string());
         self.notification_time = Some(Instant::now());
     }
 
+    /// Whether the session list search bar is being typed into
+    pub fn is_search_active(&self) -> bool {
+        self.search_active
+    }
+
+    /// Open the tag editor for `session_id`, pre-filled with `tags`
+    pub fn open_tag_editor(&mut self, session_id: String, tags: &[String]) {
+        self.tag_prompt.show(session_id, tags);
+    }
+
+    /// Check if the tag editor is open
+    pub fn is_tag_editor_open(&self) -> bool {
+        self.tag_prompt.is_visible()
+    }
+
+    /// Forward a key to the tag editor, returns the session and tags on Enter
+    pub fn handle_tag_key(
+        &mut self,
+        key: crossterm::event::KeyCode,
+    ) -> Option<(String, Vec<String>)> {
+        self.tag_prompt.handle_key(key)
+    }
+
     /// Check if replay viewer is currently open
     pub fn is_replay_open(&self) -> bool {
         self.show_replay
@@ -248,24 +342,39 @@ impl SessionsTab {
             self.complexity_warning = Some((path, tool_calls));
             return;
         }
-        self.do_open_replay(path);
+        self.do_open_replay(path, false);
     }
 
     /// Unconditionally load and open the replay viewer for a session file.
-    fn do_open_replay(&mut self, path: std::path::PathBuf) {
-        match tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current()
-                .block_on(SessionContentParser::parse_session_lines(&path))
-        }) {
-            Ok(mut messages) => {
-                messages = SessionContentParser::filter_messages(messages);
+    ///
+    /// With `tail_only`, only the last REPLAY_TAIL_LINES lines are parsed, which
+    /// stays fast on multi-megabyte sessions.
+    fn do_open_replay(&mut self, path: std::path::PathBuf, tail_only: bool) {
+        const REPLAY_TAIL_LINES: usize = 200;
+        let result = tokio::task::block_in_place(|| {
+            if tail_only {
+                SessionContentParser::parse_session_tail(&path, REPLAY_TAIL_LINES)
+            } else {
+                SessionContentParser::parse_appended(&path, 0)
+            }
+        });
+        match result {
+            Ok(parsed) => {
+                let messages = SessionContentParser::filter_messages(parsed.lines);
                 let last = messages.len().saturating_sub(1);
                 self.replay_messages = messages;
+                self.replay_path = Some(path);
+                self.replay_offset = parsed.offset;
+                self.replay_malformed_lines = parsed.malformed_lines;
+                self.replay_last_poll = Some(Instant::now());
+                self.replay_tail_only = tail_only;
                 self.replay_scroll.select(Some(last));
                 self.replay_expanded.clear();
                 self.replay_search_query.clear();
                 self.replay_search_hits.clear();
                 self.replay_search_hit_idx = 0;
+                self.replay_marks = [None; 9];
+                self.pending_mark_jump = false;
                 self.show_replay = true;
             }
             Err(e) => {
@@ -287,7 +396,12 @@ impl SessionsTab {
             match key {
                 KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
                     if let Some((path, _)) = self.complexity_warning.take() {
-                        self.do_open_replay(path);
+                        self.do_open_replay(path, false);
+                    }
+                }
+                KeyCode::Char('t') => {
+                    if let Some((path, _)) = self.complexity_warning.take() {
+                        self.do_open_replay(path, true);
                     }
                 }
                 _ => {
@@ -317,6 +431,15 @@ impl SessionsTab {
             return;
         }
 
+        // `'` + digit jumps to a replay bookmark; any other key cancels
+        if self.pending_mark_jump {
+            self.pending_mark_jump = false;
+            if let KeyCode::Char(c @ '1'..='9') = key {
+                self.replay_jump_to_mark(c);
+            }
+            return;
+        }
+
         // Session list search mode
         if self.search_active {
             match key {
@@ -456,6 +579,7 @@ impl SessionsTab {
                         } else {
                             self.refresh_message = Some("Session resumed".to_string());
                             self.notification_time = Some(Instant::now());
+                            self.resumed_session = Some(session.id.to_string());
                         }
                     }
                 }
@@ -470,6 +594,14 @@ impl SessionsTab {
                     }
                 }
             }
+            KeyCode::Char('V') => {
+                // Replay only the end of the selected session (fast on huge files)
+                if self.focus == 2 && !self.show_replay {
+                    if let Some(session) = self.get_selected_session(_sessions_by_project) {
+                        self.do_open_replay(session.file_path.clone(), true);
+                    }
+                }
+            }
             KeyCode::Char('y') => {
                 // Copy session ID to clipboard (works from Sessions pane)
                 if self.focus == 2 {
@@ -493,6 +625,35 @@ impl SessionsTab {
                     }
                 }
             }
+            KeyCode::Char('Y') => {
+                // Copy the conversation as Markdown (works from Sessions pane)
+                if self.focus == 2 {
+                    if let Some(session) = self.get_selected_session(_sessions_by_project) {
+                        self.copy_conversation(session);
+                    }
+                }
+            }
+            KeyCode::Char('P') => {
+                // Copy session file path to clipboard (works from Sessions pane)
+                if self.focus == 2 {
+                    if let Some(session) = self.get_selected_session(_sessions_by_project) {
+                        let path = session.file_path.display().to_string();
+                        match arboard::Clipboard::new() {
+                            Ok(mut clipboard) => {
+                                if let Err(e) = clipboard.set_text(&path) {
+                                    self.error_message = Some(format!("Failed to copy: {}", e));
+                                } else {
+                                    self.refresh_message = Some(format!("✓ Copied: {}", path));
+                                    self.notification_time = Some(Instant::now());
+                                }
+                            }
+                            Err(e) => {
+                                self.error_message = Some(format!("Clipboard unavailable: {}", e));
+                            }
+                        }
+                    }
+                }
+            }
             KeyCode::Char('B') => {
                 // Toggle bookmarks-only filter
                 self.show_bookmarks_only = !self.show_bookmarks_only;
@@ -534,9 +695,11 @@ impl SessionsTab {
                 } else if self.show_replay {
                     self.show_replay = false;
                     self.replay_messages.clear();
+                    self.replay_path = None;
                     self.replay_expanded.clear();
                     self.replay_search_query.clear();
                     self.replay_search_hits.clear();
+                    self.replay_marks = [None; 9];
                 } else if self.show_live_detail {
                     self.show_live_detail = false;
                 } else {
@@ -565,6 +728,12 @@ impl SessionsTab {
             KeyCode::Char('N') if self.show_replay => {
                 self.replay_next_hit(-1);
             }
+            KeyCode::Char(c @ '1'..='9') if self.show_replay => {
+                self.set_replay_mark(c);
+            }
+            KeyCode::Char('\'') if self.show_replay => {
+                self.pending_mark_jump = true;
+            }
             KeyCode::PageUp => {
                 // Jump up by 10 items
                 if self.focus == 0 {
@@ -587,7 +756,9 @@ impl SessionsTab {
                 // Vim-style: 'gg' to go to top
                 if self.pending_gg {
                     // Second 'g' - go to top
-                    if self.focus == 0 {
+                    if self.show_replay {
+                        self.replay_jump_to_first();
+                    } else if self.focus == 0 {
                         self.project_state.select(Some(0));
                         self.session_state.select(Some(0));
                     } else {
@@ -601,7 +772,9 @@ impl SessionsTab {
             }
             KeyCode::Char('G') => {
                 // Vim-style: 'G' to go to bottom
-                if self.focus == 0 {
+                if self.show_replay {
+                    self.replay_jump_to_last();
+                } else if self.focus == 0 {
                     if !self.projects.is_empty() {
                         self.project_state.select(Some(self.projects.len() - 1));
                         self.session_state.select(Some(0));
@@ -614,7 +787,9 @@ impl SessionsTab {
             }
             KeyCode::Home => {
                 // Go to first item
-                if self.focus == 0 {
+                if self.show_replay {
+                    self.replay_jump_to_first();
+                } else if self.focus == 0 {
                     self.project_state.select(Some(0));
                     self.session_state.select(Some(0));
                 } else {
@@ -624,7 +799,9 @@ impl SessionsTab {
             }
             KeyCode::End => {
                 // Go to last item
-                if self.focus == 0 {
+                if self.show_replay {
+                    self.replay_jump_to_last();
+                } else if self.focus == 0 {
                     if !self.projects.is_empty() {
                         self.project_state.select(Some(self.projects.len() - 1));
                         self.session_state.select(Some(0));
@@ -662,6 +839,57 @@ impl SessionsTab {
         self.live_sessions_state.select(Some(new_idx));
     }
 
+    /// Append lines written to the replayed session since the last poll (live tail)
+    ///
+    /// Keeps following the end of the conversation if the last message was selected.
+    fn poll_replay_tail(&mut self) {
+        const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+        if self
+            .replay_last_poll
+            .is_some_and(|t| t.elapsed() < POLL_INTERVAL)
+        {
+            return;
+        }
+        self.replay_last_poll = Some(Instant::now());
+
+        let Some(path) = self.replay_path.as_ref() else {
+            return;
+        };
+        let Ok(parsed) = SessionContentParser::parse_appended(path, self.replay_offset) else {
+            return;
+        };
+        if parsed.offset < self.replay_offset {
+            // File was rewritten: reload from scratch
+            self.replay_messages.clear();
+            self.replay_expanded.clear();
+            self.replay_malformed_lines = 0;
+        }
+        self.replay_offset = parsed.offset;
+        self.replay_malformed_lines += parsed.malformed_lines;
+
+        let new_messages = SessionContentParser::filter_messages(parsed.lines);
+        if new_messages.is_empty() {
+            return;
+        }
+
+        let was_at_end = self
+            .replay_scroll
+            .selected()
+            .is_none_or(|i| i + 1 >= self.replay_messages.len());
+        self.replay_messages.extend(new_messages);
+        if was_at_end {
+            self.replay_scroll
+                .select(Some(self.replay_messages.len().saturating_sub(1)));
+        }
+        if !self.replay_search_query.is_empty() {
+            let hit_idx = self.replay_search_hit_idx;
+            self.rebuild_replay_search_hits();
+            self.replay_search_hit_idx =
+                hit_idx.min(self.replay_search_hits.len().saturating_sub(1));
+        }
+    }
+
     fn move_replay_selection(&mut self, delta: i32) {
         if self.replay_messages.is_empty() {
             return;
@@ -671,6 +899,19 @@ impl SessionsTab {
         self.replay_scroll.select(Some(new_idx));
     }
 
+    fn replay_jump_to_first(&mut self) {
+        if !self.replay_messages.is_empty() {
+            self.replay_scroll.select(Some(0));
+        }
+    }
+
+    fn replay_jump_to_last(&mut self) {
+        if !self.replay_messages.is_empty() {
+            self.replay_scroll
+                .select(Some(self.replay_messages.len() - 1));
+        }
+    }
+
     /// Rebuild the list of message indices matching the current replay search query.
     /// Tries query as a regex first; falls back to literal match if invalid regex.
     fn rebuild_replay_search_hits(&mut self) {
@@ -731,6 +972,51 @@ impl SessionsTab {
             .select(Some(self.replay_search_hits[new_idx]));
     }
 
+    /// Bookmark the selected replay message in slot `digit` (`'1'`–`'9'`)
+    fn set_replay_mark(&mut self, digit: char) {
+        let (Some(slot), Some(idx)) = (Self::mark_slot(digit), self.replay_scroll.selected())
+        else {
+            return;
+        };
+        if idx >= self.replay_messages.len() {
+            return;
+        }
+        self.replay_marks[slot] = Some(idx);
+        self.refresh_message = Some(format!("Bookmark {} → message {}", digit, idx + 1));
+        self.notification_time = Some(Instant::now());
+    }
+
+    /// Select the replay message bookmarked in slot `digit`
+    fn replay_jump_to_mark(&mut self, digit: char) {
+        match Self::mark_slot(digit).and_then(|slot| self.replay_marks[slot]) {
+            Some(idx) if idx < self.replay_messages.len() => {
+                self.replay_scroll.select(Some(idx));
+            }
+            _ => {
+                self.refresh_message = Some(format!("Bookmark {} not set", digit));
+                self.notification_time = Some(Instant::now());
+            }
+        }
+    }
+
+    /// `'1'`–`'9'` → bookmark slot `0`–`8`
+    fn mark_slot(digit: char) -> Option<usize> {
+        digit
+            .to_digit(10)
+            .filter(|d| (1..=9).contains(d))
+            .map(|d| d as usize - 1)
+    }
+
+    /// Project currently selected in the Projects pane (`None` on the
+    /// Pinned virtual project)
+    pub fn selected_project(&self) -> Option<&str> {
+        let idx = self.project_state.selected()?;
+        self.projects
+            .get(idx)
+            .map(String::as_str)
+            .filter(|p| *p != PINNED_PROJECT)
+    }
+
     fn get_selected_session<'a>(
         &self,
         sessions_by_project: &'a HashMap<String, Vec<Arc<SessionMetadata>>>,
@@ -780,7 +1066,11 @@ impl SessionsTab {
 
         // Update project cache
         self.projects = sessions_by_project.keys().cloned().collect();
-        self.projects.sort();
+        self.projects.sort_by(|a, b| {
+            (a != PINNED_PROJECT)
+                .cmp(&(b != PINNED_PROJECT))
+                .then_with(|| a.cmp(b))
+        });
 
         // Layout: [search bar (always visible), live sessions (if any), content]
         let live_height = if live_sessions.is_empty() {
@@ -888,6 +1178,7 @@ impl SessionsTab {
         // If replay is open, show it full width
         if self.show_replay {
             self.render_replay_popup(frame, content_area, &p);
+            self.render_refresh_notification(frame, content_area, &p);
             return;
         }
 
@@ -933,7 +1224,8 @@ impl SessionsTab {
             .split(content_area);
 
         // Render projects tree
-        self.render_projects(frame, chunks[0], sessions_by_project, &p);
+        let project_display = store.project_display();
+        self.render_projects(frame, chunks[0], sessions_by_project, &project_display, &p);
 
         // Get sessions for selected project or all projects (global search)
         let selected_project = self
@@ -947,8 +1239,9 @@ impl SessionsTab {
         let all_sessions: &[Arc<SessionMetadata>] = if self.search_global && self.search_active {
             // Global search: collect all sessions from all projects
             all_sessions_vec = sessions_by_project
-                .values()
-                .flat_map(|v| v.iter().map(Arc::clone))
+                .iter()
+                .filter(|(project, _)| *project != PINNED_PROJECT)
+                .flat_map(|(_, v)| v.iter().map(Arc::clone))
                 .collect();
             &all_sessions_vec
         } else {
@@ -961,6 +1254,15 @@ impl SessionsTab {
             &all_sessions_vec
         };
 
+        // Search hits come from the store's token index
+        let search_hits: Option<HashSet<SessionId>> = (!self.search_filter.is_empty()).then(|| {
+            store
+                .search(&self.search_filter)
+                .iter()
+                .map(|s| s.id.clone())
+                .collect()
+        });
+
         // Filter sessions based on search, date filter, and bookmark filter
         let mut sessions: Vec<Arc<SessionMetadata>> = all_sessions
             .iter()
@@ -976,24 +1278,13 @@ impl SessionsTab {
                 }
 
                 // Apply search filter if active
-                if self.search_filter.is_empty() {
-                    return true;
-                }
-
-                let query_lower = self.search_filter.to_lowercase();
-                s.id.to_lowercase().contains(&query_lower)
-                    || s.project_path.to_lowercase().contains(&query_lower)
-                    || s.first_user_message
-                        .as_ref()
-                        .is_some_and(|m| m.to_lowercase().contains(&query_lower))
-                    || s.models_used
-                        .iter()
-                        .any(|m: &String| m.to_lowercase().contains(&query_lower))
+                search_hits.as_ref().is_none_or(|hits| hits.contains(&s.id))
             })
             .map(Arc::clone)
             .collect();
 
         // Apply sort mode
+        let token_source = store.token_source();
         match self.sort_mode {
             SessionSortMode::DateDesc => {
                 sessions.sort_by(|a, b| b.last_timestamp.cmp(&a.last_timestamp));
@@ -1002,10 +1293,10 @@ impl SessionsTab {
                 sessions.sort_by(|a, b| a.last_timestamp.cmp(&b.last_timestamp));
             }
             SessionSortMode::TokensDesc => {
-                sessions.sort_by(|a, b| b.total_tokens.cmp(&a.total_tokens));
+                sessions.sort_by_key(|s| std::cmp::Reverse(s.tokens_for(token_source)));
             }
             SessionSortMode::TokensAsc => {
-                sessions.sort_by(|a, b| a.total_tokens.cmp(&b.total_tokens));
+                sessions.sort_by_key(|s| s.tokens_for(token_source));
             }
             SessionSortMode::DurationDesc => {
                 sessions.sort_by(|a, b| {
@@ -1025,6 +1316,12 @@ impl SessionsTab {
             SessionSortMode::MessagesDesc => {
                 sessions.sort_by(|a, b| b.message_count.cmp(&a.message_count));
             }
+            SessionSortMode::RecentlyResumed => {
+                // Never-resumed sessions (None) sort after resumed ones
+                sessions.sort_by_cached_key(|s| {
+                    std::cmp::Reverse((store.last_resumed(&s.id), s.last_timestamp))
+                });
+            }
         }
 
         // Clamp session selection
@@ -1040,6 +1337,9 @@ impl SessionsTab {
         // Render detail popup if open
         if self.show_detail && chunks.len() > 2 {
             let selected_session = self.session_state.selected().and_then(|i| sessions.get(i));
+            if let Some(session) = selected_session {
+                self.load_tool_timeline(session);
+            }
             self.render_detail(frame, chunks[2], selected_session, &p, store);
         }
 
@@ -1056,6 +1356,8 @@ impl SessionsTab {
             self.render_error_popup(frame, area, &p);
         }
 
+        self.tag_prompt.render(frame, area, _scheme);
+
         // Render refresh notification if present
         self.render_refresh_notification(frame, area, &p);
     }
@@ -1070,7 +1372,6 @@ impl SessionsTab {
     ) {
         use ccboard_core::LiveSessionDisplayStatus;
         use chrono::Local;
-        use ratatui::style::Color;
 
         // Clamp selection to valid range
         if let Some(sel) = self.live_sessions_state.selected() {
@@ -1109,7 +1410,7 @@ impl SessionsTab {
                 // Icon + color based on hook status
                 let (icon, status_color) = match status {
                     LiveSessionDisplayStatus::Running => ("●", p.success),
-                    LiveSessionDisplayStatus::WaitingInput => ("◐", Color::Yellow),
+                    LiveSessionDisplayStatus::WaitingInput => ("◐", p.warning),
                     LiveSessionDisplayStatus::Stopped => ("✓", p.muted),
                     LiveSessionDisplayStatus::ProcessOnly => ("🟢", p.success),
                     LiveSessionDisplayStatus::Unknown => ("?", p.muted),
@@ -1324,6 +1625,7 @@ impl SessionsTab {
         frame: &mut Frame,
         area: Rect,
         sessions_by_project: &HashMap<String, Vec<Arc<SessionMetadata>>>,
+        project_display: &ProjectDisplay,
         p: &Palette,
     ) {
         let is_focused = self.focus == 1; // Projects focus
@@ -1345,7 +1647,11 @@ impl SessionsTab {
             .enumerate()
             .map(|(i, path)| {
                 let is_selected = self.project_state.selected() == Some(i);
-                let display = Self::format_project_path(path);
+                let display = if path == PINNED_PROJECT {
+                    path.clone()
+                } else {
+                    project_display.format(path)
+                };
                 let session_count = sessions_by_project.get(path).map(|v| v.len()).unwrap_or(0);
 
                 let style = if is_selected && is_focused {
@@ -1390,6 +1696,7 @@ impl SessionsTab {
         let border_color = if is_focused { p.focus } else { p.border };
 
         let time_ago = self.format_time_ago();
+        let project_display = store.project_display();
         const MAX_DISPLAY: usize = 500;
         let total_count = sessions.len();
         let display_count = total_count.min(MAX_DISPLAY);
@@ -1544,7 +1851,7 @@ impl SessionsTab {
                     Style::default().fg(p.muted)
                 };
 
-                let tokens_str = Self::format_tokens(session.total_tokens);
+                let tokens_str = Self::format_session_tokens(session, store.token_source());
                 let msgs_str = format!("{}msg", session.message_count);
 
                 // Bookmark indicator (star before selection arrow)
@@ -1554,6 +1861,11 @@ impl SessionsTab {
                 } else {
                     Span::raw("  ")
                 };
+                let pin_span = if store.is_pinned(&session.id) {
+                    Span::raw("📌")
+                } else {
+                    Span::raw("")
+                };
 
                 // Build preview spans with optional highlighting
                 let badge = session.source_tool.badge();
@@ -1565,13 +1877,14 @@ impl SessionsTab {
 
                 let mut preview_spans = vec![
                     bookmark_span,
+                    pin_span,
                     badge_span,
                     Span::styled(format!("{} ", if is_selected { "▶" } else { " " }), style),
                 ];
 
                 // Add project prefix if global search is active
                 if self.search_global && self.search_active {
-                    let project_short = Self::format_project_path(&session.project_path);
+                    let project_short = project_display.format(&session.project_path);
                     preview_spans.push(Span::styled(
                         format!("[{}] ", project_short),
                         Style::default().fg(p.focus),
@@ -1592,7 +1905,7 @@ impl SessionsTab {
                             Self::format_short(session.lines_added),
                             Self::format_short(session.lines_removed)
                         ),
-                        Style::default().fg(Color::Green),
+                        Style::default().fg(p.success),
                     ));
                 }
 
@@ -1643,6 +1956,120 @@ impl SessionsTab {
         }
     }
 
+    /// Copy the conversation of `session` to the clipboard as Markdown
+    ///
+    /// Conversations larger than `MAX_CLIPBOARD_BYTES` are cut with a notice,
+    /// since some clipboard managers drop oversized payloads.
+    fn copy_conversation(&mut self, session: &Arc<SessionMetadata>) {
+        const MAX_CLIPBOARD_BYTES: usize = 1024 * 1024;
+
+        let rendered = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(SessionContentParser::parse_conversation(
+                &session.file_path,
+                (**session).clone(),
+            ))
+        })
+        .map_err(anyhow::Error::from)
+        .and_then(|messages| ccboard_core::export::conversation_to_markdown(&messages, session));
+        let markdown = match rendered {
+            Ok(markdown) => markdown,
+            Err(e) => {
+                self.error_message = Some(format!("Failed to load conversation: {}", e));
+                return;
+            }
+        };
+        let (text, truncated) = truncate_for_clipboard(markdown, MAX_CLIPBOARD_BYTES);
+
+        match arboard::Clipboard::new() {
+            Ok(mut clipboard) => {
+                if let Err(e) = clipboard.set_text(&text) {
+                    self.error_message = Some(format!("Failed to copy: {}", e));
+                } else {
+                    let suffix = if truncated { " (truncated)" } else { "" };
+                    self.refresh_message = Some(format!(
+                        "✓ Copied conversation: {}{}",
+                        &session.id[..8.min(session.id.len())],
+                        suffix
+                    ));
+                    self.notification_time = Some(Instant::now());
+                }
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Clipboard unavailable: {}", e));
+            }
+        }
+    }
+
+    /// Parse the tool-call timeline of `session` unless already cached
+    fn load_tool_timeline(&mut self, session: &SessionMetadata) {
+        let cached = self
+            .tool_timeline
+            .as_ref()
+            .is_some_and(|(id, size, _)| *id == session.id && *size == session.file_size_bytes);
+        if cached {
+            return;
+        }
+        let timeline =
+            tokio::task::block_in_place(|| SessionContentParser::tool_timeline(&session.file_path))
+                .unwrap_or_default();
+        self.tool_timeline = Some((session.id.clone(), session.file_size_bytes, timeline));
+    }
+
+    /// Tool-call density across the session as a block strip, plus the busiest
+    /// slice's dominant tool
+    fn tool_timeline_lines(timeline: &ToolTimeline, p: &Palette) -> Vec<Line<'static>> {
+        const BUCKETS: usize = 30;
+        const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+        let density = timeline.density(BUCKETS);
+        let max = density.iter().copied().max().unwrap_or(0);
+        if max == 0 {
+            return Vec::new();
+        }
+        let strip: String = density
+            .iter()
+            .map(|&n| match n {
+                0 => ' ',
+                n => BARS[((n - 1) * BARS.len() / max).min(BARS.len() - 1)],
+            })
+            .collect();
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("Tool Timeline ({} calls):", timeline.calls.len()),
+                Style::default().fg(p.muted),
+            )),
+            Line::from(vec![
+                Span::styled("  ▕", Style::default().fg(p.muted)),
+                Span::styled(strip, Style::default().fg(p.focus)),
+                Span::styled("▏", Style::default().fg(p.muted)),
+            ]),
+        ];
+
+        // Earliest busiest slice wins ties
+        let busiest = density
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, &n)| n)
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        if let Some((tool, count)) = timeline.top_tool_in(busiest, BUCKETS) {
+            let pct = (busiest * 2 + 1) * 50 / BUCKETS;
+            lines.push(Line::from(vec![
+                Span::styled("  Peak: ", Style::default().fg(p.muted)),
+                Span::styled(tool.to_string(), Style::default().fg(p.focus)),
+                Span::styled(format!(" ×{}", count), Style::default().fg(p.warning)),
+                Span::styled(
+                    format!(" around {}% into the session", pct),
+                    Style::default().fg(p.muted),
+                ),
+            ]));
+        }
+        lines
+    }
+
     fn render_detail(
         &self,
         frame: &mut Frame,
@@ -1651,6 +2078,7 @@ impl SessionsTab {
         p: &Palette,
         store: &ccboard_core::store::DataStore,
     ) {
+        let currency = store.currency();
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
@@ -1696,6 +2124,13 @@ impl SessionsTab {
             ]));
         }
 
+        let tags = store.tags_for(&session.id);
+        if !tags.is_empty() {
+            let mut spans = vec![Span::styled("Tags: ", Style::default().fg(p.muted))];
+            spans.extend(tag_chips(&tags, p));
+            lines.push(Line::from(spans));
+        }
+
         lines.extend(vec![
             Line::from(""),
             Line::from(vec![
@@ -1733,7 +2168,7 @@ impl SessionsTab {
             Line::from(vec![
                 Span::styled("Tokens: ", Style::default().fg(p.muted)),
                 Span::styled(
-                    Self::format_tokens(session.total_tokens),
+                    Self::format_session_tokens(session, store.token_source()),
                     Style::default().fg(p.focus),
                 ),
             ]),
@@ -1786,6 +2221,26 @@ impl SessionsTab {
                     ),
                 ]));
             }
+            if session.tool_result_bytes > 0 {
+                let fresh_input = session.input_tokens + session.cache_creation_tokens;
+                let share = if fresh_input > 0 {
+                    format!(
+                        " ({:.0}% of input)",
+                        (session.tool_result_tokens() as f64 / fresh_input as f64 * 100.0)
+                            .min(100.0)
+                    )
+                } else {
+                    String::new()
+                };
+                lines.push(Line::from(vec![
+                    Span::styled("  ├─ Tool Results: ", Style::default().fg(p.muted)),
+                    Span::styled(
+                        format!("~{}", Self::format_tokens(session.tool_result_tokens())),
+                        Style::default().fg(p.error),
+                    ),
+                    Span::styled(share, Style::default().fg(p.muted)),
+                ]));
+            }
             if session.cache_read_tokens > 0 {
                 lines.push(Line::from(vec![
                     Span::styled("  └─ Cache Read: ", Style::default().fg(p.muted)),
@@ -1797,6 +2252,141 @@ impl SessionsTab {
             }
         }
 
+        // Peak context window utilization (flagged near the model's limit)
+        if session.peak_context_tokens > 0 {
+            let near_limit = session.context_near_limit();
+            let mut spans = vec![
+                Span::styled("Peak Context: ", Style::default().fg(p.muted)),
+                Span::styled(
+                    format!(
+                        "{:.0}% ({})",
+                        session.peak_context_pct,
+                        Self::format_tokens(session.peak_context_tokens)
+                    ),
+                    Style::default().fg(if near_limit { p.error } else { p.success }),
+                ),
+            ];
+            if near_limit && session.compaction_count == 0 {
+                spans.push(Span::styled(
+                    " ⚠ near limit",
+                    Style::default().fg(p.warning),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        // Context compactions: earlier conversation was summarized away
+        if session.compaction_count > 0 {
+            lines.push(Line::from(vec![
+                Span::styled("Compactions: ", Style::default().fg(p.muted)),
+                Span::styled(
+                    format!("🗜 {}", session.compaction_count),
+                    Style::default().fg(p.warning),
+                ),
+                Span::styled(
+                    " (earlier context summarized)",
+                    Style::default().fg(p.muted),
+                ),
+            ]));
+        }
+
+        // Cost efficiency (multi-model sessions split tokens by message share)
+        let na = || "n/a".to_string();
+        lines.push(Line::from(vec![
+            Span::styled("Est. Cost: ", Style::default().fg(p.muted)),
+            Span::styled(
+                currency.format(session.estimated_cost(), 4),
+                Style::default().fg(p.warning),
+            ),
+            Span::styled(
+                if session.is_multi_model() {
+                    " (approx., split by model)"
+                } else {
+                    ""
+                },
+                Style::default().fg(p.muted),
+            ),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("  ├─ Per Message: ", Style::default().fg(p.muted)),
+            Span::styled(
+                session
+                    .cost_per_message()
+                    .map_or_else(na, |c| currency.format(c, 4)),
+                Style::default().fg(p.fg),
+            ),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("  ├─ Per 1K Tokens: ", Style::default().fg(p.muted)),
+            Span::styled(
+                session
+                    .cost_per_token()
+                    .map_or_else(na, |c| currency.format(c * 1000.0, 4)),
+                Style::default().fg(p.fg),
+            ),
+        ]));
+
+        // Where the money went, per token kind and (if several) per model
+        let breakdown = session.cost_breakdown();
+        let total = &breakdown.total;
+        let kinds = [
+            ("Input", total.input_tokens, total.input_cost),
+            ("Output", total.output_tokens, total.output_cost),
+            ("Cache Read", total.cache_read_tokens, total.cache_read_cost),
+            (
+                "Cache Write",
+                total.cache_write_tokens,
+                total.cache_write_cost,
+            ),
+        ];
+        let multi = breakdown.models.len() > 1;
+        for (i, (label, tokens, cost)) in kinds.iter().enumerate() {
+            let branch = if i + 1 == kinds.len() && !multi {
+                "  └─ "
+            } else {
+                "  ├─ "
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{}{:<12}", branch, format!("{}:", label)),
+                    Style::default().fg(p.muted),
+                ),
+                Span::styled(
+                    format!("{:>10}", currency.format(*cost, 4)),
+                    Style::default().fg(p.fg),
+                ),
+                Span::styled(
+                    format!("  {} tok", Self::format_tokens(*tokens)),
+                    Style::default().fg(p.muted),
+                ),
+            ]));
+        }
+        if multi {
+            lines.push(Line::from(Span::styled(
+                "  └─ By model:",
+                Style::default().fg(p.muted),
+            )));
+            for (i, model) in breakdown.models.iter().enumerate() {
+                let branch = if i + 1 == breakdown.models.len() {
+                    "       └─ "
+                } else {
+                    "       ├─ "
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(branch, Style::default().fg(p.muted)),
+                    Span::styled(model.model.clone(), Style::default().fg(p.focus)),
+                    Span::styled(
+                        format!(" {:.0}%: ", model.share * 100.0),
+                        Style::default().fg(p.muted),
+                    ),
+                    Span::styled(
+                        currency.format(model.costs.total_cost, 4),
+                        Style::default().fg(p.warning),
+                    ),
+                ]));
+            }
+        }
+
         lines.extend(vec![Line::from(vec![
             Span::styled("File Size: ", Style::default().fg(p.muted)),
             Span::styled(session.size_display(), Style::default().fg(p.fg)),
@@ -1857,6 +2447,74 @@ impl SessionsTab {
             }
         }
 
+        // Continuation chain: how the task evolved across session files
+        if let Some(chain) = store.session_chain(&session.id) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("⛓ Chain ({} sessions):", chain.len()),
+                    Style::default().fg(p.success).bold(),
+                ),
+                Span::styled(
+                    if chain.has_inferred_links() {
+                        "  ~ = inferred from timing"
+                    } else {
+                        ""
+                    },
+                    Style::default().fg(p.muted),
+                ),
+            ]));
+            for node in chain.nodes.iter().take(8) {
+                let is_current = node.session_id == session.id.as_str();
+                let connector = match node.link {
+                    None => "  ".to_string(),
+                    Some(link) => format!(
+                        "  {}{} ",
+                        "   ".repeat(node.depth - 1),
+                        if link == ChainLink::Inferred {
+                            "└~"
+                        } else {
+                            "└─"
+                        }
+                    ),
+                };
+                let member = store.get_session(&node.session_id);
+                let started = member
+                    .as_ref()
+                    .and_then(|m| m.first_timestamp)
+                    .map(|ts| ts.format(" %m-%d %H:%M").to_string())
+                    .unwrap_or_default();
+                let msgs = member
+                    .as_ref()
+                    .map(|m| format!(" {} msgs", m.message_count))
+                    .unwrap_or_default();
+                let id_style = if is_current {
+                    Style::default().fg(p.focus).bold()
+                } else {
+                    Style::default().fg(p.fg)
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(connector, Style::default().fg(p.muted)),
+                    Span::styled(
+                        node.session_id[..node.session_id.len().min(8)].to_string(),
+                        id_style,
+                    ),
+                    Span::styled(started, Style::default().fg(p.muted)),
+                    Span::styled(msgs, Style::default().fg(p.muted)),
+                    Span::styled(
+                        if is_current { "  ◀ this session" } else { "" },
+                        Style::default().fg(p.focus),
+                    ),
+                ]));
+            }
+            if chain.len() > 8 {
+                lines.push(Line::from(vec![Span::styled(
+                    format!("  … and {} more", chain.len() - 8),
+                    Style::default().fg(p.muted),
+                )]));
+            }
+        }
+
         lines.push(Line::from(""));
 
         // Model switching timeline
@@ -1914,6 +2572,12 @@ impl SessionsTab {
             }
         }
 
+        if let Some((id, _, timeline)) = &self.tool_timeline {
+            if *id == session.id {
+                lines.extend(Self::tool_timeline_lines(timeline, p));
+            }
+        }
+
         // Bookmark section — clone to avoid lifetime issues with lines Vec
         if let Some(entry) = store.bookmark_entry(&session.id) {
             use ratatui::style::Color;
@@ -1970,16 +2634,6 @@ impl SessionsTab {
         frame.render_widget(detail, inner);
     }
 
-    fn format_project_path(path: &str) -> String {
-        // Shorten path for display
-        let parts: Vec<&str> = path.split('/').collect();
-        if parts.len() <= 3 {
-            path.to_string()
-        } else {
-            format!(".../{}", parts[parts.len() - 2..].join("/"))
-        }
-    }
-
     /// Shorten a full model ID to a compact display name.
     /// e.g. "claude-opus-4-5-20251101" → "Opus 4.5"
     fn shorten_model_name(model: &str) -> String {
@@ -2022,6 +2676,16 @@ impl SessionsTab {
         }
     }
 
+    /// Session token total under `source`, prefixed with `~` when estimated
+    fn format_session_tokens(session: &SessionMetadata, source: TokenSource) -> String {
+        let tokens = Self::format_tokens(session.tokens_for(source));
+        if session.tokens_estimated(source) {
+            format!("~{}", tokens)
+        } else {
+            tokens
+        }
+    }
+
     fn render_live_detail(
         &self,
         frame: &mut Frame,
@@ -2036,9 +2700,7 @@ impl SessionsTab {
         let status = live_session.effective_status();
         let (status_icon, status_label, status_color) = match status {
             LiveSessionDisplayStatus::Running => ("●", "Running", p.success),
-            LiveSessionDisplayStatus::WaitingInput => {
-                ("◐", "Waiting for input", ratatui::style::Color::Yellow)
-            }
+            LiveSessionDisplayStatus::WaitingInput => ("◐", "Waiting for input", p.warning),
             LiveSessionDisplayStatus::Stopped => ("✓", "Stopped", p.muted),
             LiveSessionDisplayStatus::ProcessOnly => ("🟢", "Running (ps only)", p.success),
             LiveSessionDisplayStatus::Unknown => ("?", "Unknown", p.muted),
@@ -2306,25 +2968,47 @@ impl SessionsTab {
     }
 
     fn render_replay_popup(&mut self, frame: &mut Frame, area: Rect, p: &Palette) {
+        self.poll_replay_tail();
+
         let total_messages = self.replay_messages.len();
         let selected_idx = self.replay_scroll.selected().unwrap_or(0);
 
         let title = format!(
-            " 🎬 Session Replay • Message {}/{} ",
+            " 🎬 Session Replay • Message {}/{}{} ",
             if total_messages > 0 {
                 selected_idx + 1
             } else {
                 0
             },
-            total_messages
+            total_messages,
+            if self.replay_tail_only { " (tail)" } else { "" }
         );
+        let title = if self.pending_mark_jump {
+            format!("{}• jump to bookmark 1-9 ", title)
+        } else {
+            title
+        };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(p.important))
             .style(Style::default().bg(p.surface))
             .title(Span::styled(title, Style::default().fg(p.important).bold()));
+        if self.replay_malformed_lines > 0 {
+            block = block.title(Span::styled(
+                format!(
+                    "({} line{} skipped) ",
+                    self.replay_malformed_lines,
+                    if self.replay_malformed_lines == 1 {
+                        ""
+                    } else {
+                        "s"
+                    }
+                ),
+                Style::default().fg(p.muted),
+            ));
+        }
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -2368,6 +3052,75 @@ impl SessionsTab {
                     Style::default().fg(type_color)
                 };
 
+                let marks: String = (1..=9)
+                    .filter(|d| self.replay_marks[d - 1] == Some(idx))
+                    .map(|d| format!(" '{}", d))
+                    .collect();
+
+                // Compaction: the boundary marker and the summary that
+                // replaced the earlier conversation (collapsed by default)
+                if msg.is_compact_boundary() || msg.is_compact_summary {
+                    let style = if is_selected {
+                        Style::default().fg(p.warning).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(p.warning)
+                    };
+                    let label = if msg.is_compact_summary {
+                        "Compaction summary"
+                    } else {
+                        "Context compacted"
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled(
+                            if is_selected { "▶ " } else { "  " },
+                            Style::default().fg(p.fg),
+                        ),
+                        Span::styled(format!("[{}] ", timestamp_str), style),
+                        Span::styled(format!("🗜 {}", label), style),
+                        Span::styled(marks, Style::default().fg(p.important).bold()),
+                    ]));
+                    if let Some(meta) = &msg.compact_metadata {
+                        let details: Vec<String> = meta
+                            .trigger
+                            .clone()
+                            .into_iter()
+                            .chain(
+                                meta.pre_tokens
+                                    .map(|t| format!("{} tokens before", Self::format_tokens(t))),
+                            )
+                            .collect();
+                        if !details.is_empty() {
+                            lines.push(Line::from(Span::styled(
+                                format!("  {}", details.join(" · ")),
+                                Style::default().fg(p.muted),
+                            )));
+                        }
+                    }
+                    if msg.is_compact_summary {
+                        let text = msg
+                            .message
+                            .as_ref()
+                            .and_then(|m| m.content.as_ref())
+                            .map(Self::extract_message_content)
+                            .unwrap_or_default();
+                        if is_expanded {
+                            for line in text.lines() {
+                                lines.push(Line::from(Span::styled(
+                                    format!("  {}", line),
+                                    Style::default().fg(p.fg),
+                                )));
+                            }
+                        } else {
+                            lines.push(Line::from(Span::styled(
+                                format!("  ▶ {} line(s) [Enter]", text.lines().count()),
+                                Style::default().fg(p.muted),
+                            )));
+                        }
+                    }
+                    lines.push(Line::from(""));
+                    return vec![ListItem::new(lines)];
+                }
+
                 lines.push(Line::from(vec![
                     Span::styled(
                         if is_selected { "▶ " } else { "  " },
@@ -2376,6 +3129,7 @@ impl SessionsTab {
                     Span::styled(format!("[{}] ", timestamp_str), header_style),
                     Span::styled(format!("{} ", type_icon), header_style),
                     Span::styled(msg.line_type.clone(), header_style),
+                    Span::styled(marks, Style::default().fg(p.important).bold()),
                 ]));
 
                 // Message content
@@ -2383,7 +3137,15 @@ impl SessionsTab {
                     if let Some(content) = &message.content {
                         // Text content (tool blocks handled separately)
                         let content_text = Self::extract_message_content(content);
-                        if !content_text.is_empty() {
+                        if is_expanded && has_code_block(&content_text) {
+                            // Full text with highlighted code blocks
+                            for line in render_code_blocks(&content_text) {
+                                let mut spans = vec![Span::raw("  ")];
+                                spans.extend(line.spans);
+                                lines.push(Line::from(spans).style(line.style));
+                            }
+                        } else if !content_text.is_empty() {
+                            let has_code = has_code_block(&content_text);
                             let preview = if content_text.len() > 200 {
                                 format!("{}...", &content_text[..197])
                             } else {
@@ -2401,6 +3163,12 @@ impl SessionsTab {
                                     Style::default().fg(p.fg),
                                 )]));
                             }
+                            if has_code {
+                                lines.push(Line::from(Span::styled(
+                                    "  ▶ code block [Enter]",
+                                    Style::default().fg(p.muted),
+                                )));
+                            }
                         }
 
                         // Tool calls from content blocks (real Claude Code format)
@@ -2561,6 +3329,11 @@ impl SessionsTab {
                 Span::styled("scroll", Style::default().fg(p.fg)),
                 Span::styled(" │ ", Style::default().fg(p.muted)),
                 Span::raw("["),
+                Span::styled("gg/G", Style::default().fg(p.bg).bg(p.important).bold()),
+                Span::raw("] "),
+                Span::styled("first/last", Style::default().fg(p.fg)),
+                Span::styled(" │ ", Style::default().fg(p.muted)),
+                Span::raw("["),
                 Span::styled("Enter", Style::default().fg(p.bg).bg(p.important).bold()),
                 Span::raw("] "),
                 Span::styled("expand", Style::default().fg(p.fg)),
@@ -2571,6 +3344,15 @@ impl SessionsTab {
                 Span::styled("search", Style::default().fg(p.fg)),
                 Span::styled(" │ ", Style::default().fg(p.muted)),
                 Span::raw("["),
+                Span::styled("1-9", Style::default().fg(p.bg).bg(p.important).bold()),
+                Span::raw("] "),
+                Span::styled("mark", Style::default().fg(p.fg)),
+                Span::raw(" ["),
+                Span::styled("'1-9", Style::default().fg(p.bg).bg(p.important).bold()),
+                Span::raw("] "),
+                Span::styled("jump", Style::default().fg(p.fg)),
+                Span::styled(" │ ", Style::default().fg(p.muted)),
+                Span::raw("["),
                 Span::styled("Esc", Style::default().fg(p.bg).bg(p.important).bold()),
                 Span::raw("] "),
                 Span::styled("close", Style::default().fg(p.fg)),
@@ -2678,41 +3460,42 @@ impl SessionsTab {
             .collect()
     }
 
-    /// Format a compact summary of tool input parameters for display.
+    /// Format a single-line preview of tool input parameters for display.
+    ///
+    /// Known tools show their most relevant field (command, file path,
+    /// pattern...); anything else falls back to a compact JSON snippet.
     fn format_tool_input_summary(name: &str, input: &serde_json::Value) -> String {
-        let Some(obj) = input.as_object() else {
-            return String::new();
-        };
         let key_field = match name {
-            "Read" | "Write" | "Edit" | "MultiEdit" => "file_path",
+            "Read" | "Write" | "Edit" | "MultiEdit" | "NotebookEdit" => "file_path",
             "Bash" => "command",
-            "Grep" => "pattern",
-            "Glob" => "pattern",
-            "WebFetch" | "WebSearch" => "url",
+            "Grep" | "Glob" => "pattern",
+            "WebFetch" => "url",
+            "WebSearch" => "query",
+            "Task" => "description",
             _ => "",
         };
         if !key_field.is_empty() {
-            if let Some(val) = obj.get(key_field).and_then(|v| v.as_str()) {
-                let s = if val.len() > 70 {
-                    format!("{}...", &val[..67])
-                } else {
-                    val.to_string()
-                };
-                return format!("{}: {}", key_field, s);
+            if let Some(val) = input.get(key_field).and_then(|v| v.as_str()) {
+                return format!("{}: {}", key_field, Self::single_line_preview(val));
             }
         }
-        // Fallback: first string value in the object
-        for (k, v) in obj.iter().take(1) {
-            if let Some(s) = v.as_str() {
-                let truncated = if s.len() > 70 {
-                    format!("{}...", &s[..67])
-                } else {
-                    s.to_string()
-                };
-                return format!("{}: {}", k, truncated);
-            }
+        match input {
+            serde_json::Value::Null => String::new(),
+            serde_json::Value::Object(obj) if obj.is_empty() => String::new(),
+            _ => Self::single_line_preview(&input.to_string()),
+        }
+    }
+
+    /// Collapse whitespace to one line and truncate to 150 chars
+    fn single_line_preview(text: &str) -> String {
+        const MAX_CHARS: usize = 150;
+        let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if flat.chars().count() > MAX_CHARS {
+            let cut: String = flat.chars().take(MAX_CHARS - 3).collect();
+            format!("{}...", cut)
+        } else {
+            flat
         }
-        String::new()
     }
 
     fn render_complexity_warning_popup(&self, frame: &mut Frame, area: Rect, p: &Palette) {
@@ -2756,6 +3539,8 @@ impl SessionsTab {
             Line::from(vec![
                 Span::styled("[Enter/y]", Style::default().fg(p.success).bold()),
                 Span::styled(" Load anyway  ", Style::default().fg(p.fg)),
+                Span::styled("[t]", Style::default().fg(p.warning).bold()),
+                Span::styled(" Last messages only  ", Style::default().fg(p.fg)),
                 Span::styled("[Esc/n]", Style::default().fg(p.error).bold()),
                 Span::styled(" Cancel", Style::default().fg(p.fg)),
             ]),
@@ -2930,6 +3715,11 @@ impl SessionsTab {
                     Span::styled("replay", Style::default().fg(p.fg)),
                     Span::styled(" │ ", Style::default().fg(p.muted)),
                     Span::raw("["),
+                    Span::styled("V", Style::default().fg(p.bg).bg(p.focus).bold()),
+                    Span::raw("] "),
+                    Span::styled("replay tail", Style::default().fg(p.fg)),
+                    Span::styled(" │ ", Style::default().fg(p.muted)),
+                    Span::raw("["),
                     Span::styled("w", Style::default().fg(p.bg).bg(Color::Yellow).bold()),
                     Span::raw("] "),
                     Span::styled("view waiting", Style::default().fg(p.fg)),
@@ -2953,6 +3743,16 @@ impl SessionsTab {
                     Span::styled("B", Style::default().fg(p.bg).bg(Color::Yellow).bold()),
                     Span::raw("] "),
                     Span::styled("★ only", Style::default().fg(p.fg)),
+                    Span::styled(" │ ", Style::default().fg(p.muted)),
+                    Span::raw("["),
+                    Span::styled("p", Style::default().fg(p.bg).bg(p.focus).bold()),
+                    Span::raw("] "),
+                    Span::styled("pin", Style::default().fg(p.fg)),
+                    Span::styled(" │ ", Style::default().fg(p.muted)),
+                    Span::raw("["),
+                    Span::styled("T", Style::default().fg(p.bg).bg(p.focus).bold()),
+                    Span::raw("] "),
+                    Span::styled("tags", Style::default().fg(p.fg)),
                 ]
             }
             _ => vec![],
@@ -3013,6 +3813,26 @@ impl SessionsTab {
 
 // ─── Tests ──────────────────────────────────────────────────────────────────
 
+/// Cut `text` to at most `max_bytes` (on a char boundary) plus a notice
+///
+/// Returns whether anything was cut.
+fn truncate_for_clipboard(mut text: String, max_bytes: usize) -> (String, bool) {
+    if text.len() <= max_bytes {
+        return (text, false);
+    }
+    let total = text.len();
+    let mut cut = max_bytes;
+    while !text.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    text.truncate(cut);
+    text.push_str(&format!(
+        "\n\n---\n*Truncated: copied the first {} of {} bytes. Use `ccboard export conversation` for the full session.*\n",
+        cut, total
+    ));
+    (text, true)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3084,6 +3904,61 @@ mod tests {
         assert_eq!(tab.replay_search_hits, vec![0]);
     }
 
+    #[test]
+    fn test_replay_jump_first_last() {
+        use crossterm::event::KeyCode;
+
+        let mut tab = SessionsTab::new();
+        tab.show_replay = true;
+        tab.replay_messages = vec![
+            make_text_msg("one"),
+            make_text_msg("two"),
+            make_text_msg("three"),
+        ];
+        tab.replay_scroll.select(Some(1));
+        let sessions = HashMap::new();
+
+        tab.handle_key(KeyCode::Char('G'), &sessions);
+        assert_eq!(tab.replay_scroll.selected(), Some(2));
+
+        tab.handle_key(KeyCode::Char('g'), &sessions);
+        tab.handle_key(KeyCode::Char('g'), &sessions);
+        assert_eq!(tab.replay_scroll.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_replay_bookmarks() {
+        use crossterm::event::KeyCode;
+
+        let mut tab = SessionsTab::new();
+        tab.show_replay = true;
+        tab.replay_messages = (0..5).map(|i| make_text_msg(&i.to_string())).collect();
+        let sessions = HashMap::new();
+
+        tab.replay_scroll.select(Some(3));
+        tab.handle_key(KeyCode::Char('2'), &sessions);
+        assert_eq!(tab.replay_marks[1], Some(3));
+
+        tab.replay_scroll.select(Some(0));
+        tab.handle_key(KeyCode::Char('\''), &sessions);
+        tab.handle_key(KeyCode::Char('2'), &sessions);
+        assert_eq!(tab.replay_scroll.selected(), Some(3));
+
+        // Unset slot leaves the selection alone; a non-digit cancels the jump
+        tab.handle_key(KeyCode::Char('\''), &sessions);
+        tab.handle_key(KeyCode::Char('5'), &sessions);
+        assert_eq!(tab.replay_scroll.selected(), Some(3));
+        tab.handle_key(KeyCode::Char('\''), &sessions);
+        tab.handle_key(KeyCode::Char('G'), &sessions);
+        assert_eq!(tab.replay_scroll.selected(), Some(3));
+        assert!(!tab.pending_mark_jump);
+
+        // Closing the replay drops its bookmarks
+        tab.handle_key(KeyCode::Esc, &sessions);
+        assert!(!tab.show_replay);
+        assert_eq!(tab.replay_marks, [None; 9]);
+    }
+
     #[test]
     fn test_replay_next_hit_wraps() {
         let mut tab = SessionsTab::new();
@@ -3107,6 +3982,19 @@ mod tests {
         assert_eq!(tab.replay_search_hit_idx, 1);
     }
 
+    #[test]
+    fn test_truncate_for_clipboard() {
+        let (text, truncated) = truncate_for_clipboard("short".to_string(), 100);
+        assert_eq!(text, "short");
+        assert!(!truncated);
+
+        // Never splits a multi-byte char
+        let (text, truncated) = truncate_for_clipboard("aé".repeat(10), 4);
+        assert!(truncated);
+        assert!(text.starts_with("aéa\n"));
+        assert!(text.contains("first 4 of 30 bytes"));
+    }
+
     #[test]
     fn test_extract_tool_use_blocks_empty() {
         let content = serde_json::Value::String("hello".to_string());
@@ -3155,12 +4043,31 @@ mod tests {
 
     #[test]
     fn test_format_tool_input_summary_truncates_long_values() {
-        let long_path = "a".repeat(80);
+        let long_path = "é".repeat(200);
         let input = serde_json::json!({"file_path": long_path});
         let summary = SessionsTab::format_tool_input_summary("Read", &input);
         assert!(summary.ends_with("..."));
-        // "file_path: " (11) + 67 chars + "..." (3) = 81 max
-        assert!(summary.len() <= 85);
+        // "file_path: " (11) + 147 chars + "..." (3)
+        assert_eq!(summary.chars().count(), 161);
+    }
+
+    #[test]
+    fn test_format_tool_input_summary_single_line_and_fallback() {
+        let bash_input = serde_json::json!({"command": "cd /tmp &&\n  cargo build"});
+        assert_eq!(
+            SessionsTab::format_tool_input_summary("Bash", &bash_input),
+            "command: cd /tmp && cargo build"
+        );
+
+        let unknown = serde_json::json!({"server": "github", "limit": 5});
+        assert_eq!(
+            SessionsTab::format_tool_input_summary("mcp__github__search", &unknown),
+            r#"{"limit":5,"server":"github"}"#
+        );
+        assert_eq!(
+            SessionsTab::format_tool_input_summary("Unknown", &serde_json::json!({})),
+            ""
+        );
     }
 
     #[test]
@@ -17,7 +17,7 @@ mod tab_smoke {
     #[test]
     fn dashboard_renders_empty() {
         use crate::tabs::dashboard::DashboardTab;
-        let tab = DashboardTab::new();
+        let mut tab = DashboardTab::new();
         let mut terminal = make_terminal();
         terminal
             .draw(|frame| {
@@ -81,6 +81,7 @@ mod tab_smoke {
             project: None,
             local: None,
             merged: Settings::default(),
+            sources: Default::default(),
         };
         let rules = Rules::default();
         let mut terminal = make_terminal();
@@ -160,6 +161,53 @@ mod tab_smoke {
         assert!(!buf.content().iter().all(|c| c.symbol() == " "));
     }
 
+    #[test]
+    fn costs_by_model_groups_families() {
+        use crate::tabs::costs::CostsTab;
+        use ccboard_core::models::{ModelUsage, StatsCache};
+        use crossterm::event::KeyCode;
+
+        let mut stats = StatsCache::default();
+        for model in ["claude-sonnet-4-5-20250929", "claude-sonnet-4-5-20251001"] {
+            stats.model_usage.insert(
+                model.to_string(),
+                ModelUsage {
+                    input_tokens: 1_000,
+                    ..Default::default()
+                },
+            );
+        }
+
+        let mut tab = CostsTab::new();
+        tab.handle_key(KeyCode::Tab); // By Model
+        let render = |tab: &mut CostsTab| {
+            let mut terminal = make_terminal();
+            terminal
+                .draw(|frame| {
+                    tab.render(
+                        frame,
+                        frame.area(),
+                        Some(&stats),
+                        None,
+                        ColorScheme::default(),
+                        None,
+                    );
+                })
+                .expect("draw");
+            let buf = terminal.backend().buffer().clone();
+            buf.content().iter().map(|c| c.symbol()).collect::<String>()
+        };
+
+        let raw = render(&mut tab);
+        assert_eq!(raw.matches("Sonnet 4.5").count(), 2);
+
+        tab.handle_key(KeyCode::Char('f'));
+        let grouped = render(&mut tab);
+        assert!(grouped.contains("Model Family"));
+        assert_eq!(grouped.matches("Sonnet 4 ").count(), 1);
+        assert!(!grouped.contains("Sonnet 4.5"));
+    }
+
     // ─── History ──────────────────────────────────────────────────────────────
 
     #[test]
@@ -170,13 +218,73 @@ mod tab_smoke {
         let mut terminal = make_terminal();
         terminal
             .draw(|frame| {
-                tab.render(frame, frame.area(), &[], None, ColorScheme::default());
+                tab.render(
+                    frame,
+                    frame.area(),
+                    &[],
+                    None,
+                    &ccboard_core::ProjectDisplay::default(),
+                    &ccboard_types::Currency::default(),
+                    ColorScheme::default(),
+                );
             })
             .expect("draw");
         let buf = terminal.backend().buffer().clone();
         assert!(!buf.content().iter().all(|c| c.symbol() == " "));
     }
 
+    #[test]
+    fn history_compares_two_marked_sessions() {
+        use crate::tabs::history::HistoryTab;
+        use ccboard_core::models::session::{ProjectId, SessionMetadata};
+        use crossterm::event::KeyCode;
+        use std::sync::Arc;
+
+        let sessions: Vec<Arc<SessionMetadata>> = [("run-a", 1_000), ("run-b", 3_000)]
+            .into_iter()
+            .map(|(id, tokens)| {
+                let mut meta = SessionMetadata::from_path(
+                    format!("/{}.jsonl", id).into(),
+                    ProjectId::from("/code/api"),
+                );
+                meta.total_tokens = tokens;
+                Arc::new(meta)
+            })
+            .collect();
+        let mut history = std::collections::VecDeque::new();
+
+        let mut tab = HistoryTab::new();
+        tab.init(&sessions);
+        tab.handle_key(KeyCode::Char(' '), &sessions, &mut history);
+        tab.handle_key(KeyCode::Down, &sessions, &mut history);
+        tab.handle_key(KeyCode::Char(' '), &sessions, &mut history);
+        tab.handle_key(KeyCode::Char('D'), &sessions, &mut history);
+
+        let mut terminal = make_terminal();
+        terminal
+            .draw(|frame| {
+                tab.render(
+                    frame,
+                    frame.area(),
+                    &sessions,
+                    None,
+                    &ccboard_core::ProjectDisplay::default(),
+                    &ccboard_types::Currency::default(),
+                    ColorScheme::default(),
+                );
+            })
+            .expect("draw");
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(text.contains("Compare Sessions"));
+        assert!(text.contains("+200%"));
+    }
+
     // ─── MCP ──────────────────────────────────────────────────────────────────
 
     #[test]
@@ -211,6 +319,55 @@ mod tab_smoke {
         assert!(!buf.content().iter().all(|c| c.symbol() == " "));
     }
 
+    #[test]
+    fn analytics_patterns_lists_top_branches() {
+        use crate::tabs::analytics::AnalyticsTab;
+        use ccboard_core::analytics::{AnalyticsData, Period};
+        use ccboard_core::models::session::{ProjectId, SessionMetadata};
+        use std::sync::Arc;
+
+        let sessions: Vec<Arc<SessionMetadata>> = [("a", Some("feat/export")), ("b", None)]
+            .into_iter()
+            .map(|(id, branch)| {
+                let mut meta = SessionMetadata::from_path(
+                    format!("/{}.jsonl", id).into(),
+                    ProjectId::from("/code/api"),
+                );
+                meta.first_timestamp = Some(chrono::Utc::now());
+                meta.total_tokens = 10_000;
+                meta.branch = branch.map(str::to_string);
+                Arc::new(meta)
+            })
+            .collect();
+        let data = AnalyticsData::compute(&sessions, Period::last_30d());
+
+        let mut tab = AnalyticsTab::new();
+        tab.next_view();
+        tab.next_view();
+        let mut terminal = make_terminal();
+        terminal
+            .draw(|frame| {
+                tab.render(
+                    frame,
+                    frame.area(),
+                    Some(&data),
+                    None,
+                    ColorScheme::default(),
+                );
+            })
+            .expect("draw");
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(text.contains("Top Branches"));
+        assert!(text.contains("feat/export"));
+        assert!(text.contains("(no branch)"));
+    }
+
     // ─── Search ───────────────────────────────────────────────────────────────
 
     #[test]
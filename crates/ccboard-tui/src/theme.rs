@@ -7,6 +7,10 @@
 //! - ⚪ Gray: Unknown, Disabled, Neutral
 //! - 🔵 Cyan: Selected, Focus, Interactive
 //! - 🟣 Magenta: High value, Important
+//!
+//! `ColorScheme::ColorBlindSafe` swaps green/red for blue/orange. Render code
+//! takes status colors from [`Palette`] rather than `Color::Green`/`Color::Red`
+//! so every scheme applies everywhere.
 
 use ccboard_core::models::config::ColorScheme;
 use ratatui::style::Color;
@@ -69,10 +73,55 @@ impl Palette {
                 warning: Color::Rgb(180, 120, 0),
                 important: Color::Rgb(128, 0, 128),
             },
+            // Okabe-Ito hues: status reads blue (good) → yellow → orange (bad),
+            // never relying on red vs green
+            ColorScheme::ColorBlindSafe => Self {
+                bg: Color::Rgb(13, 17, 23),
+                surface: Color::Rgb(22, 27, 34),
+                fg: Color::Rgb(220, 220, 235),
+                muted: Color::Rgb(90, 95, 120),
+                border: Color::Rgb(48, 54, 72),
+                focus: Color::Rgb(86, 180, 233),      // Sky blue
+                success: Color::Rgb(60, 130, 220),    // Blue
+                error: Color::Rgb(230, 120, 20),      // Orange
+                warning: Color::Rgb(240, 210, 70),    // Yellow
+                important: Color::Rgb(204, 121, 167), // Reddish purple
+            },
         }
     }
 }
 
+impl Palette {
+    /// Palette colors as named RGB triples, for previews outside the TUI
+    /// (`ccboard theme list`)
+    pub fn swatches(&self) -> [(&'static str, (u8, u8, u8)); 10] {
+        [
+            ("bg", rgb(self.bg)),
+            ("surface", rgb(self.surface)),
+            ("fg", rgb(self.fg)),
+            ("muted", rgb(self.muted)),
+            ("border", rgb(self.border)),
+            ("focus", rgb(self.focus)),
+            ("success", rgb(self.success)),
+            ("warning", rgb(self.warning)),
+            ("error", rgb(self.error)),
+            ("important", rgb(self.important)),
+        ]
+    }
+}
+
+/// Approximate RGB for the named colors palettes use (xterm defaults)
+fn rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black => (0, 0, 0),
+        Color::White => (255, 255, 255),
+        Color::Gray => (192, 192, 192),
+        Color::DarkGray => (128, 128, 128),
+        _ => (128, 128, 128),
+    }
+}
+
 /// Status color palette following k9s/lazygit conventions
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StatusColor {
@@ -93,23 +142,14 @@ pub enum StatusColor {
 impl StatusColor {
     /// Convert to Ratatui Color based on color scheme
     pub fn to_color(self, scheme: ColorScheme) -> Color {
-        match scheme {
-            ColorScheme::Dark => match self {
-                StatusColor::Success => Color::Rgb(80, 200, 120),
-                StatusColor::Error => Color::Rgb(220, 80, 80),
-                StatusColor::Warning => Color::Rgb(220, 175, 60),
-                StatusColor::Neutral => Color::Rgb(90, 95, 120),
-                StatusColor::Focus => Color::Rgb(80, 190, 210),
-                StatusColor::Important => Color::Rgb(180, 100, 220),
-            },
-            ColorScheme::Light => match self {
-                StatusColor::Success => Color::Rgb(0, 128, 0), // Dark green
-                StatusColor::Error => Color::Rgb(200, 0, 0),   // Dark red
-                StatusColor::Warning => Color::Rgb(180, 120, 0), // Dark yellow/orange
-                StatusColor::Neutral => Color::Gray,
-                StatusColor::Focus => Color::Rgb(0, 128, 128), // Dark cyan
-                StatusColor::Important => Color::Rgb(128, 0, 128), // Dark magenta
-            },
+        let p = Palette::new(scheme);
+        match self {
+            StatusColor::Success => p.success,
+            StatusColor::Error => p.error,
+            StatusColor::Warning => p.warning,
+            StatusColor::Neutral => p.muted,
+            StatusColor::Focus => p.focus,
+            StatusColor::Important => p.important,
         }
     }
 }
@@ -327,7 +367,7 @@ impl FocusStyle {
     /// Background for focused item
     pub fn focused_bg(scheme: ColorScheme) -> Color {
         match scheme {
-            ColorScheme::Dark => Color::Rgb(30, 36, 55), // Dark navy highlight
+            ColorScheme::Dark | ColorScheme::ColorBlindSafe => Color::Rgb(30, 36, 55), // Dark navy highlight
             ColorScheme::Light => Color::Rgb(220, 220, 220),
         }
     }
@@ -339,26 +379,17 @@ pub struct BaseColors;
 impl BaseColors {
     /// Primary background color
     pub fn bg(scheme: ColorScheme) -> Color {
-        match scheme {
-            ColorScheme::Dark => Color::Rgb(13, 17, 23),
-            ColorScheme::Light => Color::White,
-        }
+        Palette::new(scheme).bg
     }
 
     /// Primary foreground/text color
     pub fn fg(scheme: ColorScheme) -> Color {
-        match scheme {
-            ColorScheme::Dark => Color::Rgb(220, 220, 235),
-            ColorScheme::Light => Color::Black,
-        }
+        Palette::new(scheme).fg
     }
 
     /// Muted/secondary text color
     pub fn muted(scheme: ColorScheme) -> Color {
-        match scheme {
-            ColorScheme::Dark => Color::Rgb(90, 95, 120),
-            ColorScheme::Light => Color::Gray,
-        }
+        Palette::new(scheme).muted
     }
 }
 
@@ -458,6 +489,29 @@ mod tests {
         assert_eq!(ServerStatusColor::Unknown.icon(), "?");
     }
 
+    #[test]
+    fn test_colorblind_safe_avoids_red_green() {
+        let p = Palette::new(ColorScheme::ColorBlindSafe);
+        // Good reads blue, bad reads orange
+        let Color::Rgb(r, g, b) = p.success else {
+            panic!("expected RGB success color");
+        };
+        assert!(b > r && b > g);
+        let Color::Rgb(r, g, b) = p.error else {
+            panic!("expected RGB error color");
+        };
+        assert!(r > g && g > b);
+
+        assert_eq!(
+            StatusColor::Error.to_color(ColorScheme::ColorBlindSafe),
+            p.error
+        );
+        assert_eq!(
+            StatusColor::Success.to_color(ColorScheme::Dark),
+            Palette::new(ColorScheme::Dark).success
+        );
+    }
+
     #[test]
     fn test_session_status_icons() {
         assert_eq!(SessionStatusColor::Active.icon(), "▶");
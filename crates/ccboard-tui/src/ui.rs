@@ -3,6 +3,7 @@
 use crate::app::{App, Tab};
 // Breadcrumbs removed — navigation is now shown in the header tab bar
 use crate::tabs::render_search_tab;
+use crate::tabs::sessions::sessions_by_project_with_pins;
 use crate::tabs::{
     ActivityTab, AgentsTab, AnalyticsTab, ConfigTab, ConversationTab, CostsTab, DashboardTab,
     HistoryTab, HooksTab, McpTab, PluginsTab, SessionsTab,
@@ -68,6 +69,31 @@ impl Ui {
         self.sessions.is_replay_open()
     }
 
+    /// Session selected in the active tab, if it lists sessions
+    pub fn selected_session(
+        &self,
+        app: &App,
+    ) -> Option<std::sync::Arc<ccboard_core::models::SessionMetadata>> {
+        match app.active_tab {
+            Tab::Sessions => self
+                .sessions
+                .selected_session_id(&sessions_by_project_with_pins(&app.store))
+                .and_then(|id| app.store.get_session(&id)),
+            Tab::History => self.history.get_selected_session().cloned(),
+            _ => None,
+        }
+    }
+
+    /// Check if the active tab has a focused text input that must receive all keys
+    pub fn is_text_input_focused(&self, app: &App) -> bool {
+        match app.active_tab {
+            Tab::Agents => self.agents.is_search_focused(),
+            Tab::Analytics => self.analytics.is_range_picker_open(),
+            Tab::Sessions => self.sessions.is_tag_editor_open(),
+            _ => false,
+        }
+    }
+
     /// Initialize tabs with pre-scanned directory data.
     ///
     /// The scan result must be produced by `scan_all_blocking` running inside
@@ -85,6 +111,11 @@ impl Ui {
         self.config.init(claude_home, project_path);
     }
 
+    /// Open the Analytics tab on `view`
+    pub fn set_analytics_view(&mut self, view: crate::tabs::analytics::AnalyticsView) {
+        self.analytics.set_view(view);
+    }
+
     /// Handle key input for the active tab
     pub fn handle_tab_key(&mut self, key: crossterm::event::KeyCode, app: &mut App) {
         use crossterm::event::KeyCode;
@@ -107,7 +138,29 @@ impl Ui {
                 // Dashboard has no interactive elements yet
             }
             Tab::Sessions => {
-                let sessions_by_project = app.store.sessions_by_project();
+                let sessions_by_project = sessions_by_project_with_pins(&app.store);
+
+                // Tag editor takes every key while open
+                if self.sessions.is_tag_editor_open() {
+                    if let Some((session_id, tags)) = self.sessions.handle_tag_key(key) {
+                        match app.store.set_tags(&session_id, &tags) {
+                            Ok(()) => self.sessions.set_notification("Tags saved"),
+                            Err(e) => self.sessions.set_notification(&format!("Tag error: {}", e)),
+                        }
+                    }
+                    return;
+                }
+
+                // 'T' — edit tags of the selected session
+                if key == KeyCode::Char('T') && !self.sessions.is_search_active() {
+                    if let Some(session_id) =
+                        self.sessions.selected_session_id(&sessions_by_project)
+                    {
+                        let tags = app.store.tags_for(&session_id);
+                        self.sessions.open_tag_editor(session_id, &tags);
+                        return;
+                    }
+                }
 
                 // Check if 'c' key pressed to open conversation
                 if let KeyCode::Char('c') = key {
@@ -135,7 +188,26 @@ impl Ui {
                     }
                 }
 
+                // 'p' — pin/unpin selected session
+                if key == KeyCode::Char('p') && !self.sessions.is_search_active() {
+                    if let Some(session_id) =
+                        self.sessions.selected_session_id(&sessions_by_project)
+                    {
+                        match app.store.toggle_pin(&session_id) {
+                            Ok(true) => self.sessions.set_notification("Pinned 📌"),
+                            Ok(false) => self.sessions.set_notification("Unpinned"),
+                            Err(e) => self.sessions.set_notification(&format!("Pin error: {}", e)),
+                        }
+                        return;
+                    }
+                }
+
                 self.sessions.handle_key(key, &sessions_by_project);
+                if let Some(session_id) = self.sessions.take_resumed_session() {
+                    if let Err(e) = app.store.record_resume(&session_id) {
+                        tracing::warn!(error = %e, "Failed to record session resume");
+                    }
+                }
             }
             Tab::Config => {
                 self.config.handle_key(key);
@@ -151,7 +223,13 @@ impl Ui {
                 self.hooks.handle_key(key, &hooks_map);
             }
             Tab::Agents => {
-                self.agents.handle_key(key);
+                if key == KeyCode::Char('p') && !self.agents.is_search_focused() {
+                    // Scope invocation counts to the project selected in the Sessions tab
+                    let project = self.sessions.selected_project().map(str::to_string);
+                    self.agents.toggle_project_scope(project);
+                } else {
+                    self.agents.handle_key(key);
+                }
             }
             Tab::Costs => {
                 self.costs.handle_key(key);
@@ -168,6 +246,15 @@ impl Ui {
             Tab::Analytics => {
                 use ccboard_core::analytics::Period;
                 use crossterm::event::KeyCode;
+                if self.analytics.is_range_picker_open() {
+                    if let Some(period) = self.analytics.handle_range_key(key) {
+                        let store = app.store.clone();
+                        tokio::spawn(async move {
+                            store.compute_analytics(period).await;
+                        });
+                    }
+                    return;
+                }
                 match key {
                     KeyCode::F(1) => {
                         self.analytics.set_period(Period::last_7d());
@@ -197,6 +284,15 @@ impl Ui {
                             store.compute_analytics(Period::available()).await;
                         });
                     }
+                    KeyCode::F(5) => {
+                        let period = self.analytics.previous_month_period();
+                        self.analytics.set_period(period);
+                        let store = app.store.clone();
+                        tokio::spawn(async move {
+                            store.compute_analytics(period).await;
+                        });
+                    }
+                    KeyCode::Char('c') => self.analytics.open_range_picker(),
                     KeyCode::Right | KeyCode::Char('l') => self.analytics.next_view(),
                     KeyCode::Left | KeyCode::Char('h') => self.analytics.prev_view(),
                     KeyCode::Char('j') | KeyCode::Down => {
@@ -215,6 +311,16 @@ impl Ui {
                         self.analytics.scroll_down(max_items);
                     }
                     KeyCode::Char('k') | KeyCode::Up => self.analytics.scroll_up(),
+                    KeyCode::Char(c @ ('d' | 'w' | 'm')) => {
+                        use crate::tabs::analytics::{AnalyticsView, TrendGranularity};
+                        if self.analytics.current_view() == AnalyticsView::Trends {
+                            self.analytics.set_trend_granularity(match c {
+                                'w' => TrendGranularity::Weekly,
+                                'm' => TrendGranularity::Monthly,
+                                _ => TrendGranularity::Daily,
+                            });
+                        }
+                    }
                     KeyCode::Char('s') => {
                         // Cycle sort column for project leaderboard
                         self.analytics.cycle_sort_column();
@@ -323,6 +429,12 @@ impl Ui {
             return;
         }
 
+        // Data changed since the last frame: drop per-tab derived data
+        if std::mem::take(&mut app.needs_refresh) {
+            self.dashboard.invalidate_cache();
+            self.costs.invalidate_cache();
+        }
+
         // Fill entire frame with theme background (critical for light mode)
         let p = Palette::new(app.color_scheme);
         frame.render_widget(Clear, size);
@@ -360,6 +472,7 @@ impl Ui {
 
         // Render command palette (overlay on top of everything)
         app.command_palette.render(frame, size, app.color_scheme);
+        app.export_prompt.render(frame, size, app.color_scheme);
 
         // Render help modal (overlay on top of command palette)
         app.help_modal.render(
@@ -483,7 +596,8 @@ impl Ui {
 
         // Tabs — all show their full name, active gets highlight background
         let active_bg = match app.color_scheme {
-            ccboard_core::models::config::ColorScheme::Dark => Color::Rgb(20, 40, 55),
+            ccboard_core::models::config::ColorScheme::Dark
+            | ccboard_core::models::config::ColorScheme::ColorBlindSafe => Color::Rgb(20, 40, 55),
             ccboard_core::models::config::ColorScheme::Light => Color::Rgb(200, 220, 240),
         };
         let titles: Vec<Line> = Tab::all()
@@ -604,10 +718,10 @@ impl Ui {
                 );
             }
             Tab::Sessions => {
-                let sessions_by_project = app.store.sessions_by_project();
+                let sessions_by_project = sessions_by_project_with_pins(&app.store);
                 let live_sessions = app.live_sessions(); // Use cached live sessions
                                                          // Count total sessions for refresh tracking
-                let session_count: usize = sessions_by_project.values().map(|v| v.len()).sum();
+                let session_count = app.store.session_count();
                 self.sessions.mark_refreshed(session_count);
                 self.sessions.render(
                     frame,
@@ -647,8 +761,17 @@ impl Ui {
             Tab::History => {
                 let sessions: Vec<_> = app.store.recent_sessions(10000);
                 let stats = app.store.stats();
-                self.history
-                    .render(frame, area, &sessions, stats.as_ref(), scheme);
+                let project_display = app.store.project_display();
+                let currency = app.store.currency();
+                self.history.render(
+                    frame,
+                    area,
+                    &sessions,
+                    stats.as_ref(),
+                    &project_display,
+                    &currency,
+                    scheme,
+                );
             }
             Tab::Mcp => {
                 let mcp_config = app.store.mcp_config();
@@ -703,10 +826,10 @@ impl Ui {
                 Tab::Hooks => "←→ nav │ ↑↓ select │ t test │ e edit │ o reveal",
                 Tab::Agents => "Tab switch │ Enter detail",
                 Tab::Costs => "Tab/←→/h/l switch views",
-                Tab::History => "/ search │ gg/G/Home/End jump │ c clear │ x export",
+                Tab::History => "/ search │ gg/G/Home/End jump │ c clear │ x export │ Y copy path",
                 Tab::Mcp => "←→ focus │ ↑↓ select │ s stats │ e edit │ o reveal │ r refresh",
                 Tab::Analytics => {
-                    "F1-F4 period │ ←→/h/l switch views │ j/k scroll │ s sort │ o order │ r refresh"
+                    "F1-F4 period │ F5 month │ c custom range │ ←→/h/l switch views │ j/k scroll │ s sort │ o order │ r refresh"
                 }
                 Tab::Plugins => "Tab cycle columns │ j/k navigate │ s sort │ r refresh",
                 Tab::Activity => "j/k navigate │ a analyze session │ Tab/Shift+Tab switch tabs",
@@ -39,17 +39,17 @@ impl ToolCallNode {
     }
 
     /// Get status indicator and color
-    fn status(&self) -> (&'static str, Color) {
+    fn status(&self, p: &Palette) -> (&'static str, Color) {
         match &self.tool_result {
-            Some(result) if result.is_error => ("❌", Color::Red),
-            Some(_) => ("✅", Color::Green),
-            None => ("⏳", Color::Yellow),
+            Some(result) if result.is_error => ("❌", p.error),
+            Some(_) => ("✅", p.success),
+            None => ("⏳", p.warning),
         }
     }
 
     /// Render the tool call node
     pub fn render(&self, frame: &mut Frame, area: Rect, p: &Palette) {
-        let (status_icon, status_color) = self.status();
+        let (status_icon, status_color) = self.status(p);
 
         // Header line: status + tool name + expand indicator
         let expand_indicator = if self.expanded { "▼" } else { "▶" };
@@ -125,11 +125,7 @@ impl ToolCallNode {
                 };
 
                 let result_text = format!("{}\n{}", result_header, result.content);
-                let result_color = if result.is_error {
-                    Color::Red
-                } else {
-                    Color::Green
-                };
+                let result_color = if result.is_error { p.error } else { p.success };
 
                 let result_widget = Paragraph::new(result_text)
                     .wrap(Wrap { trim: false })
@@ -3,11 +3,13 @@
 //! Uses Z-score based statistical analysis to flag sessions that deviate
 //! significantly from normal behavior patterns.
 
+use crate::currency::Currency;
 use crate::models::session::SessionMetadata;
+use serde::Serialize;
 use std::sync::Arc;
 
 /// Severity level for anomalies based on standard deviations
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum AnomalySeverity {
     /// Critical: >3 standard deviations from mean
     Critical,
@@ -34,7 +36,7 @@ impl AnomalySeverity {
 }
 
 /// Metric type for anomaly detection
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum AnomalyMetric {
     /// Total token usage
     Tokens,
@@ -53,7 +55,7 @@ impl AnomalyMetric {
 }
 
 /// Detected anomaly with context
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Anomaly {
     /// Session ID
     pub session_id: String,
@@ -73,10 +75,10 @@ pub struct Anomaly {
 
 impl Anomaly {
     /// Format value based on metric type
-    pub fn format_value(&self) -> String {
+    pub fn format_value(&self, currency: &Currency) -> String {
         match self.metric {
             AnomalyMetric::Tokens => format!("{:.0}", self.value),
-            AnomalyMetric::Cost => format!("${:.2}", self.value),
+            AnomalyMetric::Cost => currency.format_cost(self.value),
         }
     }
 
@@ -4,9 +4,10 @@
 //! with R² confidence metric to assess prediction reliability.
 
 use super::trends::TrendsData;
+use serde::Serialize;
 
 /// Forecast data with predictions
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ForecastData {
     /// Predicted tokens for next 30 days
     pub next_30_days_tokens: u64,
@@ -23,7 +24,7 @@ pub struct ForecastData {
 }
 
 /// Trend direction with percentage change
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum TrendDirection {
     /// Increasing trend (percentage)
     Up(f64),
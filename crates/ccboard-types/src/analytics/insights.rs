@@ -5,9 +5,11 @@
 use super::forecasting::{ForecastData, TrendDirection};
 use super::patterns::UsagePatterns;
 use super::trends::TrendsData;
+use crate::currency::Currency;
+use serde::Serialize;
 
 /// Alert types for budget and anomaly detection
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum Alert {
     /// Budget warning (current cost approaching budget)
     BudgetWarning { current: f64, budget: f64, pct: f64 },
@@ -37,6 +39,7 @@ pub fn generate_insights(
     _trends: &TrendsData,
     patterns: &UsagePatterns,
     forecast: &ForecastData,
+    currency: &Currency,
 ) -> Vec<String> {
     let mut insights = Vec::new();
 
@@ -89,9 +92,9 @@ pub fn generate_insights(
     if let TrendDirection::Up(pct) = forecast.trend_direction {
         if pct > 20.0 && forecast.confidence > 0.5 {
             insights.push(format!(
-                "Cost trend: +{:.0}% over period. Monthly estimate: ${:.2} (confidence: {:.0}%).",
+                "Cost trend: +{:.0}% over period. Monthly estimate: {} (confidence: {:.0}%).",
                 pct,
-                forecast.monthly_cost_estimate,
+                currency.format_cost(forecast.monthly_cost_estimate),
                 forecast.confidence * 100.0
             ));
         }
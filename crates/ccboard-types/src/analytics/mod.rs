@@ -4,8 +4,10 @@
 //! and actionable insights to optimize costs and productivity.
 
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::sync::Arc;
 
+use crate::currency::Currency;
 use crate::models::session::SessionMetadata;
 
 pub mod anomalies;
@@ -24,7 +26,8 @@ pub use patterns::{detect_patterns, UsagePatterns};
 pub use trends::{compute_trends, SessionDurationStats, TrendsData};
 
 /// Period selection for analytics computation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Period {
     /// Last N days from now
     Days(usize),
@@ -71,7 +74,7 @@ impl Period {
 }
 
 /// Complete analytics data for a period
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AnalyticsData {
     /// Time series trends
     pub trends: TrendsData,
@@ -99,7 +102,7 @@ impl AnalyticsData {
         let trends = compute_trends(sessions, period.days());
         let forecast = forecast_usage(&trends);
         let patterns = detect_patterns(sessions, period.days());
-        let insights = generate_insights(&trends, &patterns, &forecast);
+        let insights = generate_insights(&trends, &patterns, &forecast, &Currency::default());
 
         Self {
             trends,
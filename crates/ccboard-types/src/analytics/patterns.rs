@@ -4,6 +4,7 @@
 //! model distribution, and session duration analytics.
 
 use chrono::{Datelike, Timelike, Weekday};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -11,7 +12,7 @@ use std::time::Duration;
 use crate::models::session::SessionMetadata;
 
 /// Usage patterns
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UsagePatterns {
     /// Most productive hour (0-23)
     pub most_productive_hour: u8,
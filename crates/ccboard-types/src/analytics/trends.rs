@@ -3,13 +3,14 @@
 //! Aggregates session data by day, hour, and weekday to identify usage patterns over time.
 
 use chrono::{Datelike, Local, Timelike};
+use serde::Serialize;
 use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use crate::models::session::SessionMetadata;
 
 /// Session duration statistics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SessionDurationStats {
     /// Average duration in seconds
     pub avg_duration_secs: f64,
@@ -40,7 +41,7 @@ impl SessionDurationStats {
 }
 
 /// Time series trends data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TrendsData {
     /// Dates in "YYYY-MM-DD" format (sorted chronologically)
     pub dates: Vec<String>,
@@ -0,0 +1,94 @@
+//! Display currency for costs
+//!
+//! Costs are computed in USD everywhere. The `currency` setting
+//! (`{ "symbol": "€", "rate": 0.92 }`) only changes how they are shown:
+//! [`Currency::format_cost`] multiplies by the rate and prefixes the symbol.
+//!
+//! There is no global currency: the backend reads it from the store's
+//! settings (`DataStore::currency`), the web frontend provides the value
+//! fetched from `/api/currency` as a context signal.
+
+use serde::{Deserialize, Serialize};
+
+/// Display currency: symbol and conversion rate from USD
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Currency {
+    /// Prefix shown before amounts
+    #[serde(default = "default_symbol")]
+    pub symbol: String,
+    /// Units of this currency per USD
+    #[serde(default = "default_rate")]
+    pub rate: f64,
+}
+
+fn default_symbol() -> String {
+    "$".to_string()
+}
+
+fn default_rate() -> f64 {
+    1.0
+}
+
+impl Default for Currency {
+    fn default() -> Self {
+        Self {
+            symbol: default_symbol(),
+            rate: default_rate(),
+        }
+    }
+}
+
+impl Currency {
+    /// Convert a USD amount to this currency
+    ///
+    /// A non-positive or non-finite rate is ignored (amounts stay in USD).
+    pub fn convert(&self, usd: f64) -> f64 {
+        if self.rate.is_finite() && self.rate > 0.0 {
+            usd * self.rate
+        } else {
+            usd
+        }
+    }
+
+    /// `usd` converted and formatted with `decimals` places, e.g. `€1.84`
+    pub fn format(&self, usd: f64, decimals: usize) -> String {
+        let amount = self.convert(usd);
+        if amount < 0.0 {
+            format!("-{}{:.*}", self.symbol, decimals, -amount)
+        } else {
+            format!("{}{:.*}", self.symbol, decimals, amount)
+        }
+    }
+
+    /// `usd` converted and formatted with 2 decimals
+    pub fn format_cost(&self, usd: f64) -> String {
+        self.format(usd, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_format() {
+        let usd = Currency::default();
+        assert_eq!(usd.format(1.234, 2), "$1.23");
+        assert_eq!(usd.format(-0.5, 2), "-$0.50");
+        assert_eq!(usd.format_cost(12.345), "$12.35");
+
+        let eur: Currency = serde_json::from_str(r#"{"symbol": "€", "rate": 0.9}"#).unwrap();
+        assert_eq!(eur.format(2.0, 2), "€1.80");
+        assert_eq!(eur.format(1.0, 4), "€0.9000");
+
+        // Missing fields fall back to USD defaults; a bad rate is ignored
+        let partial: Currency = serde_json::from_str(r#"{"symbol": "CHF "}"#).unwrap();
+        assert_eq!(partial.format(3.0, 0), "CHF 3");
+        let broken = Currency {
+            rate: 0.0,
+            ..Currency::default()
+        };
+        assert_eq!(broken.format(3.0, 2), "$3.00");
+    }
+}
@@ -9,6 +9,7 @@
 //! - ccboard-tui (terminal UI)
 
 pub mod analytics;
+pub mod currency;
 pub mod models;
 
 // Re-export analytics types
@@ -17,9 +18,11 @@ pub use analytics::{
     SessionDurationStats, TrendDirection, TrendsData, UsagePatterns,
 };
 
+pub use currency::Currency;
+
 // Re-export model types
 pub use models::{
-    BillingBlock, BillingBlockUsage, ContextWindowStats, DailyActivity, HookDefinition, HookGroup,
-    InvocationStats, MergedConfig, ModelUsage, Permissions, SessionLine, SessionMessage,
-    SessionMetadata, SessionSummary, Settings, StatsCache,
+    cache_hit_ratio, BillingBlock, BillingBlockUsage, ContextWindowStats, DailyActivity,
+    HookDefinition, HookGroup, InvocationStats, MergedConfig, ModelUsage, Permissions, SessionLine,
+    SessionMessage, SessionMetadata, SessionSummary, Settings, StatsCache,
 };
@@ -9,5 +9,5 @@ pub mod stats;
 pub use billing_block::{BillingBlock, BillingBlockManager, BillingBlockUsage};
 pub use config::{HookDefinition, HookGroup, MergedConfig, Permissions, Settings};
 pub use invocations::InvocationStats;
-pub use session::{SessionLine, SessionMessage, SessionMetadata, SessionSummary};
+pub use session::{cache_hit_ratio, SessionLine, SessionMessage, SessionMetadata, SessionSummary};
 pub use stats::{ContextWindowStats, DailyActivity, ModelUsage, StatsCache};
@@ -109,6 +109,26 @@ impl TokenUsage {
     pub fn total(&self) -> u64 {
         self.input_tokens + self.output_tokens + self.cache_read_tokens + self.cache_write_tokens
     }
+
+    /// Prompt cache hit ratio of this usage (see [`cache_hit_ratio`])
+    pub fn cache_hit_ratio(&self) -> Option<f64> {
+        cache_hit_ratio(
+            self.input_tokens,
+            self.cache_read_tokens,
+            self.cache_write_tokens,
+        )
+    }
+}
+
+/// Share of prompt tokens served from the prompt cache, 0.0-1.0
+///
+/// `cache_read / (input + cache_read + cache_write)`: cache writes are prompt
+/// tokens the cache missed, so they count against the ratio. Every cache hit
+/// ratio shown (trends, anomalies, insights) uses this definition. `None` when
+/// there are no prompt tokens.
+pub fn cache_hit_ratio(input: u64, cache_read: u64, cache_write: u64) -> Option<f64> {
+    let prompt = input + cache_read + cache_write;
+    (prompt > 0).then(|| cache_read as f64 / prompt as f64)
 }
 
 /// Summary at session end
@@ -345,4 +365,19 @@ mod token_tests {
         let total = usage.total();
         assert_eq!(total, 83386, "Total should be 10+1+19275+64100 = 83386");
     }
+
+    #[test]
+    fn test_cache_hit_ratio_counts_cache_writes() {
+        // Writes are prompt tokens the cache missed
+        assert_eq!(cache_hit_ratio(200, 750, 50), Some(0.75));
+        assert_eq!(cache_hit_ratio(0, 0, 0), None);
+
+        let usage = TokenUsage {
+            input_tokens: 10,
+            output_tokens: 500,
+            cache_read_tokens: 30,
+            cache_write_tokens: 60,
+        };
+        assert_eq!(usage.cache_hit_ratio(), Some(0.3));
+    }
 }
@@ -39,6 +39,8 @@ pub struct StatsData {
     #[serde(default)]
     pub projects_by_cost: Vec<ProjectCost>,
     #[serde(default)]
+    pub project_sparklines: Vec<ProjectSparkline>,
+    #[serde(default)]
     pub most_used_model: Option<MostUsedModel>,
     #[serde(default)]
     pub this_month_cost: f64,
@@ -60,6 +62,15 @@ pub struct ProjectCost {
     pub percentage: f64,
 }
 
+/// Daily tokens of one of the busiest projects, oldest day first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSparkline {
+    pub project: String,
+    #[serde(default)]
+    pub daily_tokens: Vec<u64>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MostUsedModel {
@@ -190,6 +201,9 @@ pub struct SessionData {
     pub model: String,
     pub messages: u64,
     pub tokens: u64,
+    /// `tokens` includes estimates for messages without usage data (`tokenSource: hybrid`)
+    #[serde(default)]
+    pub tokens_estimated: bool,
     pub input_tokens: u64,
     pub output_tokens: u64,
     pub cache_creation_tokens: u64,
@@ -203,6 +217,21 @@ pub struct SessionData {
     pub lines_added: u64,
     #[serde(default)]
     pub lines_removed: u64,
+    /// Cost from the pricing table (split across models for multi-model sessions)
+    #[serde(default)]
+    pub estimated_cost: Option<f64>,
+    /// `None` for sessions without messages
+    #[serde(default)]
+    pub cost_per_message: Option<f64>,
+    /// `None` for sessions without tokens
+    #[serde(default)]
+    pub cost_per_token: Option<f64>,
+    /// Several models answered, so `estimated_cost` is an approximation
+    #[serde(default)]
+    pub cost_approximate: bool,
+    /// User tags (`~/.ccboard/tags.json`)
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 /// Recent sessions response from API
@@ -212,6 +241,57 @@ pub struct RecentSessionsResponse {
     pub total: u64,
 }
 
+/// Pinned sessions response from API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinnedSessionsResponse {
+    pub sessions: Vec<SessionData>,
+}
+
+/// Tokens and cost per token kind, from `/api/sessions/{id}`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct TokenCostsData {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_write_tokens: u64,
+    pub input_cost: f64,
+    pub output_cost: f64,
+    pub cache_read_cost: f64,
+    pub cache_write_cost: f64,
+    pub total_cost: f64,
+}
+
+/// One model's part of a session's cost
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ModelCostData {
+    pub model: String,
+    /// Share of assistant messages (1.0 if alone)
+    pub share: f64,
+    #[serde(flatten)]
+    pub costs: TokenCostsData,
+}
+
+/// Session cost per token kind, overall and per model
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CostBreakdownData {
+    pub total: TokenCostsData,
+    pub models: Vec<ModelCostData>,
+    /// Tokens were split across models by message share
+    pub approximate: bool,
+}
+
+/// `/api/sessions/{id}` fields not already in [`SessionData`]
+#[derive(Debug, Clone, Deserialize)]
+struct SessionDetailResponse {
+    cost_breakdown: CostBreakdownData,
+}
+
+/// Result of toggling a pin
+#[derive(Debug, Clone, Deserialize)]
+struct PinResponse {
+    pinned: bool,
+}
+
 /// Fetch stats from API
 pub async fn fetch_stats() -> Result<StatsData, String> {
     let url = format!("{}/api/stats", API_BASE_URL);
@@ -232,6 +312,24 @@ pub async fn fetch_stats() -> Result<StatsData, String> {
     Ok(stats)
 }
 
+/// Fetch the display currency (symbol and rate from USD)
+pub async fn fetch_currency() -> Result<ccboard_types::Currency, String> {
+    let url = format!("{}/api/currency", API_BASE_URL);
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.ok() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
+
+    response
+        .json::<ccboard_types::Currency>()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))
+}
+
 /// Fetch recent sessions from API (for dashboard)
 pub async fn fetch_recent_sessions(limit: u32) -> Result<RecentSessionsResponse, String> {
     let url = format!("{}/api/sessions/recent?limit={}", API_BASE_URL, limit);
@@ -252,6 +350,114 @@ pub async fn fetch_recent_sessions(limit: u32) -> Result<RecentSessionsResponse,
     Ok(sessions)
 }
 
+/// Error body returned by action endpoints
+#[derive(Debug, Clone, Deserialize)]
+struct ActionError {
+    error: String,
+    /// Command to run by hand, sent when a resume fails
+    #[serde(default)]
+    command: Option<String>,
+}
+
+/// Why a resume request failed
+#[derive(Debug, Clone)]
+pub struct ResumeFailure {
+    pub error: String,
+    /// `claude --resume <id>` to run in a terminal instead, when known
+    pub command: Option<String>,
+}
+
+/// Ask the server to resume a session in the Claude CLI
+///
+/// Only works when the server was started with `--allow-resume`; the server's
+/// error message, and the command to run by hand, are returned otherwise.
+pub async fn resume_session(id: &str) -> Result<(), ResumeFailure> {
+    let url = format!("{}/api/sessions/{}/resume", API_BASE_URL, id);
+    let response = Request::post(&url)
+        .send()
+        .await
+        .map_err(|e| ResumeFailure {
+            error: format!("Network error: {}", e),
+            command: None,
+        })?;
+
+    if !response.ok() {
+        let status = response.status();
+        return Err(match response.json::<ActionError>().await {
+            Ok(body) => ResumeFailure {
+                error: body.error,
+                command: body.command,
+            },
+            Err(_) => ResumeFailure {
+                error: format!("HTTP error: {}", status),
+                command: None,
+            },
+        });
+    }
+
+    Ok(())
+}
+
+/// Fetch a session's cost breakdown
+pub async fn fetch_cost_breakdown(id: &str) -> Result<CostBreakdownData, String> {
+    let url = format!("{}/api/sessions/{}", API_BASE_URL, id);
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.ok() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
+
+    response
+        .json::<SessionDetailResponse>()
+        .await
+        .map(|body| body.cost_breakdown)
+        .map_err(|e| format!("Parse error: {}", e))
+}
+
+/// Fetch pinned sessions (for the sidebar)
+pub async fn fetch_pinned_sessions() -> Result<PinnedSessionsResponse, String> {
+    let url = format!("{}/api/sessions/pinned", API_BASE_URL);
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.ok() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
+
+    response
+        .json::<PinnedSessionsResponse>()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))
+}
+
+/// Pin or unpin a session, returns whether it is now pinned
+pub async fn toggle_pin(id: &str) -> Result<bool, String> {
+    let url = format!("{}/api/sessions/{}/pin", API_BASE_URL, id);
+    let response = Request::post(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.ok() {
+        let status = response.status();
+        return Err(match response.json::<ActionError>().await {
+            Ok(body) => body.error,
+            Err(_) => format!("HTTP error: {}", status),
+        });
+    }
+
+    response
+        .json::<PinResponse>()
+        .await
+        .map(|body| body.pinned)
+        .map_err(|e| format!("Parse error: {}", e))
+}
+
 /// Fetch quota status from API
 pub async fn fetch_quota() -> Result<QuotaData, String> {
     let url = format!("{}/api/quota", API_BASE_URL);
@@ -284,8 +490,3 @@ pub fn format_number(n: u64) -> String {
         n.to_string()
     }
 }
-
-/// Format cost as USD
-pub fn format_cost(cost: f64) -> String {
-    format!("${:.2}", cost)
-}
@@ -6,7 +6,10 @@ use leptos_router::{
     path,
 };
 
-use crate::components::{Header, Sidebar, ToastProvider};
+use crate::components::{provide_pinned_refresh, Header, Sidebar, ToastProvider};
+use crate::currency::provide_currency;
+use crate::sse_hook::provide_sse;
+use crate::theme::provide_theme;
 // Eager load Dashboard (initial page)
 use crate::pages::Dashboard;
 // Lazy load Sessions, Analytics, Config, History (defer to route closure)
@@ -17,6 +20,15 @@ pub fn App() -> impl IntoView {
     // Mobile sidebar state
     let (sidebar_open, set_sidebar_open) = signal(false);
 
+    // Single live-update stream shared by the header badge and all pages
+    provide_sse();
+    // Dark/Light/System theme, restored from localStorage
+    provide_theme();
+    // Bumped when a session is pinned or unpinned so the sidebar refetches
+    provide_pinned_refresh();
+    // Costs are formatted in the currency configured in settings
+    provide_currency();
+
     view! {
         <ToastProvider>
             <Router>
@@ -72,6 +84,12 @@ pub fn App() -> impl IntoView {
                                         view! { <crate::pages::Costs /> }
                                     }
                                 />
+                                <Route
+                                    path=path!("/compare")
+                                    view=|| {
+                                        view! { <crate::pages::ComparisonPage /> }
+                                    }
+                                />
                                 <Route
                                     path=path!("/history")
                                     view=|| {
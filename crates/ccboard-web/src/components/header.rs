@@ -2,12 +2,24 @@
 
 use leptos::prelude::*;
 
-/// Header with logo, subtitle, and mobile hamburger menu
+use crate::sse_hook::{use_sse_status, ConnectionStatus};
+use crate::theme::use_theme;
+
+/// Header with logo, subtitle, theme toggle, live connection badge, and mobile hamburger menu
 #[component]
 pub fn Header(
     sidebar_open: ReadSignal<bool>,
     set_sidebar_open: WriteSignal<bool>,
 ) -> impl IntoView {
+    let status = use_sse_status();
+    let badge = move || match status.get() {
+        ConnectionStatus::Connected => ("connection-badge connected", "● Live"),
+        ConnectionStatus::Connecting => ("connection-badge connecting", "○ Connecting…"),
+        ConnectionStatus::Reconnecting => ("connection-badge offline", "◐ Reconnecting…"),
+        ConnectionStatus::Error => ("connection-badge offline", "✕ Offline"),
+    };
+    let theme = use_theme();
+
     view! {
         <header class="header">
             <button
@@ -23,6 +35,23 @@ pub fn Header(
                 <h1 class="logo">"ccboard"</h1>
                 <p class="subtitle">"Claude Code Dashboard"</p>
             </div>
+
+            <span
+                class=move || badge().0
+                title="Live update stream status"
+                role="status"
+            >
+                {move || badge().1}
+            </span>
+
+            <button
+                class="theme-toggle"
+                on:click=move |_| theme.cycle()
+                title=move || format!("Theme: {} (click to change)", theme.mode().label())
+                aria-label="Toggle color theme"
+            >
+                {move || theme.mode().icon()}
+            </button>
         </header>
     }
 }
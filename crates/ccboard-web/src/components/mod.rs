@@ -7,6 +7,7 @@ mod forecast_chart;
 mod header;
 mod projects_breakdown;
 mod search_bar;
+mod session_chains;
 mod session_detail_modal;
 mod session_table;
 mod sidebar;
@@ -22,9 +23,10 @@ pub use forecast_chart::ForecastChart;
 pub use header::Header;
 pub use projects_breakdown::ProjectsBreakdown;
 pub use search_bar::SearchBar;
+pub use session_chains::SessionChains;
 pub use session_detail_modal::SessionDetailModal;
 pub use session_table::{SessionTable, SortColumn, SortDirection};
-pub use sidebar::Sidebar;
+pub use sidebar::{provide_pinned_refresh, PinnedRefresh, Sidebar};
 pub use sparkline::Sparkline;
 pub use stats_card::{CardColor, StatsCard};
 pub use task_graph::TaskDependencyGraph;
@@ -1,6 +1,7 @@
 //! Projects breakdown horizontal bar chart
 
-use crate::api::{format_cost, ProjectCost};
+use crate::api::ProjectCost;
+use crate::currency::use_currency;
 use leptos::prelude::*;
 
 /// Projects breakdown component
@@ -9,6 +10,8 @@ pub fn ProjectsBreakdown(
     /// Top 5 projects by cost
     projects: Vec<ProjectCost>,
 ) -> impl IntoView {
+    let currency = use_currency();
+
     // Color palette for bars (cycling through accent colors)
     let colors = [
         "--accent-primary",
@@ -38,13 +41,14 @@ pub fn ProjectsBreakdown(
                                 } else {
                                     project.project.split('/').next_back().unwrap_or(&project.project).to_string()
                                 };
+                                let cost = project.cost;
 
                                 view! {
                                     <div class="project-item">
                                         <div class="project-header">
                                             <span class="project-name">{project_name}</span>
                                             <span class="project-cost">
-                                                {format_cost(project.cost)}
+                                                {move || currency.format_cost(cost)}
                                                 " ("{format!("{:.1}%", project.percentage)}")"
                                             </span>
                                         </div>
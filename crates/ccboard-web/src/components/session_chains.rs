@@ -0,0 +1,149 @@
+//! Session continuation chains: how a task evolved across session files
+
+use gloo_net::http::Request;
+use leptos::prelude::*;
+use serde::Deserialize;
+
+/// Chains as returned by `/api/session-chains`
+#[derive(Debug, Clone, Deserialize)]
+struct SessionChainsData {
+    chains: Vec<ChainData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ChainData {
+    project: String,
+    last_timestamp: Option<String>,
+    /// Whether any link was guessed from timing
+    inferred: bool,
+    /// Depth-first order, root first
+    nodes: Vec<ChainNodeData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ChainNodeData {
+    session_id: String,
+    /// "resumed", "inferred", or absent for the root
+    link: Option<String>,
+    depth: usize,
+    started: Option<String>,
+    messages: u64,
+    preview: Option<String>,
+}
+
+async fn fetch_session_chains() -> Result<SessionChainsData, String> {
+    let response = Request::get("/api/session-chains")
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.ok() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
+
+    response
+        .json::<SessionChainsData>()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))
+}
+
+/// `2026-03-02T09:00:00+00:00` → `2026-03-02 09:00`
+fn short_timestamp(ts: &str) -> String {
+    ts.get(..16).unwrap_or(ts).replace('T', " ")
+}
+
+fn render_node(node: ChainNodeData) -> impl IntoView {
+    let (marker, link_class, link_label) = match node.link.as_deref() {
+        Some("inferred") => ("└┄ ", "chain-link chain-link-inferred", "inferred"),
+        Some(_) => ("└─ ", "chain-link chain-link-resumed", "resumed"),
+        None => ("", "chain-link", ""),
+    };
+    let indent = format!("padding-left: {}rem", node.depth as f32 * 1.5);
+    let short_id = node.session_id.chars().take(8).collect::<String>();
+    let started = node
+        .started
+        .as_deref()
+        .map(short_timestamp)
+        .unwrap_or_default();
+    let preview = node
+        .preview
+        .map(|p| p.chars().take(80).collect::<String>())
+        .unwrap_or_default();
+
+    view! {
+        <li class="chain-node" style=indent title=node.session_id.clone()>
+            <span class="chain-marker">{marker}</span>
+            <code class="chain-session-id">{short_id}</code>
+            {(!link_label.is_empty()).then(|| view! { <span class=link_class>{link_label}</span> })}
+            <span class="chain-meta">{started} " · " {node.messages} " msgs"</span>
+            <span class="chain-preview">{preview}</span>
+        </li>
+    }
+}
+
+/// Tree of sessions that resume or follow one another, per project
+#[component]
+pub fn SessionChains() -> impl IntoView {
+    let chains = LocalResource::new(|| async move { fetch_session_chains().await });
+
+    view! {
+        <div class="session-chains">
+            <div class="page-header">
+                <h2>"Session Chains"</h2>
+                <p class="subtitle">
+                    "Sessions that resume one another. Dashed links are inferred: same project, started within 30 minutes of the previous session ending."
+                </p>
+            </div>
+
+            <Suspense fallback=move || view! { <div class="loading">"Loading session chains..."</div> }>
+                {move || match chains.get().as_ref().map(|r| r.as_ref()) {
+                    Some(Ok(data)) if data.chains.is_empty() => {
+                        view! { <p class="empty-state">"No session chains found."</p> }.into_any()
+                    }
+                    Some(Ok(data)) => {
+                        let items = data
+                            .chains
+                            .clone()
+                            .into_iter()
+                            .map(|chain| {
+                                let count = chain.nodes.len();
+                                let last = chain
+                                    .last_timestamp
+                                    .as_deref()
+                                    .map(short_timestamp)
+                                    .unwrap_or_default();
+                                let nodes = chain.nodes.into_iter().map(render_node).collect_view();
+                                view! {
+                                    <div class="chain-card">
+                                        <div class="chain-header">
+                                            <span class="chain-project">{chain.project}</span>
+                                            <span class="chain-meta">
+                                                {count} " sessions · last active " {last}
+                                            </span>
+                                            {chain.inferred.then(|| view! {
+                                                <span class="chain-link chain-link-inferred">"contains inferred links"</span>
+                                            })}
+                                        </div>
+                                        <ul class="chain-tree">{nodes}</ul>
+                                    </div>
+                                }
+                            })
+                            .collect_view();
+                        view! { <div class="chain-list">{items}</div> }.into_any()
+                    }
+                    Some(Err(e)) => {
+                        let err = e.clone();
+                        view! {
+                            <div class="error">
+                                <h3>"Failed to load session chains"</h3>
+                                <p>{err}</p>
+                            </div>
+                        }
+                        .into_any()
+                    }
+                    None => view! { <div class="loading">"Loading session chains..."</div> }.into_any(),
+                }}
+            </Suspense>
+        </div>
+    }
+}
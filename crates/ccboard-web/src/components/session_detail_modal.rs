@@ -1,6 +1,11 @@
 //! Session detail modal component
 
-use crate::api::SessionData;
+use crate::api::{
+    fetch_cost_breakdown, resume_session, toggle_pin, CostBreakdownData, SessionData,
+    TokenCostsData,
+};
+use crate::components::{use_toast, PinnedRefresh};
+use crate::currency::{use_currency, CurrencyContext};
 use leptos::prelude::*;
 use leptos::web_sys::window;
 
@@ -10,6 +15,10 @@ pub fn SessionDetailModal(
     session: SessionData,
     on_close: impl Fn() + 'static + Copy + Send + Sync,
 ) -> impl IntoView {
+    let currency = use_currency();
+    let total_cost = session.estimated_cost.unwrap_or(session.cost);
+    let cost_per_message = session.cost_per_message;
+    let cost_per_token = session.cost_per_token;
     let session_id = session.id.clone();
     let copy_id = move || {
         if let Some(window) = window() {
@@ -18,6 +27,54 @@ pub fn SessionDetailModal(
         }
     };
 
+    let breakdown_id = session.id.clone();
+    let breakdown = LocalResource::new(move || {
+        let id = breakdown_id.clone();
+        async move { fetch_cost_breakdown(&id).await }
+    });
+
+    let toast = use_toast();
+    let (resuming, set_resuming) = signal(false);
+    let resume_id = session.id.clone();
+    let resume = move || {
+        let id = resume_id.clone();
+        set_resuming.set(true);
+        leptos::task::spawn_local(async move {
+            match resume_session(&id).await {
+                Ok(()) => toast.success("Session resumed in Claude CLI".to_string()),
+                Err(e) => match e.command {
+                    Some(command) => {
+                        if let Some(window) = window() {
+                            let _ = window.navigator().clipboard().write_text(&command);
+                        }
+                        toast.error(format!(
+                            "Resume failed: {}. Copied `{}` to the clipboard.",
+                            e.error, command
+                        ))
+                    }
+                    None => toast.error(format!("Resume failed: {}", e.error)),
+                },
+            }
+            set_resuming.set(false);
+        });
+    };
+
+    let pinned_refresh = use_context::<PinnedRefresh>();
+    let pin_id = session.id.clone();
+    let pin = move || {
+        let id = pin_id.clone();
+        leptos::task::spawn_local(async move {
+            match toggle_pin(&id).await {
+                Ok(true) => toast.success("Session pinned".to_string()),
+                Ok(false) => toast.info("Session unpinned".to_string()),
+                Err(e) => toast.error(format!("Pin failed: {}", e)),
+            }
+            if let Some(refresh) = pinned_refresh {
+                refresh.bump();
+            }
+        });
+    };
+
     view! {
         <div class="modal-overlay" on:click=move |_| on_close()>
             <div
@@ -83,6 +140,7 @@ pub fn SessionDetailModal(
                             <div class="detail-item">
                                 <span class="detail-label">"Total:"</span>
                                 <span class="detail-value">
+                                    {if session.tokens_estimated { "~" } else { "" }}
                                     {session.tokens.to_string()}
                                 </span>
                             </div>
@@ -118,9 +176,40 @@ pub fn SessionDetailModal(
                         <div class="detail-item">
                             <span class="detail-label">"Total Cost:"</span>
                                             <span class="detail-value cost-highlight">
-                                                {format!("${:.4}", session.cost)}
+                                                {move || currency.format(total_cost, 4)}
+                                            </span>
+                                        </div>
+                                        <div class="detail-item">
+                                            <span class="detail-label">"Per Message:"</span>
+                                            <span class="detail-value">
+                                                {move || format_optional_cost(currency, cost_per_message, 1.0)}
+                                            </span>
+                                        </div>
+                                        <div class="detail-item">
+                                            <span class="detail-label">"Per 1K Tokens:"</span>
+                                            <span class="detail-value">
+                                                {move || format_optional_cost(currency, cost_per_token, 1000.0)}
                                             </span>
                                         </div>
+                                        {session.cost_approximate.then(|| view! {
+                                            <p class="detail-note">
+                                                "Approximate: tokens are split across models by message share."
+                                            </p>
+                                        })}
+                                    </div>
+
+                                    <div class="detail-section">
+                                        <h3>"Cost Breakdown"</h3>
+                                        <Suspense fallback=move || view! { <p class="hint">"Loading cost breakdown..."</p> }>
+                                            {move || match breakdown.get().as_ref().map(|r| r.as_ref()) {
+                                                Some(Ok(data)) => render_cost_breakdown(data.clone(), currency).into_any(),
+                                                Some(Err(e)) => {
+                                                    view! { <p class="detail-note">{format!("Cost breakdown unavailable: {}", e)}</p> }
+                                                        .into_any()
+                                                }
+                                                None => view! { <p class="hint">"Loading cost breakdown..."</p> }.into_any(),
+                                            }}
+                                        </Suspense>
                                     </div>
 
                                     <div class="detail-section">
@@ -157,6 +246,21 @@ pub fn SessionDetailModal(
                                 </div>
 
                                 <div class="modal-footer">
+                                    <button
+                                        class="btn btn-primary"
+                                        on:click=move |_| resume()
+                                        disabled=move || resuming.get()
+                                        title="Run claude --resume on the ccboard host"
+                                    >
+                                        {move || if resuming.get() { "Resuming..." } else { "Resume" }}
+                                    </button>
+                                    <button
+                                        class="btn btn-secondary"
+                                        on:click=move |_| pin()
+                                        title="Pin or unpin this session in the sidebar"
+                                    >
+                                        "📌 Pin / Unpin"
+                                    </button>
                                     <button class="btn btn-secondary" on:click=move |_| on_close()>
                                         "Close (Esc)"
                                     </button>
@@ -166,6 +270,104 @@ pub fn SessionDetailModal(
     }
 }
 
+/// Per token kind table, plus a per-model table when several models answered
+fn render_cost_breakdown(data: CostBreakdownData, currency: CurrencyContext) -> impl IntoView {
+    let total = data.total;
+    let rows = [
+        ("Input", total.input_tokens, total.input_cost),
+        ("Output", total.output_tokens, total.output_cost),
+        ("Cache Read", total.cache_read_tokens, total.cache_read_cost),
+        (
+            "Cache Write",
+            total.cache_write_tokens,
+            total.cache_write_cost,
+        ),
+    ]
+    .into_iter()
+    .map(|(label, tokens, cost)| {
+        view! {
+            <tr>
+                <td>{label}</td>
+                <td class="costs-table__right">{tokens.to_string()}</td>
+                <td class="costs-table__right">{currency.format(cost, 4)}</td>
+            </tr>
+        }
+    })
+    .collect_view();
+
+    let models = (data.models.len() > 1).then(|| {
+        let model_rows = data
+            .models
+            .into_iter()
+            .map(|m| {
+                view! {
+                    <tr>
+                        <td>{m.model}</td>
+                        <td class="costs-table__right">{format!("{:.0}%", m.share * 100.0)}</td>
+                        <td class="costs-table__right">{currency.format(m.costs.input_cost, 4)}</td>
+                        <td class="costs-table__right">{currency.format(m.costs.output_cost, 4)}</td>
+                        <td class="costs-table__right">
+                            {currency.format(cache_cost(&m.costs), 4)}
+                        </td>
+                        <td class="costs-table__right costs-table__highlight">
+                            {currency.format(m.costs.total_cost, 4)}
+                        </td>
+                    </tr>
+                }
+            })
+            .collect_view();
+        view! {
+            <table class="costs-table costs-table--compact">
+                <thead>
+                    <tr>
+                        <th>"Model"</th>
+                        <th class="costs-table__right">"Share"</th>
+                        <th class="costs-table__right">"Input"</th>
+                        <th class="costs-table__right">"Output"</th>
+                        <th class="costs-table__right">"Cache"</th>
+                        <th class="costs-table__right">"Total"</th>
+                    </tr>
+                </thead>
+                <tbody>{model_rows}</tbody>
+            </table>
+        }
+    });
+
+    view! {
+        <table class="costs-table costs-table--compact">
+            <thead>
+                <tr>
+                    <th>"Kind"</th>
+                    <th class="costs-table__right">"Tokens"</th>
+                    <th class="costs-table__right">"Cost"</th>
+                </tr>
+            </thead>
+            <tbody>
+                {rows}
+                <tr>
+                    <td class="costs-table__highlight">"Total"</td>
+                    <td class="costs-table__right">
+                        {(total.input_tokens
+                            + total.output_tokens
+                            + total.cache_read_tokens
+                            + total.cache_write_tokens)
+                            .to_string()}
+                    </td>
+                    <td class="costs-table__right costs-table__highlight">
+                        {currency.format(total.total_cost, 4)}
+                    </td>
+                </tr>
+            </tbody>
+        </table>
+        {models}
+    }
+}
+
+/// Cache read + write cost
+fn cache_cost(costs: &TokenCostsData) -> f64 {
+    costs.cache_read_cost + costs.cache_write_cost
+}
+
 fn format_date(date: &Option<String>) -> String {
     date.as_ref()
         .and_then(|d| {
@@ -188,6 +390,12 @@ fn format_model(model: &str) -> String {
     }
 }
 
+/// `$x.xxxx` for `cost * scale`, or `n/a` when the ratio is undefined
+fn format_optional_cost(currency: CurrencyContext, cost: Option<f64>, scale: f64) -> String {
+    cost.map(|c| currency.format(c * scale, 4))
+        .unwrap_or_else(|| "n/a".to_string())
+}
+
 fn format_duration(duration_seconds: Option<u64>) -> String {
     duration_seconds
         .map(|secs| {
@@ -1,6 +1,7 @@
 //! Session table component with sorting
 
 use crate::api::SessionData;
+use crate::currency::use_currency;
 use leptos::prelude::*;
 
 /// Sort column
@@ -14,6 +15,29 @@ pub enum SortColumn {
     Cost,
 }
 
+impl SortColumn {
+    /// `sort` query value for columns `/api/sessions` can order by
+    pub fn api_param(self) -> Option<&'static str> {
+        match self {
+            SortColumn::Date => Some("date"),
+            SortColumn::Messages => Some("messages"),
+            SortColumn::Tokens => Some("tokens"),
+            SortColumn::Cost => Some("cost"),
+            SortColumn::Project | SortColumn::Model => None,
+        }
+    }
+
+    /// Column bound to a keyboard shortcut (`t`/`m`/`d`, like the TUI sort keys)
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "t" | "T" => Some(SortColumn::Tokens),
+            "m" | "M" => Some(SortColumn::Messages),
+            "d" | "D" => Some(SortColumn::Date),
+            _ => None,
+        }
+    }
+}
+
 /// Sort direction
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SortDirection {
@@ -21,6 +45,24 @@ pub enum SortDirection {
     Desc,
 }
 
+impl SortDirection {
+    /// `order` query value for `/api/sessions`
+    pub fn api_param(self) -> &'static str {
+        match self {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }
+    }
+
+    /// `aria-sort` value for the active column header
+    pub fn aria_sort(self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ascending",
+            SortDirection::Desc => "descending",
+        }
+    }
+}
+
 /// Get CSS class for cost cell based on threshold
 fn cost_color_class(cost: f64) -> &'static str {
     if cost >= 10.0 {
@@ -33,19 +75,35 @@ fn cost_color_class(cost: f64) -> &'static str {
 }
 
 /// Session table component
+///
+/// When focused, `t`/`m`/`d` sort by tokens/messages/date; repeating a key
+/// flips the direction. The active header carries `aria-sort`.
+///
+/// With `server_sort`, the parent owns sorting and pagination: the table writes
+/// header clicks into the signal and renders rows in the order received. Only
+/// columns the API cannot sort (project, model) are sorted within the page.
 #[component]
 pub fn SessionTable(
     sessions: Signal<Option<Vec<SessionData>>>,
     on_row_click: WriteSignal<Option<SessionData>>,
+    #[prop(optional)] server_sort: Option<RwSignal<(SortColumn, SortDirection)>>,
 ) -> impl IntoView {
-    let (sort_column, set_sort_column) = signal(SortColumn::Date);
-    let (sort_direction, set_sort_direction) = signal(SortDirection::Desc);
+    let currency = use_currency();
+    let server_side = server_sort.is_some();
+    let sort_state =
+        server_sort.unwrap_or_else(|| RwSignal::new((SortColumn::Date, SortDirection::Desc)));
+    let sort_column = Signal::derive(move || sort_state.get().0);
+    let sort_direction = Signal::derive(move || sort_state.get().1);
     let (current_page, set_current_page) = signal(0_usize);
     let page_size = 20;
 
     // Sort sessions
     let sorted_sessions = Memo::new(move |_| {
         sessions.get().map(|mut sessions| {
+            if server_side && sort_column.get().api_param().is_some() {
+                // Already ordered by the server
+                return sessions;
+            }
             match sort_column.get() {
                 SortColumn::Date => {
                     sessions.sort_by(|a, b| {
@@ -115,9 +173,12 @@ pub fn SessionTable(
         })
     });
 
-    // Paginate sessions
+    // Paginate sessions (the parent paginates in server mode)
     let paginated_sessions = Memo::new(move |_| {
         sorted_sessions.get().map(|sessions| {
+            if server_side {
+                return sessions;
+            }
             let start = current_page.get() * page_size;
             let end = (start + page_size).min(sessions.len());
             sessions[start..end].to_vec()
@@ -130,15 +191,15 @@ pub fn SessionTable(
     let toggle_sort = move |column: SortColumn| {
         if sort_column.get() == column {
             // Toggle direction
-            set_sort_direction.set(if sort_direction.get() == SortDirection::Asc {
+            let direction = if sort_direction.get() == SortDirection::Asc {
                 SortDirection::Desc
             } else {
                 SortDirection::Asc
-            });
+            };
+            sort_state.set((column, direction));
         } else {
             // New column, default to descending
-            set_sort_column.set(column);
-            set_sort_direction.set(SortDirection::Desc);
+            sort_state.set((column, SortDirection::Desc));
         }
         // Reset to first page
         set_current_page.set(0);
@@ -156,37 +217,81 @@ pub fn SessionTable(
         }
     };
 
+    // `aria-sort` for a header cell ("none" when not the active column)
+    let aria_sort = move |column: SortColumn| {
+        if sort_column.get() == column {
+            sort_direction.get().aria_sort()
+        } else {
+            "none"
+        }
+    };
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        if ev.ctrl_key() || ev.meta_key() || ev.alt_key() {
+            return;
+        }
+        if let Some(column) = SortColumn::from_key(&ev.key()) {
+            ev.prevent_default();
+            toggle_sort(column);
+        }
+    };
+
     view! {
-        <div class="session-table-container">
-            <div class="table-stats">
-                {move || {
-                    let count = total_count.get();
-                    let start = current_page.get() * page_size + 1;
-                    let end = ((current_page.get() + 1) * page_size).min(count);
-                    format!("Showing {} - {} of {} sessions", start, end, count)
-                }}
-            </div>
+        <div
+            class="session-table-container"
+            tabindex="0"
+            aria-label="Sessions table (t: tokens, m: messages, d: date)"
+            on:keydown=on_keydown
+        >
+            {(!server_side).then(|| view! {
+                <div class="table-stats">
+                    {move || {
+                        let count = total_count.get();
+                        let start = current_page.get() * page_size + 1;
+                        let end = ((current_page.get() + 1) * page_size).min(count);
+                        format!("Showing {} - {} of {} sessions", start, end, count)
+                    }}
+                </div>
+            })}
 
             <table class="session-table">
                 <thead>
                     <tr>
-                        <th on:click=move |_| toggle_sort(SortColumn::Date)>
+                        <th
+                            aria-sort=move || aria_sort(SortColumn::Date)
+                            on:click=move |_| toggle_sort(SortColumn::Date)
+                        >
                             {"Date"}{move || sort_indicator(SortColumn::Date)}
                         </th>
-                        <th on:click=move |_| toggle_sort(SortColumn::Project)>
+                        <th
+                            aria-sort=move || aria_sort(SortColumn::Project)
+                            on:click=move |_| toggle_sort(SortColumn::Project)
+                        >
                             {"Project"}{move || sort_indicator(SortColumn::Project)}
                         </th>
-                        <th on:click=move |_| toggle_sort(SortColumn::Model)>
+                        <th
+                            aria-sort=move || aria_sort(SortColumn::Model)
+                            on:click=move |_| toggle_sort(SortColumn::Model)
+                        >
                             {"Model"}{move || sort_indicator(SortColumn::Model)}
                         </th>
-                        <th on:click=move |_| toggle_sort(SortColumn::Messages)>
+                        <th
+                            aria-sort=move || aria_sort(SortColumn::Messages)
+                            on:click=move |_| toggle_sort(SortColumn::Messages)
+                        >
                             {"Messages"}{move || sort_indicator(SortColumn::Messages)}
                         </th>
-                        <th on:click=move |_| toggle_sort(SortColumn::Tokens)>
+                        <th
+                            aria-sort=move || aria_sort(SortColumn::Tokens)
+                            on:click=move |_| toggle_sort(SortColumn::Tokens)
+                        >
                             {"Tokens"}{move || sort_indicator(SortColumn::Tokens)}
                         </th>
                         <th>{"Lines"}</th>
-                        <th on:click=move |_| toggle_sort(SortColumn::Cost)>
+                        <th
+                            aria-sort=move || aria_sort(SortColumn::Cost)
+                            on:click=move |_| toggle_sort(SortColumn::Cost)
+                        >
                             {"Cost"}{move || sort_indicator(SortColumn::Cost)}
                         </th>
                         <th>{"Status"}</th>
@@ -210,10 +315,23 @@ pub fn SessionTable(
                                             >
 
                                                 <td>{format_date(&session.date)}</td>
-                                                <td>{session.project.clone()}</td>
+                                                <td>
+                                                    {session.project.clone()}
+                                                    {session
+                                                        .tags
+                                                        .iter()
+                                                        .map(|tag| {
+                                                            view! {
+                                                                <span class=tag_chip_class(tag)>
+                                                                    {tag.clone()}
+                                                                </span>
+                                                            }
+                                                        })
+                                                        .collect_view()}
+                                                </td>
                                                 <td>{format_model(&session.model)}</td>
                                                 <td>{session.messages.to_string()}</td>
-                                                <td>{format_tokens(session.tokens)}</td>
+                                                <td>{if session.tokens_estimated { "~" } else { "" }}{format_tokens(session.tokens)}</td>
                                                 <td>
                                                     {if session.lines_added > 0 || session.lines_removed > 0 {
                                                         view! {
@@ -228,7 +346,7 @@ pub fn SessionTable(
                                                     }}
                                                 </td>
                                                 <td class={cost_color_class(session.cost)}>
-                                                    {format!("${:.4}", session.cost)}
+                                                    {currency.format(session.cost, 4)}
                                                 </td>
                                                 <td>
                                                     <span class="badge badge-success">
@@ -244,33 +362,49 @@ pub fn SessionTable(
                 </tbody>
             </table>
 
-            <div class="pagination">
-                <button
-                    class="btn btn-secondary"
-                    disabled=move || current_page.get() == 0
-                    on:click=move |_| set_current_page.update(|p| *p = p.saturating_sub(1))
-                >
-                    {"← Previous"}
-                </button>
-                <span class="pagination-info">
-                    {move || format!("Page {} of {}", current_page.get() + 1, total_pages.get())}
-                </span>
-                <button
-                    class="btn btn-secondary"
-                    disabled=move || current_page.get() >= total_pages.get() - 1
-                    on:click=move |_| {
-                        if current_page.get() < total_pages.get() - 1 {
-                            set_current_page.update(|p| *p += 1)
+            {(!server_side).then(|| view! {
+                <div class="pagination">
+                    <button
+                        class="btn btn-secondary"
+                        disabled=move || current_page.get() == 0
+                        on:click=move |_| set_current_page.update(|p| *p = p.saturating_sub(1))
+                    >
+                        {"← Previous"}
+                    </button>
+                    <span class="pagination-info">
+                        {move || format!("Page {} of {}", current_page.get() + 1, total_pages.get())}
+                    </span>
+                    <button
+                        class="btn btn-secondary"
+                        disabled=move || current_page.get() >= total_pages.get() - 1
+                        on:click=move |_| {
+                            if current_page.get() < total_pages.get() - 1 {
+                                set_current_page.update(|p| *p += 1)
+                            }
                         }
-                    }
-                >
-                    {"Next →"}
-                </button>
-            </div>
+                    >
+                        {"Next →"}
+                    </button>
+                </div>
+            })}
         </div>
     }
 }
 
+/// Number of `.tag-color-N` classes in style.css
+const TAG_COLORS: u64 = 4;
+
+/// Chip classes for a tag
+///
+/// Same FNV-1a hash as `ccboard_core::tags::tag_color_index` (the frontend
+/// can't depend on core), so a tag gets the same color slot as in the TUI.
+fn tag_chip_class(tag: &str) -> String {
+    let hash = tag.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("tag-chip tag-color-{}", hash % TAG_COLORS)
+}
+
 fn format_date(date: &Option<String>) -> String {
     date.as_ref()
         .and_then(|d| {
@@ -302,3 +436,22 @@ fn format_tokens(tokens: u64) -> String {
         tokens.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_keys() {
+        assert_eq!(SortColumn::from_key("t"), Some(SortColumn::Tokens));
+        assert_eq!(SortColumn::from_key("M"), Some(SortColumn::Messages));
+        assert_eq!(SortColumn::from_key("d"), Some(SortColumn::Date));
+        assert_eq!(SortColumn::from_key("x"), None);
+        assert_eq!(SortDirection::Asc.aria_sort(), "ascending");
+    }
+    #[test]
+    fn test_tag_chip_class_is_stable() {
+        assert_eq!(tag_chip_class("bug"), tag_chip_class("bug"));
+        assert!(tag_chip_class("spike").starts_with("tag-chip tag-color-"));
+    }
+}
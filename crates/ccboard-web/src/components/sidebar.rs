@@ -1,8 +1,26 @@
 //! Sidebar navigation component with inline Lucide-style SVG icons
 
+use crate::api::{fetch_pinned_sessions, SessionData};
+use crate::components::SessionDetailModal;
+use crate::sse_hook::use_sse;
 use leptos::prelude::*;
 use leptos_router::components::A;
 
+/// Version counter bumped whenever pins change, so the sidebar refetches
+#[derive(Clone, Copy)]
+pub struct PinnedRefresh(RwSignal<u32>);
+
+impl PinnedRefresh {
+    pub fn bump(self) {
+        self.0.update(|v| *v += 1);
+    }
+}
+
+/// Provide the pinned sessions refresh counter to the app
+pub fn provide_pinned_refresh() {
+    provide_context(PinnedRefresh(RwSignal::new(0)));
+}
+
 /// Sidebar with navigation menu
 #[component]
 pub fn Sidebar(
@@ -14,6 +32,18 @@ pub fn Sidebar(
         set_sidebar_open.set(false);
     };
 
+    // Pinned sessions: refetched on pin changes and live updates
+    let pinned_refresh = use_context::<PinnedRefresh>();
+    let sse_event = use_sse();
+    let pinned_resource = LocalResource::new(move || {
+        if let Some(PinnedRefresh(version)) = pinned_refresh {
+            version.track();
+        }
+        let _ = sse_event.get();
+        async move { fetch_pinned_sessions().await }
+    });
+    let (pinned_modal, set_pinned_modal) = signal(None::<SessionData>);
+
     view! {
         <>
             // Backdrop overlay for mobile
@@ -126,6 +156,18 @@ pub fn Sidebar(
                                 <span class="sidebar-link-label">"Costs"</span>
                             </A>
                         </li>
+                        <li class="nav-item">
+                            <A href="/compare" attr:class="sidebar-link" on:click=close_sidebar>
+                                <span class="sidebar-link-icon">
+                                    <svg xmlns="http://www.w3.org/2000/svg" width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round">
+                                        <line x1="18" x2="18" y1="20" y2="10"/>
+                                        <line x1="12" x2="12" y1="20" y2="4"/>
+                                        <line x1="6" x2="6" y1="20" y2="14"/>
+                                    </svg>
+                                </span>
+                                <span class="sidebar-link-label">"Compare"</span>
+                            </A>
+                        </li>
                         <li class="nav-item">
                             <A href="/history" attr:class="sidebar-link" on:click=close_sidebar>
                                 <span class="sidebar-link-icon">
@@ -191,7 +233,47 @@ pub fn Sidebar(
                         </li>
                     </ul>
                 </nav>
+
+                <Suspense fallback=|| ()>
+                    {move || {
+                        pinned_resource.get().and_then(|result| result.as_ref().ok().cloned()).filter(|pinned| !pinned.sessions.is_empty()).map(|pinned| {
+                            view! {
+                                <div class="sidebar-section sidebar-pinned">
+                                    <div class="sidebar-section-title">"📌 Pinned"</div>
+                                    {pinned.sessions.into_iter().map(|session| {
+                                        let label = session
+                                            .preview
+                                            .clone()
+                                            .filter(|p| !p.trim().is_empty())
+                                            .unwrap_or_else(|| session.id.chars().take(8).collect());
+                                        let title = format!("{} · {}", session.project, session.id);
+                                        view! {
+                                            <button
+                                                class="sidebar-link sidebar-pinned-link"
+                                                title=title
+                                                on:click=move |_| set_pinned_modal.set(Some(session.clone()))
+                                            >
+                                                <span class="sidebar-link-label">{label}</span>
+                                            </button>
+                                        }
+                                    }).collect::<Vec<_>>()}
+                                </div>
+                            }
+                        })
+                    }}
+                </Suspense>
             </aside>
+
+            {move || {
+                pinned_modal.get().map(|session| {
+                    view! {
+                        <SessionDetailModal
+                            session=session
+                            on_close=move || set_pinned_modal.set(None)
+                        />
+                    }
+                })
+            }}
         </>
     }
 }
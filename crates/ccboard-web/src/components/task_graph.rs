@@ -144,21 +144,21 @@ pub fn TaskDependencyGraph() -> impl IntoView {
                                     <h3>"Legend"</h3>
                                     <div class="legend-items">
                                         <div class="legend-item">
-                                            <div class="legend-color" style="background: #4CAF50;"></div>
+                                            <div class="legend-color" style="background: var(--color-green);"></div>
                                             <span>"Complete"</span>
                                         </div>
                                         <div class="legend-item">
-                                            <div class="legend-color" style="background: #FFC107;"></div>
+                                            <div class="legend-color" style="background: var(--color-yellow);"></div>
                                             <span>"In Progress"</span>
                                         </div>
                                         <div class="legend-item">
-                                            <div class="legend-color" style="background: #9E9E9E;"></div>
+                                            <div class="legend-color" style="background: var(--text-muted);"></div>
                                             <span>"Future"</span>
                                         </div>
                                     </div>
                                 </div>
 
-                                <div id="d3-graph" style="width: 100%; height: 600px; border: 1px solid #333; background: #1a1a1a; border-radius: 8px;"></div>
+                                <div id="d3-graph" style="width: 100%; height: 600px; border: 1px solid var(--border-color); background: var(--bg-primary); border-radius: 8px;"></div>
 
                                 <div id="task-tooltip" class="task-tooltip hidden">
                                     <div class="tooltip-header">
@@ -0,0 +1,41 @@
+//! Display currency shared by every page
+//!
+//! Costs arrive from the API in USD. The `currency` setting is fetched once
+//! from `/api/currency` and provided as a signal: formatting reads (and tracks)
+//! it, so costs rendered before the fetch completes switch over when it does.
+
+use ccboard_types::Currency;
+use leptos::prelude::*;
+
+/// Currency context read by cost formatting
+#[derive(Clone, Copy)]
+pub struct CurrencyContext(RwSignal<Currency>);
+
+impl CurrencyContext {
+    /// Format a USD cost with 2 decimals
+    pub fn format_cost(&self, usd: f64) -> String {
+        self.0.with(|currency| currency.format_cost(usd))
+    }
+
+    /// Format a USD cost with `decimals` places
+    pub fn format(&self, usd: f64, decimals: usize) -> String {
+        self.0.with(|currency| currency.format(usd, decimals))
+    }
+}
+
+/// Provide the currency context at the app root, starting in USD
+pub fn provide_currency() {
+    let ctx = CurrencyContext(RwSignal::new(Currency::default()));
+    provide_context(ctx);
+    leptos::task::spawn_local(async move {
+        if let Ok(currency) = crate::api::fetch_currency().await {
+            ctx.0.set(currency);
+        }
+    });
+}
+
+/// The app's currency context (USD outside of [`provide_currency`])
+pub fn use_currency() -> CurrencyContext {
+    use_context::<CurrencyContext>()
+        .unwrap_or_else(|| CurrencyContext(RwSignal::new(Currency::default())))
+}
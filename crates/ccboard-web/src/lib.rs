@@ -5,31 +5,39 @@
 pub mod api;
 pub mod app;
 pub mod components;
+pub mod currency;
 pub mod pages;
 
 #[cfg(feature = "ssr")]
 pub mod router;
 #[cfg(feature = "ssr")]
 pub mod sse;
+#[cfg(feature = "ssr")]
+pub mod ws;
 
 pub mod sse_hook;
+pub mod theme;
 pub mod utils;
 
 pub use app::App;
 
-#[cfg(feature = "ssr")]
-pub use router::create_router;
 #[cfg(feature = "ssr")]
 pub use router::has_real_frontend;
+#[cfg(feature = "ssr")]
+pub use router::{create_router, create_router_with_options, ServerOptions};
 
 // Server-side only code (backend with tokio/axum)
 #[cfg(feature = "ssr")]
-pub async fn run(store: std::sync::Arc<ccboard_core::DataStore>, port: u16) -> anyhow::Result<()> {
+pub async fn run(
+    store: std::sync::Arc<ccboard_core::DataStore>,
+    port: u16,
+    options: ServerOptions,
+) -> anyhow::Result<()> {
     use std::net::SocketAddr;
     use tokio::net::TcpListener;
     use tracing::info;
 
-    let router = create_router(store);
+    let router = create_router_with_options(store, options);
 
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
     let listener = TcpListener::bind(addr).await?;
@@ -37,7 +45,12 @@ pub async fn run(store: std::sync::Arc<ccboard_core::DataStore>, port: u16) -> a
     info!("Web server listening on http://localhost:{}", port);
     println!("Web server listening on http://localhost:{}", port);
 
-    axum::serve(listener, router).await?;
+    // Peer addresses let the router restrict mutating routes to loopback
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -1,9 +1,10 @@
 //! Analytics page component
 
-use crate::api::{fetch_stats, format_cost, format_number};
+use crate::api::{fetch_stats, format_number};
 use crate::components::{
     use_toast, BudgetStatus, CardColor, ForecastChart, ProjectsBreakdown, StatsCard,
 };
+use crate::currency::use_currency;
 use crate::sse_hook::{use_sse, SseEvent};
 use crate::utils::export_as_csv;
 use leptos::prelude::*;
@@ -50,7 +51,9 @@ pub fn Analytics() -> impl IntoView {
     let sse_event = use_sse();
 
     Effect::new(move |_| {
-        if let Some(SseEvent::StatsUpdated | SseEvent::AnalyticsUpdated) = sse_event.get() {
+        if let Some(SseEvent::StatsUpdated | SseEvent::AnalyticsUpdated | SseEvent::Reconnected) =
+            sse_event.get()
+        {
             set_stats_version.update(|v| *v += 1);
             toast.info("Analytics refreshed".to_string());
         }
@@ -166,6 +169,7 @@ pub fn Analytics() -> impl IntoView {
 /// Overview tab - Key metrics and forecast
 #[component]
 fn AnalyticsOverview(data: crate::api::StatsData) -> impl IntoView {
+    let currency = use_currency();
     // Extract metrics for cards
     let total_cost = data.this_month_cost;
     let avg_session_cost = data.avg_session_cost;
@@ -182,13 +186,13 @@ fn AnalyticsOverview(data: crate::api::StatsData) -> impl IntoView {
             <div class="metrics-summary">
                 <StatsCard
                     label="Total Cost (Month)".to_string()
-                    value={format_cost(total_cost)}
+                    value={currency.format_cost(total_cost)}
                     icon="💰".to_string()
                     color=CardColor::Default
                 />
                 <StatsCard
                     label="Avg Cost Per Session".to_string()
-                    value={format_cost(avg_session_cost)}
+                    value={currency.format_cost(avg_session_cost)}
                     icon="📊".to_string()
                     color=CardColor::Default
                 />
@@ -200,7 +204,7 @@ fn AnalyticsOverview(data: crate::api::StatsData) -> impl IntoView {
                 />
                 <StatsCard
                     label="Forecast Next Month".to_string()
-                    value={format_cost(forecast_cost)}
+                    value={currency.format_cost(forecast_cost)}
                     icon="📈".to_string()
                     color=CardColor::Yellow
                 />
@@ -416,6 +420,7 @@ fn AnalyticsPatterns(data: crate::api::StatsData) -> impl IntoView {
 /// Insights tab - AI-generated insights and recommendations
 #[component]
 fn AnalyticsInsights(data: crate::api::StatsData) -> impl IntoView {
+    let currency = use_currency();
     // Generate insights based on data
     let mut insights = Vec::new();
 
@@ -425,8 +430,8 @@ fn AnalyticsInsights(data: crate::api::StatsData) -> impl IntoView {
             "💰",
             "High Usage Month",
             format!(
-                "Current month cost is ${:.2}. Consider monitoring token usage in long sessions.",
-                data.this_month_cost
+                "Current month cost is {}. Consider monitoring token usage in long sessions.",
+                currency.format_cost(data.this_month_cost)
             ),
         ));
     } else {
@@ -434,8 +439,8 @@ fn AnalyticsInsights(data: crate::api::StatsData) -> impl IntoView {
             "✅",
             "Efficient Usage",
             format!(
-                "Current month cost is ${:.2}. Your usage is within typical ranges.",
-                data.this_month_cost
+                "Current month cost is {}. Your usage is within typical ranges.",
+                currency.format_cost(data.this_month_cost)
             ),
         ));
     }
@@ -569,6 +574,7 @@ fn AnalyticsInsights(data: crate::api::StatsData) -> impl IntoView {
 /// Tools tab — per-tool efficiency metrics from /api/analytics/tool-stats
 #[component]
 fn AnalyticsTools() -> impl IntoView {
+    let currency = use_currency();
     use gloo_net::http::Request;
 
     let tool_stats_data = LocalResource::new(move || async move {
@@ -631,14 +637,14 @@ fn AnalyticsTools() -> impl IntoView {
                                                         {stats.into_iter().map(|s| {
                                                             let pct_display = format!("{:.1}%", s.pct_of_total * 100.0);
                                                             let cost_per_call = if s.cost_per_call < 0.00001 {
-                                                                "<$0.00001".to_string()
+                                                                format!("<{}", currency.format(0.00001, 5))
                                                             } else {
-                                                                format!("${:.5}", s.cost_per_call)
+                                                                currency.format(s.cost_per_call, 5)
                                                             };
                                                             let total_cost = if s.est_cost_usd < 0.001 {
-                                                                "<$0.001".to_string()
+                                                                format!("<{}", currency.format(0.001, 3))
                                                             } else {
-                                                                format!("${:.3}", s.est_cost_usd)
+                                                                currency.format(s.est_cost_usd, 3)
                                                             };
                                                             // Bar width capped at 100% for visual representation
                                                             let bar_width = (s.pct_of_total * 100.0).min(100.0);
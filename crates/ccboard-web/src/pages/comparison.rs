@@ -0,0 +1,251 @@
+//! Comparison page — side-by-side metrics of two periods ("month vs month")
+
+use crate::components::Sparkline;
+use crate::currency::{use_currency, CurrencyContext};
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const API_BASE_URL: &str = "";
+
+// ─── API types ────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DateRange {
+    label: String,
+    start: String,
+    end: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PeriodMetrics {
+    sessions: usize,
+    messages: u64,
+    tokens: u64,
+    cost: f64,
+    active_days: usize,
+    daily_sessions: Vec<u64>,
+    daily_tokens: Vec<u64>,
+    daily_cost: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MetricDelta {
+    metric: String,
+    a: f64,
+    b: f64,
+    delta: f64,
+    delta_pct: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ComparisonResponse {
+    a: DateRange,
+    b: DateRange,
+    metrics_a: PeriodMetrics,
+    metrics_b: PeriodMetrics,
+    deltas: Vec<MetricDelta>,
+}
+
+// ─── Fetch helper ─────────────────────────────────────────────────────────────
+
+async fn fetch_comparison(a: String, b: String) -> Result<ComparisonResponse, String> {
+    let url = format!(
+        "{}/api/comparison?a={}&b={}",
+        API_BASE_URL,
+        js_sys::encode_uri_component(&a),
+        js_sys::encode_uri_component(&b)
+    );
+
+    let response = gloo_net::http::Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.ok() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
+
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))?;
+    if let Some(error) = body.get("error").and_then(|e| e.as_str()) {
+        return Err(error.to_string());
+    }
+
+    serde_json::from_value(body).map_err(|e| format!("Parse error: {}", e))
+}
+
+// ─── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Previous and current month as `YYYY-MM`
+fn default_periods() -> (String, String) {
+    let now = js_sys::Date::new_0();
+    let year = now.get_full_year();
+    let month = now.get_month() + 1; // JS months are 0-based
+    let (prev_year, prev_month) = if month == 1 {
+        (year - 1, 12)
+    } else {
+        (year, month - 1)
+    };
+    (
+        format!("{}-{:02}", prev_year, prev_month),
+        format!("{}-{:02}", year, month),
+    )
+}
+
+fn format_value(currency: CurrencyContext, metric: &str, value: f64) -> String {
+    if metric == "Cost" {
+        currency.format_cost(value)
+    } else if value >= 1_000_000.0 {
+        format!("{:.1}M", value / 1_000_000.0)
+    } else if value >= 1_000.0 {
+        format!("{:.1}K", value / 1_000.0)
+    } else {
+        format!("{:.0}", value)
+    }
+}
+
+/// Delta label and color; a cost increase is shown as bad, other increases as good
+fn format_delta(delta: &MetricDelta) -> (String, &'static str) {
+    let label = match delta.delta_pct {
+        Some(pct) if pct.is_finite() => format!("{:+.1}%", pct),
+        _ if delta.b > 0.0 => "new".to_string(),
+        _ => "—".to_string(),
+    };
+    let color = if delta.delta == 0.0 || !delta.delta.is_finite() {
+        "var(--text-muted)"
+    } else if (delta.delta > 0.0) == (delta.metric != "Cost") {
+        "var(--success)"
+    } else {
+        "var(--error)"
+    };
+    (label, color)
+}
+
+/// Daily series of a metric, as integers for the sparkline (cost in cents,
+/// 1 per active day)
+fn series(metrics: &PeriodMetrics, metric: &str) -> Vec<u64> {
+    match metric {
+        "Sessions" => metrics.daily_sessions.clone(),
+        "Active days" => metrics
+            .daily_sessions
+            .iter()
+            .map(|&n| u64::from(n > 0))
+            .collect(),
+        "Tokens" => metrics.daily_tokens.clone(),
+        "Cost" => metrics
+            .daily_cost
+            .iter()
+            .map(|c| (c * 100.0).round() as u64)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+// ─── Main page component ──────────────────────────────────────────────────────
+
+/// Comparison page with per-metric sparklines and colored deltas
+#[component]
+pub fn ComparisonPage() -> impl IntoView {
+    let currency = use_currency();
+    let (default_a, default_b) = default_periods();
+    let period_a = RwSignal::new(default_a.clone());
+    let period_b = RwSignal::new(default_b.clone());
+    let submitted = RwSignal::new((default_a, default_b));
+
+    let comparison = LocalResource::new(move || {
+        let (a, b) = submitted.get();
+        async move { fetch_comparison(a, b).await }
+    });
+
+    let input_style = "padding: 0.75rem 1rem; background: var(--bg-tertiary); border: 1px solid var(--border); border-radius: 6px; color: var(--text-primary); font-size: 1rem;";
+
+    view! {
+        <div class="page">
+            <div class="page-header">
+                <h1 class="page-title">"Compare Periods"</h1>
+                <p class="page-subtitle">"Side-by-side metrics (YYYY-MM or YYYY-MM-DD..YYYY-MM-DD)"</p>
+            </div>
+
+            <div class="card" style="margin-bottom: 1.5rem;">
+                <div style="display: flex; gap: 1rem; align-items: center;">
+                    <input
+                        type="text"
+                        style=input_style
+                        prop:value=period_a
+                        on:input=move |ev| period_a.set(event_target_value(&ev))
+                    />
+                    <span style="color: var(--text-muted);">"vs"</span>
+                    <input
+                        type="text"
+                        style=input_style
+                        prop:value=period_b
+                        on:input=move |ev| period_b.set(event_target_value(&ev))
+                    />
+                    <button
+                        style="padding: 0.75rem 1.5rem; background: var(--accent); color: white; border: none; border-radius: 6px; cursor: pointer;"
+                        on:click=move |_| submitted.set((period_a.get(), period_b.get()))
+                    >
+                        "Compare"
+                    </button>
+                </div>
+            </div>
+
+            <Suspense fallback=|| view! { <div class="card" style="text-align: center; color: var(--text-muted);">"Loading comparison..."</div> }>
+                {move || {
+                    comparison.get().map(|result| {
+                        match result.as_ref() {
+                            Ok(data) => {
+                                let rows = data.deltas.iter().map(|delta| {
+                                    let (delta_label, delta_color) = format_delta(delta);
+                                    let spark_a = series(&data.metrics_a, &delta.metric);
+                                    let spark_b = series(&data.metrics_b, &delta.metric);
+                                    let has_series = !spark_a.is_empty();
+                                    view! {
+                                        <tr>
+                                            <td>{delta.metric.clone()}</td>
+                                            <td>{format_value(currency, &delta.metric, delta.a)}</td>
+                                            <td>{format_value(currency, &delta.metric, delta.b)}</td>
+                                            <td style=format!("color: {}; font-weight: 600;", delta_color)>{delta_label}</td>
+                                            <td>
+                                                {has_series.then(|| view! {
+                                                    <div style="display: flex; gap: 0.5rem;">
+                                                        <Sparkline data=spark_a width=160 height=32 label=data.a.label.clone() />
+                                                        <Sparkline data=spark_b width=160 height=32 label=data.b.label.clone() />
+                                                    </div>
+                                                })}
+                                            </td>
+                                        </tr>
+                                    }
+                                }).collect_view();
+
+                                view! {
+                                    <div class="card">
+                                        <div class="table-container">
+                                            <table class="sessions-table">
+                                                <thead>
+                                                    <tr>
+                                                        <th>"Metric"</th>
+                                                        <th>{format!("A · {} → {}", data.a.start, data.a.end)}</th>
+                                                        <th>{format!("B · {} → {}", data.b.start, data.b.end)}</th>
+                                                        <th>"Δ"</th>
+                                                        <th>"Daily"</th>
+                                                    </tr>
+                                                </thead>
+                                                <tbody>{rows}</tbody>
+                                            </table>
+                                        </div>
+                                    </div>
+                                }.into_any()
+                            }
+                            Err(e) => view! {
+                                <div class="card" style="color: var(--error);">{e.clone()}</div>
+                            }.into_any(),
+                        }
+                    })
+                }}
+            </Suspense>
+        </div>
+    }
+}
@@ -1,6 +1,7 @@
 //! Costs page - displays cost analysis with 4 tabs (Overview, By Model, Daily, Billing Blocks)
 
 use crate::api::{fetch_stats, StatsData};
+use crate::currency::use_currency;
 use leptos::prelude::*;
 
 /// Costs page component with 4 tabs
@@ -96,6 +97,7 @@ pub fn Costs() -> impl IntoView {
 /// Overview tab
 #[component]
 fn CostsOverview(stats: StatsData) -> impl IntoView {
+    let currency = use_currency();
     let total_cost = stats.total_cost();
     let total_tokens = stats.total_tokens();
 
@@ -132,7 +134,7 @@ fn CostsOverview(stats: StatsData) -> impl IntoView {
             <div class="costs-overview__header">
                 <div class="costs-total">
                     <span class="costs-total__label">"Total Estimated Cost"</span>
-                    <span class="costs-total__value">{format!("${:.2}", total_cost)}</span>
+                    <span class="costs-total__value">{currency.format_cost(total_cost)}</span>
                 </div>
             </div>
 
@@ -161,7 +163,7 @@ fn CostsOverview(stats: StatsData) -> impl IntoView {
                                             _ => "quota-gauge--safe",
                                         };
                                         let budget_str = quota.budget_limit
-                                            .map(|l| format!("${:.2}", l))
+                                            .map(|c| currency.format_cost(c))
                                             .unwrap_or_else(|| "∞".to_string());
 
                                         view! {
@@ -169,16 +171,16 @@ fn CostsOverview(stats: StatsData) -> impl IntoView {
                                                 <h3 class="costs-section__title">"💰 Monthly Budget"</h3>
                                                 <div class="quota-container">
                                                     <div class="quota-header">
-                                                        <span class="quota-label">{format!("${:.2} / {} ({:.1}%)", quota.current_cost, budget_str, quota.usage_pct)}</span>
+                                                        <span class="quota-label">{format!("{} / {} ({:.1}%)", currency.format_cost(quota.current_cost), budget_str, quota.usage_pct)}</span>
                                                     </div>
                                                     <div class="quota-gauge">
                                                         <div class={format!("quota-gauge__fill {}", gauge_class)} style={format!("width: {}%", usage_ratio * 100.0)}></div>
                                                     </div>
                                                     <div class="quota-footer">
                                                         {if let Some(overage) = quota.projected_overage {
-                                                            format!("Projected: ${:.2} (${:.2} over)", quota.projected_monthly_cost, overage)
+                                                            format!("Projected: {} ({} over)", currency.format_cost(quota.projected_monthly_cost), currency.format_cost(overage))
                                                         } else {
-                                                            format!("Projected: ${:.2}", quota.projected_monthly_cost)
+                                                            format!("Projected: {}", currency.format_cost(quota.projected_monthly_cost))
                                                         }}
                                                     </div>
                                                 </div>
@@ -240,7 +242,7 @@ fn CostsOverview(stats: StatsData) -> impl IntoView {
                             view! {
                                 <tr>
                                     <td>{model.clone()}</td>
-                                    <td class="costs-table__right">{format!("${:.2}", usage.cost_usd)}</td>
+                                    <td class="costs-table__right">{currency.format_cost(usage.cost_usd)}</td>
                                     <td class="costs-table__right">{crate::api::format_number(usage.input_tokens)}</td>
                                     <td class="costs-table__right">{crate::api::format_number(usage.output_tokens)}</td>
                                 </tr>
@@ -256,6 +258,7 @@ fn CostsOverview(stats: StatsData) -> impl IntoView {
 /// By Model tab
 #[component]
 fn CostsByModel(stats: StatsData) -> impl IntoView {
+    let currency = use_currency();
     // Sort models by cost descending
     let mut models: Vec<_> = stats.model_usage.iter().collect();
     models.sort_by(|a, b| {
@@ -286,10 +289,10 @@ fn CostsByModel(stats: StatsData) -> impl IntoView {
                         view! {
                             <tr>
                                 <td><code>{model.to_string()}</code></td>
-                                <td class="costs-table__right">{format!("${:.2}", input_cost)}</td>
-                                <td class="costs-table__right">{format!("${:.2}", output_cost)}</td>
-                                <td class="costs-table__right">{format!("${:.2}", cache_cost)}</td>
-                                <td class="costs-table__right costs-table__highlight">{format!("${:.2}", usage.cost_usd)}</td>
+                                <td class="costs-table__right">{currency.format_cost(input_cost)}</td>
+                                <td class="costs-table__right">{currency.format_cost(output_cost)}</td>
+                                <td class="costs-table__right">{currency.format_cost(cache_cost)}</td>
+                                <td class="costs-table__right costs-table__highlight">{currency.format_cost(usage.cost_usd)}</td>
                             </tr>
                         }
                     }).collect::<Vec<_>>()}
@@ -302,6 +305,7 @@ fn CostsByModel(stats: StatsData) -> impl IntoView {
 /// Daily tab
 #[component]
 fn CostsDaily(stats: StatsData) -> impl IntoView {
+    let currency = use_currency();
     // Get last 14 days from daily_activity (clone to avoid lifetime issues)
     let start = stats.daily_activity.len().saturating_sub(14);
     let daily_data: Vec<_> = stats.daily_activity[start..].to_vec();
@@ -322,7 +326,7 @@ fn CostsDaily(stats: StatsData) -> impl IntoView {
 
                     view! {
                         <div class="costs-daily__bar">
-                            <span class="costs-daily__value">{format!("${:.2}", cost)}</span>
+                            <span class="costs-daily__value">{currency.format_cost(cost)}</span>
                             <div class="costs-daily__bar-fill" style=format!("height: {}%", height_pct)></div>
                             <span class="costs-daily__label">{date_label}</span>
                         </div>
@@ -336,6 +340,7 @@ fn CostsDaily(stats: StatsData) -> impl IntoView {
 /// Billing Blocks tab - shows cost breakdown by 5-hour blocks
 #[component]
 fn CostsBillingBlocks(stats: StatsData) -> impl IntoView {
+    let currency = use_currency();
     // Get last 7 days from daily_activity
     let start = stats.daily_activity.len().saturating_sub(7);
     let daily_data: Vec<_> = stats.daily_activity[start..].to_vec();
@@ -421,7 +426,7 @@ fn CostsBillingBlocks(stats: StatsData) -> impl IntoView {
                                         <span class="billing-block-period">{period}</span>
                                     </td>
                                     <td class="costs-table__right">{block_messages.to_string()}</td>
-                                    <td class="costs-table__right">{format!("${:.2}", block_cost)}</td>
+                                    <td class="costs-table__right">{currency.format_cost(block_cost)}</td>
                                     <td class="costs-table__right">{format!("{:.0}%", percentage)}</td>
                                 </tr>
                             }
@@ -1,7 +1,8 @@
 //! Dashboard page component
 
-use crate::api::{fetch_recent_sessions, fetch_stats, format_cost, format_number};
+use crate::api::{fetch_recent_sessions, fetch_stats, format_number};
 use crate::components::{use_toast, CardColor, Sparkline, StatsCard};
+use crate::currency::use_currency;
 use crate::sse_hook::{use_sse, SseEvent};
 use crate::utils::export_as_json;
 use leptos::prelude::*;
@@ -81,6 +82,7 @@ fn elapsed(start_time: &Option<String>) -> String {
 /// Dashboard page - main overview with live stats
 #[component]
 pub fn Dashboard() -> impl IntoView {
+    let currency = use_currency();
     // Stats resource for initial load and manual refresh
     let (stats_version, set_stats_version) = signal(0u32);
     let stats = LocalResource::new(move || {
@@ -144,6 +146,11 @@ pub fn Dashboard() -> impl IntoView {
                 SseEvent::WatcherError { message } => {
                     toast.error(format!("Watcher error: {}", message));
                 }
+                SseEvent::Reconnected => {
+                    // Events may have been missed while offline
+                    set_stats_version.update(|v| *v += 1);
+                    toast.info("Live updates reconnected".to_string());
+                }
                 _ => {}
             }
         }
@@ -176,6 +183,7 @@ pub fn Dashboard() -> impl IntoView {
                             let this_month = data.this_month_sessions();
                             let this_week = data.this_week_tokens();
                             let daily_tokens = data.daily_tokens_30d();
+                            let project_sparklines = data.project_sparklines.clone();
 
                             // Additional KPIs
                             let total_messages = data.total_messages;
@@ -219,7 +227,7 @@ pub fn Dashboard() -> impl IntoView {
                                         />
                                         <StatsCard
                                             label="Total Cost".to_string()
-                                            value=format_cost(total_cost)
+                                            value=currency.format_cost(total_cost)
                                             icon="💰".to_string()
                                             color=cost_color
                                             on_click=Box::new(move || {
@@ -228,7 +236,7 @@ pub fn Dashboard() -> impl IntoView {
                                         />
                                         <StatsCard
                                             label="Avg Session Cost".to_string()
-                                            value=format_cost(avg_cost)
+                                            value=currency.format_cost(avg_cost)
                                             icon="📈".to_string()
                                             color=CardColor::Default
                                             on_click=Box::new(move || {
@@ -285,6 +293,28 @@ pub fn Dashboard() -> impl IntoView {
                                         />
                                     </div>
 
+                                    {(!project_sparklines.is_empty()).then(|| view! {
+                                        <div class="sparkline-section project-sparklines">
+                                            <h3>"Top Projects - Last 14 Days"</h3>
+                                            <div class="project-sparklines-grid">
+                                                {project_sparklines.into_iter().map(|p| {
+                                                    let name = project_name(&Some(p.project.clone()));
+                                                    let total: u64 = p.daily_tokens.iter().sum();
+                                                    view! {
+                                                        <div title=p.project.clone()>
+                                                            <Sparkline
+                                                                data=p.daily_tokens
+                                                                width=240
+                                                                height=40
+                                                                label=format!("{} · {}", name, format_number(total))
+                                                            />
+                                                        </div>
+                                                    }
+                                                }).collect_view()}
+                                            </div>
+                                        </div>
+                                    })}
+
                                     // Live sessions panel
                                     <div class="live-sessions-section">
                                         <div class="live-sessions-header">
@@ -387,7 +417,7 @@ pub fn Dashboard() -> impl IntoView {
                                                                                         </div>
                                                                                         <div class="preview-stats">
                                                                                             <span>{format_number(tokens)} " tokens"</span>
-                                                                                            <span>{format_cost(cost)}</span>
+                                                                                            <span>{currency.format_cost(cost)}</span>
                                                                                             <span>{messages} " messages"</span>
                                                                                         </div>
                                                                                         <div class="preview-snippet">
@@ -11,14 +11,14 @@ const API_BASE_URL: &str = "";
 /// Sessions response
 #[derive(Debug, Clone, Deserialize, serde::Serialize)]
 struct SessionsResponse {
-    sessions: Vec<SessionData>,
+    items: Vec<SessionData>,
     total: u64,
 }
 
 /// Fetch sessions for history timeline
 async fn fetch_history(since: String) -> Result<SessionsResponse, String> {
     let url = format!(
-        "{}/api/sessions?page=0&limit=100&since={}&sort=date&order=desc",
+        "{}/api/sessions?offset=0&limit=100&since={}&sort=date&order=desc",
         API_BASE_URL, since
     );
     let response = gloo_net::http::Request::get(&url)
@@ -102,7 +102,7 @@ pub fn History() -> impl IntoView {
                         .get()
                         .map(|result| match result.as_ref() {
                             Ok(data) => {
-                                let sessions = data.sessions.clone();
+                                let sessions = data.items.clone();
                                 let sessions_signal = Signal::derive(move || Some(sessions.clone()));
 
                                 view! {
@@ -4,6 +4,7 @@ mod activity;
 mod agents;
 mod analytics;
 mod brain;
+mod comparison;
 mod config;
 mod costs;
 mod dashboard;
@@ -19,6 +20,7 @@ pub use activity::ActivityPage;
 pub use agents::Agents;
 pub use analytics::Analytics;
 pub use brain::Brain;
+pub use comparison::ComparisonPage;
 pub use config::Config;
 pub use costs::Costs;
 pub use dashboard::Dashboard;
@@ -1,5 +1,7 @@
 //! Plugins page - displays plugin usage analytics (Skills, MCP, Agents, Commands, Native Tools)
+//! and the load status of installed Claude Code plugins
 
+use crate::currency::use_currency;
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -55,10 +57,21 @@ pub struct PluginAnalytics {
     pub computed_at: String, // ISO8601 timestamp
 }
 
+/// An installed Claude Code plugin and whether it can load
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstalledPlugin {
+    pub name: String,
+    pub enabled: bool,
+    pub path: Option<String>,
+    pub error: Option<String>,
+}
+
 /// API response wrapper
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginsResponse {
     pub analytics: PluginAnalytics,
+    #[serde(default)]
+    pub installed: Vec<InstalledPlugin>,
     pub generated_at: String,
 }
 
@@ -127,12 +140,14 @@ fn PluginListItem(plugin: PluginUsage, rank: usize) -> impl IntoView {
 fn PluginCostItem(plugin: PluginUsage, rank: usize) -> impl IntoView {
     let icon = plugin.icon.clone();
     let type_label = plugin.plugin_type.label();
-    let cost_display = if plugin.total_cost >= 100.0 {
-        format!("{:.0}", plugin.total_cost)
-    } else if plugin.total_cost >= 10.0 {
-        format!("{:.1}", plugin.total_cost)
+    let currency = use_currency();
+    let total_cost = plugin.total_cost;
+    let decimals = if total_cost >= 100.0 {
+        0
+    } else if total_cost >= 10.0 {
+        1
     } else {
-        format!("{:.2}", plugin.total_cost)
+        2
     };
 
     view! {
@@ -144,7 +159,7 @@ fn PluginCostItem(plugin: PluginUsage, rank: usize) -> impl IntoView {
                 <span class="plugin-item__type">{type_label}</span>
             </div>
             <div class="plugin-item__stat plugin-item__stat--cost">
-                <span class="plugin-item__stat-value">"$"{cost_display}</span>
+                <span class="plugin-item__stat-value">{move || currency.format(total_cost, decimals)}</span>
                 <span class="plugin-item__stat-label">{plugin.total_invocations}" uses"</span>
             </div>
         </div>
@@ -169,6 +184,37 @@ fn DeadCodeItem(name: String) -> impl IntoView {
     }
 }
 
+/// Installed plugin row: status badge, name and install path
+#[component]
+fn InstalledPluginItem(plugin: InstalledPlugin) -> impl IntoView {
+    let (icon, badge_class, label) = match (&plugin.error, plugin.enabled) {
+        (Some(_), _) => ("⚠️", "badge badge-error", "error"),
+        (None, true) => ("✅", "badge badge-success", "enabled"),
+        (None, false) => ("⏸️", "badge badge-default", "disabled"),
+    };
+    let detail = plugin
+        .error
+        .clone()
+        .or(plugin.path.clone())
+        .unwrap_or_default();
+    let detail_class = if plugin.error.is_some() {
+        "plugin-item__path plugin-item__path--error"
+    } else {
+        "plugin-item__path"
+    };
+
+    view! {
+        <div class="plugin-item plugin-item--installed" title=plugin.path.unwrap_or_default()>
+            <span class="plugin-item__icon">{icon}</span>
+            <div class="plugin-item__info">
+                <span class="plugin-item__name">{plugin.name}</span>
+                <span class=detail_class>{detail}</span>
+            </div>
+            <span class=badge_class>{label}</span>
+        </div>
+    }
+}
+
 /// Plugins page component
 #[component]
 pub fn PluginsPage() -> impl IntoView {
@@ -188,6 +234,7 @@ pub fn PluginsPage() -> impl IntoView {
                     match plugin_data.await {
                         Ok(response) => {
                             let analytics = response.analytics;
+                            let installed = response.installed;
                             let active_pct = if analytics.total_plugins > 0 {
                                 (analytics.active_plugins as f64 / analytics.total_plugins as f64) * 100.0
                             } else {
@@ -285,6 +332,26 @@ pub fn PluginsPage() -> impl IntoView {
                                             </div>
                                         </div>
                                     </div>
+
+                                    // Installed Claude Code plugins
+                                    <div class="plugins-column plugins-installed">
+                                        <div class="plugins-column__header">
+                                            <h2 class="plugins-column__title">"Installed Plugins"</h2>
+                                        </div>
+                                        <div class="plugins-column__content">
+                                            {if installed.is_empty() {
+                                                view! {
+                                                    <div class="empty-state">
+                                                        <p>"No plugins installed"</p>
+                                                    </div>
+                                                }.into_any()
+                                            } else {
+                                                installed.into_iter().map(|plugin| {
+                                                    view! { <InstalledPluginItem plugin=plugin /> }
+                                                }).collect_view().into_any()
+                                            }}
+                                        </div>
+                                    </div>
                                 </div>
                             }.into_any()
                         }
@@ -1,7 +1,8 @@
 //! Sessions Explorer page component with server-side pagination
 
 use crate::api::SessionData;
-use crate::components::{SessionDetailModal, SessionTable};
+use crate::components::{SessionDetailModal, SessionTable, SortColumn, SortDirection};
+use crate::currency::use_currency;
 use crate::utils::{export_as_csv, export_as_json};
 use leptos::prelude::*;
 use serde::Deserialize;
@@ -10,13 +11,16 @@ use wasm_bindgen::JsCast;
 /// API base URL constant (empty = relative URL, same origin)
 const API_BASE_URL: &str = "";
 
+/// Sessions fetched per page
+const PAGE_SIZE: usize = 50;
+
 /// API response for paginated sessions
 #[derive(Debug, Clone, Deserialize)]
 struct SessionsResponse {
-    sessions: Vec<SessionData>,
+    items: Vec<SessionData>,
     total: u64,
-    page: usize,
-    page_size: usize,
+    offset: usize,
+    limit: usize,
 }
 
 /// Live session data structure
@@ -63,15 +67,24 @@ async fn fetch_live_sessions() -> Result<LiveSessionsResponse, String> {
     Ok(data)
 }
 
-/// Fetch sessions from API with pagination and filters
+/// Fetch sessions from API with pagination, server-side sorting and filters
 async fn fetch_sessions(
     page: usize,
+    sort: (SortColumn, SortDirection),
     search: String,
     project: Option<String>,
     model: Option<String>,
     date_filter: Option<String>,
 ) -> Result<SessionsResponse, String> {
-    let mut url = format!("{}/api/sessions?page={}&limit=50", API_BASE_URL, page);
+    // Columns the API can't sort by keep date order and are sorted within the page
+    let mut url = format!(
+        "{}/api/sessions?offset={}&limit={}&sort={}&order={}",
+        API_BASE_URL,
+        page * PAGE_SIZE,
+        PAGE_SIZE,
+        sort.0.api_param().unwrap_or("date"),
+        sort.1.api_param()
+    );
 
     if !search.is_empty() {
         // Simple URL encoding: replace spaces with %20
@@ -110,12 +123,14 @@ async fn fetch_sessions(
 /// Sessions Explorer page
 #[component]
 pub fn Sessions() -> impl IntoView {
+    let currency = use_currency();
     // Filter state
     let (search, set_search) = signal(String::new());
     let (project_filter, set_project_filter) = signal(None::<String>);
     let (model_filter, set_model_filter) = signal(None::<String>);
     let (date_filter, set_date_filter) = signal(None::<String>);
     let (current_page, set_current_page) = signal(0usize);
+    let sort_state = RwSignal::new((SortColumn::Date, SortDirection::Desc));
 
     // Quick filters state (client-side filtering)
     let (cost_filter, set_cost_filter) = signal(None::<f64>); // Min cost threshold
@@ -134,12 +149,22 @@ pub fn Sessions() -> impl IntoView {
     // Fetch sessions data
     let sessions_resource = LocalResource::new(move || {
         let page = current_page.get();
+        let sort = sort_state.get();
         let search = search_debounced.get();
         let project = project_filter.get();
         let model = model_filter.get();
         let date = date_filter.get();
 
-        async move { fetch_sessions(page, search, project, model, date).await }
+        async move { fetch_sessions(page, sort, search, project, model, date).await }
+    });
+
+    // Sorting reorders the whole result set, so restart from the first page
+    Effect::new(move |prev: Option<(SortColumn, SortDirection)>| {
+        let sort = sort_state.get();
+        if prev.is_some_and(|prev| prev != sort) {
+            set_current_page.set(0);
+        }
+        sort
     });
 
     // Modal state
@@ -153,7 +178,7 @@ pub fn Sessions() -> impl IntoView {
         if let Some(result) = sessions_resource.get() {
             // Dereference SendWrapper to access Result
             if let Ok(response) = &*result {
-                let mut sessions = response.sessions.clone();
+                let mut sessions = response.items.clone();
 
                 // Apply client-side filters
                 if let Some(min_cost) = cost_filter.get() {
@@ -205,7 +230,7 @@ pub fn Sessions() -> impl IntoView {
             .get()
             .as_ref()
             .and_then(|r| r.as_ref().ok())
-            .map(|resp| (resp.total as f64 / resp.page_size as f64).ceil() as usize)
+            .map(|resp| (resp.total as usize).div_ceil(resp.limit.max(1)))
             .unwrap_or(1)
     });
 
@@ -219,7 +244,7 @@ pub fn Sessions() -> impl IntoView {
                         on:click=move |_| {
                             if let Some(response) = sessions_resource.get() {
                                 if let Ok(response) = response.as_ref() {
-                                let sessions = response.sessions.clone();
+                                let sessions = response.items.clone();
                                 let headers = vec![
                                     "Date".to_string(),
                                     "Project".to_string(),
@@ -237,7 +262,7 @@ pub fn Sessions() -> impl IntoView {
                                             s.model.clone(),
                                             s.messages.to_string(),
                                             s.tokens.to_string(),
-                                            format!("${:.4}", s.cost),
+                                            currency.format(s.cost, 4),
                                         ]
                                     })
                                     .collect();
@@ -253,7 +278,7 @@ pub fn Sessions() -> impl IntoView {
                         on:click=move |_| {
                             if let Some(response) = sessions_resource.get() {
                                 if let Ok(response) = response.as_ref() {
-                                    export_as_json(&response.sessions, "ccboard-sessions");
+                                    export_as_json(&response.items, "ccboard-sessions");
                                 }
                             }
                         }
@@ -472,8 +497,7 @@ pub fn Sessions() -> impl IntoView {
                         sessions_resource.get().map(|result| match result.as_ref() {
                             Ok(response) => {
                                 let total = response.total;
-                                let page = response.page;
-                                let _page_size = response.page_size;
+                                let page = response.offset / response.limit.max(1);
 
                                 // Get filtered sessions count from sessions_data (set by Effect)
                                 let sessions_count = sessions_data.get()
@@ -491,6 +515,7 @@ pub fn Sessions() -> impl IntoView {
                                         <SessionTable
                                             sessions=Signal::derive(move || sessions_data.get())
                                             on_row_click=set_modal_session
+                                            server_sort=sort_state
                                         />
 
                                         // Pagination controls
@@ -1,6 +1,6 @@
-//! Task graph page - dependency visualization
+//! Task graph page - dependency visualization and session chains
 
-use crate::components::TaskDependencyGraph;
+use crate::components::{SessionChains, TaskDependencyGraph};
 use leptos::prelude::*;
 
 /// Task graph page component
@@ -9,6 +9,7 @@ pub fn TaskGraphPage() -> impl IntoView {
     view! {
         <div class="page task-graph-page">
             <TaskDependencyGraph />
+            <SessionChains />
         </div>
     }
 }
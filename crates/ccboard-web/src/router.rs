@@ -2,8 +2,9 @@
 
 use axum::{
     body::Body,
-    extract::Query,
-    http::{header, StatusCode, Uri},
+    extract::{ConnectInfo, FromRequestParts, Query, Request},
+    http::{header, HeaderMap, Method, StatusCode, Uri},
+    middleware::Next,
     response::{IntoResponse, Response},
     routing::{get, post},
     Router,
@@ -13,6 +14,7 @@ use ccboard_core::DataStore;
 use mime_guess::from_path;
 use rust_embed::RustEmbed;
 use serde::Deserialize;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
 
@@ -24,12 +26,16 @@ use tower_http::cors::{Any, CorsLayer};
 struct DistAssets;
 
 use crate::sse;
+use crate::ws;
 
 /// Query parameters for sessions pagination
 #[derive(Debug, Deserialize)]
 struct SessionsQuery {
+    /// Legacy page number, used when `offset` is absent (offset = page * limit)
     #[serde(default)]
     page: usize,
+    #[serde(default)]
+    offset: Option<usize>,
     #[serde(default = "default_page_size")]
     limit: usize,
     #[serde(default)]
@@ -41,7 +47,7 @@ struct SessionsQuery {
     #[serde(default)]
     since: Option<String>, // e.g., "7d", "30d"
     #[serde(default = "default_sort")]
-    sort: String, // "date", "tokens", "cost"
+    sort: String, // "date", "tokens", "messages", "cost"
     #[serde(default = "default_order")]
     order: String, // "asc", "desc"
 }
@@ -79,6 +85,27 @@ fn default_search_limit() -> usize {
     20
 }
 
+/// Query parameters for period comparison (`YYYY-MM` or `YYYY-MM-DD..YYYY-MM-DD`)
+#[derive(Debug, Deserialize)]
+struct ComparisonQuery {
+    a: String,
+    b: String,
+}
+
+/// Query parameters for analytics (`period=7d|30d|90d|all|custom`, with
+/// `start`/`end` as `YYYY-MM-DD` for `custom`)
+#[derive(Debug, Deserialize)]
+struct AnalyticsQuery {
+    #[serde(default = "default_analytics_period")]
+    period: String,
+    start: Option<String>,
+    end: Option<String>,
+}
+
+fn default_analytics_period() -> String {
+    "30d".to_string()
+}
+
 /// Query parameters for activity violations
 #[derive(Debug, Deserialize)]
 struct ViolationsQuery {
@@ -135,8 +162,97 @@ async fn frontend_handler(uri: Uri) -> Response {
         .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response())
 }
 
-/// Create the web router
+/// Server behaviour toggles set from the command line
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServerOptions {
+    /// Allow `POST /api/sessions/{id}/resume` to spawn `claude --resume`
+    pub allow_resume: bool,
+}
+
+/// Reject state-changing requests that don't come from this machine
+///
+/// The server listens on all interfaces with permissive CORS so the dashboard
+/// can be read from the LAN, but requests that write or run something (pin,
+/// reload, resume...) must come from a loopback peer and be addressed to a
+/// loopback host name, so a rebound DNS name pointing at 127.0.0.1 can't pass
+/// as local. When a browser sends an `Origin`, it must also match `Host`, so a
+/// page on another site can't make the user's browser post to the dashboard.
+async fn guard_mutations(request: Request, next: Next) -> Response {
+    if matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS
+    ) {
+        return next.run(request).await;
+    }
+
+    let (mut parts, body) = request.into_parts();
+    let peer = ConnectInfo::<SocketAddr>::from_request_parts(&mut parts, &())
+        .await
+        .ok()
+        .map(|ConnectInfo(addr)| addr);
+    let error = if !peer.is_some_and(|addr| addr.ip().to_canonical().is_loopback()) {
+        Some("Only allowed from the machine running ccboard")
+    } else if !is_loopback_host(&parts.headers, &parts.uri) {
+        Some("Only allowed on localhost, 127.0.0.1 or [::1]")
+    } else if !is_same_origin(&parts.headers) {
+        Some("Cross-origin request rejected")
+    } else {
+        None
+    };
+
+    match error {
+        Some(error) => (
+            StatusCode::FORBIDDEN,
+            axum::Json(serde_json::json!({ "ok": false, "error": error })),
+        )
+            .into_response(),
+        None => next.run(Request::from_parts(parts, body)).await,
+    }
+}
+
+/// `Host` (or the request authority) is a loopback name or address, with any port
+fn is_loopback_host(headers: &HeaderMap, uri: &Uri) -> bool {
+    let host = headers
+        .get(header::HOST)
+        .and_then(|h| h.to_str().ok())
+        .or_else(|| uri.authority().map(|a| a.as_str()));
+    let Some(host) = host else {
+        return false;
+    };
+    let name = match host.strip_prefix('[') {
+        // [::1]:port
+        Some(rest) => rest.split_once(']').map(|(ip, _)| ip).unwrap_or(rest),
+        None => host.rsplit_once(':').map(|(name, _)| name).unwrap_or(host),
+    };
+    name.eq_ignore_ascii_case("localhost")
+        || name
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.to_canonical().is_loopback())
+}
+
+/// `Origin` (if any) names the host the request was sent to
+///
+/// Non-browser clients (curl, scripts) send no `Origin` and are let through.
+fn is_same_origin(headers: &HeaderMap) -> bool {
+    let Some(origin) = headers.get(header::ORIGIN) else {
+        return true;
+    };
+    let origin_host = origin
+        .to_str()
+        .ok()
+        .and_then(|o| o.split_once("://"))
+        .map(|(_, host)| host.trim_end_matches('/'));
+    let host = headers.get(header::HOST).and_then(|h| h.to_str().ok());
+    matches!((origin_host, host), (Some(o), Some(h)) if o.eq_ignore_ascii_case(h))
+}
+
+/// Create the web router with default options
 pub fn create_router(store: Arc<DataStore>) -> Router {
+    create_router_with_options(store, ServerOptions::default())
+}
+
+/// Create the web router
+pub fn create_router_with_options(store: Arc<DataStore>, options: ServerOptions) -> Router {
     let cors = CorsLayer::new()
         .allow_origin(Any)
         .allow_methods(Any)
@@ -147,22 +263,30 @@ pub fn create_router(store: Arc<DataStore>) -> Router {
         .route("/api/search", get(search_handler))
         .route("/api/stats", get(stats_handler))
         .route("/api/quota", get(quota_handler))
+        .route("/api/comparison", get(comparison_handler))
         .route("/api/sessions/recent", get(recent_sessions_handler)) // Must be before /api/sessions
         .route("/api/sessions/live", get(live_sessions_handler)) // Live sessions with CPU/RAM
+        .route("/api/sessions/pinned", get(pinned_sessions_handler))
         .route("/api/sessions", get(sessions_handler))
+        .route("/api/sessions/{id}", get(session_detail_handler))
+        .route("/api/sessions/{id}/resume", post(resume_session_handler))
+        .route("/api/sessions/{id}/pin", post(toggle_pin_handler))
         .route("/api/config/merged", get(config_handler))
+        .route("/api/currency", get(currency_handler))
         .route("/api/hooks", get(hooks_handler))
         .route("/api/mcp", get(mcp_handler))
         .route("/api/agents", get(agents_handler))
         .route("/api/commands", get(commands_handler))
         .route("/api/skills", get(skills_handler))
         .route("/api/plugins", get(plugins_handler))
+        .route("/api/analytics", get(analytics_handler))
         .route(
             "/api/analytics/suggestions",
             get(analytics_suggestions_handler),
         )
         .route("/api/analytics/tool-stats", get(tool_stats_handler))
         .route("/api/task-graph", get(task_graph_handler))
+        .route("/api/session-chains", get(session_chains_handler))
         .route("/api/insights", get(insights_handler))
         .route(
             "/api/claude-mem/summaries",
@@ -170,12 +294,18 @@ pub fn create_router(store: Arc<DataStore>) -> Router {
         )
         .route("/api/claude-mem/toggle", post(claude_mem_toggle_handler))
         .route("/api/health", get(health_handler))
+        .route("/api/reload", post(reload_handler))
         // Activity routes — literal path before parameterised path
         .route("/api/activity/violations", get(activity_violations_handler))
         .route("/api/activity/{session_id}", get(activity_session_handler))
         .route("/api/events", get(sse_handler))
+        .route("/api/ws", get(ws_handler))
+        // Prometheus scrape target
+        .route("/metrics", get(metrics_handler))
         // Serve WASM frontend (embedded in binary) + SPA fallback to index.html
         .fallback(frontend_handler)
+        .layer(axum::Extension(options))
+        .layer(axum::middleware::from_fn(guard_mutations))
         .layer(cors)
         .with_state(store)
 }
@@ -185,12 +315,14 @@ async fn stats_handler(
 ) -> axum::Json<serde_json::Value> {
     let stats = store.stats();
 
-    // Compute analytics for last 30 days
-    let sessions = store.all_sessions();
-    let analytics = ccboard_core::analytics::AnalyticsData::compute(
-        &sessions,
-        ccboard_core::analytics::Period::last_30d(),
-    );
+    // Compute analytics for last 30 days (offloaded to a blocking thread)
+    let sessions = store.aggregate_sessions();
+    let Some(analytics) = store
+        .analytics_for(ccboard_core::analytics::Period::last_30d())
+        .await
+    else {
+        return axum::Json(serde_json::json!({ "error": "Failed to compute analytics" }));
+    };
 
     // Extract forecast data points for chart
     let historical_tokens: Vec<u64> = analytics.trends.daily_tokens.clone();
@@ -328,6 +460,28 @@ async fn stats_handler(
                 // Add MCP servers count
                 let mcp_count = store.mcp_config().map(|c| c.servers.len()).unwrap_or(0);
                 obj.insert("mcpServersCount".to_string(), serde_json::json!(mcp_count));
+
+                // Busiest projects with their daily tokens over the last 14 days
+                let project_sparklines: Vec<serde_json::Value> = store
+                    .top_project_daily_tokens(store.dashboard_projects(), 14)
+                    .into_iter()
+                    .map(|(project, daily_tokens)| {
+                        serde_json::json!({
+                            "project": project,
+                            "dailyTokens": daily_tokens,
+                        })
+                    })
+                    .collect();
+                obj.insert(
+                    "projectSparklines".to_string(),
+                    serde_json::json!(project_sparklines),
+                );
+
+                // Projects left out of aggregates via excludeProjects
+                obj.insert(
+                    "excludedProjects".to_string(),
+                    serde_json::json!(store.excluded_project_count()),
+                );
             }
 
             axum::Json(value)
@@ -365,6 +519,28 @@ async fn quota_handler(
     }
 }
 
+/// Period comparison handler - side-by-side metrics, daily series and deltas
+async fn comparison_handler(
+    Query(params): Query<ComparisonQuery>,
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+) -> axum::Json<serde_json::Value> {
+    use ccboard_core::analytics::DateRange;
+
+    let (a, b) = match (DateRange::parse(&params.a), DateRange::parse(&params.b)) {
+        (Ok(a), Ok(b)) => (a, b),
+        (Err(e), _) | (_, Err(e)) => return axum::Json(serde_json::json!({ "error": e })),
+    };
+    let comparison = store.compare_periods(&a, &b);
+
+    axum::Json(serde_json::json!({
+        "a": comparison.a,
+        "b": comparison.b,
+        "metrics_a": comparison.metrics_a,
+        "metrics_b": comparison.metrics_b,
+        "deltas": comparison.deltas(),
+    }))
+}
+
 /// Recent sessions handler (lightweight, for dashboard)
 async fn recent_sessions_handler(
     Query(params): Query<RecentQuery>,
@@ -378,7 +554,7 @@ async fn recent_sessions_handler(
     let sessions: Vec<_> = all_sessions
         .iter()
         .take(params.limit)
-        .map(|s| session_to_json(s))
+        .map(|s| session_to_json(s, &store))
         .collect();
 
     axum::Json(serde_json::json!({
@@ -387,13 +563,204 @@ async fn recent_sessions_handler(
     }))
 }
 
+/// Resume a session in the Claude CLI on the machine running the server
+///
+/// Spawns `claude --resume <id>` in the session's project directory, like
+/// `ccboard resume`, detached from the server's terminal. Returns 403 unless
+/// the server was started with `--allow-resume`, since it runs a process on
+/// the host (and [`guard_mutations`] restricts it to loopback, same-origin
+/// requests). If claude exits within [`RESUME_GRACE`] the resume is reported
+/// as failed; either way the response carries the `claude --resume <id>`
+/// command so the client can offer it for copying.
+///
+/// POST /api/sessions/{id}/resume
+async fn resume_session_handler(
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+    axum::Extension(options): axum::Extension<ServerOptions>,
+    axum::extract::Path(id): axum::extract::Path<String>,
+) -> Response {
+    if !options.allow_resume {
+        return (
+            StatusCode::FORBIDDEN,
+            axum::Json(serde_json::json!({
+                "ok": false,
+                "error": "Resume is disabled. Restart ccboard with --allow-resume to enable it.",
+            })),
+        )
+            .into_response();
+    }
+
+    let Some(session) = store.get_session(&id) else {
+        return (
+            StatusCode::NOT_FOUND,
+            axum::Json(serde_json::json!({
+                "ok": false,
+                "error": format!("Session not found: {}", id),
+            })),
+        )
+            .into_response();
+    };
+
+    // Detached from ccboard's terminal: in `both` mode the TUI owns the tty
+    let mut command = std::process::Command::new("claude");
+    command
+        .args(["--resume", &session.id])
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+    let cwd = std::path::Path::new(session.project_path.as_str());
+    if cwd.is_dir() {
+        command.current_dir(cwd);
+    }
+
+    let manual = format!("claude --resume {}", session.id);
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            return resume_error(
+                format!("Failed to spawn claude (is 'claude' in PATH?): {}", e),
+                &manual,
+            )
+        }
+    };
+
+    // Without a terminal claude may quit straight away; don't report that as success
+    let deadline = tokio::time::Instant::now() + RESUME_GRACE;
+    while tokio::time::Instant::now() < deadline {
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                return resume_error(
+                    format!(
+                        "claude exited immediately ({}); run it in a terminal",
+                        status
+                    ),
+                    &manual,
+                )
+            }
+            Ok(None) => tokio::time::sleep(std::time::Duration::from_millis(100)).await,
+            Err(e) => return resume_error(format!("Failed to watch claude: {}", e), &manual),
+        }
+    }
+
+    if let Err(e) = store.record_resume(&session.id) {
+        tracing::warn!(error = %e, "Failed to record session resume");
+    }
+    // Reap the child when it exits so it doesn't linger as a zombie
+    tokio::task::spawn_blocking(move || child.wait());
+    axum::Json(serde_json::json!({
+        "ok": true,
+        "id": session.id.to_string(),
+        "project": session.project_path.as_str(),
+        "command": manual,
+    }))
+    .into_response()
+}
+
+/// How long a resumed claude must stay up before the resume counts as started
+const RESUME_GRACE: std::time::Duration = std::time::Duration::from_millis(1500);
+
+/// Failed resume, with the command to run by hand
+fn resume_error(error: String, command: &str) -> Response {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        axum::Json(serde_json::json!({
+            "ok": false,
+            "error": error,
+            "command": command,
+        })),
+    )
+        .into_response()
+}
+
+/// One session, with its cost broken down per token kind and model
+///
+/// GET /api/sessions/{id}
+async fn session_detail_handler(
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+    axum::extract::Path(id): axum::extract::Path<String>,
+) -> Response {
+    let Some(session) = store.get_session(&id) else {
+        return (
+            StatusCode::NOT_FOUND,
+            axum::Json(serde_json::json!({
+                "error": format!("Session not found: {}", id),
+            })),
+        )
+            .into_response();
+    };
+
+    let mut json = session_to_json(&session, &store);
+    json["cost_breakdown"] = serde_json::json!(session.cost_breakdown());
+    axum::Json(json).into_response()
+}
+
+/// Pinned sessions that are still loaded, most recent first
+///
+/// GET /api/sessions/pinned
+async fn pinned_sessions_handler(
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+) -> axum::Json<serde_json::Value> {
+    let sessions: Vec<_> = store
+        .pinned_sessions()
+        .iter()
+        .map(|s| session_to_json(s, &store))
+        .collect();
+    axum::Json(serde_json::json!({ "sessions": sessions }))
+}
+
+/// Pin or unpin a session
+///
+/// Unpinning works for sessions that no longer exist, so stale pins can be
+/// cleared; pinning an unknown session is a 404.
+///
+/// POST /api/sessions/{id}/pin
+async fn toggle_pin_handler(
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+    axum::extract::Path(id): axum::extract::Path<String>,
+) -> Response {
+    if !store.is_pinned(&id) && store.get_session(&id).is_none() {
+        return (
+            StatusCode::NOT_FOUND,
+            axum::Json(serde_json::json!({
+                "ok": false,
+                "error": format!("Session not found: {}", id),
+            })),
+        )
+            .into_response();
+    }
+
+    match store.toggle_pin(&id) {
+        Ok(pinned) => axum::Json(serde_json::json!({
+            "ok": true,
+            "id": id,
+            "pinned": pinned,
+        }))
+        .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            axum::Json(serde_json::json!({
+                "ok": false,
+                "error": format!("Failed to save pins: {:#}", e),
+            })),
+        )
+            .into_response(),
+    }
+}
+
 /// Live sessions handler - returns active Claude Code processes with CPU/RAM
-async fn live_sessions_handler() -> axum::Json<serde_json::Value> {
-    use ccboard_core::detect_live_sessions;
+async fn live_sessions_handler(
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+) -> axum::Json<serde_json::Value> {
+    use ccboard_core::detect_live_sessions_with;
 
     const MAX_LIVE_SESSIONS: usize = 20;
 
-    match detect_live_sessions() {
+    match detect_live_sessions_with(&store.live_monitor_config()) {
         Ok(live_sessions) => {
             let total = live_sessions.len();
             let truncated = total > MAX_LIVE_SESSIONS;
@@ -438,16 +805,19 @@ async fn sessions_handler(
 ) -> axum::Json<serde_json::Value> {
     let mut all_sessions = store.all_sessions();
 
-    // Filter by search
+    // Filter by search (`tag:<name>` words filter by tag)
     if let Some(ref search) = params.search {
-        let search_lower = search.to_lowercase();
+        let (tags, text) = ccboard_core::tags::parse_tag_filters(search);
+        let search_lower = text.to_lowercase();
         all_sessions.retain(|s| {
-            s.id.to_lowercase().contains(&search_lower)
-                || s.project_path.to_lowercase().contains(&search_lower)
-                || s.first_user_message
-                    .as_ref()
-                    .map(|m| m.to_lowercase().contains(&search_lower))
-                    .unwrap_or(false)
+            let session_tags = store.tags_for(&s.id);
+            tags.iter().all(|tag| session_tags.contains(tag))
+                && (s.id.to_lowercase().contains(&search_lower)
+                    || s.project_path.to_lowercase().contains(&search_lower)
+                    || s.first_user_message
+                        .as_ref()
+                        .map(|m| m.to_lowercase().contains(&search_lower))
+                        .unwrap_or(false))
         });
     }
 
@@ -468,71 +838,68 @@ async fn sessions_handler(
         }
     }
 
-    // Sort
-    match params.sort.as_str() {
-        "date" => all_sessions.sort_by(|a, b| {
-            if params.order == "asc" {
-                a.last_timestamp.cmp(&b.last_timestamp)
-            } else {
-                b.last_timestamp.cmp(&a.last_timestamp)
-            }
-        }),
-        "tokens" => all_sessions.sort_by(|a, b| {
-            if params.order == "asc" {
-                a.total_tokens.cmp(&b.total_tokens)
-            } else {
-                b.total_tokens.cmp(&a.total_tokens)
-            }
-        }),
-        "cost" => all_sessions.sort_by(|a, b| {
-            let cost_a = calculate_session_cost(
-                a.input_tokens,
-                a.output_tokens,
-                a.cache_creation_tokens,
-                a.cache_read_tokens,
-                &a.models_used,
-            );
-            let cost_b = calculate_session_cost(
-                b.input_tokens,
-                b.output_tokens,
-                b.cache_creation_tokens,
-                b.cache_read_tokens,
-                &b.models_used,
-            );
-            if params.order == "asc" {
-                cost_a
-                    .partial_cmp(&cost_b)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            } else {
-                cost_b
-                    .partial_cmp(&cost_a)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            }
-        }),
-        _ => {} // Keep current order
-    }
+    sort_sessions(&mut all_sessions, &params.sort, params.order == "asc");
 
     let total = all_sessions.len();
-    let page_size = params.limit.min(100); // Cap at 100
-    let offset = params.page * page_size;
+    let limit = params.limit.min(100); // Cap at 100
+    let offset = params.offset.unwrap_or(params.page * limit);
 
-    let sessions: Vec<_> = all_sessions
+    let items: Vec<_> = all_sessions
         .iter()
         .skip(offset)
-        .take(page_size)
-        .map(|s| session_to_json(s))
+        .take(limit)
+        .map(|s| session_to_json(s, &store))
         .collect();
 
     axum::Json(serde_json::json!({
-        "sessions": sessions,
         "total": total as u64,
-        "page": params.page,
-        "page_size": page_size,
+        "items": items,
+        "offset": offset,
+        "limit": limit,
     }))
 }
 
+/// Sort sessions by an API sort key (`date`, `tokens`, `messages`, `cost`)
+///
+/// Unknown keys keep the store order.
+fn sort_sessions(
+    sessions: &mut [Arc<ccboard_core::models::SessionMetadata>],
+    sort: &str,
+    ascending: bool,
+) {
+    let apply = |ordering: std::cmp::Ordering| {
+        if ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    };
+    match sort {
+        "date" => sessions.sort_by(|a, b| apply(a.last_timestamp.cmp(&b.last_timestamp))),
+        "tokens" => sessions.sort_by(|a, b| apply(a.total_tokens.cmp(&b.total_tokens))),
+        "messages" => sessions.sort_by(|a, b| apply(a.message_count.cmp(&b.message_count))),
+        "cost" => {
+            let cost = |s: &ccboard_core::models::SessionMetadata| {
+                calculate_session_cost(
+                    s.input_tokens,
+                    s.output_tokens,
+                    s.cache_creation_tokens,
+                    s.cache_read_tokens,
+                    &s.models_used,
+                )
+            };
+            sessions.sort_by(|a, b| apply(cost(a).total_cmp(&cost(b))))
+        }
+        _ => {} // Keep current order
+    }
+}
+
 /// Convert session to JSON (shared helper)
-fn session_to_json(s: &ccboard_core::models::SessionMetadata) -> serde_json::Value {
+fn session_to_json(
+    s: &ccboard_core::models::SessionMetadata,
+    store: &DataStore,
+) -> serde_json::Value {
+    let token_source = store.token_source();
     let cost = calculate_session_cost(
         s.input_tokens,
         s.output_tokens,
@@ -547,16 +914,22 @@ fn session_to_json(s: &ccboard_core::models::SessionMetadata) -> serde_json::Val
         "project": s.project_path,
         "model": s.models_used.first().map(|s| s.as_str()).unwrap_or("unknown"),
         "messages": s.message_count,
-        "tokens": s.total_tokens,
+        "tokens": s.tokens_for(token_source),
+        "tokens_estimated": s.tokens_estimated(token_source),
         "input_tokens": s.input_tokens,
         "output_tokens": s.output_tokens,
         "cache_creation_tokens": s.cache_creation_tokens,
         "cache_read_tokens": s.cache_read_tokens,
         "cost": cost,
+        "estimated_cost": s.estimated_cost(),
+        "cost_per_message": s.cost_per_message(),
+        "cost_per_token": s.cost_per_token(),
+        "cost_approximate": s.is_multi_model(),
         "status": "completed",
         "first_timestamp": s.first_timestamp.map(|t: chrono::DateTime<chrono::Utc>| t.to_rfc3339()),
         "duration_seconds": s.duration_seconds,
         "preview": s.first_user_message,
+        "tags": store.tags_for(&s.id),
     })
 }
 
@@ -597,6 +970,7 @@ async fn plugins_handler(
     // Return JSON response
     axum::Json(serde_json::json!({
         "analytics": analytics,
+        "installed": store.plugins(),
         "generated_at": Utc::now().to_rfc3339(),
     }))
 }
@@ -645,7 +1019,12 @@ async fn analytics_suggestions_handler(
         })
         .sum();
 
-    let suggestions = generate_cost_suggestions(&plugin_analytics, &tool_token_usage, total_cost);
+    let suggestions = generate_cost_suggestions(
+        &plugin_analytics,
+        &tool_token_usage,
+        total_cost,
+        &store.currency(),
+    );
 
     axum::Json(serde_json::json!({
         "suggestions": suggestions,
@@ -655,6 +1034,42 @@ async fn analytics_suggestions_handler(
     }))
 }
 
+/// Full analytics handler: trends, forecast, usage patterns, insights and
+/// anomalies for the requested period
+///
+/// Computed on demand without replacing the analytics cached for the TUI.
+/// `computed_at` lets clients cache the response.
+///
+/// GET /api/analytics?period=7d|30d|90d|all|custom&start=YYYY-MM-DD&end=YYYY-MM-DD
+async fn analytics_handler(
+    Query(params): Query<AnalyticsQuery>,
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+) -> Response {
+    let period = match ccboard_core::analytics::Period::parse(
+        &params.period,
+        params.start.as_deref(),
+        params.end.as_deref(),
+    ) {
+        Ok(period) => period,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                axum::Json(serde_json::json!({ "error": e })),
+            )
+                .into_response()
+        }
+    };
+
+    match store.analytics_for(period).await {
+        Some(data) => axum::Json(data).into_response(),
+        None => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            axum::Json(serde_json::json!({ "error": "Failed to compute analytics" })),
+        )
+            .into_response(),
+    }
+}
+
 /// Per-tool token and cost efficiency metrics handler
 ///
 /// Returns per-tool breakdown of token usage, call counts, and cost attribution
@@ -664,11 +1079,12 @@ async fn analytics_suggestions_handler(
 async fn tool_stats_handler(
     axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
 ) -> axum::Json<serde_json::Value> {
-    let sessions = store.all_sessions();
-    let analytics = ccboard_core::analytics::AnalyticsData::compute(
-        &sessions,
-        ccboard_core::analytics::Period::last_30d(),
-    );
+    let Some(analytics) = store
+        .analytics_for(ccboard_core::analytics::Period::last_30d())
+        .await
+    else {
+        return axum::Json(serde_json::json!({ "error": "Failed to compute analytics" }));
+    };
 
     let total_tools = analytics.tool_token_stats.len();
     let tool_stats: Vec<serde_json::Value> = analytics
@@ -730,6 +1146,13 @@ async fn config_handler(
     axum::Json(serde_json::to_value(&settings).unwrap_or_default())
 }
 
+/// Display currency from settings, so the frontend formats costs like the TUI
+async fn currency_handler(
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+) -> axum::Json<ccboard_types::Currency> {
+    axum::Json(store.currency())
+}
+
 async fn health_handler(
     axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
 ) -> axum::Json<serde_json::Value> {
@@ -741,6 +1164,90 @@ async fn health_handler(
     }))
 }
 
+/// Re-scan everything from scratch (`DataStore::reload`) and return its report
+async fn reload_handler(
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+) -> axum::Json<serde_json::Value> {
+    let report = store.reload().await;
+    let errors: Vec<_> = report
+        .errors
+        .iter()
+        .map(|e| {
+            serde_json::json!({
+                "source": e.source,
+                "message": e.message,
+                "severity": format!("{:?}", e.severity).to_lowercase(),
+                "suggestion": e.suggestion,
+            })
+        })
+        .collect();
+
+    axum::Json(serde_json::json!({
+        "ok": !report.has_fatal_errors(),
+        "stats_loaded": report.stats_loaded,
+        "settings_loaded": report.settings_loaded,
+        "sessions_scanned": report.sessions_scanned,
+        "sessions_failed": report.sessions_failed,
+        "malformed_lines": report.malformed_lines,
+        "errors": errors,
+    }))
+}
+
+/// Prometheus text exposition of the headline numbers
+///
+/// Session, token and cost gauges cover the same sessions as `/api/stats`
+/// (excluded projects left out). `ccboard_live_sessions` is omitted when
+/// process detection fails, so a scrape doesn't record a false zero.
+async fn metrics_handler(
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+) -> Response {
+    let sessions = store.aggregate_sessions();
+    let tokens: u64 = sessions.iter().map(|s| s.total_tokens).sum();
+    // fold from +0.0: an empty f64 `sum()` is -0.0
+    let cost = sessions.iter().fold(0.0, |acc, s| acc + s.estimated_cost());
+    let live = ccboard_core::detect_live_sessions_with(&store.live_monitor_config())
+        .ok()
+        .map(|live| live.len());
+
+    let mut body = String::new();
+    let mut gauge = |name: &str, help: &str, value: String| {
+        body.push_str(&format!(
+            "# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n"
+        ));
+    };
+    gauge(
+        "ccboard_sessions_total",
+        "Sessions loaded",
+        sessions.len().to_string(),
+    );
+    gauge(
+        "ccboard_tokens_total",
+        "Tokens across all loaded sessions",
+        tokens.to_string(),
+    );
+    gauge(
+        "ccboard_estimated_cost_usd",
+        "Estimated cost of all loaded sessions in USD",
+        format!("{:.4}", cost),
+    );
+    if let Some(live) = live {
+        gauge(
+            "ccboard_live_sessions",
+            "Running Claude Code processes",
+            live.to_string(),
+        );
+    }
+
+    (
+        [(
+            header::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8",
+        )],
+        body,
+    )
+        .into_response()
+}
+
 /// FTS5 full-text search handler
 async fn search_handler(
     Query(params): Query<SearchQuery>,
@@ -780,6 +1287,15 @@ async fn sse_handler(
     sse::create_sse_stream(event_bus)
 }
 
+/// WebSocket endpoint for live updates (same events as SSE, plus subscriptions)
+async fn ws_handler(
+    ws: axum::extract::ws::WebSocketUpgrade,
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+) -> Response {
+    let event_bus = store.event_bus().clone();
+    ws.on_upgrade(move |socket| ws::handle_socket(socket, event_bus))
+}
+
 /// Hooks handler - returns all hooks from merged settings
 async fn hooks_handler(
     axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
@@ -1031,6 +1547,48 @@ fn scan_skills_recursive(dir_path: &std::path::Path) -> Vec<serde_json::Value> {
     items
 }
 
+/// Session continuation chains (resumed or inferred), most recent first
+///
+/// GET /api/session-chains
+async fn session_chains_handler(
+    axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
+) -> axum::Json<serde_json::Value> {
+    let chains: Vec<_> = store
+        .session_chains()
+        .iter()
+        .take(50)
+        .map(|chain| {
+            let nodes: Vec<_> = chain
+                .nodes
+                .iter()
+                .map(|node| {
+                    let session = store.get_session(&node.session_id);
+                    serde_json::json!({
+                        "session_id": node.session_id,
+                        "parent_id": node.parent_id,
+                        "link": node.link,
+                        "depth": node.depth,
+                        "started": session
+                            .as_ref()
+                            .and_then(|s| s.first_timestamp)
+                            .map(|t| t.to_rfc3339()),
+                        "messages": session.as_ref().map_or(0, |s| s.message_count),
+                        "preview": session.as_ref().and_then(|s| s.first_user_message.clone()),
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "project": chain.project,
+                "first_timestamp": chain.first_timestamp.map(|t| t.to_rfc3339()),
+                "last_timestamp": chain.last_timestamp.map(|t| t.to_rfc3339()),
+                "inferred": chain.has_inferred_links(),
+                "nodes": nodes,
+            })
+        })
+        .collect();
+    axum::Json(serde_json::json!({ "chains": chains }))
+}
+
 /// Task graph handler - returns task dependency graph from PLAN.md
 async fn task_graph_handler(
     axum::extract::State(store): axum::extract::State<Arc<DataStore>>,
@@ -1043,20 +1601,7 @@ async fn task_graph_handler(
     // Try multiple PLAN.md locations (prioritize working dir)
     let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
     let cwd = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
-
-    let possible_paths = vec![
-        // Current project claudedocs/PLAN.md (highest priority - dev dir)
-        cwd.join("claudedocs/PLAN.md"),
-        // Current project .claude/PLAN.md
-        cwd.join(".claude/PLAN.md"),
-        // Claude home claudedocs/PLAN.md
-        std::path::PathBuf::from(&home).join(".claude/claudedocs/PLAN.md"),
-    ];
-
-    let plan_path = possible_paths
-        .into_iter()
-        .find(|p| p.exists())
-        .unwrap_or_else(|| cwd.join("PLAN.md"));
+    let plan_path = PlanParser::locate(&cwd, std::path::Path::new(&home));
 
     let plan_result = PlanParser::parse_file(&plan_path);
 
@@ -1094,58 +1639,9 @@ async fn task_graph_handler(
 
     match plan_result {
         Ok(Some(plan)) => {
-            // Build task graph
-            let mut graph = TaskGraph::new();
-
-            // Add all tasks to graph
-            for phase in &plan.phases {
-                for task in &phase.tasks {
-                    graph.add_task(task.clone());
-                }
-            }
-
-            // Parse dependencies from PLAN.md content directly
-            // Read the file again to extract dependency information
-            if let Ok(plan_content) = std::fs::read_to_string(&plan_path) {
-                // Look for task sections and extract dependencies
-                for phase in &plan.phases {
-                    for task in &phase.tasks {
-                        // Find this task in the content
-                        let task_header = format!("#### Task {}:", task.id);
-                        if let Some(pos) = plan_content.find(&task_header) {
-                            // Extract content until next task or section
-                            let rest = &plan_content[pos..];
-                            // Use char boundary-safe slicing
-                            let skip_offset = rest
-                                .char_indices()
-                                .nth(100)
-                                .map(|(i, _)| i)
-                                .unwrap_or(rest.len());
-                            let end_pos = rest
-                                .get(skip_offset..)
-                                .and_then(|s| s.find("\n####").map(|p| p + skip_offset))
-                                .or_else(|| {
-                                    rest.get(skip_offset..)
-                                        .and_then(|s| s.find("\n###").map(|p| p + skip_offset))
-                                })
-                                .or_else(|| {
-                                    rest.get(skip_offset..)
-                                        .and_then(|s| s.find("\n##").map(|p| p + skip_offset))
-                                })
-                                .unwrap_or(rest.len());
-
-                            let task_content = &rest[..end_pos];
-
-                            // Extract dependencies
-                            let deps = extract_dependencies(task_content);
-                            for dep_id in deps {
-                                // Add dependency: dep_id blocks task_id
-                                let _ = graph.add_dependency(&dep_id, &task.id);
-                            }
-                        }
-                    }
-                }
-            }
+            // Build task graph (dependencies come from the raw PLAN.md content)
+            let plan_content = std::fs::read_to_string(&plan_path).unwrap_or_default();
+            let graph = TaskGraph::from_plan(&plan, &plan_content);
 
             // Convert to JSON format for D3.js
             let nodes: Vec<_> = plan
@@ -1326,53 +1822,6 @@ async fn activity_session_handler(
     }
 }
 
-/// Extract dependency task IDs from description text
-/// Looks for patterns like "Depends on: F.1, F.2" or "Blocked by: #6, #7"
-fn extract_dependencies(description: &str) -> Vec<String> {
-    let mut deps = Vec::new();
-
-    // Pattern 1: "Depends on: F.1, F.2"
-    if let Some(start) = description.find("Depends on:") {
-        let rest = &description[start + "Depends on:".len()..];
-        // Take until newline or end
-        let dep_text = rest.lines().next().unwrap_or("");
-
-        // Extract task IDs like F.1, H.2, etc.
-        for part in dep_text.split(',') {
-            let trimmed = part.trim();
-            // Match pattern: Letter.Number (e.g., F.1, H.2)
-            if trimmed
-                .chars()
-                .next()
-                .map(|c| c.is_alphabetic())
-                .unwrap_or(false)
-            {
-                if let Some(task_id) = trimmed.split_whitespace().next() {
-                    deps.push(task_id.to_string());
-                }
-            }
-        }
-    }
-
-    // Pattern 2: "Blocked by: #6, #7" - extract task numbers
-    if let Some(start) = description.find("Blocked by:") {
-        let rest = &description[start + "Blocked by:".len()..];
-        let dep_text = rest.lines().next().unwrap_or("");
-
-        for part in dep_text.split(',') {
-            let trimmed = part.trim();
-            if let Some(task_num) = trimmed.strip_prefix('#') {
-                if let Ok(_num) = task_num.parse::<u32>() {
-                    // For now, skip numeric task IDs (would need task ID mapping)
-                    // We'll focus on letter.number format (F.1, H.2, etc.)
-                }
-            }
-        }
-    }
-
-    deps
-}
-
 /// Query parameters for /api/insights
 #[derive(Debug, Deserialize)]
 struct InsightsQuery {
@@ -7,6 +7,14 @@ use std::convert::Infallible;
 use tokio_stream::wrappers::BroadcastStream;
 use tokio_stream::StreamExt;
 
+/// Event name and JSON payload for a `DataEvent`
+///
+/// Shared by the SSE stream and the WebSocket endpoint so both carry the same
+/// events under the same names.
+pub fn event_payload(event: &DataEvent) -> (&'static str, serde_json::Value) {
+    (event.kind(), event.payload())
+}
+
 /// Create an SSE stream from the event bus
 /// Takes EventBus by value (cheap clone, Arc internally)
 pub fn create_sse_stream(
@@ -17,28 +25,8 @@ pub fn create_sse_stream(
 
     let sse_stream = stream.filter_map(|result: Result<DataEvent, _>| {
         result.ok().map(|event: DataEvent| {
-            let (event_type, data) = match event {
-                DataEvent::StatsUpdated => ("stats_updated", "{}".to_string()),
-                DataEvent::SessionCreated(id) => {
-                    ("session_created", format!(r#"{{"id":"{}"}}"#, id))
-                }
-                DataEvent::SessionUpdated(id) => {
-                    ("session_updated", format!(r#"{{"id":"{}"}}"#, id))
-                }
-                DataEvent::ConfigChanged(scope) => {
-                    ("config_changed", format!(r#"{{"scope":"{:?}"}}"#, scope))
-                }
-                DataEvent::AnalyticsUpdated => ("analytics_updated", "{}".to_string()),
-                DataEvent::LoadCompleted => ("load_completed", "{}".to_string()),
-                DataEvent::WatcherError(msg) => {
-                    ("watcher_error", format!(r#"{{"message":"{}"}}"#, msg))
-                }
-                DataEvent::LiveSessionStatusChanged => {
-                    ("live_session_status_changed", "{}".to_string())
-                }
-            };
-
-            Ok(Event::default().event(event_type).data(data))
+            let (event_type, data) = event_payload(&event);
+            Ok(Event::default().event(event_type).data(data.to_string()))
         })
     });
 
@@ -62,4 +50,11 @@ mod tests {
         // Original bus still usable
         assert_eq!(bus.subscriber_count(), 1);
     }
+
+    #[test]
+    fn test_event_payload_escapes_messages() {
+        let (name, data) = event_payload(&DataEvent::WatcherError("bad \"path\"".to_string()));
+        assert_eq!(name, "watcher_error");
+        assert_eq!(data["message"], "bad \"path\"");
+    }
 }
@@ -1,11 +1,18 @@
-//! SSE (Server-Sent Events) hook for live updates
+//! Live update hook: WebSocket (`/api/ws`) with SSE (`/api/events`) fallback
 
 use leptos::prelude::*;
-use leptos::web_sys::{ErrorEvent, EventSource, MessageEvent};
+use leptos::web_sys::{ErrorEvent, EventSource, MessageEvent, WebSocket};
 use serde::Deserialize;
+use std::cell::Cell;
+use std::rc::Rc;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
 
+/// Delay before the first reconnect attempt (doubles on each failure)
+const RECONNECT_BASE_MS: i32 = 1_000;
+/// Upper bound for the reconnect delay
+const RECONNECT_MAX_MS: i32 = 30_000;
+
 /// SSE event types matching backend DataEvent
 #[derive(Debug, Clone, PartialEq)]
 pub enum SseEvent {
@@ -23,6 +30,9 @@ pub enum SseEvent {
     LoadCompleted,
     /// Watcher encountered an error
     WatcherError { message: String },
+    /// Stream was re-established after a drop; events may have been missed,
+    /// so listeners should refetch current data
+    Reconnected,
 }
 
 /// JSON payload for session events
@@ -43,6 +53,20 @@ struct ErrorEventData {
     message: String,
 }
 
+/// WebSocket frame: `{"event": "<sse event name>", "data": {...}}`
+#[derive(Debug, Deserialize)]
+struct WsFrame {
+    event: String,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+/// Parse a WebSocket text frame into SseEvent (same events as the SSE stream)
+fn parse_ws_frame(text: &str) -> Option<SseEvent> {
+    let frame = serde_json::from_str::<WsFrame>(text).ok()?;
+    parse_sse_event(&frame.event, &frame.data.to_string())
+}
+
 /// Parse SSE event data into SseEvent enum
 fn parse_sse_event(event_type: &str, data: &str) -> Option<SseEvent> {
     match event_type {
@@ -69,10 +93,33 @@ fn parse_sse_event(event_type: &str, data: &str) -> Option<SseEvent> {
     }
 }
 
+/// Shared SSE stream state, provided once at the app root
+#[derive(Debug, Clone, Copy)]
+pub struct SseContext {
+    /// Last received event
+    pub event: ReadSignal<Option<SseEvent>>,
+    /// Current connection status
+    pub status: ReadSignal<ConnectionStatus>,
+}
+
+/// Open the SSE stream and share it with all descendants via context
+///
+/// Call once in the root component so pages and the header reuse a single
+/// connection instead of each opening their own.
+pub fn provide_sse() -> SseContext {
+    let ctx = open_stream();
+    provide_context(ctx);
+    ctx
+}
+
 /// Leptos hook for SSE subscription
 ///
-/// Returns a signal that updates whenever an SSE event is received.
-/// Automatically handles connection, reconnection, and cleanup.
+/// Returns a signal that updates whenever a live event is received. Events come
+/// over the WebSocket endpoint, or the SSE stream when WebSockets are unavailable
+/// (e.g. stripped by a proxy).
+/// Uses the stream from [`provide_sse`] when available, otherwise opens its own.
+/// Dropped connections are retried with exponential backoff, and a
+/// [`SseEvent::Reconnected`] event is emitted once the stream is back.
 ///
 /// # Example
 ///
@@ -80,94 +127,231 @@ fn parse_sse_event(event_type: &str, data: &str) -> Option<SseEvent> {
 /// let sse_event = use_sse();
 ///
 /// Effect::new(move |_| {
-///     if let Some(SseEvent::StatsUpdated) = sse_event.get() {
+///     if let Some(SseEvent::StatsUpdated | SseEvent::Reconnected) = sse_event.get() {
 ///         // Trigger stats refetch
 ///         stats_resource.refetch();
 ///     }
 /// });
 /// ```
 pub fn use_sse() -> ReadSignal<Option<SseEvent>> {
+    use_context::<SseContext>()
+        .unwrap_or_else(open_stream)
+        .event
+}
+
+/// Hook to get SSE connection status
+pub fn use_sse_status() -> ReadSignal<ConnectionStatus> {
+    match use_context::<SseContext>() {
+        Some(ctx) => ctx.status,
+        None => signal(ConnectionStatus::Connecting).0,
+    }
+}
+
+/// Create signals and start the connection loop (client-side only, effects
+/// don't run during SSR)
+fn open_stream() -> SseContext {
     let (event, set_event) = signal(None::<SseEvent>);
-    let (_connection_status, set_connection_status) = signal(ConnectionStatus::Connecting);
+    let (status, set_status) = signal(ConnectionStatus::Connecting);
 
     Effect::new(move |_| {
-        // Create EventSource
-        let event_source = match EventSource::new("/api/events") {
-            Ok(es) => es,
-            Err(e) => {
-                leptos::logging::error!("Failed to create EventSource: {:?}", e);
-                set_connection_status.set(ConnectionStatus::Error);
-                return;
-            }
-        };
+        connect(Rc::new(SseConnection {
+            set_event,
+            set_status,
+            attempt: Cell::new(0),
+            was_connected: Cell::new(false),
+            use_sse: Cell::new(false),
+        }));
+    });
 
-        set_connection_status.set(ConnectionStatus::Connected);
+    SseContext { event, status }
+}
 
-        // Clone EventSource for error handler closure
-        let es_error = event_source.clone();
+/// Mutable state shared by the connection callbacks
+struct SseConnection {
+    set_event: WriteSignal<Option<SseEvent>>,
+    set_status: WriteSignal<ConnectionStatus>,
+    /// Consecutive failed attempts (reset on open)
+    attempt: Cell<u32>,
+    /// Whether the stream has been open at least once
+    was_connected: Cell<bool>,
+    /// WebSocket never opened: use SSE from now on
+    use_sse: Cell<bool>,
+}
 
-        // Handle open event
-        let on_open = Closure::wrap(Box::new(move |_: web_sys::Event| {
-            leptos::logging::log!("SSE connection opened");
-            set_connection_status.set(ConnectionStatus::Connected);
-        }) as Box<dyn FnMut(_)>);
+impl SseConnection {
+    /// Stream is open: reset backoff and ask listeners to resync after a drop
+    fn on_open(&self) {
+        self.attempt.set(0);
+        self.set_status.set(ConnectionStatus::Connected);
+        if self.was_connected.replace(true) {
+            self.set_event.set(Some(SseEvent::Reconnected));
+        }
+    }
+}
 
-        event_source.set_onopen(Some(on_open.as_ref().unchecked_ref()));
-        on_open.forget();
+/// Reconnect delay for the given attempt number
+fn backoff_delay_ms(attempt: u32) -> i32 {
+    RECONNECT_BASE_MS
+        .saturating_mul(1 << attempt.min(5))
+        .min(RECONNECT_MAX_MS)
+}
 
-        // Handle error event with reconnection
-        let on_error = Closure::wrap(Box::new(move |e: ErrorEvent| {
-            leptos::logging::warn!("SSE connection error: {:?}", e.message());
-            set_connection_status.set(ConnectionStatus::Reconnecting);
+fn connect(conn: Rc<SseConnection>) {
+    if conn.use_sse.get() {
+        connect_sse(conn);
+    } else {
+        connect_ws(conn);
+    }
+}
 
-            // EventSource automatically reconnects, but we track status
-            // Check if connection is actually closed
-            if es_error.ready_state() == EventSource::CLOSED {
-                leptos::logging::error!("SSE connection closed permanently");
-                set_connection_status.set(ConnectionStatus::Error);
+/// `ws://` / `wss://` URL of the WebSocket endpoint on the current host
+fn ws_url() -> Option<String> {
+    let location = web_sys::window()?.location();
+    let scheme = if location.protocol().ok()? == "https:" {
+        "wss"
+    } else {
+        "ws"
+    };
+    Some(format!("{}://{}/api/ws", scheme, location.host().ok()?))
+}
+
+fn connect_ws(conn: Rc<SseConnection>) {
+    let Some(socket) = ws_url().and_then(|url| WebSocket::new(&url).ok()) else {
+        leptos::logging::warn!("WebSocket unavailable, falling back to SSE");
+        conn.use_sse.set(true);
+        connect_sse(conn);
+        return;
+    };
+    let opened = Rc::new(Cell::new(false));
+
+    let conn_open = conn.clone();
+    let opened_flag = opened.clone();
+    let on_open = Closure::wrap(Box::new(move |_: web_sys::Event| {
+        leptos::logging::log!("WebSocket connection opened");
+        opened_flag.set(true);
+        conn_open.on_open();
+    }) as Box<dyn FnMut(_)>);
+    socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    on_open.forget();
+
+    let set_event = conn.set_event;
+    let on_message = Closure::wrap(Box::new(move |event: MessageEvent| {
+        let text = event.data().as_string().unwrap_or_default();
+        if let Some(parsed_event) = parse_ws_frame(&text) {
+            leptos::logging::log!("WebSocket event received: {:?}", parsed_event);
+            set_event.set(Some(parsed_event));
+        }
+    }) as Box<dyn FnMut(_)>);
+    socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    on_message.forget();
+
+    // Errors are always followed by close, so reconnect logic lives here
+    let conn_close = conn.clone();
+    let on_close = Closure::wrap(Box::new(move |_: web_sys::Event| {
+        if !opened.get() && !conn_close.was_connected.get() {
+            // Never got through (proxy, old server): SSE for the rest of the page
+            leptos::logging::warn!("WebSocket failed to open, falling back to SSE");
+            conn_close.use_sse.set(true);
+            connect_sse(conn_close.clone());
+        } else {
+            leptos::logging::warn!("WebSocket connection closed");
+            schedule_reconnect(conn_close.clone());
+        }
+    }) as Box<dyn FnMut(_)>);
+    socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+    on_close.forget();
+}
+
+fn connect_sse(conn: Rc<SseConnection>) {
+    // Create EventSource
+    let event_source = match EventSource::new("/api/events") {
+        Ok(es) => es,
+        Err(e) => {
+            leptos::logging::error!("Failed to create EventSource: {:?}", e);
+            schedule_reconnect(conn);
+            return;
+        }
+    };
+
+    // Handle open event: reset backoff and ask listeners to resync after a drop
+    let conn_open = conn.clone();
+    let on_open = Closure::wrap(Box::new(move |_: web_sys::Event| {
+        leptos::logging::log!("SSE connection opened");
+        conn_open.on_open();
+    }) as Box<dyn FnMut(_)>);
+
+    event_source.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    on_open.forget();
+
+    // Handle error event: close and retry ourselves so the backoff is bounded
+    // and consistent (the browser gives up entirely on non-200 responses)
+    let es_error = event_source.clone();
+    let conn_error = conn.clone();
+    let on_error = Closure::wrap(Box::new(move |e: ErrorEvent| {
+        leptos::logging::warn!("SSE connection error: {:?}", e.message());
+        es_error.close();
+        schedule_reconnect(conn_error.clone());
+    }) as Box<dyn FnMut(_)>);
+
+    event_source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+    on_error.forget();
+
+    // Register listeners for each event type
+    let event_types = vec![
+        "stats_updated",
+        "session_created",
+        "session_updated",
+        "config_changed",
+        "analytics_updated",
+        "load_completed",
+        "watcher_error",
+    ];
+
+    for event_type in event_types {
+        let event_type_owned = event_type.to_string();
+        let set_event = conn.set_event;
+        let callback = Closure::wrap(Box::new(move |event: MessageEvent| {
+            let data = event.data().as_string().unwrap_or_default();
+
+            if let Some(parsed_event) = parse_sse_event(&event_type_owned, &data) {
+                leptos::logging::log!("SSE event received: {:?}", parsed_event);
+                set_event.set(Some(parsed_event));
             }
         }) as Box<dyn FnMut(_)>);
 
-        event_source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
-        on_error.forget();
-
-        // Register listeners for each event type
-        let event_types = vec![
-            "stats_updated",
-            "session_created",
-            "session_updated",
-            "config_changed",
-            "analytics_updated",
-            "load_completed",
-            "watcher_error",
-        ];
-
-        for event_type in event_types {
-            let event_type_owned = event_type.to_string();
-            let callback = Closure::wrap(Box::new(move |event: MessageEvent| {
-                let data = event.data().as_string().unwrap_or_default();
-
-                if let Some(parsed_event) = parse_sse_event(&event_type_owned, &data) {
-                    leptos::logging::log!("SSE event received: {:?}", parsed_event);
-                    set_event.set(Some(parsed_event));
-                }
-            }) as Box<dyn FnMut(_)>);
-
-            event_source
-                .add_event_listener_with_callback(event_type, callback.as_ref().unchecked_ref())
-                .unwrap_or_else(|e| {
-                    leptos::logging::error!("Failed to add listener for {}: {:?}", event_type, e);
-                });
-
-            callback.forget();
-        }
+        event_source
+            .add_event_listener_with_callback(event_type, callback.as_ref().unchecked_ref())
+            .unwrap_or_else(|e| {
+                leptos::logging::error!("Failed to add listener for {}: {:?}", event_type, e);
+            });
 
-        // Cleanup function (runs when effect is disposed)
-        // Note: In Leptos, Effects don't have explicit cleanup, but EventSource
-        // will be dropped when component unmounts
-    });
+        callback.forget();
+    }
+}
+
+fn schedule_reconnect(conn: Rc<SseConnection>) {
+    let attempt = conn.attempt.get();
+    conn.attempt.set(attempt.saturating_add(1));
+    conn.set_status.set(ConnectionStatus::Reconnecting);
+
+    let delay = backoff_delay_ms(attempt);
+    leptos::logging::log!(
+        "Live updates reconnecting in {}ms (attempt {})",
+        delay,
+        attempt + 1
+    );
 
-    event
+    let Some(window) = web_sys::window() else {
+        conn.set_status.set(ConnectionStatus::Error);
+        return;
+    };
+    let retry = Closure::once_into_js(move || connect(conn));
+    if window
+        .set_timeout_with_callback_and_timeout_and_arguments_0(retry.unchecked_ref(), delay)
+        .is_err()
+    {
+        leptos::logging::error!("Failed to schedule live updates reconnect");
+    }
 }
 
 /// Connection status for SSE
@@ -183,8 +367,32 @@ pub enum ConnectionStatus {
     Error,
 }
 
-/// Hook to get SSE connection status
-pub fn use_sse_status() -> ReadSignal<ConnectionStatus> {
-    let (status, _) = signal(ConnectionStatus::Connecting);
-    status
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ws_frame() {
+        assert_eq!(
+            parse_ws_frame(r#"{"event":"session_updated","data":{"id":"abc"}}"#),
+            Some(SseEvent::SessionUpdated {
+                id: "abc".to_string()
+            })
+        );
+        assert_eq!(
+            parse_ws_frame(r#"{"event":"stats_updated","data":{}}"#),
+            Some(SseEvent::StatsUpdated)
+        );
+        assert_eq!(parse_ws_frame(r#"{"event":"unknown","data":{}}"#), None);
+        assert_eq!(parse_ws_frame("not json"), None);
+    }
+
+    #[test]
+    fn test_backoff_delay_doubles_and_caps() {
+        assert_eq!(backoff_delay_ms(0), 1_000);
+        assert_eq!(backoff_delay_ms(1), 2_000);
+        assert_eq!(backoff_delay_ms(4), 16_000);
+        assert_eq!(backoff_delay_ms(5), 30_000);
+        assert_eq!(backoff_delay_ms(100), 30_000);
+    }
 }
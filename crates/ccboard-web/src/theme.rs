@@ -0,0 +1,205 @@
+//! Web color theme: Dark / Light / System, persisted to localStorage
+//!
+//! Web counterpart of the TUI `ColorScheme`. The palette itself lives in CSS
+//! variables (`static/style.css`); this module only picks which set is active by
+//! setting `data-theme="dark|light"` on `<html>`.
+
+use leptos::prelude::*;
+use leptos::web_sys::MediaQueryList;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// localStorage key holding the selected mode
+const STORAGE_KEY: &str = "ccboard-theme";
+
+/// Media query matched when the OS prefers a light theme
+const LIGHT_QUERY: &str = "(prefers-color-scheme: light)";
+
+/// User-selected theme mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeMode {
+    Dark,
+    Light,
+    /// Follow the OS `prefers-color-scheme` setting
+    #[default]
+    System,
+}
+
+impl ThemeMode {
+    /// Value stored in localStorage
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ThemeMode::Dark => "dark",
+            ThemeMode::Light => "light",
+            ThemeMode::System => "system",
+        }
+    }
+
+    /// Parse a stored value (unknown values fall back to `System`)
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "dark" => ThemeMode::Dark,
+            "light" => ThemeMode::Light,
+            _ => ThemeMode::System,
+        }
+    }
+
+    /// Next mode for the header toggle: Dark → Light → System → Dark
+    pub fn next(self) -> Self {
+        match self {
+            ThemeMode::Dark => ThemeMode::Light,
+            ThemeMode::Light => ThemeMode::System,
+            ThemeMode::System => ThemeMode::Dark,
+        }
+    }
+
+    /// Icon shown in the header toggle
+    pub fn icon(self) -> &'static str {
+        match self {
+            ThemeMode::Dark => "☾",
+            ThemeMode::Light => "☀",
+            ThemeMode::System => "◐",
+        }
+    }
+
+    /// Human-readable label (toggle tooltip)
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeMode::Dark => "Dark",
+            ThemeMode::Light => "Light",
+            ThemeMode::System => "System",
+        }
+    }
+
+    /// Concrete palette (`"dark"` or `"light"`) given the OS preference
+    pub fn resolve(self, system_prefers_light: bool) -> &'static str {
+        match self {
+            ThemeMode::Dark => "dark",
+            ThemeMode::Light => "light",
+            ThemeMode::System if system_prefers_light => "light",
+            ThemeMode::System => "dark",
+        }
+    }
+}
+
+/// Theme context shared by the header toggle and any component reading the mode
+#[derive(Clone, Copy)]
+pub struct ThemeContext {
+    mode: RwSignal<ThemeMode>,
+    system_prefers_light: RwSignal<bool>,
+}
+
+impl ThemeContext {
+    /// Currently selected mode
+    pub fn mode(&self) -> ThemeMode {
+        self.mode.get()
+    }
+
+    /// Active palette after resolving `System`
+    pub fn resolved(&self) -> &'static str {
+        self.mode.get().resolve(self.system_prefers_light.get())
+    }
+
+    /// Select a mode (applied to the document and persisted)
+    pub fn set(&self, mode: ThemeMode) {
+        self.mode.set(mode);
+    }
+
+    /// Advance to the next mode
+    pub fn cycle(&self) {
+        self.mode.update(|mode| *mode = mode.next());
+    }
+}
+
+/// Provide the theme context at the app root
+///
+/// Restores the saved mode, then keeps `<html data-theme>` and localStorage in
+/// sync with it. In `System` mode, OS theme changes apply without a reload.
+pub fn provide_theme() -> ThemeContext {
+    let ctx = ThemeContext {
+        mode: RwSignal::new(load_mode()),
+        system_prefers_light: RwSignal::new(false),
+    };
+
+    // Effects only run client-side, so SSR never touches window
+    Effect::new(move |_| {
+        let Some(query) = light_query() else {
+            return;
+        };
+        ctx.system_prefers_light.set(query.matches());
+        let on_change = Closure::<dyn Fn()>::new(move || {
+            if let Some(query) = light_query() {
+                ctx.system_prefers_light.set(query.matches());
+            }
+        });
+        query.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+        // Listener lives as long as the page
+        on_change.forget();
+    });
+
+    Effect::new(move |_| {
+        let palette = ctx.resolved();
+        let mode = ctx.mode.get();
+        if let Some(root) = document().document_element() {
+            let _ = root.set_attribute("data-theme", palette);
+        }
+        if let Some(storage) = window().local_storage().ok().flatten() {
+            let _ = storage.set_item(STORAGE_KEY, mode.as_str());
+        }
+    });
+
+    provide_context(ctx);
+    ctx
+}
+
+/// Theme context from [`provide_theme`]
+///
+/// Falls back to a detached `System` context so components still render in
+/// isolation (tests, storybook-style previews).
+pub fn use_theme() -> ThemeContext {
+    use_context::<ThemeContext>().unwrap_or_else(|| ThemeContext {
+        mode: RwSignal::new(ThemeMode::System),
+        system_prefers_light: RwSignal::new(false),
+    })
+}
+
+/// Saved mode from localStorage (`System` when unset or unavailable)
+fn load_mode() -> ThemeMode {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+            .map(|value| ThemeMode::parse(&value))
+            .unwrap_or_default()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        ThemeMode::System
+    }
+}
+
+fn light_query() -> Option<MediaQueryList> {
+    web_sys::window()?.match_media(LIGHT_QUERY).ok().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_mode_roundtrip_and_resolve() {
+        for mode in [ThemeMode::Dark, ThemeMode::Light, ThemeMode::System] {
+            assert_eq!(ThemeMode::parse(mode.as_str()), mode);
+        }
+        assert_eq!(ThemeMode::parse("garbage"), ThemeMode::System);
+        assert_eq!(ThemeMode::default(), ThemeMode::System);
+
+        // Toggle visits every mode before wrapping
+        assert_eq!(ThemeMode::Dark.next().next().next(), ThemeMode::Dark);
+
+        assert_eq!(ThemeMode::System.resolve(true), "light");
+        assert_eq!(ThemeMode::System.resolve(false), "dark");
+        assert_eq!(ThemeMode::Dark.resolve(true), "dark");
+    }
+}
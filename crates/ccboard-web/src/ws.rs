@@ -0,0 +1,92 @@
+//! WebSocket endpoint for live updates
+//!
+//! Pushes the same events as the SSE stream, one JSON text frame per event:
+//! `{"event": "session_updated", "data": {"id": "..."}}`. Unlike SSE, clients can
+//! narrow the stream by sending `{"subscribe": ["live_session_status_changed"]}`;
+//! an empty list restores all events.
+
+use crate::sse::event_payload;
+use axum::extract::ws::{Message, WebSocket};
+use ccboard_core::EventBus;
+use serde::Deserialize;
+use std::collections::HashSet;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, trace};
+
+/// Client → server message selecting which event kinds to receive
+#[derive(Debug, Deserialize)]
+struct SubscribeMessage {
+    subscribe: Vec<String>,
+}
+
+/// Parse a subscription message into an event-kind filter
+///
+/// Returns `None` for messages that are not subscriptions (ignored), and
+/// `Some(empty set)` to receive every event again.
+fn parse_subscription(text: &str) -> Option<HashSet<String>> {
+    serde_json::from_str::<SubscribeMessage>(text)
+        .ok()
+        .map(|msg| msg.subscribe.into_iter().collect())
+}
+
+/// Serve one WebSocket connection until the client leaves
+/// Takes EventBus by value (cheap clone, Arc internally)
+pub async fn handle_socket(mut socket: WebSocket, event_bus: EventBus) {
+    let mut rx = event_bus.subscribe();
+    // Empty filter = all events
+    let mut kinds: HashSet<String> = HashSet::new();
+
+    loop {
+        tokio::select! {
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    if let Some(filter) = parse_subscription(text.as_str()) {
+                        debug!(kinds = ?filter, "WebSocket subscription updated");
+                        kinds = filter;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                // Pings are answered by axum; binary frames are not part of the protocol
+                Some(Ok(_)) => {}
+            },
+            event = rx.recv() => match event {
+                Ok(event) => {
+                    let (name, data) = event_payload(&event);
+                    if !kinds.is_empty() && !kinds.contains(name) {
+                        continue;
+                    }
+                    let frame = serde_json::json!({ "event": name, "data": data });
+                    if socket.send(Message::Text(frame.to_string().into())).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    trace!(skipped, "WebSocket client lagged behind event bus");
+                }
+                Err(RecvError::Closed) => break,
+            },
+        }
+    }
+
+    debug!("WebSocket connection closed");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_subscription() {
+        let kinds = parse_subscription(r#"{"subscribe":["live_session_status_changed"]}"#).unwrap();
+        assert!(kinds.contains("live_session_status_changed"));
+        assert_eq!(kinds.len(), 1);
+
+        // Empty list means all events
+        assert!(parse_subscription(r#"{"subscribe":[]}"#)
+            .unwrap()
+            .is_empty());
+
+        assert!(parse_subscription("ping").is_none());
+        assert!(parse_subscription(r#"{"other":1}"#).is_none());
+    }
+}
@@ -0,0 +1,101 @@
+//! Integration test for the session resume endpoint
+
+use axum::body::Body;
+use axum::extract::connect_info::MockConnectInfo;
+use axum::http::{Request, StatusCode};
+use ccboard_core::DataStore;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+fn resume_request(id: &str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(format!("/api/sessions/{}/resume", id))
+        .header("host", "localhost:3333")
+        .body(Body::empty())
+        .unwrap()
+}
+
+fn loopback() -> MockConnectInfo<SocketAddr> {
+    MockConnectInfo(SocketAddr::from(([127, 0, 0, 1], 40000)))
+}
+
+#[tokio::test]
+async fn test_resume_endpoint_guarded_by_flag() {
+    let temp_dir = std::env::temp_dir().join("ccboard-test-resume");
+    std::fs::create_dir_all(&temp_dir).ok();
+    let store = Arc::new(DataStore::with_defaults(temp_dir.clone(), None));
+
+    // Disabled by default: never spawns anything
+    let router = ccboard_web::create_router(store.clone()).layer(loopback());
+    let response = router.oneshot(resume_request("abc")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Enabled: unknown sessions are rejected before spawning
+    let options = ccboard_web::ServerOptions { allow_resume: true };
+    let router = ccboard_web::create_router_with_options(store, options).layer(loopback());
+    let response = router.oneshot(resume_request("abc")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    std::fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[tokio::test]
+async fn test_mutating_routes_require_local_same_origin() {
+    let temp_dir = std::env::temp_dir().join("ccboard-test-resume-guard");
+    std::fs::create_dir_all(&temp_dir).ok();
+    let store = Arc::new(DataStore::with_defaults(temp_dir.clone(), None));
+    let options = ccboard_web::ServerOptions { allow_resume: true };
+    let router = ccboard_web::create_router_with_options(store, options);
+
+    // LAN peer
+    let lan = router.clone().layer(MockConnectInfo(SocketAddr::from((
+        [192, 168, 1, 20],
+        40000,
+    ))));
+    let response = lan.oneshot(resume_request("abc")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Loopback, but posted by a page on another site
+    let mut request = resume_request("abc");
+    let headers = request.headers_mut();
+    headers.insert("origin", "https://evil.example".parse().unwrap());
+    let response = router
+        .clone()
+        .layer(loopback())
+        .oneshot(request)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // DNS rebinding: loopback peer, same origin, but a non-loopback host name
+    let mut request = resume_request("abc");
+    let headers = request.headers_mut();
+    headers.insert("host", "rebind.evil.example:3333".parse().unwrap());
+    headers.insert("origin", "http://rebind.evil.example:3333".parse().unwrap());
+    let response = router
+        .clone()
+        .layer(loopback())
+        .oneshot(request)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Same origin on a loopback host reaches the handler (unknown session)
+    for host in ["localhost:3333", "127.0.0.1:3333", "[::1]:3333"] {
+        let mut request = resume_request("abc");
+        let headers = request.headers_mut();
+        headers.insert("host", host.parse().unwrap());
+        headers.insert("origin", format!("http://{}", host).parse().unwrap());
+        let response = router
+            .clone()
+            .layer(loopback())
+            .oneshot(request)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "host {}", host);
+    }
+
+    std::fs::remove_dir_all(&temp_dir).ok();
+}
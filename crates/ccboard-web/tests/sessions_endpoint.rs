@@ -0,0 +1,68 @@
+//! Integration test for sessions pagination and server-side sorting
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use ccboard_core::DataStore;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+/// Write a session file with `messages` user messages
+fn write_session(project_dir: &std::path::Path, id: &str, messages: usize) {
+    let lines: Vec<String> = (0..messages)
+        .map(|i| {
+            format!(
+                r#"{{"type":"user","sessionId":"{}","timestamp":"2025-01-15T10:0{}:00Z","message":{{"role":"user","content":"message {}"}}}}"#,
+                id, i, i
+            )
+        })
+        .collect();
+    std::fs::write(project_dir.join(format!("{}.jsonl", id)), lines.join("\n")).unwrap();
+}
+
+async fn get_json(router: axum::Router, uri: &str) -> serde_json::Value {
+    let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_sessions_limit_offset_and_sort() {
+    let temp_dir = std::env::temp_dir().join("ccboard-test-sessions-endpoint");
+    std::fs::remove_dir_all(&temp_dir).ok();
+    let project_dir = temp_dir.join("projects").join("-tmp-app");
+    std::fs::create_dir_all(&project_dir).unwrap();
+    write_session(&project_dir, "session-one", 1);
+    write_session(&project_dir, "session-three", 3);
+    write_session(&project_dir, "session-two", 2);
+
+    let store = Arc::new(DataStore::with_defaults(temp_dir.clone(), None));
+    store.initial_load().await;
+    let router = ccboard_web::create_router(store);
+
+    let json = get_json(
+        router.clone(),
+        "/api/sessions?limit=2&offset=0&sort=messages&order=asc",
+    )
+    .await;
+    assert_eq!(json["total"], 3);
+    let items = json["items"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert!(items[0]["messages"].as_u64() <= items[1]["messages"].as_u64());
+    assert_eq!(items[0]["id"], "session-one");
+
+    let json = get_json(
+        router,
+        "/api/sessions?limit=2&offset=2&sort=messages&order=asc",
+    )
+    .await;
+    let items = json["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["id"], "session-three");
+    assert_eq!(json["offset"], 2);
+
+    std::fs::remove_dir_all(&temp_dir).ok();
+}
@@ -49,18 +49,19 @@ async fn main() {
 
     let billing_blocks = store.billing_blocks();
     let all_blocks = billing_blocks.get_all_blocks();
+    let currency = store.currency();
 
     println!("Total billing blocks: {}", all_blocks.len());
     if !all_blocks.is_empty() {
         println!("\nFirst 5 blocks (sorted by date, most recent last):");
         for (block, usage) in all_blocks.iter().rev().take(5) {
             println!(
-                "  {} {} - Tokens: {}, Sessions: {}, Cost: ${:.3}",
+                "  {} {} - Tokens: {}, Sessions: {}, Cost: {}",
                 block.date.format("%Y-%m-%d"),
                 block.label(),
                 usage.total_tokens(),
                 usage.session_count,
-                usage.total_cost
+                currency.format(usage.total_cost, 3)
             );
         }
     }
@@ -69,7 +70,8 @@ async fn main() {
     let export_path = claude_home.join("exports/billing-blocks-test.csv");
     println!("\nExporting to: {}", export_path.display());
 
-    export_billing_blocks_to_csv(&billing_blocks, &export_path).expect("Failed to export CSV");
+    export_billing_blocks_to_csv(&billing_blocks, &export_path, &currency)
+        .expect("Failed to export CSV");
 
     println!("✓ Export successful!");
 
@@ -4,8 +4,16 @@
 
 use anyhow::{Context, Result};
 use ccboard_core::models::SessionMetadata;
-use chrono::{DateTime, Utc};
-use comfy_table::{Cell, Color, ContentArrangement, Row, Table};
+use ccboard_core::pricing::{normalize_model_id, PricingEntry};
+use ccboard_core::usage_estimator::days_in_month;
+use ccboard_core::ProjectDisplay;
+use ccboard_core::{SubscriptionPlan, UsageEstimate};
+use ccboard_types::Currency;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use comfy_table::{Cell, CellAlignment, Color, ContentArrangement, Row, Table};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 // ============================================================================
@@ -23,6 +31,20 @@ pub enum CliError {
         count: usize,
         suggestions: String,
     },
+    /// No exact/prefix match, but some IDs are within a small edit distance
+    NearMatches {
+        query: String,
+        suggestions: Vec<String>,
+    },
+    InvalidPattern {
+        pattern: String,
+        message: String,
+    },
+    /// `--after`/`--since` falls after `--before`
+    InvalidDateRange {
+        after: String,
+        before: String,
+    },
     Core(ccboard_core::error::CoreError),
     Other(anyhow::Error),
 }
@@ -48,6 +70,29 @@ impl std::fmt::Display for CliError {
                     prefix, count, suggestions
                 )
             }
+            CliError::NearMatches { query, suggestions } => match suggestions.as_slice() {
+                [only] => write!(f, "No sessions match '{}'. Did you mean {}?", query, only),
+                _ => write!(
+                    f,
+                    "No sessions match '{}'. Closest IDs:\n{}",
+                    query,
+                    suggestions
+                        .iter()
+                        .map(|s| format!("  - {}", s))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                ),
+            },
+            CliError::InvalidPattern { pattern, message } => {
+                write!(f, "Invalid regex '{}': {}", pattern, message)
+            }
+            CliError::InvalidDateRange { after, before } => {
+                write!(
+                    f,
+                    "Invalid date range: --after {} is later than --before {}",
+                    after, before
+                )
+            }
             CliError::Core(e) => write!(f, "{}", e),
             CliError::Other(e) => write!(f, "{}", e),
         }
@@ -78,6 +123,11 @@ pub enum DateFilter {
     Months(u32),
     Years(u32),
     Since(DateTime<Utc>),
+    /// `[start, end)`: `--since`/`--after` bounded by `--before`
+    Range {
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
 }
 
 impl DateFilter {
@@ -111,6 +161,29 @@ impl DateFilter {
         Ok(DateFilter::Since(datetime))
     }
 
+    /// Bound `since` (or the beginning of time) by `before` (`YYYY-MM-DD`,
+    /// inclusive: the whole day counts)
+    pub fn with_before(since: Option<DateFilter>, before: &str) -> Result<Self, CliError> {
+        let before_date = NaiveDate::parse_from_str(before, "%Y-%m-%d")
+            .with_context(|| format!("Invalid --before date '{}' (expected YYYY-MM-DD)", before))?;
+        let end = before_date
+            .succ_opt()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .context("Invalid --before date")?
+            .and_utc();
+        let start = since
+            .map(|f| f.cutoff())
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+
+        if start >= end {
+            return Err(CliError::InvalidDateRange {
+                after: start.format("%Y-%m-%d").to_string(),
+                before: before.to_string(),
+            });
+        }
+        Ok(DateFilter::Range { start, end })
+    }
+
     /// Get cutoff timestamp
     pub fn cutoff(&self) -> DateTime<Utc> {
         let now = Utc::now();
@@ -119,12 +192,16 @@ impl DateFilter {
             DateFilter::Months(m) => now - chrono::Duration::days((*m as i64) * 30),
             DateFilter::Years(y) => now - chrono::Duration::days((*y as i64) * 365),
             DateFilter::Since(dt) => *dt,
+            DateFilter::Range { start, .. } => *start,
         }
     }
 
     /// Check if timestamp matches filter
     pub fn matches(&self, timestamp: &DateTime<Utc>) -> bool {
-        *timestamp >= self.cutoff()
+        match self {
+            DateFilter::Range { end, .. } => *timestamp >= self.cutoff() && *timestamp < *end,
+            _ => *timestamp >= self.cutoff(),
+        }
     }
 }
 
@@ -132,10 +209,114 @@ impl DateFilter {
 // Query Helpers
 // ============================================================================
 
+/// Maximum edit distance for a session ID to count as a near match
+const FUZZY_MAX_DISTANCE: usize = 2;
+
+/// Number of near matches listed when several IDs are close
+const FUZZY_MAX_SUGGESTIONS: usize = 3;
+
 /// Find session by exact ID or unique prefix
+///
+/// When nothing matches, IDs within a small edit distance are suggested in the
+/// error (`Did you mean abc12345?`). Use [`find_session`] with `fuzzy` to
+/// resolve a single near match automatically.
 pub fn find_by_id_or_prefix(
     sessions: &[Arc<SessionMetadata>],
     id: &str,
+) -> Result<Arc<SessionMetadata>, CliError> {
+    find_session(sessions, id, false)
+}
+
+/// Find session by exact ID, unique prefix, or (with `fuzzy`) a single near match
+///
+/// Near matches compare `id` against the same-length start of each session ID,
+/// so truncated IDs with a typo still resolve. Two or more near matches are
+/// always an error listing the closest ones with their projects.
+pub fn find_session(
+    sessions: &[Arc<SessionMetadata>],
+    id: &str,
+    fuzzy: bool,
+) -> Result<Arc<SessionMetadata>, CliError> {
+    let err = match find_exact_or_prefix(sessions, id) {
+        Err(err @ CliError::NoResults { .. }) => err,
+        other => return other,
+    };
+
+    // Same minimum as prefix matching: short queries are too ambiguous
+    if id.chars().count() < 8 {
+        return Err(err);
+    }
+
+    let mut near: Vec<(usize, &Arc<SessionMetadata>)> = sessions
+        .iter()
+        .filter_map(|s| {
+            let distance = prefix_edit_distance(id, &s.id);
+            (distance <= FUZZY_MAX_DISTANCE).then_some((distance, s))
+        })
+        .collect();
+    near.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.id.cmp(&b.1.id)));
+
+    match near.as_slice() {
+        [] => Err(err),
+        [(_, session)] if fuzzy => Ok(Arc::clone(session)),
+        [(_, session)] => Err(CliError::NearMatches {
+            query: id.to_string(),
+            suggestions: vec![format!(
+                "{} ({})",
+                short_id(&session.id),
+                session.project_path
+            )],
+        }),
+        _ => Err(CliError::NearMatches {
+            query: id.to_string(),
+            suggestions: near
+                .iter()
+                .take(FUZZY_MAX_SUGGESTIONS)
+                .map(|(_, s)| format!("{}  {}", short_id(&s.id), s.project_path))
+                .collect(),
+        }),
+    }
+}
+
+/// First 8 chars of a session ID
+fn short_id(id: &str) -> &str {
+    id.get(..8).unwrap_or(id)
+}
+
+/// Edit distance between `query` and the closest-length start of `id`
+///
+/// Tries prefixes one char shorter/longer than `query` too, so a dropped or
+/// doubled character in a truncated ID costs 1 rather than shifting the rest.
+fn prefix_edit_distance(query: &str, id: &str) -> usize {
+    let query: Vec<char> = query.chars().collect();
+    let id: Vec<char> = id.chars().collect();
+    let lo = query.len().saturating_sub(1);
+    let hi = (query.len() + 1).min(id.len());
+    (lo..=hi)
+        .filter(|&len| len <= id.len())
+        .map(|len| levenshtein(&query, &id[..len]))
+        .min()
+        .unwrap_or(usize::MAX)
+}
+
+/// Levenshtein distance over chars
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+/// Exact ID or unique prefix (at least 8 chars)
+fn find_exact_or_prefix(
+    sessions: &[Arc<SessionMetadata>],
+    id: &str,
 ) -> Result<Arc<SessionMetadata>, CliError> {
     // Try exact match first
     if let Some(session) = sessions.iter().find(|s| s.id == id) {
@@ -174,15 +355,121 @@ pub fn find_by_id_or_prefix(
     }
 }
 
-/// Search sessions by query string
+/// Resolve an export format from `--format` or, when omitted, the output path extension
+///
+/// An explicit format is authoritative. Otherwise the extension (csv, json, md,
+/// html, xlsx, ics) must map to one of the `supported` formats of the export.
+/// Without an output path (stdout), `--format` is required.
+pub fn resolve_export_format(
+    output: Option<&Path>,
+    explicit: Option<String>,
+    supported: &[&str],
+) -> Result<String> {
+    if let Some(format) = explicit {
+        return Ok(format);
+    }
+    let Some(output) = output else {
+        anyhow::bail!(
+            "--format is required when writing to stdout ({})",
+            supported.join(", ")
+        );
+    };
+
+    let ext = output
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    let detected = match ext.as_str() {
+        "csv" => "csv",
+        "json" => "json",
+        "jsonl" | "ndjson" => "jsonl",
+        "md" | "markdown" => "md",
+        "html" | "htm" => "html",
+        "xlsx" => "xlsx",
+        "ics" => "ics",
+        "dot" | "gv" => "dot",
+        _ => anyhow::bail!(
+            "Cannot infer export format from '{}'. Use --format ({})",
+            output.display(),
+            supported.join(", ")
+        ),
+    };
+
+    supported
+        .iter()
+        .find(|f| **f == detected || (detected == "md" && **f == "markdown"))
+        .map(|f| f.to_string())
+        .with_context(|| {
+            format!(
+                "'.{}' is not supported for this export. Use --format ({})",
+                ext,
+                supported.join(", ")
+            )
+        })
+}
+
+/// `<dir>/<project>/<session-id>.<ext>` for `export conversations`
+///
+/// The project path becomes one directory name (separators → `-`), so every
+/// project's sessions land side by side under `dir`.
+pub fn conversation_export_path(dir: &Path, project: &str, session_id: &str, ext: &str) -> PathBuf {
+    let project: String = project
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' => '-',
+            c => c,
+        })
+        .collect();
+    let project = project.trim_matches(|c| c == '-' || c == '.');
+    let project = if project.is_empty() {
+        "unknown"
+    } else {
+        project
+    };
+    dir.join(project).join(format!("{}.{}", session_id, ext))
+}
+
+/// Search query: case-insensitive substring (default) or regular expression
+#[derive(Debug, Clone)]
+pub enum SearchMatcher {
+    Substring(String),
+    Regex(regex::Regex),
+}
+
+impl SearchMatcher {
+    /// Build a matcher; with `regex`, the query is compiled case-insensitively
+    pub fn new(query: &str, regex: bool) -> Result<Self, CliError> {
+        if !regex {
+            return Ok(SearchMatcher::Substring(query.to_lowercase()));
+        }
+        regex::RegexBuilder::new(query)
+            .case_insensitive(true)
+            .build()
+            .map(SearchMatcher::Regex)
+            .map_err(|e| CliError::InvalidPattern {
+                pattern: query.to_string(),
+                message: e.to_string(),
+            })
+    }
+
+    /// Whether `text` matches the query
+    pub fn is_match(&self, text: &str) -> bool {
+        match self {
+            SearchMatcher::Substring(query) => text.to_lowercase().contains(query),
+            SearchMatcher::Regex(re) => re.is_match(text),
+        }
+    }
+}
+
+/// Search sessions by ID, project path, first message or branch
 pub fn search_sessions(
     sessions: &[Arc<SessionMetadata>],
-    query: &str,
+    matcher: &SearchMatcher,
     date_filter: Option<&DateFilter>,
     limit: usize,
 ) -> Vec<Arc<SessionMetadata>> {
-    let query_lower = query.to_lowercase();
-
     sessions
         .iter()
         .filter(|s| {
@@ -197,30 +484,92 @@ pub fn search_sessions(
                 }
             }
 
-            // Text search: ID, project path, first message
-            s.id.to_lowercase().contains(&query_lower)
-                || s.project_path.to_lowercase().contains(&query_lower)
+            // Text search: ID, project path, first message, branch
+            matcher.is_match(&s.id)
+                || matcher.is_match(&s.project_path)
                 || s.first_user_message
-                    .as_ref()
-                    .map(|m| m.to_lowercase().contains(&query_lower))
-                    .unwrap_or(false)
-                || s.branch
-                    .as_ref()
-                    .map(|b| b.to_lowercase().contains(&query_lower))
-                    .unwrap_or(false)
+                    .as_deref()
+                    .is_some_and(|m| matcher.is_match(m))
+                || s.branch.as_deref().is_some_and(|b| matcher.is_match(b))
         })
         .take(limit)
         .cloned()
         .collect()
 }
 
+/// Estimated cost and token usage of one model
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ModelCost {
+    pub model: String,
+    pub input: u64,
+    pub output: u64,
+    /// Cache creation + cache read tokens
+    pub cache: u64,
+    pub total: u64,
+    /// Estimated cost in USD
+    pub cost: f64,
+}
+
+/// Per-model cost of sessions started within `date_filter`, most expensive first
+///
+/// Sessions that switched models are split across them like
+/// `SessionMetadata::estimated_cost`, so the totals match the other cost views.
+pub fn cost_by_model(
+    sessions: &[Arc<SessionMetadata>],
+    date_filter: Option<&DateFilter>,
+) -> Vec<ModelCost> {
+    let mut by_model: HashMap<String, ModelCost> = HashMap::new();
+
+    for session in sessions {
+        if let Some(filter) = date_filter {
+            if !session
+                .first_timestamp
+                .is_some_and(|ts| filter.matches(&ts))
+            {
+                continue;
+            }
+        }
+
+        // Split like `estimated_cost()` so the per-model costs add up to it
+        for share in session.cost_breakdown().models {
+            let model = normalize_model_id(&share.model);
+            let costs = share.costs;
+            let entry = by_model.entry(model.clone()).or_insert_with(|| ModelCost {
+                model,
+                ..Default::default()
+            });
+            entry.input += costs.input_tokens;
+            entry.output += costs.output_tokens;
+            entry.cache += costs.cache_write_tokens + costs.cache_read_tokens;
+            entry.total += costs.input_tokens
+                + costs.output_tokens
+                + costs.cache_write_tokens
+                + costs.cache_read_tokens;
+            entry.cost += costs.total_cost;
+        }
+    }
+
+    let mut costs: Vec<_> = by_model.into_values().collect();
+    costs.sort_by(|a, b| {
+        b.cost
+            .total_cmp(&a.cost)
+            .then_with(|| a.model.cmp(&b.model))
+    });
+    costs
+}
+
 // ============================================================================
 // Formatters
 // ============================================================================
 
 /// Format sessions as table (human) or JSON
+///
+/// With `highlight`, cells whose underlying field matches the search query
+/// are colored (unless `no_color`).
 pub fn format_session_table(
     sessions: &[Arc<SessionMetadata>],
+    display: &ProjectDisplay,
+    highlight: Option<&SearchMatcher>,
     json: bool,
     no_color: bool,
 ) -> String {
@@ -255,7 +604,7 @@ pub fn format_session_table(
 
     for session in sessions {
         let id_short = &session.id[..8.min(session.id.len())];
-        let project = shorten_project(&session.project_path);
+        let project = display.format(&session.project_path);
         let branch = session
             .branch
             .as_ref()
@@ -274,18 +623,76 @@ pub fn format_session_table(
             .map(|m| truncate(m, 40))
             .unwrap_or_else(|| "".to_string());
 
+        let matches = |field: Option<&str>| {
+            !no_color && field.is_some_and(|f| highlight.is_some_and(|m| m.is_match(f)))
+        };
+        let cell = |text: &str, matched: bool| {
+            if matched {
+                Cell::new(text).fg(Color::Yellow)
+            } else {
+                Cell::new(text)
+            }
+        };
+
         table.add_row(Row::from(vec![
-            id_short, &project, &branch, &date, &msgs, &tokens, &duration, &preview,
+            cell(id_short, matches(Some(&session.id))),
+            cell(&project, matches(Some(&session.project_path))),
+            cell(&branch, matches(session.branch.as_deref())),
+            Cell::new(&date),
+            Cell::new(&msgs),
+            Cell::new(&tokens),
+            Cell::new(&duration),
+            cell(&preview, matches(session.first_user_message.as_deref())),
         ]));
     }
 
     table.to_string()
 }
 
+/// Format sessions one per line for shell pipelines (`--compact`)
+///
+/// `<id>\t<date>\t<project>\t<first-message>`, no header or color. Tabs and
+/// newlines inside fields become spaces so every session stays on one line.
+pub fn format_session_lines(sessions: &[Arc<SessionMetadata>], display: &ProjectDisplay) -> String {
+    let field = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+    sessions
+        .iter()
+        .map(|session| {
+            let date = session
+                .first_timestamp
+                .map(|ts| ts.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+            format!(
+                "{}\t{}\t{}\t{}",
+                session.id,
+                date,
+                field(&display.format(&session.project_path)),
+                field(session.first_user_message.as_deref().unwrap_or(""))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// One `ccboard events` line: `{"event": <name>, "data": <payload>}`
+///
+/// Same shape as the web WebSocket frames, so consumers can share a parser.
+pub fn ndjson_line(event: &str, data: serde_json::Value) -> String {
+    serde_json::json!({ "event": event, "data": data }).to_string()
+}
+
 /// Format single session info (human or JSON)
 pub fn format_session_info(session: &SessionMetadata, json: bool) -> String {
     if json {
-        return serde_json::to_string_pretty(session).unwrap_or_else(|_| "{}".to_string());
+        // Full metadata (token breakdown, models, tool_usage) plus computed cost
+        let mut value = serde_json::to_value(session).unwrap_or_default();
+        if let Some(obj) = value.as_object_mut() {
+            obj.insert(
+                "estimated_cost_usd".to_string(),
+                serde_json::json!(session.estimated_cost()),
+            );
+        }
+        return serde_json::to_string_pretty(&value).unwrap_or_else(|_| "{}".to_string());
     }
 
     let mut lines = vec![];
@@ -346,6 +753,278 @@ pub fn format_session_info(session: &SessionMetadata, json: bool) -> String {
     lines.join("\n")
 }
 
+/// Format the merged pricing table (human) or JSON
+pub fn format_pricing_table(
+    entries: &[PricingEntry],
+    currency: &Currency,
+    json: bool,
+    no_color: bool,
+) -> String {
+    if json {
+        return serde_json::to_string_pretty(entries).unwrap_or_else(|_| "[]".to_string());
+    }
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+
+    let headers = [
+        "Model".to_string(),
+        format!("Input {}/M", currency.symbol),
+        format!("Output {}/M", currency.symbol),
+        "Cache Read".to_string(),
+        "Cache Write".to_string(),
+        "Source".to_string(),
+    ];
+    if no_color {
+        table.set_header(headers.to_vec());
+    } else {
+        table.set_header(
+            headers
+                .iter()
+                .map(|h| Cell::new(h).fg(Color::Cyan))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    for entry in entries {
+        let p = &entry.pricing;
+        let mut cells = vec![Cell::new(&entry.model)];
+        cells.extend(
+            [
+                currency.format_cost(p.input_price_per_million),
+                currency.format_cost(p.output_price_per_million),
+                format!("{:.2}x", p.cache_read_multiplier),
+                format!("{:.2}x", p.cache_write_multiplier),
+            ]
+            .into_iter()
+            .map(|v| Cell::new(v).set_alignment(CellAlignment::Right)),
+        );
+        cells.push(Cell::new(entry.source.as_str()));
+        table.add_row(cells);
+    }
+
+    table.to_string()
+}
+
+/// List the TUI color schemes, each with a swatch of its palette
+///
+/// Swatches use 24-bit ANSI colors; with `no_color` only names are printed.
+pub fn format_theme_list(no_color: bool) -> String {
+    use ccboard_core::models::config::ColorScheme;
+    use ccboard_tui::theme::Palette;
+
+    let width = ColorScheme::ALL
+        .iter()
+        .map(|s| s.slug().len())
+        .max()
+        .unwrap_or(0);
+    let mut lines = Vec::new();
+    for scheme in ColorScheme::ALL {
+        let mut line = format!("{:<width$}  ", scheme.slug());
+        if !no_color {
+            for (_, (r, g, b)) in Palette::new(scheme).swatches() {
+                line.push_str(&format!("\x1b[38;2;{};{};{}m██\x1b[0m", r, g, b));
+            }
+            line.push_str("  ");
+        }
+        line.push_str(scheme.label());
+        lines.push(line);
+    }
+    if !no_color {
+        let names: Vec<&str> = Palette::new(ColorScheme::Dark)
+            .swatches()
+            .iter()
+            .map(|(name, _)| *name)
+            .collect();
+        lines.push(String::new());
+        lines.push(format!("Swatches: {}", names.join(", ")));
+    }
+    lines.join("\n")
+}
+
+/// Format per-model costs as table (human) or JSON
+pub fn format_cost_table(
+    costs: &[ModelCost],
+    since: Option<&str>,
+    currency: &Currency,
+    json: bool,
+    no_color: bool,
+) -> String {
+    let total_cost: f64 = costs.iter().map(|c| c.cost).sum();
+
+    if json {
+        let value = serde_json::json!({
+            "since": since,
+            "total_cost": total_cost,
+            "models": costs,
+        });
+        return serde_json::to_string_pretty(&value).unwrap_or_else(|_| "{}".to_string());
+    }
+
+    if costs.is_empty() {
+        return "No sessions found.".to_string();
+    }
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+
+    let headers = ["Model", "Input", "Output", "Cache", "Total", "Cost"];
+    if no_color {
+        table.set_header(headers.to_vec());
+    } else {
+        table.set_header(
+            headers
+                .iter()
+                .map(|h| Cell::new(h).fg(Color::Cyan))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    let row = |model: &str, input, output, cache, total, cost: f64| {
+        let mut cells = vec![Cell::new(model)];
+        cells.extend(
+            [input, output, cache, total]
+                .into_iter()
+                .map(|n| Cell::new(format_tokens(n)).set_alignment(CellAlignment::Right)),
+        );
+        cells.push(Cell::new(currency.format_cost(cost)).set_alignment(CellAlignment::Right));
+        Row::from(cells)
+    };
+
+    for c in costs {
+        table.add_row(row(&c.model, c.input, c.output, c.cache, c.total, c.cost));
+    }
+    table.add_row(row(
+        "Total",
+        costs.iter().map(|c| c.input).sum(),
+        costs.iter().map(|c| c.output).sum(),
+        costs.iter().map(|c| c.cache).sum(),
+        costs.iter().map(|c| c.total).sum(),
+        total_cost,
+    ));
+
+    table.to_string()
+}
+
+/// Parse a `--plan` value, rejecting names `SubscriptionPlan::parse` doesn't know
+pub fn parse_plan(s: &str) -> Result<SubscriptionPlan, String> {
+    match SubscriptionPlan::parse(s) {
+        SubscriptionPlan::Unknown => Err(format!(
+            "unknown plan '{}' (valid: pro, max5x, max20x, api)",
+            s
+        )),
+        plan => Ok(plan),
+    }
+}
+
+/// Format month-to-date usage against the plan allowance, projected to month end
+pub fn format_quota(
+    estimate: &UsageEstimate,
+    today: NaiveDate,
+    currency: &Currency,
+    json: bool,
+    no_color: bool,
+) -> String {
+    let projected = estimate.projected_month_cost(today);
+    let percent_month = estimate
+        .budget_usd
+        .map(|budget| estimate.cost_month / budget * 100.0);
+    let percent_projected = estimate.percent_projected(today);
+
+    if json {
+        let value = serde_json::json!({
+            "plan": estimate.plan.id(),
+            "plan_name": estimate.plan.display_name(),
+            "allowance_usd": estimate.budget_usd,
+            "cost_today": estimate.cost_today,
+            "cost_week": estimate.cost_week,
+            "cost_month": estimate.cost_month,
+            "percent_month": percent_month,
+            "projected_month_cost": projected,
+            "percent_projected": percent_projected,
+            "day_of_month": today.day(),
+            "days_in_month": days_in_month(today),
+        });
+        return serde_json::to_string_pretty(&value).unwrap_or_else(|_| "{}".to_string());
+    }
+
+    let mut lines = Vec::new();
+    lines.push(match estimate.budget_usd {
+        Some(budget) => format!(
+            "Plan:        {} ({}/month)",
+            estimate.plan.display_name(),
+            currency.format_cost(budget)
+        ),
+        None => format!("Plan:        {}", estimate.plan.display_name()),
+    });
+    lines.push(format!(
+        "Today:       {}",
+        currency.format_cost(estimate.cost_today)
+    ));
+    lines.push(format!(
+        "This week:   {}",
+        currency.format_cost(estimate.cost_week)
+    ));
+
+    let with_bar = |label: &str, cost: f64, pct: Option<f64>| match pct {
+        Some(pct) => format!(
+            "{:<13}{:<10}{} {:>6.1}%",
+            label,
+            currency.format_cost(cost),
+            usage_bar(pct, no_color),
+            pct
+        ),
+        None => format!("{:<13}{}", label, currency.format_cost(cost)),
+    };
+    lines.push(with_bar("This month:", estimate.cost_month, percent_month));
+    lines.push(format!(
+        "{}  (day {} of {})",
+        with_bar("Projected:", projected, percent_projected),
+        today.day(),
+        days_in_month(today)
+    ));
+
+    match (estimate.budget_usd, percent_projected) {
+        (Some(budget), Some(pct)) if pct > 100.0 => {
+            lines.push(String::new());
+            lines.push(format!(
+                "Projected to exceed the plan allowance by {} this month",
+                currency.format_cost(projected - budget)
+            ));
+        }
+        (None, _) => {
+            lines.push(String::new());
+            lines.push(if estimate.plan == SubscriptionPlan::Api {
+                "Pay-as-you-go: no fixed allowance to compare against".to_string()
+            } else {
+                "No plan configured: pass --plan or set \"subscriptionPlan\" in settings.json"
+                    .to_string()
+            });
+        }
+        _ => {}
+    }
+
+    lines.join("\n")
+}
+
+/// 20-cell bar filled to `pct` (capped at 100), green/yellow/red unless `no_color`
+fn usage_bar(pct: f64, no_color: bool) -> String {
+    const WIDTH: usize = 20;
+    let filled = ((pct.clamp(0.0, 100.0) / 100.0) * WIDTH as f64).round() as usize;
+    let bar = format!("[{}{}]", "█".repeat(filled), "░".repeat(WIDTH - filled));
+    if no_color {
+        return bar;
+    }
+    let color = if pct >= 100.0 {
+        "31"
+    } else if pct >= 80.0 {
+        "33"
+    } else {
+        "32"
+    };
+    format!("\x1b[{}m{}\x1b[0m", color, bar)
+}
+
 // ============================================================================
 // Utilities
 // ============================================================================
@@ -360,6 +1039,103 @@ fn format_tokens(tokens: u64) -> String {
     }
 }
 
+/// Characters of a matching line shown on each side of the match
+const GREP_CONTEXT_CHARS: usize = 60;
+
+/// Format `ccboard grep` hits: a header per session, then each matching line
+/// with the line before and after it (dimmed)
+///
+/// At most `per_session` matches are printed per session; extra ones are
+/// summarized as "…".
+pub fn format_grep_results(
+    hits: &[(
+        Arc<SessionMetadata>,
+        Vec<ccboard_core::parsers::ContentMatch>,
+    )],
+    display: &ProjectDisplay,
+    per_session: usize,
+    no_color: bool,
+) -> String {
+    use ccboard_core::models::MessageRole;
+
+    let paint = |code: &str, text: &str| {
+        if no_color {
+            text.to_string()
+        } else {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        }
+    };
+
+    let mut out = String::new();
+    for (session, matches) in hits {
+        let date = session
+            .first_timestamp
+            .map(|ts| ts.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        out.push_str(&format!(
+            "{}  {}  {}\n",
+            paint("1;36", &session.id[..8.min(session.id.len())]),
+            display.format(&session.project_path),
+            paint("2", &date)
+        ));
+
+        for m in matches.iter().take(per_session) {
+            let role = match m.role {
+                MessageRole::User => "user",
+                MessageRole::Assistant => "assistant",
+                MessageRole::System => "system",
+            };
+            if let Some(ref before) = m.before {
+                out.push_str(&format!(
+                    "  {}\n",
+                    paint("2", &truncate(before, GREP_CONTEXT_CHARS * 2))
+                ));
+            }
+            let (head, hit, tail) = grep_snippet(&m.line, m.range.clone());
+            out.push_str(&format!(
+                "  {} {}{}{}\n",
+                paint("2", &format!("[{}]", role)),
+                head,
+                paint("1;33", hit),
+                tail
+            ));
+            if let Some(ref after) = m.after {
+                out.push_str(&format!(
+                    "  {}\n",
+                    paint("2", &truncate(after, GREP_CONTEXT_CHARS * 2))
+                ));
+            }
+        }
+        if matches.len() > per_session {
+            out.push_str(&format!("  {}\n", paint("2", "… more matches")));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Split `line` around the match, keeping `GREP_CONTEXT_CHARS` on each side
+fn grep_snippet(line: &str, range: std::ops::Range<usize>) -> (String, &str, String) {
+    let before = &line[..range.start];
+    let after = &line[range.end..];
+
+    let skip = before.chars().count().saturating_sub(GREP_CONTEXT_CHARS);
+    let head = if skip > 0 {
+        format!("…{}", before.chars().skip(skip).collect::<String>())
+    } else {
+        before.to_string()
+    };
+    let tail = if after.chars().count() > GREP_CONTEXT_CHARS {
+        format!(
+            "{}…",
+            after.chars().take(GREP_CONTEXT_CHARS).collect::<String>()
+        )
+    } else {
+        after.to_string()
+    };
+    (head, &line[range], tail)
+}
+
 fn truncate(s: &str, max: usize) -> String {
     let char_count = s.chars().count();
     if char_count <= max {
@@ -370,16 +1146,6 @@ fn truncate(s: &str, max: usize) -> String {
     }
 }
 
-fn shorten_project(path: &str) -> String {
-    // Extract last 2 path components
-    let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
-    if parts.len() > 2 {
-        format!("…/{}", parts[parts.len() - 2..].join("/"))
-    } else {
-        path.to_string()
-    }
-}
-
 // ============================================================================
 // Tests
 // ============================================================================
@@ -388,6 +1154,17 @@ fn shorten_project(path: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ndjson_line() {
+        let event = ccboard_core::DataEvent::SessionCreated("abc".into());
+        let line = ndjson_line(event.kind(), event.payload());
+        assert!(!line.contains('\n'));
+
+        let parsed: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed["event"], "session_created");
+        assert_eq!(parsed["data"]["id"], "abc");
+    }
+
     #[test]
     fn test_date_filter_parse_days() {
         let filter = DateFilter::parse("7d").unwrap();
@@ -433,6 +1210,41 @@ mod tests {
         assert!(DateFilter::parse("invalid").is_err());
     }
 
+    #[test]
+    fn test_date_filter_with_before() {
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+
+        let window =
+            DateFilter::with_before(Some(DateFilter::parse("2026-03-02").unwrap()), "2026-03-04")
+                .unwrap();
+        assert!(!window.matches(&at("2026-03-01T23:59:59Z")));
+        assert!(window.matches(&at("2026-03-02T00:00:00Z")));
+        // The --before day is included
+        assert!(window.matches(&at("2026-03-04T23:59:59Z")));
+        assert!(!window.matches(&at("2026-03-05T00:00:00Z")));
+
+        let open_start = DateFilter::with_before(None, "2026-03-04").unwrap();
+        assert!(open_start.matches(&at("2001-01-01T00:00:00Z")));
+
+        // Same day is a valid one-day window; an inverted range is not
+        assert!(DateFilter::with_before(
+            Some(DateFilter::parse("2026-03-04").unwrap()),
+            "2026-03-04"
+        )
+        .is_ok());
+        let Err(err) =
+            DateFilter::with_before(Some(DateFilter::parse("2026-03-05").unwrap()), "2026-03-04")
+        else {
+            panic!("Expected an inverted range to be rejected");
+        };
+        assert!(matches!(err, CliError::InvalidDateRange { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Invalid date range: --after 2026-03-05 is later than --before 2026-03-04"
+        );
+        assert!(DateFilter::with_before(None, "March").is_err());
+    }
+
     #[test]
     fn test_truncate_ascii() {
         assert_eq!(truncate("hello world", 20), "hello world");
@@ -503,13 +1315,23 @@ mod tests {
             first_user_message: Some("Test message".to_string()),
             has_subagents: false,
             parent_session_id: None,
+            resumed_from: None,
+            compaction_count: 0,
             duration_seconds: Some(1800),
             branch: Some("main".to_string()),
             tool_usage: std::collections::HashMap::new(),
             tool_token_usage: std::collections::HashMap::new(),
+            tool_followup_tokens: std::collections::HashMap::new(),
             source_tool: Default::default(),
             lines_added: 0,
             lines_removed: 0,
+            tool_result_bytes: 0,
+            peak_context_tokens: 0,
+            peak_context_pct: 0.0,
+            malformed_lines: 0,
+            estimated_extra_tokens: 0,
+            cwd: None,
+            author: None,
         })
     }
 
@@ -555,26 +1377,379 @@ mod tests {
         assert!(matches!(result, Err(CliError::NoResults { .. })));
     }
 
+    #[test]
+    fn test_find_by_id_suggests_near_match() {
+        let sessions = vec![
+            create_test_session("abc12345-6789-aaaa"),
+            create_test_session("xyz789ghi012"),
+        ];
+
+        // One typo in a truncated ID: strict lookup suggests, fuzzy resolves
+        let result = find_by_id_or_prefix(&sessions, "abc12845-67");
+        match result {
+            Err(CliError::NearMatches { suggestions, .. }) => {
+                assert_eq!(suggestions, vec!["abc12345 (/test)"]);
+            }
+            other => panic!(
+                "expected NearMatches, got {:?}",
+                other.map(|s| s.id.clone())
+            ),
+        }
+        let err = find_by_id_or_prefix(&sessions, "abc12845-67").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No sessions match 'abc12845-67'. Did you mean abc12345 (/test)?"
+        );
+
+        let resolved = find_session(&sessions, "abc12845-67", true).unwrap();
+        assert_eq!(resolved.id, "abc12345-6789-aaaa");
+
+        // Dropped character
+        let resolved = find_session(&sessions, "abc1245-6789", true).unwrap();
+        assert_eq!(resolved.id, "abc12345-6789-aaaa");
+
+        // Too far from anything
+        assert!(matches!(
+            find_session(&sessions, "qqqqqqqqqq", true),
+            Err(CliError::NoResults { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_by_id_fuzzy_ambiguous_lists_top_three() {
+        let sessions = vec![
+            create_test_session("abc12345-0000"),
+            create_test_session("abc12346-0000"),
+            create_test_session("abc12347-0000"),
+            create_test_session("abc12348-0000"),
+        ];
+
+        match find_session(&sessions, "abc12340-00", true) {
+            Err(CliError::NearMatches { suggestions, .. }) => {
+                assert_eq!(suggestions.len(), 3);
+                assert!(suggestions[0].starts_with("abc12345"));
+                assert!(suggestions[0].ends_with("/test"));
+            }
+            other => panic!(
+                "expected NearMatches, got {:?}",
+                other.map(|s| s.id.clone())
+            ),
+        }
+    }
+
+    #[test]
+    fn test_search_sessions_substring_and_regex() {
+        let sessions = vec![
+            create_test_session("abc123def456"),
+            create_test_session("xyz789ghi012"),
+        ];
+
+        let substring = SearchMatcher::new("ABC123", false).unwrap();
+        let results = search_sessions(&sessions, &substring, None, 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "abc123def456");
+
+        // Regex metacharacters are literal in substring mode
+        let literal = SearchMatcher::new("^xyz", false).unwrap();
+        assert!(search_sessions(&sessions, &literal, None, 10).is_empty());
+
+        let regex = SearchMatcher::new(r"^(abc|XYZ)\d{3}", true).unwrap();
+        assert_eq!(search_sessions(&sessions, &regex, None, 10).len(), 2);
+    }
+
+    #[test]
+    fn test_format_grep_results() {
+        use ccboard_core::models::MessageRole;
+        use ccboard_core::parsers::ContentMatch;
+
+        let hit = |line: &str, range: std::ops::Range<usize>| ContentMatch {
+            role: MessageRole::Assistant,
+            timestamp: None,
+            line: line.to_string(),
+            range,
+            before: Some("Looking at it.".to_string()),
+            after: None,
+        };
+        let long = format!("{}needle{}", "a".repeat(80), "b".repeat(80));
+        let hits = vec![(
+            create_test_session("abc123def456"),
+            vec![
+                hit("the needle is here", 4..10),
+                hit(&long, 80..86),
+                hit("needle", 0..6),
+            ],
+        )];
+
+        let out = format_grep_results(&hits, &ProjectDisplay::default(), 2, true);
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].starts_with("abc123de  "));
+        assert_eq!(lines[1], "  Looking at it.");
+        assert_eq!(lines[2], "  [assistant] the needle is here");
+        // Long lines keep 60 chars around the match
+        assert_eq!(
+            lines[4],
+            format!("  [assistant] …{}needle{}…", "a".repeat(60), "b".repeat(60))
+        );
+        assert_eq!(lines[5], "  … more matches");
+        assert!(!out.contains('\x1b'));
+
+        let colored = format_grep_results(&hits, &ProjectDisplay::default(), 2, false);
+        assert!(colored.contains("\x1b[1;33mneedle\x1b[0m"));
+    }
+
+    #[test]
+    fn test_search_invalid_regex_is_error() {
+        let result = SearchMatcher::new("fix(", true);
+        assert!(matches!(result, Err(CliError::InvalidPattern { .. })));
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("Invalid regex 'fix('"));
+    }
+
     #[test]
     fn test_format_session_table_empty() {
         let sessions: Vec<Arc<SessionMetadata>> = vec![];
-        let output = format_session_table(&sessions, false, false);
+        let output =
+            format_session_table(&sessions, &ProjectDisplay::default(), None, false, false);
         assert!(output.contains("No sessions found"));
     }
 
     #[test]
     fn test_format_session_table_json() {
         let sessions = vec![create_test_session("abc123def456")];
-        let output = format_session_table(&sessions, true, false);
+        let output = format_session_table(&sessions, &ProjectDisplay::default(), None, true, false);
         assert!(output.contains("abc123def456"));
         assert!(output.starts_with('['));
     }
 
+    #[test]
+    fn test_format_session_lines() {
+        let mut session = (*create_test_session("abc123def456")).clone();
+        session.first_timestamp = Some("2026-03-02T09:05:00Z".parse().unwrap());
+        session.first_user_message = Some("Fix the\tlogin\nbug".to_string());
+        let sessions = vec![Arc::new(session), create_test_session("xyz789ghi012")];
+
+        let output = format_session_lines(&sessions, &ProjectDisplay::default());
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0].split('\t').collect::<Vec<_>>(),
+            [
+                "abc123def456",
+                "2026-03-02 09:05",
+                "/test",
+                "Fix the login bug"
+            ]
+        );
+        assert!(lines[1].starts_with("xyz789ghi012\t"));
+    }
+
+    #[test]
+    fn test_cost_by_model() {
+        let mut opus = (*create_test_session("opus-session")).clone();
+        opus.models_used = vec!["claude-opus-4-5".to_string()];
+        opus.input_tokens = 1_000_000;
+        opus.output_tokens = 0;
+        opus.first_timestamp = Some(Utc::now() - chrono::Duration::days(40));
+
+        let sessions = vec![
+            create_test_session("abc123def456"),
+            create_test_session("xyz789ghi012"),
+            Arc::new(opus),
+        ];
+
+        let all = cost_by_model(&sessions, None);
+        assert_eq!(all.len(), 2);
+        // Most expensive first
+        assert_eq!(all[0].model, "claude-opus-4-5");
+        assert_eq!(all[1].input, 1000);
+        assert_eq!(all[1].total, 2000);
+
+        let recent = cost_by_model(&sessions, Some(&DateFilter::parse("7d").unwrap()));
+        assert_eq!(recent.len(), 1);
+
+        let json = format_cost_table(&recent, Some("7d"), &Currency::default(), true, false);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["since"], "7d");
+        assert_eq!(value["models"][0]["output"], 1000);
+        assert!(value["models"][0].get("cache").is_some());
+    }
+
+    #[test]
+    fn test_cost_by_model_splits_multi_model_sessions() {
+        let mut mixed = (*create_test_session("mixed")).clone();
+        mixed.models_used = vec![
+            "claude-opus-4-5".to_string(),
+            "claude-sonnet-4-5".to_string(),
+        ];
+        mixed.model_segments = vec![
+            ("claude-opus-4-5".to_string(), 1),
+            ("claude-sonnet-4-5".to_string(), 3),
+        ];
+        mixed.input_tokens = 1_000_000;
+        mixed.output_tokens = 100_000;
+        let expected = mixed.estimated_cost();
+
+        let costs = cost_by_model(&[Arc::new(mixed)], None);
+        assert_eq!(costs.len(), 2);
+        let total: f64 = costs.iter().map(|c| c.cost).sum();
+        assert!((total - expected).abs() < 1e-9);
+        let sonnet = costs
+            .iter()
+            .find(|c| c.model == "claude-sonnet-4-5")
+            .unwrap();
+        assert_eq!(sonnet.input, 750_000);
+    }
+
+    #[test]
+    fn test_format_theme_list() {
+        let plain = format_theme_list(true);
+        assert_eq!(
+            plain,
+            "dark             Dark\n\
+             light            Light\n\
+             colorblind-safe  Color-blind safe"
+        );
+
+        let colored = format_theme_list(false);
+        assert!(colored.contains("\x1b[38;2;13;17;23m██"));
+        assert!(colored.contains("Swatches: bg, surface, fg"));
+    }
+
+    #[test]
+    fn test_format_pricing_table() {
+        let entries = ccboard_core::pricing::pricing_table();
+        assert!(!entries.is_empty());
+
+        let human = format_pricing_table(&entries, &Currency::default(), false, true);
+        assert!(human.contains("claude-opus-4-5"));
+        assert!(human.contains("Source"));
+
+        let json = format_pricing_table(&entries, &Currency::default(), true, true);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let opus = value
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|e| e["model"] == "claude-opus-4-5")
+            .unwrap();
+        assert!(opus["input_price_per_million"].as_f64().is_some());
+        assert!(matches!(
+            opus["source"].as_str(),
+            Some("cache") | Some("embedded")
+        ));
+    }
+
     #[test]
     fn test_format_session_info_json() {
         let session = create_test_session("abc123def456");
         let output = format_session_info(&session, true);
         assert!(output.contains("abc123def456"));
         assert!(output.starts_with('{'));
+
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(value["cache_read_tokens"], session.cache_read_tokens);
+        assert!(value["estimated_cost_usd"].as_f64().unwrap() > 0.0);
+        assert!(value["tool_usage"].is_object());
+        assert!(value["models_used"].is_array());
+    }
+
+    #[test]
+    fn test_resolve_export_format_from_extension() {
+        let supported = ["csv", "json", "md"];
+        let resolve = |p: &str| resolve_export_format(Some(Path::new(p)), None, &supported);
+
+        assert_eq!(resolve("report.json").unwrap(), "json");
+        assert_eq!(resolve("out/Sessions.CSV").unwrap(), "csv");
+        assert_eq!(resolve("notes.md").unwrap(), "md");
+        assert!(resolve("report.txt").is_err());
+        assert!(resolve("report").is_err());
+        // Known extension, but not offered by this export
+        assert!(resolve("report.html").is_err());
+
+        // Conversation export names Markdown "markdown"
+        let conv =
+            resolve_export_format(Some(Path::new("c.md")), None, &["markdown", "json", "html"]);
+        assert_eq!(conv.unwrap(), "markdown");
+    }
+
+    #[test]
+    fn test_resolve_export_format_explicit_wins() {
+        let format = resolve_export_format(
+            Some(Path::new("report.json")),
+            Some("csv".into()),
+            &["csv", "json"],
+        );
+        assert_eq!(format.unwrap(), "csv");
+
+        // stdout: nothing to infer from
+        assert!(resolve_export_format(None, None, &["csv", "json"]).is_err());
+        assert_eq!(
+            resolve_export_format(None, Some("json".into()), &["csv", "json"]).unwrap(),
+            "json"
+        );
+    }
+
+    #[test]
+    fn test_conversation_export_path() {
+        let dir = Path::new("/archive");
+        assert_eq!(
+            conversation_export_path(dir, "/Users/me/app", "abc", "md"),
+            PathBuf::from("/archive/Users-me-app/abc.md")
+        );
+        assert_eq!(
+            conversation_export_path(dir, r"C:\code\api", "abc", "html"),
+            PathBuf::from("/archive/C--code-api/abc.html")
+        );
+        // Never escapes the output directory
+        assert_eq!(
+            conversation_export_path(dir, "..", "abc", "md"),
+            PathBuf::from("/archive/unknown/abc.md")
+        );
+    }
+
+    #[test]
+    fn test_format_quota() {
+        assert_eq!(parse_plan("Max-20x"), Ok(SubscriptionPlan::Max20x));
+        assert!(parse_plan("team").unwrap_err().contains("valid: pro"));
+
+        let estimate = UsageEstimate {
+            cost_today: 2.0,
+            cost_week: 6.0,
+            cost_month: 15.0,
+            plan: SubscriptionPlan::Pro,
+            budget_usd: Some(20.0),
+        };
+        let today = NaiveDate::from_ymd_opt(2025, 6, 10).unwrap();
+
+        let text = format_quota(&estimate, today, &Currency::default(), false, true);
+        assert!(text.contains("Plan:        Claude Pro ($20.00/month)"));
+        assert!(text.contains("This month:  $15.00    [███████████████░░░░░]   75.0%"));
+        assert!(
+            text.contains("Projected:   $45.00    [████████████████████]  225.0%  (day 10 of 30)")
+        );
+        assert!(text.contains("exceed the plan allowance by $25.00"));
+
+        let json: serde_json::Value = serde_json::from_str(&format_quota(
+            &estimate,
+            today,
+            &Currency::default(),
+            true,
+            true,
+        ))
+        .unwrap();
+        assert_eq!(json["plan"], "pro");
+        assert_eq!(json["projected_month_cost"], 45.0);
+        assert_eq!(json["days_in_month"], 30);
+
+        let api = UsageEstimate {
+            plan: SubscriptionPlan::Api,
+            budget_usd: None,
+            ..estimate
+        };
+        let text = format_quota(&api, today, &Currency::default(), false, true);
+        assert!(!text.contains('█'));
+        assert!(text.contains("Pay-as-you-go"));
     }
 }
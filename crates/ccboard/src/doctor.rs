@@ -0,0 +1,318 @@
+//! Doctor subcommand — diagnoses common setup problems
+//!
+//! Prints a pass/warn/fail checklist meant to be pasted into bug reports.
+//! Only failures make the command exit nonzero; warnings describe degraded but
+//! working setups (no sessions yet, stale pricing, ...).
+//!
+//! `--json` prints the same checks as an array of `{ check, status, detail }`
+//! objects for CI and issue triage.
+
+use serde::Serialize;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    fn marker(self, no_color: bool) -> &'static str {
+        match (self, no_color) {
+            (CheckStatus::Pass, true) => "[ok]  ",
+            (CheckStatus::Warn, true) => "[warn]",
+            (CheckStatus::Fail, true) => "[FAIL]",
+            (CheckStatus::Pass, false) => "\x1b[32m✓\x1b[0m",
+            (CheckStatus::Warn, false) => "\x1b[33m!\x1b[0m",
+            (CheckStatus::Fail, false) => "\x1b[31m✗\x1b[0m",
+        }
+    }
+}
+
+/// One line of the checklist
+#[derive(Debug, Clone, Serialize)]
+pub struct Check {
+    #[serde(rename = "check")]
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Run all checks, print the checklist (or JSON), and return whether every
+/// check passed without failures
+pub fn run_doctor(claude_home: &Path, no_color: bool, json: bool) -> bool {
+    let checks = run_checks(claude_home, std::env::var_os("PATH"));
+    let passed = !checks.iter().any(|c| c.status == CheckStatus::Fail);
+
+    if json {
+        println!("{}", format_checks_json(&checks));
+        return passed;
+    }
+
+    println!("ccboard doctor (v{})", env!("CARGO_PKG_VERSION"));
+    println!();
+    for check in &checks {
+        println!(
+            "{} {:<14} {}",
+            check.status.marker(no_color),
+            check.name,
+            check.detail
+        );
+    }
+
+    let failed = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .count();
+    let warned = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Warn)
+        .count();
+    println!();
+    println!(
+        "{} passed, {} warning(s), {} failure(s)",
+        checks.len() - failed - warned,
+        warned,
+        failed
+    );
+
+    passed
+}
+
+/// Checks as a pretty-printed JSON array
+pub fn format_checks_json(checks: &[Check]) -> String {
+    serde_json::to_string_pretty(checks).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Every check, in display order
+///
+/// `path_var` is the `PATH` to search for the `claude` binary (injected for tests).
+pub fn run_checks(claude_home: &Path, path_var: Option<OsString>) -> Vec<Check> {
+    vec![
+        check_claude_home(claude_home),
+        check_sessions(claude_home),
+        check_cache_writable(claude_home),
+        check_claude_binary(path_var),
+        check_pricing_cache(),
+    ]
+}
+
+fn check_claude_home(claude_home: &Path) -> Check {
+    const NAME: &str = "Claude home";
+    if !claude_home.is_dir() {
+        return Check::new(
+            NAME,
+            CheckStatus::Fail,
+            format!(
+                "{} not found (run `claude` once, or pass --claude-home)",
+                claude_home.display()
+            ),
+        );
+    }
+    match std::fs::read_dir(claude_home) {
+        Ok(_) => Check::new(NAME, CheckStatus::Pass, claude_home.display().to_string()),
+        Err(e) => Check::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("{} is not readable: {}", claude_home.display(), e),
+        ),
+    }
+}
+
+fn check_sessions(claude_home: &Path) -> Check {
+    const NAME: &str = "Sessions";
+    let projects = claude_home.join("projects");
+    if !projects.is_dir() {
+        return Check::new(
+            NAME,
+            CheckStatus::Warn,
+            format!("{} not found (no sessions yet?)", projects.display()),
+        );
+    }
+
+    // projects/<encoded-path>/<session>.jsonl
+    let count: usize = std::fs::read_dir(&projects)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|project| std::fs::read_dir(project.path()).ok())
+        .map(|sessions| {
+            sessions
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "jsonl"))
+                .count()
+        })
+        .sum();
+
+    if count == 0 {
+        Check::new(
+            NAME,
+            CheckStatus::Warn,
+            format!("no .jsonl session files in {}", projects.display()),
+        )
+    } else {
+        Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!("{} session file(s) in {}", count, projects.display()),
+        )
+    }
+}
+
+fn check_cache_writable(claude_home: &Path) -> Check {
+    const NAME: &str = "SQLite cache";
+    if !claude_home.is_dir() {
+        // Don't create ~/.claude just to probe it
+        return Check::new(NAME, CheckStatus::Warn, "skipped (no Claude home)");
+    }
+    let cache_dir = claude_home.join("cache");
+    let probe = cache_dir.join(".ccboard-doctor-probe");
+
+    let result = std::fs::create_dir_all(&cache_dir)
+        .and_then(|_| std::fs::write(&probe, b"ok"))
+        .and_then(|_| std::fs::remove_file(&probe));
+
+    match result {
+        Ok(()) => Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!("{} is writable", cache_dir.display()),
+        ),
+        Err(e) => Check::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("{} is not writable: {}", cache_dir.display(), e),
+        ),
+    }
+}
+
+fn check_claude_binary(path_var: Option<OsString>) -> Check {
+    const NAME: &str = "claude CLI";
+    match find_in_path("claude", path_var) {
+        Some(path) => Check::new(NAME, CheckStatus::Pass, path.display().to_string()),
+        None => Check::new(
+            NAME,
+            CheckStatus::Warn,
+            "`claude` not found on PATH (resume and summaries won't work)",
+        ),
+    }
+}
+
+fn check_pricing_cache() -> Check {
+    const NAME: &str = "Pricing cache";
+    use ccboard_core::pricing::cache::{cached_pricing_age, is_expired};
+
+    match cached_pricing_age() {
+        Ok(Some(age)) if is_expired(age) => Check::new(
+            NAME,
+            CheckStatus::Warn,
+            format!(
+                "stale ({} days old), using embedded prices; run `ccboard pricing update`",
+                age.num_days()
+            ),
+        ),
+        Ok(Some(age)) => Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!("fresh ({} days old)", age.num_days()),
+        ),
+        Ok(None) => Check::new(
+            NAME,
+            CheckStatus::Warn,
+            "not downloaded, using embedded prices; run `ccboard pricing update`",
+        ),
+        Err(e) => Check::new(NAME, CheckStatus::Warn, format!("unreadable: {:#}", e)),
+    }
+}
+
+/// First executable named `name` in `path_var`
+fn find_in_path(name: &str, path_var: Option<OsString>) -> Option<PathBuf> {
+    let candidates: Vec<String> = if cfg!(windows) {
+        vec![format!("{}.exe", name), format!("{}.cmd", name)]
+    } else {
+        vec![name.to_string()]
+    };
+    std::env::split_paths(&path_var?)
+        .flat_map(|dir| candidates.iter().map(move |c| dir.join(c)))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_home(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ccboard-doctor-{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_checks_on_missing_home() {
+        let home = temp_home("missing");
+        let checks = run_checks(&home, None);
+
+        assert_eq!(checks[0].status, CheckStatus::Fail);
+        assert_eq!(checks[1].status, CheckStatus::Warn);
+        assert!(!home.exists(), "doctor must not create the Claude home");
+        // No PATH at all: claude is not found, which is only a warning
+        assert_eq!(checks[3].status, CheckStatus::Warn);
+    }
+
+    #[test]
+    fn test_checks_on_valid_home() {
+        let home = temp_home("valid");
+        let project = home.join("projects").join("-tmp-app");
+        std::fs::create_dir_all(&project).unwrap();
+        std::fs::write(project.join("abc.jsonl"), "{}\n").unwrap();
+
+        let bin = home.join("bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        let exe = if cfg!(windows) {
+            "claude.exe"
+        } else {
+            "claude"
+        };
+        std::fs::write(bin.join(exe), "").unwrap();
+
+        let checks = run_checks(&home, Some(bin.into_os_string()));
+        assert_eq!(checks[0].status, CheckStatus::Pass);
+        assert_eq!(checks[1].status, CheckStatus::Pass);
+        assert!(checks[1].detail.starts_with("1 session file"));
+        assert_eq!(checks[2].status, CheckStatus::Pass);
+        assert!(!home.join("cache/.ccboard-doctor-probe").exists());
+        assert_eq!(checks[3].status, CheckStatus::Pass);
+
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    fn test_format_checks_json() {
+        let checks = vec![
+            Check::new("Claude home", CheckStatus::Pass, "/home/me/.claude"),
+            Check::new("claude CLI", CheckStatus::Warn, "not found"),
+        ];
+        let value: serde_json::Value = serde_json::from_str(&format_checks_json(&checks)).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([
+                { "check": "Claude home", "status": "pass", "detail": "/home/me/.claude" },
+                { "check": "claude CLI", "status": "warn", "detail": "not found" },
+            ])
+        );
+    }
+}
@@ -1,10 +1,14 @@
 //! ccboard - Unified Claude Code Management Dashboard
 
 mod cli;
+mod doctor;
 mod hook;
+mod notifications;
 mod setup;
 
 use anyhow::{Context, Result};
+use ccboard_core::models::config::ColorScheme;
+use ccboard_core::project_filter::ProjectGlobFilter;
 use ccboard_core::DataStore;
 use clap::{Parser, Subcommand};
 use indicatif::{ProgressBar, ProgressStyle};
@@ -34,8 +38,12 @@ use std::sync::Arc;
                     ccboard web --port 8080          # Custom port\n\
                     ccboard both                     # Run both TUI and web server\n\
                     ccboard stats                    # Print stats summary\n\
+                    ccboard stats --watch            # Reprint whenever data changes\n\
+                    ccboard events --since-startup   # Stream data events as NDJSON\n\
                     ccboard search \"query\"           # Search sessions\n\
                     ccboard recent 10                # Show 10 most recent sessions\n\
+                    ccboard cost --since 7d          # Estimated cost per model\n\
+                    ccboard cat <session-id>         # Print transcript to stdout\n\
                     \n\
                   Web Frontend Workflow:\n\
                     # Option 1: Production (single command)\n\
@@ -56,14 +64,24 @@ struct Cli {
     #[command(subcommand)]
     mode: Option<Mode>,
 
-    /// Path to Claude home directory (default: ~/.claude)
+    /// Path to Claude home directory (default: ~/.claude). Repeat to merge
+    /// sessions from several homes; the first one provides stats and settings
     #[arg(long, env = "CCBOARD_CLAUDE_HOME")]
-    claude_home: Option<PathBuf>,
+    claude_home: Vec<PathBuf>,
 
     /// Focus on specific project directory
     #[arg(long)]
     project: Option<PathBuf>,
 
+    /// Only index projects matching this glob (e.g. 'services/*'). Repeat to
+    /// match any of several globs
+    #[arg(long, value_name = "GLOB")]
+    project_glob: Vec<String>,
+
+    /// Skip projects matching this glob (applied after --project-glob)
+    #[arg(long, value_name = "GLOB")]
+    exclude_glob: Vec<String>,
+
     /// Disable interactive prompts (CI/CD mode)
     #[arg(long, env = "CCBOARD_NON_INTERACTIVE")]
     non_interactive: bool,
@@ -80,21 +98,58 @@ struct Cli {
 #[derive(Subcommand)]
 enum Mode {
     /// Run TUI interface (default)
-    Tui,
+    Tui {
+        /// Tab to open on (dashboard, sessions, analytics, costs, history,
+        /// activity, mcp, config, hooks, agents, plugins, search, brain)
+        #[arg(long, default_value = "dashboard")]
+        tab: ccboard_tui::Tab,
+        /// Sub-view of the tab to open on (analytics: overview, trends,
+        /// patterns, summary, anomalies, costs, heatmap, discover)
+        #[arg(long, value_name = "VIEW")]
+        sub_view: Option<String>,
+        /// Color scheme for this run, overriding settings (see `ccboard theme list`)
+        #[arg(long, value_name = "NAME")]
+        theme: Option<ColorScheme>,
+    },
     /// Run web interface
     Web {
         /// Port for web server
         #[arg(long, default_value = "3333")]
         port: u16,
+        /// Let the web UI resume sessions (spawns `claude --resume` on this machine)
+        #[arg(long)]
+        allow_resume: bool,
     },
     /// Run both TUI and web interfaces
     Both {
         /// Port for web server
         #[arg(long, default_value = "3333")]
         port: u16,
+        /// Let the web UI resume sessions (spawns `claude --resume` on this machine)
+        #[arg(long)]
+        allow_resume: bool,
+        /// TUI color scheme for this run, overriding settings (see `ccboard theme list`)
+        #[arg(long, value_name = "NAME")]
+        theme: Option<ColorScheme>,
     },
     /// Print stats to terminal and exit
-    Stats,
+    Stats {
+        /// Keep running and reprint the summary whenever data changes
+        #[arg(long)]
+        watch: bool,
+        /// Print stats from a CSV written by `ccboard export stats` instead of scanning sessions
+        #[arg(long, value_name = "PATH", conflicts_with = "watch")]
+        from_csv: Option<PathBuf>,
+    },
+    /// Stream data events to stdout as newline-delimited JSON until killed
+    Events {
+        /// Output format (one JSON object per line)
+        #[arg(long, value_parser = ["ndjson"], default_value = "ndjson")]
+        format: String,
+        /// Emit a snapshot of current sessions and stats before streaming changes
+        #[arg(long)]
+        since_startup: bool,
+    },
     /// Clear session metadata cache and exit
     ClearCache,
     /// Search sessions by query
@@ -102,14 +157,43 @@ enum Mode {
         /// Query string (searches ID, project, message, branch)
         query: String,
         /// Date filter: 7d, 30d, 3m, 1y, YYYY-MM-DD
-        #[arg(long, short = 'd')]
+        #[arg(long, short = 'd', visible_alias = "after")]
         since: Option<String>,
+        /// Only sessions started on or before this date (YYYY-MM-DD, inclusive)
+        #[arg(long, value_name = "YYYY-MM-DD")]
+        before: Option<String>,
         /// Max results
         #[arg(long, short = 'n', default_value = "20")]
         limit: usize,
+        /// Search message text inside transcripts instead of metadata (slower)
+        #[arg(long)]
+        content: bool,
+        /// Treat the query as a case-insensitive regular expression
+        #[arg(long, conflicts_with = "content")]
+        regex: bool,
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// One session per line: id, date, project, first message (tab-separated)
+        #[arg(long, conflicts_with = "json")]
+        compact: bool,
+    },
+    /// Find a phrase anywhere in conversation text, with matching lines
+    ///
+    /// Scans every transcript in parallel (most recent sessions first), so
+    /// it is much slower than `search`.
+    Grep {
+        /// Phrase to look for (case-insensitive)
+        pattern: String,
+        /// Treat the pattern as a case-insensitive regular expression
+        #[arg(long)]
+        regex: bool,
+        /// Max sessions to show
+        #[arg(long, short = 'n', default_value = "20")]
+        limit: usize,
+        /// Only search sessions whose project path contains this text
+        #[arg(long, value_name = "PROJECT")]
+        project: Option<String>,
     },
     /// Show recent sessions
     Recent {
@@ -117,12 +201,37 @@ enum Mode {
         #[arg(default_value = "10")]
         count: usize,
         /// Date filter: 7d, 30d, 3m, 1y, YYYY-MM-DD
+        #[arg(long, short = 'd', visible_alias = "after")]
+        since: Option<String>,
+        /// Only sessions started on or before this date (YYYY-MM-DD, inclusive)
+        #[arg(long, value_name = "YYYY-MM-DD")]
+        before: Option<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+        /// One session per line: id, date, project, first message (tab-separated)
+        #[arg(long, conflicts_with = "json")]
+        compact: bool,
+    },
+    /// Print estimated cost totals with a per-model breakdown
+    Cost {
+        /// Date filter: 7d, 30d, 3m, 1y, YYYY-MM-DD (default: all time)
         #[arg(long, short = 'd')]
         since: Option<String>,
         /// Output as JSON
         #[arg(long)]
         json: bool,
     },
+    /// Compare this month's usage with the subscription plan allowance
+    Quota {
+        /// Plan to compare against: pro, max5x, max20x, api
+        /// (default: subscriptionPlan from settings.json, else auto-detected)
+        #[arg(long, value_parser = cli::parse_plan)]
+        plan: Option<ccboard_core::SubscriptionPlan>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Show detailed session info
     Info {
         /// Session ID or prefix (min 8 chars)
@@ -130,11 +239,25 @@ enum Mode {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// Accept a single near match when the ID has a typo
+        #[arg(long)]
+        fuzzy: bool,
+    },
+    /// Print a session transcript to stdout (markdown, text, or json)
+    Cat {
+        /// Session ID or prefix (min 8 chars)
+        session_id: String,
+        /// Output format: markdown, text, json
+        #[arg(short = 'f', long, default_value = "markdown", value_parser = ["markdown", "text", "json"])]
+        format: String,
     },
     /// Resume session in Claude CLI
     Resume {
         /// Session ID or prefix (min 8 chars)
         session_id: String,
+        /// Accept a single near match when the ID has a typo
+        #[arg(long)]
+        fuzzy: bool,
     },
     /// Generate and cache an LLM summary for a session
     Summarize {
@@ -157,11 +280,24 @@ enum Mode {
         #[command(subcommand)]
         command: PricingCommand,
     },
+    /// TUI color schemes
+    Theme {
+        #[command(subcommand)]
+        command: ThemeCommand,
+    },
     /// Handle a Claude Code hook event (called by Claude Code hooks)
     Hook {
         /// Hook event name (PreToolUse, PostToolUse, UserPromptSubmit, Notification, Stop)
         event: String,
     },
+    /// Check the local setup (paths, cache, claude CLI, pricing) for bug reports
+    ///
+    /// Exits with status 1 if any check fails.
+    Doctor {
+        /// Output as JSON (array of { check, status, detail })
+        #[arg(long)]
+        json: bool,
+    },
     /// Inject ccboard hooks into Claude Code settings.json
     Setup {
         /// Show what would be changed without writing files
@@ -174,15 +310,22 @@ enum Mode {
     ///   ccboard report                        # Markdown report, last 7 days
     ///   ccboard report --format json          # JSON, machine-readable
     ///   ccboard report --since 30d            # Last 30 days
+    ///   ccboard report --since 2026-03        # March 2026 only
     ///   ccboard report --budget 500000        # Exit 1 if tokens > 500k
     ///   ccboard report --error-threshold 5    # Exit 1 if error rate > 5%
+    ///   ccboard report --standup --period 7d  # Weekly recap vs the prior week
     Report {
         /// Output format: json, markdown, html
         #[arg(long, short = 'f', default_value = "markdown", value_parser = ["json", "markdown", "html"])]
         format: String,
-        /// Time window: 1d, 7d, 30d, 90d, or YYYY-MM-DD (default: 7d)
-        #[arg(long, short = 'd', default_value = "7d")]
+        /// Time window: 1d, 7d, 30d, 90d, YYYY-MM-DD, a month (YYYY-MM), or
+        /// a range (YYYY-MM-DD..YYYY-MM-DD) (default: 7d)
+        #[arg(long, short = 'd', visible_alias = "period", default_value = "7d")]
         since: String,
+        /// Short recap for status updates (sessions, cost, top projects and
+        /// tools, busiest day, trend vs the prior window of the same length)
+        #[arg(long, conflicts_with_all = ["budget", "error_threshold"])]
+        standup: bool,
         /// Fail (exit 1) if total tokens exceed this budget
         #[arg(long)]
         budget: Option<u64>,
@@ -231,61 +374,131 @@ enum ExportCommand {
     Conversation {
         /// Session ID or prefix (min 8 chars)
         session_id: String,
-        /// Output file path
+        /// Output file path (default: stdout, requires --format)
         #[arg(short = 'o', long)]
-        output: PathBuf,
+        output: Option<PathBuf>,
+        /// Export format: markdown, json, html (default: inferred from output extension)
+        #[arg(short = 'f', long, value_parser = ["markdown", "json", "html"])]
+        format: Option<String>,
+    },
+    /// Export every session's conversation into <output-dir>/<project>/<session-id>.<ext>
+    Conversations {
+        /// Directory to write into (created if missing)
+        #[arg(long)]
+        output_dir: PathBuf,
         /// Export format: markdown, json, html
-        #[arg(short = 'f', long, default_value = "markdown", value_parser = ["markdown", "json", "html"])]
+        #[arg(short = 'f', long, default_value = "markdown", value_parser = ["markdown", "md", "json", "html"])]
         format: String,
     },
-    /// Export sessions list to file (csv, json, or md)
+    /// Export sessions list to file (csv, json, jsonl, or md)
     Sessions {
-        /// Output file path
+        /// Output file path (default: stdout, requires --format)
         #[arg(short = 'o', long)]
-        output: PathBuf,
-        /// Export format: csv, json, md
-        #[arg(short = 'f', long, default_value = "csv", value_parser = ["csv", "json", "md"])]
-        format: String,
+        output: Option<PathBuf>,
+        /// Export format: csv, json, jsonl, md (default: inferred from output extension)
+        #[arg(short = 'f', long, value_parser = ["csv", "json", "jsonl", "md"])]
+        format: Option<String>,
         /// Date filter: 7d, 30d, 3m, 1y, YYYY-MM-DD
         #[arg(long, short = 'd')]
         since: Option<String>,
     },
     /// Export usage statistics to file (csv, json, or md)
     Stats {
-        /// Output file path
+        /// Output file path (default: stdout, requires --format)
         #[arg(short = 'o', long)]
-        output: PathBuf,
-        /// Export format: csv, json, md
-        #[arg(short = 'f', long, default_value = "csv", value_parser = ["csv", "json", "md"])]
-        format: String,
+        output: Option<PathBuf>,
+        /// Export format: csv, json, md (default: inferred from output extension)
+        #[arg(short = 'f', long, value_parser = ["csv", "json", "md"])]
+        format: Option<String>,
     },
     /// Export billing blocks to file (csv, json, or md)
     Billing {
+        /// Output file path (default: stdout, requires --format)
+        #[arg(short = 'o', long)]
+        output: Option<PathBuf>,
+        /// Export format: csv, json, md (default: inferred from output extension)
+        #[arg(short = 'f', long, value_parser = ["csv", "json", "md"])]
+        format: Option<String>,
+    },
+    /// Export a side-by-side comparison of two periods (csv, json, or md)
+    Comparison {
+        /// Baseline period: YYYY-MM or YYYY-MM-DD..YYYY-MM-DD
+        #[arg(long)]
+        a: String,
+        /// Compared period: YYYY-MM or YYYY-MM-DD..YYYY-MM-DD
+        #[arg(long)]
+        b: String,
         /// Output file path
         #[arg(short = 'o', long)]
         output: PathBuf,
-        /// Export format: csv, json, md
-        #[arg(short = 'f', long, default_value = "csv", value_parser = ["csv", "json", "md"])]
-        format: String,
+        /// Export format: csv, json, md (default: inferred from output extension)
+        #[arg(short = 'f', long, value_parser = ["csv", "json", "md"])]
+        format: Option<String>,
+    },
+    /// Export the activity heatmap (weekday × hour session counts) as CSV
+    Heatmap {
+        /// Output file path (default: stdout, requires --format)
+        #[arg(short = 'o', long)]
+        output: Option<PathBuf>,
+        /// Export format: csv (default: inferred from output extension)
+        #[arg(short = 'f', long, value_parser = ["csv"])]
+        format: Option<String>,
+        /// Time window: 7d, 30d, YYYY-MM-DD, a month (YYYY-MM), or a range
+        /// (YYYY-MM-DD..YYYY-MM-DD) (default: all loaded sessions)
+        #[arg(long, short = 'd')]
+        since: Option<String>,
+    },
+    /// Export the PLAN.md task dependency graph as Graphviz DOT
+    TaskGraph {
+        /// Output file path (default: stdout, requires --format)
+        #[arg(short = 'o', long)]
+        output: Option<PathBuf>,
+        /// Export format: dot (default: inferred from output extension)
+        #[arg(short = 'f', long, value_parser = ["dot"])]
+        format: Option<String>,
+        /// PLAN.md to read (default: claudedocs/PLAN.md, .claude/PLAN.md, then ~/.claude/claudedocs/PLAN.md)
+        #[arg(long)]
+        plan: Option<PathBuf>,
     },
 }
 
 #[derive(Subcommand)]
 enum PricingCommand {
+    /// Show the rates in use (LiteLLM cache merged over embedded prices)
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Update pricing from LiteLLM API
-    Update,
+    Update {
+        /// Fetch even if the cached pricing is less than 7 days old
+        #[arg(long)]
+        force: bool,
+    },
     /// Clear cached pricing data
     Clear,
 }
 
+#[derive(Subcommand)]
+enum ThemeCommand {
+    /// List color schemes with a preview of their colors
+    List,
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    let claude_home = cli
-        .claude_home
+    let mut homes = cli.claude_home.into_iter();
+    let claude_home = homes
+        .next()
         .or_else(|| dirs::home_dir().map(|h: PathBuf| h.join(".claude")))
         .context("Could not determine Claude home directory")?;
+    let store_args = StoreArgs {
+        extra_homes: homes.collect(),
+        project_globs: ProjectGlobFilter::new(&cli.project_glob, &cli.exclude_glob),
+    };
 
     // Auto-detect project: if no --project specified, try current directory
     let project = cli.project.or_else(|| {
@@ -301,18 +514,44 @@ async fn main() -> Result<()> {
     // Extract flags for command handlers
     let no_color = cli.no_color;
 
-    match cli.mode.unwrap_or(Mode::Tui) {
-        Mode::Tui => {
-            run_tui(claude_home, project).await?;
+    match cli.mode.unwrap_or(Mode::Tui {
+        tab: ccboard_tui::Tab::Dashboard,
+        sub_view: None,
+        theme: None,
+    }) {
+        Mode::Tui {
+            tab,
+            sub_view,
+            theme,
+        } => {
+            let initial = ccboard_tui::InitialView {
+                color_scheme: theme,
+                ..ccboard_tui::InitialView::new(tab, sub_view.as_deref())
+                    .map_err(anyhow::Error::msg)?
+            };
+            run_tui(claude_home, project, &store_args, initial).await?;
         }
-        Mode::Web { port } => {
-            run_web(claude_home, project, port).await?;
+        Mode::Web { port, allow_resume } => {
+            run_web(claude_home, project, &store_args, port, allow_resume).await?;
         }
-        Mode::Both { port } => {
-            run_both(claude_home, project, port).await?;
+        Mode::Both {
+            port,
+            allow_resume,
+            theme,
+        } => {
+            run_both(claude_home, project, &store_args, port, allow_resume, theme).await?;
         }
-        Mode::Stats => {
-            run_stats(claude_home, project).await?;
+        Mode::Stats {
+            from_csv: Some(path),
+            ..
+        } => {
+            run_stats_from_csv(claude_home, project, &store_args, &path).await?;
+        }
+        Mode::Stats { watch, .. } => {
+            run_stats(claude_home, project, &store_args, watch, no_color).await?;
+        }
+        Mode::Events { since_startup, .. } => {
+            run_events(claude_home, project, &store_args, since_startup).await?;
         }
         Mode::ClearCache => {
             run_clear_cache(claude_home).await?;
@@ -320,26 +559,110 @@ async fn main() -> Result<()> {
         Mode::Search {
             query,
             since,
+            before,
             limit,
+            content,
+            regex,
             json,
+            compact,
         } => {
-            run_search(claude_home, project, query, since, limit, json, no_color).await?;
+            run_search(
+                claude_home,
+                project,
+                &store_args,
+                query,
+                since,
+                before,
+                limit,
+                content,
+                regex,
+                json,
+                compact,
+                no_color,
+            )
+            .await?;
         }
-        Mode::Recent { count, since, json } => {
-            run_recent(claude_home, project, count, since, json, no_color).await?;
+        Mode::Grep {
+            pattern,
+            regex,
+            limit,
+            project: scope,
+        } => {
+            run_grep(
+                claude_home,
+                project,
+                &store_args,
+                pattern,
+                regex,
+                limit,
+                scope,
+                no_color,
+            )
+            .await?;
         }
-        Mode::Info { session_id, json } => {
-            run_info(claude_home, project, session_id, json, no_color).await?;
+        Mode::Recent {
+            count,
+            since,
+            before,
+            json,
+            compact,
+        } => {
+            run_recent(
+                claude_home,
+                project,
+                &store_args,
+                count,
+                since,
+                before,
+                json,
+                compact,
+                no_color,
+            )
+            .await?;
+        }
+        Mode::Cost { since, json } => {
+            run_cost(claude_home, project, &store_args, since, json, no_color).await?;
+        }
+        Mode::Quota { plan, json } => {
+            run_quota(claude_home, project, &store_args, plan, json, no_color).await?;
+        }
+        Mode::Info {
+            session_id,
+            json,
+            fuzzy,
+        } => {
+            run_info(
+                claude_home,
+                project,
+                &store_args,
+                session_id,
+                json,
+                fuzzy,
+                no_color,
+            )
+            .await?;
         }
-        Mode::Resume { session_id } => {
-            run_resume(claude_home, project, session_id).await?;
+        Mode::Cat { session_id, format } => {
+            run_cat(claude_home, project, &store_args, session_id, format).await?;
+        }
+        Mode::Resume { session_id, fuzzy } => {
+            run_resume(claude_home, project, &store_args, session_id, fuzzy).await?;
         }
         Mode::Summarize {
             session_id,
             model,
             force,
         } => {
-            run_summarize(claude_home, project, session_id, model, force, no_color).await?;
+            run_summarize(
+                claude_home,
+                project,
+                &store_args,
+                session_id,
+                model,
+                force,
+                no_color,
+            )
+            .await?;
         }
         Mode::Export { command } => match command {
             ExportCommand::Conversation {
@@ -347,41 +670,142 @@ async fn main() -> Result<()> {
                 output,
                 format,
             } => {
-                run_export_conversation(claude_home, project, session_id, output, format, no_color)
-                    .await?;
+                let format = cli::resolve_export_format(
+                    output.as_deref(),
+                    format,
+                    &["markdown", "json", "html"],
+                )?;
+                run_export_conversation(
+                    claude_home,
+                    project,
+                    &store_args,
+                    session_id,
+                    output,
+                    format,
+                    no_color,
+                )
+                .await?;
+            }
+            ExportCommand::Conversations { output_dir, format } => {
+                run_export_conversations(
+                    claude_home,
+                    project,
+                    &store_args,
+                    output_dir,
+                    format,
+                    no_color,
+                )
+                .await?;
             }
             ExportCommand::Sessions {
                 output,
                 format,
                 since,
             } => {
-                run_export_sessions(claude_home, project, output, format, since, no_color).await?;
+                let format = cli::resolve_export_format(
+                    output.as_deref(),
+                    format,
+                    &["csv", "json", "jsonl", "md"],
+                )?;
+                run_export_sessions(
+                    claude_home,
+                    project,
+                    &store_args,
+                    output,
+                    format,
+                    since,
+                    no_color,
+                )
+                .await?;
             }
             ExportCommand::Stats { output, format } => {
-                run_export_stats(claude_home, project, output, format, no_color).await?;
+                let format =
+                    cli::resolve_export_format(output.as_deref(), format, &["csv", "json", "md"])?;
+                run_export_stats(claude_home, project, &store_args, output, format, no_color)
+                    .await?;
             }
             ExportCommand::Billing { output, format } => {
-                run_export_billing(claude_home, project, output, format, no_color).await?;
+                let format =
+                    cli::resolve_export_format(output.as_deref(), format, &["csv", "json", "md"])?;
+                run_export_billing(claude_home, project, &store_args, output, format, no_color)
+                    .await?;
+            }
+            ExportCommand::Comparison {
+                a,
+                b,
+                output,
+                format,
+            } => {
+                let format =
+                    cli::resolve_export_format(Some(&output), format, &["csv", "json", "md"])?;
+                run_export_comparison(
+                    claude_home,
+                    project,
+                    &store_args,
+                    a,
+                    b,
+                    output,
+                    format,
+                    no_color,
+                )
+                .await?;
+            }
+            ExportCommand::Heatmap {
+                output,
+                format,
+                since,
+            } => {
+                cli::resolve_export_format(output.as_deref(), format, &["csv"])?;
+                run_export_heatmap(claude_home, project, &store_args, output, since, no_color)
+                    .await?;
+            }
+            ExportCommand::TaskGraph {
+                output,
+                format,
+                plan,
+            } => {
+                cli::resolve_export_format(output.as_deref(), format, &["dot"])?;
+                run_export_task_graph(plan, output, no_color)?;
             }
         },
         Mode::Pricing { command } => match command {
-            PricingCommand::Update => {
-                run_pricing_update(no_color).await?;
+            PricingCommand::List { json } => {
+                let entries = ccboard_core::pricing::pricing_table();
+                let store = open_store(claude_home, project, &store_args);
+                store.reload_settings().await;
+                println!(
+                    "{}",
+                    cli::format_pricing_table(&entries, &store.currency(), json, no_color)
+                );
+            }
+            PricingCommand::Update { force } => {
+                run_pricing_update(force, no_color).await?;
             }
             PricingCommand::Clear => {
                 run_pricing_clear(no_color).await?;
             }
         },
+        Mode::Theme {
+            command: ThemeCommand::List,
+        } => {
+            println!("{}", cli::format_theme_list(no_color));
+        }
         Mode::Hook { event } => {
             // Sync dispatch — no tokio overhead for this fast path (<20ms)
             tokio::task::block_in_place(|| hook::run_hook(event))?;
         }
+        Mode::Doctor { json } => {
+            if !doctor::run_doctor(&claude_home, no_color, json) {
+                std::process::exit(1);
+            }
+        }
         Mode::Setup { dry_run } => {
             setup::run_setup(dry_run, claude_home).await?;
         }
         Mode::Report {
             format,
             since,
+            standup,
             budget,
             error_threshold,
             output,
@@ -389,8 +813,10 @@ async fn main() -> Result<()> {
             run_report(
                 claude_home,
                 project,
+                &store_args,
                 format,
                 since,
+                standup,
                 budget,
                 error_threshold,
                 output,
@@ -425,12 +851,31 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-async fn run_tui(claude_home: PathBuf, project: Option<PathBuf>) -> Result<()> {
+/// Global flags that shape every store
+#[derive(Debug, Default)]
+struct StoreArgs {
+    /// Additional `--claude-home` values, merged into every store
+    extra_homes: Vec<PathBuf>,
+    /// `--project-glob` / `--exclude-glob` filter
+    project_globs: ProjectGlobFilter,
+}
+
+/// Create a store over `claude_home` plus any extra `--claude-home`
+fn open_store(claude_home: PathBuf, project: Option<PathBuf>, store_args: &StoreArgs) -> DataStore {
+    let mut homes = vec![claude_home];
+    homes.extend(store_args.extra_homes.iter().cloned());
+    DataStore::with_multiple_homes(homes, project)
+        .with_project_filter(store_args.project_globs.clone())
+}
+
+async fn run_tui(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    initial: ccboard_tui::InitialView,
+) -> Result<()> {
     // Initialize data store (without loading data yet - TUI will handle that)
-    let store = Arc::new(DataStore::with_defaults(
-        claude_home.clone(),
-        project.clone(),
-    ));
+    let store = Arc::new(open_store(claude_home.clone(), project.clone(), store_args));
 
     // Start file watcher for live updates
     let _watcher = ccboard_core::FileWatcher::start(
@@ -442,23 +887,61 @@ async fn run_tui(claude_home: PathBuf, project: Option<PathBuf>) -> Result<()> {
     .await
     .context("Failed to start file watcher")?;
 
+    notifications::spawn_budget_notifier(Arc::clone(&store));
+
     // Run TUI (will show loading spinner and load data in background)
-    ccboard_tui::run(store, claude_home, project).await
+    ccboard_tui::run(store, claude_home, project, initial).await
 }
 
 /// Create a consistent CLI spinner (cyan, 80ms tick).
 fn create_spinner() -> ProgressBar {
     let spinner = ProgressBar::new_spinner();
-    spinner.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.cyan} {msg}")
-            .unwrap()
-            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏"),
-    );
+    spinner.set_style(spinner_style());
     spinner.enable_steady_tick(std::time::Duration::from_millis(80));
     spinner
 }
 
+fn spinner_style() -> ProgressStyle {
+    ProgressStyle::default_spinner()
+        .template("{spinner:.cyan} {msg}")
+        .unwrap()
+        .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏")
+}
+
+/// Run the initial load, showing session indexing as a files-done bar
+///
+/// After `clear-cache` every file is parsed again, which takes a while on
+/// large homes; files already back in the cache count as done right away.
+async fn load_with_progress(
+    store: &ccboard_core::DataStore,
+    spinner: &ProgressBar,
+) -> ccboard_core::LoadReport {
+    use ccboard_core::parsers::ScanProgress;
+
+    let bar = spinner.clone();
+    store.set_scan_progress(Some(Arc::new(move |p: ScanProgress| {
+        if bar.length() != Some(p.total as u64) {
+            bar.set_style(
+                ProgressStyle::default_bar()
+                    .template("{spinner:.cyan} Indexing sessions {pos}/{len} [{bar:30.cyan}] {msg}")
+                    .unwrap()
+                    .progress_chars("█▓░"),
+            );
+            bar.set_length(p.total as u64);
+        }
+        bar.set_position(p.done as u64);
+        if p.cached > 0 {
+            bar.set_message(format!("({} cached)", p.cached));
+        }
+    })));
+
+    let report = store.initial_load().await;
+    store.set_scan_progress(None);
+    spinner.set_style(spinner_style());
+    spinner.unset_length();
+    report
+}
+
 /// Parse an optional `--since` string into a `DateFilter`.
 fn parse_date_filter(since: Option<&str>) -> Result<Option<cli::DateFilter>> {
     since
@@ -466,6 +949,15 @@ fn parse_date_filter(since: Option<&str>) -> Result<Option<cli::DateFilter>> {
         .transpose()
 }
 
+/// Parse `--since`/`--after` and `--before` into a single `DateFilter`.
+fn parse_date_range(since: Option<&str>, before: Option<&str>) -> Result<Option<cli::DateFilter>> {
+    let since = parse_date_filter(since)?;
+    match before {
+        Some(before) => Ok(Some(cli::DateFilter::with_before(since, before)?)),
+        None => Ok(since),
+    }
+}
+
 /// Print fatal load errors and return `true` if any were found.
 fn report_fatal_errors(spinner: &ProgressBar, report: &ccboard_core::LoadReport) -> bool {
     if report.has_fatal_errors() {
@@ -480,7 +972,13 @@ fn report_fatal_errors(spinner: &ProgressBar, report: &ccboard_core::LoadReport)
     }
 }
 
-async fn run_web(claude_home: PathBuf, project: Option<PathBuf>, port: u16) -> Result<()> {
+async fn run_web(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    port: u16,
+    allow_resume: bool,
+) -> Result<()> {
     use std::time::Instant;
 
     let start = Instant::now();
@@ -488,14 +986,11 @@ async fn run_web(claude_home: PathBuf, project: Option<PathBuf>, port: u16) -> R
 
     // Initialize data store
     spinner.set_message("Initializing data store...");
-    let store = Arc::new(DataStore::with_defaults(
-        claude_home.clone(),
-        project.clone(),
-    ));
+    let store = Arc::new(open_store(claude_home.clone(), project.clone(), store_args));
 
     // Load initial data
     spinner.set_message("Loading sessions and statistics...");
-    let report = store.initial_load().await;
+    let report = load_with_progress(&store, &spinner).await;
 
     if report_fatal_errors(&spinner, &report) {
         return Ok(());
@@ -537,10 +1032,17 @@ async fn run_web(claude_home: PathBuf, project: Option<PathBuf>, port: u16) -> R
         println!("      or run `trunk build` in crates/ccboard-web/ then rebuild.");
     }
 
-    ccboard_web::run(store, port).await
+    ccboard_web::run(store, port, ccboard_web::ServerOptions { allow_resume }).await
 }
 
-async fn run_both(claude_home: PathBuf, project: Option<PathBuf>, port: u16) -> Result<()> {
+async fn run_both(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    port: u16,
+    allow_resume: bool,
+    theme: Option<ColorScheme>,
+) -> Result<()> {
     use std::time::Instant;
 
     let start = Instant::now();
@@ -548,14 +1050,11 @@ async fn run_both(claude_home: PathBuf, project: Option<PathBuf>, port: u16) ->
 
     // Initialize data store
     spinner.set_message("Initializing data store...");
-    let store = Arc::new(DataStore::with_defaults(
-        claude_home.clone(),
-        project.clone(),
-    ));
+    let store = Arc::new(open_store(claude_home.clone(), project.clone(), store_args));
 
     // Load initial data
     spinner.set_message("Loading sessions and statistics...");
-    let report = store.initial_load().await;
+    let report = load_with_progress(&store, &spinner).await;
 
     if report_fatal_errors(&spinner, &report) {
         return Ok(());
@@ -599,13 +1098,20 @@ async fn run_both(claude_home: PathBuf, project: Option<PathBuf>, port: u16) ->
     // Start web server in background
     let web_store = Arc::clone(&store);
     let web_handle = tokio::spawn(async move {
-        if let Err(e) = ccboard_web::run(web_store, port).await {
+        let options = ccboard_web::ServerOptions { allow_resume };
+        if let Err(e) = ccboard_web::run(web_store, port, options).await {
             eprintln!("Web server error: {}", e);
         }
     });
 
+    notifications::spawn_budget_notifier(Arc::clone(&store));
+
     // Run TUI in foreground
-    let tui_result = ccboard_tui::run(store, claude_home, project).await;
+    let initial = ccboard_tui::InitialView {
+        color_scheme: theme,
+        ..Default::default()
+    };
+    let tui_result = ccboard_tui::run(store, claude_home, project, initial).await;
 
     // Clean up web server
     web_handle.abort();
@@ -613,60 +1119,226 @@ async fn run_both(claude_home: PathBuf, project: Option<PathBuf>, port: u16) ->
     tui_result
 }
 
-async fn run_stats(claude_home: PathBuf, project: Option<PathBuf>) -> Result<()> {
+async fn run_stats(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    watch: bool,
+    no_color: bool,
+) -> Result<()> {
     // Initialize data store
-    let store = DataStore::with_defaults(claude_home, project);
+    let store = Arc::new(open_store(claude_home.clone(), project.clone(), store_args));
 
     // Load initial data
-    let report = store.initial_load().await;
+    let spinner = create_spinner();
+    spinner.set_message("Loading sessions and statistics...");
+    let report = load_with_progress(&store, &spinner).await;
+    spinner.finish_and_clear();
 
-    // Print stats summary
-    println!("ccboard - Claude Code Statistics");
-    println!("================================");
-    println!();
+    if !watch {
+        print_stats_summary(&store, &report);
+        return Ok(());
+    }
 
-    if let Some(stats) = store.stats() {
-        println!("Total Tokens:     {}", format_number(stats.total_tokens()));
-        println!(
-            "  Input:          {}",
-            format_number(stats.total_input_tokens())
-        );
-        println!(
-            "  Output:         {}",
-            format_number(stats.total_output_tokens())
-        );
-        println!(
-            "  Cache Read:     {}",
-            format_number(stats.total_cache_read_tokens())
-        );
+    let _watcher = ccboard_core::FileWatcher::start(
+        claude_home,
+        project,
+        Arc::clone(&store),
+        Default::default(),
+    )
+    .await
+    .context("Failed to start file watcher")?;
+    let mut events = store.event_bus().subscribe();
+
+    loop {
+        if no_color {
+            println!();
+        } else {
+            // Clear screen and move the cursor home
+            print!("\x1b[2J\x1b[H");
+        }
+        print_stats_summary(&store, &report);
+        println!();
         println!(
-            "  Cache Write:    {}",
-            format_number(stats.total_cache_write_tokens())
+            "Watching for changes (updated {}) - Ctrl-C to exit",
+            chrono::Local::now().format("%H:%M:%S")
         );
-        println!();
-        println!("Sessions:         {}", stats.session_count());
-        println!("Messages:         {}", stats.message_count());
-        println!("Cache Hit Ratio:  {:.1}%", stats.cache_ratio() * 100.0);
-        println!();
 
-        if !stats.model_usage.is_empty() {
-            println!("Models:");
-            for (name, usage) in stats.top_models(5) {
-                println!(
-                    "  {}: {} tokens (in: {}, out: {})",
-                    name,
-                    format_number(usage.total_tokens()),
-                    format_number(usage.input_tokens),
-                    format_number(usage.output_tokens)
-                );
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            changed = wait_for_stats_change(&mut events) => {
+                if !changed {
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Wait for an event that affects the stats summary, coalescing bursts
+///
+/// Returns `false` once the event bus is closed.
+async fn wait_for_stats_change(
+    events: &mut tokio::sync::broadcast::Receiver<ccboard_core::DataEvent>,
+) -> bool {
+    use ccboard_core::DataEvent;
+    use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+
+    loop {
+        match events.recv().await {
+            Ok(
+                DataEvent::StatsUpdated
+                | DataEvent::SessionCreated(_)
+                | DataEvent::SessionUpdated(_)
+                | DataEvent::LoadCompleted,
+            )
+            | Err(RecvError::Lagged(_)) => break,
+            Ok(_) => continue,
+            Err(RecvError::Closed) => return false,
+        }
+    }
+
+    // A session write usually fires several events in a row: redraw once
+    tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+    while let Ok(_) | Err(TryRecvError::Lagged(_)) = events.try_recv() {}
+    true
+}
+
+/// Stream `DataEvent`s as NDJSON (`{"event": ..., "data": ...}` per line)
+///
+/// With `since_startup`, one `session_snapshot` line per loaded session and a
+/// `stats_snapshot` line come first, closed by `snapshot_complete`. Exits on
+/// Ctrl-C or once stdout is closed (consumer went away).
+async fn run_events(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    since_startup: bool,
+) -> Result<()> {
+    use std::io::Write;
+    use tokio::sync::broadcast::error::RecvError;
+
+    let store = Arc::new(open_store(claude_home.clone(), project.clone(), store_args));
+    store.initial_load().await;
+
+    let _watcher = ccboard_core::FileWatcher::start(
+        claude_home,
+        project,
+        Arc::clone(&store),
+        Default::default(),
+    )
+    .await
+    .context("Failed to start file watcher")?;
+    // Subscribe before the snapshot so no change falls in between
+    let mut events = store.event_bus().subscribe();
+
+    let mut stdout = std::io::stdout();
+    let mut emit = |event: &str, data: serde_json::Value| -> bool {
+        writeln!(stdout, "{}", cli::ndjson_line(event, data))
+            .and_then(|_| stdout.flush())
+            .is_ok()
+    };
+
+    if since_startup {
+        let sessions = store.all_sessions();
+        for session in &sessions {
+            if !emit("session_snapshot", serde_json::to_value(session.as_ref())?) {
+                return Ok(());
             }
         }
+        let stats = store
+            .stats()
+            .map(serde_json::to_value)
+            .transpose()?
+            .unwrap_or(serde_json::Value::Null);
+        if !emit("stats_snapshot", stats)
+            || !emit(
+                "snapshot_complete",
+                serde_json::json!({ "sessions": sessions.len() }),
+            )
+        {
+            return Ok(());
+        }
+    }
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            received = events.recv() => match received {
+                Ok(event) => {
+                    if !emit(event.kind(), event.payload()) {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    if !emit("lagged", serde_json::json!({ "skipped": skipped })) {
+                        break;
+                    }
+                }
+                Err(RecvError::Closed) => break,
+            },
+        }
+    }
+
+    Ok(())
+}
+
+/// Print stats imported from an exported CSV (no `~/.claude` needed)
+async fn run_stats_from_csv(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    path: &std::path::Path,
+) -> Result<()> {
+    let stats = ccboard_core::import_stats_from_csv(path)?;
+    // Only the settings are read, for the display currency
+    let store = open_store(claude_home, project, store_args);
+    store.reload_settings().await;
+
+    println!("ccboard - Claude Code Statistics");
+    println!("================================");
+    println!("Imported from {}", path.display());
+    println!();
+    // The CSV only carries per-model usage, so session/message counts are omitted
+    print_stats_body(&stats, false);
+    println!();
+    println!(
+        "Estimated Cost:   {}",
+        store
+            .currency()
+            .format_cost(stats.model_usage.values().map(|u| u.cost_usd).sum::<f64>())
+    );
+    Ok(())
+}
+
+/// Print the `ccboard stats` summary
+fn print_stats_summary(store: &DataStore, report: &ccboard_core::LoadReport) {
+    println!("ccboard - Claude Code Statistics");
+    println!("================================");
+    println!();
+
+    if let Some(stats) = store.stats() {
+        print_stats_body(&stats, true);
     } else {
         println!("No stats available");
     }
 
     println!();
     println!("Sessions indexed: {}", store.session_count());
+    if let Some(cache) = store.cache_stats() {
+        if let (Some(duration), Some(files)) =
+            (cache.last_rebuild_duration, cache.last_rebuild_files)
+        {
+            println!(
+                "Cache:            {} entries (last rebuild: {} files in {:.1}s)",
+                cache.total_entries,
+                files,
+                duration.as_secs_f64()
+            );
+        }
+    }
 
     if report.has_errors() {
         println!();
@@ -675,8 +1347,47 @@ async fn run_stats(claude_home: PathBuf, project: Option<PathBuf>) -> Result<()>
             println!("  - {}: {}", error.source, error.message);
         }
     }
+}
 
-    Ok(())
+/// Token totals, counts and top models shared by the `stats` outputs
+fn print_stats_body(stats: &ccboard_core::models::StatsCache, with_counts: bool) {
+    println!("Total Tokens:     {}", format_number(stats.total_tokens()));
+    println!(
+        "  Input:          {}",
+        format_number(stats.total_input_tokens())
+    );
+    println!(
+        "  Output:         {}",
+        format_number(stats.total_output_tokens())
+    );
+    println!(
+        "  Cache Read:     {}",
+        format_number(stats.total_cache_read_tokens())
+    );
+    println!(
+        "  Cache Write:    {}",
+        format_number(stats.total_cache_write_tokens())
+    );
+    println!();
+    if with_counts {
+        println!("Sessions:         {}", stats.session_count());
+        println!("Messages:         {}", stats.message_count());
+    }
+    println!("Cache Hit Ratio:  {:.1}%", stats.cache_ratio() * 100.0);
+    println!();
+
+    if !stats.model_usage.is_empty() {
+        println!("Models:");
+        for (name, usage) in stats.top_models(5) {
+            println!(
+                "  {}: {} tokens (in: {}, out: {})",
+                name,
+                format_number(usage.total_tokens()),
+                format_number(usage.input_tokens),
+                format_number(usage.output_tokens)
+            );
+        }
+    }
 }
 
 async fn run_clear_cache(claude_home: PathBuf) -> Result<()> {
@@ -714,6 +1425,7 @@ async fn run_clear_cache(claude_home: PathBuf) -> Result<()> {
     println!("   Freed: {}", format_size(size_bytes));
     println!();
     println!("💡 Next run will rebuild cache with fresh metadata.");
+    println!("   If interrupted, the rebuild resumes from the files already indexed.");
 
     Ok(())
 }
@@ -744,34 +1456,58 @@ fn format_number(n: u64) -> String {
 // CLI Command Handlers
 // ============================================================================
 
+#[allow(clippy::too_many_arguments)]
 async fn run_search(
     claude_home: PathBuf,
     project: Option<PathBuf>,
+    store_args: &StoreArgs,
     query: String,
     since: Option<String>,
+    before: Option<String>,
     limit: usize,
+    content: bool,
+    regex: bool,
     json: bool,
+    compact: bool,
     no_color: bool,
 ) -> Result<()> {
-    let store = DataStore::with_defaults(claude_home, project);
+    // Fail on an invalid pattern or date range before scanning
+    let matcher = cli::SearchMatcher::new(&query, regex)?;
+    let date_filter = parse_date_range(since.as_deref(), before.as_deref())?;
+
+    let store = open_store(claude_home, project, store_args);
+    // Machine-readable output keeps stderr quiet too
+    let quiet = json || compact;
 
     // Show progress
-    if !json {
+    if !quiet {
         eprint!("Scanning sessions... ");
     }
 
     let report = store.initial_load().await;
 
-    if !json && report.sessions_scanned > 0 {
+    if !quiet && report.sessions_scanned > 0 {
         eprintln!("✓ {} sessions", report.sessions_scanned);
     }
 
-    // Parse date filter
-    let date_filter = parse_date_filter(since.as_deref())?;
-
     // Search
     let all = store.recent_sessions(usize::MAX);
-    let results = cli::search_sessions(&all, &query, date_filter.as_ref(), limit);
+    let results = if content {
+        search_content(&store, &all, &query, date_filter.as_ref(), limit, quiet).await
+    } else if regex {
+        cli::search_sessions(&all, &matcher, date_filter.as_ref(), limit)
+    } else {
+        // Token index lookup, then the same date filter and limit
+        store
+            .search(&query)
+            .into_iter()
+            .filter(|s| match &date_filter {
+                Some(filter) => s.first_timestamp.is_some_and(|ts| filter.matches(&ts)),
+                None => true,
+            })
+            .take(limit)
+            .collect()
+    };
 
     if results.is_empty() {
         return Err(cli::CliError::NoResults {
@@ -781,7 +1517,24 @@ async fn run_search(
         .into());
     }
 
-    println!("{}", cli::format_session_table(&results, json, no_color));
+    if compact {
+        println!(
+            "{}",
+            cli::format_session_lines(&results, &store.project_display())
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        cli::format_session_table(
+            &results,
+            &store.project_display(),
+            Some(&matcher),
+            json,
+            no_color
+        )
+    );
 
     if !json {
         eprintln!("\n{} results from {} sessions", results.len(), all.len());
@@ -790,15 +1543,151 @@ async fn run_search(
     Ok(())
 }
 
-async fn run_recent(
+/// Scan transcript bodies (most recent first) with a `scanned / total` progress bar
+async fn search_content(
+    store: &DataStore,
+    sessions: &[Arc<ccboard_core::models::SessionMetadata>],
+    query: &str,
+    date_filter: Option<&cli::DateFilter>,
+    limit: usize,
+    quiet: bool,
+) -> Vec<Arc<ccboard_core::models::SessionMetadata>> {
+    use ccboard_core::parsers::SessionContentParser;
+
+    let candidates: Vec<_> = sessions
+        .iter()
+        .filter(|s| match date_filter {
+            Some(filter) => s.first_timestamp.is_some_and(|ts| filter.matches(&ts)),
+            None => true,
+        })
+        .cloned()
+        .collect();
+
+    let progress = if quiet {
+        ProgressBar::hidden()
+    } else {
+        let bar = ProgressBar::new(candidates.len() as u64);
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .template("{spinner:.cyan} Searching transcripts {pos}/{len} [{bar:30.cyan}]")
+                .unwrap()
+                .progress_chars("█▓░"),
+        );
+        bar
+    };
+
+    let results = SessionContentParser::search_sessions(
+        &candidates,
+        query,
+        limit,
+        store.content_search_concurrency(),
+        |scanned, _| progress.set_position(scanned as u64),
+    )
+    .await;
+
+    progress.finish_and_clear();
+    results
+}
+
+/// Matches shown per session (one more is fetched to know there are others)
+const GREP_MATCHES_PER_SESSION: usize = 3;
+
+#[allow(clippy::too_many_arguments)]
+async fn run_grep(
     claude_home: PathBuf,
     project: Option<PathBuf>,
-    count: usize,
+    store_args: &StoreArgs,
+    pattern: String,
+    regex: bool,
+    limit: usize,
+    scope: Option<String>,
+    no_color: bool,
+) -> Result<()> {
+    use ccboard_core::parsers::{GrepPattern, SessionContentParser};
+
+    // Fail on an invalid pattern before scanning
+    let grep_pattern = if regex {
+        GrepPattern::regex(&pattern).map_err(|e| cli::CliError::InvalidPattern {
+            pattern: pattern.clone(),
+            message: e.to_string(),
+        })?
+    } else {
+        GrepPattern::literal(&pattern)
+    };
+
+    let store = open_store(claude_home, project, store_args);
+
+    eprint!("Scanning sessions... ");
+    let report = store.initial_load().await;
+    eprintln!("✓ {} sessions", report.sessions_scanned);
+
+    let scope = scope.map(|s| s.to_lowercase());
+    let candidates: Vec<_> = store
+        .recent_sessions(usize::MAX)
+        .into_iter()
+        .filter(|s| {
+            scope
+                .as_deref()
+                .is_none_or(|scope| s.project_path.as_str().to_lowercase().contains(scope))
+        })
+        .collect();
+
+    let progress = ProgressBar::new(candidates.len() as u64);
+    progress.set_style(
+        ProgressStyle::default_bar()
+            .template("{spinner:.cyan} Searching transcripts {pos}/{len} [{bar:30.cyan}]")
+            .unwrap()
+            .progress_chars("█▓░"),
+    );
+    let hits = SessionContentParser::grep_sessions(
+        &candidates,
+        &grep_pattern,
+        limit,
+        GREP_MATCHES_PER_SESSION + 1,
+        store.content_search_concurrency(),
+        |scanned, _| progress.set_position(scanned as u64),
+    )
+    .await;
+    progress.finish_and_clear();
+
+    if hits.is_empty() {
+        return Err(cli::CliError::NoResults {
+            query: pattern,
+            scanned: candidates.len(),
+        }
+        .into());
+    }
+
+    print!(
+        "{}",
+        cli::format_grep_results(
+            &hits,
+            &store.project_display(),
+            GREP_MATCHES_PER_SESSION,
+            no_color
+        )
+    );
+    eprintln!(
+        "\n{} matching sessions from {} scanned",
+        hits.len(),
+        candidates.len()
+    );
+
+    Ok(())
+}
+
+async fn run_cost(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
     since: Option<String>,
     json: bool,
     no_color: bool,
 ) -> Result<()> {
-    let store = DataStore::with_defaults(claude_home, project);
+    // Fail on an invalid filter before scanning
+    let date_filter = parse_date_filter(since.as_deref())?;
+
+    let store = open_store(claude_home, project, store_args);
 
     if !json {
         eprint!("Loading sessions... ");
@@ -810,8 +1699,77 @@ async fn run_recent(
         eprintln!("✓ {} sessions", report.sessions_scanned);
     }
 
-    // Parse date filter
-    let date_filter = parse_date_filter(since.as_deref())?;
+    let costs = cli::cost_by_model(&store.aggregate_sessions(), date_filter.as_ref());
+
+    println!(
+        "{}",
+        cli::format_cost_table(&costs, since.as_deref(), &store.currency(), json, no_color)
+    );
+
+    Ok(())
+}
+
+async fn run_quota(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    plan: Option<ccboard_core::SubscriptionPlan>,
+    json: bool,
+    no_color: bool,
+) -> Result<()> {
+    let store = open_store(claude_home, project, store_args);
+
+    if !json {
+        eprint!("Loading sessions... ");
+    }
+
+    let report = store.initial_load().await;
+    store.compute_billing_blocks().await;
+
+    if !json && report.sessions_scanned > 0 {
+        eprintln!("✓ {} sessions", report.sessions_scanned);
+    }
+
+    let plan = plan.unwrap_or_else(|| store.subscription_plan());
+    let estimate = store.usage_estimate_for(plan);
+    let today = chrono::Local::now().date_naive();
+
+    println!(
+        "{}",
+        cli::format_quota(&estimate, today, &store.currency(), json, no_color)
+    );
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_recent(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    count: usize,
+    since: Option<String>,
+    before: Option<String>,
+    json: bool,
+    compact: bool,
+    no_color: bool,
+) -> Result<()> {
+    // Fail on an invalid date range before scanning
+    let date_filter = parse_date_range(since.as_deref(), before.as_deref())?;
+
+    let store = open_store(claude_home, project, store_args);
+    // Machine-readable output keeps stderr quiet too
+    let quiet = json || compact;
+
+    if !quiet {
+        eprint!("Loading sessions... ");
+    }
+
+    let report = store.initial_load().await;
+
+    if !quiet && report.sessions_scanned > 0 {
+        eprintln!("✓ {} sessions", report.sessions_scanned);
+    }
 
     // Get recent sessions
     let mut all = store.recent_sessions(usize::MAX);
@@ -828,13 +1786,24 @@ async fn run_recent(
     let results: Vec<_> = all.into_iter().take(count).collect();
 
     if results.is_empty() {
-        if !json {
+        if !quiet {
             println!("No sessions found.");
         }
         return Ok(());
     }
 
-    println!("{}", cli::format_session_table(&results, json, no_color));
+    if compact {
+        println!(
+            "{}",
+            cli::format_session_lines(&results, &store.project_display())
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        cli::format_session_table(&results, &store.project_display(), None, json, no_color)
+    );
 
     if !json {
         eprintln!(
@@ -850,11 +1819,13 @@ async fn run_recent(
 async fn run_info(
     claude_home: PathBuf,
     project: Option<PathBuf>,
+    store_args: &StoreArgs,
     session_id: String,
     json: bool,
+    fuzzy: bool,
     _no_color: bool,
 ) -> Result<()> {
-    let store = DataStore::with_defaults(claude_home, project);
+    let store = open_store(claude_home, project, store_args);
 
     if !json {
         eprint!("Loading sessions... ");
@@ -867,26 +1838,63 @@ async fn run_info(
     }
 
     let all = store.recent_sessions(usize::MAX);
-    let session = cli::find_by_id_or_prefix(&all, &session_id)?;
+    let session = cli::find_session(&all, &session_id, fuzzy)?;
 
     println!("{}", cli::format_session_info(&session, json));
 
     Ok(())
 }
 
+async fn run_cat(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    session_id: String,
+    format: String,
+) -> Result<()> {
+    use ccboard_core::export::{
+        write_conversation_json, write_conversation_markdown, write_conversation_text,
+    };
+
+    let store = open_store(claude_home, project, store_args);
+    store.initial_load().await;
+
+    let all = store.recent_sessions(usize::MAX);
+    let session = cli::find_by_id_or_prefix(&all, &session_id)?;
+
+    let messages = store
+        .load_session_content(&session.id)
+        .await
+        .context("Failed to load session content")?;
+
+    let stdout = std::io::stdout();
+    let mut out = std::io::BufWriter::new(stdout.lock());
+
+    match format.as_str() {
+        "markdown" => write_conversation_markdown(&messages, &session, &mut out)?,
+        "text" => write_conversation_text(&messages, &session, &mut out)?,
+        "json" => write_conversation_json(&messages, &session, &mut out)?,
+        _ => anyhow::bail!("Invalid format: {}. Use markdown, text, or json", format),
+    }
+
+    Ok(())
+}
+
 async fn run_resume(
     claude_home: PathBuf,
     project: Option<PathBuf>,
+    store_args: &StoreArgs,
     session_id: String,
+    fuzzy: bool,
 ) -> Result<()> {
-    let store = DataStore::with_defaults(claude_home, project);
+    let store = open_store(claude_home, project, store_args);
 
     eprint!("Loading sessions... ");
     store.initial_load().await;
     eprintln!("✓");
 
     let all = store.recent_sessions(usize::MAX);
-    let session = cli::find_by_id_or_prefix(&all, &session_id)?;
+    let session = cli::find_session(&all, &session_id, fuzzy)?;
 
     eprintln!(
         "Resuming session {} in {}",
@@ -894,6 +1902,10 @@ async fn run_resume(
         session.project_path
     );
 
+    if let Err(e) = store.record_resume(&session.id) {
+        tracing::warn!(error = %e, "Failed to record session resume");
+    }
+
     // Unix: use exec() to replace process (no need to wait)
     #[cfg(unix)]
     {
@@ -918,6 +1930,7 @@ async fn run_resume(
 async fn run_summarize(
     claude_home: PathBuf,
     project: Option<PathBuf>,
+    store_args: &StoreArgs,
     session_id: String,
     model: String,
     force: bool,
@@ -927,7 +1940,7 @@ async fn run_summarize(
         .context("Cannot determine home directory")?
         .join(".ccboard");
 
-    let store = DataStore::with_defaults(claude_home, project);
+    let store = open_store(claude_home, project, store_args);
     eprint!("Loading sessions... ");
     store.initial_load().await;
     eprintln!("done");
@@ -1024,19 +2037,128 @@ fn extract_text_from_content(content: Option<&serde_json::Value>) -> String {
     }
 }
 
+/// Export every session's conversation, parsing with a bounded worker pool
+///
+/// Sessions that fail to parse or write are reported and skipped.
+async fn run_export_conversations(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    output_dir: PathBuf,
+    format: String,
+    no_color: bool,
+) -> Result<()> {
+    use ccboard_core::export::{
+        export_conversation_to_html, export_conversation_to_json, export_conversation_to_markdown,
+    };
+    use ccboard_core::parsers::SessionContentParser;
+
+    let store = open_store(claude_home, project, store_args);
+    eprint!("Loading sessions... ");
+    let report = store.initial_load().await;
+    eprintln!("✓ {} sessions", report.sessions_scanned);
+
+    let ext = match format.as_str() {
+        "json" => "json",
+        "html" => "html",
+        _ => "md",
+    };
+    std::fs::create_dir_all(&output_dir)
+        .with_context(|| format!("Failed to create {}", output_dir.display()))?;
+
+    let sessions = store.recent_sessions(usize::MAX);
+    let concurrency = store.content_search_concurrency().max(1);
+    let progress = ProgressBar::new(sessions.len() as u64);
+    progress.set_style(
+        ProgressStyle::default_bar()
+            .template("{spinner:.cyan} Exporting conversations {pos}/{len} [{bar:30.cyan}]")
+            .unwrap()
+            .progress_chars("█▓░"),
+    );
+
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut pending = sessions.into_iter();
+    let mut written = 0usize;
+    let mut failed = 0usize;
+    loop {
+        while tasks.len() < concurrency {
+            let Some(session) = pending.next() else {
+                break;
+            };
+            let path = cli::conversation_export_path(
+                &output_dir,
+                session.project_path.as_str(),
+                &session.id,
+                ext,
+            );
+            tasks.spawn(async move {
+                let result = async {
+                    let messages = SessionContentParser::parse_conversation(
+                        &session.file_path,
+                        (*session).clone(),
+                    )
+                    .await?;
+                    match ext {
+                        "json" => export_conversation_to_json(&messages, &session, &path),
+                        "html" => export_conversation_to_html(&messages, &session, &path),
+                        _ => export_conversation_to_markdown(&messages, &session, &path),
+                    }
+                }
+                .await;
+                (session.id.clone(), result)
+            });
+        }
+
+        let Some(joined) = tasks.join_next().await else {
+            break;
+        };
+        progress.inc(1);
+        match joined {
+            Ok((_, Ok(()))) => written += 1,
+            Ok((id, Err(e))) => {
+                failed += 1;
+                progress.println(format!("⚠ Skipped {}: {:#}", id, e));
+            }
+            Err(e) => {
+                failed += 1;
+                progress.println(format!("⚠ Export task failed: {}", e));
+            }
+        }
+    }
+    progress.finish_and_clear();
+
+    let summary = format!(
+        "✓ Wrote {} conversation(s) to {}",
+        written,
+        output_dir.display()
+    );
+    if no_color {
+        println!("{}", summary);
+    } else {
+        println!("\x1b[32m{}\x1b[0m", summary);
+    }
+    if failed > 0 {
+        println!("{} session(s) skipped after errors", failed);
+    }
+
+    Ok(())
+}
+
 async fn run_export_conversation(
     claude_home: PathBuf,
     project: Option<PathBuf>,
+    store_args: &StoreArgs,
     session_id: String,
-    output: PathBuf,
+    output: Option<PathBuf>,
     format: String,
     no_color: bool,
 ) -> Result<()> {
     use ccboard_core::export::{
         export_conversation_to_html, export_conversation_to_json, export_conversation_to_markdown,
+        write_conversation_json, write_conversation_markdown,
     };
 
-    let store = Arc::new(DataStore::with_defaults(claude_home, project));
+    let store = Arc::new(open_store(claude_home, project, store_args));
 
     // Show progress
     if !no_color {
@@ -1067,6 +2189,17 @@ async fn run_export_conversation(
         eprintln!("✓ {} messages", messages.len());
     }
 
+    let Some(output) = output else {
+        let mut stdout = std::io::stdout().lock();
+        match format.as_str() {
+            "markdown" | "md" => write_conversation_markdown(&messages, &session, &mut stdout)?,
+            "json" => write_conversation_json(&messages, &session, &mut stdout)?,
+            "html" => anyhow::bail!("HTML export requires --output"),
+            _ => anyhow::bail!("Invalid format: {}. Use markdown, json, or html", format),
+        }
+        return Ok(());
+    };
+
     // Export based on format
     if !no_color {
         eprint!("Exporting to {}... ", output.display());
@@ -1106,16 +2239,19 @@ async fn run_export_conversation(
 async fn run_export_sessions(
     claude_home: PathBuf,
     project: Option<PathBuf>,
-    output: PathBuf,
+    store_args: &StoreArgs,
+    output: Option<PathBuf>,
     format: String,
     since: Option<String>,
     no_color: bool,
 ) -> Result<()> {
     use ccboard_core::{
-        export_sessions_to_csv, export_sessions_to_json, export_sessions_to_markdown,
+        export_sessions_to_csv, export_sessions_to_json, export_sessions_to_jsonl,
+        export_sessions_to_markdown, write_sessions_csv, write_sessions_json, write_sessions_jsonl,
+        write_sessions_markdown,
     };
 
-    let store = DataStore::with_defaults(claude_home, project);
+    let store = open_store(claude_home, project, store_args);
 
     if !no_color {
         eprint!("Loading sessions... ");
@@ -1139,6 +2275,18 @@ async fn run_export_sessions(
         });
     }
 
+    let Some(output) = output else {
+        let mut stdout = std::io::stdout().lock();
+        match format.as_str() {
+            "csv" => write_sessions_csv(&sessions, &mut stdout)?,
+            "json" => write_sessions_json(&sessions, &mut stdout)?,
+            "jsonl" => write_sessions_jsonl(&sessions, &mut stdout)?,
+            "md" | "markdown" => write_sessions_markdown(&sessions, &mut stdout)?,
+            _ => anyhow::bail!("Invalid format: {}. Use csv, json, jsonl, or md", format),
+        }
+        return Ok(());
+    };
+
     if !no_color {
         eprint!(
             "Exporting {} sessions to {}... ",
@@ -1156,12 +2304,16 @@ async fn run_export_sessions(
             export_sessions_to_json(&sessions, &output)
                 .context("Failed to export sessions to JSON")?;
         }
+        "jsonl" => {
+            export_sessions_to_jsonl(&sessions, &output)
+                .context("Failed to export sessions to JSON Lines")?;
+        }
         "md" | "markdown" => {
             export_sessions_to_markdown(&sessions, &output)
                 .context("Failed to export sessions to Markdown")?;
         }
         _ => {
-            anyhow::bail!("Invalid format: {}. Use csv, json, or md", format);
+            anyhow::bail!("Invalid format: {}. Use csv, json, jsonl, or md", format);
         }
     }
 
@@ -1180,13 +2332,17 @@ async fn run_export_sessions(
 async fn run_export_stats(
     claude_home: PathBuf,
     project: Option<PathBuf>,
-    output: PathBuf,
+    store_args: &StoreArgs,
+    output: Option<PathBuf>,
     format: String,
     no_color: bool,
 ) -> Result<()> {
-    use ccboard_core::{export_stats_to_csv, export_stats_to_json, export_stats_to_markdown};
+    use ccboard_core::{
+        export_stats_to_csv, export_stats_to_json, export_stats_to_markdown, write_stats_csv,
+        write_stats_json, write_stats_markdown,
+    };
 
-    let store = DataStore::with_defaults(claude_home, project);
+    let store = open_store(claude_home, project, store_args);
 
     if !no_color {
         eprint!("Loading statistics... ");
@@ -1202,6 +2358,17 @@ async fn run_export_stats(
         .stats()
         .ok_or_else(|| anyhow::anyhow!("No stats available (stats-cache.json not found)"))?;
 
+    let Some(output) = output else {
+        let mut stdout = std::io::stdout().lock();
+        match format.as_str() {
+            "csv" => write_stats_csv(&stats, &mut stdout)?,
+            "json" => write_stats_json(&stats, &mut stdout)?,
+            "md" | "markdown" => write_stats_markdown(&stats, &mut stdout, &store.currency())?,
+            _ => anyhow::bail!("Invalid format: {}. Use csv, json, or md", format),
+        }
+        return Ok(());
+    };
+
     if !no_color {
         eprint!("Exporting stats to {}... ", output.display());
     }
@@ -1214,7 +2381,7 @@ async fn run_export_stats(
             export_stats_to_json(&stats, &output).context("Failed to export stats to JSON")?;
         }
         "md" | "markdown" => {
-            export_stats_to_markdown(&stats, &output)
+            export_stats_to_markdown(&stats, &output, &store.currency())
                 .context("Failed to export stats to Markdown")?;
         }
         _ => {
@@ -1235,19 +2402,124 @@ async fn run_export_stats(
     Ok(())
 }
 
+async fn run_export_heatmap(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    output: Option<PathBuf>,
+    since: Option<String>,
+    no_color: bool,
+) -> Result<()> {
+    use ccboard_core::analytics::{detect_patterns_for_period, Period};
+    use ccboard_core::{export_heatmap_to_csv, write_heatmap_csv};
+
+    let period = match since.as_deref() {
+        Some(since) => parse_report_period(since)?,
+        None => Period::Available,
+    };
+
+    let store = open_store(claude_home, project, store_args);
+
+    if !no_color {
+        eprint!("Loading sessions... ");
+    }
+
+    store.initial_load().await;
+
+    if !no_color {
+        eprintln!("✓");
+    }
+
+    let patterns = detect_patterns_for_period(&store.aggregate_sessions(), &period);
+
+    let Some(output) = output else {
+        write_heatmap_csv(&patterns, &mut std::io::stdout().lock())?;
+        return Ok(());
+    };
+
+    if !no_color {
+        eprint!("Exporting heatmap to {}... ", output.display());
+    }
+
+    export_heatmap_to_csv(&patterns, &output).context("Failed to export heatmap to CSV")?;
+
+    if !no_color {
+        eprintln!("✓");
+        println!("✅ Exported to {}", output.display());
+        println!(
+            "   Sessions: {}",
+            patterns.weekday_distribution.iter().sum::<usize>()
+        );
+        println!("   Format: csv");
+    } else {
+        println!("{}", output.display());
+    }
+
+    Ok(())
+}
+
+fn run_export_task_graph(
+    plan: Option<PathBuf>,
+    output: Option<PathBuf>,
+    no_color: bool,
+) -> Result<()> {
+    use ccboard_core::graph::TaskGraph;
+    use ccboard_core::parsers::PlanParser;
+    use ccboard_core::{export_task_graph_to_dot, write_task_graph_dot};
+
+    let plan_path = match plan {
+        Some(path) => path,
+        None => {
+            let cwd = std::env::current_dir().context("Could not determine current directory")?;
+            let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+            PlanParser::locate(&cwd, &home)
+        }
+    };
+
+    let content = std::fs::read_to_string(&plan_path)
+        .with_context(|| format!("Failed to read {}", plan_path.display()))?;
+    let plan = PlanParser::parse(&content)
+        .with_context(|| format!("Failed to parse {}", plan_path.display()))?
+        .with_context(|| format!("{} has no YAML frontmatter", plan_path.display()))?;
+
+    let graph = TaskGraph::from_plan(&plan, &content);
+    let statuses = plan.task_statuses();
+
+    let Some(output) = output else {
+        let mut stdout = std::io::stdout().lock();
+        write_task_graph_dot(&graph, &statuses, &mut stdout)?;
+        return Ok(());
+    };
+
+    export_task_graph_to_dot(&graph, &statuses, &output)
+        .context("Failed to export task graph to DOT")?;
+
+    if !no_color {
+        println!("✅ Exported to {}", output.display());
+        println!("   Tasks: {}", graph.len());
+        println!("   Format: dot");
+    } else {
+        println!("{}", output.display());
+    }
+
+    Ok(())
+}
+
 async fn run_export_billing(
     claude_home: PathBuf,
     project: Option<PathBuf>,
-    output: PathBuf,
+    store_args: &StoreArgs,
+    output: Option<PathBuf>,
     format: String,
     no_color: bool,
 ) -> Result<()> {
     use ccboard_core::{
         export_billing_blocks_to_csv, export_billing_blocks_to_json,
-        export_billing_blocks_to_markdown,
+        export_billing_blocks_to_markdown, write_billing_blocks_csv, write_billing_blocks_json,
+        write_billing_blocks_markdown,
     };
 
-    let store = DataStore::with_defaults(claude_home, project);
+    let store = open_store(claude_home, project, store_args);
 
     if !no_color {
         eprint!("Loading billing data... ");
@@ -1263,6 +2535,19 @@ async fn run_export_billing(
     let manager = store.billing_blocks();
     let block_count = manager.get_all_blocks().len();
 
+    let Some(output) = output else {
+        let mut stdout = std::io::stdout().lock();
+        match format.as_str() {
+            "csv" => write_billing_blocks_csv(&manager, &mut stdout, &store.currency())?,
+            "json" => write_billing_blocks_json(&manager, &mut stdout)?,
+            "md" | "markdown" => {
+                write_billing_blocks_markdown(&manager, &mut stdout, &store.currency())?
+            }
+            _ => anyhow::bail!("Invalid format: {}. Use csv, json, or md", format),
+        }
+        return Ok(());
+    };
+
     if !no_color {
         eprint!(
             "Exporting {} blocks to {}... ",
@@ -1273,7 +2558,7 @@ async fn run_export_billing(
 
     match format.as_str() {
         "csv" => {
-            export_billing_blocks_to_csv(&manager, &output)
+            export_billing_blocks_to_csv(&manager, &output, &store.currency())
                 .context("Failed to export billing to CSV")?;
         }
         "json" => {
@@ -1281,7 +2566,7 @@ async fn run_export_billing(
                 .context("Failed to export billing to JSON")?;
         }
         "md" | "markdown" => {
-            export_billing_blocks_to_markdown(&manager, &output)
+            export_billing_blocks_to_markdown(&manager, &output, &store.currency())
                 .context("Failed to export billing to Markdown")?;
         }
         _ => {
@@ -1301,20 +2586,119 @@ async fn run_export_billing(
     Ok(())
 }
 
-async fn run_pricing_update(_no_color: bool) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+async fn run_export_comparison(
+    claude_home: PathBuf,
+    project: Option<PathBuf>,
+    store_args: &StoreArgs,
+    a: String,
+    b: String,
+    output: PathBuf,
+    format: String,
+    no_color: bool,
+) -> Result<()> {
+    use ccboard_core::analytics::DateRange;
+    use ccboard_core::{
+        export_comparison_to_csv, export_comparison_to_json, export_comparison_to_markdown,
+    };
+
+    let period_a = DateRange::parse(&a).map_err(|e| anyhow::anyhow!(e))?;
+    let period_b = DateRange::parse(&b).map_err(|e| anyhow::anyhow!(e))?;
+
+    let store = open_store(claude_home, project, store_args);
+
+    if !no_color {
+        eprint!("Loading sessions... ");
+    }
+
+    store.initial_load().await;
+
+    if !no_color {
+        eprintln!("✓");
+        eprint!(
+            "Exporting {} vs {} to {}... ",
+            period_a.label,
+            period_b.label,
+            output.display()
+        );
+    }
+
+    let comparison = store.compare_periods(&period_a, &period_b);
+
+    match format.as_str() {
+        "csv" => {
+            export_comparison_to_csv(&comparison, &output)
+                .context("Failed to export comparison to CSV")?;
+        }
+        "json" => {
+            export_comparison_to_json(&comparison, &output)
+                .context("Failed to export comparison to JSON")?;
+        }
+        "md" | "markdown" => {
+            export_comparison_to_markdown(&comparison, &output, &store.currency())
+                .context("Failed to export comparison to Markdown")?;
+        }
+        _ => {
+            anyhow::bail!("Invalid format: {}. Use csv, json, or md", format);
+        }
+    }
+
+    if !no_color {
+        eprintln!("✓");
+        println!("✅ Exported to {}", output.display());
+        println!(
+            "   Sessions: {} vs {}",
+            comparison.metrics_a.sessions, comparison.metrics_b.sessions
+        );
+        println!("   Format: {}", format);
+    } else {
+        println!("{}", output.display());
+    }
+
+    Ok(())
+}
+
+async fn run_pricing_update(force: bool, _no_color: bool) -> Result<()> {
+    use ccboard_core::pricing::litellm::PricingFetchError;
+    use ccboard_core::CoreError;
+
     let spinner = create_spinner();
     spinner.set_message("Fetching pricing from LiteLLM...");
 
-    match ccboard_core::pricing::update_pricing_from_litellm().await {
-        Ok(count) => {
+    match ccboard_core::pricing::update_pricing_from_litellm(force).await {
+        Ok(update) => {
             spinner.finish_and_clear();
-            println!("✓ Updated {} model prices from LiteLLM", count);
+            if let Some(age) = update.skipped_fresh {
+                println!(
+                    "✓ Pricing cache is fresh ({} days old), nothing to do",
+                    age.num_days()
+                );
+                println!("  Use --force to fetch anyway");
+                return Ok(());
+            }
+            println!(
+                "✓ Fetched {} model prices from LiteLLM ({} updated, {} unchanged)",
+                update.updated.len() + update.unchanged.len(),
+                update.updated.len(),
+                update.unchanged.len()
+            );
+            for model in &update.updated {
+                println!("  + {}", model);
+            }
             println!("  Cache: ~/.cache/ccboard/pricing.json (TTL: 7 days)");
             Ok(())
         }
         Err(e) => {
             spinner.finish_and_clear();
-            eprintln!("✗ Failed to update pricing: {}", e);
+            match e.downcast_ref::<CoreError>() {
+                Some(CoreError::PricingFetch(PricingFetchError::Network { .. })) => {
+                    eprintln!("✗ Network failure while fetching pricing: {}", e)
+                }
+                Some(CoreError::PricingFetch(PricingFetchError::Malformed(_))) => {
+                    eprintln!("✗ LiteLLM returned unexpected data: {}", e)
+                }
+                _ => eprintln!("✗ Failed to update pricing: {:#}", e),
+            }
             eprintln!("  Using embedded pricing as fallback");
             Ok(())
         }
@@ -1529,8 +2913,10 @@ async fn run_discover(
 async fn run_report(
     claude_home: PathBuf,
     project: Option<PathBuf>,
+    store_args: &StoreArgs,
     format: String,
     since: String,
+    standup: bool,
     budget: Option<u64>,
     error_threshold: Option<f64>,
     output: Option<PathBuf>,
@@ -1541,15 +2927,30 @@ async fn run_report(
     let spinner = create_spinner();
     spinner.set_message("Loading data...");
 
-    let store = DataStore::with_defaults(claude_home, project);
+    let store = open_store(claude_home, project, store_args);
     store.initial_load().await;
 
-    let days = parse_since_to_days(&since)?;
-    let period = Period::Days(days as usize);
-    let sessions = store.all_sessions();
+    let period = parse_report_period(&since)?;
+    let period_label = match period {
+        Period::Custom { .. } => period.display(0),
+        _ => format!("last {}d", period.days()),
+    };
+    let sessions = store.aggregate_sessions();
+    let currency = store.currency();
 
     spinner.set_message("Computing analytics...");
-    let analytics = AnalyticsData::compute(&sessions, period);
+    if standup {
+        let summary = ccboard_core::analytics::standup_summary(&sessions, period);
+        spinner.finish_and_clear();
+        let content = match format.as_str() {
+            "json" => serde_json::to_string_pretty(&summary)?,
+            "markdown" => summary.to_markdown(&currency),
+            other => anyhow::bail!("--standup supports markdown or json, not {}", other),
+        };
+        return write_report(&content, output);
+    }
+    let analytics =
+        AnalyticsData::compute_with_thresholds(&sessions, period, &Default::default(), &currency);
 
     // Derive error stats: sessions with non-zero tool errors as proxy
     let total_sessions = sessions.len();
@@ -1604,13 +3005,19 @@ async fn run_report(
                 .collect();
 
             serde_json::to_string_pretty(&serde_json::json!({
-                "period": format!("{}d", days),
+                "period": match period {
+                    Period::Custom { .. } => period_label.clone(),
+                    _ => format!("{}d", period.days()),
+                },
                 "generated_at": chrono::Utc::now().to_rfc3339(),
                 "summary": {
                     "total_tokens": total_tokens,
                     "total_sessions": total_sessions,
                     "sessions_in_period": analytics.sessions_in_period,
                     "total_cost_usd": format!("{:.4}", total_cost),
+                    "monthly_cost_estimate_usd": format!("{:.2}", analytics.forecast.monthly_cost_estimate),
+                    "monthly_cost_low_usd": analytics.forecast.monthly_cost_low.map(|c| format!("{:.2}", c)),
+                    "monthly_cost_high_usd": analytics.forecast.monthly_cost_high.map(|c| format!("{:.2}", c)),
                     "error_rate_pct": format!("{:.2}", error_rate),
                     "cache_hit_ratio": store.stats().map(|s| format!("{:.1}", s.cache_ratio() * 100.0)).unwrap_or_else(|| "N/A".to_string()),
                 },
@@ -1632,12 +3039,12 @@ async fn run_report(
                 .take(10)
                 .map(|t| {
                     format!(
-                        "<tr><td>{}</td><td>{}</td><td>{}</td><td>{:.1}%</td><td>${:.4}</td></tr>",
+                        "<tr><td>{}</td><td>{}</td><td>{}</td><td>{:.1}%</td><td>{}</td></tr>",
                         t.tool_name,
                         t.call_count,
                         format_number(t.tokens),
                         t.pct_of_total * 100.0,
-                        t.est_cost_usd
+                        currency.format(t.est_cost_usd, 4)
                     )
                 })
                 .collect::<Vec<_>>()
@@ -1656,7 +3063,7 @@ async fn run_report(
 <head>
 <meta charset="UTF-8">
 <meta name="viewport" content="width=device-width, initial-scale=1.0">
-<title>ccboard Report — last {days}d</title>
+<title>ccboard Report — {period_label}</title>
 <style>
   body {{ font-family: system-ui, sans-serif; background: #0d1117; color: #e6edf3; padding: 2rem; }}
   h1 {{ color: #58a6ff; }} h2 {{ color: #79c0ff; border-bottom: 1px solid #30363d; padding-bottom: 0.4rem; }}
@@ -1671,14 +3078,15 @@ async fn run_report(
 </head>
 <body>
 <h1>ccboard Report</h1>
-<p>Period: last {days} days &nbsp;|&nbsp; Generated: {generated}</p>
+<p>Period: {period_label} &nbsp;|&nbsp; Generated: {generated}</p>
 
 <h2>Summary</h2>
 <div>
   <div class="stat"><div class="stat-label">Total Tokens</div><div class="stat-value">{tokens}</div></div>
   <div class="stat"><div class="stat-label">Sessions (period)</div><div class="stat-value">{sessions_period}</div></div>
   <div class="stat"><div class="stat-label">Total Sessions</div><div class="stat-value">{sessions_total}</div></div>
-  <div class="stat"><div class="stat-label">Est. Cost</div><div class="stat-value">${cost:.4}</div></div>
+  <div class="stat"><div class="stat-label">Est. Cost</div><div class="stat-value">{cost}</div></div>
+  <div class="stat"><div class="stat-label">Monthly Forecast</div><div class="stat-value">{monthly}</div></div>
   <div class="stat"><div class="stat-label">Error Rate</div><div class="stat-value">{error_rate:.1}%</div></div>
 </div>
 
@@ -1699,12 +3107,13 @@ async fn run_report(
 </ul>
 </body>
 </html>"#,
-                days = days,
+                period_label = period_label,
                 generated = chrono::Utc::now().format("%Y-%m-%d %H:%M UTC"),
                 tokens = format_number(total_tokens),
                 sessions_period = analytics.sessions_in_period,
                 sessions_total = total_sessions,
-                cost = total_cost,
+                cost = currency.format(total_cost, 4),
+                monthly = analytics.forecast.monthly_cost_display(&currency),
                 error_rate = error_rate,
                 rows = rows,
                 insights_html = insights_html,
@@ -1740,8 +3149,8 @@ async fn run_report(
         _ => {
             // Markdown (default)
             let mut md = format!(
-                "# ccboard Report\n\n**Period:** last {days}d  \n**Generated:** {generated}\n\n",
-                days = days,
+                "# ccboard Report\n\n**Period:** {period_label}  \n**Generated:** {generated}\n\n",
+                period_label = period_label,
                 generated = chrono::Utc::now().format("%Y-%m-%d %H:%M UTC")
             );
 
@@ -1756,7 +3165,14 @@ async fn run_report(
                 analytics.sessions_in_period
             ));
             md.push_str(&format!("| Total Sessions | {} |\n", total_sessions));
-            md.push_str(&format!("| Est. Cost | ${:.4} |\n", total_cost));
+            md.push_str(&format!(
+                "| Est. Cost | {} |\n",
+                currency.format(total_cost, 4)
+            ));
+            md.push_str(&format!(
+                "| Monthly Forecast | {} |\n",
+                analytics.forecast.monthly_cost_display(&currency)
+            ));
             md.push_str(&format!("| Error Rate | {:.1}% |\n", error_rate));
             if let Some(stats) = store.stats() {
                 md.push_str(&format!(
@@ -1771,12 +3187,12 @@ async fn run_report(
                 md.push_str("|------|-------|--------|------------|-----------|\n");
                 for t in analytics.tool_token_stats.iter().take(10) {
                     md.push_str(&format!(
-                        "| {} | {} | {} | {:.1}% | ${:.4} |\n",
+                        "| {} | {} | {} | {:.1}% | {} |\n",
                         t.tool_name,
                         t.call_count,
                         format_number(t.tokens),
                         t.pct_of_total * 100.0,
-                        t.est_cost_usd
+                        currency.format(t.est_cost_usd, 4)
                     ));
                 }
             }
@@ -1814,15 +3230,7 @@ async fn run_report(
         }
     };
 
-    // Write output
-    match output {
-        Some(path) => {
-            std::fs::write(&path, &content)
-                .with_context(|| format!("Failed to write report to {}", path.display()))?;
-            println!("Report written to {}", path.display());
-        }
-        None => print!("{}", content),
-    }
+    write_report(&content, output)?;
 
     // CI gate: exit 1 if any threshold exceeded
     let budget_exceeded = budget.map(|b| total_tokens > b).unwrap_or(false);
@@ -1849,6 +3257,32 @@ async fn run_report(
     Ok(())
 }
 
+/// Print a report, or write it to `output`
+fn write_report(content: &str, output: Option<PathBuf>) -> Result<()> {
+    match output {
+        Some(path) => {
+            std::fs::write(&path, content)
+                .with_context(|| format!("Failed to write report to {}", path.display()))?;
+            println!("Report written to {}", path.display());
+        }
+        None => print!("{}", content),
+    }
+    Ok(())
+}
+
+/// Report window: `Nd`/`YYYY-MM-DD` (up to now), a month `YYYY-MM`, or a
+/// range `YYYY-MM-DD..YYYY-MM-DD`
+fn parse_report_period(since: &str) -> Result<ccboard_core::analytics::Period> {
+    use ccboard_core::analytics::{DateRange, Period};
+
+    let is_month = since.len() == 7 && since.as_bytes()[4] == b'-';
+    if since.contains("..") || is_month {
+        let range = DateRange::parse(since).map_err(|e| anyhow::anyhow!(e))?;
+        return Ok(Period::from_date_range(&range));
+    }
+    Ok(Period::Days(parse_since_to_days(since)? as usize))
+}
+
 /// Parse a `--since` string like "7d", "30d", "90d", or "YYYY-MM-DD" into days.
 fn parse_since_to_days(since: &str) -> Result<u32> {
     // Try "Nd" format
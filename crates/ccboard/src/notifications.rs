@@ -0,0 +1,146 @@
+//! Desktop notifications for budget alerts
+//!
+//! Enabled with `"notifications": { "budget": true }` in settings. Alerts are
+//! re-evaluated periodically while the TUI runs, and a notification is only
+//! sent when a budget moves into a higher alert level (warning → critical →
+//! exceeded), so a budget that stays over its threshold notifies once.
+//!
+//! Sending requires a build with the `notifications` feature
+//! (`cargo install ccboard --features notifications`).
+
+use ccboard_core::analytics::{Alert, BudgetWatch};
+use ccboard_core::{AnalyticsData, DataStore, Period};
+use ccboard_types::Currency;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often budget alerts are re-evaluated
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Start the budget notifier if `notifications.budget` is enabled
+///
+/// Runs until the process exits. Returns immediately when the setting is off.
+pub fn spawn_budget_notifier(store: Arc<DataStore>) {
+    let enabled = store
+        .settings()
+        .merged
+        .notifications
+        .is_some_and(|n| n.budget);
+    if !enabled {
+        return;
+    }
+
+    if !cfg!(feature = "notifications") {
+        tracing::warn!(
+            "notifications.budget is enabled but this build lacks desktop notification support \
+             (rebuild with --features notifications)"
+        );
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut watch = BudgetWatch::new();
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            let Some(critical) = store.settings().merged.budget.map(|b| b.critical_threshold)
+            else {
+                continue;
+            };
+
+            // Computed separately from the TUI's analytics so the period
+            // selected there is left untouched
+            let sessions = store.aggregate_sessions();
+            let data = match tokio::task::spawn_blocking(move || {
+                AnalyticsData::compute(&sessions, Period::last_30d())
+            })
+            .await
+            {
+                Ok(data) => data,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Budget check failed");
+                    continue;
+                }
+            };
+
+            let alerts = store.budget_alerts(&data);
+            let currency = store.currency();
+            for alert in watch.escalations(&alerts, critical) {
+                send(&alert_body(&alert, &currency));
+            }
+        }
+    });
+}
+
+/// One-line notification text for a budget alert
+fn alert_body(alert: &Alert, currency: &Currency) -> String {
+    match alert {
+        Alert::BudgetWarning {
+            current,
+            budget,
+            pct,
+        } => format!(
+            "Monthly spend {} is {:.0}% of the {} budget",
+            currency.format_cost(*current),
+            pct,
+            currency.format_cost(*budget)
+        ),
+        Alert::ProjectedOverage {
+            forecast, overage, ..
+        } => format!(
+            "Month projected at {}, {} over budget",
+            currency.format_cost(*forecast),
+            currency.format_cost(*overage)
+        ),
+        Alert::DailyBudgetExceeded {
+            spent,
+            projected,
+            budget,
+        } => format!(
+            "Today: {} spent, {} projected (daily budget {})",
+            currency.format_cost(*spent),
+            currency.format_cost(*projected),
+            currency.format_cost(*budget)
+        ),
+        Alert::BlockBudgetExceeded {
+            spent,
+            projected,
+            budget,
+        } => format!(
+            "Current 5h block: {} spent, {} projected (budget {})",
+            currency.format_cost(*spent),
+            currency.format_cost(*projected),
+            currency.format_cost(*budget)
+        ),
+        Alert::ProjectBudgetWarning {
+            project,
+            spent,
+            budget,
+            pct,
+        } => format!(
+            "{}: {} this month, {:.0}% of {}",
+            project,
+            currency.format_cost(*spent),
+            pct,
+            currency.format_cost(*budget)
+        ),
+        Alert::UsageSpike { day, tokens, .. } => {
+            format!("Usage spike on {}: {} tokens", day, tokens)
+        }
+    }
+}
+
+#[cfg(feature = "notifications")]
+fn send(body: &str) {
+    if let Err(e) = notify_rust::Notification::new()
+        .summary("ccboard budget alert")
+        .body(body)
+        .show()
+    {
+        tracing::warn!(error = %e, "Failed to show desktop notification");
+    }
+}
+
+#[cfg(not(feature = "notifications"))]
+fn send(_body: &str) {}